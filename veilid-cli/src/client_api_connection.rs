@@ -109,6 +109,9 @@ impl ClientApiConnection {
             "ValueChange" => {
                 comproc.update_value_change(&update);
             }
+            "TopicMessage" => {
+                comproc.update_topic_message(&update);
+            }
             _ => {
                 comproc.log_message(Level::Error, &format!("unknown update kind: {}", update));
             }
@@ -407,6 +410,134 @@ impl ClientApiConnection {
         Ok(())
     }
 
+    pub async fn server_reload_config(&self) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::reload_config");
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "Control".into();
+        req["args"] = json::JsonValue::new_array();
+        req["args"].push("ReloadConfig").unwrap();
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        json::parse(resp["value"].as_str().unwrap_or_default()).map_err(|e| e.to_string())
+    }
+
+    async fn perform_routing_context_request(
+        &self,
+        rc_id: u32,
+        mut rc_req: json::JsonValue,
+    ) -> Result<json::JsonValue, String> {
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "RoutingContext".into();
+        req["rc_id"] = rc_id.into();
+        for (k, v) in rc_req.entries_mut() {
+            req[k] = v.take();
+        }
+        let Some(mut resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        if resp["rc_op"] == "InvalidId" {
+            return Err("Invalid routing context id".to_owned());
+        }
+        Ok(resp["value"].take())
+    }
+
+    pub async fn server_new_routing_context(&self) -> Result<u32, String> {
+        trace!("ClientApiConnection::server_new_routing_context");
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "NewRoutingContext".into();
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        resp["value"]
+            .as_u32()
+            .ok_or_else(|| format!("invalid routing context id: {}", resp["value"]))
+    }
+
+    pub async fn server_open_dht_record(
+        &self,
+        rc_id: u32,
+        key: String,
+        writer: Option<String>,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_open_dht_record");
+        let mut rc_req = json::JsonValue::new_object();
+        rc_req["rc_op"] = "OpenDhtRecord".into();
+        rc_req["key"] = key.into();
+        if let Some(writer) = writer {
+            rc_req["writer"] = writer.into();
+        }
+        self.perform_routing_context_request(rc_id, rc_req).await
+    }
+
+    pub async fn server_close_dht_record(&self, rc_id: u32, key: String) -> Result<(), String> {
+        trace!("ClientApiConnection::server_close_dht_record");
+        let mut rc_req = json::JsonValue::new_object();
+        rc_req["rc_op"] = "CloseDhtRecord".into();
+        rc_req["key"] = key.into();
+        self.perform_routing_context_request(rc_id, rc_req).await?;
+        Ok(())
+    }
+
+    pub async fn server_inspect_dht_record(
+        &self,
+        rc_id: u32,
+        key: String,
+        scope: &str,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_inspect_dht_record");
+        let mut rc_req = json::JsonValue::new_object();
+        rc_req["rc_op"] = "InspectDhtRecord".into();
+        rc_req["key"] = key.into();
+        rc_req["scope"] = scope.into();
+        self.perform_routing_context_request(rc_id, rc_req).await
+    }
+
+    pub async fn server_get_dht_value(
+        &self,
+        rc_id: u32,
+        key: String,
+        subkey: u32,
+        force_refresh: bool,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_get_dht_value");
+        let mut rc_req = json::JsonValue::new_object();
+        rc_req["rc_op"] = "GetDhtValue".into();
+        rc_req["key"] = key.into();
+        rc_req["subkey"] = subkey.into();
+        rc_req["force_refresh"] = force_refresh.into();
+        self.perform_routing_context_request(rc_id, rc_req).await
+    }
+
+    pub async fn server_set_dht_value(
+        &self,
+        rc_id: u32,
+        key: String,
+        subkey: u32,
+        data: &[u8],
+        writer: Option<String>,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_set_dht_value");
+        let mut rc_req = json::JsonValue::new_object();
+        rc_req["rc_op"] = "SetDhtValue".into();
+        rc_req["key"] = key.into();
+        rc_req["subkey"] = subkey.into();
+        rc_req["data"] = data_encoding::BASE64URL_NOPAD.encode(data).into();
+        if let Some(writer) = writer {
+            rc_req["writer"] = writer.into();
+        }
+        self.perform_routing_context_request(rc_id, rc_req).await
+    }
+
     // Start Client API connection
     pub async fn ipc_connect(&self, ipc_path: PathBuf) -> Result<(), String> {
         trace!("ClientApiConnection::ipc_connect");
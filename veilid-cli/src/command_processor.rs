@@ -1,8 +1,10 @@
 use crate::client_api_connection::*;
+use crate::node_fleet::*;
 use crate::settings::Settings;
 use crate::tools::*;
 use crate::ui::*;
 use indent::indent_all_by;
+use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::time::SystemTime;
@@ -40,6 +42,113 @@ impl ConnectionState {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DHTRecordScope {
+    Opened,
+    Local,
+    Remote,
+}
+
+impl DHTRecordScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DHTRecordScope::Opened => "opened",
+            DHTRecordScope::Local => "local",
+            DHTRecordScope::Remote => "remote",
+        }
+    }
+}
+
+/// A DHT record as seen by the record browser
+#[derive(Clone, Debug, PartialEq)]
+pub struct DHTRecordItem {
+    pub key: String,
+    pub scope: DHTRecordScope,
+    /// Writer the record was opened with, if known
+    pub writer: Option<String>,
+    /// Subkeys stored in the record store, as reported by 'record list'
+    pub stored_subkeys: Option<String>,
+    /// Record descriptor, if the browser opened the record itself
+    pub descriptor: Option<json::JsonValue>,
+    /// Most recent local inspection report, if the record is opened
+    pub report: Option<json::JsonValue>,
+    /// Subkeys from the most recent ValueChange update for this record
+    pub last_change: Option<json::JsonValue>,
+}
+
+impl DHTRecordItem {
+    pub fn new(key: String, scope: DHTRecordScope) -> Self {
+        Self {
+            key,
+            scope,
+            writer: None,
+            stored_subkeys: None,
+            descriptor: None,
+            report: None,
+            last_change: None,
+        }
+    }
+
+    pub fn is_opened(&self) -> bool {
+        self.scope == DHTRecordScope::Opened
+    }
+}
+
+/// Parse the output of the 'record list <scope>' debug command
+fn parse_dht_record_list(scope: DHTRecordScope, out: &str) -> Vec<DHTRecordItem> {
+    let mut items = Vec::new();
+    let mut in_list = false;
+    for line in out.lines() {
+        match scope {
+            DHTRecordScope::Opened => {
+                // [
+                //   <key> [<writer>]
+                // ]
+                if line.starts_with('[') {
+                    in_list = true;
+                    continue;
+                }
+                if line.starts_with(']') {
+                    break;
+                }
+                if !in_list {
+                    continue;
+                }
+                let mut parts = line.split_whitespace();
+                let Some(key) = parts.next() else {
+                    continue;
+                };
+                let mut item = DHTRecordItem::new(key.to_owned(), scope);
+                item.writer = parts.next().map(|x| x.to_owned());
+                items.push(item);
+            }
+            DHTRecordScope::Local | DHTRecordScope::Remote => {
+                // Record Index:
+                //   <key> age=<age> len=<len> subkeys=<subkeys>
+                if line.starts_with("Record Index:") {
+                    in_list = true;
+                    continue;
+                }
+                if !in_list {
+                    continue;
+                }
+                if !line.starts_with("  ") {
+                    break;
+                }
+                let Some(key) = line.split_whitespace().next() else {
+                    continue;
+                };
+                let mut item = DHTRecordItem::new(key.to_owned(), scope);
+                item.stored_subkeys = line
+                    .find("subkeys=")
+                    .map(|p| line[p + "subkeys=".len()..].trim().to_owned());
+                items.push(item);
+            }
+        }
+    }
+    items
+}
+
 struct CommandProcessorInner {
     ui_sender: Box<dyn UISender>,
     capi: Option<ClientApiConnection>,
@@ -52,16 +161,23 @@ struct CommandProcessorInner {
     connection_waker: Eventual,
     last_call_id: Option<u64>,
     enable_app_messages: bool,
+    dht_browser_rc_id: Option<u32>,
+    dht_browser_opened: HashSet<String>,
+    dht_records: Vec<DHTRecordItem>,
+    routes_view_active: bool,
+    connections_view_active: bool,
 }
 
 #[derive(Clone)]
 pub struct CommandProcessor {
     inner: Arc<Mutex<CommandProcessorInner>>,
     settings: Arc<Settings>,
+    fleet: NodeFleet,
 }
 
 impl CommandProcessor {
     pub fn new(ui_sender: Box<dyn UISender>, settings: &Settings) -> Self {
+        let fleet = NodeFleet::new(ui_sender.clone_uisender(), settings);
         Self {
             inner: Arc::new(Mutex::new(CommandProcessorInner {
                 ui_sender,
@@ -75,8 +191,14 @@ impl CommandProcessor {
                 connection_waker: Eventual::new(),
                 last_call_id: None,
                 enable_app_messages: false,
+                dht_browser_rc_id: None,
+                dht_browser_opened: HashSet::new(),
+                dht_records: Vec::new(),
+                routes_view_active: false,
+                connections_view_active: false,
             })),
             settings: Arc::new(settings.clone()),
+            fleet,
         }
     }
     pub fn set_client_api_connection(&self, capi: ClientApiConnection) {
@@ -141,6 +263,14 @@ impl CommandProcessor {
     change_log_ignore <layer> <changes> change the log target ignore list for a tracing layer
                                         targets to add to the ignore list can be separated by a comma.
                                         to remove a target from the ignore list, prepend it with a minus.
+    reload_config                       re-read the server config file and apply what can change while running
+    node add <name> <connection>        connect to an additional node by subnode index, ipc path or address
+    node remove <name>                  disconnect an additional node
+    node group <group> [name...]        set the nodes in a group, or remove the group if none are given
+    node list                           show the state of all additional nodes
+    node focus <name>                   switch the main connection to an additional node
+    on <selector> <command>             run a debug command on several nodes
+                                        selectors are comma separated node names, group names, or 'all'
     enable [flag]                       set a flag
     disable [flag]                      unset a flag
                                         valid flags in include:
@@ -198,34 +328,8 @@ Core Debug Commands:
             capi.disconnect();
 
             if let Some(rest) = rest {
-                if let Ok(subnode_index) = u16::from_str(&rest) {
-                    let ipc_path = this
-                        .settings
-                        .resolve_ipc_path(this.settings.ipc_path.clone(), subnode_index);
-                    this.set_ipc_path(ipc_path);
-                    this.set_network_address(None);
-                } else if let Some(ipc_path) =
-                    this.settings.resolve_ipc_path(Some(rest.clone().into()), 0)
-                {
-                    this.set_ipc_path(Some(ipc_path));
-                    this.set_network_address(None);
-                } else if let Ok(Some(network_address)) =
-                    this.settings.resolve_network_address(Some(rest.clone()))
-                {
-                    if let Some(addr) = network_address.first() {
-                        this.set_network_address(Some(*addr));
-                        this.set_ipc_path(None);
-                    } else {
-                        ui.add_node_event(
-                            Level::Error,
-                            &format!("Invalid network address: {}", rest),
-                        );
-                    }
-                } else {
-                    ui.add_node_event(
-                        Level::Error,
-                        &format!("Invalid connection string: {}", rest),
-                    );
+                if let Err(e) = this.set_connection_string(&rest) {
+                    ui.add_node_event(Level::Error, &e);
                 }
             }
 
@@ -329,6 +433,127 @@ Core Debug Commands:
         Ok(())
     }
 
+    pub fn cmd_reload_config(&self, callback: UICallback) -> Result<(), String> {
+        trace!("CommandProcessor::cmd_reload_config");
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        spawn_detached_local("cmd reload_config", async move {
+            match capi.server_reload_config().await {
+                Ok(report) => {
+                    ui.display_string_dialog(
+                        "Configuration reloaded",
+                        &Self::format_reload_report(&report),
+                        callback,
+                    );
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Server command 'reload_config' failed", &e, callback);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn format_reload_report(report: &json::JsonValue) -> String {
+        if report["changed"].is_empty() {
+            return "No changes".to_owned();
+        }
+        let mut out = String::new();
+        for k in report["applied"].members() {
+            out += &format!("applied: {}\n", k);
+        }
+        for k in report["restart_required"].members() {
+            out += &format!("restart required: {}\n", k);
+        }
+        for (subnode, r) in report["subnodes"].entries() {
+            out += &format!("subnode {}:\n", subnode);
+            for k in r["applied"].members() {
+                out += &format!("    applied: {}\n", k);
+            }
+            if r["network_restarted"].as_bool().unwrap_or_default() {
+                out += "    network restarted\n";
+            }
+            for k in r["restart_required"].members() {
+                out += &format!("    restart required: {}\n", k);
+            }
+        }
+        out
+    }
+
+    pub fn cmd_node(&self, rest: Option<String>, callback: UICallback) -> Result<(), String> {
+        trace!("CommandProcessor::cmd_node");
+
+        let ui = self.ui_sender();
+        let this = self.clone();
+        spawn_detached_local("cmd node", async move {
+            let rest = rest.unwrap_or_default();
+            let args: Vec<String> = rest.split_whitespace().map(|x| x.to_owned()).collect();
+            let res = match args.first().map(|x| x.as_str()) {
+                Some("add") if args.len() == 3 => this
+                    .fleet
+                    .add_node(args[1].clone(), args[2].clone())
+                    .map(|_| format!("node added: {}", args[1])),
+                Some("remove") if args.len() == 2 => this
+                    .fleet
+                    .remove_node(&args[1])
+                    .map(|_| format!("node removed: {}", args[1])),
+                Some("group") if args.len() >= 2 => this
+                    .fleet
+                    .set_group(args[1].clone(), args[2..].to_vec())
+                    .map(|_| format!("group updated: {}", args[1])),
+                Some("list") | None => Ok(this.format_fleet()),
+                Some("focus") if args.len() == 2 => {
+                    return this.focus_node(args[1].clone(), callback);
+                }
+                _ => Err(format!("Invalid node command: {}", rest)),
+            };
+            match res {
+                Ok(out) => ui.add_node_event(Level::Info, &out),
+                Err(e) => ui.add_node_event(Level::Error, &e),
+            }
+            ui.send_callback(callback);
+        });
+        Ok(())
+    }
+
+    pub fn cmd_on(&self, rest: Option<String>, callback: UICallback) -> Result<(), String> {
+        trace!("CommandProcessor::cmd_on");
+
+        let ui = self.ui_sender();
+        let (selector, command_line) = Self::word_split(&rest.unwrap_or_default());
+        let res = self
+            .fleet
+            .select(&selector)
+            .and_then(|nodes| match command_line {
+                Some(command_line) => Ok((nodes, command_line)),
+                None => Err("Missing command".to_owned()),
+            });
+        let (nodes, command_line) = match res {
+            Ok(v) => v,
+            Err(e) => {
+                ui.add_node_event(Level::Error, &e);
+                ui.send_callback(callback);
+                return Ok(());
+            }
+        };
+
+        spawn_detached_local("cmd on", async move {
+            let results = futures::future::join_all(nodes.into_iter().map(|(name, capi)| {
+                let command_line = command_line.clone();
+                async move { (name, capi.server_debug(command_line).await) }
+            }))
+            .await;
+            for (name, res) in results {
+                match res {
+                    Ok(output) => ui.add_node_event(Level::Info, &format!("[{}] {}", name, output)),
+                    Err(e) => ui.add_node_event(Level::Error, &format!("[{}] {}", name, e)),
+                }
+            }
+            ui.send_callback(callback);
+        });
+        Ok(())
+    }
+
     pub fn cmd_enable(&self, rest: Option<String>, callback: UICallback) -> Result<(), String> {
         trace!("CommandProcessor::cmd_enable");
 
@@ -385,8 +610,11 @@ Core Debug Commands:
             "shutdown" => self.cmd_shutdown(callback),
             "change_log_level" => self.cmd_change_log_level(rest, callback),
             "change_log_ignore" => self.cmd_change_log_ignore(rest, callback),
+            "reload_config" => self.cmd_reload_config(callback),
             "enable" => self.cmd_enable(rest, callback),
             "disable" => self.cmd_disable(rest, callback),
+            "node" => self.cmd_node(rest, callback),
+            "on" => self.cmd_on(rest, callback),
             _ => self.cmd_debug(command_line.to_owned(), callback),
         }
     }
@@ -497,6 +725,34 @@ Core Debug Commands:
     pub fn get_network_address(&self) -> Option<SocketAddr> {
         self.inner().network_addr
     }
+    /// Choose the server to connect to from a subnode index, ipc path, or network address
+    pub fn set_connection_string(&self, connection: &str) -> Result<(), String> {
+        if let Ok(subnode_index) = u16::from_str(connection) {
+            let ipc_path = self
+                .settings
+                .resolve_ipc_path(self.settings.ipc_path.clone(), subnode_index);
+            self.set_ipc_path(ipc_path);
+            self.set_network_address(None);
+        } else if let Some(ipc_path) = self
+            .settings
+            .resolve_ipc_path(Some(connection.to_owned().into()), 0)
+        {
+            self.set_ipc_path(Some(ipc_path));
+            self.set_network_address(None);
+        } else if let Ok(Some(network_address)) = self
+            .settings
+            .resolve_network_address(Some(connection.to_owned()))
+        {
+            let Some(addr) = network_address.first() else {
+                return Err(format!("Invalid network address: {}", connection));
+            };
+            self.set_network_address(Some(*addr));
+            self.set_ipc_path(None);
+        } else {
+            return Err(format!("Invalid connection string: {}", connection));
+        }
+        Ok(())
+    }
     // called by client_api_connection
     // calls into ui
     ////////////////////////////////////////////
@@ -527,6 +783,9 @@ Core Debug Commands:
                 .cloned()
                 .collect::<Vec<json::JsonValue>>(),
         );
+        if self.inner().connections_view_active {
+            self.refresh_connections();
+        }
     }
     pub fn update_config(&self, config: &json::JsonValue) {
         self.inner_mut().ui_sender.set_config(&config["config"])
@@ -548,6 +807,9 @@ Core Debug Commands:
         if !out.is_empty() {
             self.inner().ui_sender.add_node_event(Level::Info, &out);
         }
+        if self.inner().routes_view_active {
+            self.refresh_routes();
+        }
     }
     pub fn update_value_change(&self, value_change: &json::JsonValue) {
         let data = json_str_vec_u8(&value_change["value"]["data"]);
@@ -564,6 +826,32 @@ Core Debug Commands:
             if truncated { "..." } else { "" }
         );
         self.inner().ui_sender.add_node_event(Level::Info, &out);
+
+        // Keep the record browser current
+        let key = value_change["key"].to_string();
+        let known = {
+            let mut inner = self.inner_mut();
+            if let Some(item) = inner.dht_records.iter_mut().find(|r| r.key == key) {
+                item.last_change = Some(value_change["subkeys"].clone());
+                true
+            } else {
+                false
+            }
+        };
+        if known {
+            let capi = self.capi();
+            let this = self.clone();
+            spawn_detached_local("dht record value change", async move {
+                let rc_id = match this.dht_browser_routing_context(&capi).await {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error!("Failed to get routing context: {}", e);
+                        return;
+                    }
+                };
+                this.reinspect_dht_record(&capi, rc_id, &key).await;
+            });
+        }
     }
 
     pub fn update_log(&self, log: &json::JsonValue) {
@@ -627,6 +915,25 @@ Core Debug Commands:
         );
     }
 
+    pub fn update_topic_message(&self, msg: &json::JsonValue) {
+        if !self.inner.lock().enable_app_messages {
+            return;
+        }
+
+        let message = json_str_vec_u8(&msg["message"]);
+        let (strmsg, truncated) = Self::print_json_str_vec_u8(&message);
+
+        self.inner().ui_sender.add_node_event(
+            Level::Info,
+            &format!(
+                "TopicMessage ({}): {}{}",
+                msg["topic"],
+                strmsg,
+                if truncated { "..." } else { "" }
+            ),
+        );
+    }
+
     pub fn update_app_call(&self, call: &json::JsonValue) {
         if !self.inner.lock().enable_app_messages {
             return;
@@ -678,6 +985,12 @@ Core Debug Commands:
     // calls into ui
     ////////////////////////////////////////////
     pub fn set_connection_state(&self, state: ConnectionState) {
+        if !state.is_connected() {
+            // Routing contexts do not survive the connection
+            let mut inner = self.inner_mut();
+            inner.dht_browser_rc_id = None;
+            inner.dht_browser_opened.clear();
+        }
         self.inner_mut().ui_sender.set_connection_state(state);
     }
     // called by ui
@@ -698,6 +1011,7 @@ Core Debug Commands:
         drop(self.inner_mut().connection_waker.resolve());
     }
     pub fn quit(&self) {
+        self.fleet.shutdown();
         self.inner_mut().finished = true;
         self.inner_mut().reconnect = false;
         drop(self.inner_mut().connection_waker.resolve());
@@ -725,4 +1039,495 @@ Core Debug Commands:
             }
         });
     }
+
+    // called by ui
+    // dht record browser
+    ////////////////////////////////////////////
+    async fn dht_browser_routing_context(&self, capi: &ClientApiConnection) -> Result<u32, String> {
+        if let Some(rc_id) = self.inner().dht_browser_rc_id {
+            return Ok(rc_id);
+        }
+        let rc_id = capi.server_new_routing_context().await?;
+        self.inner_mut().dht_browser_rc_id = Some(rc_id);
+        Ok(rc_id)
+    }
+
+    async fn inspect_dht_record_item(
+        &self,
+        capi: &ClientApiConnection,
+        rc_id: u32,
+        item: &mut DHTRecordItem,
+    ) {
+        // Only opened records can be inspected
+        if !item.is_opened() {
+            item.report = None;
+            return;
+        }
+        match capi
+            .server_inspect_dht_record(rc_id, item.key.clone(), "Local")
+            .await
+        {
+            Ok(report) => {
+                item.report = Some(report);
+            }
+            Err(e) => {
+                debug!("Failed to inspect DHT record {}: {}", item.key, e);
+                item.report = None;
+            }
+        }
+    }
+
+    async fn reinspect_dht_record(&self, capi: &ClientApiConnection, rc_id: u32, key: &str) {
+        let item = self
+            .inner()
+            .dht_records
+            .iter()
+            .find(|r| r.key == key)
+            .cloned();
+        let Some(mut item) = item else {
+            return;
+        };
+        self.inspect_dht_record_item(capi, rc_id, &mut item).await;
+        {
+            let mut inner = self.inner_mut();
+            if let Some(r) = inner.dht_records.iter_mut().find(|r| r.key == key) {
+                *r = item;
+            }
+        }
+        self.publish_dht_records();
+    }
+
+    fn publish_dht_records(&self) {
+        let mut inner = self.inner_mut();
+        let records = inner.dht_records.clone();
+        inner.ui_sender.set_dht_records(records);
+    }
+
+    pub fn refresh_dht_records(&self) {
+        trace!("CommandProcessor::refresh_dht_records");
+        let capi = self.capi();
+        let this = self.clone();
+        spawn_detached_local("refresh dht records", async move {
+            let rc_id = match this.dht_browser_routing_context(&capi).await {
+                Ok(v) => v,
+                Err(e) => {
+                    error!("Failed to get routing context: {}", e);
+                    return;
+                }
+            };
+
+            let mut records: Vec<DHTRecordItem> = Vec::new();
+            for scope in [
+                DHTRecordScope::Opened,
+                DHTRecordScope::Local,
+                DHTRecordScope::Remote,
+            ] {
+                let out = match capi
+                    .server_debug(format!("record list {}", scope.as_str()))
+                    .await
+                {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error!(
+                            "Server command 'debug record list {}' failed: {}",
+                            scope.as_str(),
+                            e
+                        );
+                        return;
+                    }
+                };
+                for item in parse_dht_record_list(scope, &out) {
+                    // Opened records are also in the local record store
+                    if let Some(existing) = records.iter_mut().find(|r| r.key == item.key) {
+                        if existing.stored_subkeys.is_none() {
+                            existing.stored_subkeys = item.stored_subkeys;
+                        }
+                        continue;
+                    }
+                    records.push(item);
+                }
+            }
+
+            // Keep what we already knew about records we have seen before
+            {
+                let inner = this.inner();
+                for item in &mut records {
+                    if let Some(prev) = inner.dht_records.iter().find(|r| r.key == item.key) {
+                        item.descriptor = prev.descriptor.clone();
+                        item.last_change = prev.last_change.clone();
+                    }
+                }
+            }
+
+            for item in &mut records {
+                this.inspect_dht_record_item(&capi, rc_id, item).await;
+            }
+
+            this.inner_mut().dht_records = records;
+            this.publish_dht_records();
+        });
+    }
+
+    pub fn open_dht_record(&self, key: String, writer: Option<String>, callback: UICallback) {
+        trace!("CommandProcessor::open_dht_record");
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        let this = self.clone();
+        spawn_detached_local("open dht record", async move {
+            let rc_id = match this.dht_browser_routing_context(&capi).await {
+                Ok(v) => v,
+                Err(e) => {
+                    ui.add_node_event(
+                        Level::Error,
+                        &format!("Failed to get routing context: {}", e),
+                    );
+                    ui.send_callback(callback);
+                    return;
+                }
+            };
+
+            // Re-opening a record that another client has opened would replace its writer,
+            // so records opened elsewhere are only inspected
+            let opened_elsewhere = {
+                let inner = this.inner();
+                inner
+                    .dht_records
+                    .iter()
+                    .any(|r| r.key == key && r.is_opened())
+                    && !inner.dht_browser_opened.contains(&key)
+            };
+            if !opened_elsewhere {
+                match capi
+                    .server_open_dht_record(rc_id, key.clone(), writer.clone())
+                    .await
+                {
+                    Ok(descriptor) => {
+                        let mut inner = this.inner_mut();
+                        inner.dht_browser_opened.insert(key.clone());
+                        let pos = match inner.dht_records.iter().position(|r| r.key == key) {
+                            Some(v) => v,
+                            None => {
+                                inner
+                                    .dht_records
+                                    .push(DHTRecordItem::new(key.clone(), DHTRecordScope::Opened));
+                                inner.dht_records.len() - 1
+                            }
+                        };
+                        let item = &mut inner.dht_records[pos];
+                        item.scope = DHTRecordScope::Opened;
+                        item.writer = writer;
+                        item.descriptor = Some(descriptor);
+                    }
+                    Err(e) => {
+                        ui.add_node_event(
+                            Level::Error,
+                            &format!("Failed to open DHT record {}: {}", key, e),
+                        );
+                        ui.send_callback(callback);
+                        return;
+                    }
+                }
+            }
+
+            this.reinspect_dht_record(&capi, rc_id, &key).await;
+            ui.send_callback(callback);
+        });
+    }
+
+    pub fn close_dht_record(&self, key: String, callback: UICallback) {
+        trace!("CommandProcessor::close_dht_record");
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        let this = self.clone();
+        spawn_detached_local("close dht record", async move {
+            // Only close records the browser opened itself
+            let (rc_id, opened_here) = {
+                let inner = this.inner();
+                (
+                    inner.dht_browser_rc_id,
+                    inner.dht_browser_opened.contains(&key),
+                )
+            };
+            let Some(rc_id) = rc_id.filter(|_| opened_here) else {
+                ui.send_callback(callback);
+                return;
+            };
+            if let Err(e) = capi.server_close_dht_record(rc_id, key.clone()).await {
+                ui.add_node_event(
+                    Level::Error,
+                    &format!("Failed to close DHT record {}: {}", key, e),
+                );
+            }
+            {
+                let mut inner = this.inner_mut();
+                inner.dht_browser_opened.remove(&key);
+                if let Some(item) = inner.dht_records.iter_mut().find(|r| r.key == key) {
+                    item.scope = DHTRecordScope::Local;
+                    item.writer = None;
+                    item.report = None;
+                }
+            }
+            this.publish_dht_records();
+            ui.send_callback(callback);
+        });
+    }
+
+    pub fn get_dht_value(
+        &self,
+        key: String,
+        subkey: u32,
+        force_refresh: bool,
+        callback: UICallback,
+    ) {
+        trace!("CommandProcessor::get_dht_value");
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        let this = self.clone();
+        spawn_detached_local("get dht value", async move {
+            let rc_id = match this.dht_browser_routing_context(&capi).await {
+                Ok(v) => v,
+                Err(e) => {
+                    ui.display_string_dialog("Get DHT value failed", &e, callback);
+                    return;
+                }
+            };
+            match capi
+                .server_get_dht_value(rc_id, key.clone(), subkey, force_refresh)
+                .await
+            {
+                Ok(value) => {
+                    let text = if value.is_null() {
+                        "No value data returned".to_owned()
+                    } else {
+                        let data = json_str_vec_u8(&value["data"]);
+                        let (datastr, truncated) = Self::print_json_str_vec_u8(&data);
+                        format!(
+                            "key={}\nsubkey={}\nseq={}\nwriter={}\ndata={}{}",
+                            key,
+                            subkey,
+                            value["seq"].dump(),
+                            value["writer"],
+                            datastr,
+                            if truncated { "..." } else { "" }
+                        )
+                    };
+                    this.reinspect_dht_record(&capi, rc_id, &key).await;
+                    ui.display_string_dialog("DHT value", &text, callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Get DHT value failed", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn set_dht_value(
+        &self,
+        key: String,
+        subkey: u32,
+        data: Vec<u8>,
+        writer: Option<String>,
+        callback: UICallback,
+    ) {
+        trace!("CommandProcessor::set_dht_value");
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        let this = self.clone();
+        spawn_detached_local("set dht value", async move {
+            let rc_id = match this.dht_browser_routing_context(&capi).await {
+                Ok(v) => v,
+                Err(e) => {
+                    ui.display_string_dialog("Set DHT value failed", &e, callback);
+                    return;
+                }
+            };
+            match capi
+                .server_set_dht_value(rc_id, key.clone(), subkey, &data, writer)
+                .await
+            {
+                Ok(value) => {
+                    this.reinspect_dht_record(&capi, rc_id, &key).await;
+                    if value.is_null() {
+                        ui.add_node_event(
+                            Level::Info,
+                            &format!("DHT value set: key={} subkey={}", key, subkey),
+                        );
+                        ui.send_callback(callback);
+                    } else {
+                        let data = json_str_vec_u8(&value["data"]);
+                        let (datastr, truncated) = Self::print_json_str_vec_u8(&data);
+                        ui.display_string_dialog(
+                            "Newer value found",
+                            &format!(
+                                "seq={}\nwriter={}\ndata={}{}",
+                                value["seq"].dump(),
+                                value["writer"],
+                                datastr,
+                                if truncated { "..." } else { "" }
+                            ),
+                            callback,
+                        );
+                    }
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Set DHT value failed", &e, callback);
+                }
+            }
+        });
+    }
+
+    // called by ui
+    // route and connection inspectors
+    ////////////////////////////////////////////
+    pub fn set_routes_view_active(&self, active: bool) {
+        self.inner_mut().routes_view_active = active;
+    }
+
+    pub fn set_connections_view_active(&self, active: bool) {
+        self.inner_mut().connections_view_active = active;
+    }
+
+    async fn server_debug_json(
+        capi: &ClientApiConnection,
+        command: &str,
+    ) -> Result<json::JsonValue, String> {
+        let out = capi.server_debug(command.to_owned()).await?;
+        json::parse(&out).map_err(|e| format!("'{}' did not return json: {}: {}", command, e, out))
+    }
+
+    pub fn refresh_routes(&self) {
+        trace!("CommandProcessor::refresh_routes");
+        let capi = self.capi();
+        let this = self.clone();
+        spawn_detached_local("refresh routes", async move {
+            let list = match Self::server_debug_json(&capi, "route list json").await {
+                Ok(v) => v,
+                Err(e) => {
+                    error!("Failed to list routes: {}", e);
+                    return;
+                }
+            };
+            let routes = list["routes"]
+                .members()
+                .map(|r| {
+                    let mut r = r.clone();
+                    r["cur_ts"] = list["cur_ts"].clone();
+                    r
+                })
+                .collect::<Vec<_>>();
+            this.inner_mut().ui_sender.set_routes(routes);
+        });
+    }
+
+    pub fn refresh_connections(&self) {
+        trace!("CommandProcessor::refresh_connections");
+        let capi = self.capi();
+        let this = self.clone();
+        spawn_detached_local("refresh connections", async move {
+            let table = match Self::server_debug_json(&capi, "network connections").await {
+                Ok(v) => v,
+                Err(e) => {
+                    error!("Failed to list connections: {}", e);
+                    return;
+                }
+            };
+            let mut connections = Vec::new();
+            for protocol in table["protocols"].members() {
+                for c in protocol["connections"].members() {
+                    let mut c = c.clone();
+                    c["protocol"] = protocol["protocol"].clone();
+                    c["cur_ts"] = table["cur_ts"].clone();
+                    connections.push(c);
+                }
+            }
+            this.inner_mut().ui_sender.set_connections(connections);
+        });
+    }
+
+    pub fn inspect_route(&self, route_id: String, callback: UICallback) {
+        trace!("CommandProcessor::inspect_route");
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        spawn_detached_local("inspect route", async move {
+            match capi.server_debug(format!("route print {}", route_id)).await {
+                Ok(output) => {
+                    ui.display_string_dialog(&format!("Route {}", route_id), &output, callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Route print failed", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn test_route(&self, route_id: String, callback: UICallback) {
+        trace!("CommandProcessor::test_route");
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        let this = self.clone();
+        spawn_detached_local("test route", async move {
+            let res = capi.server_debug(format!("route test {}", route_id)).await;
+            this.refresh_routes();
+            match res {
+                Ok(output) => {
+                    ui.display_string_dialog("Route test", &output, callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Route test failed", &e, callback);
+                }
+            }
+        });
+    }
+
+    // called by ui
+    // node fleet
+    ////////////////////////////////////////////
+    fn format_fleet(&self) -> String {
+        let nodes = self.fleet.summaries();
+        let totals = FleetTotals::new(&nodes);
+        let mut out = format!(
+            "Nodes: {} (down {} up {})\n",
+            totals,
+            format_bps(&totals.bps_down.to_string().into()),
+            format_bps(&totals.bps_up.to_string().into())
+        );
+        for n in &nodes {
+            out += &format!(
+                "  {} [{}] {} {} peers={} node_id={}\n",
+                n.name,
+                n.target,
+                n.connection_state_str(),
+                n.attachment_state,
+                n.peer_count,
+                n.node_id
+            );
+        }
+        for (group, members) in self.fleet.groups() {
+            out += &format!("  group {}: {}\n", group, members.join(" "));
+        }
+        out
+    }
+
+    pub fn fleet_totals(&self) -> FleetTotals {
+        FleetTotals::new(&self.fleet.summaries())
+    }
+
+    pub fn remove_node(&self, name: String) {
+        if let Err(e) = self.fleet.remove_node(&name) {
+            self.inner().ui_sender.add_node_event(Level::Error, &e);
+        }
+    }
+
+    /// Point the main connection at one of the fleet nodes
+    pub fn focus_node(&self, name: String, callback: UICallback) {
+        let Some(target) = self.fleet.node_target(&name) else {
+            let ui = self.ui_sender();
+            ui.add_node_event(Level::Error, &format!("Unknown node: {}", name));
+            ui.send_callback(callback);
+            return;
+        };
+        if let Err(e) = self.cmd_connect(Some(target), callback) {
+            error!("Failed to focus node {}: {}", name, e);
+        }
+    }
 }
@@ -0,0 +1,78 @@
+use super::*;
+use cursive_table_view::*;
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ConnectionTableColumn {
+    Protocol,
+    ConnectionId,
+    Flow,
+    Established,
+    LastSent,
+    LastRecv,
+    RefCount,
+    Flags,
+}
+
+fn format_flags(conn: &json::JsonValue) -> String {
+    let mut flags = Vec::new();
+    if !conn["protected"].is_null() {
+        flags.push("protected");
+    }
+    if conn["priority_flow"].as_bool().unwrap_or_default() {
+        flags.push("priority");
+    }
+    if !conn["dial_info"].is_null() {
+        flags.push("outbound");
+    }
+    flags.join(",")
+}
+
+impl TableViewItem<ConnectionTableColumn> for json::JsonValue {
+    fn to_column(&self, column: ConnectionTableColumn) -> String {
+        match column {
+            ConnectionTableColumn::Protocol => self["protocol"].to_string(),
+            ConnectionTableColumn::ConnectionId => self["connection_id"].to_string(),
+            ConnectionTableColumn::Flow => self["flow"].to_string(),
+            ConnectionTableColumn::Established => {
+                format_age(&self["cur_ts"], &self["established_ts"])
+            }
+            ConnectionTableColumn::LastSent => {
+                format_age(&self["cur_ts"], &self["last_message_sent_ts"])
+            }
+            ConnectionTableColumn::LastRecv => {
+                format_age(&self["cur_ts"], &self["last_message_recv_ts"])
+            }
+            ConnectionTableColumn::RefCount => self["ref_count"].to_string(),
+            ConnectionTableColumn::Flags => format_flags(self),
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: ConnectionTableColumn) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            ConnectionTableColumn::ConnectionId => self["connection_id"]
+                .as_u64()
+                .cmp(&other["connection_id"].as_u64()),
+            // Older timestamps are longer ages, so sort them in reverse
+            ConnectionTableColumn::Established => {
+                json_str_u64(&other["established_ts"]).cmp(&json_str_u64(&self["established_ts"]))
+            }
+            ConnectionTableColumn::LastSent => json_str_u64(&other["last_message_sent_ts"])
+                .cmp(&json_str_u64(&self["last_message_sent_ts"])),
+            ConnectionTableColumn::LastRecv => json_str_u64(&other["last_message_recv_ts"])
+                .cmp(&json_str_u64(&self["last_message_recv_ts"])),
+            ConnectionTableColumn::RefCount => {
+                self["ref_count"].as_u64().cmp(&other["ref_count"].as_u64())
+            }
+            _ => self
+                .to_column(column)
+                .to_ascii_lowercase()
+                .cmp(&other.to_column(column).to_ascii_lowercase()),
+        }
+    }
+}
+
+pub type ConnectionsTableView = TableView<json::JsonValue, ConnectionTableColumn>;
@@ -1,5 +1,10 @@
 use crate::command_processor::*;
+use crate::connections_table_view::*;
+use crate::dht_records_table_view::*;
+use crate::fleet_nodes_table_view::*;
+use crate::node_fleet::*;
 use crate::peers_table_view::*;
+use crate::routes_table_view::*;
 use crate::settings::Settings;
 use crate::tools::*;
 use crate::ui::*;
@@ -73,6 +78,10 @@ struct UIState {
     connection_state: Dirty<ConnectionState>,
     peers_state: Dirty<Vec<json::JsonValue>>,
     node_id: Dirty<String>,
+    dht_records: Dirty<Vec<DHTRecordItem>>,
+    routes: Dirty<Vec<json::JsonValue>>,
+    connections: Dirty<Vec<json::JsonValue>>,
+    fleet_nodes: Dirty<Vec<FleetNodeSummary>>,
 }
 
 impl UIState {
@@ -86,6 +95,10 @@ impl UIState {
             connection_state: Dirty::new(ConnectionState::Disconnected),
             peers_state: Dirty::new(Vec::new()),
             node_id: Dirty::new("".to_owned()),
+            dht_records: Dirty::new(Vec::new()),
+            routes: Dirty::new(Vec::new()),
+            connections: Dirty::new(Vec::new()),
+            fleet_nodes: Dirty::new(Vec::new()),
         }
     }
 }
@@ -102,6 +115,7 @@ pub struct CursiveUIInner {
     cmd_history_position: usize,
     cmd_history_max_size: usize,
     connection_dialog_state: Option<ConnectionState>,
+    dht_record_editor_key: Option<String>,
 }
 
 pub struct CursiveUI {
@@ -269,6 +283,18 @@ impl CursiveUI {
     fn button_attach(s: &mut Cursive) -> ViewRef<Button> {
         s.find_name("button-attach").unwrap()
     }
+    fn button_records(s: &mut Cursive) -> ViewRef<Button> {
+        s.find_name("button-records").unwrap()
+    }
+    fn button_routes(s: &mut Cursive) -> ViewRef<Button> {
+        s.find_name("button-routes").unwrap()
+    }
+    fn button_connections(s: &mut Cursive) -> ViewRef<Button> {
+        s.find_name("button-connections").unwrap()
+    }
+    fn button_nodes(s: &mut Cursive) -> ViewRef<Button> {
+        s.find_name("button-nodes").unwrap()
+    }
     fn status_bar(s: &mut Cursive) -> ViewRef<TextView> {
         s.find_name("status-bar").unwrap()
     }
@@ -293,6 +319,33 @@ impl CursiveUI {
     fn connection_dialog(s: &mut Cursive) -> ViewRef<Dialog> {
         s.find_name("connection-dialog").unwrap()
     }
+    fn dht_records(s: &mut Cursive) -> Option<ViewRef<DHTRecordsTableView>> {
+        s.find_name("dht-records")
+    }
+    fn dht_record_details(s: &mut Cursive) -> Option<ViewRef<TextView>> {
+        s.find_name("dht-record-details")
+    }
+    fn dht_record_writer(s: &mut Cursive) -> ViewRef<EditView> {
+        s.find_name("dht-record-writer").unwrap()
+    }
+    fn dht_record_subkey(s: &mut Cursive) -> ViewRef<EditView> {
+        s.find_name("dht-record-subkey").unwrap()
+    }
+    fn dht_record_data(s: &mut Cursive) -> ViewRef<EditView> {
+        s.find_name("dht-record-data").unwrap()
+    }
+    fn routes(s: &mut Cursive) -> Option<ViewRef<RoutesTableView>> {
+        s.find_name("routes")
+    }
+    fn connections(s: &mut Cursive) -> Option<ViewRef<ConnectionsTableView>> {
+        s.find_name("connections")
+    }
+    fn fleet_nodes(s: &mut Cursive) -> Option<ViewRef<FleetNodesTableView>> {
+        s.find_name("fleet-nodes")
+    }
+    fn fleet_dialog(s: &mut Cursive) -> Option<ViewRef<Dialog>> {
+        s.find_name("fleet-dialog")
+    }
     ////////////////////////////////////////////////////////////////////////////////////////////////
 
     fn push_styled_line(s: &mut Cursive, styled_string: StyledString) {
@@ -325,6 +378,7 @@ impl CursiveUI {
             "FullyAttached" => "[||||]",
             "OverAttached" => "[++++]",
             "Detaching" => "[////]",
+            "Resuming" => "[>   ]",
             _ => "[????]",
         };
         let pi = if *inner.ui_state.public_internet_ready.get() {
@@ -359,6 +413,7 @@ impl CursiveUI {
                 "FullyAttached" => ("Detach", true),
                 "OverAttached" => ("Detach", true),
                 "Detaching" => ("Detach", false),
+                "Resuming" => ("Detach", true),
                 _ => ("???", false),
             }
         } else {
@@ -378,6 +433,10 @@ impl CursiveUI {
     fn enable_command_ui(s: &mut Cursive, enabled: bool) {
         Self::command_line(s).set_enabled(enabled);
         Self::button_attach(s).set_enabled(enabled);
+        Self::button_records(s).set_enabled(enabled);
+        Self::button_routes(s).set_enabled(enabled);
+        Self::button_connections(s).set_enabled(enabled);
+        Self::button_nodes(s).set_enabled(enabled);
     }
 
     fn display_string_dialog_cb(
@@ -516,6 +575,7 @@ impl CursiveUI {
             "FullyAttached" => Some(false),
             "OverAttached" => Some(false),
             "Detaching" => None,
+            "Resuming" => Some(false),
             _ => None,
         };
         let cmdproc = Self::command_processor(s);
@@ -652,6 +712,576 @@ impl CursiveUI {
         EventResult::Ignored
     }
 
+    fn on_button_records_pressed(s: &mut Cursive) {
+        if Self::dht_records(s).is_some() {
+            return;
+        }
+
+        let mut dht_records_table_view = DHTRecordsTableView::new()
+            .column(DHTRecordTableColumn::Key, "Key", |c| c.width(58))
+            .column(DHTRecordTableColumn::Scope, "Scope", |c| c.width(8))
+            .column(DHTRecordTableColumn::Schema, "Schema", |c| c.width(32))
+            .column(DHTRecordTableColumn::Owner, "Owner", |c| c.width(44))
+            .column(DHTRecordTableColumn::Subkeys, "Stored", |c| c.width(12))
+            .column(DHTRecordTableColumn::Seqs, "Seqs", |c| c);
+        dht_records_table_view.set_on_submit(CursiveUI::on_submit_dht_records_table_view);
+        dht_records_table_view.set_items(Self::inner(s).ui_state.dht_records.get().clone());
+
+        s.add_fullscreen_layer(
+            Dialog::around(
+                dht_records_table_view
+                    .with_name("dht-records")
+                    .full_screen(),
+            )
+            .title("DHT Records")
+            .button("Refresh", |s| {
+                Self::command_processor(s).refresh_dht_records();
+            })
+            .button("Close", |s| {
+                s.pop_layer();
+            }),
+        );
+
+        Self::command_processor(s).refresh_dht_records();
+    }
+
+    fn on_submit_dht_records_table_view(
+        s: &mut Cursive,
+        _row: Option<usize>,
+        index: Option<usize>,
+    ) {
+        let Some(index) = index else {
+            return;
+        };
+        let Some(dht_records_table_view) = CursiveUI::dht_records(s) else {
+            return;
+        };
+        let key = dht_records_table_view
+            .borrow_item(index)
+            .map(|item| item.key.clone());
+        drop(dht_records_table_view);
+        if let Some(key) = key {
+            Self::show_dht_record_editor(s, key);
+        }
+    }
+
+    fn dht_record_editor_field<V: View>(label: &str, view: V) -> LinearLayout {
+        LinearLayout::horizontal()
+            .child(TextView::new(label).fixed_width(9))
+            .child(view)
+    }
+
+    fn show_dht_record_editor(s: &mut Cursive, key: String) {
+        Self::inner_mut(s).dht_record_editor_key = Some(key);
+
+        s.add_layer(
+            Dialog::around(
+                LinearLayout::vertical()
+                    .child(
+                        TextView::new("")
+                            .with_name("dht-record-details")
+                            .scrollable()
+                            .min_height(8)
+                            .max_height(20),
+                    )
+                    .child(TextView::new(" "))
+                    .child(Self::dht_record_editor_field(
+                        "Writer:",
+                        EditView::new()
+                            .with_name("dht-record-writer")
+                            .fixed_height(1)
+                            .min_width(60),
+                    ))
+                    .child(Self::dht_record_editor_field(
+                        "Subkey:",
+                        EditView::new()
+                            .content("0")
+                            .with_name("dht-record-subkey")
+                            .fixed_height(1)
+                            .min_width(60),
+                    ))
+                    .child(Self::dht_record_editor_field(
+                        "Data:",
+                        EditView::new()
+                            .on_submit(|s, _| Self::submit_dht_record_set(s))
+                            .with_name("dht-record-data")
+                            .fixed_height(1)
+                            .min_width(60),
+                    )),
+            )
+            .title("DHT Record")
+            .button("Open", Self::submit_dht_record_open)
+            .button("Get", Self::submit_dht_record_get)
+            .button("Set", Self::submit_dht_record_set)
+            .button("Close Record", Self::submit_dht_record_close)
+            .button("Back", |s| {
+                Self::inner_mut(s).dht_record_editor_key = None;
+                s.pop_layer();
+            }),
+        );
+
+        Self::refresh_dht_record_editor(s);
+    }
+
+    fn dht_record_editor_writer(s: &mut Cursive) -> Option<String> {
+        let writer = Self::dht_record_writer(s).get_content().trim().to_owned();
+        if writer.is_empty() {
+            None
+        } else {
+            Some(writer)
+        }
+    }
+
+    fn dht_record_editor_subkey(s: &mut Cursive) -> Option<u32> {
+        let subkey = Self::dht_record_subkey(s).get_content().trim().to_owned();
+        match subkey.parse::<u32>() {
+            Ok(v) => Some(v),
+            Err(_) => {
+                s.add_layer(Dialog::text("Invalid subkey").button("Close", |s| {
+                    s.pop_layer();
+                }));
+                None
+            }
+        }
+    }
+
+    fn dht_record_editor_callback() -> UICallback {
+        UICallback::Cursive(Box::new(CursiveUI::refresh_dht_record_editor))
+    }
+
+    fn submit_dht_record_open(s: &mut Cursive) {
+        let Some(key) = Self::inner(s).dht_record_editor_key.clone() else {
+            return;
+        };
+        let writer = Self::dht_record_editor_writer(s);
+        Self::command_processor(s).open_dht_record(key, writer, Self::dht_record_editor_callback());
+    }
+
+    fn submit_dht_record_get(s: &mut Cursive) {
+        let Some(key) = Self::inner(s).dht_record_editor_key.clone() else {
+            return;
+        };
+        let Some(subkey) = Self::dht_record_editor_subkey(s) else {
+            return;
+        };
+        Self::command_processor(s).get_dht_value(
+            key,
+            subkey,
+            false,
+            Self::dht_record_editor_callback(),
+        );
+    }
+
+    fn submit_dht_record_set(s: &mut Cursive) {
+        let Some(key) = Self::inner(s).dht_record_editor_key.clone() else {
+            return;
+        };
+        let Some(subkey) = Self::dht_record_editor_subkey(s) else {
+            return;
+        };
+        let Some(data) = parse_data(&Self::dht_record_data(s).get_content()) else {
+            s.add_layer(Dialog::text("Invalid data").button("Close", |s| {
+                s.pop_layer();
+            }));
+            return;
+        };
+        let writer = Self::dht_record_editor_writer(s);
+        Self::command_processor(s).set_dht_value(
+            key,
+            subkey,
+            data,
+            writer,
+            Self::dht_record_editor_callback(),
+        );
+    }
+
+    fn submit_dht_record_close(s: &mut Cursive) {
+        let Some(key) = Self::inner(s).dht_record_editor_key.clone() else {
+            return;
+        };
+        Self::command_processor(s).close_dht_record(key, Self::dht_record_editor_callback());
+    }
+
+    fn render_dht_record_details(item: &DHTRecordItem) -> String {
+        let mut out = format!("Key:     {}\nScope:   {}\n", item.key, item.scope.as_str());
+        if let Some(descriptor) = &item.descriptor {
+            out += &format!(
+                "Owner:   {}\nSchema:  {}\n",
+                descriptor["owner"],
+                format_schema(descriptor)
+            );
+        }
+        if let Some(writer) = &item.writer {
+            out += &format!("Writer:  {}\n", writer);
+        }
+        if let Some(stored_subkeys) = &item.stored_subkeys {
+            out += &format!("Stored:  {}\n", stored_subkeys);
+        }
+        match &item.report {
+            Some(report) => {
+                let offline = format_subkey_range_set(&report["offline_subkeys"]);
+                if !offline.is_empty() {
+                    out += &format!("Offline: {}\n", offline);
+                }
+                out += "Subkey sequence numbers:\n";
+                for (subkey, seq) in report_subkey_seqs(report) {
+                    match seq {
+                        Some(seq) => out += &format!("  {}: {}\n", subkey, seq),
+                        None => out += &format!("  {}: ---\n", subkey),
+                    }
+                }
+            }
+            None => {
+                out += "Open the record to inspect its subkeys\n";
+            }
+        }
+        if let Some(last_change) = &item.last_change {
+            out += &format!("Last change: {}\n", format_subkey_range_set(last_change));
+        }
+        out
+    }
+
+    fn refresh_dht_record_editor(s: &mut Cursive) {
+        let Some(mut details) = Self::dht_record_details(s) else {
+            return;
+        };
+        let inner = Self::inner(s);
+        let Some(key) = inner.dht_record_editor_key.as_ref() else {
+            return;
+        };
+        let text = match inner
+            .ui_state
+            .dht_records
+            .get()
+            .iter()
+            .find(|r| &r.key == key)
+        {
+            Some(item) => Self::render_dht_record_details(item),
+            None => format!("Key:     {}\nRecord is no longer listed\n", key),
+        };
+        details.set_content(text);
+    }
+
+    fn refresh_dht_records(s: &mut Cursive) {
+        if let Some(mut dht_records) = CursiveUI::dht_records(s) {
+            let inner = Self::inner_mut(s);
+            let sel_item = dht_records.item();
+            let sel_key = dht_records
+                .item()
+                .and_then(|x| dht_records.borrow_item(x).map(|item| item.key.clone()));
+
+            dht_records.set_items_stable(inner.ui_state.dht_records.get().clone());
+
+            let mut selected = false;
+            if let Some(sel_key) = sel_key {
+                for n in 0..dht_records.borrow_items().len() {
+                    if dht_records.borrow_items()[n].key == sel_key {
+                        dht_records.set_selected_item(n);
+                        selected = true;
+                    }
+                }
+            }
+            if !selected {
+                if let Some(sel_item) = sel_item {
+                    dht_records.set_selected_item(sel_item);
+                }
+            }
+        }
+        Self::refresh_dht_record_editor(s);
+    }
+
+    fn on_button_routes_pressed(s: &mut Cursive) {
+        if Self::routes(s).is_some() {
+            return;
+        }
+
+        let mut routes_table_view = RoutesTableView::new()
+            .column(RouteTableColumn::RouteId, "Route Id", |c| c.width(45))
+            .column(RouteTableColumn::Kind, "Kind", |c| c.width(10))
+            .column(RouteTableColumn::Hops, "Hops", |c| c.width(5))
+            .column(RouteTableColumn::LatencyAvg, "Latency", |c| c.width(10))
+            .column(RouteTableColumn::LostAnswers, "Lost", |c| c.width(8))
+            .column(RouteTableColumn::LastValid, "Last Valid", |c| c.width(12))
+            .column(RouteTableColumn::TransferDownAvg, "Down", |c| c.width(10))
+            .column(RouteTableColumn::TransferUpAvg, "Up", |c| c.width(10));
+        routes_table_view.set_on_submit(CursiveUI::on_submit_routes_table_view);
+        routes_table_view.set_items(Self::inner(s).ui_state.routes.get().clone());
+
+        s.add_fullscreen_layer(
+            Dialog::around(routes_table_view.with_name("routes").full_screen())
+                .title("Routes")
+                .button("Refresh", |s| {
+                    Self::command_processor(s).refresh_routes();
+                })
+                .button("Test", Self::on_routes_test_pressed)
+                .button("Close", |s| {
+                    Self::command_processor(s).set_routes_view_active(false);
+                    s.pop_layer();
+                }),
+        );
+
+        let cmdproc = Self::command_processor(s);
+        cmdproc.set_routes_view_active(true);
+        cmdproc.refresh_routes();
+    }
+
+    fn selected_route_id(s: &mut Cursive) -> Option<String> {
+        let routes = Self::routes(s)?;
+        let index = routes.item()?;
+        routes.borrow_item(index).map(|r| r["route_id"].to_string())
+    }
+
+    fn on_submit_routes_table_view(s: &mut Cursive, _row: Option<usize>, _index: Option<usize>) {
+        let Some(route_id) = Self::selected_route_id(s) else {
+            return;
+        };
+        Self::command_processor(s).inspect_route(
+            route_id,
+            UICallback::Cursive(Box::new(|_: &mut Cursive| {})),
+        );
+    }
+
+    fn on_routes_test_pressed(s: &mut Cursive) {
+        let Some(route_id) = Self::selected_route_id(s) else {
+            return;
+        };
+        Self::command_processor(s).test_route(
+            route_id,
+            UICallback::Cursive(Box::new(|_: &mut Cursive| {})),
+        );
+    }
+
+    fn on_button_connections_pressed(s: &mut Cursive) {
+        if Self::connections(s).is_some() {
+            return;
+        }
+
+        let mut connections_table_view = ConnectionsTableView::new()
+            .column(ConnectionTableColumn::Protocol, "Proto", |c| c.width(6))
+            .column(ConnectionTableColumn::ConnectionId, "Id", |c| c.width(6))
+            .column(ConnectionTableColumn::Flow, "Flow", |c| c.width(56))
+            .column(ConnectionTableColumn::Established, "Age", |c| c.width(10))
+            .column(ConnectionTableColumn::LastSent, "Last Sent", |c| {
+                c.width(10)
+            })
+            .column(ConnectionTableColumn::LastRecv, "Last Rcvd", |c| {
+                c.width(10)
+            })
+            .column(ConnectionTableColumn::RefCount, "Refs", |c| c.width(5))
+            .column(ConnectionTableColumn::Flags, "Flags", |c| c);
+        connections_table_view.set_on_submit(CursiveUI::on_submit_connections_table_view);
+        connections_table_view.set_items(Self::inner(s).ui_state.connections.get().clone());
+
+        s.add_fullscreen_layer(
+            Dialog::around(
+                connections_table_view
+                    .with_name("connections")
+                    .full_screen(),
+            )
+            .title("Connections")
+            .button("Refresh", |s| {
+                Self::command_processor(s).refresh_connections();
+            })
+            .button("Close", |s| {
+                Self::command_processor(s).set_connections_view_active(false);
+                s.pop_layer();
+            }),
+        );
+
+        let cmdproc = Self::command_processor(s);
+        cmdproc.set_connections_view_active(true);
+        cmdproc.refresh_connections();
+    }
+
+    fn on_submit_connections_table_view(
+        s: &mut Cursive,
+        _row: Option<usize>,
+        index: Option<usize>,
+    ) {
+        let Some(index) = index else {
+            return;
+        };
+        let Some(connections) = Self::connections(s) else {
+            return;
+        };
+        let Some(conn) = connections.borrow_item(index).cloned() else {
+            return;
+        };
+        drop(connections);
+
+        let text = format!(
+            "Protocol:    {}\nId:          {}\nFlow:        {}\nDial Info:   {}\nEstablished: {} ago\nLast Sent:   {} ago\nLast Rcvd:   {} ago\nRef Count:   {}\nProtected:   {}\nPriority:    {}\n",
+            conn["protocol"],
+            conn["connection_id"],
+            conn["flow"],
+            conn["dial_info"],
+            format_age(&conn["cur_ts"], &conn["established_ts"]),
+            format_age(&conn["cur_ts"], &conn["last_message_sent_ts"]),
+            format_age(&conn["cur_ts"], &conn["last_message_recv_ts"]),
+            conn["ref_count"],
+            conn["protected"],
+            conn["priority_flow"],
+        );
+        Self::display_string_dialog_cb(s, "Connection".to_owned(), text, None);
+    }
+
+    fn refresh_routes(s: &mut Cursive) {
+        let Some(mut routes) = CursiveUI::routes(s) else {
+            return;
+        };
+        let inner = Self::inner_mut(s);
+        let sel_item = routes.item();
+        let sel_route_id = routes
+            .item()
+            .and_then(|x| routes.borrow_item(x).map(|r| r["route_id"].clone()));
+
+        routes.set_items_stable(inner.ui_state.routes.get().clone());
+
+        let mut selected = false;
+        if let Some(sel_route_id) = sel_route_id {
+            for n in 0..routes.borrow_items().len() {
+                if routes.borrow_items()[n]["route_id"] == sel_route_id {
+                    routes.set_selected_item(n);
+                    selected = true;
+                }
+            }
+        }
+        if !selected {
+            if let Some(sel_item) = sel_item {
+                routes.set_selected_item(sel_item);
+            }
+        }
+    }
+
+    fn refresh_connections(s: &mut Cursive) {
+        let Some(mut connections) = CursiveUI::connections(s) else {
+            return;
+        };
+        let inner = Self::inner_mut(s);
+        let sel_item = connections.item();
+        let sel_flow = connections
+            .item()
+            .and_then(|x| connections.borrow_item(x).map(|c| c["flow"].clone()));
+
+        connections.set_items_stable(inner.ui_state.connections.get().clone());
+
+        let mut selected = false;
+        if let Some(sel_flow) = sel_flow {
+            for n in 0..connections.borrow_items().len() {
+                if connections.borrow_items()[n]["flow"] == sel_flow {
+                    connections.set_selected_item(n);
+                    selected = true;
+                }
+            }
+        }
+        if !selected {
+            if let Some(sel_item) = sel_item {
+                connections.set_selected_item(sel_item);
+            }
+        }
+    }
+
+    fn fleet_dialog_title(s: &mut Cursive) -> String {
+        let totals = FleetTotals::new(Self::inner(s).ui_state.fleet_nodes.get());
+        format!("Nodes: {}", totals)
+    }
+
+    fn on_button_nodes_pressed(s: &mut Cursive) {
+        if Self::fleet_nodes(s).is_some() {
+            return;
+        }
+
+        let mut fleet_nodes_table_view = FleetNodesTableView::new()
+            .column(FleetNodeTableColumn::Name, "Name", |c| c.width(16))
+            .column(FleetNodeTableColumn::Target, "Connection", |c| c.width(32))
+            .column(FleetNodeTableColumn::Connection, "State", |c| c.width(13))
+            .column(FleetNodeTableColumn::Attachment, "Attachment", |c| {
+                c.width(15)
+            })
+            .column(FleetNodeTableColumn::Peers, "Peers", |c| c.width(6))
+            .column(FleetNodeTableColumn::TransferDown, "Down", |c| c.width(10))
+            .column(FleetNodeTableColumn::TransferUp, "Up", |c| c.width(10))
+            .column(FleetNodeTableColumn::Groups, "Groups", |c| c);
+        fleet_nodes_table_view.set_on_submit(|s, _row, _index| Self::on_fleet_focus_pressed(s));
+        fleet_nodes_table_view.set_items(Self::inner(s).ui_state.fleet_nodes.get().clone());
+
+        let title = Self::fleet_dialog_title(s);
+        s.add_fullscreen_layer(
+            Dialog::around(
+                LinearLayout::vertical()
+                    .child(
+                        fleet_nodes_table_view
+                            .with_name("fleet-nodes")
+                            .full_screen(),
+                    )
+                    .child(TextView::new(
+                        "Add nodes with 'node add <name> <connection>', run commands with 'on <selector> <command>'",
+                    )),
+            )
+            .title(title)
+            .button("Focus", Self::on_fleet_focus_pressed)
+            .button("Remove", Self::on_fleet_remove_pressed)
+            .button("Close", |s| {
+                s.pop_layer();
+            })
+            .with_name("fleet-dialog"),
+        );
+    }
+
+    fn selected_fleet_node(s: &mut Cursive) -> Option<String> {
+        let fleet_nodes = Self::fleet_nodes(s)?;
+        let index = fleet_nodes.item()?;
+        fleet_nodes.borrow_item(index).map(|n| n.name.clone())
+    }
+
+    fn on_fleet_focus_pressed(s: &mut Cursive) {
+        let Some(name) = Self::selected_fleet_node(s) else {
+            return;
+        };
+        s.pop_layer();
+        Self::command_processor(s)
+            .focus_node(name, UICallback::Cursive(Box::new(|_: &mut Cursive| {})));
+    }
+
+    fn on_fleet_remove_pressed(s: &mut Cursive) {
+        let Some(name) = Self::selected_fleet_node(s) else {
+            return;
+        };
+        Self::command_processor(s).remove_node(name);
+    }
+
+    fn refresh_fleet_nodes(s: &mut Cursive) {
+        let title = Self::fleet_dialog_title(s);
+        if let Some(mut fleet_dialog) = Self::fleet_dialog(s) {
+            fleet_dialog.set_title(title);
+        }
+        let Some(mut fleet_nodes) = Self::fleet_nodes(s) else {
+            return;
+        };
+        let inner = Self::inner_mut(s);
+        let sel_item = fleet_nodes.item();
+        let sel_name = fleet_nodes
+            .item()
+            .and_then(|x| fleet_nodes.borrow_item(x).map(|n| n.name.clone()));
+
+        fleet_nodes.set_items_stable(inner.ui_state.fleet_nodes.get().clone());
+
+        let mut selected = false;
+        if let Some(sel_name) = sel_name {
+            for n in 0..fleet_nodes.borrow_items().len() {
+                if fleet_nodes.borrow_items()[n].name == sel_name {
+                    fleet_nodes.set_selected_item(n);
+                    selected = true;
+                }
+            }
+        }
+        if !selected {
+            if let Some(sel_item) = sel_item {
+                fleet_nodes.set_selected_item(sel_item);
+            }
+        }
+    }
+
     fn draw_connection_dialog(s: &mut Cursive, state: ConnectionState) -> bool {
         let is_ipc = Self::command_processor(s).get_ipc_path().is_some();
         let mut inner = Self::inner_mut(s);
@@ -981,6 +1611,10 @@ impl CursiveUI {
         let mut refresh_connection_dialog = false;
         let mut refresh_peers = false;
         let mut refresh_main_titlebar = false;
+        let mut refresh_dht_records = false;
+        let mut refresh_routes = false;
+        let mut refresh_connections = false;
+        let mut refresh_fleet_nodes = false;
         if inner.ui_state.attachment_state.take_dirty() {
             refresh_statusbar = true;
             refresh_button_attach = true;
@@ -1004,6 +1638,18 @@ impl CursiveUI {
         if inner.ui_state.node_id.take_dirty() {
             refresh_main_titlebar = true;
         }
+        if inner.ui_state.dht_records.take_dirty() {
+            refresh_dht_records = true;
+        }
+        if inner.ui_state.routes.take_dirty() {
+            refresh_routes = true;
+        }
+        if inner.ui_state.connections.take_dirty() {
+            refresh_connections = true;
+        }
+        if inner.ui_state.fleet_nodes.take_dirty() {
+            refresh_fleet_nodes = true;
+        }
 
         drop(inner);
 
@@ -1022,6 +1668,18 @@ impl CursiveUI {
         if refresh_main_titlebar {
             Self::refresh_main_titlebar(s);
         }
+        if refresh_dht_records {
+            Self::refresh_dht_records(s);
+        }
+        if refresh_routes {
+            Self::refresh_routes(s);
+        }
+        if refresh_connections {
+            Self::refresh_connections(s);
+        }
+        if refresh_fleet_nodes {
+            Self::refresh_fleet_nodes(s);
+        }
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -1114,6 +1772,7 @@ impl CursiveUI {
                 cmd_history_position: 0,
                 cmd_history_max_size: settings.interface.command_line.history_size,
                 connection_dialog_state: None,
+                dht_record_editor_key: None,
             })),
         };
 
@@ -1188,6 +1847,30 @@ impl CursiveUI {
                         CursiveUI::on_button_attach_pressed(s);
                     })
                     .with_name("button-attach"),
+                )
+                .child(
+                    Button::new("Records", |s| {
+                        CursiveUI::on_button_records_pressed(s);
+                    })
+                    .with_name("button-records"),
+                )
+                .child(
+                    Button::new("Routes", |s| {
+                        CursiveUI::on_button_routes_pressed(s);
+                    })
+                    .with_name("button-routes"),
+                )
+                .child(
+                    Button::new("Connections", |s| {
+                        CursiveUI::on_button_connections_pressed(s);
+                    })
+                    .with_name("button-connections"),
+                )
+                .child(
+                    Button::new("Nodes", |s| {
+                        CursiveUI::on_button_nodes_pressed(s);
+                    })
+                    .with_name("button-nodes"),
                 ),
         );
         let mut version = StyledString::new();
@@ -1346,6 +2029,34 @@ impl UISender for CursiveUISender {
 
         inner.ui_state.node_id.set(node_id_str);
     }
+    fn set_dht_records(&mut self, records: Vec<DHTRecordItem>) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.dht_records.set(records);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn set_routes(&mut self, routes: Vec<json::JsonValue>) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.routes.set(routes);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn set_connections(&mut self, connections: Vec<json::JsonValue>) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.connections.set(connections);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn set_fleet_nodes(&mut self, nodes: Vec<FleetNodeSummary>) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.fleet_nodes.set(nodes);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
     fn set_connection_state(&mut self, state: ConnectionState) {
         {
             let mut inner = self.inner.lock();
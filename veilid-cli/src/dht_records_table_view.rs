@@ -0,0 +1,142 @@
+use super::*;
+use crate::command_processor::*;
+use cursive_table_view::*;
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DHTRecordTableColumn {
+    Key,
+    Scope,
+    Schema,
+    Owner,
+    Subkeys,
+    Seqs,
+}
+
+pub fn format_schema(descriptor: &json::JsonValue) -> String {
+    let schema = &descriptor["schema"];
+    match schema["kind"].as_str() {
+        Some("DFLT") => format!("DFLT(o_cnt={})", schema["o_cnt"]),
+        Some("SMPL") => {
+            let m_cnt: u64 = schema["members"]
+                .members()
+                .map(|m| m["m_cnt"].as_u64().unwrap_or_default())
+                .sum();
+            format!(
+                "SMPL(o_cnt={},members={},m_cnt={})",
+                schema["o_cnt"],
+                schema["members"].len(),
+                m_cnt
+            )
+        }
+        Some("MULT") => {
+            let m_cnt: u64 = schema["members"]
+                .members()
+                .map(|m| m["m_cnt"].as_u64().unwrap_or_default())
+                .sum();
+            format!(
+                "MULT(o_cnt={},owners={},threshold={},members={},m_cnt={})",
+                schema["o_cnt"],
+                schema["owners"].len(),
+                schema["threshold"],
+                schema["members"].len(),
+                m_cnt
+            )
+        }
+        _ => "---".to_owned(),
+    }
+}
+
+/// Pair each inspected subkey with its local sequence number
+pub fn report_subkey_seqs(report: &json::JsonValue) -> Vec<(u64, Option<u64>)> {
+    let mut out = Vec::new();
+    let mut seqs = report["local_seqs"].members();
+    for range in report["subkeys"].members() {
+        let start = range[0].as_u64().unwrap_or_default();
+        let end = range[1].as_u64().unwrap_or_default();
+        for subkey in start..=end {
+            let Some(seq) = seqs.next() else {
+                return out;
+            };
+            out.push((subkey, seq.as_u64()));
+        }
+    }
+    out
+}
+
+pub fn format_subkey_range_set(ranges: &json::JsonValue) -> String {
+    ranges
+        .members()
+        .map(|r| {
+            if r[0] == r[1] {
+                format!("{}", r[0])
+            } else {
+                format!("{}..={}", r[0], r[1])
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn format_seqs(report: &json::JsonValue) -> String {
+    if report.is_null() {
+        return "---".to_owned();
+    }
+    report_subkey_seqs(report)
+        .into_iter()
+        .map(|(subkey, seq)| match seq {
+            Some(seq) => format!("{}:{}", subkey, seq),
+            None => format!("{}:-", subkey),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl TableViewItem<DHTRecordTableColumn> for DHTRecordItem {
+    fn to_column(&self, column: DHTRecordTableColumn) -> String {
+        match column {
+            DHTRecordTableColumn::Key => self.key.clone(),
+            DHTRecordTableColumn::Scope => self.scope.as_str().to_owned(),
+            DHTRecordTableColumn::Schema => match &self.descriptor {
+                Some(d) => format_schema(d),
+                None => "---".to_owned(),
+            },
+            DHTRecordTableColumn::Owner => match &self.descriptor {
+                Some(d) => d["owner"].to_string(),
+                None => "---".to_owned(),
+            },
+            DHTRecordTableColumn::Subkeys => self
+                .stored_subkeys
+                .clone()
+                .unwrap_or_else(|| "---".to_owned()),
+            DHTRecordTableColumn::Seqs => {
+                let seqs = match &self.report {
+                    Some(r) => format_seqs(r),
+                    None => "---".to_owned(),
+                };
+                match &self.last_change {
+                    Some(c) => format!("{} (changed: {})", seqs, format_subkey_range_set(c)),
+                    None => seqs,
+                }
+            }
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: DHTRecordTableColumn) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            DHTRecordTableColumn::Scope => self
+                .scope
+                .cmp(&other.scope)
+                .then_with(|| self.key.cmp(&other.key)),
+            _ => self
+                .to_column(column)
+                .to_ascii_lowercase()
+                .cmp(&other.to_column(column).to_ascii_lowercase()),
+        }
+    }
+}
+
+pub type DHTRecordsTableView = TableView<DHTRecordItem, DHTRecordTableColumn>;
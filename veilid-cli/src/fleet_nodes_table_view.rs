@@ -0,0 +1,48 @@
+use super::*;
+use crate::node_fleet::*;
+use cursive_table_view::*;
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FleetNodeTableColumn {
+    Name,
+    Target,
+    Connection,
+    Attachment,
+    Peers,
+    TransferDown,
+    TransferUp,
+    Groups,
+}
+
+impl TableViewItem<FleetNodeTableColumn> for FleetNodeSummary {
+    fn to_column(&self, column: FleetNodeTableColumn) -> String {
+        match column {
+            FleetNodeTableColumn::Name => self.name.clone(),
+            FleetNodeTableColumn::Target => self.target.clone(),
+            FleetNodeTableColumn::Connection => self.connection_state_str().to_owned(),
+            FleetNodeTableColumn::Attachment => self.attachment_state.clone(),
+            FleetNodeTableColumn::Peers => self.peer_count.to_string(),
+            FleetNodeTableColumn::TransferDown => format_bps(&self.bps_down.to_string().into()),
+            FleetNodeTableColumn::TransferUp => format_bps(&self.bps_up.to_string().into()),
+            FleetNodeTableColumn::Groups => self.groups.join(","),
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: FleetNodeTableColumn) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            FleetNodeTableColumn::Peers => self.peer_count.cmp(&other.peer_count),
+            FleetNodeTableColumn::TransferDown => self.bps_down.cmp(&other.bps_down),
+            FleetNodeTableColumn::TransferUp => self.bps_up.cmp(&other.bps_up),
+            _ => self
+                .to_column(column)
+                .to_ascii_lowercase()
+                .cmp(&other.to_column(column).to_ascii_lowercase()),
+        }
+    }
+}
+
+pub type FleetNodesTableView = TableView<FleetNodeSummary, FleetNodeTableColumn>;
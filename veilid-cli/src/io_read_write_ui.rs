@@ -1,4 +1,5 @@
 use crate::command_processor::*;
+use crate::node_fleet::FleetNodeSummary;
 use crate::settings::*;
 use crate::tools::*;
 use crate::ui::*;
@@ -251,6 +252,18 @@ impl<R: AsyncRead + Unpin + Send + 'static, W: AsyncWrite + Unpin + Send + 'stat
     fn set_config(&mut self, _config: &json::JsonValue) {
         //
     }
+    fn set_dht_records(&mut self, _records: Vec<DHTRecordItem>) {
+        //
+    }
+    fn set_routes(&mut self, _routes: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_connections(&mut self, _connections: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_fleet_nodes(&mut self, _nodes: Vec<FleetNodeSummary>) {
+        //
+    }
     fn set_connection_state(&mut self, state: ConnectionState) {
         if let Err(e) = self.connection_state_sender.send(state) {
             eprintln!("Error: {:?}", e);
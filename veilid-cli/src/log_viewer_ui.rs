@@ -1,5 +1,6 @@
 use crate::command_processor::*;
 use crate::cursive_ui::CursiveUI;
+use crate::node_fleet::FleetNodeSummary;
 use crate::settings::*;
 use crate::tools::*;
 use crate::ui::*;
@@ -240,6 +241,18 @@ impl UISender for LogViewerUISender {
     fn set_config(&mut self, _config: &json::JsonValue) {
         //
     }
+    fn set_dht_records(&mut self, _records: Vec<DHTRecordItem>) {
+        //
+    }
+    fn set_routes(&mut self, _routes: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_connections(&mut self, _connections: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_fleet_nodes(&mut self, _nodes: Vec<FleetNodeSummary>) {
+        //
+    }
     fn set_connection_state(&mut self, state: ConnectionState) {
         if let Err(e) = self.connection_state_sender.send(state) {
             eprintln!("Error: {:?}", e);
@@ -9,11 +9,16 @@ use std::path::PathBuf;
 mod cached_text_view;
 mod client_api_connection;
 mod command_processor;
+mod connections_table_view;
 mod cursive_ui;
+mod dht_records_table_view;
+mod fleet_nodes_table_view;
 mod interactive_ui;
 mod io_read_write_ui;
 mod log_viewer_ui;
+mod node_fleet;
 mod peers_table_view;
+mod routes_table_view;
 mod settings;
 mod tools;
 mod ui;
@@ -0,0 +1,429 @@
+use crate::client_api_connection::*;
+use crate::command_processor::*;
+use crate::settings::Settings;
+use crate::tools::*;
+use crate::ui::*;
+use flexi_logger::writers::LogWriter;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Selector that matches every node in the fleet
+pub const FLEET_SELECT_ALL: &str = "all";
+
+/// State of one additional node connection, as shown in the fleet overview
+#[derive(Clone, PartialEq)]
+pub struct FleetNodeSummary {
+    pub name: String,
+    pub target: String,
+    pub groups: Vec<String>,
+    pub connection_state: ConnectionState,
+    pub attachment_state: String,
+    pub public_internet_ready: bool,
+    pub local_network_ready: bool,
+    pub network_started: bool,
+    pub bps_down: u64,
+    pub bps_up: u64,
+    pub peer_count: usize,
+    pub node_id: String,
+}
+
+impl FleetNodeSummary {
+    fn new(name: String, target: String) -> Self {
+        Self {
+            name,
+            target,
+            groups: Vec::new(),
+            connection_state: ConnectionState::Disconnected,
+            attachment_state: "Detached".to_owned(),
+            public_internet_ready: false,
+            local_network_ready: false,
+            network_started: false,
+            bps_down: 0,
+            bps_up: 0,
+            peer_count: 0,
+            node_id: String::new(),
+        }
+    }
+
+    pub fn connection_state_str(&self) -> &'static str {
+        if self.connection_state.is_connected() {
+            "connected"
+        } else if self.connection_state.is_retrying() {
+            "retrying"
+        } else {
+            "disconnected"
+        }
+    }
+}
+
+/// Totals across all nodes in the fleet
+#[derive(Clone, Default, PartialEq)]
+pub struct FleetTotals {
+    pub nodes: usize,
+    pub connected: usize,
+    pub attached: usize,
+    pub peers: usize,
+    pub bps_down: u64,
+    pub bps_up: u64,
+}
+
+impl FleetTotals {
+    pub fn new(nodes: &[FleetNodeSummary]) -> Self {
+        let mut totals = Self {
+            nodes: nodes.len(),
+            ..Default::default()
+        };
+        for n in nodes {
+            if n.connection_state.is_connected() {
+                totals.connected += 1;
+            }
+            if matches!(
+                n.attachment_state.as_str(),
+                "AttachedWeak"
+                    | "AttachedGood"
+                    | "AttachedStrong"
+                    | "FullyAttached"
+                    | "OverAttached"
+            ) {
+                totals.attached += 1;
+            }
+            totals.peers += n.peer_count;
+            totals.bps_down += n.bps_down;
+            totals.bps_up += n.bps_up;
+        }
+        totals
+    }
+}
+
+impl fmt::Display for FleetTotals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} connected, {} attached, {} peers",
+            self.connected, self.nodes, self.attached, self.peers
+        )
+    }
+}
+
+struct FleetNode {
+    comproc: CommandProcessor,
+    capi: ClientApiConnection,
+    summary: FleetNodeSummary,
+}
+
+struct NodeFleetInner {
+    ui_sender: Box<dyn UISender>,
+    nodes: BTreeMap<String, FleetNode>,
+    groups: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Additional veilid-server connections managed alongside the main one
+///
+/// Each node gets its own command processor and client api connection, so it
+/// reconnects independently. Its updates are folded into a summary for the
+/// fleet overview and its output is forwarded to the main ui with the node name.
+#[derive(Clone)]
+pub struct NodeFleet {
+    inner: Arc<Mutex<NodeFleetInner>>,
+    settings: Arc<Settings>,
+}
+
+impl NodeFleet {
+    pub fn new(ui_sender: Box<dyn UISender>, settings: &Settings) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(NodeFleetInner {
+                ui_sender,
+                nodes: BTreeMap::new(),
+                groups: BTreeMap::new(),
+            })),
+            settings: Arc::new(settings.clone()),
+        }
+    }
+
+    fn parent_ui_sender(&self) -> Box<dyn UISender> {
+        self.inner.lock().ui_sender.clone_uisender()
+    }
+
+    fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name != FLEET_SELECT_ALL
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    }
+
+    pub fn add_node(&self, name: String, target: String) -> Result<(), String> {
+        if !Self::is_valid_name(&name) {
+            return Err(format!("Invalid node name: {}", name));
+        }
+        {
+            let inner = self.inner.lock();
+            if inner.nodes.contains_key(&name) || inner.groups.contains_key(&name) {
+                return Err(format!("Name already in use: {}", name));
+            }
+        }
+
+        let ui_sender = FleetUISender {
+            name: name.clone(),
+            fleet: self.clone(),
+        };
+        let comproc = CommandProcessor::new(Box::new(ui_sender), &self.settings);
+        comproc.set_connection_string(&target)?;
+        let capi = ClientApiConnection::new(comproc.clone());
+        comproc.set_client_api_connection(capi.clone());
+
+        self.inner.lock().nodes.insert(
+            name.clone(),
+            FleetNode {
+                comproc: comproc.clone(),
+                capi,
+                summary: FleetNodeSummary::new(name, target),
+            },
+        );
+
+        let comproc2 = comproc.clone();
+        spawn_detached_local("fleet node connection", async move {
+            comproc2.connection_manager().await;
+        });
+        comproc.start_connection();
+
+        self.publish();
+        Ok(())
+    }
+
+    pub fn remove_node(&self, name: &str) -> Result<(), String> {
+        let node = {
+            let mut inner = self.inner.lock();
+            let Some(node) = inner.nodes.remove(name) else {
+                return Err(format!("Unknown node: {}", name));
+            };
+            for members in inner.groups.values_mut() {
+                members.remove(name);
+            }
+            inner.groups.retain(|_, members| !members.is_empty());
+            node
+        };
+
+        // Stop the connection outside the lock, as the command processor calls back into us
+        node.comproc.quit();
+        node.capi.disconnect();
+
+        self.publish();
+        Ok(())
+    }
+
+    /// Replace a group's members, or remove the group if there are none
+    pub fn set_group(&self, group: String, members: Vec<String>) -> Result<(), String> {
+        if !Self::is_valid_name(&group) {
+            return Err(format!("Invalid group name: {}", group));
+        }
+        {
+            let mut inner = self.inner.lock();
+            if inner.nodes.contains_key(&group) {
+                return Err(format!("Name already in use by a node: {}", group));
+            }
+            if let Some(m) = members.iter().find(|m| !inner.nodes.contains_key(*m)) {
+                return Err(format!("Unknown node: {}", m));
+            }
+            if members.is_empty() {
+                inner.groups.remove(&group);
+            } else {
+                inner.groups.insert(group, members.into_iter().collect());
+            }
+        }
+        self.publish();
+        Ok(())
+    }
+
+    pub fn groups(&self) -> Vec<(String, Vec<String>)> {
+        self.inner
+            .lock()
+            .groups
+            .iter()
+            .map(|(g, m)| (g.clone(), m.iter().cloned().collect()))
+            .collect()
+    }
+
+    pub fn node_target(&self, name: &str) -> Option<String> {
+        self.inner
+            .lock()
+            .nodes
+            .get(name)
+            .map(|n| n.summary.target.clone())
+    }
+
+    /// Resolve a comma separated list of node names, group names, or 'all'
+    pub fn select(&self, selector: &str) -> Result<Vec<(String, ClientApiConnection)>, String> {
+        let inner = self.inner.lock();
+        let mut names = BTreeSet::new();
+        for s in selector
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            if s == FLEET_SELECT_ALL {
+                names.extend(inner.nodes.keys().cloned());
+            } else if let Some(members) = inner.groups.get(s) {
+                names.extend(members.iter().cloned());
+            } else if inner.nodes.contains_key(s) {
+                names.insert(s.to_owned());
+            } else {
+                return Err(format!("Unknown node or group: {}", s));
+            }
+        }
+        if names.is_empty() {
+            return Err("No nodes selected".to_owned());
+        }
+        Ok(names
+            .into_iter()
+            .map(|n| {
+                let capi = inner.nodes[&n].capi.clone();
+                (n, capi)
+            })
+            .collect())
+    }
+
+    pub fn summaries(&self) -> Vec<FleetNodeSummary> {
+        let inner = self.inner.lock();
+        inner
+            .nodes
+            .values()
+            .map(|n| {
+                let mut summary = n.summary.clone();
+                summary.groups = inner
+                    .groups
+                    .iter()
+                    .filter(|(_, m)| m.contains(&summary.name))
+                    .map(|(g, _)| g.clone())
+                    .collect();
+                summary
+            })
+            .collect()
+    }
+
+    fn publish(&self) {
+        let summaries = self.summaries();
+        self.inner.lock().ui_sender.set_fleet_nodes(summaries);
+    }
+
+    fn update_summary<F: FnOnce(&mut FleetNodeSummary)>(&self, name: &str, f: F) {
+        {
+            let mut inner = self.inner.lock();
+            let Some(node) = inner.nodes.get_mut(name) else {
+                return;
+            };
+            f(&mut node.summary);
+        }
+        self.publish();
+    }
+
+    pub fn shutdown(&self) {
+        let nodes = std::mem::take(&mut self.inner.lock().nodes);
+        for node in nodes.into_values() {
+            node.comproc.quit();
+            node.capi.disconnect();
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// Routes the updates of a fleet node into the fleet summary and the main ui
+#[derive(Clone)]
+struct FleetUISender {
+    name: String,
+    fleet: NodeFleet,
+}
+
+impl FleetUISender {
+    fn prefix(&self, text: &str) -> String {
+        format!("[{}] {}", self.name, text)
+    }
+}
+
+impl UISender for FleetUISender {
+    fn clone_uisender(&self) -> Box<dyn UISender> {
+        Box::new(self.clone())
+    }
+    fn as_logwriter(&self) -> Option<Box<dyn LogWriter>> {
+        None
+    }
+
+    fn display_string_dialog(&self, title: &str, text: &str, close_cb: UICallback) {
+        self.fleet
+            .parent_ui_sender()
+            .display_string_dialog(&self.prefix(title), text, close_cb);
+    }
+
+    fn quit(&self) {
+        // A fleet node can not quit the whole client
+    }
+
+    fn send_callback(&self, callback: UICallback) {
+        self.fleet.parent_ui_sender().send_callback(callback);
+    }
+    fn set_attachment_state(
+        &mut self,
+        state: &str,
+        public_internet_ready: bool,
+        local_network_ready: bool,
+    ) {
+        self.fleet.update_summary(&self.name, |s| {
+            s.attachment_state = state.to_owned();
+            s.public_internet_ready = public_internet_ready;
+            s.local_network_ready = local_network_ready;
+        });
+    }
+    fn set_network_status(
+        &mut self,
+        started: bool,
+        bps_down: u64,
+        bps_up: u64,
+        peers: Vec<json::JsonValue>,
+    ) {
+        self.fleet.update_summary(&self.name, |s| {
+            s.network_started = started;
+            s.bps_down = bps_down;
+            s.bps_up = bps_up;
+            s.peer_count = peers.len();
+        });
+    }
+    fn set_config(&mut self, config: &json::JsonValue) {
+        let node_id = config["network"]["routing_table"]["node_id"]
+            .members()
+            .map(|nid| nid.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.fleet.update_summary(&self.name, |s| {
+            s.node_id = node_id;
+        });
+    }
+    fn set_dht_records(&mut self, _records: Vec<DHTRecordItem>) {
+        //
+    }
+    fn set_routes(&mut self, _routes: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_connections(&mut self, _connections: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_fleet_nodes(&mut self, _nodes: Vec<FleetNodeSummary>) {
+        //
+    }
+    fn set_connection_state(&mut self, state: ConnectionState) {
+        self.fleet.update_summary(&self.name, |s| {
+            s.connection_state = state;
+        });
+    }
+
+    fn add_node_event(&self, log_color: Level, event: &str) {
+        self.fleet
+            .parent_ui_sender()
+            .add_node_event(log_color, &self.prefix(event));
+    }
+    fn add_log_event(&self, log_color: Level, event: &str) {
+        self.fleet
+            .parent_ui_sender()
+            .add_log_event(log_color, &self.prefix(event));
+    }
+}
@@ -23,35 +23,6 @@ pub enum PeerTableColumn {
 //     }
 // }
 
-fn format_ts(ts: &json::JsonValue) -> String {
-    if ts.is_null() {
-        return "---".to_owned();
-    }
-    let ts = json_str_u64(ts);
-    let secs = timestamp_to_secs(ts);
-    if secs >= 1.0 {
-        format!("{:.2}s", timestamp_to_secs(ts))
-    } else {
-        format!("{:.2}ms", timestamp_to_secs(ts) * 1000.0)
-    }
-}
-
-fn format_bps(bps: &json::JsonValue) -> String {
-    if bps.is_null() {
-        return "---".to_owned();
-    }
-    let bps = json_str_u64(bps);
-    if bps >= 1024u64 * 1024u64 * 1024u64 {
-        format!("{:.2}GB/s", (bps / (1024u64 * 1024u64)) as f64 / 1024.0)
-    } else if bps >= 1024u64 * 1024u64 {
-        format!("{:.2}MB/s", (bps / 1024u64) as f64 / 1024.0)
-    } else if bps >= 1024u64 {
-        format!("{:.2}KB/s", bps as f64 / 1024.0)
-    } else {
-        format!("{:.2}B/s", bps as f64)
-    }
-}
-
 impl TableViewItem<PeerTableColumn> for json::JsonValue {
     fn to_column(&self, column: PeerTableColumn) -> String {
         match column {
@@ -0,0 +1,92 @@
+use super::*;
+use cursive_table_view::*;
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RouteTableColumn {
+    RouteId,
+    Kind,
+    Hops,
+    LatencyAvg,
+    LostAnswers,
+    LastValid,
+    TransferDownAvg,
+    TransferUpAvg,
+}
+
+fn format_kind(route: &json::JsonValue) -> String {
+    if route["remote"].as_bool().unwrap_or_default() {
+        "remote".to_owned()
+    } else if route["published"].as_bool().unwrap_or_default() {
+        "published".to_owned()
+    } else {
+        "allocated".to_owned()
+    }
+}
+
+impl TableViewItem<RouteTableColumn> for json::JsonValue {
+    fn to_column(&self, column: RouteTableColumn) -> String {
+        match column {
+            RouteTableColumn::RouteId => self["route_id"].to_string(),
+            RouteTableColumn::Kind => format_kind(self),
+            RouteTableColumn::Hops => {
+                if self["hop_count"].is_null() {
+                    "---".to_owned()
+                } else {
+                    self["hop_count"].to_string()
+                }
+            }
+            RouteTableColumn::LatencyAvg => format_ts(&self["stats"]["latency"]["average"]),
+            RouteTableColumn::LostAnswers => format!(
+                "{}/{}",
+                self["stats"]["recent_lost_answers"], self["stats"]["answer"]["lost_answers"]
+            ),
+            RouteTableColumn::LastValid => {
+                format_age(&self["cur_ts"], &self["stats"]["last_known_valid_ts"])
+            }
+            RouteTableColumn::TransferDownAvg => {
+                format_bps(&self["stats"]["transfer"]["down"]["average"])
+            }
+            RouteTableColumn::TransferUpAvg => {
+                format_bps(&self["stats"]["transfer"]["up"]["average"])
+            }
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: RouteTableColumn) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            RouteTableColumn::RouteId | RouteTableColumn::Kind => self
+                .to_column(column)
+                .to_ascii_lowercase()
+                .cmp(&other.to_column(column).to_ascii_lowercase()),
+            RouteTableColumn::Hops => self["hop_count"].as_u64().cmp(&other["hop_count"].as_u64()),
+            RouteTableColumn::LatencyAvg => json_str_u64(&self["stats"]["latency"]["average"])
+                .cmp(&json_str_u64(&other["stats"]["latency"]["average"])),
+            RouteTableColumn::LostAnswers => self["stats"]["recent_lost_answers"]
+                .as_u64()
+                .cmp(&other["stats"]["recent_lost_answers"].as_u64())
+                .then_with(|| {
+                    self["stats"]["answer"]["lost_answers"]
+                        .as_u64()
+                        .cmp(&other["stats"]["answer"]["lost_answers"].as_u64())
+                }),
+            // Older timestamps are longer ages, so sort them in reverse
+            RouteTableColumn::LastValid => json_str_u64(&other["stats"]["last_known_valid_ts"])
+                .cmp(&json_str_u64(&self["stats"]["last_known_valid_ts"])),
+            RouteTableColumn::TransferDownAvg => {
+                json_str_u64(&self["stats"]["transfer"]["down"]["average"]).cmp(&json_str_u64(
+                    &other["stats"]["transfer"]["down"]["average"],
+                ))
+            }
+            RouteTableColumn::TransferUpAvg => {
+                json_str_u64(&self["stats"]["transfer"]["up"]["average"])
+                    .cmp(&json_str_u64(&other["stats"]["transfer"]["up"]["average"]))
+            }
+        }
+    }
+}
+
+pub type RoutesTableView = TableView<json::JsonValue, RouteTableColumn>;
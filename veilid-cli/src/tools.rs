@@ -31,3 +31,131 @@ pub fn json_str_vec_u8(value: &json::JsonValue) -> Vec<u8> {
         .decode(value.as_str().unwrap_or_default().as_bytes())
         .unwrap_or_default()
 }
+
+pub fn format_ts(ts: &json::JsonValue) -> String {
+    if ts.is_null() {
+        return "---".to_owned();
+    }
+    let ts = json_str_u64(ts);
+    let secs = timestamp_to_secs(ts);
+    if secs >= 1.0 {
+        format!("{:.2}s", timestamp_to_secs(ts))
+    } else {
+        format!("{:.2}ms", timestamp_to_secs(ts) * 1000.0)
+    }
+}
+
+pub fn format_bps(bps: &json::JsonValue) -> String {
+    if bps.is_null() {
+        return "---".to_owned();
+    }
+    let bps = json_str_u64(bps);
+    if bps >= 1024u64 * 1024u64 * 1024u64 {
+        format!("{:.2}GB/s", (bps / (1024u64 * 1024u64)) as f64 / 1024.0)
+    } else if bps >= 1024u64 * 1024u64 {
+        format!("{:.2}MB/s", (bps / 1024u64) as f64 / 1024.0)
+    } else if bps >= 1024u64 {
+        format!("{:.2}KB/s", bps as f64 / 1024.0)
+    } else {
+        format!("{:.2}B/s", bps as f64)
+    }
+}
+
+/// Format the time elapsed between two json timestamps
+pub fn format_age(cur_ts: &json::JsonValue, ts: &json::JsonValue) -> String {
+    if ts.is_null() {
+        return "---".to_owned();
+    }
+    let age = json_str_u64(cur_ts).saturating_sub(json_str_u64(ts));
+    format_ts(&json::JsonValue::String(age.to_string()))
+}
+
+/// Parse data entered by the user: '#' prefixed hex, a quoted json string, or plain text
+///
+/// Single quoted strings take the same escapes as json strings, plus \' for a single quote.
+pub fn parse_data(text: &str) -> Option<Vec<u8>> {
+    if let Some(stripped_text) = text.strip_prefix('#') {
+        hex::decode(stripped_text).ok()
+    } else if text.starts_with('"') {
+        parse_json_string(text)
+    } else if let Some(inner) = text.strip_prefix('\'') {
+        let inner = inner.strip_suffix('\'')?;
+        parse_json_string(&single_to_double_quoted(inner)?)
+    } else {
+        Some(text.as_bytes().to_vec())
+    }
+}
+
+fn parse_json_string(text: &str) -> Option<Vec<u8>> {
+    json::parse(text)
+        .ok()?
+        .as_str()
+        .map(|x| x.as_bytes().to_vec())
+}
+
+/// Turn the inside of a single quoted string into a double quoted json string
+fn single_to_double_quoted(inner: &str) -> Option<String> {
+    let mut out = String::with_capacity(inner.len() + 2);
+    out.push('"');
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '\'' => out.push('\''),
+                e => {
+                    out.push('\\');
+                    out.push(e);
+                }
+            },
+            '\'' => return None,
+            '"' => out.push_str("\\\""),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data_plain_and_hex() {
+        assert_eq!(parse_data("hello"), Some(b"hello".to_vec()));
+        assert_eq!(parse_data("#00ff"), Some(vec![0x00, 0xff]));
+        assert_eq!(parse_data("#0g"), None);
+    }
+
+    #[test]
+    fn test_parse_data_double_quoted() {
+        assert_eq!(parse_data("\"hello\""), Some(b"hello".to_vec()));
+        assert_eq!(parse_data("\"it's\""), Some(b"it's".to_vec()));
+        assert_eq!(parse_data("\"unterminated"), None);
+    }
+
+    #[test]
+    fn test_parse_data_single_quoted() {
+        assert_eq!(parse_data("'hello'"), Some(b"hello".to_vec()));
+        assert_eq!(parse_data("''"), Some(Vec::new()));
+        assert_eq!(parse_data("'say \"hi\"'"), Some(b"say \"hi\"".to_vec()));
+        assert_eq!(parse_data("'unterminated"), None);
+        assert_eq!(parse_data("'"), None);
+        assert_eq!(parse_data("'a'b'"), None);
+    }
+
+    #[test]
+    fn test_parse_data_escapes() {
+        assert_eq!(parse_data("\"a\\nb\""), Some(b"a\nb".to_vec()));
+        assert_eq!(parse_data("'a\\nb'"), Some(b"a\nb".to_vec()));
+        assert_eq!(parse_data("'it\\'s'"), Some(b"it's".to_vec()));
+        assert_eq!(parse_data("'back\\\\slash'"), Some(b"back\\slash".to_vec()));
+        assert_eq!(
+            parse_data("\"\\u00e9\""),
+            Some("\u{e9}".as_bytes().to_vec())
+        );
+        assert_eq!(parse_data("'\\u00e9'"), Some("\u{e9}".as_bytes().to_vec()));
+        assert_eq!(parse_data("'trailing\\'"), None);
+        assert_eq!(parse_data("'bad\\q'"), None);
+    }
+}
@@ -3,6 +3,7 @@ use crate::cursive_ui::CursiveUICallback;
 use crate::interactive_ui::InteractiveUICallback;
 use crate::io_read_write_ui::IOReadWriteUICallback;
 use crate::log_viewer_ui::LogViewerUICallback;
+use crate::node_fleet::FleetNodeSummary;
 use crate::tools::*;
 use flexi_logger::writers::LogWriter;
 use log::Level;
@@ -35,6 +36,10 @@ pub trait UISender: Send {
         peers: Vec<json::JsonValue>,
     );
     fn set_config(&mut self, config: &json::JsonValue);
+    fn set_dht_records(&mut self, records: Vec<DHTRecordItem>);
+    fn set_routes(&mut self, routes: Vec<json::JsonValue>);
+    fn set_connections(&mut self, connections: Vec<json::JsonValue>);
+    fn set_fleet_nodes(&mut self, nodes: Vec<FleetNodeSummary>);
     fn set_connection_state(&mut self, state: ConnectionState);
     fn add_node_event(&self, log_color: Level, event: &str);
     fn add_log_event(&self, log_color: Level, event: &str);
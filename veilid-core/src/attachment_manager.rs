@@ -1,8 +1,15 @@
 use crate::{network_manager::StartupDisposition, *};
-use routing_table::RoutingTableHealth;
+use routing_table::{RoutingTableHealth, SuspendState};
+use stop_token::future::FutureExt as _;
 
 impl_veilid_log_facility!("attach");
 
+/// How frequently the attachment maintainer ticks in the normal power profile
+const TICK_INTERVAL_US: u64 = 1_000_000;
+/// How frequently the attachment maintainer ticks in the low power profile
+/// Must stay well below the relay keepalive interval plus the typical UDP NAT timeout
+const LOW_POWER_TICK_INTERVAL_US: u64 = 5_000_000;
+
 #[derive(Debug, Clone)]
 pub struct AttachmentManagerStartupContext {
     pub startup_lock: Arc<StartupLock>,
@@ -28,6 +35,9 @@ struct AttachmentManagerInner {
     started_ts: Timestamp,
     attach_ts: Option<Timestamp>,
     attachment_maintainer_jh: Option<MustJoinHandle<()>>,
+    power_profile: PowerProfile,
+    tick_wakeup: Option<StopSource>,
+    resuming: bool,
 }
 
 #[derive(Debug)]
@@ -48,6 +58,9 @@ impl AttachmentManager {
             started_ts: Timestamp::now(),
             attach_ts: None,
             attachment_maintainer_jh: None,
+            power_profile: PowerProfile::default(),
+            tick_wakeup: None,
+            resuming: false,
         }
     }
     pub fn new(
@@ -77,6 +90,38 @@ impl AttachmentManager {
         self.inner.lock().attach_ts
     }
 
+    pub fn power_profile(&self) -> PowerProfile {
+        self.inner.lock().power_profile
+    }
+
+    /// Change the power profile, waking the attachment maintainer so the new cadence applies immediately
+    pub fn set_power_profile(&self, power_profile: PowerProfile) {
+        let mut inner = self.inner.lock();
+        if inner.power_profile == power_profile {
+            return;
+        }
+        veilid_log!(self debug "power profile changed to {}", power_profile);
+        inner.power_profile = power_profile;
+        inner.tick_wakeup = None;
+    }
+
+    /// Check if a tick task may run under the current power profile.
+    /// In low power mode a task is held back until `low_power_interval_secs` have passed since it last ran.
+    pub fn power_profile_allows_tick<E: Send + 'static>(
+        &self,
+        task: &TickTask<E>,
+        low_power_interval_secs: u32,
+    ) -> bool {
+        if !self.power_profile().is_low_power() {
+            return true;
+        }
+        let Some(last_timestamp_us) = task.last_timestamp_us() else {
+            return true;
+        };
+        get_timestamp().saturating_sub(last_timestamp_us)
+            >= (low_power_interval_secs as u64 * 1_000_000u64)
+    }
+
     fn translate_routing_table_health(
         health: &RoutingTableHealth,
         config: &VeilidConfigRoutingTable,
@@ -132,12 +177,15 @@ impl AttachmentManager {
             let opt_previous_health = inner.last_routing_table_health.take();
             inner.last_routing_table_health = Some(Arc::new(health.clone()));
 
-            // Calculate new attachment state
+            // Calculate new attachment state, which stays at resuming until revalidation is done
             let config = self.config();
             let routing_table_config = &config.get().network.routing_table;
             let previous_attachment_state = inner.last_attachment_state;
-            inner.last_attachment_state =
-                AttachmentManager::translate_routing_table_health(&health, routing_table_config);
+            inner.last_attachment_state = if inner.resuming {
+                AttachmentState::Resuming
+            } else {
+                AttachmentManager::translate_routing_table_health(&health, routing_table_config)
+            };
 
             // Send update if one of:
             // * the attachment state has changed
@@ -176,7 +224,7 @@ impl AttachmentManager {
             inner.last_attachment_state = state;
 
             // Set timestamps
-            if state == AttachmentState::Attaching {
+            if state == AttachmentState::Attaching || state == AttachmentState::Resuming {
                 inner.attach_ts = Some(Timestamp::now());
             } else if state == AttachmentState::Detached {
                 inner.attach_ts = None;
@@ -275,10 +323,54 @@ impl AttachmentManager {
         Ok(())
     }
 
+    /// Bring the state saved by a suspend up to date, then start reporting the real attachment state
+    #[instrument(parent = None, level = "debug", skip_all)]
+    async fn revalidate_suspend_state(&self, suspend_state: SuspendState, stop_token: StopToken) {
+        let routing_table = self.routing_table();
+        let storage_manager = self.storage_manager();
+
+        let res = futures_util::future::join(
+            routing_table.revalidate_suspend_state(suspend_state, stop_token.clone()),
+            storage_manager.revalidate_outbound_watches(stop_token.clone()),
+        )
+        .timeout_at(stop_token)
+        .await;
+
+        {
+            let mut inner = self.inner.lock();
+            inner.resuming = false;
+            inner.last_routing_table_health = None;
+        }
+        if res.is_ok() {
+            veilid_log!(self debug "resume finished");
+            self.update_attachment();
+        }
+    }
+
+    fn spawn_revalidate_suspend_state(
+        &self,
+        suspend_state: SuspendState,
+    ) -> (StopSource, MustJoinHandle<()>) {
+        let stop_source = StopSource::new();
+        let stop_token = stop_source.token();
+        let registry = self.registry();
+        let jh = spawn("attachment resume", async move {
+            let this = registry.attachment_manager();
+            this.revalidate_suspend_state(suspend_state, stop_token)
+                .await;
+        });
+        (stop_source, jh)
+    }
+
     #[instrument(parent = None, level = "debug", skip_all)]
-    async fn attachment_maintainer(&self) {
+    async fn attachment_maintainer(&self, mut opt_suspend_state: Option<SuspendState>) {
         veilid_log!(self debug "attachment starting");
-        self.update_attaching_detaching_state(AttachmentState::Attaching);
+        if opt_suspend_state.is_some() {
+            self.inner.lock().resuming = true;
+            self.update_attaching_detaching_state(AttachmentState::Resuming);
+        } else {
+            self.update_attaching_detaching_state(AttachmentState::Attaching);
+        }
 
         let network_manager = self.network_manager();
 
@@ -301,9 +393,19 @@ impl AttachmentManager {
                 Ok(StartupDisposition::Success) => {
                     veilid_log!(self debug "started maintaining peers");
 
+                    // Revalidate anything saved by a suspend alongside the regular ticks
+                    let opt_resume = opt_suspend_state
+                        .take()
+                        .map(|suspend_state| self.spawn_revalidate_suspend_state(suspend_state));
+
                     while self.inner.lock().maintain_peers {
                         // tick network manager
-                        let next_tick_ts = get_timestamp() + 1_000_000u64;
+                        let tick_interval_us = if self.power_profile().is_low_power() {
+                            LOW_POWER_TICK_INTERVAL_US
+                        } else {
+                            TICK_INTERVAL_US
+                        };
+                        let next_tick_ts = get_timestamp() + tick_interval_us;
                         if let Err(err) = self.tick().await {
                             error!("Error in attachment tick: {}", err);
                             self.inner.lock().maintain_peers = false;
@@ -323,13 +425,29 @@ impl AttachmentManager {
                         self.update_attachment();
 
                         // sleep should be at the end in case maintain_peers changes state
+                        // a power profile change wakes us up early
                         let wait_duration = next_tick_ts
                             .saturating_sub(get_timestamp())
-                            .clamp(0, 1_000_000u64);
-                        sleep((wait_duration / 1_000) as u32).await;
+                            .clamp(0, tick_interval_us);
+                        let wakeup_token = {
+                            let mut inner = self.inner.lock();
+                            let wakeup = StopSource::new();
+                            let wakeup_token = wakeup.token();
+                            inner.tick_wakeup = Some(wakeup);
+                            wakeup_token
+                        };
+                        let _ = sleep((wait_duration / 1_000) as u32)
+                            .timeout_at(wakeup_token)
+                            .await;
                     }
                     veilid_log!(self debug "stopped maintaining peers");
 
+                    // Stop revalidating if we never finished resuming
+                    if let Some((stop_source, jh)) = opt_resume {
+                        drop(stop_source);
+                        jh.await;
+                    }
+
                     if !restart {
                         self.update_attaching_detaching_state(AttachmentState::Detaching);
                         veilid_log!(self debug "attachment stopping");
@@ -378,8 +496,7 @@ impl AttachmentManager {
     #[instrument(level = "debug", skip_all)]
     pub async fn terminate_async(&self) {}
 
-    #[instrument(level = "trace", skip_all)]
-    pub async fn attach(&self) -> bool {
+    fn start_attachment_maintainer(&self, opt_suspend_state: Option<SuspendState>) -> bool {
         // Create long-running connection maintenance routine
         let mut inner = self.inner.lock();
         if inner.attachment_maintainer_jh.is_some() {
@@ -389,20 +506,75 @@ impl AttachmentManager {
         let registry = self.registry();
         inner.attachment_maintainer_jh = Some(spawn("attachment maintainer", async move {
             let this = registry.attachment_manager();
-            this.attachment_maintainer().await;
+            this.attachment_maintainer(opt_suspend_state).await;
         }));
 
         true
     }
 
+    /// Forget any suspend state, so it is not revalidated by a resume after we attached or detached some other way
+    async fn discard_suspend_state(&self) {
+        if let Err(e) = self.routing_table().clear_suspend_state().await {
+            veilid_log!(self error "failed to clear suspend state: {}", e);
+        }
+    }
+
+    #[instrument(level = "trace", skip_all)]
+    pub async fn attach(&self) -> bool {
+        self.discard_suspend_state().await;
+        self.start_attachment_maintainer(None)
+    }
+
+    /// Save the relay, live peers, published routes and outbound watch state, then detach
+    #[instrument(level = "trace", skip_all)]
+    pub async fn suspend(&self) -> bool {
+        if self.inner.lock().attachment_maintainer_jh.is_none() {
+            return false;
+        }
+
+        if let Err(e) = self.routing_table().save_suspend_state().await {
+            veilid_log!(self error "failed to save suspend state: {}", e);
+        }
+        self.storage_manager().save_suspend_state().await;
+
+        self.stop_attachment_maintainer().await
+    }
+
+    /// Attach again, revalidating whatever the last suspend saved instead of starting from scratch
+    #[instrument(level = "trace", skip_all)]
+    pub async fn resume(&self) -> bool {
+        if self.inner.lock().attachment_maintainer_jh.is_some() {
+            return false;
+        }
+
+        let opt_suspend_state = match self.routing_table().take_suspend_state().await {
+            Ok(v) => v,
+            Err(e) => {
+                veilid_log!(self error "failed to load suspend state: {}", e);
+                None
+            }
+        };
+        if opt_suspend_state.is_none() {
+            veilid_log!(self debug "no suspend state saved, attaching from scratch");
+        }
+
+        self.start_attachment_maintainer(opt_suspend_state)
+    }
+
     #[instrument(level = "trace", skip_all)]
     pub async fn detach(&self) -> bool {
+        self.discard_suspend_state().await;
+        self.stop_attachment_maintainer().await
+    }
+
+    async fn stop_attachment_maintainer(&self) -> bool {
         let attachment_maintainer_jh = {
             let mut inner = self.inner.lock();
             let attachment_maintainer_jh = inner.attachment_maintainer_jh.take();
             if attachment_maintainer_jh.is_some() {
                 // Terminate long-running connection maintenance routine
                 inner.maintain_peers = false;
+                inner.tick_wakeup = None;
             }
             attachment_maintainer_jh
         };
@@ -441,7 +613,6 @@ impl AttachmentManager {
         Self::get_veilid_state_inner(&inner)
     }
 
-    #[expect(dead_code)]
     pub fn get_attachment_state(&self) -> AttachmentState {
         self.inner.lock().last_attachment_state
     }
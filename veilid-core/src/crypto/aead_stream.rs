@@ -0,0 +1,279 @@
+use super::*;
+use std::io;
+
+/// Current version of the streaming AEAD wire format
+pub const AEAD_STREAM_VERSION: u8 = 0;
+/// Size of the plaintext in every chunk of a stream but the last
+pub const AEAD_STREAM_CHUNK_SIZE: usize = 65536;
+/// Length of the random part of every chunk nonce, the rest being the chunk counter and the final chunk flag
+pub const AEAD_STREAM_NONCE_PREFIX_LENGTH: usize = NONCE_LENGTH - 4 - 1;
+/// Length of the stream header: version and nonce prefix.
+/// The header is authenticated as associated data of every chunk.
+pub const AEAD_STREAM_HEADER_LENGTH: usize = 1 + AEAD_STREAM_NONCE_PREFIX_LENGTH;
+
+const AEAD_STREAM_FLAG_MORE: u8 = 0;
+const AEAD_STREAM_FLAG_FINAL: u8 = 1;
+
+/// Shared state of the encrypting and decrypting sides of a stream
+struct AeadStreamState<'a, C: CryptoSystem + ?Sized> {
+    vcrypto: &'a C,
+    shared_secret: SharedSecret,
+    associated_data: Vec<u8>,
+    nonce_prefix: [u8; AEAD_STREAM_NONCE_PREFIX_LENGTH],
+    counter: u32,
+}
+
+impl<'a, C: CryptoSystem + ?Sized> AeadStreamState<'a, C> {
+    fn new(
+        vcrypto: &'a C,
+        shared_secret: &SharedSecret,
+        associated_data: Option<&[u8]>,
+        header: &[u8; AEAD_STREAM_HEADER_LENGTH],
+    ) -> Self {
+        let mut nonce_prefix = [0u8; AEAD_STREAM_NONCE_PREFIX_LENGTH];
+        nonce_prefix.copy_from_slice(&header[1..]);
+        Self {
+            vcrypto,
+            shared_secret: *shared_secret,
+            associated_data: [header.as_slice(), associated_data.unwrap_or_default()].concat(),
+            nonce_prefix,
+            counter: 0,
+        }
+    }
+
+    /// Make the nonce of the next chunk, so no two chunks of a stream share a nonce
+    /// and a stream cut short at a chunk boundary does not decrypt
+    fn next_nonce(&mut self, flag: u8) -> io::Result<Nonce> {
+        let mut nonce = [0u8; NONCE_LENGTH];
+        nonce[0..AEAD_STREAM_NONCE_PREFIX_LENGTH].copy_from_slice(&self.nonce_prefix);
+        nonce[AEAD_STREAM_NONCE_PREFIX_LENGTH..NONCE_LENGTH - 1]
+            .copy_from_slice(&self.counter.to_be_bytes());
+        nonce[NONCE_LENGTH - 1] = flag;
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "stream too long"))?;
+        Ok(Nonce::new(nonce))
+    }
+}
+
+fn to_io_error(e: VeilidAPIError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+/// Encrypts everything written to it in chunks and passes the ciphertext on to an inner writer.
+/// Call `finish` once everything is written to seal the final chunk; a stream dropped without
+/// finishing is incomplete and will not decrypt.
+#[must_use]
+pub struct AeadStreamWriter<'a, C: CryptoSystem + ?Sized, W: io::Write> {
+    state: AeadStreamState<'a, C>,
+    inner: W,
+    header: Option<[u8; AEAD_STREAM_HEADER_LENGTH]>,
+    buffer: Vec<u8>,
+}
+
+impl<'a, C: CryptoSystem + ?Sized, W: io::Write> AeadStreamWriter<'a, C, W> {
+    pub fn new(
+        vcrypto: &'a C,
+        inner: W,
+        shared_secret: &SharedSecret,
+        associated_data: Option<&[u8]>,
+    ) -> Self {
+        let mut header = [0u8; AEAD_STREAM_HEADER_LENGTH];
+        header[0] = AEAD_STREAM_VERSION;
+        random_bytes(&mut header[1..]);
+        Self {
+            state: AeadStreamState::new(vcrypto, shared_secret, associated_data, &header),
+            inner,
+            header: Some(header),
+            buffer: Vec::with_capacity(AEAD_STREAM_CHUNK_SIZE),
+        }
+    }
+
+    /// Encrypt the rest of the buffered data as the final chunk and return the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        let chunk = core::mem::take(&mut self.buffer);
+        self.write_chunk(&chunk, AEAD_STREAM_FLAG_FINAL)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_chunk(&mut self, chunk: &[u8], flag: u8) -> io::Result<()> {
+        if let Some(header) = self.header.take() {
+            self.inner.write_all(&header)?;
+        }
+        let nonce = self.state.next_nonce(flag)?;
+        let out = self
+            .state
+            .vcrypto
+            .encrypt_aead(
+                chunk,
+                &nonce,
+                &self.state.shared_secret,
+                Some(&self.state.associated_data),
+            )
+            .map_err(to_io_error)?;
+        self.inner.write_all(&out)
+    }
+}
+
+impl<C: CryptoSystem + ?Sized, W: io::Write> io::Write for AeadStreamWriter<'_, C, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = usize::min(buf.len(), AEAD_STREAM_CHUNK_SIZE - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[0..len]);
+        // Full chunks are never final, so the final chunk is always short, even if empty
+        if self.buffer.len() == AEAD_STREAM_CHUNK_SIZE {
+            let chunk = core::mem::take(&mut self.buffer);
+            self.write_chunk(&chunk, AEAD_STREAM_FLAG_MORE)?;
+            self.buffer = chunk;
+            self.buffer.clear();
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reads ciphertext from an inner reader and decrypts it chunk by chunk.
+/// Every chunk is authenticated before any of it is returned, and reading fails
+/// if the stream was truncated, extended or reordered.
+#[must_use]
+pub struct AeadStreamReader<'a, C: CryptoSystem + ?Sized, R: io::Read> {
+    vcrypto: &'a C,
+    shared_secret: SharedSecret,
+    associated_data: Vec<u8>,
+    state: Option<AeadStreamState<'a, C>>,
+    inner: R,
+    chunk: Vec<u8>,
+    pos: usize,
+    done: bool,
+}
+
+impl<'a, C: CryptoSystem + ?Sized, R: io::Read> AeadStreamReader<'a, C, R> {
+    pub fn new(
+        vcrypto: &'a C,
+        inner: R,
+        shared_secret: &SharedSecret,
+        associated_data: Option<&[u8]>,
+    ) -> Self {
+        Self {
+            vcrypto,
+            shared_secret: *shared_secret,
+            associated_data: associated_data.unwrap_or_default().to_vec(),
+            state: None,
+            inner,
+            chunk: Vec::new(),
+            pos: 0,
+            done: false,
+        }
+    }
+
+    /// Return the inner reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read as much as is available up to the length of the buffer
+    fn fill(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut len = 0;
+        while len < buf.len() {
+            match self.inner.read(&mut buf[len..]) {
+                Ok(0) => break,
+                Ok(n) => len += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(len)
+    }
+
+    fn read_chunk(&mut self) -> io::Result<()> {
+        if self.state.is_none() {
+            let mut header = [0u8; AEAD_STREAM_HEADER_LENGTH];
+            if self.fill(&mut header)? != AEAD_STREAM_HEADER_LENGTH {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "stream header truncated",
+                ));
+            }
+            if header[0] != AEAD_STREAM_VERSION {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unsupported stream version: {}", header[0]),
+                ));
+            }
+            self.state = Some(AeadStreamState::new(
+                self.vcrypto,
+                &self.shared_secret,
+                Some(&self.associated_data),
+                &header,
+            ));
+        }
+
+        let overhead = self.vcrypto.aead_overhead();
+        let mut chunk = core::mem::take(&mut self.chunk);
+        chunk.resize(AEAD_STREAM_CHUNK_SIZE + overhead, 0);
+        let len = self.fill(&mut chunk)?;
+        if len < overhead {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "stream truncated",
+            ));
+        }
+        chunk.truncate(len);
+
+        // Only the final chunk is short
+        let flag = if len == AEAD_STREAM_CHUNK_SIZE + overhead {
+            AEAD_STREAM_FLAG_MORE
+        } else {
+            AEAD_STREAM_FLAG_FINAL
+        };
+
+        // Nothing may follow the final chunk
+        if flag == AEAD_STREAM_FLAG_FINAL && self.fill(&mut [0u8; 1])? != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "data after final chunk",
+            ));
+        }
+
+        let Some(state) = self.state.as_mut() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream header missing",
+            ));
+        };
+        let nonce = state.next_nonce(flag)?;
+        state
+            .vcrypto
+            .decrypt_in_place_aead(
+                &mut chunk,
+                &nonce,
+                &state.shared_secret,
+                Some(&state.associated_data),
+            )
+            .map_err(to_io_error)?;
+
+        self.chunk = chunk;
+        self.pos = 0;
+        self.done = flag == AEAD_STREAM_FLAG_FINAL;
+        Ok(())
+    }
+}
+
+impl<C: CryptoSystem + ?Sized, R: io::Read> io::Read for AeadStreamReader<'_, C, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.chunk.len() {
+            if self.done {
+                return Ok(0);
+            }
+            self.read_chunk()?;
+        }
+        let len = usize::min(buf.len(), self.chunk.len() - self.pos);
+        buf[0..len].copy_from_slice(&self.chunk[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
@@ -1,6 +1,25 @@
 use super::*;
 
 pub(crate) const VEILID_DOMAIN_API: &[u8] = b"VEILID_API";
+const VEILID_DOMAIN_SEAL: &[u8] = b"VEILID_SEAL";
+const VEILID_DOMAIN_SIGNCRYPT: &[u8] = b"VEILID_SIGNCRYPT";
+
+/// Leading byte of a sealed box
+pub const SEALED_BOX_TAG: u8 = b'S';
+/// Leading byte of a signcrypted box
+pub const SIGNCRYPTED_BOX_TAG: u8 = b'C';
+/// Current version of the sealed and signcrypted box wire format
+pub const SEALED_BOX_VERSION: u8 = 0;
+/// Length of the header of a sealed or signcrypted box:
+/// tag, version, crypto kind fourcc, ephemeral public key and nonce.
+/// The header is authenticated as associated data of the encrypted body.
+pub const SEALED_BOX_HEADER_LENGTH: usize = 1 + 1 + 4 + PUBLIC_KEY_LENGTH + NONCE_LENGTH;
+
+/// Header fields of a sealed or signcrypted box
+struct SealedBoxHeader {
+    ephemeral_key: PublicKey,
+    nonce: Nonce,
+}
 
 pub trait CryptoSystem {
     // Accessors
@@ -31,6 +50,7 @@ pub trait CryptoSystem {
         )))
     }
     fn generate_keypair(&self) -> KeyPair;
+    fn derive_keypair(&self, seed: &[u8], path: &DerivationPath) -> VeilidAPIResult<KeyPair>;
     fn generate_hash(&self, data: &[u8]) -> HashDigest;
     fn generate_hash_reader(&self, reader: &mut dyn std::io::Read) -> VeilidAPIResult<PublicKey>;
 
@@ -81,6 +101,141 @@ pub trait CryptoSystem {
         associated_data: Option<&[u8]>,
     ) -> VeilidAPIResult<Vec<u8>>;
 
+    /// Encrypt everything from `reader` to `writer` in authenticated chunks, without holding it all in memory.
+    /// The output can only be decrypted whole and in order, with `decrypt_aead_stream` or an `AeadStreamReader`.
+    fn encrypt_aead_stream(
+        &self,
+        reader: &mut dyn std::io::Read,
+        writer: &mut dyn std::io::Write,
+        shared_secret: &SharedSecret,
+        associated_data: Option<&[u8]>,
+    ) -> VeilidAPIResult<()> {
+        let mut stream = AeadStreamWriter::new(self, writer, shared_secret, associated_data);
+        std::io::copy(reader, &mut stream).map_err(VeilidAPIError::generic)?;
+        stream.finish().map_err(VeilidAPIError::generic)?;
+        Ok(())
+    }
+    /// Decrypt a stream made with `encrypt_aead_stream` or an `AeadStreamWriter` from `reader` to `writer`.
+    /// Chunks are written out as they are authenticated, so on failure the output written so far must be discarded.
+    fn decrypt_aead_stream(
+        &self,
+        reader: &mut dyn std::io::Read,
+        writer: &mut dyn std::io::Write,
+        shared_secret: &SharedSecret,
+        associated_data: Option<&[u8]>,
+    ) -> VeilidAPIResult<()> {
+        let mut stream = AeadStreamReader::new(self, reader, shared_secret, associated_data);
+        std::io::copy(&mut stream, writer).map_err(VeilidAPIError::generic)?;
+        Ok(())
+    }
+
+    // Sealed Boxes
+    /// Encrypt data so only the holder of the secret for `key` can read it, without revealing who sent it.
+    /// Each box uses a fresh ephemeral keypair and nonce.
+    fn seal(&self, key: &PublicKey, body: &[u8]) -> VeilidAPIResult<Vec<u8>> {
+        let ephemeral = self.generate_keypair();
+        let nonce = self.random_nonce();
+        let header = make_sealed_box_header(self, SEALED_BOX_TAG, &ephemeral.key, &nonce);
+        let shared_secret = self.generate_shared_secret(
+            key,
+            &ephemeral.secret,
+            &[VEILID_DOMAIN_SEAL, &ephemeral.key.bytes, &key.bytes].concat(),
+        )?;
+        let mut out = header.clone();
+        out.extend(self.encrypt_aead(body, &nonce, &shared_secret, Some(&header))?);
+        Ok(out)
+    }
+    /// Decrypt a box made with `seal` for the keypair `key`/`secret`
+    fn open(&self, key: &PublicKey, secret: &SecretKey, sealed: &[u8]) -> VeilidAPIResult<Vec<u8>> {
+        let header = parse_sealed_box_header(self, SEALED_BOX_TAG, sealed)?;
+        let shared_secret = self.generate_shared_secret(
+            &header.ephemeral_key,
+            secret,
+            &[VEILID_DOMAIN_SEAL, &header.ephemeral_key.bytes, &key.bytes].concat(),
+        )?;
+        self.decrypt_aead(
+            &sealed[SEALED_BOX_HEADER_LENGTH..],
+            &header.nonce,
+            &shared_secret,
+            Some(&sealed[0..SEALED_BOX_HEADER_LENGTH]),
+        )
+    }
+    /// Sign data with the sender's keypair and seal it for the holder of the secret for `key`.
+    /// Only the recipient can read it, and only the sender can have made it.
+    fn signcrypt(
+        &self,
+        key: &PublicKey,
+        sender_key: &PublicKey,
+        sender_secret: &SecretKey,
+        body: &[u8],
+    ) -> VeilidAPIResult<Vec<u8>> {
+        let ephemeral = self.generate_keypair();
+        let nonce = self.random_nonce();
+        let header = make_sealed_box_header(self, SIGNCRYPTED_BOX_TAG, &ephemeral.key, &nonce);
+        let signature = self.sign(
+            sender_key,
+            sender_secret,
+            &[VEILID_DOMAIN_SIGNCRYPT, &header, &key.bytes, body].concat(),
+        )?;
+        let shared_secret = self.generate_shared_secret(
+            key,
+            &ephemeral.secret,
+            &[VEILID_DOMAIN_SIGNCRYPT, &ephemeral.key.bytes, &key.bytes].concat(),
+        )?;
+        let mut out = header.clone();
+        out.extend(self.encrypt_aead(
+            &[&signature.bytes, body].concat(),
+            &nonce,
+            &shared_secret,
+            Some(&header),
+        )?);
+        Ok(out)
+    }
+    /// Decrypt a box made with `signcrypt` for the keypair `key`/`secret`, and check it was signed by `sender_key`
+    fn unsigncrypt(
+        &self,
+        key: &PublicKey,
+        secret: &SecretKey,
+        sender_key: &PublicKey,
+        signcrypted: &[u8],
+    ) -> VeilidAPIResult<Vec<u8>> {
+        let header = parse_sealed_box_header(self, SIGNCRYPTED_BOX_TAG, signcrypted)?;
+        let shared_secret = self.generate_shared_secret(
+            &header.ephemeral_key,
+            secret,
+            &[
+                VEILID_DOMAIN_SIGNCRYPT,
+                &header.ephemeral_key.bytes,
+                &key.bytes,
+            ]
+            .concat(),
+        )?;
+        let mut body = self.decrypt_aead(
+            &signcrypted[SEALED_BOX_HEADER_LENGTH..],
+            &header.nonce,
+            &shared_secret,
+            Some(&signcrypted[0..SEALED_BOX_HEADER_LENGTH]),
+        )?;
+        if body.len() < SIGNATURE_LENGTH {
+            apibail_parse_error!("signcrypted box too short", body.len());
+        }
+        let data = body.split_off(SIGNATURE_LENGTH);
+        let signature = Signature::try_from(body.as_slice())?;
+        if !self.verify(
+            sender_key,
+            &[
+                VEILID_DOMAIN_SIGNCRYPT,
+                &signcrypted[0..SEALED_BOX_HEADER_LENGTH],
+                &key.bytes,
+                &data,
+            ]
+            .concat(),
+            &signature,
+        )? {
+            apibail_generic!("signcrypted box was not signed by sender");
+        }
+        Ok(data)
+    }
     // NoAuth Encrypt/Decrypt
     fn crypt_in_place_no_auth(&self, body: &mut [u8], nonce: &Nonce, shared_secret: &SharedSecret);
     fn crypt_b2b_no_auth(
@@ -103,3 +258,49 @@ pub trait CryptoSystem {
         shared_secret: &SharedSecret,
     ) -> Vec<u8>;
 }
+
+/// Build the header of a sealed or signcrypted box
+fn make_sealed_box_header<C: CryptoSystem + ?Sized>(
+    vcrypto: &C,
+    tag: u8,
+    ephemeral_key: &PublicKey,
+    nonce: &Nonce,
+) -> Vec<u8> {
+    let mut header = Vec::with_capacity(SEALED_BOX_HEADER_LENGTH);
+    header.push(tag);
+    header.push(SEALED_BOX_VERSION);
+    header.extend_from_slice(&vcrypto.kind().0);
+    header.extend_from_slice(&ephemeral_key.bytes);
+    header.extend_from_slice(&nonce.bytes);
+    header
+}
+
+/// Check the header of a sealed or signcrypted box matches this cryptosystem and wire format version
+fn parse_sealed_box_header<C: CryptoSystem + ?Sized>(
+    vcrypto: &C,
+    tag: u8,
+    data: &[u8],
+) -> VeilidAPIResult<SealedBoxHeader> {
+    if data.len() < SEALED_BOX_HEADER_LENGTH + vcrypto.aead_overhead() {
+        apibail_parse_error!("sealed box too short", data.len());
+    }
+    if data[0] != tag {
+        apibail_parse_error!("wrong kind of sealed box", data[0]);
+    }
+    if data[1] != SEALED_BOX_VERSION {
+        apibail_parse_error!("unsupported sealed box version", data[1]);
+    }
+    let kind_end = 2 + 4;
+    if data[2..kind_end] != vcrypto.kind().0 {
+        apibail_invalid_argument!(
+            "sealed box is for another cryptosystem",
+            "kind",
+            String::from_utf8_lossy(&data[2..kind_end])
+        );
+    }
+    let key_end = kind_end + PUBLIC_KEY_LENGTH;
+    Ok(SealedBoxHeader {
+        ephemeral_key: PublicKey::try_from(&data[kind_end..key_end])?,
+        nonce: Nonce::try_from(&data[key_end..SEALED_BOX_HEADER_LENGTH])?,
+    })
+}
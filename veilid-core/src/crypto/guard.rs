@@ -113,6 +113,14 @@ impl AsyncCryptoSystemGuard<'_> {
         yielding(|| self.guard.generate_keypair()).await
     }
 
+    pub async fn derive_keypair(
+        &self,
+        seed: &[u8],
+        path: &DerivationPath,
+    ) -> VeilidAPIResult<KeyPair> {
+        yielding(|| self.guard.derive_keypair(seed, path)).await
+    }
+
     pub async fn generate_hash(&self, data: &[u8]) -> HashDigest {
         yielding(|| self.guard.generate_hash(data)).await
     }
@@ -226,6 +234,68 @@ impl AsyncCryptoSystemGuard<'_> {
         .await
     }
 
+    pub async fn encrypt_aead_stream(
+        &self,
+        reader: &mut dyn std::io::Read,
+        writer: &mut dyn std::io::Write,
+        shared_secret: &SharedSecret,
+        associated_data: Option<&[u8]>,
+    ) -> VeilidAPIResult<()> {
+        yielding(|| {
+            self.guard
+                .encrypt_aead_stream(reader, writer, shared_secret, associated_data)
+        })
+        .await
+    }
+
+    pub async fn decrypt_aead_stream(
+        &self,
+        reader: &mut dyn std::io::Read,
+        writer: &mut dyn std::io::Write,
+        shared_secret: &SharedSecret,
+        associated_data: Option<&[u8]>,
+    ) -> VeilidAPIResult<()> {
+        yielding(|| {
+            self.guard
+                .decrypt_aead_stream(reader, writer, shared_secret, associated_data)
+        })
+        .await
+    }
+
+    // Sealed Boxes
+    pub async fn seal(&self, key: &PublicKey, body: &[u8]) -> VeilidAPIResult<Vec<u8>> {
+        yielding(|| self.guard.seal(key, body)).await
+    }
+
+    pub async fn open(
+        &self,
+        key: &PublicKey,
+        secret: &SecretKey,
+        sealed: &[u8],
+    ) -> VeilidAPIResult<Vec<u8>> {
+        yielding(|| self.guard.open(key, secret, sealed)).await
+    }
+
+    pub async fn signcrypt(
+        &self,
+        key: &PublicKey,
+        sender_key: &PublicKey,
+        sender_secret: &SecretKey,
+        body: &[u8],
+    ) -> VeilidAPIResult<Vec<u8>> {
+        yielding(|| self.guard.signcrypt(key, sender_key, sender_secret, body)).await
+    }
+
+    pub async fn unsigncrypt(
+        &self,
+        key: &PublicKey,
+        secret: &SecretKey,
+        sender_key: &PublicKey,
+        signcrypted: &[u8],
+    ) -> VeilidAPIResult<Vec<u8>> {
+        yielding(|| self.guard.unsigncrypt(key, secret, sender_key, signcrypted)).await
+    }
+
     // NoAuth Encrypt/Decrypt
     pub async fn crypt_in_place_no_auth(
         &self,
@@ -0,0 +1,71 @@
+use super::*;
+
+/// Number of words in a newly generated seed phrase
+pub const SEED_PHRASE_WORD_COUNT: usize = 24;
+/// Entropy behind a newly generated seed phrase, 32 bits per three words
+const SEED_PHRASE_ENTROPY_LENGTH: usize = 32;
+/// Length of the seed a seed phrase is stretched into
+pub const HD_SEED_LENGTH: usize = 64;
+/// Shortest seed that keys may be derived from
+pub const MIN_HD_SEED_LENGTH: usize = 16;
+
+/// Length of the secret and of the chain code at each level of the derivation
+const HD_SECRET_LENGTH: usize = 32;
+
+/// Generate a new random seed phrase to back up and restore derived keys with
+#[must_use]
+pub fn generate_seed_phrase() -> String {
+    let mut entropy = [0u8; SEED_PHRASE_ENTROPY_LENGTH];
+    random_bytes(&mut entropy);
+    // Entropy of a valid length can not fail
+    bip39::Mnemonic::from_entropy(&entropy)
+        .map(|m| m.to_string())
+        .unwrap_or_default()
+}
+
+/// Check a seed phrase and stretch it into a seed, along with an optional passphrase.
+/// Words are matched without regard to case or extra whitespace.
+pub fn seed_from_phrase(seed_phrase: &str, passphrase: &str) -> VeilidAPIResult<Vec<u8>> {
+    let normalized = seed_phrase
+        .split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mnemonic = bip39::Mnemonic::parse_normalized(&normalized)
+        .map_err(|e| VeilidAPIError::invalid_argument("seed_from_phrase", "seed_phrase", e))?;
+    Ok(mnemonic.to_seed_normalized(passphrase).to_vec())
+}
+
+/// Walk a derivation path down from a seed to the secret bytes a keypair is made from.
+/// Each level takes a keyed hash of its parent's secret and the index under the parent's chain code,
+/// starting from a root that is specific to the crypto kind so every kind derives unrelated keys.
+pub(crate) fn hd_derive_secret(
+    kind: CryptoKind,
+    seed: &[u8],
+    path: &DerivationPath,
+) -> VeilidAPIResult<[u8; HD_SECRET_LENGTH]> {
+    if seed.len() < MIN_HD_SEED_LENGTH {
+        apibail_invalid_argument!("seed is too short", "seed", seed.len());
+    }
+
+    let mut node = [0u8; HD_SECRET_LENGTH * 2];
+    let context = format!("veilid hd root {}", kind);
+    let mut hasher = blake3::Hasher::new_derive_key(&context);
+    hasher.update(seed);
+    hasher.finalize_xof().fill(&mut node);
+
+    for index in path.indices() {
+        let (secret, chain_code) = node.split_at(HD_SECRET_LENGTH);
+        let mut key = [0u8; HD_SECRET_LENGTH];
+        key.copy_from_slice(chain_code);
+        let mut hasher = blake3::Hasher::new_keyed(&key);
+        hasher.update(&[0u8]);
+        hasher.update(secret);
+        hasher.update(&index.to_be_bytes());
+        hasher.finalize_xof().fill(&mut node);
+    }
+
+    let mut secret = [0u8; HD_SECRET_LENGTH];
+    secret.copy_from_slice(&node[0..HD_SECRET_LENGTH]);
+    Ok(secret)
+}
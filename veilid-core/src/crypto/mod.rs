@@ -1,23 +1,29 @@
+mod aead_stream;
 mod blake3digest512;
 mod dh_cache;
 mod envelope;
 mod guard;
+mod hd;
 mod receipt;
 mod types;
 
 pub mod crypto_system;
 #[cfg(feature = "enable-crypto-none")]
 pub(crate) mod none;
+#[cfg(feature = "enable-crypto-pqh0")]
+pub mod pqh0;
 #[doc(hidden)]
 pub mod tests;
 #[cfg(feature = "enable-crypto-vld0")]
 pub(crate) mod vld0;
 
+pub use aead_stream::*;
 pub use blake3digest512::*;
 
 pub use crypto_system::*;
 pub(crate) use envelope::*;
 pub use guard::*;
+pub use hd::*;
 pub(crate) use receipt::*;
 pub use types::*;
 
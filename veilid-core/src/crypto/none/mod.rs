@@ -130,6 +130,14 @@ impl CryptoSystem for CryptoSystemNONE {
     fn generate_keypair(&self) -> KeyPair {
         none_generate_keypair()
     }
+    fn derive_keypair(&self, seed: &[u8], path: &DerivationPath) -> VeilidAPIResult<KeyPair> {
+        let pub_bytes = hd_derive_secret(self.kind(), seed, path)?;
+        let sec_bytes = pub_bytes.map(|b| !b);
+        Ok(KeyPair::new(
+            PublicKey::new(pub_bytes),
+            SecretKey::new(sec_bytes),
+        ))
+    }
     fn generate_hash(&self, data: &[u8]) -> PublicKey {
         PublicKey::new(*blake3::hash(data).as_bytes())
     }
@@ -0,0 +1,292 @@
+//! Hybrid post-quantum primitives: X25519 + ML-KEM-768 key agreement and Ed25519 + ML-DSA-65 signatures
+//!
+//! Everything here is derived from a single 32 byte Ed25519 secret, so a PQH0 keypair has the same
+//! shape as a VLD0 keypair and can be stored and typed the same way. The post-quantum halves have
+//! much larger public keys, ciphertexts and signatures than the fixed-width `PublicKey` and
+//! `Signature` types can hold, so they travel in a [PQH0PublicBundle] and as variable length byte
+//! vectors. Key agreement is a KEM rather than a static DH: the sender produces a ciphertext that
+//! must be delivered along with the message.
+//!
+//! A secret is only as strong as the stronger of its two halves being unbroken: the shared secret
+//! mixes both the X25519 and ML-KEM results, and a signature only verifies if both the Ed25519 and
+//! ML-DSA signatures do.
+//!
+//! PQH0 is deliberately not one of the [VALID_CRYPTO_KINDS]: node ids, envelopes and DHT records
+//! only carry fixed-width keys and signatures, and a PQH0 kind that fell back to its classical
+//! halves there would claim protection it does not give.
+
+use super::*;
+
+use curve25519_dalek::digest::Digest;
+use ed25519_dalek as ed;
+use ml_dsa::signature::{Signer, Verifier};
+use ml_dsa::{KeyGen, MlDsa65};
+use ml_kem::kem::{Decapsulate, Encapsulate};
+use ml_kem::{EncodedSizeUser, KemCore, MlKem768};
+use x25519_dalek as xd;
+
+const VEILID_DOMAIN_SIGN: &[u8] = b"PQH0_SIGN";
+const VEILID_DOMAIN_CRYPT: &[u8] = b"PQH0_CRYPT";
+
+const KDF_CONTEXT_KEM_D: &str = "veilid PQH0 2025-01-01 ml-kem-768 d";
+const KDF_CONTEXT_KEM_Z: &str = "veilid PQH0 2025-01-01 ml-kem-768 z";
+const KDF_CONTEXT_DSA_XI: &str = "veilid PQH0 2025-01-01 ml-dsa-65 xi";
+
+pub const CRYPTO_KIND_PQH0: CryptoKind = CryptoKind(*b"PQH0");
+
+/// Length of an encoded ML-KEM-768 encapsulation key
+pub const PQH0_KEM_PUBLIC_KEY_LENGTH: usize = 1184;
+/// Length of an encoded ML-KEM-768 ciphertext
+pub const PQH0_KEM_CIPHERTEXT_LENGTH: usize = 1088;
+/// Length of an encoded ML-DSA-65 verifying key
+pub const PQH0_DSA_PUBLIC_KEY_LENGTH: usize = 1952;
+/// Length of an encoded ML-DSA-65 signature
+pub const PQH0_DSA_SIGNATURE_LENGTH: usize = 3309;
+/// Length of a hybrid signature, the Ed25519 signature followed by the ML-DSA-65 signature
+pub const PQH0_SIGNATURE_LENGTH: usize = SIGNATURE_LENGTH + PQH0_DSA_SIGNATURE_LENGTH;
+
+type KemEncapsulationKey = <MlKem768 as KemCore>::EncapsulationKey;
+type KemDecapsulationKey = <MlKem768 as KemCore>::DecapsulationKey;
+
+/// All the public halves of a PQH0 keypair
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct PQH0PublicBundle {
+    /// The Ed25519 public key, also used for X25519
+    pub key: PublicKey,
+    /// The encoded ML-KEM-768 encapsulation key
+    #[serde(with = "as_human_base64")]
+    pub kem_key: Vec<u8>,
+    /// The encoded ML-DSA-65 verifying key
+    #[serde(with = "as_human_base64")]
+    pub dsa_key: Vec<u8>,
+}
+
+impl PQH0PublicBundle {
+    /// A hash of the whole bundle, suitable for pinning it where only a fixed-width key fits
+    pub fn fingerprint(&self) -> HashDigest {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.key.bytes);
+        hasher.update(&self.kem_key);
+        hasher.update(&self.dsa_key);
+        HashDigest::new(*hasher.finalize().as_bytes())
+    }
+
+    fn kem_encapsulation_key(&self) -> VeilidAPIResult<KemEncapsulationKey> {
+        let encoded = ml_kem::Encoded::<KemEncapsulationKey>::try_from(self.kem_key.as_slice())
+            .map_err(|_| VeilidAPIError::parse_error("invalid ML-KEM key length", ""))?;
+        Ok(KemEncapsulationKey::from_bytes(&encoded))
+    }
+
+    fn dsa_verifying_key(&self) -> VeilidAPIResult<Box<ml_dsa::VerifyingKey<MlDsa65>>> {
+        let encoded = ml_dsa::EncodedVerifyingKey::<MlDsa65>::try_from(self.dsa_key.as_slice())
+            .map_err(|_| VeilidAPIError::parse_error("invalid ML-DSA key length", ""))?;
+        Ok(Box::new(ml_dsa::VerifyingKey::<MlDsa65>::decode(&encoded)))
+    }
+}
+
+fn public_to_x25519_pk(public: &PublicKey) -> VeilidAPIResult<xd::PublicKey> {
+    let pk_ed = ed::VerifyingKey::from_bytes(&public.bytes).map_err(VeilidAPIError::internal)?;
+    Ok(xd::PublicKey::from(*pk_ed.to_montgomery().as_bytes()))
+}
+
+fn secret_to_x25519_sk(secret: &SecretKey) -> xd::StaticSecret {
+    // NOTE: ed::SigningKey.to_scalar() does not produce an unreduced scalar, we want the raw bytes here
+    // See https://github.com/dalek-cryptography/curve25519-dalek/issues/565
+    let hash: [u8; SIGNATURE_LENGTH] = ed::Sha512::default()
+        .chain_update(secret.bytes)
+        .finalize()
+        .into();
+    let mut output = [0u8; SECRET_KEY_LENGTH];
+    output.copy_from_slice(&hash[..SECRET_KEY_LENGTH]);
+
+    xd::StaticSecret::from(output)
+}
+
+fn secret_to_kem_keys(secret: &SecretKey) -> (KemDecapsulationKey, KemEncapsulationKey) {
+    let d = ml_kem::B32::from(blake3::derive_key(KDF_CONTEXT_KEM_D, &secret.bytes));
+    let z = ml_kem::B32::from(blake3::derive_key(KDF_CONTEXT_KEM_Z, &secret.bytes));
+    MlKem768::generate_deterministic(&d, &z)
+}
+
+// ML-DSA keys are tens of kilobytes, keep them off the stack of the callers
+fn secret_to_dsa_keys(secret: &SecretKey) -> Box<ml_dsa::KeyPair<MlDsa65>> {
+    let xi = ml_dsa::B32::from(blake3::derive_key(KDF_CONTEXT_DSA_XI, &secret.bytes));
+    Box::new(MlDsa65::key_gen_internal(&xi))
+}
+
+fn combine_shared_secrets(
+    kem_shared: &[u8],
+    dh_shared: &[u8],
+    ciphertext: &[u8],
+    recipient: &PublicKey,
+    sender: &PublicKey,
+    domain: &[u8],
+) -> SharedSecret {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(VEILID_DOMAIN_CRYPT);
+    hasher.update(kem_shared);
+    hasher.update(dh_shared);
+    hasher.update(ciphertext);
+    hasher.update(&recipient.bytes);
+    hasher.update(&sender.bytes);
+    hasher.update(domain);
+    SharedSecret::new(*hasher.finalize().as_bytes())
+}
+
+/// Generate a PQH0 keypair. The post-quantum keys are derived from its secret.
+pub fn pqh0_generate_keypair() -> KeyPair {
+    let mut csprng = VeilidRng {};
+    let signing_key = ed::SigningKey::generate(&mut csprng);
+    let verifying_key = signing_key.verifying_key();
+    let public_key = PublicKey::new(verifying_key.to_bytes());
+    let secret_key = SecretKey::new(signing_key.to_bytes());
+
+    KeyPair::new(public_key, secret_key)
+}
+
+/// Derive all the public halves of a PQH0 keypair from its secret
+pub fn pqh0_public_bundle(secret: &SecretKey) -> PQH0PublicBundle {
+    let key = PublicKey::new(
+        ed::SigningKey::from_bytes(&secret.bytes)
+            .verifying_key()
+            .to_bytes(),
+    );
+    let (_, kem_ek) = secret_to_kem_keys(secret);
+    let dsa_kp = secret_to_dsa_keys(secret);
+
+    PQH0PublicBundle {
+        key,
+        kem_key: kem_ek.as_bytes().to_vec(),
+        dsa_key: dsa_kp.verifying_key().encode().to_vec(),
+    }
+}
+
+/// Agree on a shared secret with the holder of `recipient`
+///
+/// Returns the shared secret and the ciphertext the recipient needs to recover it.
+pub fn pqh0_encapsulate(
+    recipient: &PQH0PublicBundle,
+    sender_secret: &SecretKey,
+    domain: &[u8],
+) -> VeilidAPIResult<(SharedSecret, Vec<u8>)> {
+    let sender = PublicKey::new(
+        ed::SigningKey::from_bytes(&sender_secret.bytes)
+            .verifying_key()
+            .to_bytes(),
+    );
+
+    let dh_shared = secret_to_x25519_sk(sender_secret)
+        .diffie_hellman(&public_to_x25519_pk(&recipient.key)?)
+        .to_bytes();
+
+    let mut csprng = VeilidRng {};
+    let (ciphertext, kem_shared) = recipient
+        .kem_encapsulation_key()?
+        .encapsulate(&mut csprng)
+        .map_err(|_| VeilidAPIError::internal("ML-KEM encapsulation failed"))?;
+
+    let shared_secret = combine_shared_secrets(
+        &kem_shared,
+        &dh_shared,
+        &ciphertext,
+        &recipient.key,
+        &sender,
+        domain,
+    );
+    Ok((shared_secret, ciphertext.to_vec()))
+}
+
+/// Recover the shared secret that `sender` agreed on with us in [pqh0_encapsulate]
+pub fn pqh0_decapsulate(
+    recipient_secret: &SecretKey,
+    sender: &PublicKey,
+    ciphertext: &[u8],
+    domain: &[u8],
+) -> VeilidAPIResult<SharedSecret> {
+    if ciphertext.len() != PQH0_KEM_CIPHERTEXT_LENGTH {
+        apibail_parse_error!("invalid ML-KEM ciphertext length", ciphertext.len());
+    }
+    let recipient = PublicKey::new(
+        ed::SigningKey::from_bytes(&recipient_secret.bytes)
+            .verifying_key()
+            .to_bytes(),
+    );
+
+    let dh_shared = secret_to_x25519_sk(recipient_secret)
+        .diffie_hellman(&public_to_x25519_pk(sender)?)
+        .to_bytes();
+
+    let ct = ml_kem::Ciphertext::<MlKem768>::try_from(ciphertext)
+        .map_err(|_| VeilidAPIError::parse_error("invalid ML-KEM ciphertext length", ""))?;
+    let (kem_dk, _) = secret_to_kem_keys(recipient_secret);
+    let kem_shared = kem_dk
+        .decapsulate(&ct)
+        .map_err(|_| VeilidAPIError::internal("ML-KEM decapsulation failed"))?;
+
+    Ok(combine_shared_secrets(
+        &kem_shared,
+        &dh_shared,
+        ciphertext,
+        &recipient,
+        sender,
+        domain,
+    ))
+}
+
+/// Sign with both halves of a PQH0 keypair
+pub fn pqh0_sign(secret: &SecretKey, data: &[u8]) -> VeilidAPIResult<Vec<u8>> {
+    let signing_key = ed::SigningKey::from_bytes(&secret.bytes);
+    let mut dig: ed::Sha512 = ed::Sha512::default();
+    dig.update(data);
+    let ed_sig = signing_key
+        .sign_prehashed(dig, Some(VEILID_DOMAIN_SIGN))
+        .map_err(VeilidAPIError::internal)?;
+
+    let dsa_kp = secret_to_dsa_keys(secret);
+    let dsa_sig = dsa_kp
+        .signing_key()
+        .try_sign(&[VEILID_DOMAIN_SIGN, data].concat())
+        .map_err(VeilidAPIError::internal)?;
+
+    let mut out = Vec::with_capacity(PQH0_SIGNATURE_LENGTH);
+    out.extend_from_slice(&ed_sig.to_bytes());
+    out.extend_from_slice(&dsa_sig.encode());
+    Ok(out)
+}
+
+/// Verify a hybrid signature, both halves must be valid
+pub fn pqh0_verify(
+    public: &PQH0PublicBundle,
+    data: &[u8],
+    signature: &[u8],
+) -> VeilidAPIResult<bool> {
+    if signature.len() != PQH0_SIGNATURE_LENGTH {
+        apibail_parse_error!("invalid PQH0 signature length", signature.len());
+    }
+    let (ed_sig_bytes, dsa_sig_bytes) = signature.split_at(SIGNATURE_LENGTH);
+
+    let pk = ed::VerifyingKey::from_bytes(&public.key.bytes)
+        .map_err(|e| VeilidAPIError::parse_error("Public key is invalid", e))?;
+    let ed_sig = ed::Signature::from_slice(ed_sig_bytes)
+        .map_err(|e| VeilidAPIError::parse_error("Signature is invalid", e))?;
+    let mut dig: ed::Sha512 = ed::Sha512::default();
+    dig.update(data);
+    if pk
+        .verify_prehashed_strict(dig, Some(VEILID_DOMAIN_SIGN), &ed_sig)
+        .is_err()
+    {
+        return Ok(false);
+    }
+
+    let Ok(encoded_dsa_sig) = ml_dsa::EncodedSignature::<MlDsa65>::try_from(dsa_sig_bytes) else {
+        return Ok(false);
+    };
+    let Some(dsa_sig) = ml_dsa::Signature::<MlDsa65>::decode(&encoded_dsa_sig) else {
+        return Ok(false);
+    };
+    Ok(public
+        .dsa_verifying_key()?
+        .verify(&[VEILID_DOMAIN_SIGN, data].concat(), &dsa_sig)
+        .is_ok())
+}
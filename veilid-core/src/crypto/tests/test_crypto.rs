@@ -96,6 +96,95 @@ pub async fn test_aead(vcrypto: &AsyncCryptoSystemGuard<'_>) {
     assert_eq!(body5, body7);
 }
 
+pub async fn test_aead_stream(vcrypto: &AsyncCryptoSystemGuard<'_>) {
+    trace!("test_aead_stream");
+    let ss1 = vcrypto.random_shared_secret().await;
+    let ss2 = vcrypto.random_shared_secret().await;
+    let full_chunk_len =
+        AEAD_STREAM_HEADER_LENGTH + AEAD_STREAM_CHUNK_SIZE + vcrypto.aead_overhead();
+
+    for len in [
+        0,
+        1,
+        AEAD_STREAM_CHUNK_SIZE - 1,
+        AEAD_STREAM_CHUNK_SIZE,
+        AEAD_STREAM_CHUNK_SIZE + 1,
+        3 * AEAD_STREAM_CHUNK_SIZE + 17,
+    ] {
+        let body = vcrypto.random_bytes(len as u32).await;
+
+        let mut encrypted = Vec::new();
+        vcrypto
+            .encrypt_aead_stream(&mut body.as_slice(), &mut encrypted, &ss1, Some(b"ad"))
+            .await
+            .unwrap();
+
+        let mut decrypted = Vec::new();
+        vcrypto
+            .decrypt_aead_stream(&mut encrypted.as_slice(), &mut decrypted, &ss1, Some(b"ad"))
+            .await
+            .expect("should decrypt");
+        assert_eq!(body, decrypted);
+
+        // wrong secret
+        assert!(vcrypto
+            .decrypt_aead_stream(
+                &mut encrypted.as_slice(),
+                &mut Vec::new(),
+                &ss2,
+                Some(b"ad")
+            )
+            .await
+            .is_err());
+
+        // truncated
+        assert!(vcrypto
+            .decrypt_aead_stream(
+                &mut &encrypted[0..encrypted.len() - 1],
+                &mut Vec::new(),
+                &ss1,
+                Some(b"ad")
+            )
+            .await
+            .is_err());
+        if encrypted.len() > full_chunk_len {
+            assert!(vcrypto
+                .decrypt_aead_stream(
+                    &mut &encrypted[0..full_chunk_len],
+                    &mut Vec::new(),
+                    &ss1,
+                    Some(b"ad")
+                )
+                .await
+                .is_err());
+        }
+
+        // extended
+        let mut extended = encrypted.clone();
+        extended.push(0);
+        assert!(vcrypto
+            .decrypt_aead_stream(&mut extended.as_slice(), &mut Vec::new(), &ss1, Some(b"ad"))
+            .await
+            .is_err());
+    }
+
+    // adapters
+    let crypto = vcrypto.crypto();
+    let csv = crypto.get(vcrypto.kind()).unwrap();
+    let body = vcrypto
+        .random_bytes(AEAD_STREAM_CHUNK_SIZE as u32 * 2)
+        .await;
+    let mut writer = AeadStreamWriter::new(&*csv, Vec::new(), &ss1, None);
+    for part in body.chunks(1000) {
+        std::io::Write::write_all(&mut writer, part).unwrap();
+    }
+    let encrypted = writer.finish().unwrap();
+    let mut reader = AeadStreamReader::new(&*csv, encrypted.as_slice(), &ss1, None);
+    let mut decrypted = Vec::new();
+    std::io::Read::read_to_end(&mut reader, &mut decrypted).unwrap();
+    assert_eq!(body, decrypted);
+}
+
 pub async fn test_no_auth(vcrypto: &AsyncCryptoSystemGuard<'_>) {
     trace!("test_no_auth");
 
@@ -198,6 +287,64 @@ pub async fn test_dh(vcrypto: &AsyncCryptoSystemGuard<'_>) {
     trace!("cached_dh: {:?}", r5);
 }
 
+pub async fn test_sealed_box(vcrypto: &AsyncCryptoSystemGuard<'_>) {
+    trace!("test_sealed_box");
+    let (key, secret) = vcrypto.generate_keypair().await.into_split();
+    let (key2, secret2) = vcrypto.generate_keypair().await.into_split();
+    let (sender_key, sender_secret) = vcrypto.generate_keypair().await.into_split();
+    let body = b"Ever heard of the sealed box? It's a box, sealed.".to_vec();
+
+    // seal and open
+    let sealed = vcrypto.seal(&key, &body).await.unwrap();
+    assert_eq!(sealed[0], SEALED_BOX_TAG);
+    assert_eq!(sealed[1], SEALED_BOX_VERSION);
+    assert_eq!(
+        sealed.len(),
+        SEALED_BOX_HEADER_LENGTH + body.len() + vcrypto.aead_overhead()
+    );
+    assert_eq!(vcrypto.open(&key, &secret, &sealed).await.unwrap(), body);
+    assert!(vcrypto.open(&key2, &secret2, &sealed).await.is_err());
+    assert_ne!(vcrypto.seal(&key, &body).await.unwrap(), sealed);
+
+    // wire format checks
+    let mut bad_version = sealed.clone();
+    bad_version[1] = SEALED_BOX_VERSION + 1;
+    assert!(vcrypto.open(&key, &secret, &bad_version).await.is_err());
+    assert!(vcrypto
+        .open(&key, &secret, &sealed[0..SEALED_BOX_HEADER_LENGTH])
+        .await
+        .is_err());
+
+    // signcrypt and unsigncrypt
+    let signcrypted = vcrypto
+        .signcrypt(&key, &sender_key, &sender_secret, &body)
+        .await
+        .unwrap();
+    assert_eq!(signcrypted[0], SIGNCRYPTED_BOX_TAG);
+    assert_eq!(
+        vcrypto
+            .unsigncrypt(&key, &secret, &sender_key, &signcrypted)
+            .await
+            .unwrap(),
+        body
+    );
+    assert!(vcrypto
+        .unsigncrypt(&key, &secret, &key2, &signcrypted)
+        .await
+        .is_err());
+    assert!(vcrypto
+        .unsigncrypt(&key2, &secret2, &sender_key, &signcrypted)
+        .await
+        .is_err());
+
+    // sealed and signcrypted boxes are not interchangeable
+    assert!(vcrypto.open(&key, &secret, &signcrypted).await.is_err());
+    assert!(vcrypto
+        .unsigncrypt(&key, &secret, &sender_key, &sealed)
+        .await
+        .is_err());
+}
+
 pub async fn test_generation(vcrypto: &AsyncCryptoSystemGuard<'_>) {
     let b1 = vcrypto.random_bytes(32).await;
     let b2 = vcrypto.random_bytes(32).await;
@@ -262,6 +409,107 @@ pub async fn test_generation(vcrypto: &AsyncCryptoSystemGuard<'_>) {
         .expect_err("should reject long salt");
 }
 
+pub async fn test_derivation(vcrypto: &AsyncCryptoSystemGuard<'_>) {
+    let phrase = generate_seed_phrase();
+    assert_eq!(phrase.split_whitespace().count(), SEED_PHRASE_WORD_COUNT);
+    assert_ne!(phrase, generate_seed_phrase());
+
+    let seed = seed_from_phrase(&phrase, "").unwrap();
+    assert_eq!(seed.len(), HD_SEED_LENGTH);
+    let messy = format!("  {}\n", phrase.to_uppercase().replace(' ', "   "));
+    assert_eq!(seed, seed_from_phrase(&messy, "").unwrap());
+    assert_ne!(seed, seed_from_phrase(&phrase, "passphrase").unwrap());
+    let _ =
+        seed_from_phrase("abandon abandon abandon", "").expect_err("should reject short phrase");
+    let _ = seed_from_phrase(&format!("{} veilid", phrase), "")
+        .expect_err("should reject unknown word");
+
+    // Derivation is deterministic and every path gives an independent valid keypair
+    let path1 = DerivationPath::record_writer(0, 1);
+    let kp1 = vcrypto.derive_keypair(&seed, &path1).await.unwrap();
+    assert_eq!(kp1, vcrypto.derive_keypair(&seed, &path1).await.unwrap());
+    assert!(vcrypto.validate_keypair(&kp1.key, &kp1.secret).await);
+    let paths = [
+        DerivationPath::default(),
+        DerivationPath::node_id(0),
+        DerivationPath::node_id(1),
+        DerivationPath::app_identity(0),
+        DerivationPath::app_identity(0).child(1),
+        DerivationPath::record_writer(0, 2),
+        DerivationPath::record_writer(1, 1),
+    ];
+    for path in paths {
+        let kp = vcrypto.derive_keypair(&seed, &path).await.unwrap();
+        assert_ne!(kp, kp1);
+        assert!(vcrypto.validate_keypair(&kp.key, &kp.secret).await);
+    }
+    let other_seed = seed_from_phrase(&phrase, "passphrase").unwrap();
+    assert_ne!(
+        kp1,
+        vcrypto.derive_keypair(&other_seed, &path1).await.unwrap()
+    );
+    let _ = vcrypto
+        .derive_keypair(&seed[0..8], &path1)
+        .await
+        .expect_err("should reject short seed");
+
+    // Paths round trip through their string form
+    assert_eq!(path1.to_string(), "m/2'/0'/1'");
+    assert_eq!(DerivationPath::from_str("m/2'/0'/1'").unwrap(), path1);
+    assert_eq!(DerivationPath::from_str("m/2/0/1").unwrap(), path1);
+    assert_eq!(
+        DerivationPath::from_str("m").unwrap(),
+        DerivationPath::default()
+    );
+    let _ = DerivationPath::from_str("2'/0'").expect_err("should require root");
+    let _ = DerivationPath::from_str("m/x'").expect_err("should reject bad index");
+    let _ = DerivationPath::from_str("m/4294967296'").expect_err("should reject big index");
+    let json = serialize_json(&path1);
+    assert_eq!(deserialize_json::<DerivationPath>(&json).unwrap(), path1);
+}
+
+#[cfg(feature = "enable-crypto-pqh0")]
+pub fn test_pqh0() {
+    use crate::crypto::pqh0::*;
+
+    let kp1 = pqh0_generate_keypair();
+    let kp2 = pqh0_generate_keypair();
+    let bundle1 = pqh0_public_bundle(&kp1.secret);
+    let bundle2 = pqh0_public_bundle(&kp2.secret);
+    assert_eq!(bundle1.key, kp1.key);
+    assert_eq!(bundle1.kem_key.len(), PQH0_KEM_PUBLIC_KEY_LENGTH);
+    assert_eq!(bundle1.dsa_key.len(), PQH0_DSA_PUBLIC_KEY_LENGTH);
+    assert_eq!(bundle1, pqh0_public_bundle(&kp1.secret));
+    assert_ne!(bundle1.fingerprint(), bundle2.fingerprint());
+
+    // Key agreement
+    let (ss1, ct) = pqh0_encapsulate(&bundle2, &kp1.secret, b"test").unwrap();
+    assert_eq!(ct.len(), PQH0_KEM_CIPHERTEXT_LENGTH);
+    let ss2 = pqh0_decapsulate(&kp2.secret, &kp1.key, &ct, b"test").unwrap();
+    assert_eq!(ss1, ss2);
+    let ss3 = pqh0_decapsulate(&kp2.secret, &kp1.key, &ct, b"other").unwrap();
+    assert_ne!(ss1, ss3);
+    let ss4 = pqh0_decapsulate(&kp1.secret, &kp2.key, &ct, b"test").unwrap();
+    assert_ne!(ss1, ss4);
+    let _ = pqh0_decapsulate(&kp2.secret, &kp1.key, &ct[1..], b"test")
+        .expect_err("should reject short ciphertext");
+
+    // Signatures
+    let sig = pqh0_sign(&kp1.secret, b"message").unwrap();
+    assert_eq!(sig.len(), PQH0_SIGNATURE_LENGTH);
+    assert!(pqh0_verify(&bundle1, b"message", &sig).unwrap());
+    assert!(!pqh0_verify(&bundle1, b"massage", &sig).unwrap());
+    assert!(!pqh0_verify(&bundle2, b"message", &sig).unwrap());
+
+    // Both halves must verify
+    let mut bad_ed = sig.clone();
+    bad_ed[0] ^= 1;
+    assert!(!pqh0_verify(&bundle1, b"message", &bad_ed).unwrap());
+    let mut bad_dsa = sig.clone();
+    bad_dsa[SIGNATURE_LENGTH + 1] ^= 1;
+    assert!(!pqh0_verify(&bundle1, b"message", &bad_dsa).unwrap());
+}
+
 pub async fn test_all() {
     let api = crypto_tests_startup().await;
     let crypto = api.crypto().unwrap();
@@ -270,10 +518,15 @@ pub async fn test_all() {
     for v in VALID_CRYPTO_KINDS {
         let vcrypto = crypto.get_async(v).unwrap();
         test_aead(&vcrypto).await;
+        test_aead_stream(&vcrypto).await;
         test_no_auth(&vcrypto).await;
         test_dh(&vcrypto).await;
+        test_sealed_box(&vcrypto).await;
         test_generation(&vcrypto).await;
+        test_derivation(&vcrypto).await;
     }
+    #[cfg(feature = "enable-crypto-pqh0")]
+    test_pqh0();
 
     crypto_tests_shutdown(api.clone()).await;
     assert!(api.is_shutdown());
@@ -0,0 +1,122 @@
+use super::*;
+
+/// Purpose of keypairs derived for node ids
+const DERIVATION_PURPOSE_NODE_ID: u32 = 0;
+/// Purpose of keypairs derived for application identities
+const DERIVATION_PURPOSE_APP_IDENTITY: u32 = 1;
+/// Purpose of keypairs derived for DHT record writers
+const DERIVATION_PURPOSE_RECORD_WRITER: u32 = 2;
+
+/// Path from the root of a seed to a derived keypair, written as `m/1'/0'`.
+/// Every level is hardened, so a derived secret reveals nothing about its parent or its siblings.
+/// The trailing `'` is optional when parsing.
+#[derive(Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct DerivationPath {
+    indices: Vec<u32>,
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(target_arch = "wasm32", target_os = "unknown"))] {
+        #[wasm_bindgen(typescript_custom_section)]
+        const DERIVATIONPATH_TYPE: &'static str = r#"
+export type DerivationPath = string;
+"#;
+    }
+}
+
+impl DerivationPath {
+    pub fn new(indices: Vec<u32>) -> Self {
+        Self { indices }
+    }
+
+    /// Path of the node id keypair with the given index
+    pub fn node_id(index: u32) -> Self {
+        Self::new(vec![DERIVATION_PURPOSE_NODE_ID, index])
+    }
+
+    /// Path of the identity keypair of the application with the given index
+    pub fn app_identity(app: u32) -> Self {
+        Self::new(vec![DERIVATION_PURPOSE_APP_IDENTITY, app])
+    }
+
+    /// Path of the writer keypair for the given record of the application with the given index
+    pub fn record_writer(app: u32, record: u32) -> Self {
+        Self::new(vec![DERIVATION_PURPOSE_RECORD_WRITER, app, record])
+    }
+
+    /// Path one level below this one
+    pub fn child(&self, index: u32) -> Self {
+        let mut indices = self.indices.clone();
+        indices.push(index);
+        Self::new(indices)
+    }
+
+    #[must_use]
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.indices {
+            write!(f, "/{}'", index)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DerivationPath({})", self)
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = VeilidAPIError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('/');
+        if parts.next() != Some("m") {
+            apibail_parse_error!("derivation path must start with 'm'", s);
+        }
+        let mut indices = Vec::new();
+        for part in parts {
+            let digits = part.strip_suffix('\'').unwrap_or(part);
+            let index = digits
+                .parse::<u32>()
+                .map_err(|_| VeilidAPIError::parse_error("invalid derivation path index", part))?;
+            indices.push(index);
+        }
+        Ok(Self::new(indices))
+    }
+}
+
+impl TryFrom<&str> for DerivationPath {
+    type Error = VeilidAPIError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str(value)
+    }
+}
+
+impl serde::Serialize for DerivationPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let s = self.to_string();
+        serde::Serialize::serialize(&s, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DerivationPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        DerivationPath::from_str(s.as_str()).map_err(serde::de::Error::custom)
+    }
+}
@@ -45,11 +45,13 @@ pub fn common_crypto_kinds(a: &[CryptoKind], b: &[CryptoKind]) -> Vec<CryptoKind
 mod byte_array_types;
 mod crypto_typed;
 mod crypto_typed_group;
+mod derivation_path;
 mod keypair;
 
 pub use byte_array_types::*;
 pub use crypto_typed::*;
 pub use crypto_typed_group::*;
+pub use derivation_path::*;
 pub use keypair::*;
 
 #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), declare)]
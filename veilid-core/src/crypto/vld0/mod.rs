@@ -162,6 +162,16 @@ impl CryptoSystem for CryptoSystemVLD0 {
         vld0_generate_keypair()
     }
 
+    #[instrument(level = "trace", target = "crypto", skip_all)]
+    fn derive_keypair(&self, seed: &[u8], path: &DerivationPath) -> VeilidAPIResult<KeyPair> {
+        let secret = hd_derive_secret(self.kind(), seed, path)?;
+        let signing_key = ed::SigningKey::from_bytes(&secret);
+        let public_key = PublicKey::new(signing_key.verifying_key().to_bytes());
+        let secret_key = SecretKey::new(signing_key.to_bytes());
+
+        Ok(KeyPair::new(public_key, secret_key))
+    }
+
     #[instrument(level = "trace", target = "crypto", skip_all)]
     fn generate_hash(&self, data: &[u8]) -> HashDigest {
         HashDigest::new(*blake3::hash(data).as_bytes())
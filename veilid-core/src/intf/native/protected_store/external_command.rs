@@ -0,0 +1,93 @@
+use super::*;
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+/// Exit status the command uses to say there is no secret for the service and key
+const EXIT_NOT_FOUND: i32 = 2;
+
+/// Secrets kept by an external program, such as a bridge to a PKCS#11 token or HSM.
+/// The program is run once per operation with its configured arguments followed by
+/// `get <service> <key>`, `set <service> <key>` or `delete <service> <key>`.
+/// Values are read from its standard output for `get` and written to its standard input for `set`,
+/// so they never appear on a command line. It exits with 0 on success, and with 2 from `get` or `delete`
+/// when there is no such secret.
+pub(super) struct ExternalCommandBackend {
+    program: String,
+    args: Vec<String>,
+}
+
+impl ExternalCommandBackend {
+    pub fn new(command: &[String]) -> EyreResult<Self> {
+        let Some((program, args)) = command.split_first() else {
+            bail!("external command must not be empty");
+        };
+        Ok(Self {
+            program: program.clone(),
+            args: args.to_vec(),
+        })
+    }
+
+    /// Run one operation, returning its standard output, or None if the secret was not found
+    fn run(
+        &self,
+        op: &str,
+        service: &str,
+        key: &str,
+        input: Option<&str>,
+    ) -> EyreResult<Option<String>> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .args([op, service, key])
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .wrap_err_with(|| format!("failed to run '{}'", self.program))?;
+        if let Some(input) = input {
+            let mut stdin = child.stdin.take().expect("stdin should be piped");
+            stdin
+                .write_all(input.as_bytes())
+                .wrap_err("failed to send secret to external command")?;
+        }
+        let output = child
+            .wait_with_output()
+            .wrap_err("failed to wait for external command")?;
+
+        match output.status.code() {
+            Some(0) => {}
+            Some(EXIT_NOT_FOUND) if op != "set" => return Ok(None),
+            _ => bail!(
+                "external command '{}' failed with {}: {}",
+                op,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+        let mut out =
+            String::from_utf8(output.stdout).wrap_err("external command output is not text")?;
+        if out.ends_with('\n') {
+            out.pop();
+        }
+        Ok(Some(out))
+    }
+}
+
+impl ProtectedStoreBackend for ExternalCommandBackend {
+    fn save(&self, service: &str, key: &str, value: &str) -> EyreResult<bool> {
+        let existed = self.run("get", service, key, None)?.is_some();
+        self.run("set", service, key, Some(value))?;
+        Ok(existed)
+    }
+
+    fn load(&self, service: &str, key: &str) -> EyreResult<Option<String>> {
+        self.run("get", service, key, None)
+    }
+
+    fn remove(&self, service: &str, key: &str) -> EyreResult<bool> {
+        Ok(self.run("delete", service, key, None)?.is_some())
+    }
+}
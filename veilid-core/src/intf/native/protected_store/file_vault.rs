@@ -0,0 +1,173 @@
+use super::*;
+use argon2::Argon2;
+use chacha20poly1305 as ch;
+use chacha20poly1305::aead::{Aead, Payload};
+use chacha20poly1305::KeyInit;
+
+const VAULT_MAGIC: &[u8; 4] = b"VPSV";
+const VAULT_VERSION: u8 = 1;
+const VAULT_SALT_LENGTH: usize = 16;
+const VAULT_NONCE_LENGTH: usize = 24;
+/// Magic, version and salt
+const VAULT_HEADER_LENGTH: usize = 4 + 1 + VAULT_SALT_LENGTH;
+
+/// Secrets by service name and key
+type VaultEntries = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Secrets kept in a single file, encrypted with a key derived from a password using Argon2.
+/// The file is a header holding the salt, then a nonce, then the AEAD encrypted entries bound to the header.
+/// Every change rewrites the whole file with a fresh nonce.
+pub(super) struct FileVaultBackend {
+    path: PathBuf,
+    header: [u8; VAULT_HEADER_LENGTH],
+    key: ch::Key,
+    entries: Mutex<VaultEntries>,
+}
+
+impl FileVaultBackend {
+    /// Open the vault at `path`, creating it if it does not exist
+    pub fn open(path: &Path, password: &str) -> EyreResult<Self> {
+        if password.is_empty() {
+            bail!("vault password must not be empty");
+        }
+
+        // Ensure permissions are correct
+        ensure_file_private_owner(path).map_err(|e| eyre!("{}", e))?;
+
+        let existing = match std::fs::read(path) {
+            Ok(v) => Some(v),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => bail!("failed to read vault: {}", e),
+        };
+
+        let Some(data) = existing else {
+            // Start a new vault with a fresh salt
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).wrap_err("failed to create vault directory")?;
+            }
+            let mut header = [0u8; VAULT_HEADER_LENGTH];
+            header[0..4].copy_from_slice(VAULT_MAGIC);
+            header[4] = VAULT_VERSION;
+            random_bytes(&mut header[5..]);
+            let key = Self::derive_key(password, &header[5..])?;
+            let out = Self {
+                path: path.to_owned(),
+                header,
+                key,
+                entries: Mutex::new(VaultEntries::new()),
+            };
+            out.write(&VaultEntries::new())?;
+            return Ok(out);
+        };
+
+        if data.len() < VAULT_HEADER_LENGTH + VAULT_NONCE_LENGTH {
+            bail!("vault is too short");
+        }
+        let (header_bytes, rest) = data.split_at(VAULT_HEADER_LENGTH);
+        if &header_bytes[0..4] != VAULT_MAGIC {
+            bail!("not a protected store vault");
+        }
+        if header_bytes[4] != VAULT_VERSION {
+            bail!("unsupported vault version {}", header_bytes[4]);
+        }
+        let mut header = [0u8; VAULT_HEADER_LENGTH];
+        header.copy_from_slice(header_bytes);
+        let key = Self::derive_key(password, &header[5..])?;
+
+        let (nonce, encrypted) = rest.split_at(VAULT_NONCE_LENGTH);
+        let body = ch::XChaCha20Poly1305::new(&key)
+            .decrypt(
+                ch::XNonce::from_slice(nonce),
+                Payload {
+                    msg: encrypted,
+                    aad: &header,
+                },
+            )
+            .map_err(|_| eyre!("wrong vault password or vault has been modified"))?;
+        let entries: VaultEntries = serde_json::from_slice(&body).wrap_err("vault is corrupt")?;
+
+        Ok(Self {
+            path: path.to_owned(),
+            header,
+            key,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    fn derive_key(password: &str, salt: &[u8]) -> EyreResult<ch::Key> {
+        let mut key = ch::Key::default();
+        Argon2::default()
+            .hash_password_into(password.as_bytes(), salt, &mut key)
+            .map_err(|e| eyre!("failed to derive vault key: {}", e))?;
+        Ok(key)
+    }
+
+    /// Encrypt the entries and replace the vault file with them
+    fn write(&self, entries: &VaultEntries) -> EyreResult<()> {
+        let body = serde_json::to_vec(entries)?;
+        let mut nonce = [0u8; VAULT_NONCE_LENGTH];
+        random_bytes(&mut nonce);
+        let encrypted = ch::XChaCha20Poly1305::new(&self.key)
+            .encrypt(
+                ch::XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &body,
+                    aad: &self.header,
+                },
+            )
+            .map_err(|e| eyre!("failed to encrypt vault: {}", e))?;
+
+        let mut data =
+            Vec::with_capacity(VAULT_HEADER_LENGTH + VAULT_NONCE_LENGTH + encrypted.len());
+        data.extend_from_slice(&self.header);
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&encrypted);
+
+        // Write beside the vault and move it into place, so an interrupted write leaves the old vault intact
+        let temp_path = self.path.with_extension("tmp");
+        std::fs::write(&temp_path, &data).wrap_err("failed to write vault")?;
+        ensure_file_private_owner(&temp_path).map_err(|e| eyre!("{}", e))?;
+        std::fs::rename(&temp_path, &self.path).wrap_err("failed to replace vault")?;
+        Ok(())
+    }
+}
+
+impl ProtectedStoreBackend for FileVaultBackend {
+    fn save(&self, service: &str, key: &str, value: &str) -> EyreResult<bool> {
+        let mut entries = self.entries.lock();
+        let mut new_entries = entries.clone();
+        let existed = new_entries
+            .entry(service.to_owned())
+            .or_default()
+            .insert(key.to_owned(), value.to_owned())
+            .is_some();
+        self.write(&new_entries)?;
+        *entries = new_entries;
+        Ok(existed)
+    }
+
+    fn load(&self, service: &str, key: &str) -> EyreResult<Option<String>> {
+        let entries = self.entries.lock();
+        Ok(entries
+            .get(service)
+            .and_then(|service_entries| service_entries.get(key))
+            .cloned())
+    }
+
+    fn remove(&self, service: &str, key: &str) -> EyreResult<bool> {
+        let mut entries = self.entries.lock();
+        let mut new_entries = entries.clone();
+        let Some(service_entries) = new_entries.get_mut(service) else {
+            return Ok(false);
+        };
+        if service_entries.remove(key).is_none() {
+            return Ok(false);
+        }
+        if service_entries.is_empty() {
+            new_entries.remove(service);
+        }
+        self.write(&new_entries)?;
+        *entries = new_entries;
+        Ok(true)
+    }
+}
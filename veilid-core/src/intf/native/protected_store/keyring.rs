@@ -0,0 +1,58 @@
+use super::*;
+use keyring_manager::*;
+
+/// Secrets kept in the operating system keyring, or in a plaintext file standing in for one
+pub(super) struct KeyringBackend {
+    keyring_manager: KeyringManager,
+}
+
+impl KeyringBackend {
+    pub fn new_secure(program_name: &str) -> EyreResult<Self> {
+        cfg_if! {
+            if #[cfg(target_os = "android")] {
+                let keyring_manager = KeyringManager::new_secure(program_name, crate::intf::android::get_android_globals())?;
+            } else {
+                let keyring_manager = KeyringManager::new_secure(program_name)?;
+            }
+        }
+        Ok(Self { keyring_manager })
+    }
+
+    pub fn new_insecure(program_name: &str, insecure_keyring_file: &Path) -> EyreResult<Self> {
+        Ok(Self {
+            keyring_manager: KeyringManager::new_insecure(program_name, insecure_keyring_file)?,
+        })
+    }
+}
+
+impl ProtectedStoreBackend for KeyringBackend {
+    fn save(&self, service: &str, key: &str, value: &str) -> EyreResult<bool> {
+        Ok(self.keyring_manager.with_keyring(service, key, |kr| {
+            let existed = kr.get_value().is_ok();
+            kr.set_value(value)?;
+            Ok(existed)
+        })?)
+    }
+
+    fn load(&self, service: &str, key: &str) -> EyreResult<Option<String>> {
+        match self
+            .keyring_manager
+            .with_keyring(service, key, |kr| kr.get_value())
+        {
+            Ok(v) => Ok(Some(v)),
+            Err(KeyringError::NoPasswordFound) => Ok(None),
+            Err(e) => Err(eyre!("Failed to load user secret: {}", e)),
+        }
+    }
+
+    fn remove(&self, service: &str, key: &str) -> EyreResult<bool> {
+        match self
+            .keyring_manager
+            .with_keyring(service, key, |kr| kr.delete_value())
+        {
+            Ok(_) => Ok(true),
+            Err(KeyringError::NoPasswordFound) => Ok(false),
+            Err(e) => Err(eyre!("Failed to remove user secret: {}", e)),
+        }
+    }
+}
@@ -1,12 +1,29 @@
 use super::*;
+
+mod external_command;
+mod file_vault;
+mod keyring;
+
 use data_encoding::BASE64URL_NOPAD;
-use keyring_manager::*;
-use std::path::Path;
+use external_command::*;
+use file_vault::*;
+use keyring::*;
+use std::path::{Path, PathBuf};
 
 impl_veilid_log_facility!("pstore");
 
+/// Somewhere to keep protected store secrets, by service name and key
+trait ProtectedStoreBackend: Send {
+    /// Store a value, returning true if it replaced an existing one
+    fn save(&self, service: &str, key: &str, value: &str) -> EyreResult<bool>;
+    /// Retrieve a value, or None if there is none
+    fn load(&self, service: &str, key: &str) -> EyreResult<Option<String>>;
+    /// Remove a value, returning true if there was one
+    fn remove(&self, service: &str, key: &str) -> EyreResult<bool>;
+}
+
 pub struct ProtectedStoreInner {
-    keyring_manager: Option<KeyringManager>,
+    backend: Option<Box<dyn ProtectedStoreBackend>>,
 }
 impl fmt::Debug for ProtectedStoreInner {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -25,9 +42,7 @@ impl_veilid_component!(ProtectedStore);
 
 impl ProtectedStore {
     fn new_inner() -> ProtectedStoreInner {
-        ProtectedStoreInner {
-            keyring_manager: None,
-        }
+        ProtectedStoreInner { backend: None }
     }
 
     pub(crate) fn new(registry: VeilidComponentRegistry) -> Self {
@@ -49,56 +64,66 @@ impl ProtectedStore {
         Ok(())
     }
 
+    /// File name in the protected store directory, kept separate for each namespace
+    fn namespaced_file(c: &VeilidConfig, name: &str) -> PathBuf {
+        Path::new(&c.protected_store.directory).join(format!(
+            "{}{}",
+            name,
+            if c.namespace.is_empty() {
+                "".to_owned()
+            } else {
+                format!("_{}", c.namespace)
+            }
+        ))
+    }
+
+    fn open_keyring(&self, c: &VeilidConfig) -> EyreResult<Box<dyn ProtectedStoreBackend>> {
+        if !c.protected_store.always_use_insecure_storage {
+            // Attempt to open the secure keyring
+            match KeyringBackend::new_secure(&c.program_name) {
+                Ok(v) => return Ok(Box::new(v)),
+                Err(e) => {
+                    veilid_log!(self info "Secure key storage service unavailable, falling back to direct disk-based storage: {}", e);
+                }
+            }
+        }
+        if !(c.protected_store.always_use_insecure_storage
+            || c.protected_store.allow_insecure_fallback)
+        {
+            bail!("Could not initialize the protected store.");
+        }
+        let insecure_keyring_file = Self::namespaced_file(c, "insecure_keyring");
+
+        // Ensure permissions are correct
+        ensure_file_private_owner(&insecure_keyring_file).map_err(|e| eyre!("{}", e))?;
+
+        // Open the insecure keyring
+        Ok(Box::new(
+            KeyringBackend::new_insecure(&c.program_name, &insecure_keyring_file)
+                .wrap_err("failed to create insecure keyring")?,
+        ))
+    }
+
     #[instrument(level = "debug", skip(self), err)]
     async fn init_async(&self) -> EyreResult<()> {
         let delete = {
             let config = self.config();
             let c = config.get();
-            let mut inner = self.inner.lock();
-            if !c.protected_store.always_use_insecure_storage {
-                // Attempt to open the secure keyring
-                cfg_if! {
-                    if #[cfg(target_os = "android")] {
-                        let maybe_km = KeyringManager::new_secure(&c.program_name, crate::intf::android::get_android_globals());
-                    } else {
-                        let maybe_km = KeyringManager::new_secure(&c.program_name);
-                    }
-                }
-
-                inner.keyring_manager = match maybe_km {
-                    Ok(v) => Some(v),
-                    Err(e) => {
-                        veilid_log!(self info "Secure key storage service unavailable, falling back to direct disk-based storage: {}", e);
-                        None
-                    }
-                };
-            }
-            if (c.protected_store.always_use_insecure_storage
-                || c.protected_store.allow_insecure_fallback)
-                && inner.keyring_manager.is_none()
-            {
-                let directory = Path::new(&c.protected_store.directory);
-                let insecure_keyring_file = directory.to_owned().join(format!(
-                    "insecure_keyring{}",
-                    if c.namespace.is_empty() {
-                        "".to_owned()
-                    } else {
-                        format!("_{}", c.namespace)
-                    }
-                ));
-
-                // Ensure permissions are correct
-                ensure_file_private_owner(&insecure_keyring_file).map_err(|e| eyre!("{}", e))?;
-
-                // Open the insecure keyring
-                inner.keyring_manager = Some(
-                    KeyringManager::new_insecure(&c.program_name, &insecure_keyring_file)
-                        .wrap_err("failed to create insecure keyring")?,
-                );
-            }
-            if inner.keyring_manager.is_none() {
-                bail!("Could not initialize the protected store.");
-            }
+            let backend: Box<dyn ProtectedStoreBackend> = match c.protected_store.backend {
+                VeilidConfigProtectedStoreBackend::Keyring => self.open_keyring(&c)?,
+                VeilidConfigProtectedStoreBackend::FileVault => Box::new(
+                    FileVaultBackend::open(
+                        &Self::namespaced_file(&c, "vault"),
+                        &c.protected_store.vault_password,
+                    )
+                    .wrap_err("failed to open protected store vault")?,
+                ),
+                VeilidConfigProtectedStoreBackend::ExternalCommand => Box::new(
+                    ExternalCommandBackend::new(&c.protected_store.external_command)
+                        .wrap_err("failed to set up protected store command")?,
+                ),
+            };
+            self.inner.lock().backend = Some(backend);
             c.protected_store.delete
         };
 
@@ -140,14 +165,10 @@ impl ProtectedStore {
     ) -> EyreResult<bool> {
         let inner = self.inner.lock();
         inner
-            .keyring_manager
+            .backend
             .as_ref()
             .ok_or_else(|| eyre!("Protected store not initialized"))?
-            .with_keyring(&self.service_name(), key.as_ref(), |kr| {
-                let existed = kr.get_value().is_ok();
-                kr.set_value(value.as_ref())?;
-                Ok(existed)
-            })
+            .save(&self.service_name(), key.as_ref(), value.as_ref())
             .wrap_err("failed to save user secret")
     }
 
@@ -157,16 +178,12 @@ impl ProtectedStore {
         key: K,
     ) -> EyreResult<Option<String>> {
         let inner = self.inner.lock();
-        match inner
-            .keyring_manager
+        inner
+            .backend
             .as_ref()
             .ok_or_else(|| eyre!("Protected store not initialized"))?
-            .with_keyring(&self.service_name(), key.as_ref(), |kr| kr.get_value())
-        {
-            Ok(v) => Ok(Some(v)),
-            Err(KeyringError::NoPasswordFound) => Ok(None),
-            Err(e) => Err(eyre!("Failed to load user secret: {}", e)),
-        }
+            .load(&self.service_name(), key.as_ref())
+            .wrap_err("failed to load user secret")
     }
 
     #[instrument(level = "trace", skip(self, value))]
@@ -246,15 +263,11 @@ impl ProtectedStore {
     #[instrument(level = "trace", skip(self), ret, err)]
     pub fn remove_user_secret<K: AsRef<str> + fmt::Debug>(&self, key: K) -> EyreResult<bool> {
         let inner = self.inner.lock();
-        match inner
-            .keyring_manager
+        inner
+            .backend
             .as_ref()
             .ok_or_else(|| eyre!("Protected store not initialized"))?
-            .with_keyring(&self.service_name(), key.as_ref(), |kr| kr.delete_value())
-        {
-            Ok(_) => Ok(true),
-            Err(KeyringError::NoPasswordFound) => Ok(false),
-            Err(e) => Err(eyre!("Failed to remove user secret: {}", e)),
-        }
+            .remove(&self.service_name(), key.as_ref())
+            .wrap_err("failed to remove user secret")
     }
 }
@@ -32,6 +32,15 @@ impl ProtectedStore {
 
     #[instrument(level = "debug", skip(self), err)]
     async fn init_async(&self) -> EyreResult<()> {
+        // Browsers only have local storage
+        let backend = self.config().with(|c| c.protected_store.backend);
+        if backend != VeilidConfigProtectedStoreBackend::Keyring {
+            bail!(
+                "Protected store backend {:?} is not supported in the browser",
+                backend
+            );
+        }
+
         if self.config().with(|c| c.protected_store.delete) {
             self.delete_all()?;
         }
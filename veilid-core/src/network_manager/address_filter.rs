@@ -265,6 +265,15 @@ impl AddressFilter {
             .or_insert(ts);
     }
 
+    pub fn get_metrics(&self) -> VeilidMetricsPunishments {
+        let inner = self.inner.lock();
+        VeilidMetricsPunishments {
+            ipv4_addresses: inner.punishments_by_ip4.len() as u64,
+            ipv6_prefixes: inner.punishments_by_ip6_prefix.len() as u64,
+            node_ids: inner.punishments_by_node_id.len() as u64,
+        }
+    }
+
     pub fn clear_punishments(&self) {
         let mut inner = self.inner.lock();
         inner.punishments_by_ip4.clear();
@@ -709,6 +709,10 @@ impl ConnectionManager {
                 }));
     }
 
+    pub fn debug_info(&self) -> Vec<ConnectionTableDebugInfo> {
+        self.arc.connection_table.debug_info_table()
+    }
+
     pub fn debug_print(&self) -> String {
         format!(
             "Connection Table:\n\n{}",
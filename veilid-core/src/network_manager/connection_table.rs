@@ -37,6 +37,16 @@ pub enum ConnectionRefKind {
     RemoveRef,
 }
 
+/// Snapshot of one protocol's connections for diagnostic output
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionTableDebugInfo {
+    pub protocol: ProtocolType,
+    pub max_connections: usize,
+    pub priority_flow_count: usize,
+    pub priority_flow_capacity: usize,
+    pub connections: Vec<NetworkConnectionDebugInfo>,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug)]
@@ -492,6 +502,29 @@ impl ConnectionTable {
         Some(conn)
     }
 
+    pub fn debug_info_table(&self) -> Vec<ConnectionTableDebugInfo> {
+        let inner = self.inner.lock();
+        let mut out = Vec::with_capacity(inner.conn_by_id.len());
+        for t in 0..inner.conn_by_id.len() {
+            let connections = inner.conn_by_id[t]
+                .iter()
+                .map(|(_, conn)| {
+                    let is_priority_flow = inner.priority_flows[t].contains_key(&conn.flow());
+                    conn.debug_info(is_priority_flow)
+                })
+                .collect();
+
+            out.push(ConnectionTableDebugInfo {
+                protocol: Self::index_to_protocol(t),
+                max_connections: inner.max_connections[t],
+                priority_flow_count: inner.priority_flows[t].len(),
+                priority_flow_capacity: inner.priority_flows[t].capacity(),
+                connections,
+            });
+        }
+        out
+    }
+
     pub fn debug_print_table(&self) -> String {
         let mut out = String::new();
         let inner = self.inner.lock();
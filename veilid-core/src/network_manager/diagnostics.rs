@@ -0,0 +1,53 @@
+use super::*;
+
+/// How long a clock skew rejection stays relevant to diagnostics
+const CLOCK_SKEW_RECENT_SECS: u32 = 600;
+/// Clock skew rejections needed before we suspect our own clock
+const CLOCK_SKEW_MIN_REJECTIONS: u64 = 3;
+
+impl NetworkManager {
+    /// Check the low level network for problems
+    pub fn diagnose(&self) -> Vec<VeilidDiagnosticFinding> {
+        let mut findings = Vec::new();
+        let Some(net) = self.opt_net() else {
+            return findings;
+        };
+        if !self.network_is_started() {
+            return findings;
+        }
+
+        findings.extend(net.diagnose());
+
+        // Clock skew
+        let (behind, ahead, opt_last_ts) = {
+            let inner = self.inner.lock();
+            (
+                inner.stats.timestamp_behind_rejections,
+                inner.stats.timestamp_ahead_rejections,
+                inner.stats.last_timestamp_rejection_ts,
+            )
+        };
+        let recent = opt_last_ts.is_some_and(|last_ts| {
+            Timestamp::now().saturating_sub(last_ts)
+                < TimestampDuration::new_secs(CLOCK_SKEW_RECENT_SECS)
+        });
+        if recent && behind + ahead >= CLOCK_SKEW_MIN_REJECTIONS {
+            let likely = if behind > ahead {
+                "our clock is probably fast"
+            } else {
+                "our clock is probably slow"
+            };
+            findings.push(VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Warning,
+                DiagnosticKind::ClockSkew,
+                format!(
+                    "{} envelopes were rejected for timestamps too far behind our clock and {} for timestamps too far ahead, so {}",
+                    behind, ahead, likely
+                ),
+                "Synchronize the system clock with a time server, peers can not talk to a node whose clock is off",
+            ));
+        }
+
+        findings
+    }
+}
@@ -12,6 +12,7 @@ mod connection_handle;
 mod connection_manager;
 mod connection_table;
 mod debug;
+mod diagnostics;
 mod network_connection;
 mod node_contact_method_cache;
 mod receipt_manager;
@@ -32,6 +33,7 @@ pub use receipt_manager::*;
 pub use stats::*;
 
 pub(crate) use bootstrap::*;
+pub(crate) use connection_table::ConnectionTableDebugInfo;
 pub(crate) use node_contact_method_cache::*;
 pub(crate) use types::*;
 
@@ -1082,6 +1084,7 @@ impl NetworkManager {
                     timestamp_to_secs(ts.saturating_sub(ets).as_u64()) * 1000f64,
                     flow.remote()
                 );
+                self.stats_timestamp_rejected(false);
                 return Ok(false);
             }
         }
@@ -1092,6 +1095,7 @@ impl NetworkManager {
                     timestamp_to_secs(ets.saturating_sub(ts).as_u64()) * 1000f64,
                     flow.remote()
                 );
+                self.stats_timestamp_rejected(true);
                 return Ok(false);
             }
         }
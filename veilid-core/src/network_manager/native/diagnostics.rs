@@ -0,0 +1,122 @@
+use super::*;
+
+/// Consecutive dial info failures before we suspect the network is down
+const DIAL_INFO_FAILURE_WARNING_COUNT: usize = 10;
+
+impl Network {
+    /// Check the results of NAT detection, UPnP and dial info use for problems
+    pub fn diagnose(&self) -> Vec<VeilidDiagnosticFinding> {
+        let mut findings = Vec::new();
+
+        let (last_discovery_results, dial_info_failure_count) = {
+            let inner = self.inner.lock();
+            (
+                inner.last_discovery_results.clone(),
+                inner
+                    .dial_info_failure_count
+                    .get(&RoutingDomain::PublicInternet)
+                    .copied()
+                    .unwrap_or_default(),
+            )
+        };
+        let enable_upnp = self.config().with(|c| c.network.upnp);
+
+        // NAT detection
+        let mut unknown = Vec::new();
+        let mut symmetric = Vec::new();
+        let mut restricted = Vec::new();
+        for (cc, opt_ddi) in &last_discovery_results {
+            match opt_ddi {
+                None => unknown.push(cc.to_string()),
+                Some(DetectedDialInfo::SymmetricNAT) => symmetric.push(cc.to_string()),
+                Some(DetectedDialInfo::Detected(did)) => {
+                    if did.class.requires_relay() {
+                        restricted.push(format!("{} ({:?})", cc, did.class));
+                    }
+                }
+            }
+        }
+        let port_fix = if enable_upnp {
+            "Enable UPnP on the router or forward the listen ports to this device"
+        } else {
+            "Enable 'network.upnp' or forward the listen ports to this device"
+        };
+        if !unknown.is_empty() {
+            findings.push(VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Warning,
+                DiagnosticKind::ExternalAddressUnknown,
+                format!(
+                    "Not enough peers reported an external address for {}",
+                    unknown.join(", ")
+                ),
+                "Check that outbound traffic for these protocols is not blocked by a firewall. Detection is retried automatically",
+            ));
+        }
+        if !symmetric.is_empty() {
+            findings.push(VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Warning,
+                DiagnosticKind::SymmetricNAT,
+                format!(
+                    "Behind a symmetric NAT for {}, so inbound traffic must go through a relay",
+                    symmetric.join(", ")
+                ),
+                format!("{}, or use a network without carrier-grade NAT", port_fix),
+            ));
+        }
+        if !restricted.is_empty() {
+            findings.push(VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Info,
+                DiagnosticKind::RestrictedNAT,
+                format!(
+                    "Only reachable with a relay or hole punching for {}",
+                    restricted.join(", ")
+                ),
+                format!("{} to accept direct inbound connections", port_fix),
+            ));
+        }
+
+        // UPnP
+        if enable_upnp {
+            let igd_status = self.igd_manager.status();
+            if igd_status.gateway_search_failed && igd_status.gateway_count == 0 {
+                findings.push(VeilidDiagnosticFinding::new(
+                    DiagnosticSeverity::Info,
+                    DiagnosticKind::UPnPGatewayNotFound,
+                    "UPnP is enabled but no UPnP gateway was found",
+                    "Enable UPnP on the router, or disable 'network.upnp' if ports are forwarded manually",
+                ));
+            }
+            if igd_status.failing_port_map_count != 0 {
+                findings.push(VeilidDiagnosticFinding::new(
+                    DiagnosticSeverity::Warning,
+                    DiagnosticKind::UPnPMappingFailing,
+                    format!(
+                        "{} of {} UPnP port mappings failed to renew",
+                        igd_status.failing_port_map_count, igd_status.port_map_count
+                    ),
+                    "Check that the router still allows UPnP, or forward the listen ports manually",
+                ));
+            }
+        }
+
+        // Dial info failures
+        if dial_info_failure_count >= DIAL_INFO_FAILURE_WARNING_COUNT {
+            let severity = if dial_info_failure_count >= MAX_DIAL_INFO_FAILURE_COUNT {
+                DiagnosticSeverity::Error
+            } else {
+                DiagnosticSeverity::Warning
+            };
+            findings.push(VeilidDiagnosticFinding::new(
+                severity,
+                DiagnosticKind::DialInfoFailures,
+                format!(
+                    "The last {} connections to peers on the public internet failed",
+                    dial_info_failure_count
+                ),
+                "Check the internet connection, and that a firewall or proxy is not blocking outbound connections",
+            ));
+        }
+
+        findings
+    }
+}
@@ -109,6 +109,16 @@ pub struct DiscoveryContextConfig {
     pub port: u16,
 }
 
+impl fmt::Display for DiscoveryContextConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{:?}:{}",
+            self.protocol_type, self.address_type, self.port
+        )
+    }
+}
+
 // Result of checking external address
 #[derive(Clone, Debug)]
 struct ExternalInfo {
@@ -29,6 +29,20 @@ struct IGDManagerInner {
     local_ip_addrs: BTreeMap<IGDAddressType, IpAddr>,
     gateways: BTreeMap<IpAddr, Arc<Gateway>>,
     port_maps: BTreeMap<PortMapKey, PortMapValue>,
+    gateway_search_failed: bool,
+}
+
+/// Summary of the UPnP port mapping state, for diagnostics
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IGDStatus {
+    /// number of gateways found
+    pub gateway_count: usize,
+    /// the last search for a gateway found nothing
+    pub gateway_search_failed: bool,
+    /// number of ports currently mapped
+    pub port_map_count: usize,
+    /// number of mapped ports whose last renewal failed
+    pub failing_port_map_count: usize,
 }
 
 #[derive(Clone)]
@@ -87,10 +101,25 @@ impl IGDManager {
                 local_ip_addrs: BTreeMap::new(),
                 gateways: BTreeMap::new(),
                 port_maps: BTreeMap::new(),
+                gateway_search_failed: false,
             })),
         }
     }
 
+    pub fn status(&self) -> IGDStatus {
+        let inner = self.inner.lock();
+        IGDStatus {
+            gateway_count: inner.gateways.len(),
+            gateway_search_failed: inner.gateway_search_failed,
+            port_map_count: inner.port_maps.len(),
+            failing_port_map_count: inner
+                .port_maps
+                .values()
+                .filter(|v| v.renewal_attempts > 0)
+                .count(),
+        }
+    }
+
     #[instrument(level = "trace", target = "net", skip_all)]
     pub async fn unmap_port(
         &self,
@@ -473,6 +502,7 @@ impl IGDManager {
                     Ok(v) => v,
                     Err(e) => {
                         veilid_log!(self debug "couldn't find ipv4 igd: {}", e);
+                        inner.gateway_search_failed = true;
                         return None;
                     }
                 }
@@ -488,6 +518,7 @@ impl IGDManager {
                     Ok(v) => v,
                     Err(e) => {
                         veilid_log!(self debug "couldn't find ipv6 igd: {}", e);
+                        inner.gateway_search_failed = true;
                         return None;
                     }
                 }
@@ -495,6 +526,7 @@ impl IGDManager {
         };
         let gw = Arc::new(gateway);
         inner.gateways.insert(local_ip, gw.clone());
+        inner.gateway_search_failed = false;
         Some(gw)
     }
 
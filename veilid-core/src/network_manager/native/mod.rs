@@ -1,3 +1,4 @@
+mod diagnostics;
 mod discovery_context;
 mod igd_manager;
 mod network_state;
@@ -93,6 +94,8 @@ struct NetworkInner {
     needs_update_network_class: bool,
     /// the next time we are allowed to check for better dialinfo when we are OutboundOnly
     next_outbound_only_dial_info_check: Timestamp,
+    /// the outcome of the last public dial info discovery for each protocol and address type
+    last_discovery_results: BTreeMap<DiscoveryContextConfig, Option<DetectedDialInfo>>,
     /// join handles for all the low level network background tasks
     join_handles: Vec<MustJoinHandle<()>>,
     /// stop source for shutting down the low level network background tasks
@@ -158,6 +161,7 @@ impl Network {
             dial_info_failure_count: BTreeMap::new(),
             needs_update_network_class: false,
             next_outbound_only_dial_info_check: Timestamp::default(),
+            last_discovery_results: BTreeMap::new(),
             join_handles: Vec::new(),
             stop_source: None,
             bound_address_per_protocol: BTreeMap::new(),
@@ -191,6 +191,16 @@ impl Network {
             }
         }
 
+        // Keep the outcome for each discovery context config for diagnostics
+        let mut last_discovery_results = context_configs
+            .iter()
+            .map(|cc| (*cc, None))
+            .collect::<BTreeMap<_, _>>();
+        for (cc, dr) in &detection_results {
+            last_discovery_results.insert(*cc, Some(dr.ddi.clone()));
+        }
+        self.inner.lock().last_discovery_results = last_discovery_results;
+
         // Apply best effort coalesced detection results
         for (_, dr) in detection_results {
             // Import the dialinfo
@@ -82,6 +82,20 @@ pub struct NetworkConnectionStats {
     last_message_recv_time: Option<Timestamp>,
 }
 
+/// Snapshot of a connection's state for diagnostic output
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkConnectionDebugInfo {
+    pub connection_id: u64,
+    pub flow: String,
+    pub dial_info: Option<String>,
+    pub established_ts: Timestamp,
+    pub last_message_sent_ts: Option<Timestamp>,
+    pub last_message_recv_ts: Option<Timestamp>,
+    pub ref_count: usize,
+    pub protected: Option<String>,
+    pub priority_flow: bool,
+}
+
 /// Represents a connection in the connection table for connection-oriented protocols
 pub(crate) struct NetworkConnection {
     /// Registry accessor
@@ -498,6 +512,21 @@ impl NetworkConnection {
         }.in_current_span())
     }
 
+    pub fn debug_info(&self, priority_flow: bool) -> NetworkConnectionDebugInfo {
+        let stats = self.stats();
+        NetworkConnectionDebugInfo {
+            connection_id: self.connection_id.as_u64(),
+            flow: self.flow.to_string(),
+            dial_info: self.opt_dial_info.as_ref().map(|di| di.to_string()),
+            established_ts: self.established_time,
+            last_message_sent_ts: stats.last_message_sent_time,
+            last_message_recv_ts: stats.last_message_recv_time,
+            ref_count: self.ref_count,
+            protected: self.protected_nr.as_ref().map(|pnr| pnr.to_string()),
+            priority_flow,
+        }
+    }
+
     pub fn debug_print(&self, cur_ts: Timestamp) -> String {
         format!(
             "{} | {} | est {} sent {} rcvd {} refcount {}{}",
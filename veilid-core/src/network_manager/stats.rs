@@ -26,6 +26,10 @@ pub struct NetworkManagerStats {
     pub relay_worker_process_latency: LatencyStats,
     pub relay_worker_dequeue_latency_accounting: LatencyStatsAccounting,
     pub relay_worker_process_latency_accounting: LatencyStatsAccounting,
+    pub relayed_envelopes: u64,
+    pub timestamp_behind_rejections: u64,
+    pub timestamp_ahead_rejections: u64,
+    pub last_timestamp_rejection_ts: Option<Timestamp>,
 }
 
 impl Default for NetworkManagerStats {
@@ -37,6 +41,10 @@ impl Default for NetworkManagerStats {
             relay_worker_process_latency: LatencyStats::default(),
             relay_worker_dequeue_latency_accounting: LatencyStatsAccounting::new(),
             relay_worker_process_latency_accounting: LatencyStatsAccounting::new(),
+            relayed_envelopes: 0,
+            timestamp_behind_rejections: 0,
+            timestamp_ahead_rejections: 0,
+            last_timestamp_rejection_ts: None,
         }
     }
 }
@@ -83,6 +91,7 @@ impl NetworkManager {
         process_latency: TimestampDuration,
     ) {
         let mut inner = self.inner.lock();
+        inner.stats.relayed_envelopes += 1;
         inner.stats.relay_worker_dequeue_latency = inner
             .stats
             .relay_worker_dequeue_latency_accounting
@@ -93,6 +102,16 @@ impl NetworkManager {
             .record_latency(process_latency);
     }
 
+    pub fn stats_timestamp_rejected(&self, ahead: bool) {
+        let mut inner = self.inner.lock();
+        if ahead {
+            inner.stats.timestamp_ahead_rejections += 1;
+        } else {
+            inner.stats.timestamp_behind_rejections += 1;
+        }
+        inner.stats.last_timestamp_rejection_ts = Some(Timestamp::now());
+    }
+
     pub fn get_stats(&self) -> NetworkManagerStats {
         let inner = self.inner.lock();
         inner.stats.clone()
@@ -139,6 +158,30 @@ impl NetworkManager {
         })
     }
 
+    pub fn get_relay_metrics(&self) -> VeilidMetricsRelay {
+        let routing_table = self.routing_table();
+        let public_internet_relay = routing_table
+            .relay_node(RoutingDomain::PublicInternet)
+            .is_some();
+        let local_network_relay = routing_table
+            .relay_node(RoutingDomain::LocalNetwork)
+            .is_some();
+
+        let inner = self.inner.lock();
+        VeilidMetricsRelay {
+            public_internet_relay,
+            local_network_relay,
+            relayed_envelopes: inner.stats.relayed_envelopes,
+            queue_depth: inner
+                .relay_send_channel
+                .as_ref()
+                .map(|c| c.len() as u32)
+                .unwrap_or_default(),
+            dequeue_latency: inner.stats.relay_worker_dequeue_latency.clone(),
+            process_latency: inner.stats.relay_worker_process_latency.clone(),
+        }
+    }
+
     pub fn send_network_update(&self) {
         let update_cb = self.update_callback();
 
@@ -488,6 +488,11 @@ impl Network {
         None
     }
 
+    /// There is no NAT detection or port mapping to check in the browser
+    pub fn diagnose(&self) -> Vec<VeilidDiagnosticFinding> {
+        Vec::new()
+    }
+
     //////////////////////////////////////////
 
     #[expect(dead_code)]
@@ -0,0 +1,127 @@
+use super::*;
+
+/// How long the relay can go without a keepalive before it is considered unhealthy
+const RELAY_KEEPALIVE_STALE_SECS: u32 = 60;
+
+impl RoutingTable {
+    /// Check the network class, relay and bucket fill for problems
+    pub fn diagnose(&self) -> Vec<VeilidDiagnosticFinding> {
+        let mut findings = Vec::new();
+        let cur_ts = Timestamp::now();
+
+        // Network class
+        match self.get_network_class(RoutingDomain::PublicInternet) {
+            NetworkClass::InboundCapable | NetworkClass::WebApp => {}
+            NetworkClass::OutboundOnly => {
+                findings.push(VeilidDiagnosticFinding::new(
+                    DiagnosticSeverity::Warning,
+                    DiagnosticKind::OutboundOnly,
+                    "No inbound dial info could be validated, so other nodes can only reach this node through a relay",
+                    "Forward the listen ports to this device or enable UPnP on the router",
+                ));
+            }
+            NetworkClass::Invalid => {
+                findings.push(VeilidDiagnosticFinding::new(
+                    DiagnosticSeverity::Warning,
+                    DiagnosticKind::NetworkClassUnknown,
+                    "Detection of the public internet network class has not finished",
+                    "Wait for detection to finish. If it does not, check the internet connection and the bootstrap settings",
+                ));
+            }
+        }
+
+        // Relay health
+        let requires_relay = self
+            .inner
+            .read()
+            .with_routing_domain(RoutingDomain::PublicInternet, |rdd| rdd.requires_relay());
+        match self.relay_node(RoutingDomain::PublicInternet) {
+            None => {
+                if let Some(relay_kind) = requires_relay {
+                    findings.push(VeilidDiagnosticFinding::new(
+                        DiagnosticSeverity::Error,
+                        DiagnosticKind::NoRelay,
+                        format!("An {:?} relay is required but none is selected", relay_kind),
+                        "Wait for a relay to be selected. If none is, there may be too few relay-capable peers, so check the bootstrap settings",
+                    ));
+                }
+            }
+            Some(relay_node) => {
+                let state = relay_node.state(cur_ts);
+                let keepalive_age = self
+                    .relay_node_last_keepalive(RoutingDomain::PublicInternet)
+                    .map(|ts| cur_ts.saturating_sub(ts));
+                let stale = keepalive_age.is_some_and(|age| {
+                    age > TimestampDuration::new_secs(RELAY_KEEPALIVE_STALE_SECS)
+                });
+                if !matches!(state, BucketEntryState::Reliable) || stale {
+                    findings.push(VeilidDiagnosticFinding::new(
+                        DiagnosticSeverity::Warning,
+                        DiagnosticKind::RelayUnreliable,
+                        format!(
+                            "The relay {} is {:?}, last keepalive {}",
+                            relay_node,
+                            state,
+                            keepalive_age
+                                .map(|age| format!("{} ago", age))
+                                .unwrap_or_else(|| "never".to_owned())
+                        ),
+                        "A new relay is selected automatically if this one stays unresponsive. If relays keep failing, check for an unstable connection",
+                    ));
+                }
+            }
+        }
+
+        // Bucket fill
+        let health = self.get_routing_table_health();
+        let limit_attached_good = self
+            .config()
+            .with(|c| c.network.routing_table.limit_attached_good as usize);
+        let (filled_buckets, total_buckets) = {
+            let inner = self.inner.read();
+            let inner = &*inner;
+            let mut filled_buckets = 0usize;
+            let mut total_buckets = 0usize;
+            for ck in &VALID_CRYPTO_KINDS {
+                for bucket in &inner.buckets[ck] {
+                    total_buckets += 1;
+                    if bucket.entries().any(|(_k, e)| {
+                        e.with(inner, |_rti, e| {
+                            e.state(cur_ts) >= BucketEntryState::Unreliable
+                        })
+                    }) {
+                        filled_buckets += 1;
+                    }
+                }
+            }
+            (filled_buckets, total_buckets)
+        };
+        let live_entry_count = health.reliable_entry_count + health.unreliable_entry_count;
+        if live_entry_count == 0 {
+            findings.push(VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Error,
+                DiagnosticKind::FewPeers,
+                format!(
+                    "No peers are reachable and {} are unresponsive",
+                    health.dead_entry_count
+                ),
+                "Check the internet connection, and that the bootstrap settings point to reachable nodes",
+            ));
+        } else if health.reliable_entry_count < limit_attached_good {
+            findings.push(VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Warning,
+                DiagnosticKind::FewPeers,
+                format!(
+                    "Only {} reliable and {} unreliable peers, with live peers in {} of {} buckets",
+                    health.reliable_entry_count,
+                    health.unreliable_entry_count,
+                    filled_buckets,
+                    total_buckets
+                ),
+                "Stay attached longer to find more peers. If the count stays low, check for a firewall dropping traffic",
+            ));
+        }
+
+        findings
+    }
+}
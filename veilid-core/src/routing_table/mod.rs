@@ -1,6 +1,7 @@
 mod bucket;
 mod bucket_entry;
 mod debug;
+mod diagnostics;
 mod find_peers;
 #[cfg(feature = "geolocation")]
 mod geolocation;
@@ -9,6 +10,7 @@ mod privacy;
 mod route_spec_store;
 mod routing_table_inner;
 mod stats_accounting;
+mod suspend;
 mod tasks;
 mod types;
 
@@ -20,6 +22,7 @@ pub(crate) use privacy::*;
 pub(crate) use route_spec_store::*;
 pub(crate) use routing_table_inner::*;
 pub(crate) use stats_accounting::*;
+pub(crate) use suspend::*;
 pub use types::*;
 
 use super::*;
@@ -49,6 +52,11 @@ pub const RELAY_OPTIMIZATION_PERCENTILE: f32 = 66.0;
 /// What percentile to choose our relays from (must be greater than RELAY_OPTIMIZATION_PERCENTILE)
 pub const RELAY_SELECTION_PERCENTILE: f32 = 85.0;
 
+/// How frequently we validate peers with pings in the low power profile
+pub const LOW_POWER_PING_VALIDATOR_INTERVAL_SECS: u32 = 60;
+/// How frequently we refresh our closest peers in the low power profile
+pub const LOW_POWER_CLOSEST_PEERS_REFRESH_INTERVAL_SECS: u32 = 600;
+
 /// How frequently we tick the private route management routine
 pub const PRIVATE_ROUTE_MANAGEMENT_INTERVAL_SECS: u32 = 1;
 
@@ -116,6 +124,8 @@ pub(crate) struct RoutingTable {
     route_spec_store: RouteSpecStore,
     /// Buckets to kick on our next kick task
     kick_queue: Mutex<BTreeSet<BucketIndex>>,
+    /// Relay to reuse when relay management next picks one, after a resume
+    preferred_relay_node_id: Mutex<Option<TypedNodeId>>,
     /// Background process for flushing the table to disk
     flush_task: TickTask<EyreReport>,
     /// Background process for computing statistics
@@ -168,6 +178,7 @@ impl RoutingTable {
             inner,
             route_spec_store,
             kick_queue: Mutex::new(BTreeSet::default()),
+            preferred_relay_node_id: Mutex::new(None),
             flush_task: TickTask::new("flush_task", ROUTING_TABLE_FLUSH_INTERVAL_SECS),
             rolling_transfers_task: TickTask::new(
                 "rolling_transfers_task",
@@ -264,6 +275,9 @@ impl RoutingTable {
         veilid_log!(self debug "stopping routing table tasks");
         self.cancel_tasks().await;
 
+        // Forget any relay left over from a resume
+        self.preferred_relay_node_id.lock().take();
+
         // Unpublish peer info
         veilid_log!(self debug "unpublishing peer info");
         {
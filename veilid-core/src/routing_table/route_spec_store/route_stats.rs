@@ -39,6 +39,22 @@ pub struct RouteStats {
     answer_stats_accounting: AnswerStatsAccounting,
 }
 
+/// Snapshot of a route's statistics for diagnostic output
+/// Unlike the persisted form of RouteStats, this includes the transient counters and timestamps
+#[derive(Clone, Debug, Serialize)]
+pub struct RouteStatsDebugInfo {
+    pub created_ts: Timestamp,
+    pub failed_to_send: u32,
+    pub recent_lost_answers: u32,
+    pub last_known_valid_ts: Option<Timestamp>,
+    pub last_sent_ts: Option<Timestamp>,
+    pub last_rcvd_question_ts: Option<Timestamp>,
+    pub last_rcvd_answer_ts: Option<Timestamp>,
+    pub transfer: TransferStatsDownUp,
+    pub latency: LatencyStats,
+    pub answer: AnswerStats,
+}
+
 impl fmt::Display for RouteStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "created: {}", self.created_ts)?;
@@ -153,6 +169,22 @@ impl RouteStats {
         &self.transfer
     }
 
+    /// Get a snapshot of all stats for diagnostic output
+    pub fn debug_info(&self) -> RouteStatsDebugInfo {
+        RouteStatsDebugInfo {
+            created_ts: self.created_ts,
+            failed_to_send: self.failed_to_send,
+            recent_lost_answers: self.recent_lost_answers,
+            last_known_valid_ts: self.last_known_valid_ts,
+            last_sent_ts: self.last_sent_ts,
+            last_rcvd_question_ts: self.last_rcvd_question_ts,
+            last_rcvd_answer_ts: self.last_rcvd_answer_ts,
+            transfer: self.transfer.clone(),
+            latency: self.latency.clone(),
+            answer: self.answer.clone(),
+        }
+    }
+
     /// Reset stats when network restarts
     pub fn reset(&mut self) {
         self.last_known_valid_ts = None;
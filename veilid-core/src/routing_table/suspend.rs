@@ -0,0 +1,275 @@
+use super::*;
+
+use futures_util::FutureExt;
+use stop_token::future::FutureExt as _;
+
+impl_veilid_log_facility!("rtab");
+
+/// Table store key for the state saved by a suspend
+const SUSPEND_STATE: &[u8] = b"suspend_state";
+/// Oldest suspend state a resume will use, as peers, relays and routes are unlikely to have survived longer
+const SUSPEND_STATE_MAX_AGE_SECS: u32 = 3600;
+/// Most live nodes to remember across a suspend
+const SUSPEND_MAX_LIVE_NODES: usize = 32;
+/// Number of pings to have in flight at once while resuming
+const RESUME_MAX_PARALLEL_PINGS: usize = 16;
+/// How long to wait for our peer info to be republished before testing routes
+const RESUME_PUBLISH_WAIT_SECS: u32 = 15;
+/// How often to check if our peer info has been republished
+const RESUME_PUBLISH_POLL_MS: u32 = 250;
+
+type ResumePingFuture = PinBoxFutureStatic<Result<(), RPCError>>;
+
+/// Routing table state captured by a suspend, so a resume can revalidate it
+/// instead of rebuilding it from scratch
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct SuspendState {
+    /// When the node was suspended
+    pub suspended_ts: Timestamp,
+    /// The PublicInternet relay we were using, if any
+    pub relay_node_id: Option<TypedNodeId>,
+    /// Nodes that were reliable when we suspended
+    pub live_node_ids: Vec<TypedNodeId>,
+    /// Allocated routes that were published
+    pub published_route_ids: Vec<RouteId>,
+}
+
+impl SuspendState {
+    /// Check if the state is too old to revalidate, or from the future because the clock changed
+    pub fn is_stale(&self, cur_ts: Timestamp) -> bool {
+        self.suspended_ts > cur_ts
+            || cur_ts.saturating_sub(self.suspended_ts)
+                > TimestampDuration::new_secs(SUSPEND_STATE_MAX_AGE_SECS)
+    }
+}
+
+impl RoutingTable {
+    /// Capture the current relay, live nodes and published routes and persist them,
+    /// along with the rest of the routing table, so they survive until a resume
+    #[instrument(level = "debug", skip_all, err)]
+    pub async fn save_suspend_state(&self) -> EyreResult<()> {
+        let cur_ts = Timestamp::now();
+
+        let relay_node_id = self
+            .relay_node(RoutingDomain::PublicInternet)
+            .and_then(|nr| nr.best_node_id());
+
+        let mut live_node_ids = Vec::new();
+        self.inner
+            .read()
+            .with_entries(cur_ts, BucketEntryState::Reliable, |_rti, entry| {
+                if let Some(node_id) = entry.with_inner(|e| e.best_node_id()) {
+                    live_node_ids.push(node_id);
+                }
+                if live_node_ids.len() >= SUSPEND_MAX_LIVE_NODES {
+                    return Some(());
+                }
+                Option::<()>::None
+            });
+
+        let published_route_ids = self.route_spec_store().list_allocated_routes(|k, v| {
+            if v.is_published() {
+                Some(*k)
+            } else {
+                None
+            }
+        });
+
+        let suspend_state = SuspendState {
+            suspended_ts: cur_ts,
+            relay_node_id,
+            live_node_ids,
+            published_route_ids,
+        };
+        veilid_log!(self debug "saving suspend state: {:?}", suspend_state);
+
+        self.flush().await;
+
+        let table_store = self.table_store();
+        let db = table_store.open(ROUTING_TABLE, 1).await?;
+        db.store_json(0, SUSPEND_STATE, &suspend_state).await?;
+
+        Ok(())
+    }
+
+    /// Load and remove the state saved by the last suspend, if there was one and it is not stale
+    #[instrument(level = "debug", skip_all, err)]
+    pub async fn take_suspend_state(&self) -> EyreResult<Option<SuspendState>> {
+        let table_store = self.table_store();
+        let db = table_store.open(ROUTING_TABLE, 1).await?;
+        let res = db.load_json::<SuspendState>(0, SUSPEND_STATE).await;
+
+        // A suspend state is only used once, even if it could not be read
+        db.delete(0, SUSPEND_STATE).await?;
+
+        let Some(suspend_state) = res? else {
+            return Ok(None);
+        };
+        let cur_ts = Timestamp::now();
+        if suspend_state.is_stale(cur_ts) {
+            veilid_log!(self debug
+                "discarding stale suspend state from {}",
+                suspend_state.suspended_ts
+            );
+            return Ok(None);
+        }
+        Ok(Some(suspend_state))
+    }
+
+    /// Forget the state saved by the last suspend, so a later resume starts from scratch
+    #[instrument(level = "debug", skip_all, err)]
+    pub async fn clear_suspend_state(&self) -> EyreResult<()> {
+        let table_store = self.table_store();
+        let db = table_store.open(ROUTING_TABLE, 1).await?;
+        db.delete(0, SUSPEND_STATE).await?;
+        Ok(())
+    }
+
+    /// Bring the state saved by a suspend back up to date.
+    ///
+    /// The old relay and live nodes are pinged in parallel, the old relay is preferred
+    /// when relay management picks a relay, and once our peer info is republished
+    /// the published routes are tested in parallel and the dead ones released.
+    #[instrument(level = "debug", skip_all)]
+    pub async fn revalidate_suspend_state(
+        &self,
+        suspend_state: SuspendState,
+        stop_token: StopToken,
+    ) {
+        let start_ts = Timestamp::now();
+        veilid_log!(self debug
+            "revalidating suspend state from {} ago: {} live nodes, {} published routes",
+            start_ts.saturating_sub(suspend_state.suspended_ts),
+            suspend_state.live_node_ids.len(),
+            suspend_state.published_route_ids.len()
+        );
+
+        *self.preferred_relay_node_id.lock() = suspend_state.relay_node_id;
+
+        // Ping the old relay and the nodes that were live
+        let mut future_queue: VecDeque<ResumePingFuture> = VecDeque::new();
+        for node_id in suspend_state
+            .relay_node_id
+            .iter()
+            .chain(suspend_state.live_node_ids.iter())
+        {
+            let nr = match self.lookup_node_ref(*node_id) {
+                Ok(Some(nr)) => nr,
+                Ok(None) => continue,
+                Err(e) => {
+                    veilid_log!(self debug "Error looking up node to revalidate: {}", e);
+                    continue;
+                }
+            };
+            future_queue.push_back(
+                async move {
+                    veilid_log!(nr debug "--> Resume ping to {:?}", nr);
+                    let rpc_processor = nr.rpc_processor();
+                    let _ = rpc_processor
+                        .rpc_call_status(Destination::direct(
+                            nr.sequencing_filtered(Sequencing::PreferOrdered),
+                        ))
+                        .await?;
+                    Ok(())
+                }
+                .boxed(),
+            );
+        }
+        let _ = process_batched_future_queue_result(
+            future_queue,
+            RESUME_MAX_PARALLEL_PINGS,
+            stop_token.clone(),
+            |res| {
+                if let Err(e) = res {
+                    veilid_log!(self debug "Error performing resume ping: {}", e);
+                }
+                Result::<(), ()>::Ok(())
+            },
+        )
+        .await;
+
+        // Routes can only be tested once our peer info is published again
+        let publish_deadline_ts = start_ts + TimestampDuration::new_secs(RESUME_PUBLISH_WAIT_SECS);
+        while self
+            .get_published_peer_info(RoutingDomain::PublicInternet)
+            .is_none()
+        {
+            if Timestamp::now() >= publish_deadline_ts {
+                break;
+            }
+            if sleep(RESUME_PUBLISH_POLL_MS)
+                .timeout_at(stop_token.clone())
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        // Relay management has had its chance to pick the old relay
+        self.preferred_relay_node_id.lock().take();
+
+        if self
+            .get_published_peer_info(RoutingDomain::PublicInternet)
+            .is_none()
+        {
+            veilid_log!(self debug "peer info not republished, leaving route tests to route management");
+        } else {
+            // Routes released while we were suspended don't need testing
+            let allocated_route_ids = self
+                .route_spec_store()
+                .list_allocated_routes(|k, _v| Some(*k));
+            let published_route_ids = suspend_state
+                .published_route_ids
+                .into_iter()
+                .filter(|r| allocated_route_ids.contains(r))
+                .collect();
+            if let Err(e) = self.test_route_set(stop_token, published_route_ids).await {
+                veilid_log!(self debug "Error testing published routes on resume: {}", e);
+            }
+        }
+
+        veilid_log!(self debug
+            "finished revalidating suspend state in {}",
+            Timestamp::now().saturating_sub(start_ts)
+        );
+    }
+
+    /// Take the relay we were using before a suspend, if it is still usable as a relay
+    pub(super) fn take_preferred_relay_node(
+        &self,
+        cur_ts: Timestamp,
+        relay_node_filter: &impl Fn(&BucketEntryInner) -> bool,
+    ) -> Option<NodeRef> {
+        let node_id = self.preferred_relay_node_id.lock().take()?;
+        let nr = self.lookup_node_ref(node_id).ok().flatten()?;
+        if !nr.state(cur_ts).is_alive() || !nr.operate(|_rti, e| relay_node_filter(e)) {
+            return None;
+        }
+        Some(nr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspend_state_is_stale() {
+        let cur_ts = Timestamp::new(10_000_000_000_000);
+        let max_age = TimestampDuration::new_secs(SUSPEND_STATE_MAX_AGE_SECS);
+        let at = |suspended_ts| SuspendState {
+            suspended_ts,
+            ..Default::default()
+        };
+
+        assert!(!at(cur_ts).is_stale(cur_ts));
+        assert!(!at(cur_ts - TimestampDuration::new_secs(60)).is_stale(cur_ts));
+        assert!(!at(cur_ts - max_age).is_stale(cur_ts));
+        assert!(at(cur_ts - max_age - TimestampDuration::new(1)).is_stale(cur_ts));
+        assert!(at(Timestamp::default()).is_stale(cur_ts));
+
+        // The clock went backwards since the suspend
+        assert!(at(cur_ts + TimestampDuration::new(1)).is_stale(cur_ts));
+    }
+}
@@ -197,9 +197,23 @@ impl RoutingTable {
             }
         }
 
+        // In low power mode, validate and refresh peers less often
+        let attachment_manager = self.attachment_manager();
+
         // Ping validate some nodes to groom the table
-        self.ping_validator_public_internet_task.tick().await?;
-        self.ping_validator_local_network_task.tick().await?;
+        if attachment_manager.power_profile_allows_tick(
+            &self.ping_validator_public_internet_task,
+            LOW_POWER_PING_VALIDATOR_INTERVAL_SECS,
+        ) {
+            self.ping_validator_public_internet_task.tick().await?;
+        }
+        if attachment_manager.power_profile_allows_tick(
+            &self.ping_validator_local_network_task,
+            LOW_POWER_PING_VALIDATOR_INTERVAL_SECS,
+        ) {
+            self.ping_validator_local_network_task.tick().await?;
+        }
+        // Relay keepalives always run so we stay reachable
         self.ping_validator_public_internet_relay_task
             .tick()
             .await?;
@@ -209,7 +223,13 @@ impl RoutingTable {
         self.relay_management_task.tick().await?;
 
         // Get more nodes close to our node id
-        if !needs_bootstrap && !needs_peer_minimum_refresh {
+        if !needs_bootstrap
+            && !needs_peer_minimum_refresh
+            && attachment_manager.power_profile_allows_tick(
+                &self.closest_peers_refresh_task,
+                LOW_POWER_CLOSEST_PEERS_REFRESH_INTERVAL_SECS,
+            )
+        {
             // Run closest peers refresh task
             self.closest_peers_refresh_task.tick().await?;
         }
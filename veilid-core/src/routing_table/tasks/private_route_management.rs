@@ -107,7 +107,7 @@ impl RoutingTable {
 
     /// Test set of routes and remove the ones that don't test clean
     #[instrument(level = "trace", skip(self, stop_token), err)]
-    async fn test_route_set(
+    pub(in crate::routing_table) async fn test_route_set(
         &self,
         stop_token: StopToken,
         routes_needing_testing: Vec<RouteId>,
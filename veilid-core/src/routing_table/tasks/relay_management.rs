@@ -90,6 +90,11 @@ impl RoutingTable {
             return false;
         }
 
+        // In low power mode keep the relay we have for as long as it works
+        if self.attachment_manager().power_profile().is_low_power() {
+            return true;
+        }
+
         // See if our relay was optimized last long enough ago to consider getting a new one
         // if it is no longer fast enough
         let mut inner = self.inner.upgradable_read();
@@ -220,8 +225,12 @@ impl RoutingTable {
                 }
             }
             if !got_outbound_relay {
-                // Find a node in our routing table that is an acceptable inbound relay
-                if let Some(nr) = self.find_random_fast_node(
+                // Reuse the relay we had before a suspend if it still works,
+                // otherwise find a node in our routing table that is an acceptable inbound relay
+                if let Some(nr) = self.take_preferred_relay_node(cur_ts, &relay_node_filter) {
+                    veilid_log!(self debug "Resumed relay node selected: {}", nr);
+                    editor.set_relay_node(Some(nr));
+                } else if let Some(nr) = self.find_random_fast_node(
                     cur_ts,
                     &relay_node_filter,
                     RELAY_SELECTION_PERCENTILE,
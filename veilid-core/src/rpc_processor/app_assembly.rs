@@ -0,0 +1,940 @@
+use super::*;
+use range_set_blaze::*;
+
+const MAX_APP_ASSEMBLY_SOURCES: usize = 256;
+const MAX_APP_ASSEMBLIES_PER_SOURCE: usize = 16;
+const MAX_APP_ASSEMBLY_BUFFER_PER_SOURCE: usize = 4 * MAX_APP_FRAGMENTED_MESSAGE_LEN;
+const MAX_APP_ASSEMBLY_BUFFER_TOTAL: usize = 16 * MAX_APP_FRAGMENTED_MESSAGE_LEN;
+const MAX_APP_ASSEMBLY_AGE_US: u64 = 60_000_000;
+const MAX_APP_REPLIES_PER_SOURCE: usize = 16;
+const MAX_APP_REPLY_BUFFER_PER_SOURCE: usize = 4 * MAX_APP_FRAGMENTED_MESSAGE_LEN;
+const MAX_APP_REPLY_BUFFER_TOTAL: usize = 16 * MAX_APP_FRAGMENTED_MESSAGE_LEN;
+const MAX_APP_REPLY_AGE_US: u64 = 60_000_000;
+
+/// Where the fragments of a large app message come from.
+/// Routed fragments are told apart by the safety route they were sent over.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum AppAssemblySource {
+    Node(TypedNodeId),
+    SafetyRoute(PublicKey),
+}
+
+impl AppAssemblySource {
+    pub fn from_header(header: &MessageHeader) -> Self {
+        match &header.detail {
+            RPCMessageHeaderDetail::Direct(d) => {
+                AppAssemblySource::Node(d.envelope.get_sender_typed_id())
+            }
+            RPCMessageHeaderDetail::SafetyRouted(s) => {
+                AppAssemblySource::SafetyRoute(s.remote_safety_route)
+            }
+            RPCMessageHeaderDetail::PrivateRouted(p) => {
+                AppAssemblySource::SafetyRoute(p.remote_safety_route)
+            }
+        }
+    }
+
+    /// Safety routes cost nothing to make, so every routed sender shares one set of limits
+    fn bucket(&self) -> AppAssemblyBucket {
+        match self {
+            AppAssemblySource::Node(node_id) => AppAssemblyBucket::Node(*node_id),
+            AppAssemblySource::SafetyRoute(_) => AppAssemblyBucket::Routed,
+        }
+    }
+}
+
+/// The set of limits a source's partial messages count against
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum AppAssemblyBucket {
+    Node(TypedNodeId),
+    Routed,
+}
+
+struct AppAssembly {
+    source: AppAssemblySource,
+    message_id: u64,
+    total_length: u32,
+    timestamp: Timestamp,
+    // Received fragments by offset, only allocated as they arrive
+    fragments: BTreeMap<u32, Vec<u8>>,
+    received: usize,
+    parts: RangeSetBlaze<u32>,
+}
+
+impl fmt::Debug for AppAssembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppAssembly")
+            .field("source", &self.source)
+            .field("message_id", &self.message_id)
+            .field("total_length", &self.total_length)
+            .field("timestamp", &self.timestamp)
+            .field("received", &self.received)
+            .field("parts", &self.parts.ranges_len())
+            .finish()
+    }
+}
+
+impl AppAssembly {
+    fn new(cur_ts: Timestamp, source: AppAssemblySource, fragment: &AppFragment) -> Self {
+        Self {
+            source,
+            message_id: fragment.message_id,
+            total_length: fragment.total_length,
+            timestamp: cur_ts,
+            fragments: BTreeMap::new(),
+            received: 0,
+            parts: RangeSetBlaze::new(),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.parts.ranges_len() == 1
+            && self.parts.first() == Some(0)
+            && self.parts.last() == Some(self.total_length - 1)
+    }
+
+    /// Join the fragments of a complete message
+    fn into_data(self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.total_length as usize);
+        for fragment in self.fragments.into_values() {
+            data.extend_from_slice(&fragment);
+        }
+        data
+    }
+}
+
+#[derive(Debug, Default)]
+struct AppAssemblySourceMessages {
+    total_buffer: usize,
+    // Least recently added to first
+    assemblies: Vec<AppAssembly>,
+}
+
+impl AppAssemblySourceMessages {
+    fn last_timestamp(&self) -> Timestamp {
+        self.assemblies
+            .iter()
+            .map(|a| a.timestamp)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn remove_assembly(&mut self, index: usize) -> AppAssembly {
+        let assembly = self.assemblies.remove(index);
+        self.total_buffer -= assembly.received;
+        assembly
+    }
+}
+
+/// Reassembles large AppCall and AppMessage payloads from their fragments,
+/// keeping a bounded amount of partial messages for a bounded time per source and overall
+#[derive(Debug, Default)]
+pub(super) struct AppAssemblyTable {
+    total_buffer: usize,
+    sources: BTreeMap<AppAssemblyBucket, AppAssemblySourceMessages>,
+}
+
+impl AppAssemblyTable {
+    /// Add a fragment, returning the whole message once all of its fragments have arrived.
+    /// Fragments that do not fit in the message they claim to be part of are dropped.
+    pub fn insert_fragment(
+        &mut self,
+        cur_ts: Timestamp,
+        source: AppAssemblySource,
+        fragment: AppFragment,
+        data: &[u8],
+    ) -> Option<Vec<u8>> {
+        self.expire(cur_ts);
+
+        if fragment.validate(data.len()).is_err() {
+            return None;
+        }
+        let part_start = fragment.offset;
+        let part_end = fragment.offset + data.len() as u32 - 1;
+        let part = RangeSetBlaze::from_iter([part_start..=part_end]);
+        let bucket = source.bucket();
+
+        // Take out the message this fragment belongs to, so it is not dropped while making room for the fragment
+        let mut assembly = match self.take_assembly(bucket, source, fragment.message_id) {
+            Some(mut assembly) if assembly.total_length == fragment.total_length => {
+                if part.is_subset(&assembly.parts) {
+                    // Repeated fragment
+                    assembly.timestamp = cur_ts;
+                    self.make_room(bucket, assembly.received);
+                    self.put_assembly(bucket, assembly);
+                    return None;
+                }
+                if assembly.parts.is_disjoint(&part) {
+                    assembly
+                } else {
+                    // The message was redefined by this fragment, start over with it
+                    AppAssembly::new(cur_ts, source, &fragment)
+                }
+            }
+            // A new message, or one redefined with a different length
+            _ => AppAssembly::new(cur_ts, source, &fragment),
+        };
+
+        assembly.timestamp = cur_ts;
+        assembly.parts |= part;
+        assembly.fragments.insert(part_start, data.to_vec());
+        assembly.received += data.len();
+        if assembly.is_complete() {
+            return Some(assembly.into_data());
+        }
+
+        self.make_room(bucket, assembly.received);
+        self.put_assembly(bucket, assembly);
+
+        None
+    }
+
+    fn take_assembly(
+        &mut self,
+        bucket: AppAssemblyBucket,
+        source: AppAssemblySource,
+        message_id: u64,
+    ) -> Option<AppAssembly> {
+        let messages = self.sources.get_mut(&bucket)?;
+        let index = messages
+            .assemblies
+            .iter()
+            .position(|a| a.source == source && a.message_id == message_id)?;
+        let assembly = messages.remove_assembly(index);
+        if messages.assemblies.is_empty() {
+            self.sources.remove(&bucket);
+        }
+        self.total_buffer -= assembly.received;
+        Some(assembly)
+    }
+
+    fn put_assembly(&mut self, bucket: AppAssemblyBucket, assembly: AppAssembly) {
+        let messages = self.sources.entry(bucket).or_default();
+        messages.total_buffer += assembly.received;
+        self.total_buffer += assembly.received;
+        messages.assemblies.push(assembly);
+    }
+
+    /// Drop the least recently added to partial messages until there is room for another with `needed_space` bytes
+    fn make_room(&mut self, bucket: AppAssemblyBucket, needed_space: usize) {
+        // Make room for a new source by dropping the one that has been quiet the longest
+        if !self.sources.contains_key(&bucket) && self.sources.len() >= MAX_APP_ASSEMBLY_SOURCES {
+            let oldest = self
+                .sources
+                .iter()
+                .min_by_key(|(_, m)| m.last_timestamp())
+                .map(|(k, _)| *k);
+            if let Some(oldest) = oldest {
+                if let Some(messages) = self.sources.remove(&oldest) {
+                    self.total_buffer -= messages.total_buffer;
+                }
+            }
+        }
+
+        // Stay within the source's limits
+        if let Some(messages) = self.sources.get_mut(&bucket) {
+            while !messages.assemblies.is_empty()
+                && (messages.assemblies.len() >= MAX_APP_ASSEMBLIES_PER_SOURCE
+                    || messages.total_buffer + needed_space > MAX_APP_ASSEMBLY_BUFFER_PER_SOURCE)
+            {
+                let assembly = messages.remove_assembly(0);
+                self.total_buffer -= assembly.received;
+            }
+            if messages.assemblies.is_empty() {
+                self.sources.remove(&bucket);
+            }
+        }
+
+        // Stay within the limit shared by every source
+        while self.total_buffer + needed_space > MAX_APP_ASSEMBLY_BUFFER_TOTAL {
+            let oldest = self
+                .sources
+                .iter()
+                .filter_map(|(k, m)| m.assemblies.first().map(|a| (*k, a.timestamp)))
+                .min_by_key(|(_, ts)| *ts)
+                .map(|(k, _)| k);
+            let Some(oldest) = oldest else {
+                break;
+            };
+            let Some(messages) = self.sources.get_mut(&oldest) else {
+                break;
+            };
+            let assembly = messages.remove_assembly(0);
+            self.total_buffer -= assembly.received;
+            if messages.assemblies.is_empty() {
+                self.sources.remove(&oldest);
+            }
+        }
+    }
+
+    /// Drop partial messages that have not received a fragment in a while
+    fn expire(&mut self, cur_ts: Timestamp) {
+        let max_age = TimestampDuration::new(MAX_APP_ASSEMBLY_AGE_US);
+        let mut total_buffer = self.total_buffer;
+        self.sources.retain(|_, messages| {
+            let mut n = 0;
+            while n < messages.assemblies.len() {
+                if cur_ts.saturating_sub(messages.assemblies[n].timestamp) > max_age {
+                    let assembly = messages.remove_assembly(n);
+                    total_buffer -= assembly.received;
+                } else {
+                    n += 1;
+                }
+            }
+            !messages.assemblies.is_empty()
+        });
+        self.total_buffer = total_buffer;
+    }
+}
+
+struct AppReply {
+    source: AppAssemblySource,
+    message_id: u64,
+    timestamp: Timestamp,
+    data: Vec<u8>,
+}
+
+impl fmt::Debug for AppReply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppReply")
+            .field("source", &self.source)
+            .field("message_id", &self.message_id)
+            .field("timestamp", &self.timestamp)
+            .field("len", &self.data.len())
+            .finish()
+    }
+}
+
+/// Keeps large AppCall answers until the requester has fetched all of their fragments,
+/// keeping a bounded amount of answers for a bounded time per source and overall
+#[derive(Debug, Default)]
+pub(super) struct AppReplyTable {
+    total_buffer: usize,
+    // Least recently fetched from first
+    replies: Vec<AppReply>,
+}
+
+impl AppReplyTable {
+    /// Keep an answer whose first fragment is being sent so the requester can fetch the rest
+    pub fn insert_reply(
+        &mut self,
+        cur_ts: Timestamp,
+        source: AppAssemblySource,
+        message_id: u64,
+        data: Vec<u8>,
+    ) {
+        self.expire(cur_ts);
+
+        // Stay within the source's limits
+        let bucket = source.bucket();
+        loop {
+            let (count, buffer) = self
+                .replies
+                .iter()
+                .filter(|r| r.source.bucket() == bucket)
+                .fold((0usize, 0usize), |(c, b), r| (c + 1, b + r.data.len()));
+            if count < MAX_APP_REPLIES_PER_SOURCE
+                && buffer + data.len() <= MAX_APP_REPLY_BUFFER_PER_SOURCE
+            {
+                break;
+            }
+            let Some(index) = self
+                .replies
+                .iter()
+                .position(|r| r.source.bucket() == bucket)
+            else {
+                break;
+            };
+            self.remove_reply(index);
+        }
+
+        // Stay within the limit shared by every source
+        while !self.replies.is_empty()
+            && self.total_buffer + data.len() > MAX_APP_REPLY_BUFFER_TOTAL
+        {
+            self.remove_reply(0);
+        }
+
+        self.total_buffer += data.len();
+        self.replies.push(AppReply {
+            source,
+            message_id,
+            timestamp: cur_ts,
+            data,
+        });
+    }
+
+    /// Get the fragment of a kept answer that starts at the requested offset.
+    /// The answer is dropped once its last fragment has been fetched.
+    pub fn get_fragment(
+        &mut self,
+        cur_ts: Timestamp,
+        source: AppAssemblySource,
+        fragment: &AppFragment,
+    ) -> Option<Vec<u8>> {
+        self.expire(cur_ts);
+
+        let index = self
+            .replies
+            .iter()
+            .position(|r| r.source == source && r.message_id == fragment.message_id)?;
+        let total_length = self.replies[index].data.len();
+        if fragment.total_length as usize != total_length
+            || fragment.offset as usize >= total_length
+        {
+            return None;
+        }
+        let start = fragment.offset as usize;
+        let end = usize::min(start + APP_FRAGMENT_LEN, total_length);
+
+        let mut reply = self.remove_reply(index);
+        let data = reply.data[start..end].to_vec();
+        if end < total_length {
+            reply.timestamp = cur_ts;
+            self.total_buffer += reply.data.len();
+            self.replies.push(reply);
+        }
+        Some(data)
+    }
+
+    fn remove_reply(&mut self, index: usize) -> AppReply {
+        let reply = self.replies.remove(index);
+        self.total_buffer -= reply.data.len();
+        reply
+    }
+
+    /// Drop answers the requester has stopped fetching
+    fn expire(&mut self, cur_ts: Timestamp) {
+        let max_age = TimestampDuration::new(MAX_APP_REPLY_AGE_US);
+        let mut total_buffer = self.total_buffer;
+        self.replies.retain(|r| {
+            if cur_ts.saturating_sub(r.timestamp) > max_age {
+                total_buffer -= r.data.len();
+                false
+            } else {
+                true
+            }
+        });
+        self.total_buffer = total_buffer;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE_LEN: usize = 1000;
+
+    fn message(seed: u8) -> Vec<u8> {
+        (0..MESSAGE_LEN)
+            .map(|n| (n as u8).wrapping_add(seed))
+            .collect()
+    }
+
+    fn fragment(message_id: u64, offset: usize, total_length: usize) -> AppFragment {
+        AppFragment {
+            message_id,
+            offset: offset as u32,
+            total_length: total_length as u32,
+        }
+    }
+
+    fn node_source(n: u8) -> AppAssemblySource {
+        AppAssemblySource::Node(TypedNodeId::new(
+            CRYPTO_KIND_VLD0,
+            NodeId::new([n; CRYPTO_KEY_LENGTH]),
+        ))
+    }
+
+    fn route_source(n: u8) -> AppAssemblySource {
+        AppAssemblySource::SafetyRoute(PublicKey::new([n; CRYPTO_KEY_LENGTH]))
+    }
+
+    /// Insert the fragments of a message starting at each offset
+    fn insert(
+        table: &mut AppAssemblyTable,
+        ts: u64,
+        source: AppAssemblySource,
+        message_id: u64,
+        data: &[u8],
+        offsets: &[usize],
+    ) -> Option<Vec<u8>> {
+        let mut out = None;
+        for (n, start) in offsets.iter().enumerate() {
+            let end = offsets.get(n + 1).copied().unwrap_or(data.len());
+            out = table.insert_fragment(
+                Timestamp::new(ts),
+                source,
+                fragment(message_id, *start, data.len()),
+                &data[*start..end],
+            );
+        }
+        out
+    }
+
+    #[test]
+    fn test_in_and_out_of_order() {
+        let mut table = AppAssemblyTable::default();
+        let data = message(1);
+
+        assert_eq!(
+            insert(&mut table, 0, node_source(1), 1, &data, &[0, 300, 600]),
+            Some(data.clone())
+        );
+        assert_eq!(table.total_buffer, 0);
+        assert!(table.sources.is_empty());
+
+        let out_of_order = [(600, 1000), (0, 300), (300, 600)];
+        let mut out = None;
+        for (start, end) in out_of_order {
+            out = table.insert_fragment(
+                Timestamp::new(0),
+                node_source(1),
+                fragment(2, start, MESSAGE_LEN),
+                &data[start..end],
+            );
+        }
+        assert_eq!(out, Some(data));
+        assert_eq!(table.total_buffer, 0);
+    }
+
+    #[test]
+    fn test_repeated_and_overlapping_fragments() {
+        let mut table = AppAssemblyTable::default();
+        let data = message(2);
+        let source = node_source(1);
+
+        // Repeats are ignored
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 0, MESSAGE_LEN),
+                &data[0..500]
+            )
+            .is_none());
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 100, MESSAGE_LEN),
+                &data[100..200]
+            )
+            .is_none());
+        assert_eq!(table.total_buffer, 500);
+
+        // An overlap starts the message over
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 400, MESSAGE_LEN),
+                &data[400..700]
+            )
+            .is_none());
+        assert_eq!(table.total_buffer, 300);
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 700, MESSAGE_LEN),
+                &data[700..1000]
+            )
+            .is_none());
+        assert_eq!(
+            table.insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 0, MESSAGE_LEN),
+                &data[0..400]
+            ),
+            Some(data)
+        );
+        assert_eq!(table.total_buffer, 0);
+    }
+
+    #[test]
+    fn test_out_of_range_fragment() {
+        let mut table = AppAssemblyTable::default();
+        let data = message(3);
+
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                node_source(1),
+                fragment(1, 900, MESSAGE_LEN),
+                &data[0..200]
+            )
+            .is_none());
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                node_source(1),
+                fragment(1, u32::MAX as usize, MESSAGE_LEN),
+                &data[0..1]
+            )
+            .is_none());
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                node_source(1),
+                fragment(1, 0, MAX_APP_FRAGMENTED_MESSAGE_LEN + 1),
+                &data
+            )
+            .is_none());
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                node_source(1),
+                fragment(1, 0, MESSAGE_LEN),
+                &[]
+            )
+            .is_none());
+        assert_eq!(table.total_buffer, 0);
+        assert!(table.sources.is_empty());
+    }
+
+    #[test]
+    fn test_mismatched_total_length() {
+        let mut table = AppAssemblyTable::default();
+        let data = message(4);
+        let source = node_source(1);
+
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 0, MESSAGE_LEN),
+                &data[0..500]
+            )
+            .is_none());
+
+        // A different length redefines the message, dropping what was received under the old length
+        let short = &data[0..800];
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 500, short.len()),
+                &short[500..800]
+            )
+            .is_none());
+        assert_eq!(table.total_buffer, 300);
+        assert_eq!(
+            table.insert_fragment(
+                Timestamp::new(0),
+                source,
+                fragment(1, 0, short.len()),
+                &short[0..500]
+            ),
+            Some(short.to_vec())
+        );
+    }
+
+    #[test]
+    fn test_sources_are_kept_apart() {
+        let mut table = AppAssemblyTable::default();
+        let data_a = message(5);
+        let data_b = message(6);
+
+        // Same message id from different senders, including two routed senders sharing the routed limits
+        for source in [
+            node_source(1),
+            node_source(2),
+            route_source(1),
+            route_source(2),
+        ] {
+            assert!(table
+                .insert_fragment(
+                    Timestamp::new(0),
+                    source,
+                    fragment(1, 0, MESSAGE_LEN),
+                    &data_a[0..500]
+                )
+                .is_none());
+        }
+        assert_eq!(table.sources.len(), 3);
+        assert_eq!(table.total_buffer, 2000);
+
+        assert_eq!(
+            table.insert_fragment(
+                Timestamp::new(0),
+                route_source(2),
+                fragment(1, 500, MESSAGE_LEN),
+                &data_a[500..1000]
+            ),
+            Some(data_a.clone())
+        );
+        let mut mixed = data_a[0..500].to_vec();
+        mixed.extend_from_slice(&data_b[500..1000]);
+        assert_eq!(
+            table.insert_fragment(
+                Timestamp::new(0),
+                route_source(1),
+                fragment(1, 500, MESSAGE_LEN),
+                &data_b[500..1000]
+            ),
+            Some(mixed)
+        );
+        assert_eq!(table.total_buffer, 1000);
+    }
+
+    #[test]
+    fn test_eviction() {
+        let mut table = AppAssemblyTable::default();
+        let data = message(7);
+
+        // Too many partial messages from one source drops the least recently added to
+        for message_id in 0..MAX_APP_ASSEMBLIES_PER_SOURCE as u64 {
+            let _ = table.insert_fragment(
+                Timestamp::new(message_id),
+                route_source(1),
+                fragment(message_id, 0, MESSAGE_LEN),
+                &data[0..500],
+            );
+        }
+        assert_eq!(
+            table.sources[&AppAssemblyBucket::Routed].assemblies.len(),
+            MAX_APP_ASSEMBLIES_PER_SOURCE
+        );
+        let next_id = MAX_APP_ASSEMBLIES_PER_SOURCE as u64;
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(next_id),
+                route_source(2),
+                fragment(next_id, 0, MESSAGE_LEN),
+                &data[0..500]
+            )
+            .is_none());
+        let routed = &table.sources[&AppAssemblyBucket::Routed];
+        assert_eq!(routed.assemblies.len(), MAX_APP_ASSEMBLIES_PER_SOURCE);
+        assert_eq!(routed.assemblies[0].message_id, 1);
+        assert_eq!(table.total_buffer, MAX_APP_ASSEMBLIES_PER_SOURCE * 500);
+
+        // The first message was dropped, so completing it takes every fragment again
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(next_id),
+                route_source(1),
+                fragment(0, 500, MESSAGE_LEN),
+                &data[500..1000]
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_buffer_limits() {
+        let mut table = AppAssemblyTable::default();
+        let big = vec![0u8; MAX_APP_FRAGMENTED_MESSAGE_LEN];
+        let half = MAX_APP_FRAGMENTED_MESSAGE_LEN / 2;
+
+        // One source can only hold so many bytes
+        for message_id in 0..8u64 {
+            let _ = table.insert_fragment(
+                Timestamp::new(message_id),
+                node_source(1),
+                fragment(message_id, 0, big.len()),
+                &big[0..half + 1],
+            );
+        }
+        let messages = &table.sources[&node_source(1).bucket()];
+        assert_eq!(messages.assemblies.len(), 7);
+        assert!(messages.total_buffer <= MAX_APP_ASSEMBLY_BUFFER_PER_SOURCE);
+
+        // All sources together can only hold so many bytes
+        for n in 0..64u8 {
+            for message_id in 0..4u64 {
+                let _ = table.insert_fragment(
+                    Timestamp::new(100 + u64::from(n)),
+                    node_source(n),
+                    fragment(message_id, 0, big.len()),
+                    &big[0..half],
+                );
+            }
+        }
+        assert!(table.total_buffer <= MAX_APP_ASSEMBLY_BUFFER_TOTAL);
+        assert_eq!(
+            table.total_buffer,
+            table
+                .sources
+                .values()
+                .map(|m| m.total_buffer)
+                .sum::<usize>()
+        );
+        // The most recent source kept everything it sent
+        assert_eq!(table.sources[&node_source(63).bucket()].assemblies.len(), 4);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut table = AppAssemblyTable::default();
+        let data = message(8);
+
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(0),
+                node_source(1),
+                fragment(1, 0, MESSAGE_LEN),
+                &data[0..500]
+            )
+            .is_none());
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(MAX_APP_ASSEMBLY_AGE_US),
+                node_source(2),
+                fragment(1, 0, MESSAGE_LEN),
+                &data[0..500]
+            )
+            .is_none());
+
+        // Every fragment received resets the timeout
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(MAX_APP_ASSEMBLY_AGE_US),
+                node_source(1),
+                fragment(1, 500, MESSAGE_LEN),
+                &data[500..600]
+            )
+            .is_none());
+
+        // Still current because of the reset
+        assert_eq!(
+            table.insert_fragment(
+                Timestamp::new(2 * MAX_APP_ASSEMBLY_AGE_US),
+                node_source(1),
+                fragment(1, 600, MESSAGE_LEN),
+                &data[600..1000]
+            ),
+            Some(data.clone())
+        );
+
+        // Too old, so this fragment starts the message over
+        assert!(table
+            .insert_fragment(
+                Timestamp::new(2 * MAX_APP_ASSEMBLY_AGE_US + 1),
+                node_source(2),
+                fragment(1, 500, MESSAGE_LEN),
+                &data[500..1000]
+            )
+            .is_none());
+        assert_eq!(table.sources.len(), 1);
+        assert_eq!(table.total_buffer, 500);
+        assert_eq!(
+            table.sources[&node_source(2).bucket()].assemblies[0].parts,
+            RangeSetBlaze::from_iter([500u32..=999])
+        );
+    }
+
+    /// Fetch every fragment of a kept answer in order, reassembling them as a requester does
+    fn fetch_reply(
+        replies: &mut AppReplyTable,
+        table: &mut AppAssemblyTable,
+        source: AppAssemblySource,
+        message_id: u64,
+        total_length: usize,
+    ) -> Option<Vec<u8>> {
+        let mut offset = 0;
+        while offset < total_length {
+            let fragment = fragment(message_id, offset, total_length);
+            let data = replies.get_fragment(Timestamp::new(0), source, &fragment)?;
+            offset += data.len();
+            if let Some(out) = table.insert_fragment(Timestamp::new(0), source, fragment, &data) {
+                return Some(out);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_oversized_reply() {
+        let mut replies = AppReplyTable::default();
+        let mut table = AppAssemblyTable::default();
+        let data: Vec<u8> = (0..3 * APP_FRAGMENT_LEN + 100)
+            .map(|n| (n % 251) as u8)
+            .collect();
+
+        replies.insert_reply(Timestamp::new(0), route_source(1), 1, data.clone());
+        assert_eq!(replies.total_buffer, data.len());
+
+        // Another source can not fetch the answer, nor can a request with the wrong length
+        assert!(replies
+            .get_fragment(
+                Timestamp::new(0),
+                route_source(2),
+                &fragment(1, 0, data.len())
+            )
+            .is_none());
+        assert!(replies
+            .get_fragment(
+                Timestamp::new(0),
+                route_source(1),
+                &fragment(1, 0, data.len() - 1)
+            )
+            .is_none());
+
+        assert_eq!(
+            fetch_reply(&mut replies, &mut table, route_source(1), 1, data.len()),
+            Some(data)
+        );
+
+        // Fetching the last fragment drops the answer
+        assert!(replies.replies.is_empty());
+        assert_eq!(replies.total_buffer, 0);
+        assert_eq!(table.total_buffer, 0);
+    }
+
+    #[test]
+    fn test_reply_limits() {
+        let mut replies = AppReplyTable::default();
+        let big = vec![0u8; MAX_APP_FRAGMENTED_MESSAGE_LEN];
+
+        // One source can only have so many answers kept
+        for message_id in 0..8u64 {
+            replies.insert_reply(
+                Timestamp::new(message_id),
+                node_source(1),
+                message_id,
+                big.clone(),
+            );
+        }
+        assert_eq!(replies.replies.len(), 4);
+        assert_eq!(replies.replies[0].message_id, 4);
+
+        for message_id in 0..(MAX_APP_REPLIES_PER_SOURCE as u64 + 1) {
+            replies.insert_reply(
+                Timestamp::new(10),
+                route_source(message_id as u8),
+                message_id,
+                message(1),
+            );
+        }
+        assert_eq!(
+            replies
+                .replies
+                .iter()
+                .filter(|r| r.source.bucket() == AppAssemblyBucket::Routed)
+                .count(),
+            MAX_APP_REPLIES_PER_SOURCE
+        );
+
+        // All sources together can only have so many bytes kept
+        for n in 2..32u8 {
+            replies.insert_reply(Timestamp::new(20), node_source(n), 0, big.clone());
+        }
+        assert!(replies.total_buffer <= MAX_APP_REPLY_BUFFER_TOTAL);
+        assert_eq!(
+            replies.total_buffer,
+            replies.replies.iter().map(|r| r.data.len()).sum::<usize>()
+        );
+
+        // Answers that are not fetched from expire
+        replies.insert_reply(
+            Timestamp::new(20 + MAX_APP_REPLY_AGE_US + 1),
+            node_source(1),
+            9,
+            message(2),
+        );
+        assert_eq!(replies.replies.len(), 1);
+        assert_eq!(replies.total_buffer, MESSAGE_LEN);
+    }
+}
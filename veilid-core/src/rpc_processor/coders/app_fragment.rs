@@ -0,0 +1,60 @@
+use super::*;
+
+/// Size of every fragment of a large app message but the last
+pub const APP_FRAGMENT_LEN: usize = 32768;
+/// Largest app message that can be sent in fragments
+pub const MAX_APP_FRAGMENTED_MESSAGE_LEN: usize = 1048576;
+
+/// Position of one fragment of a large AppCall or AppMessage payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::rpc_processor) struct AppFragment {
+    pub message_id: u64,
+    pub offset: u32,
+    pub total_length: u32,
+}
+
+impl AppFragment {
+    /// Check that a fragment of this length fits in the large message
+    pub fn validate(&self, fragment_len: usize) -> Result<(), RPCError> {
+        if self.total_length as usize > MAX_APP_FRAGMENTED_MESSAGE_LEN {
+            return Err(RPCError::protocol("fragmented app message too long"));
+        }
+        if fragment_len == 0 {
+            return Err(RPCError::protocol("app message fragment is empty"));
+        }
+        if self.offset as usize + fragment_len > self.total_length as usize {
+            return Err(RPCError::protocol("app message fragment out of range"));
+        }
+        Ok(())
+    }
+
+    /// Check that a request for the fragment at this offset names part of a large message
+    pub fn validate_request(&self) -> Result<(), RPCError> {
+        if self.total_length as usize > MAX_APP_FRAGMENTED_MESSAGE_LEN {
+            return Err(RPCError::protocol("fragmented app message too long"));
+        }
+        if self.offset >= self.total_length {
+            return Err(RPCError::protocol("app message fragment out of range"));
+        }
+        Ok(())
+    }
+}
+
+pub(in crate::rpc_processor) fn encode_app_fragment(
+    app_fragment: &AppFragment,
+    builder: &mut veilid_capnp::app_fragment::Builder,
+) {
+    builder.set_message_id(app_fragment.message_id);
+    builder.set_offset(app_fragment.offset);
+    builder.set_total_length(app_fragment.total_length);
+}
+
+pub(in crate::rpc_processor) fn decode_app_fragment(
+    reader: &veilid_capnp::app_fragment::Reader,
+) -> AppFragment {
+    AppFragment {
+        message_id: reader.get_message_id(),
+        offset: reader.get_offset(),
+        total_length: reader.get_total_length(),
+    }
+}
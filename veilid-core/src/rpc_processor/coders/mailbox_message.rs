@@ -0,0 +1,23 @@
+use super::*;
+use crate::storage_manager::MailboxMessage;
+
+pub fn encode_mailbox_message(
+    mailbox_message: &MailboxMessage,
+    builder: &mut veilid_capnp::mailbox_message::Builder,
+) {
+    builder.set_id(mailbox_message.id());
+    builder.set_expiration(mailbox_message.expiration().as_u64());
+    builder.set_message(mailbox_message.message());
+}
+
+pub fn decode_mailbox_message(
+    reader: &veilid_capnp::mailbox_message::Reader,
+) -> Result<MailboxMessage, RPCError> {
+    let id = reader.get_id();
+    let expiration = Timestamp::new(reader.get_expiration());
+    let message = reader.get_message().map_err(RPCError::protocol)?;
+    if message.len() > MAX_MAILBOX_MESSAGE_LEN {
+        return Err(RPCError::protocol("mailbox message too long"));
+    }
+    Ok(MailboxMessage::new(id, expiration, message.to_vec()))
+}
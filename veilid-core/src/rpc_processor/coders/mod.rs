@@ -1,9 +1,11 @@
 mod address;
 mod address_type_set;
+mod app_fragment;
 mod dial_info;
 mod dial_info_class;
 mod dial_info_detail;
 mod key256;
+mod mailbox_message;
 mod network_class;
 mod node_info;
 mod node_status;
@@ -29,10 +31,12 @@ mod typed_signature;
 
 pub use address::*;
 pub use address_type_set::*;
+pub use app_fragment::*;
 pub use dial_info::*;
 pub use dial_info_class::*;
 pub use dial_info_detail::*;
 pub use key256::*;
+pub use mailbox_message::*;
 pub use network_class::*;
 pub use node_info::*;
 pub use node_status::*;
@@ -5,6 +5,8 @@ mod operation_app_message;
 mod operation_find_node;
 mod operation_get_value;
 mod operation_inspect_value;
+mod operation_mailbox_deposit;
+mod operation_mailbox_drain;
 mod operation_return_receipt;
 mod operation_route;
 mod operation_set_value;
@@ -31,6 +33,8 @@ mod operation_complete_tunnel;
 mod operation_start_tunnel;
 
 pub use operation_inspect_value::MAX_INSPECT_VALUE_A_SEQS_LEN;
+pub use operation_mailbox_deposit::MAX_MAILBOX_MESSAGE_LEN;
+pub use operation_mailbox_drain::MAX_MAILBOX_DRAIN_A_MESSAGES_LEN;
 
 pub(in crate::rpc_processor) use answer::*;
 pub(in crate::rpc_processor) use operation::*;
@@ -39,6 +43,8 @@ pub(in crate::rpc_processor) use operation_app_message::*;
 pub(in crate::rpc_processor) use operation_find_node::*;
 pub(in crate::rpc_processor) use operation_get_value::*;
 pub(in crate::rpc_processor) use operation_inspect_value::*;
+pub(in crate::rpc_processor) use operation_mailbox_deposit::*;
+pub(in crate::rpc_processor) use operation_mailbox_drain::*;
 pub(in crate::rpc_processor) use operation_return_receipt::*;
 pub(in crate::rpc_processor) use operation_route::*;
 pub(in crate::rpc_processor) use operation_set_value::*;
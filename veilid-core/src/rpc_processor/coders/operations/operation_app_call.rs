@@ -5,26 +5,75 @@ const MAX_APP_CALL_A_MESSAGE_LEN: usize = 32768;
 
 #[derive(Debug, Clone)]
 pub(in crate::rpc_processor) struct RPCOperationAppCallQ {
+    namespace: u32,
     message: Vec<u8>,
+    fragment: Option<AppFragment>,
+    fragmented_reply: bool,
+    reply_fragment: Option<AppFragment>,
 }
 
 impl RPCOperationAppCallQ {
-    pub fn new(message: Vec<u8>) -> Result<Self, RPCError> {
+    pub fn new(namespace: u32, message: Vec<u8>, fragmented_reply: bool) -> Result<Self, RPCError> {
+        if message.len() > MAX_APP_CALL_Q_MESSAGE_LEN {
+            return Err(RPCError::protocol("AppCallQ message too long to set"));
+        }
+        Ok(Self {
+            namespace,
+            message,
+            fragment: None,
+            fragmented_reply,
+            reply_fragment: None,
+        })
+    }
+
+    pub fn new_fragment(
+        namespace: u32,
+        message: Vec<u8>,
+        fragment: AppFragment,
+        fragmented_reply: bool,
+    ) -> Result<Self, RPCError> {
         if message.len() > MAX_APP_CALL_Q_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallQ message too long to set"));
         }
-        Ok(Self { message })
+        fragment.validate(message.len())?;
+        Ok(Self {
+            namespace,
+            message,
+            fragment: Some(fragment),
+            fragmented_reply,
+            reply_fragment: None,
+        })
+    }
+
+    pub fn new_reply_fragment(
+        namespace: u32,
+        reply_fragment: AppFragment,
+    ) -> Result<Self, RPCError> {
+        reply_fragment.validate_request()?;
+        Ok(Self {
+            namespace,
+            message: vec![],
+            fragment: None,
+            fragmented_reply: true,
+            reply_fragment: Some(reply_fragment),
+        })
     }
     pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
         Ok(())
     }
 
-    // pub fn message(&self) -> &[u8] {
-    //     &self.message
-    // }
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
 
-    pub fn destructure(self) -> Vec<u8> {
-        self.message
+    pub fn destructure(self) -> (u32, Vec<u8>, Option<AppFragment>, bool, Option<AppFragment>) {
+        (
+            self.namespace,
+            self.message,
+            self.fragment,
+            self.fragmented_reply,
+            self.reply_fragment,
+        )
     }
 
     pub fn decode(
@@ -35,15 +84,51 @@ impl RPCOperationAppCallQ {
         if mr.len() > MAX_APP_CALL_Q_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallQ message too long to set"));
         }
+        let fragment = if reader.has_fragment() {
+            let fr = reader.get_fragment().map_err(RPCError::protocol)?;
+            let fragment = decode_app_fragment(&fr);
+            fragment.validate(mr.len())?;
+            Some(fragment)
+        } else {
+            None
+        };
+        let reply_fragment = if reader.has_reply_fragment() {
+            if !mr.is_empty() || fragment.is_some() {
+                return Err(RPCError::protocol(
+                    "AppCallQ reply fragment request must not carry a message",
+                ));
+            }
+            let fr = reader.get_reply_fragment().map_err(RPCError::protocol)?;
+            let reply_fragment = decode_app_fragment(&fr);
+            reply_fragment.validate_request()?;
+            Some(reply_fragment)
+        } else {
+            None
+        };
         Ok(Self {
+            namespace: reader.get_namespace(),
             message: mr.to_vec(),
+            fragment,
+            fragmented_reply: reader.get_fragmented_reply(),
+            reply_fragment,
         })
     }
     pub fn encode(
         &self,
         builder: &mut veilid_capnp::operation_app_call_q::Builder,
     ) -> Result<(), RPCError> {
+        builder.set_namespace(self.namespace);
         builder.set_message(&self.message);
+        if let Some(fragment) = &self.fragment {
+            encode_app_fragment(fragment, &mut builder.reborrow().init_fragment());
+        }
+        builder.set_fragmented_reply(self.fragmented_reply);
+        if let Some(reply_fragment) = &self.reply_fragment {
+            encode_app_fragment(
+                reply_fragment,
+                &mut builder.reborrow().init_reply_fragment(),
+            );
+        }
         Ok(())
     }
 }
@@ -53,6 +138,7 @@ impl RPCOperationAppCallQ {
 #[derive(Debug, Clone)]
 pub(in crate::rpc_processor) struct RPCOperationAppCallA {
     message: Vec<u8>,
+    fragment: Option<AppFragment>,
 }
 
 impl RPCOperationAppCallA {
@@ -60,7 +146,21 @@ impl RPCOperationAppCallA {
         if message.len() > MAX_APP_CALL_A_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallA message too long to set"));
         }
-        Ok(Self { message })
+        Ok(Self {
+            message,
+            fragment: None,
+        })
+    }
+
+    pub fn new_fragment(message: Vec<u8>, fragment: AppFragment) -> Result<Self, RPCError> {
+        if message.len() > MAX_APP_CALL_A_MESSAGE_LEN {
+            return Err(RPCError::protocol("AppCallA message too long to set"));
+        }
+        fragment.validate(message.len())?;
+        Ok(Self {
+            message,
+            fragment: Some(fragment),
+        })
     }
 
     pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
@@ -71,8 +171,8 @@ impl RPCOperationAppCallA {
     //     &self.message
     // }
 
-    pub fn destructure(self) -> Vec<u8> {
-        self.message
+    pub fn destructure(self) -> (Vec<u8>, Option<AppFragment>) {
+        (self.message, self.fragment)
     }
 
     pub fn decode(
@@ -83,8 +183,17 @@ impl RPCOperationAppCallA {
         if mr.len() > MAX_APP_CALL_A_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallA message too long to set"));
         }
+        let fragment = if reader.has_fragment() {
+            let fr = reader.get_fragment().map_err(RPCError::protocol)?;
+            let fragment = decode_app_fragment(&fr);
+            fragment.validate(mr.len())?;
+            Some(fragment)
+        } else {
+            None
+        };
         Ok(Self {
             message: mr.to_vec(),
+            fragment,
         })
     }
     pub fn encode(
@@ -92,6 +201,9 @@ impl RPCOperationAppCallA {
         builder: &mut veilid_capnp::operation_app_call_a::Builder,
     ) -> Result<(), RPCError> {
         builder.set_message(&self.message);
+        if let Some(fragment) = &self.fragment {
+            encode_app_fragment(fragment, &mut builder.reborrow().init_fragment());
+        }
         Ok(())
     }
 }
@@ -4,15 +4,37 @@ const MAX_APP_MESSAGE_MESSAGE_LEN: usize = 32768;
 
 #[derive(Debug, Clone)]
 pub(in crate::rpc_processor) struct RPCOperationAppMessage {
+    namespace: u32,
     message: Vec<u8>,
+    fragment: Option<AppFragment>,
 }
 
 impl RPCOperationAppMessage {
-    pub fn new(message: Vec<u8>) -> Result<Self, RPCError> {
+    pub fn new(namespace: u32, message: Vec<u8>) -> Result<Self, RPCError> {
         if message.len() > MAX_APP_MESSAGE_MESSAGE_LEN {
             return Err(RPCError::protocol("AppMessage message too long to set"));
         }
-        Ok(Self { message })
+        Ok(Self {
+            namespace,
+            message,
+            fragment: None,
+        })
+    }
+
+    pub fn new_fragment(
+        namespace: u32,
+        message: Vec<u8>,
+        fragment: AppFragment,
+    ) -> Result<Self, RPCError> {
+        if message.len() > MAX_APP_MESSAGE_MESSAGE_LEN {
+            return Err(RPCError::protocol("AppMessage message too long to set"));
+        }
+        fragment.validate(message.len())?;
+        Ok(Self {
+            namespace,
+            message,
+            fragment: Some(fragment),
+        })
     }
 
     pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
@@ -22,8 +44,8 @@ impl RPCOperationAppMessage {
     // pub fn message(&self) -> &[u8] {
     //     &self.message
     // }
-    pub fn destructure(self) -> Vec<u8> {
-        self.message
+    pub fn destructure(self) -> (u32, Vec<u8>, Option<AppFragment>) {
+        (self.namespace, self.message, self.fragment)
     }
 
     pub fn decode(
@@ -34,15 +56,29 @@ impl RPCOperationAppMessage {
         if mr.len() > MAX_APP_MESSAGE_MESSAGE_LEN {
             return Err(RPCError::protocol("AppMessage message too long to set"));
         }
+        let fragment = if reader.has_fragment() {
+            let fr = reader.get_fragment().map_err(RPCError::protocol)?;
+            let fragment = decode_app_fragment(&fr);
+            fragment.validate(mr.len())?;
+            Some(fragment)
+        } else {
+            None
+        };
         Ok(Self {
+            namespace: reader.get_namespace(),
             message: mr.to_vec(),
+            fragment,
         })
     }
     pub fn encode(
         &self,
         builder: &mut veilid_capnp::operation_app_message::Builder,
     ) -> Result<(), RPCError> {
+        builder.set_namespace(self.namespace);
         builder.set_message(&self.message);
+        if let Some(fragment) = &self.fragment {
+            encode_app_fragment(fragment, &mut builder.reborrow().init_fragment());
+        }
         Ok(())
     }
 }
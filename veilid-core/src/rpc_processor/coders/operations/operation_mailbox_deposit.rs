@@ -0,0 +1,102 @@
+use super::*;
+
+pub const MAX_MAILBOX_MESSAGE_LEN: usize = 32768;
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationMailboxDepositQ {
+    mailbox: TypedPublicKey,
+    expiration: u64,
+    message: Vec<u8>,
+}
+
+impl RPCOperationMailboxDepositQ {
+    pub fn new(
+        mailbox: TypedPublicKey,
+        expiration: u64,
+        message: Vec<u8>,
+    ) -> Result<Self, RPCError> {
+        if message.len() > MAX_MAILBOX_MESSAGE_LEN {
+            return Err(RPCError::protocol(
+                "MailboxDepositQ message too long to set",
+            ));
+        }
+        Ok(Self {
+            mailbox,
+            expiration,
+            message,
+        })
+    }
+    pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        Ok(())
+    }
+
+    pub fn destructure(self) -> (TypedPublicKey, u64, Vec<u8>) {
+        (self.mailbox, self.expiration, self.message)
+    }
+
+    pub fn decode(
+        _decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_mailbox_deposit_q::Reader,
+    ) -> Result<Self, RPCError> {
+        let m_reader = reader.get_mailbox().map_err(RPCError::protocol)?;
+        let mailbox = decode_typed_public_key(&m_reader)?;
+        let expiration = reader.get_expiration();
+        let mr = reader.get_message().map_err(RPCError::protocol)?;
+        if mr.len() > MAX_MAILBOX_MESSAGE_LEN {
+            return Err(RPCError::protocol(
+                "MailboxDepositQ message too long to set",
+            ));
+        }
+        Ok(Self {
+            mailbox,
+            expiration,
+            message: mr.to_vec(),
+        })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_mailbox_deposit_q::Builder,
+    ) -> Result<(), RPCError> {
+        let mut m_builder = builder.reborrow().init_mailbox();
+        encode_typed_public_key(&self.mailbox, &mut m_builder);
+        builder.set_expiration(self.expiration);
+        builder.set_message(&self.message);
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationMailboxDepositA {
+    accepted: bool,
+}
+
+impl RPCOperationMailboxDepositA {
+    pub fn new(accepted: bool) -> Result<Self, RPCError> {
+        Ok(Self { accepted })
+    }
+
+    pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        Ok(())
+    }
+
+    pub fn destructure(self) -> bool {
+        self.accepted
+    }
+
+    pub fn decode(
+        _decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_mailbox_deposit_a::Reader,
+    ) -> Result<Self, RPCError> {
+        let accepted = reader.get_accepted();
+        Ok(Self { accepted })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_mailbox_deposit_a::Builder,
+    ) -> Result<(), RPCError> {
+        builder.set_accepted(self.accepted);
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use super::*;
+use crate::storage_manager::MailboxMessage;
+
+pub const MAX_MAILBOX_DRAIN_A_MESSAGES_LEN: usize = 32;
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationMailboxDrainQ {
+    mailbox: TypedPublicKey,
+    acknowledge: u64,
+    timestamp: u64,
+    signature: Signature,
+}
+
+impl RPCOperationMailboxDrainQ {
+    pub fn new(
+        host: TypedNodeId,
+        mailbox: KeyPair,
+        acknowledge: u64,
+        timestamp: u64,
+        vcrypto: &CryptoSystemGuard<'_>,
+    ) -> Result<Self, RPCError> {
+        let mailbox_key = TypedPublicKey::new(vcrypto.kind(), mailbox.key);
+        let signature_data = Self::make_signature_data(&host, &mailbox_key, acknowledge, timestamp);
+        let signature = vcrypto
+            .sign(&mailbox.key, &mailbox.secret, &signature_data)
+            .map_err(RPCError::protocol)?;
+
+        Ok(Self {
+            mailbox: mailbox_key,
+            acknowledge,
+            timestamp,
+            signature,
+        })
+    }
+
+    // signature covers: host node id, mailbox, acknowledge, timestamp, using mailbox key
+    fn make_signature_data(
+        host: &TypedNodeId,
+        mailbox: &TypedPublicKey,
+        acknowledge: u64,
+        timestamp: u64,
+    ) -> Vec<u8> {
+        let mut sig_data = Vec::with_capacity(2 * (4 + PUBLIC_KEY_LENGTH) + 8 + 8);
+        sig_data.extend_from_slice(&host.kind.0);
+        sig_data.extend_from_slice(&host.value.bytes);
+        sig_data.extend_from_slice(&mailbox.kind.0);
+        sig_data.extend_from_slice(&mailbox.value.bytes);
+        sig_data.extend_from_slice(&acknowledge.to_le_bytes());
+        sig_data.extend_from_slice(&timestamp.to_le_bytes());
+        sig_data
+    }
+
+    pub fn validate(&mut self, validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        let crypto = validate_context.crypto();
+        let Some(vcrypto) = crypto.get(self.mailbox.kind) else {
+            return Err(RPCError::protocol("unsupported cryptosystem"));
+        };
+
+        // Requests signed for another host can not be replayed to us
+        let host = validate_context.routing_table().node_id(self.mailbox.kind);
+        let sig_data =
+            Self::make_signature_data(&host, &self.mailbox, self.acknowledge, self.timestamp);
+        if !vcrypto
+            .verify(&self.mailbox.value, &sig_data, &self.signature)
+            .map_err(RPCError::protocol)?
+        {
+            return Err(RPCError::protocol("failed to validate mailbox signature"));
+        }
+
+        Ok(())
+    }
+
+    pub fn destructure(self) -> (TypedPublicKey, u64, u64) {
+        (self.mailbox, self.acknowledge, self.timestamp)
+    }
+
+    pub fn decode(
+        _decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_mailbox_drain_q::Reader,
+    ) -> Result<Self, RPCError> {
+        let m_reader = reader.get_mailbox().map_err(RPCError::protocol)?;
+        let mailbox = decode_typed_public_key(&m_reader)?;
+        let acknowledge = reader.get_acknowledge();
+        let timestamp = reader.get_timestamp();
+        let s_reader = reader.get_signature().map_err(RPCError::protocol)?;
+        let signature = decode_signature512(&s_reader);
+
+        Ok(Self {
+            mailbox,
+            acknowledge,
+            timestamp,
+            signature,
+        })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_mailbox_drain_q::Builder,
+    ) -> Result<(), RPCError> {
+        let mut m_builder = builder.reborrow().init_mailbox();
+        encode_typed_public_key(&self.mailbox, &mut m_builder);
+        builder.set_acknowledge(self.acknowledge);
+        builder.set_timestamp(self.timestamp);
+        let mut s_builder = builder.reborrow().init_signature();
+        encode_signature512(&self.signature, &mut s_builder);
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationMailboxDrainA {
+    accepted: bool,
+    messages: Vec<MailboxMessage>,
+}
+
+impl RPCOperationMailboxDrainA {
+    pub fn new(accepted: bool, messages: Vec<MailboxMessage>) -> Result<Self, RPCError> {
+        if messages.len() > MAX_MAILBOX_DRAIN_A_MESSAGES_LEN {
+            return Err(RPCError::protocol(
+                "encoded MailboxDrainA messages length too long",
+            ));
+        }
+        Ok(Self { accepted, messages })
+    }
+
+    pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        if !self.accepted && !self.messages.is_empty() {
+            return Err(RPCError::protocol(
+                "MailboxDrainA has messages but was not accepted",
+            ));
+        }
+        // Messages are returned oldest first
+        if self.messages.windows(2).any(|w| w[0].id() >= w[1].id()) {
+            return Err(RPCError::protocol("MailboxDrainA messages out of order"));
+        }
+        Ok(())
+    }
+
+    pub fn destructure(self) -> (bool, Vec<MailboxMessage>) {
+        (self.accepted, self.messages)
+    }
+
+    pub fn decode(
+        _decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_mailbox_drain_a::Reader,
+    ) -> Result<Self, RPCError> {
+        let accepted = reader.get_accepted();
+        let m_reader = reader.get_messages().map_err(RPCError::protocol)?;
+        if m_reader.len() as usize > MAX_MAILBOX_DRAIN_A_MESSAGES_LEN {
+            return Err(RPCError::protocol(
+                "decoded MailboxDrainA messages length too long",
+            ));
+        }
+        let mut messages = Vec::<MailboxMessage>::with_capacity(m_reader.len() as usize);
+        for m in m_reader.iter() {
+            messages.push(decode_mailbox_message(&m)?);
+        }
+
+        Ok(Self { accepted, messages })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_mailbox_drain_a::Builder,
+    ) -> Result<(), RPCError> {
+        builder.set_accepted(self.accepted);
+        let mut m_builder = builder.reborrow().init_messages(
+            self.messages
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid messages list length"))?,
+        );
+        for (i, message) in self.messages.iter().enumerate() {
+            let mut mb = m_builder.reborrow().get(i as u32);
+            encode_mailbox_message(message, &mut mb);
+        }
+        Ok(())
+    }
+}
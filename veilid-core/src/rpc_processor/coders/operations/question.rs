@@ -54,6 +54,8 @@ pub(in crate::rpc_processor) enum RPCQuestionDetail {
     SetValueQ(Box<RPCOperationSetValueQ>),
     WatchValueQ(Box<RPCOperationWatchValueQ>),
     InspectValueQ(Box<RPCOperationInspectValueQ>),
+    MailboxDepositQ(Box<RPCOperationMailboxDepositQ>),
+    MailboxDrainQ(Box<RPCOperationMailboxDrainQ>),
     #[cfg(feature = "unstable-blockstore")]
     SupplyBlockQ(Box<RPCOperationSupplyBlockQ>),
     #[cfg(feature = "unstable-blockstore")]
@@ -76,6 +78,8 @@ impl RPCQuestionDetail {
             RPCQuestionDetail::SetValueQ(_) => "SetValueQ",
             RPCQuestionDetail::WatchValueQ(_) => "WatchValueQ",
             RPCQuestionDetail::InspectValueQ(_) => "InspectValueQ",
+            RPCQuestionDetail::MailboxDepositQ(_) => "MailboxDepositQ",
+            RPCQuestionDetail::MailboxDrainQ(_) => "MailboxDrainQ",
             #[cfg(feature = "unstable-blockstore")]
             RPCQuestionDetail::SupplyBlockQ(_) => "SupplyBlockQ",
             #[cfg(feature = "unstable-blockstore")]
@@ -97,6 +101,8 @@ impl RPCQuestionDetail {
             RPCQuestionDetail::SetValueQ(r) => r.validate(validate_context),
             RPCQuestionDetail::WatchValueQ(r) => r.validate(validate_context),
             RPCQuestionDetail::InspectValueQ(r) => r.validate(validate_context),
+            RPCQuestionDetail::MailboxDepositQ(r) => r.validate(validate_context),
+            RPCQuestionDetail::MailboxDrainQ(r) => r.validate(validate_context),
             #[cfg(feature = "unstable-blockstore")]
             RPCQuestionDetail::SupplyBlockQ(r) => r.validate(validate_context),
             #[cfg(feature = "unstable-blockstore")]
@@ -151,6 +157,16 @@ impl RPCQuestionDetail {
                 let out = RPCOperationInspectValueQ::decode(decode_context, &op_reader)?;
                 RPCQuestionDetail::InspectValueQ(Box::new(out))
             }
+            veilid_capnp::question::detail::MailboxDepositQ(r) => {
+                let op_reader = r.map_err(RPCError::protocol)?;
+                let out = RPCOperationMailboxDepositQ::decode(decode_context, &op_reader)?;
+                RPCQuestionDetail::MailboxDepositQ(Box::new(out))
+            }
+            veilid_capnp::question::detail::MailboxDrainQ(r) => {
+                let op_reader = r.map_err(RPCError::protocol)?;
+                let out = RPCOperationMailboxDrainQ::decode(decode_context, &op_reader)?;
+                RPCQuestionDetail::MailboxDrainQ(Box::new(out))
+            }
             #[cfg(feature = "unstable-blockstore")]
             veilid_capnp::question::detail::SupplyBlockQ(r) => {
                 let op_reader = r.map_err(RPCError::protocol)?;
@@ -200,6 +216,12 @@ impl RPCQuestionDetail {
             RPCQuestionDetail::InspectValueQ(d) => {
                 d.encode(&mut builder.reborrow().init_inspect_value_q())
             }
+            RPCQuestionDetail::MailboxDepositQ(d) => {
+                d.encode(&mut builder.reborrow().init_mailbox_deposit_q())
+            }
+            RPCQuestionDetail::MailboxDrainQ(d) => {
+                d.encode(&mut builder.reborrow().init_mailbox_drain_q())
+            }
             #[cfg(feature = "unstable-blockstore")]
             RPCQuestionDetail::SupplyBlockQ(d) => {
                 d.encode(&mut builder.reborrow().init_supply_block_q())
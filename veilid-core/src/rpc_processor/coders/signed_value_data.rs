@@ -11,6 +11,22 @@ pub fn encode_signed_value_data(
     encode_key256(signed_value_data.value_data().writer(), &mut wb);
     let mut sb = builder.reborrow().init_signature();
     encode_signature512(signed_value_data.signature(), &mut sb);
+    let cosignatures = signed_value_data.cosignatures();
+    if !cosignatures.is_empty() {
+        let mut cb = builder.reborrow().init_cosignatures(
+            cosignatures
+                .len()
+                .try_into()
+                .map_err(RPCError::map_protocol("too many cosignatures"))?,
+        );
+        for (i, cosignature) in cosignatures.iter().enumerate() {
+            let mut cosb = cb.reborrow().get(i as u32);
+            let mut cwb = cosb.reborrow().init_signer();
+            encode_key256(cosignature.signer(), &mut cwb);
+            let mut csb = cosb.reborrow().init_signature();
+            encode_signature512(cosignature.signature(), &mut csb);
+        }
+    }
     Ok(())
 }
 
@@ -23,9 +39,25 @@ pub fn decode_signed_value_data(
     let writer = decode_key256(&wr);
     let sr = reader.get_signature().map_err(RPCError::protocol)?;
     let signature = decode_signature512(&sr);
+    let cosignatures = if reader.has_cosignatures() {
+        let cr = reader.get_cosignatures().map_err(RPCError::protocol)?;
+        let mut cosignatures = Vec::with_capacity(cr.len() as usize);
+        for cosr in cr.iter() {
+            let cwr = cosr.get_signer().map_err(RPCError::protocol)?;
+            let csr = cosr.get_signature().map_err(RPCError::protocol)?;
+            cosignatures.push(DHTCosignature::new(
+                decode_key256(&cwr),
+                decode_signature512(&csr),
+            ));
+        }
+        cosignatures
+    } else {
+        Vec::new()
+    };
 
     Ok(SignedValueData::new(
         ValueData::new_with_seq(seq, data, writer).map_err(RPCError::protocol)?,
         signature,
-    ))
+    )
+    .with_cosignatures(cosignatures))
 }
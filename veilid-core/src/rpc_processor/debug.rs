@@ -13,6 +13,62 @@ impl RPCProcessor {
             "RPC Worker Process Latency:\n{}",
             indent_all_string(&inner.rpc_worker_process_latency)
         );
+        out += "\n";
+        if let Some(queue) = inner.rpc_worker_queue.as_ref() {
+            out += "RPC Worker Queues:\n";
+            for (class, m) in queue.get_metrics() {
+                out += &format!(
+                    "    {}: {}/{} weight={} enqueued={} dropped={}\n",
+                    class, m.depth, m.capacity, m.weight, m.enqueued, m.dropped
+                );
+            }
+        }
         out
     }
+
+    pub(super) fn stats_operation_sent(&self, desc: &'static str) {
+        let mut inner = self.inner.lock();
+        *inner.rpc_operations_sent.entry(desc).or_default() += 1;
+    }
+
+    pub(super) fn stats_operation_rcvd(&self, desc: &'static str) {
+        let mut inner = self.inner.lock();
+        *inner.rpc_operations_rcvd.entry(desc).or_default() += 1;
+    }
+
+    pub fn get_metrics(&self) -> VeilidMetricsRPC {
+        let questions_in_flight = self.waiting_rpc_table.get_operation_ids().len() as u32;
+        let inner = self.inner.lock();
+        VeilidMetricsRPC {
+            queue_depth: inner
+                .rpc_worker_queue
+                .as_ref()
+                .map(|q| q.depth())
+                .unwrap_or_default(),
+            queue_capacity: inner
+                .rpc_worker_queue
+                .as_ref()
+                .map(|q| q.capacity())
+                .unwrap_or(self.queue_config.queue_size),
+            queues: inner
+                .rpc_worker_queue
+                .as_ref()
+                .map(|q| q.get_metrics())
+                .unwrap_or_default(),
+            worker_count: self.concurrency,
+            questions_in_flight,
+            dequeue_latency: inner.rpc_worker_dequeue_latency.clone(),
+            process_latency: inner.rpc_worker_process_latency.clone(),
+            operations_rcvd: inner
+                .rpc_operations_rcvd
+                .iter()
+                .map(|(k, v)| (k.to_string(), *v))
+                .collect(),
+            operations_sent: inner
+                .rpc_operations_sent
+                .iter()
+                .map(|(k, v)| (k.to_string(), *v))
+                .collect(),
+        }
+    }
 }
@@ -17,6 +17,8 @@ pub(crate) enum RPCError {
     TryAgain(String),
     #[error("[RPCError: Ignore({0})]")]
     Ignore(String),
+    #[error("[RPCError: Cancelled]")]
+    Cancelled,
 }
 
 impl RPCError {
@@ -81,6 +83,7 @@ impl From<RPCError> for VeilidAPIError {
             RPCError::Network(message) => VeilidAPIError::Generic { message },
             RPCError::TryAgain(message) => VeilidAPIError::TryAgain { message },
             RPCError::Ignore(message) => VeilidAPIError::Generic { message },
+            RPCError::Cancelled => VeilidAPIError::Cancelled,
         }
     }
 }
@@ -97,6 +100,7 @@ impl<T> ToRPCNetworkResult<T> for VeilidAPIResult<T> {
             Err(VeilidAPIError::TryAgain { message }) => Err(RPCError::TryAgain(message)),
             Err(VeilidAPIError::Timeout) => Ok(NetworkResult::timeout()),
             Err(VeilidAPIError::Unimplemented { message }) => Err(RPCError::Unimplemented(message)),
+            Err(VeilidAPIError::Cancelled) => Err(RPCError::Cancelled),
             Err(e) => Err(RPCError::internal(e)),
             Ok(v) => Ok(NetworkResult::value(v)),
         }
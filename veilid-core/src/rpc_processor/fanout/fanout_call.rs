@@ -154,6 +154,7 @@ pub(crate) struct FanoutCall<'a> {
     node_info_filter: FanoutNodeInfoFilter,
     call_routine: FanoutCallRoutine,
     check_done: FanoutCheckDone,
+    stop_token: Option<StopToken>,
 }
 
 impl VeilidComponentRegistryAccessor for FanoutCall<'_> {
@@ -185,9 +186,16 @@ impl<'a> FanoutCall<'a> {
             node_info_filter,
             call_routine,
             check_done,
+            stop_token: None,
         }
     }
 
+    /// Allow the fanout to be cancelled, in which case it returns RPCError::Cancelled
+    pub fn with_stop_token(mut self, stop_token: Option<StopToken>) -> Self {
+        self.stop_token = stop_token;
+        self
+    }
+
     #[instrument(level = "trace", target = "fanout", skip_all)]
     fn evaluate_done(&self, ctx: &mut FanoutContext) -> bool {
         // If we already finished, just return
@@ -471,7 +479,7 @@ impl<'a> FanoutCall<'a> {
             }
         }
         // Wait for them to complete
-        match timeout(
+        let fanout_fut = timeout(
             timeout_ms,
             async {
                 loop {
@@ -492,9 +500,20 @@ impl<'a> FanoutCall<'a> {
                 }
             }
             .in_current_span(),
-        )
-        .await
-        {
+        );
+        let res = match self.stop_token.clone() {
+            Some(stop_token) => match fanout_fut.timeout_at(stop_token).await {
+                Ok(res) => res,
+                Err(_) => {
+                    // Cancelled, drop the outstanding calls and don't return a partial result
+                    let context_locked = &*context.lock();
+                    veilid_log!(self debug "Cancelled FanoutQueue: {}", context_locked.fanout_queue);
+                    return Err(RPCError::Cancelled);
+                }
+            },
+            None => fanout_fut.await,
+        };
+        match res {
             Ok(Ok(())) => {
                 // Finished, either by exhaustion or consensus,
                 // time to return whatever value we came up with
@@ -1,6 +1,7 @@
 use super::*;
 
 mod answer;
+mod app_assembly;
 mod coders;
 mod debug;
 mod destination;
@@ -15,6 +16,7 @@ mod rpc_app_message;
 mod rpc_find_node;
 mod rpc_get_value;
 mod rpc_inspect_value;
+mod rpc_mailbox;
 mod rpc_return_receipt;
 mod rpc_route;
 mod rpc_set_value;
@@ -24,6 +26,7 @@ mod rpc_validate_dial_info;
 mod rpc_value_changed;
 mod rpc_watch_value;
 mod rpc_worker;
+mod rpc_worker_queue;
 mod sender_info;
 mod sender_peer_info;
 
@@ -43,7 +46,7 @@ pub(crate) use answer::*;
 pub(crate) use coders::{
     builder_to_vec, decode_private_route, encode_node_info, encode_private_route, encode_route_hop,
     encode_signed_direct_node_info, encode_typed_node_id, RPCDecodeContext,
-    MAX_INSPECT_VALUE_A_SEQS_LEN,
+    MAX_INSPECT_VALUE_A_SEQS_LEN, MAX_MAILBOX_DRAIN_A_MESSAGES_LEN, MAX_MAILBOX_MESSAGE_LEN,
 };
 pub(crate) use destination::*;
 pub(crate) use error::*;
@@ -53,12 +56,14 @@ pub(crate) use sender_info::*;
 use futures_util::StreamExt as _;
 use stop_token::future::FutureExt as _;
 
+use app_assembly::*;
 use coders::*;
 use message::*;
 use message_header::*;
 use operation_waiter::*;
 use rendered_operation::*;
 use rpc_worker::*;
+use rpc_worker_queue::*;
 use sender_peer_info::*;
 
 use crypto::*;
@@ -92,6 +97,16 @@ struct WaitableReply {
     handle: OperationWaitHandle<Message, Option<QuestionContext>>,
     _opt_connection_ref_scope: Option<ConnectionRefScope>,
     context: WaitableReplyContext,
+    stop_token: Option<StopToken>,
+}
+
+impl WaitableReply {
+    /// Limit the wait for the reply to the deadline of the call options and make it cancellable
+    fn with_call_options(mut self, options: &CallOptions) -> Self {
+        self.context.timeout_us = options.limit_timeout(self.context.timeout_us);
+        self.stop_token = options.stop_token();
+        self
+    }
 }
 
 /////////////////////////////////////////////////////////////////////
@@ -129,13 +144,17 @@ impl Default for RPCProcessorStartupContext {
 #[derive(Debug)]
 #[must_use]
 struct RPCProcessorInner {
-    rpc_send_channel: Option<flume::Sender<RPCWorkerRequest>>,
+    rpc_worker_queue: Option<Arc<RPCWorkerQueue>>,
     rpc_stop_source: Option<StopSource>,
     rpc_worker_join_handles: Vec<MustJoinHandle<()>>,
     rpc_worker_dequeue_latency: LatencyStats,
     rpc_worker_process_latency: LatencyStats,
     rpc_worker_dequeue_latency_accounting: LatencyStatsAccounting,
     rpc_worker_process_latency_accounting: LatencyStatsAccounting,
+    rpc_operations_rcvd: BTreeMap<&'static str, u64>,
+    rpc_operations_sent: BTreeMap<&'static str, u64>,
+    app_assemblies: AppAssemblyTable,
+    app_replies: AppReplyTable,
 }
 
 #[derive(Debug)]
@@ -144,7 +163,7 @@ pub(crate) struct RPCProcessor {
     registry: VeilidComponentRegistry,
     inner: Mutex<RPCProcessorInner>,
     timeout_us: TimestampDuration,
-    queue_size: u32,
+    queue_config: RPCWorkerQueueConfig,
     concurrency: u32,
     max_route_hop_count: usize,
     waiting_rpc_table: OperationWaiter<Message, Option<QuestionContext>>,
@@ -157,13 +176,17 @@ impl_veilid_component!(RPCProcessor);
 impl RPCProcessor {
     fn new_inner() -> RPCProcessorInner {
         RPCProcessorInner {
-            rpc_send_channel: None,
+            rpc_worker_queue: None,
             rpc_stop_source: None,
             rpc_worker_join_handles: Vec::new(),
             rpc_worker_dequeue_latency: LatencyStats::default(),
             rpc_worker_process_latency: LatencyStats::default(),
             rpc_worker_dequeue_latency_accounting: LatencyStatsAccounting::new(),
             rpc_worker_process_latency_accounting: LatencyStatsAccounting::new(),
+            rpc_operations_rcvd: BTreeMap::new(),
+            rpc_operations_sent: BTreeMap::new(),
+            app_assemblies: AppAssemblyTable::default(),
+            app_replies: AppReplyTable::default(),
         }
     }
 
@@ -172,13 +195,13 @@ impl RPCProcessor {
         startup_context: RPCProcessorStartupContext,
     ) -> Self {
         // make local copy of node id for easy access
-        let (concurrency, queue_size, max_route_hop_count, timeout_us) = {
+        let (concurrency, queue_config, max_route_hop_count, timeout_us) = {
             let config = registry.config();
             let c = config.get();
 
             // set up channel
             let mut concurrency = c.network.rpc.concurrency;
-            let queue_size = c.network.rpc.queue_size;
+            let queue_config = RPCWorkerQueueConfig::new(&c.network.rpc);
             let timeout_us = TimestampDuration::new(ms_to_us(c.network.rpc.timeout_ms));
             let max_route_hop_count = c.network.rpc.max_route_hop_count as usize;
             if concurrency == 0 {
@@ -190,14 +213,14 @@ impl RPCProcessor {
                 // Default RPC concurrency is the number of CPUs * 16 rpc workers per core, as a single worker takes about 1% CPU when relaying and 16% is reasonable for baseline plus relay
                 concurrency *= RPC_WORKERS_PER_CORE;
             }
-            (concurrency, queue_size, max_route_hop_count, timeout_us)
+            (concurrency, queue_config, max_route_hop_count, timeout_us)
         };
 
         Self {
             registry,
             inner: Mutex::new(Self::new_inner()),
             timeout_us,
-            queue_size,
+            queue_config,
             concurrency,
             max_route_hop_count,
             waiting_rpc_table: OperationWaiter::new(),
@@ -241,8 +264,7 @@ impl RPCProcessor {
         {
             let mut inner = self.inner.lock();
 
-            let channel = flume::bounded(self.queue_size as usize);
-            inner.rpc_send_channel = Some(channel.0.clone());
+            inner.rpc_worker_queue = Some(Arc::new(RPCWorkerQueue::new(&self.queue_config)));
             inner.rpc_stop_source = Some(StopSource::new());
         }
 
@@ -541,9 +563,17 @@ impl RPCProcessor {
         let id = waitable_reply.handle.id();
         let out = self
             .waiting_rpc_table
-            .wait_for_op(waitable_reply.handle, waitable_reply.context.timeout_us)
+            .wait_for_op(
+                waitable_reply.handle,
+                waitable_reply.context.timeout_us,
+                waitable_reply.stop_token,
+            )
             .await;
         match &out {
+            Err(RPCError::Cancelled) => {
+                // Cancelled locally, the remote node is not at fault
+                veilid_log!(self debug "RPC Cancelled (id={} {})", id, debug_string);
+            }
             Err(e) => {
                 veilid_log!(self debug "RPC Lost (id={} {}): {} ({}) ", id, debug_string, e, waitable_reply.context.send_data_result.unique_flow().flow);
                 self.record_lost_answer(&waitable_reply.context);
@@ -1136,6 +1166,7 @@ impl RPCProcessor {
 
         // Log rpc send
         veilid_log!(self debug target: "rpc_message", dir = "send", kind = "question", op_id = op_id.as_u64(), desc = operation.kind().desc(), ?dest);
+        self.stats_operation_sent(operation.kind().desc());
 
         // Produce rendered operation
         let RenderedOperation {
@@ -1221,6 +1252,7 @@ impl RPCProcessor {
                 remote_private_route,
                 reply_private_route,
             },
+            stop_token: None,
         }))
     }
 
@@ -1235,6 +1267,7 @@ impl RPCProcessor {
 
         // Log rpc send
         veilid_log!(self debug target: "rpc_message", dir = "send", kind = "statement", op_id = operation.op_id().as_u64(), desc = operation.kind().desc(), ?dest);
+        self.stats_operation_sent(operation.kind().desc());
 
         // Produce rendered operation
         let RenderedOperation {
@@ -1309,6 +1342,7 @@ impl RPCProcessor {
 
         // Log rpc send
         veilid_log!(self debug target: "rpc_message", dir = "send", kind = "answer", op_id = operation.op_id().as_u64(), desc = operation.kind().desc(), ?dest);
+        self.stats_operation_sent(operation.kind().desc());
 
         // Produce rendered operation
         let RenderedOperation {
@@ -1448,7 +1482,10 @@ impl RPCProcessor {
                                 );
                             }
                             // Ignored messages that should be dropped
-                            RPCError::Ignore(_) | RPCError::Network(_) | RPCError::TryAgain(_) => {
+                            RPCError::Ignore(_)
+                            | RPCError::Network(_)
+                            | RPCError::TryAgain(_)
+                            | RPCError::Cancelled => {
                                 veilid_log!(self trace "Dropping RPC Operation: {}", e);
                             }
                             // Internal errors that deserve louder logging
@@ -1514,7 +1551,10 @@ impl RPCProcessor {
                                 // self.network_manager().address_filter().punish_route_id(xxx, PunishmentReason::FailedToDecodeRoutedMessage);
                             }
                             // Ignored messages that should be dropped
-                            RPCError::Ignore(_) | RPCError::Network(_) | RPCError::TryAgain(_) => {
+                            RPCError::Ignore(_)
+                            | RPCError::Network(_)
+                            | RPCError::TryAgain(_)
+                            | RPCError::Cancelled => {
                                 veilid_log!(self trace "Dropping routed RPC Operation: {}", e);
                             }
                             // Internal errors that deserve louder logging
@@ -1537,6 +1577,7 @@ impl RPCProcessor {
         };
 
         // Process stats for questions/statements received
+        self.stats_operation_rcvd(msg.operation.kind().desc());
         match msg.operation.kind() {
             RPCOperationKind::Question(_) => {
                 self.record_question_received(&msg);
@@ -1589,6 +1630,12 @@ impl RPCProcessor {
                     RPCQuestionDetail::InspectValueQ(_) => {
                         pin_dyn_future_closure!(self.process_inspect_value_q(msg))
                     }
+                    RPCQuestionDetail::MailboxDepositQ(_) => {
+                        pin_dyn_future_closure!(self.process_mailbox_deposit_q(msg))
+                    }
+                    RPCQuestionDetail::MailboxDrainQ(_) => {
+                        pin_dyn_future_closure!(self.process_mailbox_drain_q(msg))
+                    }
                     #[cfg(feature = "unstable-blockstore")]
                     RPCQuestionDetail::SupplyBlockQ(_) => {
                         pin_dyn_future_closure!(self.process_supply_block_q(msg))
@@ -1645,7 +1692,8 @@ impl RPCProcessor {
                         RPCError::InvalidFormat(_)
                         | RPCError::Protocol(_)
                         | RPCError::Network(_)
-                        | RPCError::TryAgain(_) => {
+                        | RPCError::TryAgain(_)
+                        | RPCError::Cancelled => {
                             veilid_log!(self debug "Could not complete RPC operation: id = {}: {}", op_id, e);
                         }
                         RPCError::Ignore(e) => {
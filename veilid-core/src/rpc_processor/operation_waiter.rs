@@ -108,6 +108,11 @@ where
         }
     }
 
+    /// Check if an operation is being waited on
+    pub fn is_waiting(&self, op_id: OperationId) -> bool {
+        self.inner.lock().waiting_op_table.contains_key(&op_id)
+    }
+
     /// Get all waiting operation ids
     pub fn get_operation_ids(&self) -> Vec<OperationId> {
         let inner = self.inner.lock();
@@ -175,14 +180,23 @@ where
         &self,
         handle: OperationWaitHandle<T, C>,
         timeout_us: TimestampDuration,
+        stop_token: Option<StopToken>,
     ) -> Result<TimeoutOr<(T, TimestampDuration)>, RPCError> {
         let timeout_ms = us_to_ms(timeout_us.as_u64()).map_err(RPCError::internal)?;
 
         let result_fut = handle.result_receiver.recv_async().in_current_span();
 
-        // wait for eventualvalue
+        // wait for eventualvalue, unless we are cancelled first
         let start_ts = Timestamp::now();
-        let res = timeout(timeout_ms, result_fut).await.into_timeout_or();
+        let res = match stop_token {
+            Some(stop_token) => {
+                match timeout(timeout_ms, result_fut).timeout_at(stop_token).await {
+                    Ok(res) => res.into_timeout_or(),
+                    Err(_) => return Err(RPCError::Cancelled),
+                }
+            }
+            None => timeout(timeout_ms, result_fut).await.into_timeout_or(),
+        };
 
         match res {
             TimeoutOr::Timeout => Ok(TimeoutOr::Timeout),
@@ -2,6 +2,13 @@ use super::*;
 
 impl_veilid_log_facility!("rpc");
 
+/// One answer to an AppCallQ, which may be a fragment of a larger answer
+struct AppCallAnswer {
+    message: Vec<u8>,
+    fragment: Option<AppFragment>,
+    source: AppAssemblySource,
+}
+
 impl RPCProcessor {
     // Sends a high level app request and wait for response
     // Can be sent via all methods including relays and routes
@@ -9,7 +16,42 @@ impl RPCProcessor {
     pub async fn rpc_call_app_call(
         &self,
         dest: Destination,
+        namespace: u32,
+        message: Vec<u8>,
+        options: CallOptions,
+    ) -> RPCNetworkResult<Answer<Vec<u8>>> {
+        let _guard = self
+            .startup_context
+            .startup_lock
+            .enter()
+            .map_err(RPCError::map_try_again("not started up"))?;
+
+        let app_call_q = RPCOperationAppCallQ::new(namespace, message, false)?;
+        let answer =
+            network_result_try!(self.rpc_call_app_call_q(dest, app_call_q, &options).await?);
+        if answer.answer.fragment.is_some() {
+            return Ok(NetworkResult::invalid_message(
+                "appcall answer was fragmented without being asked to be",
+            ));
+        }
+        Ok(NetworkResult::value(Answer::new(
+            answer._latency,
+            answer.reply_private_route,
+            answer.answer.message,
+        )))
+    }
+
+    // Sends a high level app request that may be too large for a single operation and wait for response
+    // Every fragment but the last is acknowledged with an empty answer before the next is sent,
+    // and the answer to the last fragment is the answer to the whole request.
+    // Answers too large for a single operation are fetched a fragment at a time and reassembled.
+    #[instrument(level = "trace", target = "rpc", skip(self, message), fields(message.len = message.len()), err)]
+    pub async fn rpc_call_app_call_fragmented(
+        &self,
+        dest: Destination,
+        namespace: u32,
         message: Vec<u8>,
+        options: CallOptions,
     ) -> RPCNetworkResult<Answer<Vec<u8>>> {
         let _guard = self
             .startup_context
@@ -17,16 +59,153 @@ impl RPCProcessor {
             .enter()
             .map_err(RPCError::map_try_again("not started up"))?;
 
-        let debug_string = format!("AppCall(message(len)={}) => {}", message.len(), dest);
+        if message.len() <= APP_FRAGMENT_LEN {
+            let app_call_q = RPCOperationAppCallQ::new(namespace, message, true)?;
+            let answer = network_result_try!(
+                self.rpc_call_app_call_q(dest.clone(), app_call_q, &options)
+                    .await?
+            );
+            return self
+                .rpc_call_app_call_answer_fragments(dest, namespace, answer, &options)
+                .await;
+        }
+
+        if message.len() > MAX_APP_FRAGMENTED_MESSAGE_LEN {
+            return Err(RPCError::protocol("AppCallQ message too long to fragment"));
+        }
+
+        let message_id = get_random_u64();
+        let fragment_count = message.len().div_ceil(APP_FRAGMENT_LEN);
+        for (n, chunk) in message.chunks(APP_FRAGMENT_LEN).enumerate() {
+            let fragment = AppFragment {
+                message_id,
+                offset: (n * APP_FRAGMENT_LEN) as u32,
+                total_length: message.len() as u32,
+            };
+            let app_call_q =
+                RPCOperationAppCallQ::new_fragment(namespace, chunk.to_vec(), fragment, true)?;
+            let answer = network_result_try!(
+                self.rpc_call_app_call_q(dest.clone(), app_call_q, &options)
+                    .await?
+            );
+            if n + 1 == fragment_count {
+                return self
+                    .rpc_call_app_call_answer_fragments(dest, namespace, answer, &options)
+                    .await;
+            }
+            if !answer.answer.message.is_empty() || answer.answer.fragment.is_some() {
+                return Ok(NetworkResult::invalid_message(
+                    "appcall fragment acknowledgement was not empty",
+                ));
+            }
+        }
+
+        Err(RPCError::internal("appcall fragments not sent"))
+    }
+
+    // Fetches the rest of an answer whose first fragment has arrived, reassembling it from its fragments
+    async fn rpc_call_app_call_answer_fragments(
+        &self,
+        dest: Destination,
+        namespace: u32,
+        answer: Answer<AppCallAnswer>,
+        options: &CallOptions,
+    ) -> RPCNetworkResult<Answer<Vec<u8>>> {
+        let mut latency = answer._latency;
+        let mut reply_private_route = answer.reply_private_route;
+        let AppCallAnswer {
+            message,
+            fragment,
+            source,
+        } = answer.answer;
+        let Some(first_fragment) = fragment else {
+            return Ok(NetworkResult::value(Answer::new(
+                latency,
+                reply_private_route,
+                message,
+            )));
+        };
+        if first_fragment.offset != 0 {
+            return Ok(NetworkResult::invalid_message(
+                "appcall answer did not start with its first fragment",
+            ));
+        }
+
+        let total_length = first_fragment.total_length as usize;
+        let mut offset = message.len();
+        let mut opt_message = self.inner.lock().app_assemblies.insert_fragment(
+            Timestamp::now(),
+            source,
+            first_fragment,
+            &message,
+        );
+        loop {
+            if let Some(message) = opt_message {
+                return Ok(NetworkResult::value(Answer::new(
+                    latency,
+                    reply_private_route,
+                    message,
+                )));
+            }
+            if offset >= total_length {
+                return Ok(NetworkResult::invalid_message(
+                    "appcall answer fragments were dropped before reassembly",
+                ));
+            }
+
+            let fragment = AppFragment {
+                message_id: first_fragment.message_id,
+                offset: offset as u32,
+                total_length: first_fragment.total_length,
+            };
+            let app_call_q = RPCOperationAppCallQ::new_reply_fragment(namespace, fragment)?;
+            let answer = network_result_try!(
+                self.rpc_call_app_call_q(dest.clone(), app_call_q, options)
+                    .await?
+            );
+            if answer.answer.fragment != Some(fragment) {
+                return Ok(NetworkResult::invalid_message(
+                    "appcall answer fragment does not match the one requested",
+                ));
+            }
+            latency += answer._latency.as_u64();
+            reply_private_route = answer.reply_private_route;
+            offset += answer.answer.message.len();
+
+            // Fragments are reassembled as coming from the node that sent the first one
+            opt_message = self.inner.lock().app_assemblies.insert_fragment(
+                Timestamp::now(),
+                source,
+                fragment,
+                &answer.answer.message,
+            );
+        }
+    }
+
+    async fn rpc_call_app_call_q(
+        &self,
+        dest: Destination,
+        app_call_q: RPCOperationAppCallQ,
+        options: &CallOptions,
+    ) -> RPCNetworkResult<Answer<AppCallAnswer>> {
+        if options.is_cancelled() {
+            return Err(RPCError::Cancelled);
+        }
+
+        let debug_string = format!(
+            "AppCall(message(len)={}) => {}",
+            app_call_q.message().len(),
+            dest
+        );
 
-        let app_call_q = RPCOperationAppCallQ::new(message)?;
         let question = RPCQuestion::new(
             network_result_try!(self.get_destination_respond_to(&dest)?),
             RPCQuestionDetail::AppCallQ(Box::new(app_call_q)),
         );
 
         // Send the app call question
-        let waitable_reply = network_result_try!(self.question(dest, question, None).await?);
+        let waitable_reply = network_result_try!(self.question(dest, question, None).await?)
+            .with_call_options(options);
 
         // Keep the reply private route that was used to return with the answer
         let reply_private_route = waitable_reply.context.reply_private_route;
@@ -47,7 +226,7 @@ impl RPCProcessor {
             _ => return Ok(NetworkResult::invalid_message("not an answer")),
         };
 
-        let a_message = app_call_a.destructure();
+        let (a_message, a_fragment) = app_call_a.destructure();
 
         #[cfg(feature = "verbose-tracing")]
         tracing::Span::current().record("ret.latency", latency.as_u64());
@@ -56,7 +235,11 @@ impl RPCProcessor {
         Ok(NetworkResult::value(Answer::new(
             latency,
             reply_private_route,
-            a_message,
+            AppCallAnswer {
+                message: a_message,
+                fragment: a_fragment,
+                source: AppAssemblySource::from_header(&msg.header),
+            },
         )))
     }
 
@@ -127,19 +310,66 @@ impl RPCProcessor {
             }
         }
 
+        let source = AppAssemblySource::from_header(&msg.header);
+        let (namespace, message_q, opt_fragment, fragmented_reply, opt_reply_fragment) =
+            app_call_q.destructure();
+
+        // Hand out the next fragment of an answer that was too large for a single operation
+        if let Some(reply_fragment) = opt_reply_fragment {
+            let opt_message_a = self.inner.lock().app_replies.get_fragment(
+                Timestamp::now(),
+                source,
+                &reply_fragment,
+            );
+            let Some(message_a) = opt_message_a else {
+                return Ok(NetworkResult::invalid_message(
+                    "appcall answer fragment is not available",
+                ));
+            };
+            let app_call_a = RPCOperationAppCallA::new_fragment(message_a, reply_fragment)?;
+            return self
+                .answer(
+                    msg,
+                    RPCAnswer::new(RPCAnswerDetail::AppCallA(Box::new(app_call_a))),
+                )
+                .await;
+        }
+
+        // Reassemble fragmented requests, acknowledging every fragment that does not complete one
+        let message_q = match opt_fragment {
+            None => message_q,
+            Some(fragment) => {
+                let opt_message_q = self.inner.lock().app_assemblies.insert_fragment(
+                    Timestamp::now(),
+                    source,
+                    fragment,
+                    &message_q,
+                );
+                let Some(message_q) = opt_message_q else {
+                    let app_call_a = RPCOperationAppCallA::new(vec![])?;
+                    return self
+                        .answer(
+                            msg,
+                            RPCAnswer::new(RPCAnswerDetail::AppCallA(Box::new(app_call_a))),
+                        )
+                        .await;
+                };
+                message_q
+            }
+        };
+
         // Register a waiter for this app call
         let handle = self.waiting_app_call_table.add_op_waiter(op_id, ());
 
         // Pass the call up through the update callback
-        let message_q = app_call_q.destructure();
         (self.update_callback())(VeilidUpdate::AppCall(Box::new(VeilidAppCall::new(
-            sender, route_id, message_q, op_id,
+            sender, route_id, namespace, message_q, op_id,
         ))));
 
         // Wait for an app call answer to come back from the app
         let res = self
             .waiting_app_call_table
-            .wait_for_op(handle, self.timeout_us)
+            .wait_for_op(handle, self.timeout_us, None)
             .await?;
         let (message_a, _latency) = match res {
             TimeoutOr::Timeout => {
@@ -150,8 +380,27 @@ impl RPCProcessor {
             TimeoutOr::Value(v) => v,
         };
 
-        // Return the appcall answer
-        let app_call_a = RPCOperationAppCallA::new(message_a)?;
+        // Return the appcall answer, keeping the rest of it to be fetched if it has to be fragmented
+        let app_call_a = if fragmented_reply && message_a.len() > APP_FRAGMENT_LEN {
+            if message_a.len() > MAX_APP_FRAGMENTED_MESSAGE_LEN {
+                return Err(RPCError::protocol("AppCallA message too long to fragment"));
+            }
+            let fragment = AppFragment {
+                message_id: get_random_u64(),
+                offset: 0,
+                total_length: message_a.len() as u32,
+            };
+            let first_fragment = message_a[0..APP_FRAGMENT_LEN].to_vec();
+            self.inner.lock().app_replies.insert_reply(
+                Timestamp::now(),
+                source,
+                fragment.message_id,
+                message_a,
+            );
+            RPCOperationAppCallA::new_fragment(first_fragment, fragment)?
+        } else {
+            RPCOperationAppCallA::new(message_a)?
+        };
 
         // Send status answer
         self.answer(
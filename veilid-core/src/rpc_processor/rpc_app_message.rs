@@ -9,6 +9,7 @@ impl RPCProcessor {
     pub async fn rpc_call_app_message(
         &self,
         dest: Destination,
+        namespace: u32,
         message: Vec<u8>,
     ) -> RPCNetworkResult<()> {
         let _guard = self
@@ -17,13 +18,57 @@ impl RPCProcessor {
             .enter()
             .map_err(RPCError::map_try_again("not started up"))?;
 
-        let app_message = RPCOperationAppMessage::new(message)?;
+        let app_message = RPCOperationAppMessage::new(namespace, message)?;
         let statement = RPCStatement::new(RPCStatementDetail::AppMessage(Box::new(app_message)));
 
         // Send the app message request
         self.statement(dest, statement).await
     }
 
+    // Sends a high level app message that may be too large for a single operation,
+    // splitting it into fragments that are reassembled by the receiver
+    #[instrument(level = "trace", target = "rpc", skip(self, message), fields(message.len = message.len()), err)]
+    pub async fn rpc_call_app_message_fragmented(
+        &self,
+        dest: Destination,
+        namespace: u32,
+        message: Vec<u8>,
+    ) -> RPCNetworkResult<()> {
+        if message.len() <= APP_FRAGMENT_LEN {
+            return self.rpc_call_app_message(dest, namespace, message).await;
+        }
+
+        let _guard = self
+            .startup_context
+            .startup_lock
+            .enter()
+            .map_err(RPCError::map_try_again("not started up"))?;
+
+        if message.len() > MAX_APP_FRAGMENTED_MESSAGE_LEN {
+            return Err(RPCError::protocol(
+                "AppMessage message too long to fragment",
+            ));
+        }
+
+        let message_id = get_random_u64();
+        for (n, chunk) in message.chunks(APP_FRAGMENT_LEN).enumerate() {
+            let fragment = AppFragment {
+                message_id,
+                offset: (n * APP_FRAGMENT_LEN) as u32,
+                total_length: message.len() as u32,
+            };
+            let app_message =
+                RPCOperationAppMessage::new_fragment(namespace, chunk.to_vec(), fragment)?;
+            let statement =
+                RPCStatement::new(RPCStatementDetail::AppMessage(Box::new(app_message)));
+
+            // Send the fragment
+            network_result_try!(self.statement(dest.clone(), statement).await?);
+        }
+
+        Ok(NetworkResult::value(()))
+    }
+
     ////////////////////////////////////////////////////////////////////////////////////////////////
 
     #[instrument(level = "trace", target = "rpc", skip(self, msg), fields(msg.operation.op_id), ret, err)]
@@ -90,10 +135,28 @@ impl RPCProcessor {
             }
         }
 
+        // Reassemble fragmented messages before passing them on
+        let (namespace, message, opt_fragment) = app_message.destructure();
+        let message = match opt_fragment {
+            None => message,
+            Some(fragment) => {
+                let source = AppAssemblySource::from_header(&msg.header);
+                let opt_message = self.inner.lock().app_assemblies.insert_fragment(
+                    Timestamp::now(),
+                    source,
+                    fragment,
+                    &message,
+                );
+                let Some(message) = opt_message else {
+                    return Ok(NetworkResult::value(()));
+                };
+                message
+            }
+        };
+
         // Pass the message up through the update callback
-        let message = app_message.destructure();
         (self.update_callback())(VeilidUpdate::AppMessage(Box::new(VeilidAppMessage::new(
-            sender, route_id, message,
+            sender, route_id, namespace, message,
         ))));
 
         Ok(NetworkResult::value(()))
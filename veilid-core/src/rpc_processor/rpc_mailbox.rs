@@ -0,0 +1,334 @@
+use super::*;
+use crate::storage_manager::MailboxMessage;
+
+impl_veilid_log_facility!("rpc");
+
+impl RPCProcessor {
+    /// Sends a message to the host of a peer-hosted mailbox and waits for it to be accepted
+    /// Can be sent via all methods including relays
+    /// Safety routes may be used, but never private routes.
+    /// The answer is false if the host does not keep the mailbox or the mailbox is full.
+    #[instrument(level = "trace", target = "rpc", skip(self, message), fields(message.len = message.len(), ret.latency), err)]
+    pub async fn rpc_call_mailbox_deposit(
+        &self,
+        dest: Destination,
+        mailbox: TypedPublicKey,
+        expiration: Timestamp,
+        message: Vec<u8>,
+    ) -> RPCNetworkResult<Answer<bool>> {
+        let _guard = self
+            .startup_context
+            .startup_lock
+            .enter()
+            .map_err(RPCError::map_try_again("not started up"))?;
+
+        // Ensure destination never has a private route
+        if dest.get_target_node_ids().is_none() {
+            return Err(RPCError::internal(
+                "Never send mailbox deposit requests over private routes",
+            ));
+        }
+
+        let debug_string = format!(
+            "OUT ==> MailboxDepositQ({} message(len)={}) => {}",
+            mailbox,
+            message.len(),
+            dest
+        );
+
+        // Send the mailbox deposit question
+        let mailbox_deposit_q =
+            RPCOperationMailboxDepositQ::new(mailbox, expiration.as_u64(), message)?;
+        let question = RPCQuestion::new(
+            network_result_try!(self.get_destination_respond_to(&dest)?),
+            RPCQuestionDetail::MailboxDepositQ(Box::new(mailbox_deposit_q)),
+        );
+
+        veilid_log!(self debug target: "dht", "{}", debug_string);
+
+        let waitable_reply = network_result_try!(self.question(dest, question, None).await?);
+
+        // Keep the reply private route that was used to return with the answer
+        let reply_private_route = waitable_reply.context.reply_private_route;
+
+        // Wait for reply
+        let (msg, latency) = match self.wait_for_reply(waitable_reply, debug_string).await? {
+            TimeoutOr::Timeout => return Ok(NetworkResult::Timeout),
+            TimeoutOr::Value(v) => v,
+        };
+
+        // Get the right answer type
+        let (_, _, kind) = msg.operation.destructure();
+        let mailbox_deposit_a = match kind {
+            RPCOperationKind::Answer(a) => match a.destructure() {
+                RPCAnswerDetail::MailboxDepositA(a) => a,
+                _ => {
+                    return Ok(NetworkResult::invalid_message(
+                        "not a mailboxdeposit answer",
+                    ))
+                }
+            },
+            _ => return Ok(NetworkResult::invalid_message("not an answer")),
+        };
+
+        let accepted = mailbox_deposit_a.destructure();
+
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.latency", latency.as_u64());
+
+        Ok(NetworkResult::value(Answer::new(
+            latency,
+            reply_private_route,
+            accepted,
+        )))
+    }
+
+    /// Sends a signed request to the host of a peer-hosted mailbox for the messages deposited in it
+    /// Can be sent via all methods including relays
+    /// Safety routes may be used, but never private routes.
+    /// Messages up to and including 'acknowledge' are dropped by the host before the rest are returned.
+    /// The first accepted drain makes the host start keeping the mailbox.
+    /// The answer is None if the host does not keep the mailbox and can not start keeping it.
+    #[instrument(level = "trace", target = "rpc", skip(self, mailbox), fields(ret.latency, ret.messages.len), err)]
+    pub async fn rpc_call_mailbox_drain(
+        &self,
+        dest: Destination,
+        host: TypedNodeId,
+        mailbox: KeyPair,
+        acknowledge: u64,
+    ) -> RPCNetworkResult<Answer<Option<Vec<MailboxMessage>>>> {
+        let _guard = self
+            .startup_context
+            .startup_lock
+            .enter()
+            .map_err(RPCError::map_try_again("not started up"))?;
+
+        // Ensure destination never has a private route
+        if dest.get_target_node_ids().is_none() {
+            return Err(RPCError::internal(
+                "Never send mailbox drain requests over private routes",
+            ));
+        }
+
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get(host.kind) else {
+            return Err(RPCError::internal("unsupported cryptosystem"));
+        };
+
+        let debug_string = format!(
+            "OUT ==> MailboxDrainQ({}:{} ack={}) => {}",
+            host.kind, mailbox.key, acknowledge, dest
+        );
+
+        // Send the mailbox drain question
+        let mailbox_drain_q = RPCOperationMailboxDrainQ::new(
+            host,
+            mailbox,
+            acknowledge,
+            Timestamp::now().as_u64(),
+            &vcrypto,
+        )?;
+        let question = RPCQuestion::new(
+            network_result_try!(self.get_destination_respond_to(&dest)?),
+            RPCQuestionDetail::MailboxDrainQ(Box::new(mailbox_drain_q)),
+        );
+
+        veilid_log!(self debug target: "dht", "{}", debug_string);
+
+        let waitable_reply = network_result_try!(self.question(dest, question, None).await?);
+
+        // Keep the reply private route that was used to return with the answer
+        let reply_private_route = waitable_reply.context.reply_private_route;
+
+        // Wait for reply
+        let (msg, latency) = match self.wait_for_reply(waitable_reply, debug_string).await? {
+            TimeoutOr::Timeout => return Ok(NetworkResult::Timeout),
+            TimeoutOr::Value(v) => v,
+        };
+
+        // Get the right answer type
+        let (_, _, kind) = msg.operation.destructure();
+        let mailbox_drain_a = match kind {
+            RPCOperationKind::Answer(a) => match a.destructure() {
+                RPCAnswerDetail::MailboxDrainA(a) => a,
+                _ => return Ok(NetworkResult::invalid_message("not a mailboxdrain answer")),
+            },
+            _ => return Ok(NetworkResult::invalid_message("not an answer")),
+        };
+
+        let (accepted, messages) = mailbox_drain_a.destructure();
+        if messages.first().is_some_and(|m| m.id() <= acknowledge) {
+            return Ok(NetworkResult::invalid_message(
+                "mailboxdrain answer returned acknowledged messages",
+            ));
+        }
+
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.latency", latency.as_u64());
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.messages.len", messages.len());
+
+        Ok(NetworkResult::value(Answer::new(
+            latency,
+            reply_private_route,
+            accepted.then_some(messages),
+        )))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+
+    #[instrument(level = "trace", target = "rpc", skip(self, msg), fields(msg.operation.op_id), ret, err)]
+    pub(super) async fn process_mailbox_deposit_q(&self, msg: Message) -> RPCNetworkResult<()> {
+        // Ensure this never came over a private route, safety route is okay though
+        match &msg.header.detail {
+            RPCMessageHeaderDetail::Direct(_) | RPCMessageHeaderDetail::SafetyRouted(_) => {}
+            RPCMessageHeaderDetail::PrivateRouted(_) => {
+                return Ok(NetworkResult::invalid_message(
+                    "not processing mailbox deposit request over private route",
+                ))
+            }
+        }
+
+        // Ignore if disabled
+        if !self.has_mailbox_hosting_capability(msg.header.routing_domain()) {
+            return Ok(NetworkResult::service_unavailable("dht is not available"));
+        }
+
+        // Get the question
+        let kind = msg.operation.kind().clone();
+        let mailbox_deposit_q = match kind {
+            RPCOperationKind::Question(q) => match q.destructure() {
+                (_, RPCQuestionDetail::MailboxDepositQ(q)) => q,
+                _ => panic!("not a mailboxdeposit question"),
+            },
+            _ => panic!("not a question"),
+        };
+
+        // Destructure
+        let (mailbox, expiration, message) = mailbox_deposit_q.destructure();
+
+        if debug_target_enabled!("dht") {
+            let debug_string = format!(
+                "IN <=== MailboxDepositQ({} message(len)={}) <== {}",
+                mailbox,
+                message.len(),
+                msg.header.direct_sender_node_id()
+            );
+
+            veilid_log!(self debug target: "dht", "{}", debug_string);
+        }
+
+        // Keep the message if we host this mailbox
+        let storage_manager = self.storage_manager();
+        let accepted = network_result_try!(storage_manager
+            .inbound_mailbox_deposit(mailbox, Timestamp::new(expiration), message)
+            .await
+            .map_err(RPCError::internal)?);
+
+        if debug_target_enabled!("dht") {
+            let debug_string_answer = format!(
+                "IN ===> MailboxDepositA({} accepted={}) ==> {}",
+                mailbox,
+                accepted,
+                msg.header.direct_sender_node_id()
+            );
+
+            veilid_log!(self debug target: "dht", "{}", debug_string_answer);
+        }
+
+        // Make MailboxDeposit answer
+        let mailbox_deposit_a = RPCOperationMailboxDepositA::new(accepted)?;
+
+        // Send MailboxDeposit answer
+        self.answer(
+            msg,
+            RPCAnswer::new(RPCAnswerDetail::MailboxDepositA(Box::new(
+                mailbox_deposit_a,
+            ))),
+        )
+        .await
+    }
+
+    #[instrument(level = "trace", target = "rpc", skip(self, msg), fields(msg.operation.op_id), ret, err)]
+    pub(super) async fn process_mailbox_drain_q(&self, msg: Message) -> RPCNetworkResult<()> {
+        // Ensure this never came over a private route, safety route is okay though
+        match &msg.header.detail {
+            RPCMessageHeaderDetail::Direct(_) | RPCMessageHeaderDetail::SafetyRouted(_) => {}
+            RPCMessageHeaderDetail::PrivateRouted(_) => {
+                return Ok(NetworkResult::invalid_message(
+                    "not processing mailbox drain request over private route",
+                ))
+            }
+        }
+
+        // Ignore if disabled
+        if !self.has_mailbox_hosting_capability(msg.header.routing_domain()) {
+            return Ok(NetworkResult::service_unavailable("dht is not available"));
+        }
+
+        // Get the question
+        let kind = msg.operation.kind().clone();
+        let mailbox_drain_q = match kind {
+            RPCOperationKind::Question(q) => match q.destructure() {
+                (_, RPCQuestionDetail::MailboxDrainQ(q)) => q,
+                _ => panic!("not a mailboxdrain question"),
+            },
+            _ => panic!("not a question"),
+        };
+
+        // Destructure, the signature has already been checked
+        let (mailbox, acknowledge, timestamp) = mailbox_drain_q.destructure();
+
+        if debug_target_enabled!("dht") {
+            let debug_string = format!(
+                "IN <=== MailboxDrainQ({} ack={}) <== {}",
+                mailbox,
+                acknowledge,
+                msg.header.direct_sender_node_id()
+            );
+
+            veilid_log!(self debug target: "dht", "{}", debug_string);
+        }
+
+        // Drop the acknowledged messages and get the rest
+        let storage_manager = self.storage_manager();
+        let opt_messages = network_result_try!(storage_manager
+            .inbound_mailbox_drain(mailbox, acknowledge, Timestamp::new(timestamp))
+            .await
+            .map_err(RPCError::internal)?);
+
+        if debug_target_enabled!("dht") {
+            let debug_string_answer = format!(
+                "IN ===> MailboxDrainA({} accepted={} messages={}) ==> {}",
+                mailbox,
+                opt_messages.is_some(),
+                opt_messages.as_ref().map(|m| m.len()).unwrap_or_default(),
+                msg.header.direct_sender_node_id()
+            );
+
+            veilid_log!(self debug target: "dht", "{}", debug_string_answer);
+        }
+
+        // Make MailboxDrain answer
+        let mailbox_drain_a = match opt_messages {
+            Some(messages) => RPCOperationMailboxDrainA::new(true, messages)?,
+            None => RPCOperationMailboxDrainA::new(false, vec![])?,
+        };
+
+        // Send MailboxDrain answer
+        self.answer(
+            msg,
+            RPCAnswer::new(RPCAnswerDetail::MailboxDrainA(Box::new(mailbox_drain_a))),
+        )
+        .await
+    }
+
+    /// Mailboxes are only hosted by nodes that offer DHT storage
+    fn has_mailbox_hosting_capability(&self, routing_domain: RoutingDomain) -> bool {
+        self.routing_table()
+            .get_published_peer_info(routing_domain)
+            .map(|ppi| ppi.signed_node_info().node_info().has_capability(CAP_DHT))
+            .unwrap_or(false)
+    }
+}
@@ -12,29 +12,39 @@ pub(super) enum RPCWorkerRequestKind {
 
 #[derive(Debug)]
 pub(super) struct RPCWorkerRequest {
-    enqueued_ts: Timestamp,
     span: Span,
     kind: RPCWorkerRequestKind,
 }
 
 impl RPCProcessor {
+    /// Answers only get the control class if we are waiting on them
+    fn priority_class(&self, kind: &RPCWorkerRequestKind) -> RPCPriorityClass {
+        match kind {
+            RPCWorkerRequestKind::Message { message_encoded } => {
+                RPCPriorityClass::classify(&message_encoded.data, |op_id| {
+                    self.waiting_rpc_table.is_waiting(op_id)
+                })
+            }
+        }
+    }
+
     pub(super) fn startup_rpc_workers(&self) -> EyreResult<()> {
         let mut inner = self.inner.lock();
 
         // Relay workers
-        let channel = flume::bounded(self.queue_size as usize);
-        inner.rpc_send_channel = Some(channel.0.clone());
+        let queue = Arc::new(RPCWorkerQueue::new(&self.queue_config));
+        inner.rpc_worker_queue = Some(queue.clone());
         inner.rpc_stop_source = Some(StopSource::new());
 
         // spin up N workers
         veilid_log!(self debug "Starting {} RPC workers", self.concurrency);
         for task_n in 0..self.concurrency {
             let registry = self.registry();
-            let receiver = channel.1.clone();
+            let queue = queue.clone();
             let stop_token = inner.rpc_stop_source.as_ref().unwrap().token();
             let jh = spawn(&format!("relay worker {}", task_n), async move {
                 let this = registry.rpc_processor();
-                Box::pin(this.rpc_worker(stop_token, receiver)).await
+                Box::pin(this.rpc_worker(stop_token, queue)).await
             });
             inner.rpc_worker_join_handles.push(jh);
         }
@@ -59,14 +69,16 @@ impl RPCProcessor {
         while unord.next().await.is_some() {}
     }
 
-    async fn rpc_worker(&self, stop_token: StopToken, receiver: flume::Receiver<RPCWorkerRequest>) {
-        while let Ok(Ok(request)) = receiver.recv_async().timeout_at(stop_token.clone()).await {
+    async fn rpc_worker(&self, stop_token: StopToken, queue: Arc<RPCWorkerQueue>) {
+        while let Ok(Some((enqueued_ts, request))) =
+            queue.dequeue().timeout_at(stop_token.clone()).await
+        {
             let rpc_request_span = tracing::trace_span!("rpc request");
             rpc_request_span.follows_from(request.span);
 
             // Measure dequeue time
             let dequeue_ts = Timestamp::now();
-            let dequeue_latency = dequeue_ts.saturating_sub(request.enqueued_ts);
+            let dequeue_latency = dequeue_ts.saturating_sub(enqueued_ts);
 
             // Process request kind
             match request.kind {
@@ -137,20 +149,23 @@ impl RPCProcessor {
             data: MessageData { contents: body },
         };
 
-        let send_channel = {
+        let queue = {
             let inner = self.inner.lock();
-            let Some(send_channel) = inner.rpc_send_channel.as_ref().cloned() else {
-                bail!("send channel is closed");
+            let Some(queue) = inner.rpc_worker_queue.as_ref().cloned() else {
+                bail!("rpc queue is closed");
             };
-            send_channel
+            queue
         };
-        send_channel
-            .try_send(RPCWorkerRequest {
-                enqueued_ts: Timestamp::now(),
-                span: Span::current(),
-                kind: RPCWorkerRequestKind::Message { message_encoded },
-            })
-            .map_err(|e| eyre!("failed to enqueue direct RPC message: {}", e))?;
+        let kind = RPCWorkerRequestKind::Message { message_encoded };
+        queue
+            .enqueue(
+                self.priority_class(&kind),
+                RPCWorkerRequest {
+                    span: Span::current(),
+                    kind,
+                },
+            )
+            .wrap_err("failed to enqueue direct RPC message")?;
         Ok(())
     }
 
@@ -182,20 +197,23 @@ impl RPCProcessor {
             header,
             data: MessageData { contents: body },
         };
-        let send_channel = {
+        let queue = {
             let inner = self.inner.lock();
-            let Some(send_channel) = inner.rpc_send_channel.as_ref().cloned() else {
-                bail!("send channel is closed");
+            let Some(queue) = inner.rpc_worker_queue.as_ref().cloned() else {
+                bail!("rpc queue is closed");
             };
-            send_channel
+            queue
         };
-        send_channel
-            .try_send(RPCWorkerRequest {
-                enqueued_ts: Timestamp::now(),
-                span: Span::current(),
-                kind: RPCWorkerRequestKind::Message { message_encoded },
-            })
-            .map_err(|e| eyre!("failed to enqueue safety routed RPC message: {}", e))?;
+        let kind = RPCWorkerRequestKind::Message { message_encoded };
+        queue
+            .enqueue(
+                self.priority_class(&kind),
+                RPCWorkerRequest {
+                    span: Span::current(),
+                    kind,
+                },
+            )
+            .wrap_err("failed to enqueue safety routed RPC message")?;
         Ok(())
     }
 
@@ -230,20 +248,23 @@ impl RPCProcessor {
             data: MessageData { contents: body },
         };
 
-        let send_channel = {
+        let queue = {
             let inner = self.inner.lock();
-            let Some(send_channel) = inner.rpc_send_channel.as_ref().cloned() else {
-                bail!("send channel is closed");
+            let Some(queue) = inner.rpc_worker_queue.as_ref().cloned() else {
+                bail!("rpc queue is closed");
             };
-            send_channel
+            queue
         };
-        send_channel
-            .try_send(RPCWorkerRequest {
-                enqueued_ts: Timestamp::now(),
-                span: Span::current(),
-                kind: RPCWorkerRequestKind::Message { message_encoded },
-            })
-            .map_err(|e| eyre!("failed to enqueue private routed RPC message: {}", e))?;
+        let kind = RPCWorkerRequestKind::Message { message_encoded };
+        queue
+            .enqueue(
+                self.priority_class(&kind),
+                RPCWorkerRequest {
+                    span: Span::current(),
+                    kind,
+                },
+            )
+            .wrap_err("failed to enqueue private routed RPC message")?;
         Ok(())
     }
 }
@@ -0,0 +1,503 @@
+use super::*;
+
+/// Classes of inbound rpc messages, each with its own queue, so that a flood
+/// of one kind of traffic can not starve the others of rpc workers
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum RPCPriorityClass {
+    /// Liveness and connectivity: status, receipts, signals, dial info validation and answers to our own questions
+    Control,
+    /// Node lookups and private route hops
+    Routing,
+    /// DHT record operations and value change notifications
+    DHT,
+    /// AppCall and AppMessage traffic, and answers to questions we are not waiting on
+    App,
+}
+
+/// What to do with a message when its class queue is full
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum RPCDropPolicy {
+    /// Reject the message being enqueued
+    DropNewest,
+    /// Discard the message that has waited longest to make room, as its sender is the most likely to have given up on it
+    DropOldest,
+}
+
+impl RPCPriorityClass {
+    pub const COUNT: usize = 4;
+    pub const ALL: [RPCPriorityClass; Self::COUNT] = [
+        RPCPriorityClass::Control,
+        RPCPriorityClass::Routing,
+        RPCPriorityClass::DHT,
+        RPCPriorityClass::App,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            RPCPriorityClass::Control => 0,
+            RPCPriorityClass::Routing => 1,
+            RPCPriorityClass::DHT => 2,
+            RPCPriorityClass::App => 3,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RPCPriorityClass::Control => "control",
+            RPCPriorityClass::Routing => "routing",
+            RPCPriorityClass::DHT => "dht",
+            RPCPriorityClass::App => "app",
+        }
+    }
+
+    pub fn drop_policy(self) -> RPCDropPolicy {
+        match self {
+            RPCPriorityClass::Control => RPCDropPolicy::DropOldest,
+            RPCPriorityClass::Routing => RPCDropPolicy::DropNewest,
+            RPCPriorityClass::DHT => RPCDropPolicy::DropNewest,
+            RPCPriorityClass::App => RPCDropPolicy::DropOldest,
+        }
+    }
+
+    /// Determine the class of an encoded message by peeking at its operation kind.
+    /// Messages that can't be read are queued with the DHT traffic and get rejected by the worker.
+    /// Only answers whose operation id passes `is_waiting` are control traffic, so unsolicited
+    /// answers can not crowd out the control queue.
+    pub fn classify<F>(data: &MessageData, is_waiting: F) -> Self
+    where
+        F: Fn(OperationId) -> bool,
+    {
+        Self::try_classify(data, is_waiting).unwrap_or(RPCPriorityClass::DHT)
+    }
+
+    fn try_classify<F>(data: &MessageData, is_waiting: F) -> Result<Self, RPCError>
+    where
+        F: Fn(OperationId) -> bool,
+    {
+        let reader = data.get_reader()?;
+        let op_reader = reader
+            .get_root::<veilid_capnp::operation::Reader>()
+            .map_err(RPCError::protocol)?;
+        let class = match op_reader.get_kind().which().map_err(RPCError::protocol)? {
+            veilid_capnp::operation::kind::Which::Question(r) => {
+                let q_reader = r.map_err(RPCError::protocol)?;
+                match q_reader.get_detail().which().map_err(RPCError::protocol)? {
+                    veilid_capnp::question::detail::Which::StatusQ(_) => RPCPriorityClass::Control,
+                    veilid_capnp::question::detail::Which::FindNodeQ(_) => {
+                        RPCPriorityClass::Routing
+                    }
+                    veilid_capnp::question::detail::Which::AppCallQ(_) => RPCPriorityClass::App,
+                    veilid_capnp::question::detail::Which::GetValueQ(_)
+                    | veilid_capnp::question::detail::Which::SetValueQ(_)
+                    | veilid_capnp::question::detail::Which::WatchValueQ(_)
+                    | veilid_capnp::question::detail::Which::InspectValueQ(_)
+                    | veilid_capnp::question::detail::Which::MailboxDepositQ(_)
+                    | veilid_capnp::question::detail::Which::MailboxDrainQ(_) => {
+                        RPCPriorityClass::DHT
+                    }
+                }
+            }
+            veilid_capnp::operation::kind::Which::Statement(r) => {
+                let s_reader = r.map_err(RPCError::protocol)?;
+                match s_reader.get_detail().which().map_err(RPCError::protocol)? {
+                    veilid_capnp::statement::detail::Which::ValidateDialInfo(_)
+                    | veilid_capnp::statement::detail::Which::Signal(_)
+                    | veilid_capnp::statement::detail::Which::ReturnReceipt(_) => {
+                        RPCPriorityClass::Control
+                    }
+                    veilid_capnp::statement::detail::Which::Route(_) => RPCPriorityClass::Routing,
+                    veilid_capnp::statement::detail::Which::AppMessage(_) => RPCPriorityClass::App,
+                    veilid_capnp::statement::detail::Which::ValueChanged(_) => {
+                        RPCPriorityClass::DHT
+                    }
+                }
+            }
+            // Answers to questions we are waiting on can only be as many as we asked,
+            // anything else gets the lowest class and is dropped by the worker
+            veilid_capnp::operation::kind::Which::Answer(_) => {
+                if is_waiting(OperationId::new(op_reader.get_op_id())) {
+                    RPCPriorityClass::Control
+                } else {
+                    RPCPriorityClass::App
+                }
+            }
+        };
+        Ok(class)
+    }
+}
+
+/// Queue shares and weights of the priority classes, from 'network.rpc'
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RPCWorkerQueueConfig {
+    pub queue_size: u32,
+    /// Percent of the queue size given to each class, indexed like [RPCPriorityClass::ALL]
+    pub shares: [u32; RPCPriorityClass::COUNT],
+    /// Relative share of the rpc workers each class gets while other classes are waiting too
+    pub weights: [u32; RPCPriorityClass::COUNT],
+}
+
+impl RPCWorkerQueueConfig {
+    pub fn new(c: &VeilidConfigRPC) -> Self {
+        Self {
+            queue_size: c.queue_size,
+            shares: [
+                c.queue_share_control,
+                c.queue_share_routing,
+                c.queue_share_dht,
+                c.queue_share_app,
+            ],
+            weights: [
+                c.queue_weight_control,
+                c.queue_weight_routing,
+                c.queue_weight_dht,
+                c.queue_weight_app,
+            ],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RPCWorkerQueueClass<T> {
+    requests: VecDeque<(Timestamp, T)>,
+    current_weight: i64,
+    enqueued: u64,
+    dropped: u64,
+    dequeue_latency: LatencyStats,
+    dequeue_latency_accounting: LatencyStatsAccounting,
+}
+
+impl<T> Default for RPCWorkerQueueClass<T> {
+    fn default() -> Self {
+        Self {
+            requests: VecDeque::new(),
+            current_weight: 0,
+            enqueued: 0,
+            dropped: 0,
+            dequeue_latency: LatencyStats::default(),
+            dequeue_latency_accounting: LatencyStatsAccounting::new(),
+        }
+    }
+}
+
+/// Per-class queues of requests waiting for an rpc worker, served by weighted round robin
+#[derive(Debug)]
+pub(super) struct RPCWorkerQueue<T = RPCWorkerRequest> {
+    classes: Mutex<[RPCWorkerQueueClass<T>; RPCPriorityClass::COUNT]>,
+    limits: [usize; RPCPriorityClass::COUNT],
+    weights: [u32; RPCPriorityClass::COUNT],
+    // One token per queued request, so workers can wait for work across all classes
+    ready_send: flume::Sender<()>,
+    ready_recv: flume::Receiver<()>,
+}
+
+impl<T> RPCWorkerQueue<T> {
+    pub fn new(config: &RPCWorkerQueueConfig) -> Self {
+        let limits = config
+            .shares
+            .map(|share| usize::max(1, (config.queue_size as u64 * share as u64 / 100) as usize));
+        let weights = config.weights.map(|weight| weight.max(1));
+        let (ready_send, ready_recv) = flume::unbounded();
+        Self {
+            classes: Mutex::new(Default::default()),
+            limits,
+            weights,
+            ready_send,
+            ready_recv,
+        }
+    }
+
+    /// Total number of requests that can be queued across all classes
+    pub fn capacity(&self) -> u32 {
+        self.limits.iter().sum::<usize>() as u32
+    }
+
+    /// Total number of requests waiting for a worker
+    pub fn depth(&self) -> u32 {
+        self.classes
+            .lock()
+            .iter()
+            .map(|c| c.requests.len())
+            .sum::<usize>() as u32
+    }
+
+    /// Queue a request in its class, applying the class drop policy if the class queue is full
+    pub fn enqueue(&self, class: RPCPriorityClass, request: T) -> EyreResult<()> {
+        let n = class.index();
+        let entry = (Timestamp::now(), request);
+        {
+            let mut classes = self.classes.lock();
+            let queue = &mut classes[n];
+            if queue.requests.len() >= self.limits[n] {
+                queue.dropped += 1;
+                match class.drop_policy() {
+                    RPCDropPolicy::DropNewest => {
+                        bail!("{} rpc queue is full", class.name());
+                    }
+                    RPCDropPolicy::DropOldest => {
+                        // Replace the oldest request, the token for it stays in the ready channel
+                        queue.requests.pop_front();
+                        queue.requests.push_back(entry);
+                        queue.enqueued += 1;
+                        return Ok(());
+                    }
+                }
+            }
+            queue.requests.push_back(entry);
+            queue.enqueued += 1;
+        }
+        self.ready_send
+            .send(())
+            .map_err(|e| eyre!("rpc queue is closed: {}", e))
+    }
+
+    /// Wait for the next request to process, returning it with the time it was enqueued
+    pub async fn dequeue(&self) -> Option<(Timestamp, T)> {
+        loop {
+            self.ready_recv.recv_async().await.ok()?;
+            if let Some(entry) = self.take_next() {
+                return Some(entry);
+            }
+        }
+    }
+
+    /// Pick the next request by smooth weighted round robin over the classes that have requests waiting
+    fn take_next(&self) -> Option<(Timestamp, T)> {
+        let mut classes = self.classes.lock();
+
+        let mut total_weight = 0i64;
+        let mut best: Option<usize> = None;
+        for class in RPCPriorityClass::ALL {
+            let n = class.index();
+            if classes[n].requests.is_empty() {
+                continue;
+            }
+            let weight = self.weights[n] as i64;
+            classes[n].current_weight += weight;
+            total_weight += weight;
+            if best
+                .map(|b| classes[n].current_weight > classes[b].current_weight)
+                .unwrap_or(true)
+            {
+                best = Some(n);
+            }
+        }
+
+        let queue = &mut classes[best?];
+        queue.current_weight -= total_weight;
+        let (enqueued_ts, request) = queue.requests.pop_front()?;
+
+        let dequeue_latency = Timestamp::now().saturating_sub(enqueued_ts);
+        queue.dequeue_latency = queue
+            .dequeue_latency_accounting
+            .record_latency(dequeue_latency);
+
+        Some((enqueued_ts, request))
+    }
+
+    pub fn get_metrics(&self) -> BTreeMap<String, VeilidMetricsRPCQueue> {
+        let classes = self.classes.lock();
+        RPCPriorityClass::ALL
+            .iter()
+            .map(|class| {
+                let n = class.index();
+                let queue = &classes[n];
+                (
+                    class.name().to_string(),
+                    VeilidMetricsRPCQueue {
+                        depth: queue.requests.len() as u32,
+                        capacity: self.limits[n] as u32,
+                        weight: self.weights[n],
+                        enqueued: queue.enqueued,
+                        dropped: queue.dropped,
+                        dequeue_latency: queue.dequeue_latency.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config(queue_size: u32) -> RPCWorkerQueueConfig {
+        RPCWorkerQueueConfig::new(&VeilidConfigRPC {
+            queue_size,
+            ..Default::default()
+        })
+    }
+
+    fn take_classes(
+        queue: &RPCWorkerQueue<(RPCPriorityClass, u32)>,
+        count: usize,
+    ) -> Vec<RPCPriorityClass> {
+        (0..count)
+            .map_while(|_| queue.take_next())
+            .map(|(_, (class, _))| class)
+            .collect()
+    }
+
+    #[test]
+    fn test_weighted_dequeue_order() {
+        let queue = RPCWorkerQueue::new(&test_config(1024));
+        for class in RPCPriorityClass::ALL {
+            for n in 0..64 {
+                queue.enqueue(class, (class, n)).unwrap();
+            }
+        }
+
+        // Every round of 16 dequeues serves the classes by their 8/4/2/2 weights
+        for _ in 0..4 {
+            let taken = take_classes(&queue, 16);
+            for class in RPCPriorityClass::ALL {
+                let count = taken.iter().filter(|c| **c == class).count();
+                assert_eq!(
+                    count as u32,
+                    queue.weights[class.index()],
+                    "{}",
+                    class.name()
+                );
+            }
+        }
+
+        // Within a class, requests come out in the order they went in
+        let mut last = BTreeMap::<RPCPriorityClass, u32>::new();
+        while let Some((_, (class, n))) = queue.take_next() {
+            if let Some(prev) = last.insert(class, n) {
+                assert!(n > prev);
+            }
+        }
+        assert_eq!(queue.depth(), 0);
+    }
+
+    #[test]
+    fn test_configured_weights() {
+        let mut config = test_config(1024);
+        config.weights = [1, 1, 1, 5];
+        let queue = RPCWorkerQueue::new(&config);
+        for class in RPCPriorityClass::ALL {
+            for n in 0..16 {
+                queue.enqueue(class, (class, n)).unwrap();
+            }
+        }
+        let taken = take_classes(&queue, 8);
+        let app = taken
+            .iter()
+            .filter(|c| **c == RPCPriorityClass::App)
+            .count();
+        assert_eq!(app, 5);
+        assert_eq!(queue.get_metrics()["app"].weight, 5);
+    }
+
+    #[test]
+    fn test_class_overflow() {
+        // 256 * 30% = 76 routing slots, 256 * 20% = 51 app slots
+        let queue = RPCWorkerQueue::new(&test_config(256));
+        assert_eq!(queue.capacity(), 25 + 76 + 102 + 51);
+
+        // Routing drops the newest request when full
+        for n in 0..76 {
+            queue
+                .enqueue(RPCPriorityClass::Routing, (RPCPriorityClass::Routing, n))
+                .unwrap();
+        }
+        assert!(queue
+            .enqueue(RPCPriorityClass::Routing, (RPCPriorityClass::Routing, 76))
+            .is_err());
+
+        // App drops the oldest request when full
+        for n in 0..52 {
+            queue
+                .enqueue(RPCPriorityClass::App, (RPCPriorityClass::App, n))
+                .unwrap();
+        }
+
+        // A full class does not take room from the others
+        queue
+            .enqueue(RPCPriorityClass::DHT, (RPCPriorityClass::DHT, 0))
+            .unwrap();
+
+        let metrics = queue.get_metrics();
+        assert_eq!(metrics["routing"].depth, 76);
+        assert_eq!(metrics["routing"].dropped, 1);
+        assert_eq!(metrics["app"].depth, 51);
+        assert_eq!(metrics["app"].dropped, 1);
+        assert_eq!(metrics["app"].enqueued, 52);
+        assert_eq!(metrics["dht"].depth, 1);
+        assert_eq!(queue.depth(), 128);
+
+        let mut routing = Vec::new();
+        let mut app = Vec::new();
+        while let Some((_, (class, n))) = queue.take_next() {
+            match class {
+                RPCPriorityClass::Routing => routing.push(n),
+                RPCPriorityClass::App => app.push(n),
+                _ => {}
+            }
+        }
+        assert_eq!(routing, (0..76).collect::<Vec<_>>());
+        assert_eq!(app, (1..52).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_no_starvation() {
+        let queue = RPCWorkerQueue::new(&test_config(1024));
+
+        // A flood of control traffic still lets each waiting class through once per round of weights
+        for n in 0..100 {
+            queue
+                .enqueue(RPCPriorityClass::Control, (RPCPriorityClass::Control, n))
+                .unwrap();
+        }
+        queue
+            .enqueue(RPCPriorityClass::App, (RPCPriorityClass::App, 0))
+            .unwrap();
+        queue
+            .enqueue(RPCPriorityClass::DHT, (RPCPriorityClass::DHT, 0))
+            .unwrap();
+
+        let round = queue.weights.iter().sum::<u32>() as usize;
+        let taken = take_classes(&queue, round);
+        assert!(taken.contains(&RPCPriorityClass::App));
+        assert!(taken.contains(&RPCPriorityClass::DHT));
+    }
+
+    #[test]
+    fn test_drop_oldest_keeps_tokens() {
+        let queue = RPCWorkerQueue::new(&test_config(256));
+        for n in 0..60 {
+            queue
+                .enqueue(RPCPriorityClass::App, (RPCPriorityClass::App, n))
+                .unwrap();
+        }
+        // Dropping the oldest app requests leaves one token per queued request
+        let mut count = 0;
+        while queue.ready_recv.try_recv().is_ok() {
+            count += 1;
+        }
+        assert_eq!(count, queue.depth());
+    }
+
+    #[test]
+    fn test_classify_answers() {
+        // An answer to a question nobody asked must not be control traffic
+        let mut builder = ::capnp::message::Builder::new_default();
+        let op_id = OperationId::new(12345);
+        {
+            let mut op = builder.init_root::<veilid_capnp::operation::Builder>();
+            op.set_op_id(op_id.as_u64());
+            let mut answer = op.init_kind().init_answer();
+            answer.reborrow().init_detail().init_status_a();
+        }
+        let data = MessageData::new(builder_to_vec(builder).unwrap());
+        assert_eq!(
+            RPCPriorityClass::classify(&data, |id| id == op_id),
+            RPCPriorityClass::Control
+        );
+        assert_eq!(
+            RPCPriorityClass::classify(&data, |_| false),
+            RPCPriorityClass::App
+        );
+    }
+}
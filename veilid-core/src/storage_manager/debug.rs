@@ -1,6 +1,28 @@
 use super::*;
 
 impl StorageManager {
+    pub async fn get_metrics(&self) -> VeilidMetricsDHT {
+        let inner = self.inner.lock().await;
+        let (local_record_count, local_storage_bytes) = inner
+            .local_record_store
+            .as_ref()
+            .map(|rs| (rs.record_count() as u64, rs.total_storage_space()))
+            .unwrap_or_default();
+        let (remote_record_count, remote_storage_bytes) = inner
+            .remote_record_store
+            .as_ref()
+            .map(|rs| (rs.record_count() as u64, rs.total_storage_space()))
+            .unwrap_or_default();
+        VeilidMetricsDHT {
+            local_record_count,
+            local_storage_bytes,
+            remote_record_count,
+            remote_storage_bytes,
+            opened_record_count: inner.opened_records.len() as u64,
+            offline_subkey_write_count: inner.offline_subkey_writes.len() as u64,
+        }
+    }
+
     pub async fn debug_local_records(&self) -> String {
         let inner = self.inner.lock().await;
         let Some(local_record_store) = &inner.local_record_store else {
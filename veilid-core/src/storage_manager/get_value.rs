@@ -32,6 +32,7 @@ impl StorageManager {
         subkey: ValueSubkey,
         safety_selection: SafetySelection,
         last_get_result: GetResult,
+        options: &CallOptions,
     ) -> VeilidAPIResult<flume::Receiver<VeilidAPIResult<OutboundGetValueResult>>> {
         let routing_domain = RoutingDomain::PublicInternet;
 
@@ -45,6 +46,10 @@ impl StorageManager {
             )
         });
 
+        // Don't run the fanout past the deadline of the call
+        let timeout_us = options.limit_timeout(timeout_us);
+        let stop_token = options.stop_token();
+
         // Get the nodes we know are caching this value to seed the fanout
         let init_fanout_queue = {
             self.get_value_nodes(record_key)
@@ -149,6 +154,7 @@ impl StorageManager {
                             descriptor.owner(),
                             subkey,
                             value.value_data(),
+                            &value.cosigners(),
                         ) {
                             // Validation failed, ignore this value
                             // Move to the next node
@@ -263,7 +269,8 @@ impl StorageManager {
                         capability_fanout_node_info_filter(vec![CAP_DHT]),
                         call_routine,
                         check_done,
-                    );
+                    )
+                    .with_stop_token(stop_token);
 
                     let fanout_result = match fanout_call.run(init_fanout_queue).await {
                         Ok(v) => v,
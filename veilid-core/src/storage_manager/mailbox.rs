@@ -0,0 +1,659 @@
+use super::*;
+
+impl_veilid_log_facility!("stor");
+
+/// Largest number of deposit slots a mailbox can have
+pub(crate) const MAX_MAILBOX_SLOTS: u16 = 128;
+/// How long a deposited message waits to be drained if the sender does not say
+pub(crate) const DEFAULT_MAILBOX_MESSAGE_EXPIRATION_US: u64 = 7 * 24 * 60 * 60 * 1_000_000;
+/// Owner subkey of a mailbox record where the recipient acknowledges the slots it has drained
+const MAILBOX_ACK_SUBKEY: ValueSubkey = 0;
+/// Length of the expiration timestamp in front of each deposited message
+const MAILBOX_EXPIRATION_LEN: usize = 8;
+/// Length of the namespace in front of the sealed message body
+const MAILBOX_NAMESPACE_LEN: usize = 4;
+/// Length of one slot acknowledgement
+const MAILBOX_ACK_LEN: usize = 8;
+/// How many slots to try before giving up when other senders take them first
+const MAILBOX_DEPOSIT_ATTEMPTS: usize = 3;
+
+/// A mailbox this node owns and drains
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(super) struct MailboxState {
+    /// Owner of the mailbox record, whose public key messages are sealed to
+    pub owner: KeyPair,
+    /// Safety selection to use when draining the mailbox
+    pub safety_selection: SafetySelection,
+}
+
+/// Get the deposit slots of a mailbox record schema, or fail if the record is not a mailbox.
+/// Mailboxes have one owner subkey for acknowledgements followed by the slots of each depositor member.
+/// With a depositor, only the slots of that depositor's member are returned.
+fn mailbox_slots(
+    schema: &DHTSchema,
+    depositor: Option<&PublicKey>,
+) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+    let DHTSchema::SMPL(smpl) = schema else {
+        apibail_generic!("record is not a mailbox");
+    };
+    if smpl.o_cnt() != 1 || smpl.members().is_empty() || smpl.members().iter().any(|m| m.m_cnt == 0)
+    {
+        apibail_generic!("record is not a mailbox");
+    }
+
+    let mut first = MAILBOX_ACK_SUBKEY + 1;
+    for member in smpl.members() {
+        let last = first + member.m_cnt as ValueSubkey - 1;
+        if depositor.is_some_and(|d| d == &member.m_key) {
+            return Ok(ValueSubkeyRangeSet::single_range(first, last));
+        }
+        first = last + 1;
+    }
+    if depositor.is_some() {
+        apibail_generic!("mailbox address does not match record");
+    }
+    Ok(ValueSubkeyRangeSet::single_range(
+        MAILBOX_ACK_SUBKEY + 1,
+        first - 1,
+    ))
+}
+
+/// Split mailbox slots into the ones a message can be deposited in and the ones holding undrained messages.
+/// A slot is free if it was never written or if its message has been acknowledged.
+fn sort_mailbox_slots(
+    slots: &ValueSubkeyRangeSet,
+    seqs: &[Option<ValueSeqNum>],
+    acks: &BTreeMap<ValueSubkey, ValueSeqNum>,
+) -> (Vec<ValueSubkey>, Vec<ValueSubkey>) {
+    let mut free_slots = Vec::new();
+    let mut undrained_slots = Vec::new();
+    for (slot, seq) in slots.iter().zip(seqs.iter()) {
+        match seq {
+            None => free_slots.push(slot),
+            Some(seq) if acks.get(&slot).is_some_and(|a| a >= seq) => free_slots.push(slot),
+            Some(_) => undrained_slots.push(slot),
+        }
+    }
+    (free_slots, undrained_slots)
+}
+
+/// Slots of a mailbox and the sequence number of the last message drained from each
+fn decode_mailbox_acks(data: &[u8]) -> BTreeMap<ValueSubkey, ValueSeqNum> {
+    data.chunks_exact(MAILBOX_ACK_LEN)
+        .map(|c| {
+            (
+                ValueSubkey::from_le_bytes(c[0..4].try_into().unwrap()),
+                ValueSeqNum::from_le_bytes(c[4..8].try_into().unwrap()),
+            )
+        })
+        .collect()
+}
+
+fn encode_mailbox_acks(acks: &BTreeMap<ValueSubkey, ValueSeqNum>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(acks.len() * MAILBOX_ACK_LEN);
+    for (slot, seq) in acks {
+        out.extend_from_slice(&slot.to_le_bytes());
+        out.extend_from_slice(&seq.to_le_bytes());
+    }
+    out
+}
+
+/// Get the expiration timestamp of a deposited message, which is readable without opening it
+fn mailbox_message_expiration(data: &[u8]) -> Option<Timestamp> {
+    let expiration = data.get(0..MAILBOX_EXPIRATION_LEN)?;
+    Some(Timestamp::new(u64::from_le_bytes(
+        expiration.try_into().unwrap(),
+    )))
+}
+
+/// Whether a deposited message has expired, so its slot can be reused or its message dropped
+fn mailbox_message_expired(data: &[u8], cur_ts: Timestamp) -> bool {
+    mailbox_message_expiration(data).is_none_or(|e| e < cur_ts)
+}
+
+/// Frame an app message for sealing, keeping its namespace with it
+pub(super) fn encode_mailbox_body(namespace: u32, message: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(MAILBOX_NAMESPACE_LEN + message.len());
+    body.extend_from_slice(&namespace.to_le_bytes());
+    body.extend_from_slice(message);
+    body
+}
+
+pub(super) fn decode_mailbox_body(body: &[u8]) -> Option<(u32, Vec<u8>)> {
+    let namespace = body.get(0..MAILBOX_NAMESPACE_LEN)?;
+    Some((
+        u32::from_le_bytes(namespace.try_into().unwrap()),
+        body[MAILBOX_NAMESPACE_LEN..].to_vec(),
+    ))
+}
+
+/// Frame a sealed message for a slot, leaving the expiration readable so other senders can reuse the slot
+fn encode_mailbox_message(expiration: Timestamp, sealed: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(MAILBOX_EXPIRATION_LEN + sealed.len());
+    data.extend_from_slice(&expiration.as_u64().to_le_bytes());
+    data.extend_from_slice(sealed);
+    data
+}
+
+impl StorageManager {
+    /// Create a mailbox that other nodes can deposit messages in while we are away.
+    /// Each depositor gets its own member keypair and range of slots, so one sender can not overwrite another's messages.
+    /// The mailbox is drained in the background whenever we are online.
+    /// For a mailbox kept by a chosen peer instead of the DHT, see create_peer_mailbox.
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn create_mailbox(
+        &self,
+        kind: CryptoKind,
+        depositor_count: u16,
+        slots_per_depositor: u16,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<Vec<MailboxAddress>> {
+        if depositor_count == 0 || depositor_count > MAX_MAILBOX_SLOTS {
+            apibail_invalid_argument!(
+                "invalid mailbox depositor count",
+                "depositor_count",
+                depositor_count
+            );
+        }
+        if slots_per_depositor == 0
+            || depositor_count as usize * slots_per_depositor as usize > MAX_MAILBOX_SLOTS as usize
+        {
+            apibail_invalid_argument!(
+                "invalid mailbox slot count",
+                "slots_per_depositor",
+                slots_per_depositor
+            );
+        }
+
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get_async(kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        // Each sender gets a depositor keypair, the owner keypair stays with us
+        let owner = vcrypto.generate_keypair().await;
+        let mut depositors = Vec::with_capacity(depositor_count as usize);
+        for _ in 0..depositor_count {
+            depositors.push(vcrypto.generate_keypair().await);
+        }
+        let schema = DHTSchema::SMPL(DHTSchemaSMPL::new(
+            1,
+            depositors
+                .iter()
+                .map(|d| DHTSchemaSMPLMember {
+                    m_key: d.key,
+                    m_cnt: slots_per_depositor,
+                })
+                .collect(),
+        )?);
+
+        let descriptor = self
+            .create_record(kind, schema, Some(owner), safety_selection)
+            .await?;
+        let record_key = *descriptor.key();
+
+        // Publish the record with nothing acknowledged yet so senders can find it
+        let res = self
+            .set_value(
+                record_key,
+                MAILBOX_ACK_SUBKEY,
+                vec![],
+                None,
+                vec![],
+                CallOptions::default(),
+            )
+            .await;
+        self.close_record(record_key).await?;
+        res?;
+
+        self.inner.lock().await.mailboxes.insert(
+            record_key,
+            MailboxState {
+                owner,
+                safety_selection,
+            },
+        );
+
+        Ok(depositors
+            .into_iter()
+            .map(|depositor| MailboxAddress::new(record_key, depositor))
+            .collect())
+    }
+
+    /// Stop draining a mailbox we created and delete its record
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn delete_mailbox(&self, record_key: TypedRecordKey) -> VeilidAPIResult<()> {
+        if self
+            .inner
+            .lock()
+            .await
+            .mailboxes
+            .remove(&record_key)
+            .is_none()
+        {
+            apibail_key_not_found!(record_key);
+        }
+        self.delete_record(record_key).await
+    }
+
+    pub(super) async fn has_mailboxes(&self) -> bool {
+        let inner = self.inner.lock().await;
+        !inner.mailboxes.is_empty() || !inner.peer_mailboxes.is_empty()
+    }
+
+    /// Seal a message to the owner of a mailbox and write it to a free deposit slot.
+    /// A slot is free if it was never written, if its message was drained, or if its message has expired.
+    /// Messages expire after a week if no expiration is given.
+    /// When offline, the slot is chosen from our local copy of the mailbox and the deposit is
+    /// queued as an offline subkey write, so a mailbox we have not seen before can not be deposited in.
+    #[instrument(level = "trace", target = "stor", skip(self, message), fields(message.len = message.len()))]
+    pub async fn deposit_mailbox_message(
+        &self,
+        address: MailboxAddress,
+        namespace: u32,
+        message: Vec<u8>,
+        expiration: Option<Timestamp>,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<()> {
+        let expiration = expiration.unwrap_or_else(|| {
+            Timestamp::now() + TimestampDuration::new(DEFAULT_MAILBOX_MESSAGE_EXPIRATION_US)
+        });
+        if expiration <= Timestamp::now() {
+            apibail_invalid_argument!("expiration is in the past", "expiration", expiration);
+        }
+
+        let record_key = *address.record_key();
+        let (descriptor, was_open) = self
+            .open_mailbox_record(record_key, *address.depositor(), safety_selection)
+            .await?;
+
+        let res = self
+            .deposit_mailbox_message_inner(
+                &descriptor,
+                &address,
+                namespace,
+                message,
+                expiration,
+                self.dht_is_online(),
+            )
+            .await;
+
+        if !was_open {
+            self.close_record(record_key).await?;
+        }
+        res
+    }
+
+    async fn deposit_mailbox_message_inner(
+        &self,
+        descriptor: &DHTRecordDescriptor,
+        address: &MailboxAddress,
+        namespace: u32,
+        message: Vec<u8>,
+        expiration: Timestamp,
+        online: bool,
+    ) -> VeilidAPIResult<()> {
+        let record_key = *descriptor.key();
+        let depositor = *address.depositor();
+        let slots = mailbox_slots(descriptor.schema(), Some(&depositor.key))?;
+        let total_slots = mailbox_slots(descriptor.schema(), None)?.len();
+
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get_async(record_key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        // Seal the message to the recipient
+        let sealed = vcrypto
+            .seal(
+                descriptor.owner(),
+                &encode_mailbox_body(namespace, &message),
+            )
+            .await?;
+        let data = encode_mailbox_message(expiration, &sealed);
+
+        let max_value_len = usize::min(
+            MAX_SUBKEY_SIZE,
+            MAX_RECORD_DATA_SIZE / (total_slots as usize + 1),
+        );
+        if data.len() > max_value_len {
+            apibail_invalid_argument!("message too long for mailbox", "message.len", message.len());
+        }
+
+        // See which slots hold messages that have not been drained
+        let acks = match self
+            .get_mailbox_value(record_key, MAILBOX_ACK_SUBKEY, online)
+            .await?
+        {
+            Some(v) => decode_mailbox_acks(v.data()),
+            None => BTreeMap::new(),
+        };
+        let (mut free_slots, mut used_slots) = if online {
+            let report = self
+                .inspect_record(record_key, slots, DHTReportScope::SyncGet)
+                .await?;
+            sort_mailbox_slots(report.subkeys(), report.network_seqs(), &acks)
+        } else {
+            let report = self
+                .inspect_record(record_key, slots, DHTReportScope::Local)
+                .await?;
+            sort_mailbox_slots(report.subkeys(), report.local_seqs(), &acks)
+        };
+
+        // Try the free slots in random order, then the slots with expired messages
+        let cur_ts = Timestamp::now();
+        let mut attempts = 0;
+        loop {
+            if attempts == MAILBOX_DEPOSIT_ATTEMPTS {
+                apibail_try_again!("mailbox is busy");
+            }
+
+            let slot = if !free_slots.is_empty() {
+                free_slots.swap_remove(get_random_u32() as usize % free_slots.len())
+            } else {
+                let mut expired_slot = None;
+                while let Some(slot) = used_slots.pop() {
+                    let expired = self
+                        .get_mailbox_value(record_key, slot, online)
+                        .await?
+                        .map(|v| mailbox_message_expired(v.data(), cur_ts))
+                        .unwrap_or(true);
+                    if expired {
+                        expired_slot = Some(slot);
+                        break;
+                    }
+                }
+                let Some(slot) = expired_slot else {
+                    apibail_try_again!("mailbox is full");
+                };
+                slot
+            };
+            attempts += 1;
+
+            // Sync the slot so our write has the next sequence number
+            let last_seq = self
+                .get_mailbox_value(record_key, slot, online)
+                .await?
+                .map(|v| v.seq());
+
+            // Offline, or if the network write fails, the deposit is queued as an offline subkey write
+            let Some(newer) = self
+                .set_value(
+                    record_key,
+                    slot,
+                    data.clone(),
+                    Some(depositor),
+                    vec![],
+                    CallOptions::default(),
+                )
+                .await?
+            else {
+                return Ok(());
+            };
+            if newer.data() == data.as_slice() {
+                return Ok(());
+            }
+            // Another sender took the slot first
+            veilid_log!(self debug "Mailbox slot taken: {}:{} last_seq={:?} seq={}", record_key, slot, last_seq, newer.seq());
+        }
+    }
+
+    /// Get a mailbox subkey from the network, or from our local copy when offline
+    async fn get_mailbox_value(
+        &self,
+        record_key: TypedRecordKey,
+        subkey: ValueSubkey,
+        online: bool,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        if online {
+            return self
+                .get_value(record_key, subkey, true, CallOptions::default())
+                .await;
+        }
+        let mut inner = self.inner.lock().await;
+        let get_result =
+            Self::handle_get_local_value_inner(&mut inner, record_key, subkey, false).await?;
+        Ok(get_result.opt_value.map(|v| v.value_data().clone()))
+    }
+
+    /// Open a mailbox record unless the application already has it open, in which case its writer and
+    /// safety selection are left alone. Returns whether the record was already open, so it is only closed if it was not.
+    async fn open_mailbox_record(
+        &self,
+        record_key: TypedRecordKey,
+        writer: KeyPair,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<(DHTRecordDescriptor, bool)> {
+        let opened = self
+            .inner
+            .lock()
+            .await
+            .opened_records
+            .get(&record_key)
+            .map(|o| (o.writer().cloned(), o.safety_selection()));
+        match opened {
+            Some((opened_writer, opened_safety_selection)) => Ok((
+                self.open_record(record_key, opened_writer, opened_safety_selection)
+                    .await?,
+                true,
+            )),
+            None => Ok((
+                self.open_record(record_key, Some(writer), safety_selection)
+                    .await?,
+                false,
+            )),
+        }
+    }
+
+    /// Deliver the undrained messages of a mailbox as app messages and acknowledge them
+    #[instrument(level = "trace", target = "stor", skip(self, state))]
+    pub(super) async fn drain_mailbox(
+        &self,
+        record_key: TypedRecordKey,
+        state: MailboxState,
+    ) -> VeilidAPIResult<usize> {
+        let (descriptor, was_open) = self
+            .open_mailbox_record(record_key, state.owner, state.safety_selection)
+            .await?;
+
+        let res = self.drain_mailbox_inner(&descriptor, &state.owner).await;
+
+        if !was_open {
+            self.close_record(record_key).await?;
+        }
+        res
+    }
+
+    async fn drain_mailbox_inner(
+        &self,
+        descriptor: &DHTRecordDescriptor,
+        owner: &KeyPair,
+    ) -> VeilidAPIResult<usize> {
+        let record_key = *descriptor.key();
+        let slots = mailbox_slots(descriptor.schema(), None)?;
+
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get_async(record_key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        // Only we write the acknowledgements, so the local copy is the latest
+        let mut acks = match self
+            .get_value(
+                record_key,
+                MAILBOX_ACK_SUBKEY,
+                false,
+                CallOptions::default(),
+            )
+            .await?
+        {
+            Some(v) => decode_mailbox_acks(v.data()),
+            None => BTreeMap::new(),
+        };
+        let report = self
+            .inspect_record(record_key, slots, DHTReportScope::SyncGet)
+            .await?;
+        let (_, undrained_slots) =
+            sort_mailbox_slots(report.subkeys(), report.network_seqs(), &acks);
+
+        let cur_ts = Timestamp::now();
+        let mut acked = false;
+        let mut delivered = 0;
+        for slot in undrained_slots {
+            let Some(value) = self
+                .get_value(record_key, slot, true, CallOptions::default())
+                .await?
+            else {
+                continue;
+            };
+
+            // Acknowledge whatever is in the slot, even if it is expired or can not be opened
+            acks.insert(slot, value.seq());
+            acked = true;
+
+            if mailbox_message_expired(value.data(), cur_ts) {
+                veilid_log!(self debug "Dropping expired mailbox message: {}:{}", record_key, slot);
+                continue;
+            }
+            let opened = vcrypto
+                .open(
+                    &owner.key,
+                    &owner.secret,
+                    &value.data()[MAILBOX_EXPIRATION_LEN..],
+                )
+                .await;
+            let Some((namespace, message)) = opened.ok().as_deref().and_then(decode_mailbox_body)
+            else {
+                veilid_log!(self debug "Dropping unreadable mailbox message: {}:{}", record_key, slot);
+                continue;
+            };
+
+            // Mailbox messages are delivered like app messages received over a safety route
+            (self.update_callback())(VeilidUpdate::AppMessage(Box::new(VeilidAppMessage::new(
+                None, None, namespace, message,
+            ))));
+            delivered += 1;
+        }
+
+        // Acknowledge the drained slots, which are written when we are online again if this write fails
+        if acked {
+            self.set_value(
+                record_key,
+                MAILBOX_ACK_SUBKEY,
+                encode_mailbox_acks(&acks),
+                Some(*owner),
+                vec![],
+                CallOptions::default(),
+            )
+            .await?;
+        }
+
+        Ok(delivered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(seed: u8, m_cnt: u16) -> (PublicKey, DHTSchemaSMPLMember) {
+        let m_key = PublicKey::new([seed; PUBLIC_KEY_LENGTH]);
+        (m_key, DHTSchemaSMPLMember { m_key, m_cnt })
+    }
+
+    fn expiring_message(expiration: u64) -> Vec<u8> {
+        encode_mailbox_message(Timestamp::new(expiration), b"sealed")
+    }
+
+    #[test]
+    fn test_mailbox_slots() {
+        let (a, ma) = member(1, 2);
+        let (b, mb) = member(2, 3);
+        let (c, _) = member(3, 1);
+        let schema = DHTSchema::SMPL(DHTSchemaSMPL::new(1, vec![ma.clone(), mb.clone()]).unwrap());
+
+        // Each depositor only sees its own slots, the owner drains all of them
+        assert_eq!(
+            mailbox_slots(&schema, Some(&a)).unwrap(),
+            ValueSubkeyRangeSet::single_range(1, 2)
+        );
+        assert_eq!(
+            mailbox_slots(&schema, Some(&b)).unwrap(),
+            ValueSubkeyRangeSet::single_range(3, 5)
+        );
+        assert_eq!(
+            mailbox_slots(&schema, None).unwrap(),
+            ValueSubkeyRangeSet::single_range(1, 5)
+        );
+        assert!(mailbox_slots(&schema, Some(&c)).is_err());
+
+        // Records that are not mailboxes are refused
+        let no_ack = DHTSchema::SMPL(DHTSchemaSMPL::new(0, vec![ma.clone()]).unwrap());
+        assert!(mailbox_slots(&no_ack, None).is_err());
+        let no_members = DHTSchema::SMPL(DHTSchemaSMPL::new(1, vec![]).unwrap());
+        assert!(mailbox_slots(&no_members, None).is_err());
+        let dflt = DHTSchema::dflt(3).unwrap();
+        assert!(mailbox_slots(&dflt, None).is_err());
+    }
+
+    #[test]
+    fn test_deposit_slots() {
+        // Never written, drained, and undrained slots
+        let slots = ValueSubkeyRangeSet::single_range(1, 4);
+        let seqs = [None, Some(2), Some(5), Some(0)];
+        let acks = BTreeMap::from([(2, 2), (3, 4)]);
+        let (free, undrained) = sort_mailbox_slots(&slots, &seqs, &acks);
+        assert_eq!(free, vec![1, 2]);
+        assert_eq!(undrained, vec![3, 4]);
+
+        let body = encode_mailbox_body(7, b"hello");
+        assert_eq!(decode_mailbox_body(&body), Some((7, b"hello".to_vec())));
+        assert_eq!(decode_mailbox_body(&[1, 2]), None);
+
+        let data = expiring_message(1000);
+        assert_eq!(
+            mailbox_message_expiration(&data),
+            Some(Timestamp::new(1000))
+        );
+        assert_eq!(&data[MAILBOX_EXPIRATION_LEN..], b"sealed");
+    }
+
+    #[test]
+    fn test_drain_acks() {
+        let slots = ValueSubkeyRangeSet::single_range(1, 3);
+        let seqs = [Some(0), Some(3), None];
+        let mut acks = decode_mailbox_acks(&[]);
+        let (_, undrained) = sort_mailbox_slots(&slots, &seqs, &acks);
+        assert_eq!(undrained, vec![1, 2]);
+
+        // Once acknowledged the slots are free again until a newer message is deposited
+        acks.insert(1, 0);
+        acks.insert(2, 3);
+        let acks = decode_mailbox_acks(&encode_mailbox_acks(&acks));
+        let (free, undrained) = sort_mailbox_slots(&slots, &seqs, &acks);
+        assert_eq!(free, vec![1, 2, 3]);
+        assert!(undrained.is_empty());
+
+        let seqs = [Some(1), Some(3), None];
+        let (_, undrained) = sort_mailbox_slots(&slots, &seqs, &acks);
+        assert_eq!(undrained, vec![1]);
+    }
+
+    #[test]
+    fn test_full_mailbox() {
+        let slots = ValueSubkeyRangeSet::single_range(1, 2);
+        let seqs = [Some(0), Some(1)];
+        let (free, undrained) = sort_mailbox_slots(&slots, &seqs, &BTreeMap::new());
+        assert!(free.is_empty());
+        assert_eq!(undrained, vec![1, 2]);
+
+        // A full mailbox only takes a deposit in a slot whose message has expired
+        let cur_ts = Timestamp::new(2000);
+        assert!(!mailbox_message_expired(&expiring_message(3000), cur_ts));
+        assert!(mailbox_message_expired(&expiring_message(1000), cur_ts));
+        assert!(mailbox_message_expired(&[0u8; 4], cur_ts));
+    }
+}
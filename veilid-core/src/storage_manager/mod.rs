@@ -1,22 +1,29 @@
 mod debug;
 mod get_value;
 mod inspect_value;
+mod mailbox;
 mod outbound_watch_manager;
+mod peer_mailbox;
 mod record_store;
 mod rehydrate;
 mod set_value;
+mod suspend;
 mod tasks;
+mod topic;
 mod types;
 mod watch_value;
 
 use super::*;
 
 use hashlink::LinkedHashMap;
+use mailbox::*;
 use outbound_watch_manager::*;
+use peer_mailbox::*;
 use record_store::*;
 use rehydrate::*;
 use routing_table::*;
 use rpc_processor::*;
+use topic::*;
 
 pub use record_store::{InboundWatchParameters, InboundWatchResult};
 
@@ -30,6 +37,8 @@ pub(crate) const MAX_SUBKEY_SIZE: usize = ValueData::MAX_LEN;
 pub(crate) const MAX_RECORD_DATA_SIZE: usize = 1_048_576;
 /// Frequency to flush record stores to disk
 const FLUSH_RECORD_STORES_INTERVAL_SECS: u32 = 1;
+/// Frequency to flush record stores to disk in the low power profile
+const LOW_POWER_FLUSH_RECORD_STORES_INTERVAL_SECS: u32 = 60;
 /// Frequency to save metadata to disk
 const SAVE_METADATA_INTERVAL_SECS: u32 = 30;
 /// Frequency to check for offline subkeys writes to send to the network
@@ -52,6 +61,12 @@ const CHECK_WATCHED_RECORDS_INTERVAL_SECS: u32 = 1;
 const REHYDRATE_RECORDS_INTERVAL_SECS: u32 = 1;
 /// Number of rehydration requests to process in parallel
 const REHYDRATE_BATCH_SIZE: usize = 16;
+/// Frequency to check our mailboxes for deposited messages
+const DRAIN_MAILBOXES_INTERVAL_SECS: u32 = 60;
+/// Number of mailboxes to drain in parallel
+const DRAIN_MAILBOXES_BATCH_SIZE: usize = 4;
+/// Frequency to renew dead topic watches and fetch topic messages that changes did not include
+const CHECK_TOPIC_SUBSCRIPTIONS_INTERVAL_SECS: u32 = 1;
 /// Table store table for storage manager metadata
 const STORAGE_MANAGER_METADATA: &str = "storage_manager_metadata";
 /// Storage manager metadata key name for offline subkey write persistence
@@ -60,6 +75,12 @@ const OFFLINE_SUBKEY_WRITES: &[u8] = b"offline_subkey_writes";
 const OUTBOUND_WATCH_MANAGER: &[u8] = b"outbound_watch_manager";
 /// Rehydration requests metadata key name for watch persistence
 const REHYDRATION_REQUESTS: &[u8] = b"rehydration_requests";
+/// Mailboxes metadata key name for mailbox persistence
+const MAILBOXES: &[u8] = b"mailboxes";
+/// Peer mailboxes metadata key name for persistence of mailboxes kept for us by other nodes
+const PEER_MAILBOXES: &[u8] = b"peer_mailboxes";
+/// Hosted mailboxes metadata key name for persistence of mailboxes we keep for other nodes
+const HOSTED_MAILBOXES: &[u8] = b"hosted_mailboxes";
 
 #[derive(Debug, Clone)]
 /// A single 'value changed' message to send
@@ -88,6 +109,12 @@ struct StorageManagerInner {
     pub active_subkey_writes: HashMap<TypedRecordKey, ValueSubkeyRangeSet>,
     /// Records that have rehydration requests
     pub rehydration_requests: HashMap<TypedRecordKey, RehydrationRequest>,
+    /// Mailboxes we created and drain for deposited messages
+    pub mailboxes: HashMap<TypedRecordKey, MailboxState>,
+    /// Mailboxes other nodes keep for us, which we drain for deposited messages
+    pub peer_mailboxes: HashMap<TypedPublicKey, PeerMailboxState>,
+    /// Mailboxes we keep for other nodes, with the messages deposited in them
+    pub hosted_mailboxes: HashMap<TypedPublicKey, HostedMailbox>,
     /// State management for outbound watches
     pub outbound_watch_manager: OutboundWatchManager,
     /// Storage manager metadata that is persistent, including copy of offline subkey writes
@@ -108,6 +135,9 @@ impl fmt::Debug for StorageManagerInner {
             .field("offline_subkey_writes", &self.offline_subkey_writes)
             .field("active_subkey_writes", &self.active_subkey_writes)
             .field("rehydration_requests", &self.rehydration_requests)
+            .field("mailboxes", &self.mailboxes)
+            .field("peer_mailboxes", &self.peer_mailboxes)
+            .field("hosted_mailboxes", &self.hosted_mailboxes)
             .field("outbound_watch_manager", &self.outbound_watch_manager)
             .field(
                 "peer_info_change_subscription",
@@ -131,6 +161,8 @@ pub(crate) struct StorageManager {
     check_outbound_watches_task: TickTask<EyreReport>,
     check_inbound_watches_task: TickTask<EyreReport>,
     rehydrate_records_task: TickTask<EyreReport>,
+    drain_mailboxes_task: TickTask<EyreReport>,
+    check_topic_subscriptions_task: TickTask<EyreReport>,
 
     // Anonymous watch keys
     anonymous_watch_keys: TypedKeyPairGroup,
@@ -146,6 +178,9 @@ pub(crate) struct StorageManager {
 
     // Online check
     is_online: AtomicBool,
+
+    // Topics we deliver messages from, checked synchronously when values change
+    topic_subscriptions: Mutex<HashMap<TypedRecordKey, TopicSubscription>>,
 }
 
 impl fmt::Debug for StorageManager {
@@ -223,10 +258,19 @@ impl StorageManager {
                 "rehydrate_records_task",
                 REHYDRATE_RECORDS_INTERVAL_SECS,
             ),
+            drain_mailboxes_task: TickTask::new(
+                "drain_mailboxes_task",
+                DRAIN_MAILBOXES_INTERVAL_SECS,
+            ),
+            check_topic_subscriptions_task: TickTask::new(
+                "check_topic_subscriptions_task",
+                CHECK_TOPIC_SUBSCRIPTIONS_INTERVAL_SECS,
+            ),
             outbound_watch_lock_table: AsyncTagLockTable::new(),
             anonymous_watch_keys,
             background_operation_processor: DeferredStreamProcessor::new(),
             is_online: AtomicBool::new(false),
+            topic_subscriptions: Mutex::new(HashMap::new()),
         };
 
         this.setup_tasks();
@@ -274,6 +318,21 @@ impl StorageManager {
         }
     }
 
+    /// Pick up record store limits that have changed in the config
+    pub(crate) async fn reload_limits(&self) {
+        let config = self.config();
+        let local_limits = Self::local_limits_from_config(config.clone());
+        let remote_limits = Self::remote_limits_from_config(config);
+
+        let mut inner = self.inner.lock().await;
+        if let Some(local_record_store) = inner.local_record_store.as_mut() {
+            local_record_store.set_watch_limits(&local_limits);
+        }
+        if let Some(remote_record_store) = inner.remote_record_store.as_mut() {
+            remote_record_store.set_watch_limits(&remote_limits);
+        }
+    }
+
     #[instrument(level = "debug", skip_all, err)]
     async fn init_async(&self) -> EyreResult<()> {
         veilid_log!(self debug "startup storage manager");
@@ -382,6 +441,7 @@ impl StorageManager {
             // Reset inner state
             *inner = Self::new_inner();
         }
+        self.topic_subscriptions.lock().clear();
 
         veilid_log!(self debug "finished storage manager shutdown");
     }
@@ -392,6 +452,9 @@ impl StorageManager {
             tx.store_json(0, OFFLINE_SUBKEY_WRITES, &inner.offline_subkey_writes)?;
             tx.store_json(0, OUTBOUND_WATCH_MANAGER, &inner.outbound_watch_manager)?;
             tx.store_json(0, REHYDRATION_REQUESTS, &inner.rehydration_requests)?;
+            tx.store_json(0, MAILBOXES, &inner.mailboxes)?;
+            tx.store_json(0, PEER_MAILBOXES, &inner.peer_mailboxes)?;
+            tx.store_json(0, HOSTED_MAILBOXES, &inner.hosted_mailboxes)?;
             tx.commit().await.wrap_err("failed to commit")?
         }
         Ok(())
@@ -433,6 +496,33 @@ impl StorageManager {
                     Default::default()
                 }
             };
+            inner.mailboxes = match metadata_db.load_json(0, MAILBOXES).await {
+                Ok(v) => v.unwrap_or_default(),
+                Err(_) => {
+                    if let Err(e) = metadata_db.delete(0, MAILBOXES).await {
+                        veilid_log!(self debug "mailboxes format changed, clearing: {}", e);
+                    }
+                    Default::default()
+                }
+            };
+            inner.peer_mailboxes = match metadata_db.load_json(0, PEER_MAILBOXES).await {
+                Ok(v) => v.unwrap_or_default(),
+                Err(_) => {
+                    if let Err(e) = metadata_db.delete(0, PEER_MAILBOXES).await {
+                        veilid_log!(self debug "peer_mailboxes format changed, clearing: {}", e);
+                    }
+                    Default::default()
+                }
+            };
+            inner.hosted_mailboxes = match metadata_db.load_json(0, HOSTED_MAILBOXES).await {
+                Ok(v) => v.unwrap_or_default(),
+                Err(_) => {
+                    if let Err(e) = metadata_db.delete(0, HOSTED_MAILBOXES).await {
+                        veilid_log!(self debug "hosted_mailboxes format changed, clearing: {}", e);
+                    }
+                    Default::default()
+                }
+            };
         }
         Ok(())
     }
@@ -645,12 +735,14 @@ impl StorageManager {
     }
 
     /// Get the value of a subkey from an opened local record
+    /// The call options limit and cancel the network refresh, not the local lookup
     #[instrument(level = "trace", target = "stor", skip_all)]
     pub async fn get_value(
         &self,
         record_key: TypedRecordKey,
         subkey: ValueSubkey,
         force_refresh: bool,
+        options: CallOptions,
     ) -> VeilidAPIResult<Option<ValueData>> {
         let mut inner = self.inner.lock().await;
         let safety_selection = {
@@ -690,7 +782,13 @@ impl StorageManager {
             .as_ref()
             .map(|v| v.value_data().seq());
         let res_rx = self
-            .outbound_get_value(record_key, subkey, safety_selection, last_get_result)
+            .outbound_get_value(
+                record_key,
+                subkey,
+                safety_selection,
+                last_get_result,
+                &options,
+            )
             .await?;
 
         // Wait for the first result
@@ -721,6 +819,8 @@ impl StorageManager {
     }
 
     /// Set the value of a subkey on an opened local record
+    /// Any cosignatures must have been made over the value as it will be written, including its next sequence number
+    /// If the call is cancelled, the value has still been written locally and is pushed to the network later as an offline write
     #[instrument(level = "trace", target = "stor", skip_all)]
     pub async fn set_value(
         &self,
@@ -728,6 +828,8 @@ impl StorageManager {
         subkey: ValueSubkey,
         data: Vec<u8>,
         writer: Option<KeyPair>,
+        cosignatures: Vec<DHTCosignature>,
+        options: CallOptions,
     ) -> VeilidAPIResult<Option<ValueData>> {
         let mut inner = self.inner.lock().await;
 
@@ -769,6 +871,7 @@ impl StorageManager {
         let value_data = if let Some(last_signed_value_data) = last_get_result.opt_value {
             if last_signed_value_data.value_data().data() == data
                 && last_signed_value_data.value_data().writer() == &writer.key
+                && cosignatures.is_empty()
             {
                 // Data and writer is the same, nothing is changing,
                 // just return that we set it, but no network activity needs to happen
@@ -781,19 +884,31 @@ impl StorageManager {
         };
 
         // Validate with schema
-        if !schema.check_subkey_value_data(descriptor.owner(), subkey, &value_data) {
+        let cosigners = cosignatures.iter().map(|c| *c.signer()).collect::<Vec<_>>();
+        if !schema.check_subkey_value_data(descriptor.owner(), subkey, &value_data, &cosigners) {
             // Validation failed, ignore this value
             apibail_generic!("failed schema validation");
         }
 
         // Sign the new value data with the writer
-        let signed_value_data = Arc::new(SignedValueData::make_signature(
+        let signed_value_data = SignedValueData::make_signature(
             value_data,
             descriptor.owner(),
             subkey,
             &vcrypto,
             writer.secret,
-        )?);
+        )?
+        .with_cosignatures(cosignatures);
+
+        // Cosignatures must match the value being written
+        if !signed_value_data.validate_cosignatures(descriptor.owner(), subkey, &vcrypto)? {
+            apibail_invalid_argument!(
+                "cosignatures do not match value",
+                "seq",
+                signed_value_data.value_data().seq()
+            );
+        }
+        let signed_value_data = Arc::new(signed_value_data);
 
         // Write the value locally first
         veilid_log!(self debug "Writing subkey locally: {}:{} len={}", record_key, subkey, signed_value_data.value_data().data().len() );
@@ -840,6 +955,7 @@ impl StorageManager {
                 safety_selection,
                 signed_value_data.clone(),
                 descriptor,
+                &options,
             )
             .await
         {
@@ -919,10 +1035,58 @@ impl StorageManager {
         if matches!(out, Err(VeilidAPIError::TryAgain { message: _ })) {
             return Ok(None);
         }
+        if matches!(out, Err(VeilidAPIError::Cancelled)) {
+            // Finish the write in the background
+            Self::add_offline_subkey_write_inner(&mut inner, record_key, subkey, safety_selection);
+        }
 
         out
     }
 
+    /// Cosign a value that another owner of an opened local record is going to write to an owner subkey
+    /// The value is not written, the cosignature is handed to the writer to pass to set_value
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn cosign_value(
+        &self,
+        record_key: TypedRecordKey,
+        subkey: ValueSubkey,
+        seq: ValueSeqNum,
+        data: Vec<u8>,
+        signer: KeyPair,
+    ) -> VeilidAPIResult<DHTCosignature> {
+        let mut inner = self.inner.lock().await;
+
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get(record_key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        if !inner.opened_records.contains_key(&record_key) {
+            apibail_generic!("record not open");
+        }
+
+        // Get the descriptor and schema for the key
+        let last_get_result =
+            Self::handle_get_local_value_inner(&mut inner, record_key, subkey, true).await?;
+        let Some(descriptor) = last_get_result.opt_descriptor else {
+            apibail_generic!("must have a descriptor");
+        };
+        let schema = descriptor.schema()?;
+
+        // Only owner keys of a multi-owner schema cosign
+        let DHTSchema::MULT(mult) = &schema else {
+            apibail_generic!("schema does not support cosigning");
+        };
+        if !mult.owners().contains(&signer.key) {
+            apibail_invalid_argument!("signer is not an owner", "signer", signer.key);
+        }
+
+        // The writer is not covered by cosignatures, so any owner may go on to write the value
+        let value_data = ValueData::new_with_seq(seq, data, signer.key)?;
+        SignedValueData::make_cosignature(&value_data, descriptor.owner(), subkey, &vcrypto, signer)
+    }
+
     /// Create, update or cancel an outbound watch to a DHT value
     #[instrument(level = "trace", target = "stor", skip_all)]
     pub async fn watch_values(
@@ -1261,6 +1425,11 @@ impl StorageManager {
         count: u32,
         value: Option<ValueData>,
     ) {
+        // Changes to topic records become topic messages
+        if self.handle_topic_value_change(record_key, &subkeys, count, value.as_ref()) {
+            return;
+        }
+
         let update_callback = self.update_callback();
         update_callback(VeilidUpdate::ValueChange(Box::new(VeilidValueChange {
             key: record_key,
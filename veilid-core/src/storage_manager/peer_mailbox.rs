@@ -0,0 +1,484 @@
+use super::*;
+
+impl_veilid_log_facility!("stor");
+
+/// Most peer-hosted mailboxes this node will keep for other nodes
+const MAX_HOSTED_MAILBOXES: usize = 64;
+/// Most undrained messages a hosted mailbox keeps
+const MAX_HOSTED_MAILBOX_MESSAGES: usize = 64;
+/// Most bytes of undrained messages a hosted mailbox keeps
+const MAX_HOSTED_MAILBOX_DATA_SIZE: usize = 262_144;
+/// Most bytes of messages returned for a single drain request
+const MAX_HOSTED_MAILBOX_DRAIN_SIZE: usize = MAX_MAILBOX_MESSAGE_LEN;
+/// How far the timestamp of a drain request may be from our clock
+const MAILBOX_DRAIN_SKEW_US: u64 = 5 * 60 * 1_000_000;
+/// How long a hosted mailbox that is not drained is kept before another one can take its place
+const HOSTED_MAILBOX_IDLE_US: u64 = 30 * 24 * 60 * 60 * 1_000_000;
+/// Most drain requests sent to a host in one pass when it has more messages than fit in an answer
+const MAX_PEER_MAILBOX_DRAINS_PER_PASS: usize = 8;
+
+/// A mailbox this node keeps for another node, which deposits are held in until the owner drains them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(super) struct HostedMailbox {
+    /// Id to give the next deposited message
+    next_id: u64,
+    /// Timestamp of the last drain request, which later requests must be newer than
+    last_drain_ts: Timestamp,
+    /// Undrained messages, oldest first
+    messages: VecDeque<MailboxMessage>,
+}
+
+impl HostedMailbox {
+    fn new() -> Self {
+        Self {
+            next_id: 1,
+            last_drain_ts: Timestamp::default(),
+            messages: VecDeque::new(),
+        }
+    }
+
+    fn data_size(&self) -> usize {
+        self.messages.iter().map(|m| m.message().len()).sum()
+    }
+
+    fn drop_expired(&mut self, cur_ts: Timestamp) {
+        self.messages.retain(|m| m.expiration() >= cur_ts);
+    }
+
+    /// Keep a deposited message, unless the mailbox is full
+    fn deposit(&mut self, cur_ts: Timestamp, expiration: Timestamp, message: Vec<u8>) -> bool {
+        self.drop_expired(cur_ts);
+        if expiration < cur_ts
+            || self.messages.len() >= MAX_HOSTED_MAILBOX_MESSAGES
+            || self.data_size() + message.len() > MAX_HOSTED_MAILBOX_DATA_SIZE
+        {
+            return false;
+        }
+        self.messages
+            .push_back(MailboxMessage::new(self.next_id, expiration, message));
+        self.next_id += 1;
+        true
+    }
+
+    /// Whether a drain request with this timestamp is newer than the last one and close enough to our clock
+    fn accepts_drain(&self, cur_ts: Timestamp, timestamp: Timestamp) -> bool {
+        timestamp > self.last_drain_ts
+            && timestamp.as_u64().abs_diff(cur_ts.as_u64()) <= MAILBOX_DRAIN_SKEW_US
+    }
+
+    /// Drop the acknowledged messages and return the oldest of the rest that fit in an answer
+    fn drain(
+        &mut self,
+        cur_ts: Timestamp,
+        timestamp: Timestamp,
+        acknowledge: u64,
+    ) -> Vec<MailboxMessage> {
+        self.last_drain_ts = timestamp;
+        self.drop_expired(cur_ts);
+        self.messages.retain(|m| m.id() > acknowledge);
+
+        let mut size = 0;
+        let mut out = Vec::new();
+        for m in &self.messages {
+            if out.len() == MAX_MAILBOX_DRAIN_A_MESSAGES_LEN
+                || (!out.is_empty() && size + m.message().len() > MAX_HOSTED_MAILBOX_DRAIN_SIZE)
+            {
+                break;
+            }
+            size += m.message().len();
+            out.push(m.clone());
+        }
+        out
+    }
+
+    fn is_idle(&self, cur_ts: Timestamp) -> bool {
+        cur_ts.as_u64().saturating_sub(self.last_drain_ts.as_u64()) > HOSTED_MAILBOX_IDLE_US
+    }
+}
+
+/// A mailbox another node keeps for us, which we drain for deposited messages
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(super) struct PeerMailboxState {
+    /// Node that keeps the mailbox
+    pub host: TypedNodeId,
+    /// Keypair of the mailbox, whose public key messages are sealed to and drain requests are signed with
+    pub owner: KeyPair,
+    /// Safety selection to use when draining the mailbox
+    pub safety_selection: SafetySelection,
+    /// Id of the last message delivered, which the host drops on the next drain
+    pub acknowledged: u64,
+}
+
+/// Turn the result of a mailbox request to a host into an API result
+fn peer_mailbox_result<T>(res: RPCNetworkResult<Answer<T>>) -> VeilidAPIResult<T> {
+    match res {
+        Ok(NetworkResult::Value(v)) => Ok(v.answer),
+        Ok(NetworkResult::Timeout) => apibail_timeout!(),
+        Ok(NetworkResult::ServiceUnavailable(e)) => apibail_invalid_target!(e),
+        Ok(NetworkResult::NoConnection(e)) | Ok(NetworkResult::AlreadyExists(e)) => {
+            apibail_no_connection!(e);
+        }
+        Ok(NetworkResult::InvalidMessage(message)) => {
+            apibail_generic!(message);
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+impl StorageManager {
+    /// Create a mailbox kept by a chosen peer, which other nodes can deposit messages in while we are away.
+    /// The host must offer DHT storage and be reachable now, as the mailbox is set up with a first drain.
+    /// The mailbox is drained in the background whenever we are online.
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn create_peer_mailbox(
+        &self,
+        host: TypedNodeId,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<PeerMailboxAddress> {
+        if !self.dht_is_online() {
+            apibail_try_again!("network is not available");
+        }
+
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get_async(host.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+        let owner = vcrypto.generate_keypair().await;
+        let mailbox = TypedPublicKey::new(host.kind, owner.key);
+
+        // The host starts keeping the mailbox when it is drained for the first time
+        let Some(messages) = self
+            .drain_peer_mailbox_once(host, owner, safety_selection, 0)
+            .await?
+        else {
+            apibail_generic!("host does not accept more mailboxes");
+        };
+        if !messages.is_empty() {
+            apibail_internal!("new mailbox has messages");
+        }
+
+        self.inner.lock().await.peer_mailboxes.insert(
+            mailbox,
+            PeerMailboxState {
+                host,
+                owner,
+                safety_selection,
+                acknowledged: 0,
+            },
+        );
+
+        Ok(PeerMailboxAddress::new(host, mailbox))
+    }
+
+    /// Stop draining a mailbox kept for us by a peer. The host drops it once it has not been drained for a while.
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn delete_peer_mailbox(&self, mailbox: TypedPublicKey) -> VeilidAPIResult<()> {
+        if self
+            .inner
+            .lock()
+            .await
+            .peer_mailboxes
+            .remove(&mailbox)
+            .is_none()
+        {
+            apibail_invalid_argument!("peer mailbox not found", "mailbox", mailbox);
+        }
+        Ok(())
+    }
+
+    /// Seal a message to the owner of a peer-hosted mailbox and send it to the host.
+    /// Messages expire after a week if no expiration is given.
+    /// The host must be reachable, so this fails with TryAgain when offline or when the mailbox is full.
+    #[instrument(level = "trace", target = "stor", skip(self, message), fields(message.len = message.len()))]
+    pub async fn deposit_peer_mailbox_message(
+        &self,
+        address: PeerMailboxAddress,
+        namespace: u32,
+        message: Vec<u8>,
+        expiration: Option<Timestamp>,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<()> {
+        if !self.dht_is_online() {
+            apibail_try_again!("network is not available");
+        }
+        let expiration = expiration.unwrap_or_else(|| {
+            Timestamp::now() + TimestampDuration::new(DEFAULT_MAILBOX_MESSAGE_EXPIRATION_US)
+        });
+        if expiration <= Timestamp::now() {
+            apibail_invalid_argument!("expiration is in the past", "expiration", expiration);
+        }
+        let mailbox = *address.mailbox();
+        if address.host().kind != mailbox.kind {
+            apibail_generic!("mailbox address host and mailbox have different cryptosystems");
+        }
+
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get_async(mailbox.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        // Seal the message to the recipient
+        let sealed = vcrypto
+            .seal(&mailbox.value, &encode_mailbox_body(namespace, &message))
+            .await?;
+        if sealed.len() > MAX_MAILBOX_MESSAGE_LEN {
+            apibail_invalid_argument!("message too long for mailbox", "message.len", message.len());
+        }
+
+        let rpc_processor = self.rpc_processor();
+        let dest = rpc_processor
+            .resolve_target_to_destination(Target::NodeId(*address.host()), safety_selection)
+            .await
+            .map_err(VeilidAPIError::invalid_target)?;
+        let accepted = peer_mailbox_result(
+            rpc_processor
+                .rpc_call_mailbox_deposit(dest, mailbox, expiration, sealed)
+                .await,
+        )?;
+        if !accepted {
+            apibail_try_again!("mailbox is full or not kept by its host");
+        }
+        Ok(())
+    }
+
+    /// Send one signed drain request to the host of a mailbox.
+    /// Returns None if the host does not keep the mailbox and can not start keeping it.
+    async fn drain_peer_mailbox_once(
+        &self,
+        host: TypedNodeId,
+        owner: KeyPair,
+        safety_selection: SafetySelection,
+        acknowledge: u64,
+    ) -> VeilidAPIResult<Option<Vec<MailboxMessage>>> {
+        let rpc_processor = self.rpc_processor();
+        let dest = rpc_processor
+            .resolve_target_to_destination(Target::NodeId(host), safety_selection)
+            .await
+            .map_err(VeilidAPIError::invalid_target)?;
+        peer_mailbox_result(
+            rpc_processor
+                .rpc_call_mailbox_drain(dest, host, owner, acknowledge)
+                .await,
+        )
+    }
+
+    /// Deliver the messages a host kept for us as app messages and acknowledge them on the next drain
+    #[instrument(level = "trace", target = "stor", skip(self, state))]
+    pub(super) async fn drain_peer_mailbox(
+        &self,
+        mailbox: TypedPublicKey,
+        state: PeerMailboxState,
+    ) -> VeilidAPIResult<usize> {
+        // Get cryptosystem
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get_async(mailbox.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let cur_ts = Timestamp::now();
+        let mut acknowledged = state.acknowledged;
+        let mut delivered = 0;
+        for _ in 0..MAX_PEER_MAILBOX_DRAINS_PER_PASS {
+            let Some(messages) = self
+                .drain_peer_mailbox_once(
+                    state.host,
+                    state.owner,
+                    state.safety_selection,
+                    acknowledged,
+                )
+                .await?
+            else {
+                apibail_generic!("host no longer keeps mailbox");
+            };
+            let Some(last) = messages.last() else {
+                break;
+            };
+            acknowledged = last.id();
+
+            for m in messages {
+                if m.expiration() < cur_ts {
+                    veilid_log!(self debug "Dropping expired mailbox message: {}:{}", mailbox, m.id());
+                    continue;
+                }
+                let opened = vcrypto
+                    .open(&state.owner.key, &state.owner.secret, m.message())
+                    .await;
+                let Some((namespace, message)) =
+                    opened.ok().as_deref().and_then(decode_mailbox_body)
+                else {
+                    veilid_log!(self debug "Dropping unreadable mailbox message: {}:{}", mailbox, m.id());
+                    continue;
+                };
+
+                // Mailbox messages are delivered like app messages received over a safety route
+                (self.update_callback())(VeilidUpdate::AppMessage(Box::new(
+                    VeilidAppMessage::new(None, None, namespace, message),
+                )));
+                delivered += 1;
+            }
+
+            // Remember what was delivered so it is acknowledged even if the next drain is in a later pass
+            if let Some(s) = self.inner.lock().await.peer_mailboxes.get_mut(&mailbox) {
+                s.acknowledged = acknowledged;
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    /// Keep a message deposited in a mailbox we host.
+    /// Returns false if we do not host the mailbox or it is full.
+    #[instrument(level = "trace", target = "stor", skip(self, message), fields(message.len = message.len()))]
+    pub async fn inbound_mailbox_deposit(
+        &self,
+        mailbox: TypedPublicKey,
+        expiration: Timestamp,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<NetworkResult<bool>> {
+        let mut inner = self.inner.lock().await;
+        let Some(hosted_mailbox) = inner.hosted_mailboxes.get_mut(&mailbox) else {
+            return Ok(NetworkResult::value(false));
+        };
+        Ok(NetworkResult::value(hosted_mailbox.deposit(
+            Timestamp::now(),
+            expiration,
+            message,
+        )))
+    }
+
+    /// Drop the acknowledged messages of a mailbox we host and return the rest.
+    /// The signature of the request has been checked already. A mailbox we do not host yet is started
+    /// if there is room for it. Returns None if there is not.
+    #[instrument(level = "trace", target = "stor", skip(self))]
+    pub async fn inbound_mailbox_drain(
+        &self,
+        mailbox: TypedPublicKey,
+        acknowledge: u64,
+        timestamp: Timestamp,
+    ) -> VeilidAPIResult<NetworkResult<Option<Vec<MailboxMessage>>>> {
+        let cur_ts = Timestamp::now();
+        let mut inner = self.inner.lock().await;
+
+        if let Some(hosted_mailbox) = inner.hosted_mailboxes.get_mut(&mailbox) {
+            // Drain requests can not be replayed
+            if !hosted_mailbox.accepts_drain(cur_ts, timestamp) {
+                return Ok(NetworkResult::invalid_message(
+                    "mailbox drain request is stale",
+                ));
+            }
+            return Ok(NetworkResult::value(Some(hosted_mailbox.drain(
+                cur_ts,
+                timestamp,
+                acknowledge,
+            ))));
+        }
+
+        let mut hosted_mailbox = HostedMailbox::new();
+        if !hosted_mailbox.accepts_drain(cur_ts, timestamp) {
+            return Ok(NetworkResult::invalid_message(
+                "mailbox drain request is stale",
+            ));
+        }
+
+        // Make room by dropping mailboxes their owners stopped draining
+        inner.hosted_mailboxes.retain(|_, m| !m.is_idle(cur_ts));
+        if inner.hosted_mailboxes.len() >= MAX_HOSTED_MAILBOXES {
+            return Ok(NetworkResult::value(None));
+        }
+        let messages = hosted_mailbox.drain(cur_ts, timestamp, acknowledge);
+        inner.hosted_mailboxes.insert(mailbox, hosted_mailbox);
+        Ok(NetworkResult::value(Some(messages)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND_US: u64 = 1_000_000;
+
+    fn ids(messages: &[MailboxMessage]) -> Vec<u64> {
+        messages.iter().map(|m| m.id()).collect()
+    }
+
+    #[test]
+    fn test_hosted_mailbox_drain() {
+        let cur_ts = Timestamp::new(100 * SECOND_US);
+        let expiration = Timestamp::new(200 * SECOND_US);
+        let mut mailbox = HostedMailbox::new();
+        assert!(mailbox.deposit(cur_ts, expiration, b"one".to_vec()));
+        assert!(mailbox.deposit(cur_ts, expiration, b"two".to_vec()));
+        assert!(mailbox.deposit(cur_ts, expiration, b"three".to_vec()));
+
+        // Nothing acknowledged yet returns everything, oldest first
+        let drained = mailbox.drain(cur_ts, cur_ts, 0);
+        assert_eq!(ids(&drained), vec![1, 2, 3]);
+        assert_eq!(drained[0].message(), b"one");
+
+        // Acknowledged messages are dropped and ids keep increasing
+        let drained = mailbox.drain(cur_ts, cur_ts, 2);
+        assert_eq!(ids(&drained), vec![3]);
+        assert!(mailbox.deposit(cur_ts, expiration, b"four".to_vec()));
+        let drained = mailbox.drain(cur_ts, cur_ts, 3);
+        assert_eq!(ids(&drained), vec![4]);
+
+        // Expired messages are dropped without being drained
+        let later_ts = Timestamp::new(300 * SECOND_US);
+        assert!(mailbox.drain(later_ts, later_ts, 0).is_empty());
+        assert!(!mailbox.deposit(later_ts, expiration, b"late".to_vec()));
+    }
+
+    #[test]
+    fn test_hosted_mailbox_limits() {
+        let cur_ts = Timestamp::new(100 * SECOND_US);
+        let expiration = Timestamp::new(200 * SECOND_US);
+        let mut mailbox = HostedMailbox::new();
+        for _ in 0..MAX_HOSTED_MAILBOX_MESSAGES {
+            assert!(mailbox.deposit(cur_ts, expiration, vec![0u8; 16]));
+        }
+        assert!(!mailbox.deposit(cur_ts, expiration, vec![0u8; 16]));
+
+        // A drain answer holds a limited number of messages
+        let drained = mailbox.drain(cur_ts, cur_ts, 0);
+        assert_eq!(drained.len(), MAX_MAILBOX_DRAIN_A_MESSAGES_LEN);
+
+        // And a limited number of bytes, but always at least one message
+        let mut mailbox = HostedMailbox::new();
+        for _ in 0..4 {
+            assert!(mailbox.deposit(cur_ts, expiration, vec![0u8; MAX_MAILBOX_MESSAGE_LEN]));
+        }
+        assert_eq!(ids(&mailbox.drain(cur_ts, cur_ts, 0)), vec![1]);
+        assert_eq!(ids(&mailbox.drain(cur_ts, cur_ts, 1)), vec![2]);
+
+        // The bytes kept by a mailbox are limited too
+        let count = MAX_HOSTED_MAILBOX_DATA_SIZE / MAX_MAILBOX_MESSAGE_LEN;
+        let mut mailbox = HostedMailbox::new();
+        for _ in 0..count {
+            assert!(mailbox.deposit(cur_ts, expiration, vec![0u8; MAX_MAILBOX_MESSAGE_LEN]));
+        }
+        assert!(!mailbox.deposit(cur_ts, expiration, vec![0u8; 1]));
+    }
+
+    #[test]
+    fn test_hosted_mailbox_replay() {
+        let cur_ts = Timestamp::new(1000 * SECOND_US);
+        let mut mailbox = HostedMailbox::new();
+        assert!(mailbox.accepts_drain(cur_ts, cur_ts));
+        mailbox.drain(cur_ts, cur_ts, 0);
+
+        // The same or an older request is refused, a newer one is not
+        assert!(!mailbox.accepts_drain(cur_ts, cur_ts));
+        assert!(mailbox.accepts_drain(cur_ts, Timestamp::new(cur_ts.as_u64() + 1)));
+
+        // Requests too far from our clock are refused
+        let skewed = Timestamp::new(cur_ts.as_u64() + MAILBOX_DRAIN_SKEW_US + 1);
+        assert!(!mailbox.accepts_drain(cur_ts, skewed));
+
+        // Mailboxes that are not drained for a long time become idle
+        assert!(!mailbox.is_idle(cur_ts));
+        let idle_ts = Timestamp::new(cur_ts.as_u64() + HOSTED_MAILBOX_IDLE_US + 1);
+        assert!(mailbox.is_idle(idle_ts));
+    }
+}
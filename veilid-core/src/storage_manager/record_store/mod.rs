@@ -1341,6 +1341,21 @@ where
         reclaimed
     }
 
+    /// Take new watch limits, existing watches are kept until they expire
+    pub fn set_watch_limits(&mut self, new_limits: &RecordStoreLimits) {
+        self.limits.public_watch_limit = new_limits.public_watch_limit;
+        self.limits.member_watch_limit = new_limits.member_watch_limit;
+        self.limits.max_watch_expiration = new_limits.max_watch_expiration;
+    }
+
+    pub fn record_count(&self) -> usize {
+        self.record_index.len()
+    }
+
+    pub fn total_storage_space(&self) -> u64 {
+        self.total_storage_space.get()
+    }
+
     pub fn debug_records(&self) -> String {
         // Dump fields in an abbreviated way
         let mut out = String::new();
@@ -33,6 +33,7 @@ impl StorageManager {
         safety_selection: SafetySelection,
         value: Arc<SignedValueData>,
         descriptor: Arc<SignedValueDescriptor>,
+        options: &CallOptions,
     ) -> VeilidAPIResult<flume::Receiver<VeilidAPIResult<OutboundSetValueResult>>> {
         let routing_domain = RoutingDomain::PublicInternet;
 
@@ -46,6 +47,10 @@ impl StorageManager {
             )
         });
 
+        // Don't run the fanout past the deadline of the call
+        let timeout_us = options.limit_timeout(timeout_us);
+        let stop_token = options.stop_token();
+
         // Get the nodes we know are caching this value to seed the fanout
         let init_fanout_queue = {
             self.get_value_nodes(record_key)
@@ -146,6 +151,7 @@ impl StorageManager {
                             descriptor.owner(),
                             subkey,
                             value.value_data(),
+                            &value.cosigners(),
                         ) {
                             // Validation failed, ignore this value and pretend we never saw this node
                             return Ok(FanoutCallOutput{peer_info_list: vec![], disposition: FanoutCallDisposition::Invalid});
@@ -236,7 +242,8 @@ impl StorageManager {
                         capability_fanout_node_info_filter(vec![CAP_DHT]),
                         call_routine,
                         check_done,
-                    );
+                    )
+                    .with_stop_token(stop_token);
 
                     let fanout_result = match fanout_call.run(init_fanout_queue).await {
                         Ok(v) => v,
@@ -474,11 +481,27 @@ impl StorageManager {
         };
 
         // Validate new value with schema
-        if !schema.check_subkey_value_data(actual_descriptor.owner(), subkey, value.value_data()) {
+        if !schema.check_subkey_value_data(
+            actual_descriptor.owner(),
+            subkey,
+            value.value_data(),
+            &value.cosigners(),
+        ) {
             // Validation failed, ignore this value
             return Ok(NetworkResult::invalid_message("failed schema validation"));
         }
 
+        // Cosignatures count towards the schema threshold, so they must be genuine
+        if !value.cosignatures().is_empty() {
+            let crypto = self.crypto();
+            let Some(vcrypto) = crypto.get(key.kind) else {
+                return Ok(NetworkResult::invalid_message("unsupported cryptosystem"));
+            };
+            if !value.validate_cosignatures(actual_descriptor.owner(), subkey, &vcrypto)? {
+                return Ok(NetworkResult::invalid_message("invalid cosignature"));
+            }
+        }
+
         // Do the set and return no new value
         let res = if is_local {
             Self::handle_set_local_value_inner(
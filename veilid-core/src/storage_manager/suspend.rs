@@ -0,0 +1,70 @@
+use super::*;
+
+impl_veilid_log_facility!("stor");
+
+/// Number of watch nodes to ping at once while resuming
+const RESUME_MAX_PARALLEL_WATCH_PINGS: usize = 8;
+
+impl StorageManager {
+    /// Write record stores and metadata, including outbound watch state, to disk ahead of a suspend
+    #[instrument(level = "debug", target = "stor", skip_all)]
+    pub async fn save_suspend_state(&self) {
+        let mut inner = self.inner.lock().await;
+        if let Some(local_record_store) = &mut inner.local_record_store {
+            if let Err(e) = local_record_store.flush().await {
+                veilid_log!(self error "suspend local record store flush failed: {}", e);
+            }
+        }
+        if let Some(remote_record_store) = &mut inner.remote_record_store {
+            if let Err(e) = remote_record_store.flush().await {
+                veilid_log!(self error "suspend remote record store flush failed: {}", e);
+            }
+        }
+        if let Err(e) = self.save_metadata_inner(&mut inner).await {
+            veilid_log!(self error "suspend metadata save failed: {}", e);
+        }
+    }
+
+    /// Ping the nodes holding our outbound watches after a resume so dead ones are noticed
+    /// right away, and reconcile every watch on the next check instead of waiting out its backoff
+    #[instrument(level = "debug", target = "stor", skip_all)]
+    pub async fn revalidate_outbound_watches(&self, stop_token: StopToken) {
+        let watch_destinations = self.get_outbound_watch_nodes().await;
+
+        let registry = self.registry();
+        let future_queue = watch_destinations.into_iter().map(|watch_destination| {
+            let registry = registry.clone();
+            async move {
+                veilid_log!(registry debug target: "watch", "--> Resume watch ping to {:?}", watch_destination);
+                let rpc_processor = registry.rpc_processor();
+                rpc_processor.rpc_call_status(watch_destination).await
+            }
+        });
+        let _ = process_batched_future_queue_result(
+            future_queue,
+            RESUME_MAX_PARALLEL_WATCH_PINGS,
+            stop_token,
+            |res| {
+                if let Err(e) = res {
+                    veilid_log!(self debug target: "watch", "Error performing resume watch ping: {}", e);
+                }
+                Result::<(), ()>::Ok(())
+            },
+        )
+        .await;
+
+        let mut inner = self.inner.lock().await;
+        let cur_ts = Timestamp::now();
+        let record_keys = inner
+            .outbound_watch_manager
+            .outbound_watches
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        for record_key in record_keys {
+            inner
+                .outbound_watch_manager
+                .set_next_reconcile_ts(record_key, cur_ts);
+        }
+    }
+}
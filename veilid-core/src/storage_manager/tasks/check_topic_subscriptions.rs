@@ -0,0 +1,56 @@
+use super::*;
+
+impl_veilid_log_facility!("stor");
+
+impl StorageManager {
+    /// Renew dead topic watches and deliver topic messages that value changes did not include
+    #[instrument(level = "trace", target = "stor", skip_all, err)]
+    pub(super) async fn check_topic_subscriptions_task_routine(
+        &self,
+        _stop_token: StopToken,
+        _last_ts: Timestamp,
+        _cur_ts: Timestamp,
+    ) -> EyreResult<()> {
+        let (unwatched, pending) = {
+            let topic_subscriptions = self.topic_subscriptions.lock();
+            let unwatched = topic_subscriptions
+                .iter()
+                .filter(|(_, s)| !s.watching)
+                .filter_map(|(k, s)| Some((*k, topic_slots(&s.schema, None).ok()?)))
+                .collect::<Vec<_>>();
+            let pending = topic_subscriptions
+                .iter()
+                .filter(|(_, s)| !s.pending_subkeys.is_empty())
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>();
+            (unwatched, pending)
+        };
+
+        for (record_key, slots) in unwatched {
+            // Catch up on whatever was published while the watch was down
+            match self
+                .watch_values(record_key, slots.clone(), Timestamp::new(0), u32::MAX)
+                .await
+            {
+                Ok(watching) => {
+                    if let Some(subscription) = self.topic_subscriptions.lock().get_mut(&record_key)
+                    {
+                        subscription.watching = watching;
+                        subscription.pending_subkeys = slots;
+                    }
+                }
+                Err(e) => {
+                    veilid_log!(self debug "Renewing topic watch failed: {}: {}", record_key, e);
+                }
+            }
+        }
+
+        for record_key in pending {
+            if let Err(e) = self.catch_up_topic(record_key).await {
+                veilid_log!(self debug "Topic catch up failed: {}: {}", record_key, e);
+            }
+        }
+
+        Ok(())
+    }
+}
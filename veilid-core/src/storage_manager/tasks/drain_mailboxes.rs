@@ -0,0 +1,53 @@
+use super::*;
+
+impl_veilid_log_facility!("stor");
+
+impl StorageManager {
+    /// Deliver messages deposited in our mailboxes
+    #[instrument(level = "trace", target = "stor", skip_all, err)]
+    pub(super) async fn drain_mailboxes_task_routine(
+        &self,
+        stop_token: StopToken,
+        _last_ts: Timestamp,
+        _cur_ts: Timestamp,
+    ) -> EyreResult<()> {
+        let (mailboxes, peer_mailboxes) = {
+            let inner = self.inner.lock().await;
+            (inner.mailboxes.clone(), inner.peer_mailboxes.clone())
+        };
+
+        let mut futs = Vec::new();
+        for (record_key, state) in mailboxes {
+            futs.push(pin_dyn_future!(async move {
+                match self.drain_mailbox(record_key, state).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        veilid_log!(self debug "Drained {} messages from mailbox {}", count, record_key);
+                    }
+                    Err(e) => {
+                        // Undrained messages stay in the mailbox until the next pass
+                        veilid_log!(self debug "Draining mailbox {} failed: {}", record_key, e);
+                    }
+                }
+            }));
+        }
+        for (mailbox, state) in peer_mailboxes {
+            futs.push(pin_dyn_future!(async move {
+                match self.drain_peer_mailbox(mailbox, state).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        veilid_log!(self debug "Drained {} messages from peer mailbox {}", count, mailbox);
+                    }
+                    Err(e) => {
+                        // Undrained messages stay with the host until the next pass
+                        veilid_log!(self debug "Draining peer mailbox {} failed: {}", mailbox, e);
+                    }
+                }
+            }));
+        }
+
+        process_batched_future_queue_void(futs, DRAIN_MAILBOXES_BATCH_SIZE, stop_token).await;
+
+        Ok(())
+    }
+}
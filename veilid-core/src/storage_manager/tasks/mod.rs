@@ -1,5 +1,7 @@
 pub mod check_inbound_watches;
 pub mod check_outbound_watches;
+pub mod check_topic_subscriptions;
+pub mod drain_mailboxes;
 pub mod flush_record_stores;
 pub mod offline_subkey_writes;
 pub mod rehydrate_records;
@@ -65,12 +67,39 @@ impl StorageManager {
             rehydrate_records_task,
             rehydrate_records_task_routine
         );
+
+        // Set drain mailboxes tick task
+        veilid_log!(self debug "starting drain mailboxes task");
+        impl_setup_task!(
+            self,
+            Self,
+            drain_mailboxes_task,
+            drain_mailboxes_task_routine
+        );
+
+        // Set check topic subscriptions tick task
+        veilid_log!(self debug "starting check topic subscriptions task");
+        impl_setup_task!(
+            self,
+            Self,
+            check_topic_subscriptions_task,
+            check_topic_subscriptions_task_routine
+        );
     }
 
     #[instrument(parent = None, level = "trace", target = "stor", name = "StorageManager::tick", skip_all, err)]
     pub async fn tick(&self) -> EyreResult<()> {
+        // In low power mode, flush less often and hold off on rehydration
+        let attachment_manager = self.attachment_manager();
+        let low_power = attachment_manager.power_profile().is_low_power();
+
         // Run the flush stores task
-        self.flush_record_stores_task.tick().await?;
+        if attachment_manager.power_profile_allows_tick(
+            &self.flush_record_stores_task,
+            LOW_POWER_FLUSH_RECORD_STORES_INTERVAL_SECS,
+        ) {
+            self.flush_record_stores_task.tick().await?;
+        }
 
         // Run the flush stores task
         self.save_metadata_task.tick().await?;
@@ -89,10 +118,20 @@ impl StorageManager {
             }
 
             // Do requested rehydrations
-            if self.has_rehydration_requests().await {
+            if !low_power && self.has_rehydration_requests().await {
                 self.rehydrate_records_task.tick().await?;
             }
 
+            // Collect messages deposited in our mailboxes
+            if self.has_mailboxes().await {
+                self.drain_mailboxes_task.tick().await?;
+            }
+
+            // Keep topic subscriptions watching and caught up
+            if self.has_topic_subscriptions() {
+                self.check_topic_subscriptions_task.tick().await?;
+            }
+
             // Send value changed notifications
             self.send_value_changes_task.tick().await?;
         }
@@ -125,5 +164,13 @@ impl StorageManager {
         if let Err(e) = self.rehydrate_records_task.stop().await {
             veilid_log!(self warn "rehydrate_records_task not stopped: {}", e);
         }
+        veilid_log!(self debug "stopping drain mailboxes task");
+        if let Err(e) = self.drain_mailboxes_task.stop().await {
+            veilid_log!(self warn "drain_mailboxes_task not stopped: {}", e);
+        }
+        veilid_log!(self debug "stopping check topic subscriptions task");
+        if let Err(e) = self.check_topic_subscriptions_task.stop().await {
+            veilid_log!(self warn "check_topic_subscriptions_task not stopped: {}", e);
+        }
     }
 }
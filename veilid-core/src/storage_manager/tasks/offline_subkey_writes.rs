@@ -67,7 +67,14 @@ impl StorageManager {
         };
         veilid_log!(self debug "Offline subkey write: {}:{} len={}", key, subkey, value.value_data().data().len());
         let osvres = self
-            .outbound_set_value(key, subkey, safety_selection, value.clone(), descriptor)
+            .outbound_set_value(
+                key,
+                subkey,
+                safety_selection,
+                value.clone(),
+                descriptor,
+                &CallOptions::default(),
+            )
             .await;
         match osvres {
             Ok(res_rx) => {
@@ -0,0 +1,579 @@
+use super::*;
+
+impl_veilid_log_facility!("stor");
+
+/// Largest number of publishers a topic can have
+pub(crate) const MAX_TOPIC_PUBLISHERS: usize = 64;
+/// Number of subkeys that each publisher of a topic rotates through
+const TOPIC_SLOTS_PER_PUBLISHER: u16 = 4;
+/// Longest topic name
+const MAX_TOPIC_NAME_LEN: usize = 256;
+/// Owner subkey of a topic record holding the topic name
+const TOPIC_NAME_SUBKEY: ValueSubkey = 0;
+/// Length of the publication timestamp in front of each topic message
+const TOPIC_TIMESTAMP_LEN: usize = 8;
+/// How many slots to try before giving up when the publisher writes from elsewhere at the same time
+const TOPIC_PUBLISH_ATTEMPTS: usize = 3;
+
+/// A topic this node is subscribed to
+#[derive(Debug, Clone)]
+pub(super) struct TopicSubscription {
+    /// The name of the topic
+    pub topic: String,
+    /// The schema of the topic record, which says which publisher owns each slot
+    pub schema: DHTSchema,
+    /// If the application had the topic record open already, so unsubscribing leaves it open
+    pub was_open: bool,
+    /// Sequence number of the last message delivered from each slot
+    pub delivered_seqs: BTreeMap<ValueSubkey, ValueSeqNum>,
+    /// Slots that may hold messages that have not been delivered yet
+    pub pending_subkeys: ValueSubkeyRangeSet,
+    /// If the watch on the topic record is believed to be alive
+    pub watching: bool,
+}
+
+fn topic_schema(publishers: &[PublicKey]) -> VeilidAPIResult<DHTSchema> {
+    Ok(DHTSchema::SMPL(DHTSchemaSMPL::new(
+        1,
+        publishers
+            .iter()
+            .map(|p| DHTSchemaSMPLMember {
+                m_key: *p,
+                m_cnt: TOPIC_SLOTS_PER_PUBLISHER,
+            })
+            .collect(),
+    )?))
+}
+
+/// Get the message slots of a topic record schema, or fail if the record is not a topic.
+/// Topics have one owner subkey for the topic name followed by the slots of each publisher member.
+/// With a publisher, only the slots of that publisher's member are returned.
+pub(super) fn topic_slots(
+    schema: &DHTSchema,
+    publisher: Option<&PublicKey>,
+) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+    let DHTSchema::SMPL(smpl) = schema else {
+        apibail_generic!("record is not a topic");
+    };
+    if smpl.o_cnt() != 1 || smpl.members().is_empty() || smpl.members().iter().any(|m| m.m_cnt == 0)
+    {
+        apibail_generic!("record is not a topic");
+    }
+
+    let mut first = TOPIC_NAME_SUBKEY + 1;
+    for member in smpl.members() {
+        let last = first + member.m_cnt as ValueSubkey - 1;
+        if publisher.is_some_and(|p| p == &member.m_key) {
+            return Ok(ValueSubkeyRangeSet::single_range(first, last));
+        }
+        first = last + 1;
+    }
+    if publisher.is_some() {
+        apibail_generic!("not a publisher of this topic");
+    }
+    Ok(ValueSubkeyRangeSet::single_range(
+        TOPIC_NAME_SUBKEY + 1,
+        first - 1,
+    ))
+}
+
+/// Get the publisher whose member owns a slot of a topic record
+fn topic_publisher(schema: &DHTSchema, slot: ValueSubkey) -> Option<PublicKey> {
+    let DHTSchema::SMPL(smpl) = schema else {
+        return None;
+    };
+    let mut first = TOPIC_NAME_SUBKEY + 1;
+    for member in smpl.members() {
+        let last = first + member.m_cnt as ValueSubkey - 1;
+        if (first..=last).contains(&slot) {
+            return Some(member.m_key);
+        }
+        first = last + 1;
+    }
+    None
+}
+
+/// Split a topic slot into its publication timestamp and message
+fn decode_topic_message(data: &[u8]) -> Option<(Timestamp, Vec<u8>)> {
+    let published = data.get(0..TOPIC_TIMESTAMP_LEN)?;
+    Some((
+        Timestamp::new(u64::from_le_bytes(published.try_into().unwrap())),
+        data[TOPIC_TIMESTAMP_LEN..].to_vec(),
+    ))
+}
+
+fn encode_topic_message(published: Timestamp, message: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(TOPIC_TIMESTAMP_LEN + message.len());
+    data.extend_from_slice(&published.as_u64().to_le_bytes());
+    data.extend_from_slice(message);
+    data
+}
+
+/// Order the slots of a publisher so the least written ones come first,
+/// picking randomly between equals so a publisher writing from several places is unlikely to collide
+fn order_topic_slots(
+    slots: &ValueSubkeyRangeSet,
+    seqs: &[Option<ValueSeqNum>],
+) -> Vec<ValueSubkey> {
+    let mut slots = slots
+        .iter()
+        .zip(seqs.iter())
+        .map(|(slot, seq)| (seq.map(|s| s as i64).unwrap_or(-1), get_random_u32(), slot))
+        .collect::<Vec<_>>();
+    slots.sort();
+    slots.into_iter().map(|(_, _, slot)| slot).collect()
+}
+
+/// Build the topic message update for a slot value, if it holds a message
+fn topic_message_update(
+    record_key: TypedRecordKey,
+    subscription: &TopicSubscription,
+    slot: ValueSubkey,
+    value: &ValueData,
+) -> Option<VeilidTopicMessage> {
+    let publisher = topic_publisher(&subscription.schema, slot)?;
+    let (published, message) = decode_topic_message(value.data())?;
+    Some(VeilidTopicMessage {
+        key: record_key,
+        topic: subscription.topic.clone(),
+        publisher,
+        published,
+        message,
+    })
+}
+
+impl StorageManager {
+    pub(super) fn has_topic_subscriptions(&self) -> bool {
+        !self.topic_subscriptions.lock().is_empty()
+    }
+
+    /// Create a topic record that only the given publishers can publish to.
+    /// Each publisher gets its own member of the record schema and signs what it publishes with its own keypair.
+    #[instrument(level = "trace", target = "stor", skip(self))]
+    pub async fn create_topic(
+        &self,
+        kind: CryptoKind,
+        topic: String,
+        publishers: Vec<PublicKey>,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<TypedRecordKey> {
+        if topic.len() > MAX_TOPIC_NAME_LEN {
+            apibail_invalid_argument!("topic name too long", "topic.len", topic.len());
+        }
+        if publishers.is_empty() || publishers.len() > MAX_TOPIC_PUBLISHERS {
+            apibail_invalid_argument!(
+                "invalid topic publisher count",
+                "publishers.len",
+                publishers.len()
+            );
+        }
+        if publishers.iter().collect::<HashSet<_>>().len() != publishers.len() {
+            apibail_invalid_argument!("duplicate topic publisher", "publishers", publishers.len());
+        }
+        let schema = topic_schema(&publishers)?;
+
+        let descriptor = self
+            .create_record(kind, schema, None, safety_selection)
+            .await?;
+        let record_key = *descriptor.key();
+
+        // The owner only writes the topic name, so subscribers can label the messages
+        let res = self
+            .set_value(
+                record_key,
+                TOPIC_NAME_SUBKEY,
+                topic.into_bytes(),
+                None,
+                vec![],
+                CallOptions::default(),
+            )
+            .await;
+        self.close_record(record_key).await?;
+        res?;
+
+        Ok(record_key)
+    }
+
+    /// Open a topic record unless the application already has it open, in which case its writer and
+    /// safety selection are left alone. Returns whether the record was already open, so it is only closed if it was not.
+    async fn open_topic_record(
+        &self,
+        record_key: TypedRecordKey,
+        writer: Option<KeyPair>,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<(DHTRecordDescriptor, bool)> {
+        let opened = self
+            .inner
+            .lock()
+            .await
+            .opened_records
+            .get(&record_key)
+            .map(|o| (o.writer().cloned(), o.safety_selection()));
+        match opened {
+            Some((opened_writer, opened_safety_selection)) => Ok((
+                self.open_record(record_key, opened_writer, opened_safety_selection)
+                    .await?,
+                true,
+            )),
+            None => Ok((
+                self.open_record(record_key, writer, safety_selection)
+                    .await?,
+                false,
+            )),
+        }
+    }
+
+    /// Publish a message to a topic by writing it to the least recently used slot of the publisher
+    #[instrument(level = "trace", target = "stor", skip(self, message), fields(message.len = message.len()))]
+    pub async fn publish_topic(
+        &self,
+        record_key: TypedRecordKey,
+        publisher: KeyPair,
+        message: Vec<u8>,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<()> {
+        if !self.dht_is_online() {
+            apibail_try_again!("offline, try again later");
+        }
+
+        let (descriptor, was_open) = self
+            .open_topic_record(record_key, Some(publisher), safety_selection)
+            .await?;
+
+        let res = self
+            .publish_topic_inner(&descriptor, publisher, message)
+            .await;
+
+        if !was_open {
+            self.close_record(record_key).await?;
+        }
+        res
+    }
+
+    async fn publish_topic_inner(
+        &self,
+        descriptor: &DHTRecordDescriptor,
+        publisher: KeyPair,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<()> {
+        let record_key = *descriptor.key();
+        let slots = topic_slots(descriptor.schema(), Some(&publisher.key))?;
+        let total_slots = topic_slots(descriptor.schema(), None)?.len();
+
+        let max_message_len = usize::min(
+            MAX_SUBKEY_SIZE,
+            MAX_RECORD_DATA_SIZE / (total_slots as usize + 1),
+        ) - TOPIC_TIMESTAMP_LEN;
+        if message.len() > max_message_len {
+            apibail_invalid_argument!("message too long for topic", "message.len", message.len());
+        }
+        let data = encode_topic_message(Timestamp::now(), &message);
+
+        let report = self
+            .inspect_record(record_key, slots, DHTReportScope::SyncSet)
+            .await?;
+        let slots = order_topic_slots(report.subkeys(), report.network_seqs());
+
+        for slot in slots.into_iter().take(TOPIC_PUBLISH_ATTEMPTS) {
+            let Some(newer) = self
+                .set_value(
+                    record_key,
+                    slot,
+                    data.clone(),
+                    Some(publisher),
+                    vec![],
+                    CallOptions::default(),
+                )
+                .await?
+            else {
+                return Ok(());
+            };
+            if newer.data() == data.as_slice() {
+                return Ok(());
+            }
+            // The publisher wrote the slot from elsewhere first
+            veilid_log!(self debug "Topic slot taken: {}:{} seq={}", record_key, slot, newer.seq());
+        }
+
+        apibail_try_again!("topic is busy");
+    }
+
+    /// Subscribe to a topic, delivering its messages as `VeilidUpdate::TopicMessage`.
+    /// With 'catch_up' the messages still held by the topic are delivered first.
+    #[instrument(level = "trace", target = "stor", skip(self))]
+    pub async fn subscribe_topic(
+        &self,
+        record_key: TypedRecordKey,
+        catch_up: bool,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<()> {
+        if self.topic_subscriptions.lock().contains_key(&record_key) {
+            return Ok(());
+        }
+
+        let (descriptor, was_open) = self
+            .open_topic_record(record_key, None, safety_selection)
+            .await?;
+
+        let res = self
+            .subscribe_topic_inner(&descriptor, was_open, catch_up)
+            .await;
+
+        if res.is_err() && !was_open {
+            self.close_record(record_key).await?;
+        }
+        res
+    }
+
+    async fn subscribe_topic_inner(
+        &self,
+        descriptor: &DHTRecordDescriptor,
+        was_open: bool,
+        catch_up: bool,
+    ) -> VeilidAPIResult<()> {
+        let record_key = *descriptor.key();
+        let schema = descriptor.schema().clone();
+        let slots = topic_slots(&schema, None)?;
+
+        let online = self.dht_is_online();
+        let Some(topic) = self
+            .get_value(
+                record_key,
+                TOPIC_NAME_SUBKEY,
+                online,
+                CallOptions::default(),
+            )
+            .await?
+        else {
+            apibail_try_again!("topic name not found");
+        };
+        let topic = String::from_utf8_lossy(topic.data()).into_owned();
+
+        // Skip what has already been published unless we are catching up
+        let mut delivered_seqs = BTreeMap::new();
+        let pending_subkeys = if catch_up {
+            slots.clone()
+        } else {
+            let scope = if online {
+                DHTReportScope::SyncGet
+            } else {
+                DHTReportScope::Local
+            };
+            let report = self
+                .inspect_record(record_key, slots.clone(), scope)
+                .await?;
+            for (slot, seq) in report.subkeys().iter().zip(report.network_seqs().iter()) {
+                if let Some(seq) = seq {
+                    delivered_seqs.insert(slot, *seq);
+                }
+            }
+            ValueSubkeyRangeSet::new()
+        };
+
+        self.topic_subscriptions.lock().insert(
+            record_key,
+            TopicSubscription {
+                topic,
+                schema,
+                was_open,
+                delivered_seqs,
+                pending_subkeys,
+                watching: false,
+            },
+        );
+
+        // Watch indefinitely, the outbound watch manager renews the watch as it expires
+        let watching = match self
+            .watch_values(record_key, slots, Timestamp::new(0), u32::MAX)
+            .await
+        {
+            Ok(v) => v,
+            Err(e) => {
+                self.topic_subscriptions.lock().remove(&record_key);
+                return Err(e);
+            }
+        };
+        if let Some(subscription) = self.topic_subscriptions.lock().get_mut(&record_key) {
+            subscription.watching = watching;
+        }
+
+        Ok(())
+    }
+
+    /// Stop delivering the messages of a topic.
+    /// The topic record is closed unless the application had it open before subscribing.
+    #[instrument(level = "trace", target = "stor", skip(self))]
+    pub async fn unsubscribe_topic(&self, record_key: TypedRecordKey) -> VeilidAPIResult<()> {
+        let Some(subscription) = self.topic_subscriptions.lock().remove(&record_key) else {
+            apibail_key_not_found!(record_key);
+        };
+
+        if subscription.was_open {
+            let slots = topic_slots(&subscription.schema, None)?;
+            self.cancel_watch_values(record_key, slots).await?;
+            return Ok(());
+        }
+
+        // Closing the record cancels the watch
+        self.close_record(record_key).await
+    }
+
+    /// Turn a value change on a subscribed topic record into topic messages.
+    /// Returns false if the record is not a topic we are subscribed to.
+    pub(super) fn handle_topic_value_change(
+        &self,
+        record_key: TypedRecordKey,
+        subkeys: &ValueSubkeyRangeSet,
+        count: u32,
+        value: Option<&ValueData>,
+    ) -> bool {
+        let update = {
+            let mut topic_subscriptions = self.topic_subscriptions.lock();
+            let Some(subscription) = topic_subscriptions.get_mut(&record_key) else {
+                return false;
+            };
+
+            // A dead watch is set up again by the topic subscription task
+            if count == 0 && subkeys.is_empty() {
+                subscription.watching = false;
+                return true;
+            }
+
+            let mut pending_subkeys = subkeys.clone();
+            let mut update = None;
+            if let (Some(first_subkey), Some(value)) = (subkeys.first(), value) {
+                pending_subkeys.remove(first_subkey);
+                if subscription
+                    .delivered_seqs
+                    .get(&first_subkey)
+                    .is_none_or(|s| *s < value.seq())
+                {
+                    subscription
+                        .delivered_seqs
+                        .insert(first_subkey, value.seq());
+                    update = topic_message_update(record_key, subscription, first_subkey, value);
+                }
+            }
+
+            // Only the first changed value comes with the change, the rest are fetched in the background
+            subscription.pending_subkeys = subscription.pending_subkeys.union(&pending_subkeys);
+            update
+        };
+
+        if let Some(update) = update {
+            (self.update_callback())(VeilidUpdate::TopicMessage(Box::new(update)));
+        }
+        true
+    }
+
+    /// Fetch the slots of a topic that may hold undelivered messages and deliver them in publication order
+    #[instrument(level = "trace", target = "stor", skip(self))]
+    pub(super) async fn catch_up_topic(&self, record_key: TypedRecordKey) -> VeilidAPIResult<()> {
+        let pending_subkeys = {
+            let mut topic_subscriptions = self.topic_subscriptions.lock();
+            let Some(subscription) = topic_subscriptions.get_mut(&record_key) else {
+                return Ok(());
+            };
+            core::mem::take(&mut subscription.pending_subkeys)
+        };
+
+        let mut messages = Vec::new();
+        let mut failed_subkeys = ValueSubkeyRangeSet::new();
+        for subkey in pending_subkeys.iter() {
+            match self
+                .get_value(record_key, subkey, true, CallOptions::default())
+                .await
+            {
+                Ok(Some(value)) => messages.push((subkey, value)),
+                Ok(None) => {}
+                Err(e) => {
+                    veilid_log!(self debug "Topic catch up failed: {}:{}: {}", record_key, subkey, e);
+                    failed_subkeys.insert(subkey);
+                }
+            }
+        }
+
+        let updates = {
+            let mut topic_subscriptions = self.topic_subscriptions.lock();
+            let Some(subscription) = topic_subscriptions.get_mut(&record_key) else {
+                return Ok(());
+            };
+            subscription.pending_subkeys = subscription.pending_subkeys.union(&failed_subkeys);
+
+            let mut updates = Vec::new();
+            for (subkey, value) in messages {
+                if subscription
+                    .delivered_seqs
+                    .get(&subkey)
+                    .is_some_and(|s| *s >= value.seq())
+                {
+                    continue;
+                }
+                subscription.delivered_seqs.insert(subkey, value.seq());
+                updates.extend(topic_message_update(
+                    record_key,
+                    subscription,
+                    subkey,
+                    &value,
+                ));
+            }
+            updates.sort_by_key(|u| u.published);
+            updates
+        };
+
+        let update_callback = self.update_callback();
+        for update in updates {
+            update_callback(VeilidUpdate::TopicMessage(Box::new(update)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_slots() {
+        let a = PublicKey::new([1u8; PUBLIC_KEY_LENGTH]);
+        let b = PublicKey::new([2u8; PUBLIC_KEY_LENGTH]);
+        let c = PublicKey::new([3u8; PUBLIC_KEY_LENGTH]);
+        let schema = topic_schema(&[a, b]).unwrap();
+
+        // Each publisher has its own slots after the topic name
+        assert_eq!(
+            topic_slots(&schema, Some(&a)).unwrap(),
+            ValueSubkeyRangeSet::single_range(1, 4)
+        );
+        assert_eq!(
+            topic_slots(&schema, Some(&b)).unwrap(),
+            ValueSubkeyRangeSet::single_range(5, 8)
+        );
+        assert_eq!(
+            topic_slots(&schema, None).unwrap(),
+            ValueSubkeyRangeSet::single_range(1, 8)
+        );
+        assert!(topic_slots(&schema, Some(&c)).is_err());
+        assert!(topic_slots(&DHTSchema::dflt(8).unwrap(), None).is_err());
+
+        // Messages are attributed to the publisher that owns the slot
+        assert_eq!(topic_publisher(&schema, TOPIC_NAME_SUBKEY), None);
+        assert_eq!(topic_publisher(&schema, 4), Some(a));
+        assert_eq!(topic_publisher(&schema, 5), Some(b));
+        assert_eq!(topic_publisher(&schema, 9), None);
+    }
+
+    #[test]
+    fn test_order_topic_slots() {
+        let slots = ValueSubkeyRangeSet::single_range(5, 8);
+        let ordered = order_topic_slots(&slots, &[Some(3), Some(1), None, Some(2)]);
+        assert_eq!(ordered, vec![7, 6, 8, 5]);
+
+        let data = encode_topic_message(Timestamp::new(42), b"hello");
+        assert_eq!(
+            decode_topic_message(&data),
+            Some((Timestamp::new(42), b"hello".to_vec()))
+        );
+        assert_eq!(decode_topic_message(&[0u8; 4]), None);
+    }
+}
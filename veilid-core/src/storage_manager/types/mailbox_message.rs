@@ -0,0 +1,44 @@
+use super::*;
+
+/////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A sealed message held by the host of a peer-hosted mailbox until the mailbox owner drains it
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MailboxMessage {
+    id: u64,
+    expiration: Timestamp,
+    #[serde(with = "as_human_base64")]
+    message: Vec<u8>,
+}
+
+impl MailboxMessage {
+    pub fn new(id: u64, expiration: Timestamp, message: Vec<u8>) -> Self {
+        Self {
+            id,
+            expiration,
+            message,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn expiration(&self) -> Timestamp {
+        self.expiration
+    }
+
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+}
+
+impl fmt::Debug for MailboxMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MailboxMessage")
+            .field("id", &self.id)
+            .field("expiration", &self.expiration)
+            .field("message.len", &self.message.len())
+            .finish()
+    }
+}
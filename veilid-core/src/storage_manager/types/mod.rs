@@ -1,7 +1,9 @@
+mod mailbox_message;
 mod signed_value_data;
 mod signed_value_descriptor;
 
 use super::*;
 
+pub use mailbox_message::*;
 pub use signed_value_data::*;
 pub use signed_value_descriptor::*;
@@ -6,15 +6,23 @@ use super::*;
 pub struct SignedValueData {
     value_data: ValueData,
     signature: Signature,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cosignatures: Vec<DHTCosignature>,
 }
 impl SignedValueData {
     pub fn new(value_data: ValueData, signature: Signature) -> Self {
         Self {
             value_data,
             signature,
+            cosignatures: Vec::new(),
         }
     }
 
+    pub fn with_cosignatures(mut self, cosignatures: Vec<DHTCosignature>) -> Self {
+        self.cosignatures = cosignatures;
+        self
+    }
+
     pub fn validate(
         &self,
         owner: &PublicKey,
@@ -23,7 +31,35 @@ impl SignedValueData {
     ) -> VeilidAPIResult<bool> {
         let node_info_bytes = Self::make_signature_bytes(&self.value_data, owner, subkey)?;
         // validate signature
-        vcrypto.verify(self.value_data.writer(), &node_info_bytes, &self.signature)
+        if !vcrypto.verify(self.value_data.writer(), &node_info_bytes, &self.signature)? {
+            return Ok(false);
+        }
+        // validate cosignatures
+        Self::validate_cosignature_bytes(
+            &self.cosignatures,
+            self.value_data.writer(),
+            &node_info_bytes,
+            vcrypto,
+        )
+    }
+
+    /// Validate only the cosignatures, for values whose writer signature has been checked elsewhere
+    pub fn validate_cosignatures(
+        &self,
+        owner: &PublicKey,
+        subkey: ValueSubkey,
+        vcrypto: &CryptoSystemGuard<'_>,
+    ) -> VeilidAPIResult<bool> {
+        if self.cosignatures.is_empty() {
+            return Ok(true);
+        }
+        let node_info_bytes = Self::make_signature_bytes(&self.value_data, owner, subkey)?;
+        Self::validate_cosignature_bytes(
+            &self.cosignatures,
+            self.value_data.writer(),
+            &node_info_bytes,
+            vcrypto,
+        )
     }
 
     pub fn make_signature(
@@ -40,9 +76,25 @@ impl SignedValueData {
         Ok(Self {
             value_data,
             signature,
+            cosignatures: Vec::new(),
         })
     }
 
+    /// Sign a value as a cosigner. The writer of the value is not covered by the signature.
+    pub fn make_cosignature(
+        value_data: &ValueData,
+        owner: &PublicKey,
+        subkey: ValueSubkey,
+        vcrypto: &CryptoSystemGuard<'_>,
+        signer: KeyPair,
+    ) -> VeilidAPIResult<DHTCosignature> {
+        let node_info_bytes = Self::make_signature_bytes(value_data, owner, subkey)?;
+
+        // create signature
+        let signature = vcrypto.sign(&signer.key, &signer.secret, &node_info_bytes)?;
+        Ok(DHTCosignature::new(signer.key, signature))
+    }
+
     pub fn value_data(&self) -> &ValueData {
         &self.value_data
     }
@@ -51,12 +103,49 @@ impl SignedValueData {
         &self.signature
     }
 
+    pub fn cosignatures(&self) -> &[DHTCosignature] {
+        &self.cosignatures
+    }
+
+    /// The keys that cosigned this value, for checking against the schema
+    pub fn cosigners(&self) -> Vec<PublicKey> {
+        self.cosignatures.iter().map(|c| *c.signer()).collect()
+    }
+
     pub fn data_size(&self) -> usize {
         self.value_data.data_size()
     }
 
     pub fn total_size(&self) -> usize {
-        (mem::size_of::<Self>() - mem::size_of::<ValueData>()) + self.value_data.total_size()
+        (mem::size_of::<Self>() - mem::size_of::<ValueData>())
+            + self.value_data.total_size()
+            + self.cosignatures.len() * mem::size_of::<DHTCosignature>()
+    }
+
+    fn validate_cosignature_bytes(
+        cosignatures: &[DHTCosignature],
+        writer: &PublicKey,
+        node_info_bytes: &[u8],
+        vcrypto: &CryptoSystemGuard<'_>,
+    ) -> VeilidAPIResult<bool> {
+        for (n, cosignature) in cosignatures.iter().enumerate() {
+            // Each cosigner may only sign once, and not as well as writing
+            if cosignature.signer() == writer
+                || cosignatures[0..n]
+                    .iter()
+                    .any(|c| c.signer() == cosignature.signer())
+            {
+                return Ok(false);
+            }
+            if !vcrypto.verify(
+                cosignature.signer(),
+                node_info_bytes,
+                cosignature.signature(),
+            )? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
     }
 
     fn make_signature_bytes(
@@ -943,7 +943,15 @@ impl StorageManager {
                 while !newer_online_subkeys.is_empty() {
                     let first_changed_subkey = newer_online_subkeys.first().unwrap();
 
-                    let value = match this.get_value(record_key, first_changed_subkey, true).await {
+                    let value = match this
+                        .get_value(
+                            record_key,
+                            first_changed_subkey,
+                            true,
+                            CallOptions::default(),
+                        )
+                        .await
+                    {
                         Ok(v) => v,
                         Err(e) => {
                             veilid_log!(this debug "Failed to get changed record: {}", e);
@@ -1183,6 +1191,7 @@ impl StorageManager {
                     descriptor.owner(),
                     first_subkey,
                     value.value_data(),
+                    &value.cosigners(),
                 ) {
                     // Validation failed, ignore this value
                     // Move to the next node
@@ -1192,6 +1201,20 @@ impl StorageManager {
                     )));
                 }
 
+                // Cosignatures count towards the schema threshold, so they must be genuine
+                if !value.cosignatures().is_empty() {
+                    let crypto = self.crypto();
+                    let Some(vcrypto) = crypto.get(record_key.kind) else {
+                        return Ok(NetworkResult::invalid_message("unsupported cryptosystem"));
+                    };
+                    if !value.validate_cosignatures(descriptor.owner(), first_subkey, &vcrypto)? {
+                        return Ok(NetworkResult::invalid_message(format!(
+                            "Cosignature validation failed on subkey {}",
+                            first_subkey
+                        )));
+                    }
+                }
+
                 // Make sure this value would actually be newer
                 report_value_change = true;
                 if let Some(last_value) = &last_get_result.opt_value {
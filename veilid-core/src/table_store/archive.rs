@@ -0,0 +1,265 @@
+use super::*;
+
+const TABLE_ARCHIVE_MAGIC: &[u8; 4] = b"VTSA";
+const TABLE_ARCHIVE_VERSION: u8 = 1;
+/// Magic, version and crypto kind
+const TABLE_ARCHIVE_HEADER_LENGTH: usize = 4 + 1 + 4;
+
+/// A table that has been imported but not yet swapped in for the table of the same name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct PendingTableImport {
+    pub real_name: String,
+    pub ordered_columns: BTreeSet<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TableArchiveEntry {
+    #[serde(with = "as_human_base64")]
+    key: Vec<u8>,
+    #[serde(with = "as_human_base64")]
+    value: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TableArchiveTable {
+    name: String,
+    column_count: u32,
+    ordered_columns: BTreeSet<u32>,
+    columns: Vec<Vec<TableArchiveEntry>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TableArchive {
+    namespace: String,
+    tables: Vec<TableArchiveTable>,
+}
+
+impl TableStore {
+    /// Names of all tables in this node's namespace
+    #[must_use]
+    pub fn table_names(&self) -> Vec<String> {
+        let namespace = self.config().with(|c| c.namespace.clone());
+        let prefix = format!("_ns_{}_", namespace);
+        let inner = self.inner.lock();
+        let mut out = inner
+            .all_table_names
+            .keys()
+            .filter_map(|name| {
+                if namespace.is_empty() {
+                    (!name.starts_with("_ns_")).then(|| name.clone())
+                } else {
+                    name.strip_prefix(&prefix).map(|n| n.to_owned())
+                }
+            })
+            .collect::<Vec<_>>();
+        out.sort();
+        out
+    }
+
+    /// Export tables to a single archive encrypted with a password.
+    /// If `tables` is empty, every table in this node's namespace is exported, which includes the
+    /// node id secrets, routing table, route spec store and DHT record stores.
+    /// The archive holds the tables' plaintext contents, so it does not depend on this device's encryption key.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub async fn export(&self, tables: &[String], password: &str) -> VeilidAPIResult<Vec<u8>> {
+        if password.is_empty() {
+            apibail_invalid_argument!("archive password must not be empty", "password", "");
+        }
+        let namespace = self.config().with(|c| c.namespace.clone());
+        let names = if tables.is_empty() {
+            self.table_names()
+        } else {
+            tables.to_vec()
+        };
+
+        let mut archive = TableArchive {
+            namespace,
+            tables: Vec::with_capacity(names.len()),
+        };
+        for name in names {
+            if self.name_get(&name).await?.is_none() {
+                apibail_invalid_argument!("table does not exist", "table", name);
+            }
+            let tdb = self.open(&name, 0).await?;
+            let column_count = tdb.get_column_count()?;
+            let mut columns = Vec::with_capacity(column_count as usize);
+            for col in 0..column_count {
+                columns.push(
+                    tdb.read_all(col)
+                        .await?
+                        .into_iter()
+                        .map(|(key, value)| TableArchiveEntry { key, value })
+                        .collect(),
+                );
+            }
+            archive.tables.push(TableArchiveTable {
+                name,
+                column_count,
+                ordered_columns: tdb.ordered_columns(),
+                columns,
+            });
+        }
+
+        let body = compress_prepend_size(&serialize_json_bytes(&archive));
+
+        let crypto = self.crypto();
+        let vcrypto = crypto.get_async(best_crypto_kind()).unwrap();
+        let mut header = Vec::with_capacity(TABLE_ARCHIVE_HEADER_LENGTH);
+        header.extend_from_slice(TABLE_ARCHIVE_MAGIC);
+        header.push(TABLE_ARCHIVE_VERSION);
+        header.extend_from_slice(&vcrypto.kind().0);
+
+        let nonce = vcrypto.random_nonce().await;
+        let shared_secret = vcrypto
+            .derive_shared_secret(password.as_bytes(), &nonce.bytes)
+            .await?;
+        let encrypted = vcrypto
+            .encrypt_aead(&body, &nonce, &shared_secret, Some(&header))
+            .await?;
+
+        let mut out = header;
+        out.extend_from_slice(&nonce.bytes);
+        out.extend_from_slice(&encrypted);
+        Ok(out)
+    }
+
+    /// Import tables from an archive made by [TableStore::export].
+    /// Imported tables are written alongside the existing ones, and replace the tables of the same name
+    /// the next time the TableStore starts up, before anything else has opened them.
+    /// Returns the names of the tables that were imported.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub async fn import(&self, archive: &[u8], password: &str) -> VeilidAPIResult<Vec<String>> {
+        if archive.len() < TABLE_ARCHIVE_HEADER_LENGTH + NONCE_LENGTH {
+            apibail_parse_error!("archive is too short", archive.len());
+        }
+        let (header, rest) = archive.split_at(TABLE_ARCHIVE_HEADER_LENGTH);
+        if &header[0..4] != TABLE_ARCHIVE_MAGIC {
+            apibail_parse_error!("not a table store archive", "");
+        }
+        if header[4] != TABLE_ARCHIVE_VERSION {
+            apibail_parse_error!("unsupported archive version", header[4]);
+        }
+        let kind = CryptoKind::try_from(&header[5..]).map_err(VeilidAPIError::internal)?;
+        let (nonce, encrypted) = rest.split_at(NONCE_LENGTH);
+        let nonce = Nonce::try_from(nonce).map_err(VeilidAPIError::internal)?;
+
+        let crypto = self.crypto();
+        let Some(vcrypto) = crypto.get_async(kind) else {
+            apibail_generic!(format!("unsupported cryptosystem '{}'", kind));
+        };
+        let shared_secret = vcrypto
+            .derive_shared_secret(password.as_bytes(), &nonce.bytes)
+            .await?;
+        let body = vcrypto
+            .decrypt_aead(encrypted, &nonce, &shared_secret, Some(header))
+            .await
+            .map_err(|_| VeilidAPIError::tampered("wrong password or archive has been modified"))?;
+        let body = decompress_size_prepended(&body, None).map_err(VeilidAPIError::internal)?;
+        let archive: TableArchive = deserialize_json_bytes(&body)?;
+
+        // Keep the encryption key steady while writing
+        let _async_guard = self.async_lock.lock().await;
+
+        let mut imported = Vec::with_capacity(archive.tables.len());
+        for table in archive.tables {
+            if table.columns.len() != table.column_count as usize
+                || table.column_count == 0
+                || table
+                    .ordered_columns
+                    .iter()
+                    .any(|col| *col >= table.column_count)
+            {
+                apibail_parse_error!("archive table is malformed", table.name);
+            }
+            let name = self.namespaced_name(&table.name)?;
+
+            // Write the table under a fresh internal name
+            let mut real_name_bytes = [0u8; 32];
+            random_bytes(&mut real_name_bytes);
+            let real_name = data_encoding::BASE64URL_NOPAD.encode(&real_name_bytes);
+
+            let db = self
+                .table_store_driver
+                .open(&real_name, table.column_count)
+                .await?;
+            let encryption_key = self.inner.lock().encryption_key;
+            let tdb = TableDB::new(
+                real_name.clone(),
+                self.registry(),
+                db,
+                encryption_key.map(|k| CryptInfo::new(k, TABLE_DB_FORMAT_CURRENT)),
+                encryption_key.map(|k| CryptInfo::new(k, TABLE_DB_FORMAT_CURRENT)),
+                table.ordered_columns.clone(),
+                0,
+            );
+            let tx = tdb.transact();
+            for (col, entries) in table.columns.iter().enumerate() {
+                for entry in entries {
+                    tx.store(col as u32, &entry.key, &entry.value)?;
+                }
+            }
+            tx.commit().await?;
+            drop(tdb);
+
+            // Remember to swap it in at the next startup
+            let replaced = self.inner.lock().all_table_pending_imports.insert(
+                name,
+                PendingTableImport {
+                    real_name,
+                    ordered_columns: table.ordered_columns,
+                },
+            );
+            if let Some(replaced) = replaced {
+                self.table_store_driver.delete(&replaced.real_name).await?;
+            }
+            imported.push(table.name);
+        }
+        self.flush().await;
+
+        veilid_log!(self debug
+            "imported {} tables from archive of namespace '{}', pending restart",
+            imported.len(), archive.namespace
+        );
+        Ok(imported)
+    }
+
+    /// Swap imported tables in for the tables they replace
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub(super) async fn apply_pending_imports(&self) {
+        let (pending, replaced) = {
+            let mut inner = self.inner.lock();
+            let pending = core::mem::take(&mut inner.all_table_pending_imports);
+            let mut replaced = Vec::new();
+            for (name, import) in &pending {
+                if let Some(old_real_name) = inner
+                    .all_table_names
+                    .insert(name.clone(), import.real_name.clone())
+                {
+                    inner.all_table_formats.remove(&old_real_name);
+                    inner.all_table_ordered_columns.remove(&old_real_name);
+                    replaced.push(old_real_name);
+                }
+                inner
+                    .all_table_formats
+                    .insert(import.real_name.clone(), TABLE_DB_FORMAT_CURRENT);
+                if !import.ordered_columns.is_empty() {
+                    inner
+                        .all_table_ordered_columns
+                        .insert(import.real_name.clone(), import.ordered_columns.clone());
+                }
+            }
+            (pending, replaced)
+        };
+        if pending.is_empty() {
+            return;
+        }
+        self.flush().await;
+
+        for old_real_name in replaced {
+            if let Err(e) = self.table_store_driver.delete(&old_real_name).await {
+                error!("error deleting replaced table: {}", e);
+            }
+        }
+        veilid_log!(self info "applied {} imported tables", pending.len());
+    }
+}
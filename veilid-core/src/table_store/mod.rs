@@ -1,6 +1,10 @@
 use super::*;
 
+mod archive;
+mod rotation;
 mod table_db;
+use archive::*;
+pub use rotation::*;
 pub use table_db::*;
 
 pub mod tests;
@@ -20,6 +24,13 @@ use weak_table::WeakValueHashMap;
 impl_veilid_log_facility!("tstore");
 
 const ALL_TABLE_NAMES: &[u8] = b"all_table_names";
+const ALL_TABLE_FORMATS: &[u8] = b"all_table_formats";
+const ALL_TABLE_ORDERED_COLUMNS: &[u8] = b"all_table_ordered_columns";
+const ALL_TABLE_PENDING_IMPORTS: &[u8] = b"all_table_pending_imports";
+
+const DEVICE_ENCRYPTION_KEY: &str = "device_encryption_key";
+/// Replacement device encryption key, present only while a key rotation is in progress
+const NEXT_DEVICE_ENCRYPTION_KEY: &str = "next_device_encryption_key";
 
 /// Description of column
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -75,7 +86,15 @@ pub struct TableInfo {
 struct TableStoreInner {
     opened: WeakValueHashMap<String, Weak<TableDBUnlockedInner>>,
     encryption_key: Option<TypedSharedSecret>,
+    /// The device encryption key being replaced, while a key rotation is in progress
+    previous_encryption_key: Option<TypedSharedSecret>,
     all_table_names: HashMap<String, String>,
+    /// Entry format of each table by internal name. Tables missing from here predate format tracking.
+    all_table_formats: HashMap<String, u8>,
+    /// Columns of each table by internal name that keep their keys in order
+    all_table_ordered_columns: HashMap<String, BTreeSet<u32>>,
+    /// Imported tables by name, waiting to replace the existing tables at the next startup
+    all_table_pending_imports: HashMap<String, PendingTableImport>,
     all_tables_db: Option<Database>,
 }
 
@@ -84,7 +103,11 @@ impl fmt::Debug for TableStoreInner {
         f.debug_struct("TableStoreInner")
             .field("opened", &self.opened)
             .field("encryption_key", &self.encryption_key)
+            .field("previous_encryption_key", &self.previous_encryption_key)
             .field("all_table_names", &self.all_table_names)
+            .field("all_table_formats", &self.all_table_formats)
+            .field("all_table_ordered_columns", &self.all_table_ordered_columns)
+            .field("all_table_pending_imports", &self.all_table_pending_imports)
             //.field("all_tables_db", &self.all_tables_db)
             .finish()
     }
@@ -118,7 +141,11 @@ impl TableStore {
         TableStoreInner {
             opened: WeakValueHashMap::new(),
             encryption_key: None,
+            previous_encryption_key: None,
             all_table_names: HashMap::new(),
+            all_table_formats: HashMap::new(),
+            all_table_ordered_columns: HashMap::new(),
+            all_table_pending_imports: HashMap::new(),
             all_tables_db: None,
         }
     }
@@ -136,13 +163,31 @@ impl TableStore {
 
     // Flush internal control state
     async fn flush(&self) {
-        let (all_table_names_value, all_tables_db) = {
+        let (dbt, all_tables_db) = {
             let inner = self.inner.lock();
-            let all_table_names_value = serialize_json_bytes(&inner.all_table_names);
-            (all_table_names_value, inner.all_tables_db.clone().unwrap())
+            let mut dbt = DBTransaction::new();
+            dbt.put(
+                0,
+                ALL_TABLE_NAMES,
+                serialize_json_bytes(&inner.all_table_names),
+            );
+            dbt.put(
+                0,
+                ALL_TABLE_FORMATS,
+                serialize_json_bytes(&inner.all_table_formats),
+            );
+            dbt.put(
+                0,
+                ALL_TABLE_ORDERED_COLUMNS,
+                serialize_json_bytes(&inner.all_table_ordered_columns),
+            );
+            dbt.put(
+                0,
+                ALL_TABLE_PENDING_IMPORTS,
+                serialize_json_bytes(&inner.all_table_pending_imports),
+            );
+            (dbt, inner.all_tables_db.clone().unwrap())
         };
-        let mut dbt = DBTransaction::new();
-        dbt.put(0, ALL_TABLE_NAMES, &all_table_names_value);
         if let Err(e) = all_tables_db.write(dbt).await {
             error!("failed to write all tables db: {}", e);
         }
@@ -187,6 +232,9 @@ impl TableStore {
         {
             panic!("should not have had some value");
         };
+        inner
+            .all_table_formats
+            .insert(real_name.clone(), TABLE_DB_FORMAT_CURRENT);
 
         Ok(real_name)
     }
@@ -196,6 +244,10 @@ impl TableStore {
         let name = self.namespaced_name(table)?;
         let mut inner = self.inner.lock();
         let real_name = inner.all_table_names.remove(&name);
+        if let Some(real_name) = &real_name {
+            inner.all_table_formats.remove(real_name);
+            inner.all_table_ordered_columns.remove(real_name);
+        }
         Ok(real_name)
     }
 
@@ -244,12 +296,20 @@ impl TableStore {
         // Get all tables
         let real_names = {
             let mut inner = self.inner.lock();
-            let real_names = inner
+            let mut real_names = inner
                 .all_table_names
                 .values()
                 .cloned()
                 .collect::<Vec<String>>();
             inner.all_table_names.clear();
+            inner.all_table_formats.clear();
+            inner.all_table_ordered_columns.clear();
+            real_names.extend(
+                inner
+                    .all_table_pending_imports
+                    .drain()
+                    .map(|(_, import)| import.real_name),
+            );
             real_names
         };
 
@@ -359,12 +419,13 @@ impl TableStore {
     }
 
     #[instrument(level = "trace", target = "tstore", skip_all)]
-    async fn load_device_encryption_key(&self) -> EyreResult<Option<TypedSharedSecret>> {
-        let dek_bytes: Option<Vec<u8>> = self
-            .protected_store()
-            .load_user_secret("device_encryption_key")?;
+    async fn load_device_encryption_key(
+        &self,
+        key_name: &str,
+    ) -> EyreResult<Option<TypedSharedSecret>> {
+        let dek_bytes: Option<Vec<u8>> = self.protected_store().load_user_secret(key_name)?;
         let Some(dek_bytes) = dek_bytes else {
-            veilid_log!(self debug "no device encryption key");
+            veilid_log!(self debug "no {}", key_name);
             return Ok(None);
         };
 
@@ -382,14 +443,13 @@ impl TableStore {
     #[instrument(level = "trace", target = "tstore", skip_all)]
     async fn save_device_encryption_key(
         &self,
+        key_name: &str,
         device_encryption_key: Option<TypedSharedSecret>,
     ) -> EyreResult<()> {
         let Some(device_encryption_key) = device_encryption_key else {
             // Remove the device encryption key
-            let existed = self
-                .protected_store()
-                .remove_user_secret("device_encryption_key")?;
-            veilid_log!(self debug "removed device encryption key. existed: {}", existed);
+            let existed = self.protected_store().remove_user_secret(key_name)?;
+            veilid_log!(self debug "removed {}. existed: {}", key_name, existed);
             return Ok(());
         };
 
@@ -427,8 +487,8 @@ impl TableStore {
         // Save the new device encryption key
         let existed = self
             .protected_store()
-            .save_user_secret("device_encryption_key", &dek_bytes)?;
-        veilid_log!(self debug "saving device encryption key. existed: {}", existed);
+            .save_user_secret(key_name, &dek_bytes)?;
+        veilid_log!(self debug "saving {}. existed: {}", key_name, existed);
         Ok(())
     }
 
@@ -438,7 +498,9 @@ impl TableStore {
             let _async_guard = self.async_lock.lock().await;
 
             // Get device encryption key from protected store
-            let mut device_encryption_key = self.load_device_encryption_key().await?;
+            let mut device_encryption_key = self
+                .load_device_encryption_key(DEVICE_ENCRYPTION_KEY)
+                .await?;
             let mut device_encryption_key_changed = false;
             if let Some(device_encryption_key) = device_encryption_key {
                 // If encryption in current use is not the best encryption, then run table migration
@@ -465,7 +527,7 @@ impl TableStore {
 
             // Save encryption key if it has changed or if the protecting password wants to change
             if device_encryption_key_changed || changing_password {
-                self.save_device_encryption_key(device_encryption_key)
+                self.save_device_encryption_key(DEVICE_ENCRYPTION_KEY, device_encryption_key)
                     .await?;
             }
 
@@ -493,6 +555,68 @@ impl TableStore {
                     error!("could not get __veilid_all_tables: {}", e);
                 }
             };
+            match all_tables_db.get(0, ALL_TABLE_FORMATS).await {
+                Ok(Some(v)) => match deserialize_json_bytes::<HashMap<String, u8>>(&v) {
+                    Ok(all_table_formats) => {
+                        let mut inner = self.inner.lock();
+                        inner.all_table_formats = all_table_formats;
+                    }
+                    Err(e) => {
+                        error!("could not deserialize __veilid_all_tables formats: {}", e);
+                    }
+                },
+                Ok(None) => {
+                    // No table formats yet, any existing tables are in the legacy format
+                    veilid_log!(self trace "__veilid_all_tables has no formats");
+                }
+                Err(e) => {
+                    error!("could not get __veilid_all_tables formats: {}", e);
+                }
+            };
+            match all_tables_db.get(0, ALL_TABLE_ORDERED_COLUMNS).await {
+                Ok(Some(v)) => match deserialize_json_bytes::<HashMap<String, BTreeSet<u32>>>(&v) {
+                    Ok(all_table_ordered_columns) => {
+                        let mut inner = self.inner.lock();
+                        inner.all_table_ordered_columns = all_table_ordered_columns;
+                    }
+                    Err(e) => {
+                        error!(
+                            "could not deserialize __veilid_all_tables ordered columns: {}",
+                            e
+                        );
+                    }
+                },
+                Ok(None) => {
+                    // No ordered columns yet, that's okay
+                    veilid_log!(self trace "__veilid_all_tables has no ordered columns");
+                }
+                Err(e) => {
+                    error!("could not get __veilid_all_tables ordered columns: {}", e);
+                }
+            };
+            match all_tables_db.get(0, ALL_TABLE_PENDING_IMPORTS).await {
+                Ok(Some(v)) => {
+                    match deserialize_json_bytes::<HashMap<String, PendingTableImport>>(&v) {
+                        Ok(all_table_pending_imports) => {
+                            let mut inner = self.inner.lock();
+                            inner.all_table_pending_imports = all_table_pending_imports;
+                        }
+                        Err(e) => {
+                            error!(
+                                "could not deserialize __veilid_all_tables pending imports: {}",
+                                e
+                            );
+                        }
+                    }
+                }
+                Ok(None) => {
+                    // No pending imports, that's okay
+                    veilid_log!(self trace "__veilid_all_tables has no pending imports");
+                }
+                Err(e) => {
+                    error!("could not get __veilid_all_tables pending imports: {}", e);
+                }
+            };
 
             {
                 let mut inner = self.inner.lock();
@@ -500,6 +624,18 @@ impl TableStore {
                 inner.all_tables_db = Some(all_tables_db);
             }
 
+            // Finish any device encryption key rotation that was interrupted
+            let next_device_encryption_key = self
+                .load_device_encryption_key(NEXT_DEVICE_ENCRYPTION_KEY)
+                .await?;
+            if let Some(next_device_encryption_key) = next_device_encryption_key {
+                self.resume_device_encryption_key_rotation(next_device_encryption_key)
+                    .await?;
+            }
+
+            // Swap in any tables imported since the last startup
+            self.apply_pending_imports().await;
+
             let do_delete = self.config().with(|c| c.table_store.delete);
 
             if do_delete {
@@ -539,13 +675,31 @@ impl TableStore {
         }
         inner.all_tables_db = None;
         inner.all_table_names.clear();
+        inner.all_table_formats.clear();
+        inner.all_table_ordered_columns.clear();
+        inner.all_table_pending_imports.clear();
         inner.encryption_key = None;
+        inner.previous_encryption_key = None;
     }
 
     /// Get or create a TableDB database table. If the column count is greater than an
     /// existing TableDB's column count, the database will be upgraded to add the missing columns.
     #[instrument(level = "trace", target = "tstore", skip_all)]
     pub async fn open(&self, name: &str, column_count: u32) -> VeilidAPIResult<TableDB> {
+        self.open_ordered(name, column_count, &[]).await
+    }
+
+    /// Get or create a TableDB database table, as with [TableStore::open], and keep the keys of some columns
+    /// in order so they can be read with [TableDB::iter_range] and [TableDB::iter_prefix].
+    /// Keys in ordered columns are stored unencrypted, their values are still encrypted.
+    /// A column stays ordered once it has been opened as ordered, and can only become ordered while it is empty.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub async fn open_ordered(
+        &self,
+        name: &str,
+        column_count: u32,
+        ordered_columns: &[u32],
+    ) -> VeilidAPIResult<TableDB> {
         let _async_guard = self.async_lock.lock().await;
 
         // If we aren't initialized yet, bail
@@ -573,14 +727,26 @@ impl TableStore {
                     )));
                 }
 
+                // Ensure no more columns are being ordered
+                let existing_ordered_columns = tdb.ordered_columns();
+                if ordered_columns
+                    .iter()
+                    .any(|col| !existing_ordered_columns.contains(col))
+                {
+                    return Err(VeilidAPIError::generic(
+                        "database must be closed before ordering more columns",
+                    ));
+                }
+
                 return Ok(tdb);
             }
         }
 
         // Open table db using platform-specific driver
+        // A column count of zero asks for the existing columns, which are reopened below, but the driver needs at least one
         let mut db = match self
             .table_store_driver
-            .open(&table_name, column_count)
+            .open(&table_name, column_count.max(1))
             .await
         {
             Ok(db) => db,
@@ -612,16 +778,93 @@ impl TableStore {
             };
         }
 
+        // Migrate tables written in an older entry format
+        let (encryption_key, previous_encryption_key, table_format, mut table_ordered_columns) = {
+            let inner = self.inner.lock();
+            (
+                inner.encryption_key,
+                inner.previous_encryption_key,
+                inner
+                    .all_table_formats
+                    .get(&table_name)
+                    .copied()
+                    .unwrap_or(TABLE_DB_FORMAT_LEGACY),
+                inner
+                    .all_table_ordered_columns
+                    .get(&table_name)
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+        };
+        if table_format != TABLE_DB_FORMAT_CURRENT {
+            // Tables in an older format have not been touched by a key rotation in progress
+            let decryption_key = previous_encryption_key.or(encryption_key);
+            let migration_db = TableDB::new(
+                table_name.clone(),
+                self.registry(),
+                db.clone(),
+                encryption_key.map(|k| CryptInfo::new(k, TABLE_DB_FORMAT_CURRENT)),
+                decryption_key.map(|k| CryptInfo::new(k, table_format)),
+                table_ordered_columns.clone(),
+                0,
+            );
+            let count = migration_db.reencrypt_in_place().await?;
+            drop(migration_db);
+
+            veilid_log!(self debug
+                "migrated table '{}' from format {} to {}: {} entries",
+                name, table_format, TABLE_DB_FORMAT_CURRENT, count
+            );
+            self.inner
+                .lock()
+                .all_table_formats
+                .insert(table_name.clone(), TABLE_DB_FORMAT_CURRENT);
+            self.flush().await;
+        }
+
+        // Order any newly requested columns, which must still be empty
+        let total_columns = db.num_columns().map_err(VeilidAPIError::from)?;
+        let mut ordering_changed = false;
+        for col in ordered_columns {
+            if table_ordered_columns.contains(col) {
+                continue;
+            }
+            if *col >= total_columns {
+                apibail_invalid_argument!("ordered column does not exist", "col", col);
+            }
+            if db.num_keys(*col).await.map_err(VeilidAPIError::from)? != 0 {
+                apibail_generic!(format!(
+                    "column {} must be empty before it can be ordered",
+                    col
+                ));
+            }
+            table_ordered_columns.insert(*col);
+            ordering_changed = true;
+        }
+        if ordering_changed {
+            self.inner
+                .lock()
+                .all_table_ordered_columns
+                .insert(table_name.clone(), table_ordered_columns.clone());
+            self.flush().await;
+        }
+
         // Wrap low-level Database in TableDB object
         let mut inner = self.inner.lock();
         let table_db = TableDB::new(
             table_name.clone(),
             self.registry(),
             db,
-            inner.encryption_key,
-            inner.encryption_key,
+            encryption_key.map(|k| CryptInfo::new(k, TABLE_DB_FORMAT_CURRENT)),
+            encryption_key.map(|k| CryptInfo::new(k, TABLE_DB_FORMAT_CURRENT)),
+            table_ordered_columns,
             column_count,
         );
+        if let (Some(encryption_key), Some(previous_encryption_key)) =
+            (encryption_key, previous_encryption_key)
+        {
+            table_db.begin_key_rotation(encryption_key, previous_encryption_key);
+        }
 
         // Keep track of opened DBs
         inner
@@ -0,0 +1,228 @@
+use super::*;
+
+/// Progress of a device encryption key rotation, reported after each table has been re-encrypted
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct KeyRotationProgress {
+    /// Number of tables re-encrypted so far
+    pub tables_done: usize,
+    /// Number of tables being re-encrypted
+    pub tables_total: usize,
+    /// Internal name of the table that was just re-encrypted
+    pub table: String,
+}
+
+impl TableStore {
+    /// Replace the device encryption key with a new one and re-encrypt every table with it.
+    /// Tables stay usable while this runs: open tables are read with either key and written with the new one.
+    /// The new key is kept in the protected store until every table has been rewritten, so if the rotation
+    /// is interrupted it is finished the next time the TableStore starts up.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub async fn rotate_device_encryption_key<F>(&self, mut progress: F) -> VeilidAPIResult<()>
+    where
+        F: FnMut(KeyRotationProgress) + Send,
+    {
+        {
+            let _async_guard = self.async_lock.lock().await;
+
+            let (encryption_key, rotating) = {
+                let inner = self.inner.lock();
+                if inner.all_tables_db.is_none() {
+                    apibail_not_initialized!();
+                }
+                (
+                    inner.encryption_key,
+                    inner.previous_encryption_key.is_some(),
+                )
+            };
+            if rotating {
+                apibail_generic!("device encryption key rotation is already in progress");
+            }
+            let Some(encryption_key) = encryption_key else {
+                apibail_generic!("no device encryption key to rotate");
+            };
+
+            // Make the new key and keep it safe before anything is written with it
+            let mut shared_secret = SharedSecret::default();
+            random_bytes(&mut shared_secret.bytes);
+            let next_encryption_key = TypedSharedSecret::new(best_crypto_kind(), shared_secret);
+            self.save_device_encryption_key(NEXT_DEVICE_ENCRYPTION_KEY, Some(next_encryption_key))
+                .await
+                .map_err(VeilidAPIError::internal)?;
+
+            self.begin_device_encryption_key_rotation(next_encryption_key, encryption_key);
+        }
+
+        let tables = self.rotation_table_names();
+        let tables_total = tables.len();
+        for (n, table) in tables.into_iter().enumerate() {
+            let count = {
+                let _async_guard = self.async_lock.lock().await;
+                self.rotate_table(&table).await?
+            };
+            veilid_log!(self debug "re-encrypted table '{}': {} entries", table, count);
+            progress(KeyRotationProgress {
+                tables_done: n + 1,
+                tables_total,
+                table,
+            });
+        }
+
+        let _async_guard = self.async_lock.lock().await;
+        self.end_device_encryption_key_rotation()
+            .await
+            .map_err(VeilidAPIError::internal)
+    }
+
+    /// Finish a rotation to `next_encryption_key` that was interrupted. The async lock must be held.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub(super) async fn resume_device_encryption_key_rotation(
+        &self,
+        next_encryption_key: TypedSharedSecret,
+    ) -> EyreResult<()> {
+        let encryption_key = self.inner.lock().encryption_key;
+        match encryption_key {
+            Some(encryption_key) if encryption_key != next_encryption_key => {
+                veilid_log!(self info "resuming device encryption key rotation");
+                self.begin_device_encryption_key_rotation(next_encryption_key, encryption_key);
+                for table in self.rotation_table_names() {
+                    self.rotate_table(&table).await?;
+                }
+            }
+            _ => {
+                // Every table was rewritten and the new key saved, only the cleanup was interrupted
+                self.inner.lock().encryption_key = Some(next_encryption_key);
+            }
+        }
+        self.end_device_encryption_key_rotation().await
+    }
+
+    /// Switch to the new key, reading with the previous one where needed
+    pub(super) fn begin_device_encryption_key_rotation(
+        &self,
+        next_encryption_key: TypedSharedSecret,
+        encryption_key: TypedSharedSecret,
+    ) {
+        let opened = {
+            let mut inner = self.inner.lock();
+            inner.encryption_key = Some(next_encryption_key);
+            inner.previous_encryption_key = Some(encryption_key);
+            inner.opened.values().collect::<Vec<_>>()
+        };
+        for unlocked_inner in opened {
+            TableDB::new_from_unlocked_inner(unlocked_inner, 0)
+                .begin_key_rotation(next_encryption_key, encryption_key);
+        }
+    }
+
+    /// Make the new key the device encryption key and forget the previous one. The async lock must be held.
+    pub(super) async fn end_device_encryption_key_rotation(&self) -> EyreResult<()> {
+        let encryption_key = self.inner.lock().encryption_key;
+        self.save_device_encryption_key(DEVICE_ENCRYPTION_KEY, encryption_key)
+            .await?;
+        self.save_device_encryption_key(NEXT_DEVICE_ENCRYPTION_KEY, None)
+            .await?;
+        self.inner.lock().previous_encryption_key = None;
+        veilid_log!(self info "device encryption key rotated");
+        Ok(())
+    }
+
+    /// Internal names of every table to re-encrypt, including imported tables not yet swapped in
+    pub(super) fn rotation_table_names(&self) -> Vec<String> {
+        let inner = self.inner.lock();
+        let tables = inner
+            .all_table_names
+            .values()
+            .chain(
+                inner
+                    .all_table_pending_imports
+                    .values()
+                    .map(|import| &import.real_name),
+            )
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        tables.into_iter().collect()
+    }
+
+    /// Re-encrypt one table with the new key. The async lock must be held.
+    /// Returns the number of entries rewritten.
+    pub(super) async fn rotate_table(&self, table: &str) -> VeilidAPIResult<u64> {
+        let (opened, encryption_key, previous_encryption_key) = {
+            let inner = self.inner.lock();
+            (
+                inner.opened.get(table),
+                inner.encryption_key,
+                inner.previous_encryption_key,
+            )
+        };
+
+        // Open tables are already reading with both keys, so rewrite them in place
+        if let Some(unlocked_inner) = opened {
+            return TableDB::new_from_unlocked_inner(unlocked_inner, 0)
+                .finish_key_rotation()
+                .await;
+        }
+        let (Some(encryption_key), Some(previous_encryption_key)) =
+            (encryption_key, previous_encryption_key)
+        else {
+            return Ok(0);
+        };
+
+        // Imported tables are always written in the current format
+        let (table_format, ordered_columns) = {
+            let inner = self.inner.lock();
+            if let Some(import) = inner
+                .all_table_pending_imports
+                .values()
+                .find(|import| import.real_name == table)
+            {
+                (TABLE_DB_FORMAT_CURRENT, import.ordered_columns.clone())
+            } else if inner.all_table_names.values().any(|n| n == table) {
+                (
+                    inner
+                        .all_table_formats
+                        .get(table)
+                        .copied()
+                        .unwrap_or(TABLE_DB_FORMAT_LEGACY),
+                    inner
+                        .all_table_ordered_columns
+                        .get(table)
+                        .cloned()
+                        .unwrap_or_default(),
+                )
+            } else {
+                // Deleted since the rotation started
+                return Ok(0);
+            }
+        };
+
+        // Entries already written with the new key are left alone, so this also migrates older formats
+        // The driver needs at least one column, then reopen with every column the table has
+        let mut db = self.table_store_driver.open(table, 1).await?;
+        let column_count = db.num_columns().map_err(VeilidAPIError::from)?;
+        if column_count > 1 {
+            drop(db);
+            db = self.table_store_driver.open(table, column_count).await?;
+        }
+        let rotation_db = TableDB::new(
+            table.to_owned(),
+            self.registry(),
+            db,
+            Some(CryptInfo::new(encryption_key, TABLE_DB_FORMAT_CURRENT)),
+            Some(CryptInfo::new(previous_encryption_key, table_format)),
+            ordered_columns,
+            0,
+        );
+        let count = rotation_db.reencrypt_in_place().await?;
+        drop(rotation_db);
+
+        if table_format != TABLE_DB_FORMAT_CURRENT {
+            self.inner
+                .lock()
+                .all_table_formats
+                .insert(table.to_owned(), TABLE_DB_FORMAT_CURRENT);
+            self.flush().await;
+        }
+        Ok(count)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use core::ops::{Bound, RangeBounds};
 
 cfg_if! {
     if #[cfg(all(target_arch = "wasm32", target_os = "unknown"))] {
@@ -12,13 +13,149 @@ cfg_if! {
 
 impl_veilid_log_facility!("tstore");
 
+/// Original format for encrypted entries: nonce followed by unauthenticated ciphertext, with no header
+pub(super) const TABLE_DB_FORMAT_LEGACY: u8 = 0;
+/// Versioned header, nonce, then AEAD ciphertext bound to the header, column and (for values) key
+pub(super) const TABLE_DB_FORMAT_AEAD: u8 = 1;
+/// The format all new entries are written in
+pub(super) const TABLE_DB_FORMAT_CURRENT: u8 = TABLE_DB_FORMAT_AEAD;
+
+const TABLE_DB_MAGIC: &[u8; 3] = b"VTD";
+const TABLE_DB_HEADER_LENGTH: usize = 4;
+
+/// Approximate memory the sorted keys of a table's ordered columns may use. Columns are dropped from the index,
+/// least recently used first, to stay within it, and a column whose keys don't fit on their own is not indexed.
+pub(super) const KEY_INDEX_MAX_BYTES: usize = 4 * 1024 * 1024;
+
+#[derive(Clone, Copy)]
 #[must_use]
-struct CryptInfo {
+pub(super) struct CryptInfo {
     typed_key: TypedSharedSecret,
+    format: u8,
 }
 impl CryptInfo {
-    pub fn new(typed_key: TypedSharedSecret) -> Self {
-        Self { typed_key }
+    pub fn new(typed_key: TypedSharedSecret, format: u8) -> Self {
+        Self { typed_key, format }
+    }
+}
+
+/// The keys a table is currently read and written with
+#[derive(Clone, Copy)]
+struct TableDBCrypt {
+    // Encryption and decryption key will be the same unless configured for an in-place migration
+    encrypt_info: Option<CryptInfo>,
+    decrypt_info: Option<CryptInfo>,
+    // While the device encryption key is being rotated, the previous key for entries not yet rewritten
+    fallback_info: Option<CryptInfo>,
+}
+
+/// One page of entries from an ordered column, returned by [TableDB::iter_range] and [TableDB::iter_prefix]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use]
+pub struct TableDBPage {
+    /// Key and value pairs, in the requested order
+    pub entries: Vec<(Vec<u8>, Vec<u8>)>,
+    /// If there were more entries than the limit, the key to continue after when fetching the next page
+    pub next: Option<Vec<u8>>,
+}
+
+/// The sorted keys of one ordered column
+struct ColumnKeyIndex {
+    keys: BTreeSet<Vec<u8>>,
+    bytes: usize,
+    last_used: u64,
+}
+
+/// Sorted keys of the ordered columns that have been iterated, kept within [KEY_INDEX_MAX_BYTES].
+/// The backing store can only iterate a column's keys unordered, so without this every page would read them all.
+#[derive(Default)]
+struct KeyIndex {
+    columns: BTreeMap<u32, ColumnKeyIndex>,
+    // Columns whose keys did not fit the last time they were read
+    oversized: BTreeSet<u32>,
+    total_bytes: usize,
+    use_count: u64,
+}
+
+impl KeyIndex {
+    fn key_bytes(key: &[u8]) -> usize {
+        key.len() + mem::size_of::<Vec<u8>>()
+    }
+
+    /// Select up to `take` keys in a range of a column, in the requested order, if the column is indexed
+    fn page(
+        &mut self,
+        col: u32,
+        start: Bound<&[u8]>,
+        end: Bound<&[u8]>,
+        take: usize,
+        reverse: bool,
+    ) -> Option<Vec<Vec<u8>>> {
+        let column = self.columns.get_mut(&col)?;
+        self.use_count += 1;
+        column.last_used = self.use_count;
+        let range = column.keys.range::<[u8], _>((start, end));
+        Some(if reverse {
+            range.rev().take(take).cloned().collect()
+        } else {
+            range.take(take).cloned().collect()
+        })
+    }
+
+    fn insert_column(&mut self, col: u32, keys: BTreeSet<Vec<u8>>, bytes: usize) {
+        self.use_count += 1;
+        self.oversized.remove(&col);
+        if let Some(old) = self.columns.insert(
+            col,
+            ColumnKeyIndex {
+                keys,
+                bytes,
+                last_used: self.use_count,
+            },
+        ) {
+            self.total_bytes -= old.bytes;
+        }
+        self.total_bytes += bytes;
+        self.evict();
+    }
+
+    /// Record a key being stored in or deleted from a column
+    fn update(&mut self, col: u32, key: &[u8], present: bool) {
+        if !present {
+            // The column may fit now
+            self.oversized.remove(&col);
+        }
+        let Some(column) = self.columns.get_mut(&col) else {
+            return;
+        };
+        let key_bytes = Self::key_bytes(key);
+        if present {
+            if column.keys.insert(key.to_vec()) {
+                column.bytes += key_bytes;
+                self.total_bytes += key_bytes;
+            }
+        } else if column.keys.remove(key) {
+            column.bytes -= key_bytes;
+            self.total_bytes -= key_bytes;
+        }
+        self.evict();
+    }
+
+    /// Drop the least recently used columns until the index is within its budget
+    fn evict(&mut self) {
+        while self.total_bytes > KEY_INDEX_MAX_BYTES {
+            let Some(col) = self
+                .columns
+                .iter()
+                .min_by_key(|(_, column)| column.last_used)
+                .map(|(col, _)| *col)
+            else {
+                break;
+            };
+            if let Some(column) = self.columns.remove(&col) {
+                self.total_bytes -= column.bytes;
+            }
+        }
     }
 }
 
@@ -27,9 +164,13 @@ pub struct TableDBUnlockedInner {
     registry: VeilidComponentRegistry,
     table: String,
     database: Database,
-    // Encryption and decryption key will be the same unless configured for an in-place migration
-    encrypt_info: Option<CryptInfo>,
-    decrypt_info: Option<CryptInfo>,
+    crypt: Mutex<TableDBCrypt>,
+    // Held shared by writers and exclusively while rewriting every entry, so no write uses a key that is being retired
+    write_lock: AsyncRwLock<()>,
+    // Columns whose keys are stored in plaintext so they can be iterated in order
+    ordered_columns: BTreeSet<u32>,
+    // Sorted keys of the ordered columns, read from the database when a column is iterated
+    key_index: Mutex<KeyIndex>,
 }
 
 impl fmt::Debug for TableDBUnlockedInner {
@@ -55,13 +196,11 @@ impl TableDB {
         table: String,
         registry: VeilidComponentRegistry,
         database: Database,
-        encryption_key: Option<TypedSharedSecret>,
-        decryption_key: Option<TypedSharedSecret>,
+        encrypt_info: Option<CryptInfo>,
+        decrypt_info: Option<CryptInfo>,
+        ordered_columns: BTreeSet<u32>,
         opened_column_count: u32,
     ) -> Self {
-        let encrypt_info = encryption_key.map(CryptInfo::new);
-        let decrypt_info = decryption_key.map(CryptInfo::new);
-
         let total_columns = database.num_columns().unwrap();
 
         Self {
@@ -74,8 +213,14 @@ impl TableDB {
                 registry,
                 table,
                 database,
-                encrypt_info,
-                decrypt_info,
+                crypt: Mutex::new(TableDBCrypt {
+                    encrypt_info,
+                    decrypt_info,
+                    fallback_info: None,
+                }),
+                write_lock: AsyncRwLock::new(()),
+                ordered_columns,
+                key_index: Mutex::new(KeyIndex::default()),
             }),
         }
     }
@@ -112,6 +257,12 @@ impl TableDB {
         self.unlocked_inner.database.io_stats(kind)
     }
 
+    /// Get the columns that keep their keys in order
+    #[must_use]
+    pub fn ordered_columns(&self) -> BTreeSet<u32> {
+        self.unlocked_inner.ordered_columns.clone()
+    }
+
     /// Get the total number of columns in the TableDB.
     /// Not the number of columns that were opened, rather the total number that could be opened.
     #[instrument(level = "trace", target = "tstore", skip_all)]
@@ -120,52 +271,253 @@ impl TableDB {
         db.num_columns().map_err(VeilidAPIError::from)
     }
 
-    /// Encrypt buffer using encrypt key and prepend nonce to output.
+    fn crypt(&self) -> TableDBCrypt {
+        *self.unlocked_inner.crypt.lock()
+    }
+
+    /// Write with a new device encryption key, and read with it or with the previous key for entries not yet rewritten
+    pub(super) fn begin_key_rotation(
+        &self,
+        new_key: TypedSharedSecret,
+        previous_key: TypedSharedSecret,
+    ) {
+        let mut crypt = self.unlocked_inner.crypt.lock();
+        crypt.encrypt_info = Some(CryptInfo::new(new_key, TABLE_DB_FORMAT_CURRENT));
+        crypt.decrypt_info = Some(CryptInfo::new(new_key, TABLE_DB_FORMAT_CURRENT));
+        crypt.fallback_info = Some(CryptInfo::new(previous_key, TABLE_DB_FORMAT_CURRENT));
+    }
+
+    /// Rewrite every entry still encrypted with the previous device encryption key, then stop reading with it.
+    /// Writes are held off while this runs so none can be lost to the rewrite.
+    /// Returns the number of entries rewritten.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub(super) async fn finish_key_rotation(&self) -> VeilidAPIResult<u64> {
+        let _write_guard = self.unlocked_inner.write_lock.write().await;
+        if self.crypt().fallback_info.is_none() {
+            return Ok(0);
+        }
+        let count = self.reencrypt_in_place().await?;
+        self.unlocked_inner.crypt.lock().fallback_info = None;
+        Ok(count)
+    }
+
+    /// Keys in ordered columns are stored as plaintext so the database can compare them, all others are encrypted
+    fn encode_key(&self, crypt: &TableDBCrypt, col: u32, key: &[u8]) -> VeilidAPIResult<Vec<u8>> {
+        if self.unlocked_inner.ordered_columns.contains(&col) {
+            Ok(key.to_vec())
+        } else {
+            self.maybe_encrypt(crypt, col, key, None)
+        }
+    }
+
+    /// The key an entry is stored under if it has not yet been rewritten with the new device encryption key.
+    /// None if there is no key rotation in progress or the key is the same either way.
+    fn encode_fallback_key(
+        &self,
+        crypt: &TableDBCrypt,
+        col: u32,
+        key: &[u8],
+    ) -> VeilidAPIResult<Option<Vec<u8>>> {
+        let Some(fi) = &crypt.fallback_info else {
+            return Ok(None);
+        };
+        if self.unlocked_inner.ordered_columns.contains(&col) {
+            return Ok(None);
+        }
+        self.encrypt_entry(fi, col, key, None).map(Some)
+    }
+
+    fn decode_key(&self, crypt: &TableDBCrypt, col: u32, data: &[u8]) -> VeilidAPIResult<Vec<u8>> {
+        if self.unlocked_inner.ordered_columns.contains(&col) {
+            Ok(data.to_vec())
+        } else {
+            self.maybe_decrypt(crypt, col, data, None)
+        }
+    }
+
+    /// Record a key being stored in or deleted from a column, if the column's sorted keys are indexed
+    fn update_key_index(&self, col: u32, key: &[u8], present: bool) {
+        self.unlocked_inner
+            .key_index
+            .lock()
+            .update(col, key, present);
+    }
+
+    /// Read a page of keys from an ordered column that is not indexed, by reading all of its keys and keeping the
+    /// first `take` in the requested order. The column is indexed along the way if its keys fit.
+    async fn scan_key_page(
+        &self,
+        col: u32,
+        start: Bound<&[u8]>,
+        end: Bound<&[u8]>,
+        take: usize,
+        reverse: bool,
+    ) -> VeilidAPIResult<Vec<Vec<u8>>> {
+        let oversized = self
+            .unlocked_inner
+            .key_index
+            .lock()
+            .oversized
+            .contains(&col);
+
+        // Writes are held off while the keys are indexed so none are missed
+        let _write_guard = if oversized {
+            None
+        } else {
+            Some(self.unlocked_inner.write_lock.write().await)
+        };
+
+        let db = self.unlocked_inner.database.clone();
+        let mut page = BTreeSet::new();
+        let mut index = (!oversized).then(BTreeSet::new);
+        let mut index_bytes = 0usize;
+        db.iter_keys(col, None, |k| {
+            if let Some(keys) = &mut index {
+                index_bytes += KeyIndex::key_bytes(k);
+                if index_bytes > KEY_INDEX_MAX_BYTES {
+                    index = None;
+                } else {
+                    keys.insert(k.to_vec());
+                }
+            }
+            if RangeBounds::<[u8]>::contains(&(start, end), k.as_slice()) {
+                page.insert(k.to_vec());
+                if page.len() > take {
+                    if reverse {
+                        page.pop_first();
+                    } else {
+                        page.pop_last();
+                    }
+                }
+            }
+            Ok(Option::<()>::None)
+        })
+        .await
+        .map_err(VeilidAPIError::from)?;
+
+        {
+            let mut key_index = self.unlocked_inner.key_index.lock();
+            match index {
+                Some(keys) => key_index.insert_column(col, keys, index_bytes),
+                None => {
+                    key_index.oversized.insert(col);
+                }
+            }
+        }
+
+        Ok(if reverse {
+            page.into_iter().rev().collect()
+        } else {
+            page.into_iter().collect()
+        })
+    }
+
+    /// Associated data for an entry: the header, the column, and for values the plaintext key.
+    /// Binding these stops entries being moved between columns or keys without detection.
+    fn associated_data(header: &[u8], col: u32, key: Option<&[u8]>) -> Vec<u8> {
+        let mut ad =
+            Vec::with_capacity(header.len() + 4 + key.map(|k| k.len()).unwrap_or_default());
+        ad.extend_from_slice(header);
+        ad.extend_from_slice(&col.to_le_bytes());
+        if let Some(key) = key {
+            ad.extend_from_slice(key);
+        }
+        ad
+    }
+
+    /// Encrypt buffer using encrypt key, prepending a versioned header and the nonce to the output.
+    /// Keys are encrypted with `key` set to None, values with `key` set to the plaintext key they are stored under.
     /// Keyed nonces are unique because keys must be unique.
     /// Normally they must be sequential or random, but the critical.
     /// requirement is that they are different for each encryption
     /// but if the contents are guaranteed to be unique, then a nonce
     /// can be generated from the hash of the contents and the encryption key itself.
     #[instrument(level = "trace", target = "tstore", skip_all)]
-    fn maybe_encrypt(&self, data: &[u8], keyed_nonce: bool) -> Vec<u8> {
+    fn maybe_encrypt(
+        &self,
+        crypt: &TableDBCrypt,
+        col: u32,
+        data: &[u8],
+        key: Option<&[u8]>,
+    ) -> VeilidAPIResult<Vec<u8>> {
+        let Some(ei) = &crypt.encrypt_info else {
+            return Ok(compress_prepend_size(data));
+        };
+        self.encrypt_entry(ei, col, data, key)
+    }
+
+    fn encrypt_entry(
+        &self,
+        ei: &CryptInfo,
+        col: u32,
+        data: &[u8],
+        key: Option<&[u8]>,
+    ) -> VeilidAPIResult<Vec<u8>> {
         let data = compress_prepend_size(data);
-        if let Some(ei) = &self.unlocked_inner.encrypt_info {
-            let crypto = self.crypto();
-            let vcrypto = crypto.get(ei.typed_key.kind).unwrap();
-            let mut out = unsafe { unaligned_u8_vec_uninit(NONCE_LENGTH + data.len()) };
-
-            if keyed_nonce {
-                // Key content nonce
-                let mut noncedata = Vec::with_capacity(data.len() + PUBLIC_KEY_LENGTH);
-                noncedata.extend_from_slice(&data);
-                noncedata.extend_from_slice(&ei.typed_key.value.bytes);
-                let noncehash = vcrypto.generate_hash(&noncedata);
-                out[0..NONCE_LENGTH].copy_from_slice(&noncehash[0..NONCE_LENGTH])
-            } else {
-                // Random nonce
-                random_bytes(&mut out[0..NONCE_LENGTH]);
-            }
+        let crypto = self.crypto();
+        let vcrypto = crypto.get(ei.typed_key.kind).unwrap();
 
-            let (nonce, encout) = out.split_at_mut(NONCE_LENGTH);
-            vcrypto.crypt_b2b_no_auth(
-                &data,
-                encout,
-                &Nonce::try_from(&nonce[0..NONCE_LENGTH]).unwrap(),
-                &ei.typed_key.value,
-            );
-            out
+        let nonce = if key.is_none() {
+            // Key content nonce
+            let mut noncedata = Vec::with_capacity(data.len() + PUBLIC_KEY_LENGTH);
+            noncedata.extend_from_slice(&data);
+            noncedata.extend_from_slice(&ei.typed_key.value.bytes);
+            let noncehash = vcrypto.generate_hash(&noncedata);
+            Nonce::try_from(&noncehash[0..NONCE_LENGTH]).unwrap()
         } else {
-            data
-        }
+            // Random nonce
+            vcrypto.random_nonce()
+        };
+
+        let mut header = [0u8; TABLE_DB_HEADER_LENGTH];
+        header[0..TABLE_DB_MAGIC.len()].copy_from_slice(TABLE_DB_MAGIC);
+        header[TABLE_DB_MAGIC.len()] = TABLE_DB_FORMAT_CURRENT;
+
+        let ad = Self::associated_data(&header, col, key);
+        let encout = vcrypto.encrypt_aead(&data, &nonce, &ei.typed_key.value, Some(&ad))?;
+
+        let mut out = Vec::with_capacity(TABLE_DB_HEADER_LENGTH + NONCE_LENGTH + encout.len());
+        out.extend_from_slice(&header);
+        out.extend_from_slice(&nonce.bytes);
+        out.extend_from_slice(&encout);
+        Ok(out)
     }
 
-    /// Decrypt buffer using decrypt key with nonce prepended to input
+    /// Decrypt buffer using decrypt key, with the header and nonce prepended to input.
+    /// Keys are decrypted with `key` set to None, values with `key` set to the plaintext key they are stored under.
+    /// Entries that fail authentication return VeilidAPIError::Tampered.
+    /// During a key rotation, entries that do not decrypt with the new key are tried with the previous one.
     #[instrument(level = "trace", target = "tstore", skip_all)]
-    fn maybe_decrypt(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
-        if let Some(di) = &self.unlocked_inner.decrypt_info {
-            let crypto = self.crypto();
-            let vcrypto = crypto.get(di.typed_key.kind).unwrap();
-            assert!(data.len() >= NONCE_LENGTH);
+    fn maybe_decrypt(
+        &self,
+        crypt: &TableDBCrypt,
+        col: u32,
+        data: &[u8],
+        key: Option<&[u8]>,
+    ) -> VeilidAPIResult<Vec<u8>> {
+        let Some(di) = &crypt.decrypt_info else {
+            return decompress_size_prepended(data, None).map_err(VeilidAPIError::generic);
+        };
+        match (self.decrypt_entry(di, col, data, key), &crypt.fallback_info) {
+            (Err(_), Some(fi)) => self.decrypt_entry(fi, col, data, key),
+            (res, _) => res,
+        }
+    }
+
+    fn decrypt_entry(
+        &self,
+        di: &CryptInfo,
+        col: u32,
+        data: &[u8],
+        key: Option<&[u8]>,
+    ) -> VeilidAPIResult<Vec<u8>> {
+        let crypto = self.crypto();
+        let vcrypto = crypto.get(di.typed_key.kind).unwrap();
+
+        if di.format == TABLE_DB_FORMAT_LEGACY {
+            if data.len() < NONCE_LENGTH {
+                apibail_parse_error!("table entry is too short", data.len());
+            }
             if data.len() == NONCE_LENGTH {
                 return Ok(Vec::new());
             }
@@ -178,12 +530,87 @@ impl TableDB {
                 &Nonce::try_from(&data[0..NONCE_LENGTH]).unwrap(),
                 &di.typed_key.value,
             );
-            decompress_size_prepended(&out, None)
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
-        } else {
-            decompress_size_prepended(data, None)
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+            return decompress_size_prepended(&out, None).map_err(VeilidAPIError::generic);
+        }
+
+        if data.len() < TABLE_DB_HEADER_LENGTH + NONCE_LENGTH + vcrypto.aead_overhead() {
+            return Err(VeilidAPIError::tampered("table entry is truncated"));
+        }
+        let (header, rest) = data.split_at(TABLE_DB_HEADER_LENGTH);
+        if &header[0..TABLE_DB_MAGIC.len()] != TABLE_DB_MAGIC {
+            return Err(VeilidAPIError::tampered(
+                "table entry has an invalid header",
+            ));
+        }
+        let format = header[TABLE_DB_MAGIC.len()];
+        if format != TABLE_DB_FORMAT_AEAD {
+            return Err(VeilidAPIError::unimplemented(format!(
+                "unsupported table entry format version {}",
+                format
+            )));
+        }
+        let (nonce, encdata) = rest.split_at(NONCE_LENGTH);
+
+        let ad = Self::associated_data(header, col, key);
+        let out = vcrypto
+            .decrypt_aead(
+                encdata,
+                &Nonce::try_from(nonce).unwrap(),
+                &di.typed_key.value,
+                Some(&ad),
+            )
+            .map_err(|_| VeilidAPIError::tampered("table entry failed authentication"))?;
+        decompress_size_prepended(&out, None).map_err(VeilidAPIError::internal)
+    }
+
+    /// Decrypt every entry with the decryption key and format, and rewrite it with the encryption key in the current format.
+    /// All columns are rewritten in a single transaction, so if this is interrupted the table is left as it was.
+    /// Entries that already decrypt with the encryption key in the current format were rewritten by an earlier
+    /// run whose completion was not recorded, and are left alone.
+    /// Returns the number of entries rewritten.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub(super) async fn reencrypt_in_place(&self) -> VeilidAPIResult<u64> {
+        let db = self.unlocked_inner.database.clone();
+        let column_count = self.get_column_count()?;
+
+        let crypt = self.crypt();
+        let mut dbt = db.transaction();
+        let mut count = 0u64;
+        for col in 0..column_count {
+            let mut entries = Vec::<(Vec<u8>, Vec<u8>)>::new();
+            db.iter(col, None, |(k, v)| {
+                entries.push((k.to_vec(), v.to_vec()));
+                Ok(Option::<()>::None)
+            })
+            .await
+            .map_err(VeilidAPIError::from)?;
+
+            let ordered = self.unlocked_inner.ordered_columns.contains(&col);
+            for (k, v) in entries {
+                if let Some(ei) = &crypt.encrypt_info {
+                    let done = if ordered {
+                        self.decrypt_entry(ei, col, &v, Some(&k)).is_ok()
+                    } else {
+                        self.decrypt_entry(ei, col, &k, None).is_ok()
+                    };
+                    if done {
+                        continue;
+                    }
+                }
+                let key = self.decode_key(&crypt, col, &k)?;
+                let value = self.maybe_decrypt(&crypt, col, &v, Some(&key))?;
+                dbt.delete_owned(col, k);
+                dbt.put_owned(
+                    col,
+                    self.encode_key(&crypt, col, &key)?,
+                    self.maybe_encrypt(&crypt, col, &value, Some(&key))?,
+                );
+                count += 1;
+            }
         }
+
+        db.write(dbt).await.map_err(VeilidAPIError::generic)?;
+        Ok(count)
     }
 
     /// Get the list of keys in a column of the TableDB
@@ -196,14 +623,46 @@ impl TableDB {
             ));
         }
         let db = self.unlocked_inner.database.clone();
+        let crypt = self.crypt();
         let mut out = Vec::new();
-        db.iter_keys(col, None, |k| {
-            let key = self.maybe_decrypt(k)?;
-            out.push(key);
+        let mut decrypt_error = None;
+        db.iter_keys(col, None, |k| match self.decode_key(&crypt, col, k) {
+            Ok(key) => {
+                out.push(key);
+                Ok(Option::<()>::None)
+            }
+            Err(e) => {
+                decrypt_error = Some(e);
+                Ok(Some(()))
+            }
+        })
+        .await
+        .map_err(VeilidAPIError::from)?;
+        if let Some(e) = decrypt_error {
+            return Err(e);
+        }
+        Ok(out)
+    }
+
+    /// Get every key and value in a column of the TableDB
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub(super) async fn read_all(&self, col: u32) -> VeilidAPIResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        let db = self.unlocked_inner.database.clone();
+        let mut entries = Vec::<(Vec<u8>, Vec<u8>)>::new();
+        db.iter(col, None, |(k, v)| {
+            entries.push((k.to_vec(), v.to_vec()));
             Ok(Option::<()>::None)
         })
         .await
         .map_err(VeilidAPIError::from)?;
+
+        let crypt = self.crypt();
+        let mut out = Vec::with_capacity(entries.len());
+        for (k, v) in entries {
+            let key = self.decode_key(&crypt, col, &k)?;
+            let value = self.maybe_decrypt(&crypt, col, &v, Some(&key))?;
+            out.push((key, value));
+        }
         Ok(out)
     }
 
@@ -224,8 +683,7 @@ impl TableDB {
     /// Start a TableDB write transaction. The transaction object must be committed or rolled back before dropping.
     #[instrument(level = "trace", target = "tstore", skip_all)]
     pub fn transact(&self) -> TableDBTransaction {
-        let dbt = self.unlocked_inner.database.transaction();
-        TableDBTransaction::new(self.clone(), dbt)
+        TableDBTransaction::new(self.clone())
     }
 
     /// Store a key with a value in a column in the TableDB. Performs a single transaction immediately.
@@ -238,13 +696,20 @@ impl TableDB {
             ));
         }
         let db = self.unlocked_inner.database.clone();
+        let _write_guard = self.unlocked_inner.write_lock.read().await;
+        let crypt = self.crypt();
         let mut dbt = db.transaction();
-        dbt.put(
+        if let Some(fallback_key) = self.encode_fallback_key(&crypt, col, key)? {
+            dbt.delete_owned(col, fallback_key);
+        }
+        dbt.put_owned(
             col,
-            self.maybe_encrypt(key, true),
-            self.maybe_encrypt(value, false),
+            self.encode_key(&crypt, col, key)?,
+            self.maybe_encrypt(&crypt, col, value, Some(key))?,
         );
-        db.write(dbt).await.map_err(VeilidAPIError::generic)
+        db.write(dbt).await.map_err(VeilidAPIError::generic)?;
+        self.update_key_index(col, key, true);
+        Ok(())
     }
 
     /// Store a key in json format with a value in a column in the TableDB. Performs a single transaction immediately.
@@ -267,9 +732,19 @@ impl TableDB {
             ));
         }
         let db = self.unlocked_inner.database.clone();
-        let key = self.maybe_encrypt(key, true);
-        match db.get(col, &key).await.map_err(VeilidAPIError::from)? {
-            Some(v) => Ok(Some(self.maybe_decrypt(&v).map_err(VeilidAPIError::from)?)),
+        let crypt = self.crypt();
+        let enc_key = self.encode_key(&crypt, col, key)?;
+        let mut value = db.get(col, &enc_key).await.map_err(VeilidAPIError::from)?;
+        if value.is_none() {
+            if let Some(fallback_key) = self.encode_fallback_key(&crypt, col, key)? {
+                value = db
+                    .get(col, &fallback_key)
+                    .await
+                    .map_err(VeilidAPIError::from)?;
+            }
+        }
+        match value {
+            Some(v) => Ok(Some(self.maybe_decrypt(&crypt, col, &v, Some(key))?)),
             None => Ok(None),
         }
     }
@@ -296,12 +771,27 @@ impl TableDB {
                 col, self.opened_column_count
             ));
         }
-        let key = self.maybe_encrypt(key, true);
+        let _write_guard = self.unlocked_inner.write_lock.read().await;
+        let crypt = self.crypt();
+        let enc_key = self.encode_key(&crypt, col, key)?;
 
         let db = self.unlocked_inner.database.clone();
 
-        match db.delete(col, &key).await.map_err(VeilidAPIError::from)? {
-            Some(v) => Ok(Some(self.maybe_decrypt(&v).map_err(VeilidAPIError::from)?)),
+        let mut old_value = db
+            .delete(col, &enc_key)
+            .await
+            .map_err(VeilidAPIError::from)?;
+        if old_value.is_none() {
+            if let Some(fallback_key) = self.encode_fallback_key(&crypt, col, key)? {
+                old_value = db
+                    .delete(col, &fallback_key)
+                    .await
+                    .map_err(VeilidAPIError::from)?;
+            }
+        }
+        self.update_key_index(col, key, false);
+        match old_value {
+            Some(v) => Ok(Some(self.maybe_decrypt(&crypt, col, &v, Some(key))?)),
             None => Ok(None),
         }
     }
@@ -318,13 +808,144 @@ impl TableDB {
         };
         Ok(old_value)
     }
+
+    /// Read the entries of an ordered column whose keys fall within a range, in key order or reverse key order.
+    /// The backing store can't seek, so a column's keys are kept sorted in memory while they fit in a bounded index,
+    /// and larger columns have all of their keys read for each page. At most `limit` entries are read. If there are
+    /// more, [TableDBPage::next] is set and the following page is fetched by passing it as an excluded `start`
+    /// (or excluded `end` when `reverse` is set).
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub async fn iter_range(
+        &self,
+        col: u32,
+        start: Bound<&[u8]>,
+        end: Bound<&[u8]>,
+        limit: Option<usize>,
+        reverse: bool,
+    ) -> VeilidAPIResult<TableDBPage> {
+        if col >= self.opened_column_count {
+            apibail_generic!(format!(
+                "Column exceeds opened column count {} >= {}",
+                col, self.opened_column_count
+            ));
+        }
+        if !self.unlocked_inner.ordered_columns.contains(&col) {
+            apibail_invalid_argument!("column is not ordered", "col", col);
+        }
+        if limit == Some(0) {
+            apibail_invalid_argument!("limit must be greater than zero", "limit", 0);
+        }
+
+        // BTreeSet::range panics on an empty or inverted range, which can't hold any keys anyway
+        let empty = match (start, end) {
+            (Bound::Included(s), Bound::Included(e)) => s > e,
+            (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e)) => {
+                s >= e
+            }
+            _ => false,
+        };
+        if empty {
+            return Ok(TableDBPage {
+                entries: Vec::new(),
+                next: None,
+            });
+        }
+
+        // Select the keys of the page, taking one more key than needed to see if there are more
+        let take = limit
+            .map(|limit| limit.saturating_add(1))
+            .unwrap_or(usize::MAX);
+        let indexed_keys = self
+            .unlocked_inner
+            .key_index
+            .lock()
+            .page(col, start, end, take, reverse);
+        let mut keys = match indexed_keys {
+            Some(keys) => keys,
+            None => self.scan_key_page(col, start, end, take, reverse).await?,
+        };
+        let next = match limit {
+            Some(limit) if keys.len() > limit => {
+                keys.truncate(limit);
+                keys.last().cloned()
+            }
+            _ => None,
+        };
+
+        let db = self.unlocked_inner.database.clone();
+        let crypt = self.crypt();
+        let mut entries = Vec::with_capacity(keys.len());
+        for k in keys {
+            // Skip keys deleted since the page was selected
+            let Some(v) = db.get(col, &k).await.map_err(VeilidAPIError::from)? else {
+                continue;
+            };
+            let value = self.maybe_decrypt(&crypt, col, &v, Some(&k))?;
+            entries.push((k, value));
+        }
+        Ok(TableDBPage { entries, next })
+    }
+
+    /// Read the entries of an ordered column whose keys start with a prefix, in key order or reverse key order.
+    /// Pass the previous page's [TableDBPage::next] as `after` to continue where it left off.
+    #[instrument(level = "trace", target = "tstore", skip_all)]
+    pub async fn iter_prefix(
+        &self,
+        col: u32,
+        prefix: &[u8],
+        after: Option<&[u8]>,
+        limit: Option<usize>,
+        reverse: bool,
+    ) -> VeilidAPIResult<TableDBPage> {
+        // The first key past every key with this prefix, if there is one
+        let prefix_end = {
+            let mut prefix_end = prefix.to_vec();
+            while prefix_end.last() == Some(&0xFF) {
+                prefix_end.pop();
+            }
+            if let Some(last) = prefix_end.last_mut() {
+                *last += 1;
+                Some(prefix_end)
+            } else {
+                None
+            }
+        };
+
+        let mut start = Bound::Included(prefix);
+        let mut end = match &prefix_end {
+            Some(prefix_end) => Bound::Excluded(prefix_end.as_slice()),
+            None => Bound::Unbounded,
+        };
+        if let Some(after) = after {
+            if reverse {
+                end = Bound::Excluded(after);
+            } else {
+                start = Bound::Excluded(after);
+            }
+        }
+
+        self.iter_range(col, start, end, limit, reverse).await
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+enum TableDBTransactionOp {
+    Put {
+        col: u32,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    Delete {
+        col: u32,
+        key: Vec<u8>,
+    },
+}
+
 struct TableDBTransactionInner {
     registry: VeilidComponentRegistry,
-    dbt: Option<DBTransaction>,
+    // Kept in plaintext and encrypted at commit, with whichever key the table is using then
+    ops: Option<Vec<TableDBTransactionOp>>,
 }
 
 impl fmt::Debug for TableDBTransactionInner {
@@ -332,8 +953,8 @@ impl fmt::Debug for TableDBTransactionInner {
         write!(
             f,
             "TableDBTransactionInner({})",
-            match &self.dbt {
-                Some(dbt) => format!("len={}", dbt.ops.len()),
+            match &self.ops {
+                Some(ops) => format!("len={}", ops.len()),
                 None => "".to_owned(),
             }
         )
@@ -342,7 +963,7 @@ impl fmt::Debug for TableDBTransactionInner {
 
 impl Drop for TableDBTransactionInner {
     fn drop(&mut self) {
-        if self.dbt.is_some() {
+        if self.ops.is_some() {
             let registry = &self.registry;
             veilid_log!(registry warn "Dropped transaction without commit or rollback");
         }
@@ -358,13 +979,13 @@ pub struct TableDBTransaction {
 }
 
 impl TableDBTransaction {
-    fn new(db: TableDB, dbt: DBTransaction) -> Self {
+    fn new(db: TableDB) -> Self {
         let registry = db.registry();
         Self {
             db,
             inner: Arc::new(Mutex::new(TableDBTransactionInner {
                 registry,
-                dbt: Some(dbt),
+                ops: Some(Vec::new()),
             })),
         }
     }
@@ -372,25 +993,61 @@ impl TableDBTransaction {
     /// Commit the transaction. Performs all actions atomically.
     #[instrument(level = "trace", target = "tstore", skip_all)]
     pub async fn commit(self) -> VeilidAPIResult<()> {
-        let dbt = {
+        let ops = {
             let mut inner = self.inner.lock();
             inner
-                .dbt
+                .ops
                 .take()
                 .ok_or_else(|| VeilidAPIError::generic("transaction already completed"))?
         };
 
-        let db = self.db.unlocked_inner.database.clone();
-        db.write(dbt)
-            .await
-            .map_err(|e| VeilidAPIError::generic(format!("commit failed, transaction lost: {}", e)))
+        let tdb = &self.db;
+        let db = tdb.unlocked_inner.database.clone();
+        let _write_guard = tdb.unlocked_inner.write_lock.read().await;
+        let crypt = tdb.crypt();
+        let mut dbt = db.transaction();
+        // Keys of ordered columns stored (true) or deleted (false), in order
+        let mut ordered_keys = Vec::<(u32, Vec<u8>, bool)>::new();
+        for op in ops {
+            match op {
+                TableDBTransactionOp::Put { col, key, value } => {
+                    if let Some(fallback_key) = tdb.encode_fallback_key(&crypt, col, &key)? {
+                        dbt.delete_owned(col, fallback_key);
+                    }
+                    dbt.put_owned(
+                        col,
+                        tdb.encode_key(&crypt, col, &key)?,
+                        tdb.maybe_encrypt(&crypt, col, &value, Some(&key))?,
+                    );
+                    if tdb.unlocked_inner.ordered_columns.contains(&col) {
+                        ordered_keys.push((col, key, true));
+                    }
+                }
+                TableDBTransactionOp::Delete { col, key } => {
+                    if let Some(fallback_key) = tdb.encode_fallback_key(&crypt, col, &key)? {
+                        dbt.delete_owned(col, fallback_key);
+                    }
+                    dbt.delete_owned(col, tdb.encode_key(&crypt, col, &key)?);
+                    if tdb.unlocked_inner.ordered_columns.contains(&col) {
+                        ordered_keys.push((col, key, false));
+                    }
+                }
+            }
+        }
+        db.write(dbt).await.map_err(|e| {
+            VeilidAPIError::generic(format!("commit failed, transaction lost: {}", e))
+        })?;
+        for (col, key, present) in ordered_keys {
+            tdb.update_key_index(col, &key, present);
+        }
+        Ok(())
     }
 
     /// Rollback the transaction. Does nothing to the TableDB.
     #[instrument(level = "trace", target = "tstore", skip_all)]
     pub fn rollback(self) {
         let mut inner = self.inner.lock();
-        inner.dbt = None;
+        inner.ops = None;
     }
 
     /// Store a key with a value in a column in the TableDB
@@ -403,10 +1060,16 @@ impl TableDBTransaction {
             ));
         }
 
-        let key = self.db.maybe_encrypt(key, true);
-        let value = self.db.maybe_encrypt(value, false);
         let mut inner = self.inner.lock();
-        inner.dbt.as_mut().unwrap().put_owned(col, key, value);
+        inner
+            .ops
+            .as_mut()
+            .ok_or_else(|| VeilidAPIError::generic("transaction already completed"))?
+            .push(TableDBTransactionOp::Put {
+                col,
+                key: key.to_vec(),
+                value: value.to_vec(),
+            });
         Ok(())
     }
 
@@ -430,9 +1093,15 @@ impl TableDBTransaction {
             ));
         }
 
-        let key = self.db.maybe_encrypt(key, true);
         let mut inner = self.inner.lock();
-        inner.dbt.as_mut().unwrap().delete_owned(col, key);
+        inner
+            .ops
+            .as_mut()
+            .ok_or_else(|| VeilidAPIError::generic("transaction already completed"))?
+            .push(TableDBTransactionOp::Delete {
+                col,
+                key: key.to_vec(),
+            });
         Ok(())
     }
 }
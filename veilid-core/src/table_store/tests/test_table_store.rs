@@ -1,5 +1,6 @@
+use crate::table_store::*;
 use crate::tests::test_veilid_config::*;
-use crate::*;
+use core::ops::Bound;
 use futures_util::StreamExt as _;
 
 async fn startup() -> VeilidAPI {
@@ -320,6 +321,651 @@ pub async fn test_store_load_json_many(ts: &TableStore) {
     trace!("test_store_load_json_many duration={}", (end_ts - start_ts));
 }
 
+async fn raw_table(ts: &TableStore, name: &str, column_count: u32) -> Database {
+    let real_name = ts
+        .name_get(name)
+        .await
+        .expect("should get name")
+        .expect("should exist");
+    ts.table_store_driver
+        .open(&real_name, column_count)
+        .await
+        .expect("should open raw table")
+}
+
+async fn raw_entries(raw: &Database, col: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut out = Vec::new();
+    raw.iter(col, None, |(k, v)| {
+        out.push((k.to_vec(), v.to_vec()));
+        Ok(Option::<()>::None)
+    })
+    .await
+    .expect("should iterate");
+    out
+}
+
+pub async fn test_tamper(ts: &TableStore) {
+    trace!("test_tamper");
+
+    let _ = ts.delete("test").await;
+    let db = ts.open("test", 2).await.expect("should have opened");
+    db.store(0, b"foo", b"1234567890").await.unwrap();
+    assert_eq!(
+        db.load(0, b"foo").await.unwrap(),
+        Some(b"1234567890".to_vec())
+    );
+
+    // Flip a bit in the stored value
+    let raw = raw_table(ts, "test", 2).await;
+    let entries = raw_entries(&raw, 0).await;
+    assert_eq!(entries.len(), 1);
+    let (k, mut v) = entries[0].clone();
+    let last = v.len() - 1;
+    v[last] ^= 1;
+    let mut dbt = raw.transaction();
+    dbt.put_owned(0, k.clone(), v);
+    raw.write(dbt).await.unwrap();
+    assert!(matches!(
+        db.load(0, b"foo").await,
+        Err(VeilidAPIError::Tampered { .. })
+    ));
+
+    // Truncate it
+    let mut dbt = raw.transaction();
+    dbt.put_owned(0, k.clone(), vec![0u8; 8]);
+    raw.write(dbt).await.unwrap();
+    assert!(matches!(
+        db.load(0, b"foo").await,
+        Err(VeilidAPIError::Tampered { .. })
+    ));
+
+    // Swap values between keys
+    db.store(0, b"foo", b"1234567890").await.unwrap();
+    db.store(0, b"bar", b"abcdefghij").await.unwrap();
+    let entries = raw_entries(&raw, 0).await;
+    assert_eq!(entries.len(), 2);
+    let mut dbt = raw.transaction();
+    dbt.put_owned(0, entries[0].0.clone(), entries[1].1.clone());
+    dbt.put_owned(0, entries[1].0.clone(), entries[0].1.clone());
+    raw.write(dbt).await.unwrap();
+    assert!(matches!(
+        db.load(0, b"foo").await,
+        Err(VeilidAPIError::Tampered { .. })
+    ));
+
+    // Move an entry to another column
+    let mut dbt = raw.transaction();
+    dbt.put_owned(1, entries[0].0.clone(), entries[0].1.clone());
+    raw.write(dbt).await.unwrap();
+    assert!(matches!(
+        db.get_keys(1).await,
+        Err(VeilidAPIError::Tampered { .. })
+    ));
+
+    drop(raw);
+    drop(db);
+    let _ = ts.delete("test").await;
+}
+
+pub async fn test_legacy_migration(ts: &TableStore) {
+    trace!("test_legacy_migration");
+
+    let _ = ts.delete("test").await;
+    let db = ts.open("test", 2).await.expect("should have opened");
+    drop(db);
+
+    // Write entries the way older versions did: nonce followed by unauthenticated ciphertext
+    let raw = raw_table(ts, "test", 2).await;
+    let encryption_key = ts.inner.lock().encryption_key.expect("should have key");
+    let crypto = ts.crypto();
+    let vcrypto = crypto.get(encryption_key.kind).unwrap();
+    let legacy_encrypt = |data: &[u8], keyed_nonce: bool| {
+        let data = compress_prepend_size(data);
+        let nonce = if keyed_nonce {
+            let mut noncedata = data.clone();
+            noncedata.extend_from_slice(&encryption_key.value.bytes);
+            let noncehash = vcrypto.generate_hash(&noncedata);
+            Nonce::try_from(&noncehash[0..NONCE_LENGTH]).unwrap()
+        } else {
+            vcrypto.random_nonce()
+        };
+        let mut out = vec![0u8; NONCE_LENGTH + data.len()];
+        out[0..NONCE_LENGTH].copy_from_slice(&nonce.bytes);
+        vcrypto.crypt_b2b_no_auth(
+            &data,
+            &mut out[NONCE_LENGTH..],
+            &nonce,
+            &encryption_key.value,
+        );
+        out
+    };
+    let mut dbt = raw.transaction();
+    dbt.put_owned(
+        0,
+        legacy_encrypt(b"foo", true),
+        legacy_encrypt(b"1234", false),
+    );
+    dbt.put_owned(
+        1,
+        legacy_encrypt(b"bar", true),
+        legacy_encrypt(b"5678", false),
+    );
+    raw.write(dbt).await.unwrap();
+    drop(raw);
+
+    let real_name = ts.name_get("test").await.unwrap().unwrap();
+    ts.inner
+        .lock()
+        .all_table_formats
+        .insert(real_name.clone(), TABLE_DB_FORMAT_LEGACY);
+
+    // Opening migrates in place
+    let db = ts.open("test", 2).await.expect("should have opened");
+    assert_eq!(
+        ts.inner.lock().all_table_formats.get(&real_name).copied(),
+        Some(TABLE_DB_FORMAT_CURRENT)
+    );
+    assert_eq!(db.load(0, b"foo").await.unwrap(), Some(b"1234".to_vec()));
+    assert_eq!(db.load(1, b"bar").await.unwrap(), Some(b"5678".to_vec()));
+    assert_eq!(db.get_keys(0).await.unwrap(), vec![b"foo".to_vec()]);
+    assert_eq!(db.get_key_count(1).await.unwrap(), 1);
+    drop(db);
+
+    // Migrating again after an unrecorded completion leaves entries alone
+    ts.inner
+        .lock()
+        .all_table_formats
+        .insert(real_name.clone(), TABLE_DB_FORMAT_LEGACY);
+    let db = ts.open("test", 2).await.expect("should have opened");
+    assert_eq!(db.load(0, b"foo").await.unwrap(), Some(b"1234".to_vec()));
+    assert_eq!(db.load(1, b"bar").await.unwrap(), Some(b"5678".to_vec()));
+    drop(db);
+
+    let _ = ts.delete("test").await;
+}
+
+pub async fn test_ordered(ts: &TableStore) {
+    trace!("test_ordered");
+
+    let _ = ts.delete("test").await;
+    let db = ts
+        .open_ordered("test", 3, &[1])
+        .await
+        .expect("should have opened");
+    assert_eq!(db.ordered_columns(), BTreeSet::from([1]));
+
+    // Unordered columns can't be iterated in order
+    assert!(db
+        .iter_range(0, Bound::Unbounded, Bound::Unbounded, None, false)
+        .await
+        .is_err());
+
+    for n in (0u32..50).rev() {
+        db.store(1, format!("msg/{:04}", n).as_bytes(), &n.to_le_bytes())
+            .await
+            .unwrap();
+    }
+    db.store(1, b"other", b"x").await.unwrap();
+    db.store(1, b"msg0", b"y").await.unwrap();
+    assert_eq!(
+        db.load(1, b"msg/0007").await.unwrap(),
+        Some(7u32.to_le_bytes().to_vec())
+    );
+
+    let keys_of = |page: &TableDBPage| {
+        page.entries
+            .iter()
+            .map(|(k, _)| String::from_utf8(k.clone()).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    // Whole prefix
+    let page = db.iter_prefix(1, b"msg/", None, None, false).await.unwrap();
+    assert_eq!(page.entries.len(), 50);
+    assert_eq!(page.next, None);
+    assert_eq!(page.entries[3].1, 3u32.to_le_bytes().to_vec());
+    let keys = keys_of(&page);
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert_eq!(keys, sorted);
+
+    // Paginate forward
+    let mut after = None;
+    let mut seen = Vec::new();
+    loop {
+        let page = db
+            .iter_prefix(1, b"msg/", after.as_deref(), Some(15), false)
+            .await
+            .unwrap();
+        assert!(page.entries.len() <= 15);
+        seen.extend(keys_of(&page));
+        match page.next {
+            Some(next) => after = Some(next),
+            None => break,
+        }
+    }
+    assert_eq!(seen, keys);
+
+    // Latest first
+    let page = db
+        .iter_prefix(1, b"msg/", None, Some(3), true)
+        .await
+        .unwrap();
+    assert_eq!(keys_of(&page), vec!["msg/0049", "msg/0048", "msg/0047"]);
+    assert_eq!(page.next, Some(b"msg/0047".to_vec()));
+    let page = db
+        .iter_prefix(1, b"msg/", page.next.as_deref(), Some(2), true)
+        .await
+        .unwrap();
+    assert_eq!(keys_of(&page), vec!["msg/0046", "msg/0045"]);
+
+    // Range
+    let page = db
+        .iter_range(
+            1,
+            Bound::Included(b"msg/0010".as_slice()),
+            Bound::Excluded(b"msg/0013".as_slice()),
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(keys_of(&page), vec!["msg/0010", "msg/0011", "msg/0012"]);
+
+    // Ordering more columns requires closing the table, and the column must be empty
+    db.store(2, b"foo", b"bar").await.unwrap();
+    assert!(ts.open_ordered("test", 3, &[2]).await.is_err());
+    drop(db);
+    assert!(ts.open_ordered("test", 3, &[2]).await.is_err());
+    let db = ts
+        .open_ordered("test", 3, &[0])
+        .await
+        .expect("should have opened");
+    assert_eq!(db.ordered_columns(), BTreeSet::from([0, 1]));
+    drop(db);
+
+    // Ordering persists
+    let db = ts.open("test", 3).await.expect("should have opened");
+    assert_eq!(db.ordered_columns(), BTreeSet::from([0, 1]));
+    assert_eq!(db.get_key_count(1).await.unwrap(), 52);
+    drop(db);
+
+    let _ = ts.delete("test").await;
+}
+
+pub async fn test_ordered_paging(ts: &TableStore) {
+    trace!("test_ordered_paging");
+
+    let _ = ts.delete("test").await;
+    let db = ts
+        .open_ordered("test", 2, &[1])
+        .await
+        .expect("should have opened");
+
+    // Written before the first page is read, in a transaction, and after
+    let dbt = db.transact();
+    for n in (0u32..300).step_by(2) {
+        dbt.store(1, format!("k/{:05}", n).as_bytes(), &n.to_le_bytes())
+            .unwrap();
+    }
+    dbt.commit().await.unwrap();
+    let page = db
+        .iter_prefix(1, b"k/", None, Some(7), false)
+        .await
+        .unwrap();
+    assert_eq!(page.entries.len(), 7);
+    assert_eq!(page.next, Some(b"k/00012".to_vec()));
+    for n in (1u32..300).step_by(2) {
+        db.store(1, format!("k/{:05}", n).as_bytes(), &n.to_le_bytes())
+            .await
+            .unwrap();
+    }
+    db.delete(1, b"k/00100").await.unwrap();
+    let dbt = db.transact();
+    dbt.delete(1, b"k/00200").unwrap();
+    dbt.commit().await.unwrap();
+
+    let expected = (0u32..300)
+        .filter(|n| *n != 100 && *n != 200)
+        .collect::<Vec<_>>();
+
+    let read_pages = |reverse: bool| {
+        let db = db.clone();
+        async move {
+            let mut after = None;
+            let mut pages = 0;
+            let mut seen = Vec::new();
+            loop {
+                let page = db
+                    .iter_prefix(1, b"k/", after.as_deref(), Some(32), reverse)
+                    .await
+                    .unwrap();
+                assert!(page.entries.len() <= 32);
+                pages += 1;
+                for (k, v) in page.entries {
+                    let n = u32::from_le_bytes(v.try_into().unwrap());
+                    assert_eq!(k, format!("k/{:05}", n).into_bytes());
+                    seen.push(n);
+                }
+                match page.next {
+                    Some(next) => after = Some(next),
+                    None => break,
+                }
+            }
+            (pages, seen)
+        }
+    };
+
+    let (pages, seen) = read_pages(false).await;
+    assert_eq!(pages, 10);
+    assert_eq!(seen, expected);
+    let (pages, mut seen) = read_pages(true).await;
+    assert_eq!(pages, 10);
+    seen.reverse();
+    assert_eq!(seen, expected);
+
+    // Empty and inverted ranges
+    let page = db
+        .iter_range(
+            1,
+            Bound::Excluded(b"k/00050".as_slice()),
+            Bound::Excluded(b"k/00050".as_slice()),
+            Some(5),
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(page.entries.is_empty());
+    let page = db
+        .iter_range(
+            1,
+            Bound::Included(b"k/00060".as_slice()),
+            Bound::Included(b"k/00050".as_slice()),
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(page.entries.is_empty());
+    drop(db);
+
+    // Reopening reads the sorted keys back from the database
+    let db = ts.open("test", 2).await.expect("should have opened");
+    let page = db
+        .iter_prefix(1, b"k/", Some(b"k/00297"), None, false)
+        .await
+        .unwrap();
+    assert_eq!(page.entries.len(), 2);
+    assert_eq!(page.next, None);
+    drop(db);
+
+    let _ = ts.delete("test").await;
+}
+
+pub async fn test_ordered_paging_unindexed(ts: &TableStore) {
+    trace!("test_ordered_paging_unindexed");
+
+    let _ = ts.delete("test").await;
+    let db = ts
+        .open_ordered("test", 2, &[1])
+        .await
+        .expect("should have opened");
+
+    // More key bytes than the sorted key index will hold
+    let pad = vec![b'x'; 64 * 1024];
+    let key = |n: u32| {
+        let mut k = format!("k/{:05}/", n).into_bytes();
+        k.extend_from_slice(&pad);
+        k
+    };
+    let count = (KEY_INDEX_MAX_BYTES / pad.len()) as u32 + 8;
+    let dbt = db.transact();
+    for n in 0..count {
+        dbt.store(1, &key(n), &n.to_le_bytes()).unwrap();
+    }
+    dbt.commit().await.unwrap();
+
+    for reverse in [false, true] {
+        let mut after = None;
+        let mut seen = Vec::new();
+        loop {
+            let page = db
+                .iter_prefix(1, b"k/", after.as_deref(), Some(10), reverse)
+                .await
+                .unwrap();
+            assert!(page.entries.len() <= 10);
+            for (k, v) in page.entries {
+                let n = u32::from_le_bytes(v.try_into().unwrap());
+                assert_eq!(k, key(n));
+                seen.push(n);
+            }
+            match page.next {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+        if reverse {
+            seen.reverse();
+        }
+        assert_eq!(seen, (0..count).collect::<Vec<_>>());
+    }
+
+    // After enough deletes the column fits again, and pages keep up with later writes
+    for n in 8..count {
+        db.delete(1, &key(n)).await.unwrap();
+    }
+    let page = db.iter_prefix(1, b"k/", None, None, false).await.unwrap();
+    assert_eq!(page.entries.len(), 8);
+    db.store(1, b"k/", b"first").await.unwrap();
+    db.delete(1, &key(3)).await.unwrap();
+    let page_keys = |page: &TableDBPage| {
+        page.entries
+            .iter()
+            .map(|(k, _)| k.clone())
+            .collect::<Vec<_>>()
+    };
+    let page = db
+        .iter_prefix(1, b"k/", None, Some(3), false)
+        .await
+        .unwrap();
+    assert_eq!(page_keys(&page), vec![b"k/".to_vec(), key(0), key(1)]);
+    assert_eq!(page.next, Some(key(1)));
+    let page = db
+        .iter_prefix(1, b"k/", page.next.as_deref(), None, false)
+        .await
+        .unwrap();
+    assert_eq!(
+        page_keys(&page),
+        vec![key(2), key(4), key(5), key(6), key(7)]
+    );
+    assert_eq!(page.next, None);
+    drop(db);
+
+    let _ = ts.delete("test").await;
+}
+
+pub async fn test_export_import(ts: &TableStore) {
+    trace!("test_export_import");
+
+    let _ = ts.delete("test").await;
+    let _ = ts.delete("test2").await;
+    let db = ts
+        .open_ordered("test", 2, &[1])
+        .await
+        .expect("should have opened");
+    db.store(0, b"foo", b"1234").await.unwrap();
+    db.store(1, b"a/1", b"5678").await.unwrap();
+    db.store(1, b"a/2", b"90").await.unwrap();
+    drop(db);
+    let db2 = ts.open("test2", 1).await.expect("should have opened");
+    db2.store(0, b"bar", b"baz").await.unwrap();
+    drop(db2);
+    assert!(ts.table_names().contains(&"test".to_owned()));
+
+    assert!(ts.export(&["test".to_owned()], "").await.is_err());
+    assert!(ts.export(&["nonexistent".to_owned()], "pw").await.is_err());
+    let archive = ts
+        .export(&["test".to_owned(), "test2".to_owned()], "correct horse")
+        .await
+        .expect("should export");
+
+    // Wrong password or a modified archive
+    assert!(matches!(
+        ts.import(&archive, "wrong horse").await,
+        Err(VeilidAPIError::Tampered { .. })
+    ));
+    let mut modified = archive.clone();
+    let last = modified.len() - 1;
+    modified[last] ^= 1;
+    assert!(matches!(
+        ts.import(&modified, "correct horse").await,
+        Err(VeilidAPIError::Tampered { .. })
+    ));
+
+    // Change the tables so the import is observable
+    let db = ts.open("test", 2).await.expect("should have opened");
+    db.store(0, b"foo", b"changed").await.unwrap();
+    db.store(0, b"extra", b"x").await.unwrap();
+    drop(db);
+
+    let imported = ts
+        .import(&archive, "correct horse")
+        .await
+        .expect("should import");
+    assert_eq!(imported, vec!["test".to_owned(), "test2".to_owned()]);
+
+    // Nothing changes until the next startup
+    let db = ts.open("test", 2).await.expect("should have opened");
+    assert_eq!(db.load(0, b"foo").await.unwrap(), Some(b"changed".to_vec()));
+    drop(db);
+    ts.apply_pending_imports().await;
+
+    let db = ts.open("test", 2).await.expect("should have opened");
+    assert_eq!(db.load(0, b"foo").await.unwrap(), Some(b"1234".to_vec()));
+    assert_eq!(db.load(0, b"extra").await.unwrap(), None);
+    assert_eq!(db.ordered_columns(), BTreeSet::from([1]));
+    let page = db.iter_prefix(1, b"a/", None, None, false).await.unwrap();
+    assert_eq!(
+        page.entries,
+        vec![
+            (b"a/1".to_vec(), b"5678".to_vec()),
+            (b"a/2".to_vec(), b"90".to_vec())
+        ]
+    );
+    drop(db);
+    let db2 = ts.open("test2", 1).await.expect("should have opened");
+    assert_eq!(db2.load(0, b"bar").await.unwrap(), Some(b"baz".to_vec()));
+    drop(db2);
+
+    let _ = ts.delete("test").await;
+    let _ = ts.delete("test2").await;
+}
+
+pub async fn test_key_rotation(ts: &TableStore) {
+    trace!("test_key_rotation");
+
+    let _ = ts.delete("test").await;
+    let _ = ts.delete("test2").await;
+    let db = ts
+        .open_ordered("test", 2, &[1])
+        .await
+        .expect("should have opened");
+    db.store(0, b"foo", b"1234").await.unwrap();
+    db.store(1, b"a/1", b"5678").await.unwrap();
+    let db2 = ts.open("test2", 1).await.expect("should have opened");
+    db2.store(0, b"bar", b"baz").await.unwrap();
+    drop(db2);
+
+    // A transaction started before the rotation is written with the new key
+    let tx = db.transact();
+    tx.store(0, b"tx", b"before").unwrap();
+
+    let first_key = ts.inner.lock().encryption_key.unwrap();
+    let mut reports = Vec::new();
+    ts.rotate_device_encryption_key(|p| reports.push(p))
+        .await
+        .expect("should rotate");
+    tx.commit().await.unwrap();
+
+    let second_key = ts.inner.lock().encryption_key.unwrap();
+    assert_ne!(first_key, second_key);
+    assert!(ts.inner.lock().previous_encryption_key.is_none());
+    assert_eq!(
+        ts.load_device_encryption_key(DEVICE_ENCRYPTION_KEY)
+            .await
+            .unwrap(),
+        Some(second_key)
+    );
+    assert_eq!(
+        ts.load_device_encryption_key(NEXT_DEVICE_ENCRYPTION_KEY)
+            .await
+            .unwrap(),
+        None
+    );
+    assert!(!reports.is_empty());
+    for (n, report) in reports.iter().enumerate() {
+        assert_eq!(report.tables_done, n + 1);
+        assert_eq!(report.tables_total, reports.len());
+    }
+    assert!(reports.iter().any(|r| r.table == db.table_name()));
+
+    // The table that stayed open is still readable and writable
+    assert_eq!(db.load(0, b"foo").await.unwrap(), Some(b"1234".to_vec()));
+    assert_eq!(db.load(0, b"tx").await.unwrap(), Some(b"before".to_vec()));
+    assert_eq!(db.get_keys(0).await.unwrap().len(), 2);
+    let page = db.iter_prefix(1, b"a/", None, None, false).await.unwrap();
+    assert_eq!(page.entries, vec![(b"a/1".to_vec(), b"5678".to_vec())]);
+    db.store(0, b"foo", b"after").await.unwrap();
+    assert_eq!(db.load(0, b"foo").await.unwrap(), Some(b"after".to_vec()));
+    let db2 = ts.open("test2", 1).await.expect("should have opened");
+    assert_eq!(db2.load(0, b"bar").await.unwrap(), Some(b"baz".to_vec()));
+    let test2_table = db2.table_name();
+    drop(db2);
+
+    // Interrupt a rotation after one table has been rewritten, then finish it as at startup
+    let mut shared_secret = SharedSecret::default();
+    random_bytes(&mut shared_secret.bytes);
+    let third_key = TypedSharedSecret::new(second_key.kind, shared_secret);
+    ts.save_device_encryption_key(NEXT_DEVICE_ENCRYPTION_KEY, Some(third_key))
+        .await
+        .unwrap();
+    {
+        let _async_guard = ts.async_lock.lock().await;
+        ts.begin_device_encryption_key_rotation(third_key, second_key);
+        ts.rotate_table(&test2_table).await.unwrap();
+    }
+    {
+        let mut inner = ts.inner.lock();
+        inner.encryption_key = Some(second_key);
+        inner.previous_encryption_key = None;
+    }
+    drop(db);
+    {
+        let _async_guard = ts.async_lock.lock().await;
+        ts.resume_device_encryption_key_rotation(third_key)
+            .await
+            .expect("should resume");
+    }
+    assert_eq!(ts.inner.lock().encryption_key, Some(third_key));
+    assert_eq!(
+        ts.load_device_encryption_key(NEXT_DEVICE_ENCRYPTION_KEY)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let db = ts.open("test", 2).await.expect("should have opened");
+    assert_eq!(db.load(0, b"foo").await.unwrap(), Some(b"after".to_vec()));
+    assert_eq!(db.load(0, b"tx").await.unwrap(), Some(b"before".to_vec()));
+    drop(db);
+    let db2 = ts.open("test2", 1).await.expect("should have opened");
+    assert_eq!(db2.load(0, b"bar").await.unwrap(), Some(b"baz".to_vec()));
+    drop(db2);
+
+    let _ = ts.delete("test").await;
+    let _ = ts.delete("test2").await;
+}
+
 pub async fn test_all() {
     let api = startup().await;
     let crypto = api.crypto().unwrap();
@@ -334,6 +980,13 @@ pub async fn test_all() {
         test_store_delete_load(&ts).await;
         test_transaction(&ts).await;
         test_json(&vcrypto, &ts).await;
+        test_tamper(&ts).await;
+        test_legacy_migration(&ts).await;
+        test_ordered(&ts).await;
+        test_ordered_paging(&ts).await;
+        test_ordered_paging_unindexed(&ts).await;
+        test_export_import(&ts).await;
+        test_key_rotation(&ts).await;
         let _ = ts.delete("test").await;
     }
 
@@ -1,3 +1,4 @@
+pub mod test_app_rpc;
 pub mod test_dht;
 pub mod test_protected_store;
 pub mod test_veilid_config;
@@ -0,0 +1,242 @@
+use super::test_veilid_config::*;
+use crate::*;
+
+const TEST_NAMESPACE: u32 = 7;
+
+app_rpc_method!(TestEcho = "test.echo", String => String);
+app_rpc_method!(TestBig = "test.big", u32 => String);
+app_rpc_method!(TestSlow = "test.slow", u32 => u32);
+app_rpc_method!(TestReserved = "rpc.test", () => ());
+
+fn app_call(namespace: u32, request: &AppRpcRequest) -> VeilidAppCall {
+    VeilidAppCall::new(
+        None,
+        None,
+        namespace,
+        serialize_json_bytes(request),
+        OperationId::new(get_random_u64()),
+    )
+}
+
+async fn call(server: &AppRpcServer, request: AppRpcRequest) -> AppRpcResponse {
+    let answer = server
+        .answer_app_call(&app_call(server.namespace(), &request))
+        .await
+        .expect("request should be answered");
+    let response: AppRpcResponse = deserialize_json_bytes(&answer).unwrap();
+    assert_eq!(response.id, request.id);
+    response
+}
+
+fn new_server(api: &VeilidAPI) -> AppRpcServer {
+    let server = AppRpcServer::new(api.clone(), TEST_NAMESPACE);
+    server
+        .register::<TestEcho, _, _>(|_ctx, request| async move { Ok(request) })
+        .unwrap();
+    server
+        .register::<TestBig, _, _>(|_ctx, len| async move { Ok("x".repeat(len as usize)) })
+        .unwrap();
+    server
+}
+
+pub fn test_register(api: VeilidAPI) {
+    let server = new_server(&api);
+
+    // Reserved names are refused
+    assert!(matches!(
+        server.register::<TestReserved, _, _>(|_ctx, _request| async move { Ok(()) }),
+        Err(VeilidAPIError::InvalidArgument { .. })
+    ));
+
+    // A method can only be registered once
+    assert!(matches!(
+        server.register::<TestEcho, _, _>(|_ctx, request| async move { Ok(request) }),
+        Err(VeilidAPIError::InvalidArgument { .. })
+    ));
+
+    let names: Vec<String> = server
+        .describe()
+        .methods
+        .into_iter()
+        .map(|m| m.name)
+        .collect();
+    assert_eq!(names, vec!["test.big".to_owned(), "test.echo".to_owned()]);
+
+    // Once unregistered it can be registered again
+    assert!(server.unregister::<TestEcho>());
+    assert!(!server.unregister::<TestEcho>());
+    server
+        .register::<TestEcho, _, _>(|_ctx, request| async move { Ok(request) })
+        .unwrap();
+}
+
+pub async fn test_dispatch(api: VeilidAPI) {
+    let server = new_server(&api);
+
+    let response = call(
+        &server,
+        AppRpcRequest::new(1, TestEcho::NAME, serde_json::json!("hello")),
+    )
+    .await;
+    assert_eq!(response.into_result(), Ok(serde_json::json!("hello")));
+
+    // Unknown method
+    let response = call(
+        &server,
+        AppRpcRequest::new(2, "test.missing", serde_json::Value::Null),
+    )
+    .await;
+    assert_eq!(
+        response.into_result(),
+        Err(AppRpcError::method_not_found("test.missing"))
+    );
+
+    // Parameters of the wrong type
+    let response = call(
+        &server,
+        AppRpcRequest::new(3, TestEcho::NAME, serde_json::json!(42)),
+    )
+    .await;
+    assert!(matches!(
+        response.into_result(),
+        Err(AppRpcError::InvalidRequest { .. })
+    ));
+
+    // Version mismatch
+    let mut request = AppRpcRequest::new(4, TestEcho::NAME, serde_json::json!("hello"));
+    request.rpc = APP_RPC_VERSION + 1;
+    let response = call(&server, request).await;
+    assert!(matches!(
+        response.into_result(),
+        Err(AppRpcError::InvalidRequest { .. })
+    ));
+
+    // The describe method is always served
+    let response = call(
+        &server,
+        AppRpcRequest::new(5, APP_RPC_DESCRIBE_METHOD, serde_json::Value::Null),
+    )
+    .await;
+    let description: AppRpcServiceDescription =
+        serde_json::from_value(response.into_result().unwrap()).unwrap();
+    assert_eq!(description.namespace, TEST_NAMESPACE);
+    assert_eq!(description.methods.len(), 2);
+
+    // Responses too large for an app call reply are replaced with an error
+    let response = call(
+        &server,
+        AppRpcRequest::new(6, TestBig::NAME, serde_json::json!(100)),
+    )
+    .await;
+    assert_eq!(
+        response.into_result(),
+        Ok(serde_json::json!("x".repeat(100)))
+    );
+    let request = AppRpcRequest::new(
+        7,
+        TestBig::NAME,
+        serde_json::json!(MAX_APP_RPC_RESPONSE_LEN as u32),
+    );
+    let answer = server
+        .answer_app_call(&app_call(TEST_NAMESPACE, &request))
+        .await
+        .unwrap();
+    assert!(answer.len() <= MAX_APP_RPC_RESPONSE_LEN);
+    let response: AppRpcResponse = deserialize_json_bytes(&answer).unwrap();
+    assert_eq!(response.id, 7);
+    assert!(matches!(
+        response.into_result(),
+        Err(AppRpcError::Remote {
+            error: VeilidAPIError::Internal { .. }
+        })
+    ));
+
+    // Calls to other namespaces and other messages are left alone
+    let request = AppRpcRequest::new(8, TestEcho::NAME, serde_json::json!("hello"));
+    assert!(server
+        .answer_app_call(&app_call(TEST_NAMESPACE + 1, &request))
+        .await
+        .is_none());
+    let not_rpc = VeilidAppCall::new(
+        None,
+        None,
+        TEST_NAMESPACE,
+        b"hello".to_vec(),
+        OperationId::new(get_random_u64()),
+    );
+    assert!(server.answer_app_call(&not_rpc).await.is_none());
+}
+
+pub async fn test_limits(api: VeilidAPI) {
+    let server = AppRpcServer::new_with_limits(api.clone(), TEST_NAMESPACE, 1, 250);
+    server
+        .register::<TestSlow, _, _>(|_ctx, ms| async move {
+            sleep(ms).await;
+            Ok(ms)
+        })
+        .unwrap();
+
+    // Handlers that finish in time are answered
+    let response = call(
+        &server,
+        AppRpcRequest::new(1, TestSlow::NAME, serde_json::json!(10)),
+    )
+    .await;
+    assert_eq!(response.into_result(), Ok(serde_json::json!(10)));
+
+    // Handlers that run past the deadline are answered with a timeout
+    let response = call(
+        &server,
+        AppRpcRequest::new(2, TestSlow::NAME, serde_json::json!(1000)),
+    )
+    .await;
+    assert_eq!(
+        response.into_result(),
+        Err(AppRpcError::remote(VeilidAPIError::timeout()))
+    );
+
+    // A request beyond the concurrency limit is turned away while the slot is busy
+    let (slow, busy) = futures_util::future::join(
+        call(
+            &server,
+            AppRpcRequest::new(3, TestSlow::NAME, serde_json::json!(100)),
+        ),
+        async {
+            sleep(20).await;
+            call(
+                &server,
+                AppRpcRequest::new(4, TestSlow::NAME, serde_json::json!(0)),
+            )
+            .await
+        },
+    )
+    .await;
+    assert_eq!(slow.into_result(), Ok(serde_json::json!(100)));
+    assert!(matches!(
+        busy.into_result(),
+        Err(AppRpcError::Remote {
+            error: VeilidAPIError::TryAgain { .. }
+        })
+    ));
+
+    // The slot is given back when the request finishes
+    let response = call(
+        &server,
+        AppRpcRequest::new(5, TestSlow::NAME, serde_json::json!(0)),
+    )
+    .await;
+    assert_eq!(response.into_result(), Ok(serde_json::json!(0)));
+}
+
+pub async fn test_all() {
+    let (update_callback, config_callback) = setup_veilid_core();
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+
+    test_register(api.clone());
+    test_dispatch(api.clone()).await;
+    test_limits(api.clone()).await;
+
+    api.shutdown().await;
+}
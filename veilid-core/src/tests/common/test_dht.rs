@@ -78,27 +78,30 @@ pub async fn test_get_dht_record_key(api: VeilidAPI) {
     let rc = api.routing_context().unwrap();
 
     let crypto = api.crypto().unwrap();
-    let cs = crypto.get(CRYPTO_KIND_VLD0).unwrap();
-    let owner_keypair = cs.generate_keypair();
-    let schema = DHTSchema::dflt(1).unwrap();
-
-    // create the record normally
-    let rec = rc
-        .create_dht_record(schema.clone(), Some(owner_keypair), Some(CRYPTO_KIND_VLD0))
-        .await
-        .unwrap();
-
-    // recreate the record key from the metadata alone
-    let key = rc
-        .get_dht_record_key(schema.clone(), &owner_keypair.key, Some(CRYPTO_KIND_VLD0))
-        .unwrap();
-
-    // keys should be the same
-    assert_eq!(key, *rec.key());
-
-    let dht_key = *rec.key();
-    rc.close_dht_record(dht_key).await.unwrap();
-    rc.delete_dht_record(dht_key).await.unwrap();
+    for kind in VALID_CRYPTO_KINDS {
+        let cs = crypto.get(kind).unwrap();
+        let owner_keypair = cs.generate_keypair();
+        let schema = DHTSchema::dflt(1).unwrap();
+
+        // create the record normally
+        let rec = rc
+            .create_dht_record(schema.clone(), Some(owner_keypair), Some(kind))
+            .await
+            .unwrap();
+        assert_eq!(rec.key().kind, kind);
+
+        // recreate the record key from the metadata alone
+        let key = rc
+            .get_dht_record_key(schema.clone(), &owner_keypair.key, Some(kind))
+            .unwrap();
+
+        // keys should be the same
+        assert_eq!(key, *rec.key());
+
+        let dht_key = *rec.key();
+        rc.close_dht_record(dht_key).await.unwrap();
+        rc.delete_dht_record(dht_key).await.unwrap();
+    }
 }
 
 pub async fn test_get_dht_value_nonexistent(api: VeilidAPI) {
@@ -360,6 +363,7 @@ pub async fn test_all() {
     test_get_dht_value_nonexistent(api.clone()).await;
     test_create_delete_dht_record_simple(api.clone()).await;
     test_create_dht_record_with_owner(api.clone()).await;
+    test_get_dht_record_key(api.clone()).await;
     test_set_get_dht_value(api.clone()).await;
     test_open_writer_dht_value(api.clone()).await;
 
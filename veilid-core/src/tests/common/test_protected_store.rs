@@ -60,10 +60,76 @@ pub fn test_protected_store(ps: &ProtectedStore) {
     let _ = ps.remove_user_secret("_test_broken");
 }
 
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn file_vault_config(vault_password: &str, delete: bool) -> VeilidConfig {
+    VeilidConfig {
+        program_name: "VeilidCoreTests".into(),
+        namespace: "file_vault".into(),
+        table_store: VeilidConfigTableStore {
+            directory: get_table_store_path(),
+            delete: true,
+        },
+        block_store: VeilidConfigBlockStore {
+            directory: get_block_store_path(),
+            delete: true,
+        },
+        protected_store: VeilidConfigProtectedStore {
+            directory: get_protected_store_path(),
+            delete,
+            backend: VeilidConfigProtectedStoreBackend::FileVault,
+            vault_password: vault_password.to_owned(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn test_file_vault() {
+    info!("testing protected store file vault");
+
+    let api = api_startup_config(
+        Arc::new(|_: VeilidUpdate| {}),
+        file_vault_config("correct horse", true),
+    )
+    .await
+    .expect("startup failed");
+    let ps = api.protected_store().unwrap();
+    test_protected_store(&ps);
+    assert!(!ps.save_user_secret("_test_key", &[5u8, 6u8]).unwrap());
+    shutdown(api).await;
+
+    // Secrets survive a restart with the same password
+    let api = api_startup_config(
+        Arc::new(|_: VeilidUpdate| {}),
+        file_vault_config("correct horse", false),
+    )
+    .await
+    .expect("startup failed");
+    let ps = api.protected_store().unwrap();
+    assert_eq!(
+        ps.load_user_secret("_test_key").unwrap(),
+        Some(vec![5u8, 6u8])
+    );
+    assert!(ps.remove_user_secret("_test_key").unwrap());
+    shutdown(api).await;
+
+    // The vault can not be opened with the wrong password
+    assert!(api_startup_config(
+        Arc::new(|_: VeilidUpdate| {}),
+        file_vault_config("wrong horse", false),
+    )
+    .await
+    .is_err());
+}
+
 pub async fn test_all() {
     let api = startup().await;
     let ps = api.protected_store().unwrap();
     test_protected_store(&ps);
 
     shutdown(api).await;
+
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    test_file_vault().await;
 }
@@ -194,6 +194,9 @@ pub fn config_callback(key: String) -> ConfigCallbackReturn {
         "protected_store.new_device_encryption_key_password" => {
             Ok(Box::new(Option::<String>::None))
         }
+        "protected_store.backend" => Ok(Box::new(VeilidConfigProtectedStoreBackend::Keyring)),
+        "protected_store.vault_password" => Ok(Box::new("".to_owned())),
+        "protected_store.external_command" => Ok(Box::new(Vec::<String>::new())),
         "network.connection_initial_timeout_ms" => Ok(Box::new(2_000u32)),
         "network.connection_inactivity_timeout_ms" => Ok(Box::new(60_000u32)),
         "network.max_connections_per_ip4" => Ok(Box::new(32u32)),
@@ -232,6 +235,14 @@ pub fn config_callback(key: String) -> ConfigCallbackReturn {
         "network.rpc.timeout_ms" => Ok(Box::new(5_000u32)),
         "network.rpc.max_route_hop_count" => Ok(Box::new(4u8)),
         "network.rpc.default_route_hop_count" => Ok(Box::new(1u8)),
+        "network.rpc.queue_share_control" => Ok(Box::new(10u32)),
+        "network.rpc.queue_share_routing" => Ok(Box::new(30u32)),
+        "network.rpc.queue_share_dht" => Ok(Box::new(40u32)),
+        "network.rpc.queue_share_app" => Ok(Box::new(20u32)),
+        "network.rpc.queue_weight_control" => Ok(Box::new(8u32)),
+        "network.rpc.queue_weight_routing" => Ok(Box::new(4u32)),
+        "network.rpc.queue_weight_dht" => Ok(Box::new(2u32)),
+        "network.rpc.queue_weight_app" => Ok(Box::new(2u32)),
         "network.dht.max_find_node_count" => Ok(Box::new(20u32)),
         "network.dht.resolve_node_timeout_ms" => Ok(Box::new(10_000u32)),
         "network.dht.resolve_node_count" => Ok(Box::new(1u32)),
@@ -340,6 +351,12 @@ pub fn test_config() {
         inner.protected_store.new_device_encryption_key_password,
         Option::<String>::None
     );
+    assert_eq!(
+        inner.protected_store.backend,
+        VeilidConfigProtectedStoreBackend::Keyring
+    );
+    assert_eq!(inner.protected_store.vault_password, "".to_owned());
+    assert!(inner.protected_store.external_command.is_empty());
     assert_eq!(inner.network.connection_initial_timeout_ms, 2_000u32);
     assert_eq!(inner.network.connection_inactivity_timeout_ms, 60_000u32);
     assert_eq!(inner.network.max_connections_per_ip4, 32u32);
@@ -355,6 +372,14 @@ pub fn test_config() {
     assert_eq!(inner.network.rpc.timeout_ms, 5_000u32);
     assert_eq!(inner.network.rpc.max_route_hop_count, 4u8);
     assert_eq!(inner.network.rpc.default_route_hop_count, 1u8);
+    assert_eq!(inner.network.rpc.queue_share_control, 10u32);
+    assert_eq!(inner.network.rpc.queue_share_routing, 30u32);
+    assert_eq!(inner.network.rpc.queue_share_dht, 40u32);
+    assert_eq!(inner.network.rpc.queue_share_app, 20u32);
+    assert_eq!(inner.network.rpc.queue_weight_control, 8u32);
+    assert_eq!(inner.network.rpc.queue_weight_routing, 4u32);
+    assert_eq!(inner.network.rpc.queue_weight_dht, 2u32);
+    assert_eq!(inner.network.rpc.queue_weight_app, 2u32);
     assert_eq!(inner.network.routing_table.node_id.len(), 0);
     assert_eq!(inner.network.routing_table.node_id_secret.len(), 0);
     #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
@@ -444,7 +469,63 @@ pub fn test_config() {
     assert_eq!(inner.network.virtual_network.server_address, "");
 }
 
+pub fn test_config_reload() {
+    let vc = get_config();
+
+    let mut new_config = vc.get().clone();
+    new_config.network.dht.get_value_timeout_ms = 20_000u32;
+    new_config.network.routing_table.bootstrap = vec!["bootstrap.example.com".to_owned()];
+    new_config.network.protocol.ws.listen = false;
+    new_config.network.rpc.concurrency = 8u32;
+    new_config.network.rpc.default_route_hop_count = 2u8;
+    new_config.network.detect_address_changes = false;
+
+    assert_eq!(
+        vc.get().changed_keys(&new_config).unwrap(),
+        vec![
+            "network.detect_address_changes".to_owned(),
+            "network.dht.get_value_timeout_ms".to_owned(),
+            "network.protocol.ws.listen".to_owned(),
+            "network.routing_table.bootstrap".to_owned(),
+            "network.rpc.concurrency".to_owned(),
+            "network.rpc.default_route_hop_count".to_owned(),
+        ]
+    );
+
+    let (report, reload_storage_limits) = vc.apply_reload(&new_config).unwrap();
+    assert_eq!(
+        report.applied,
+        vec![
+            "network.dht.get_value_timeout_ms".to_owned(),
+            "network.protocol.ws.listen".to_owned(),
+            "network.routing_table.bootstrap".to_owned(),
+        ]
+    );
+    assert!(report.network_restarted);
+    assert_eq!(
+        report.restart_required,
+        vec![
+            "network.detect_address_changes".to_owned(),
+            "network.rpc.concurrency".to_owned(),
+            "network.rpc.default_route_hop_count".to_owned(),
+        ]
+    );
+    assert!(!reload_storage_limits);
+
+    let inner = vc.get();
+    assert_eq!(inner.network.dht.get_value_timeout_ms, 20_000u32);
+    assert_eq!(
+        inner.network.routing_table.bootstrap,
+        vec!["bootstrap.example.com".to_owned()]
+    );
+    assert!(!inner.network.protocol.ws.listen);
+    assert_eq!(inner.network.rpc.concurrency, 0u32);
+    assert_eq!(inner.network.rpc.default_route_hop_count, 1u8);
+    assert!(inner.network.detect_address_changes);
+}
+
 #[expect(clippy::unused_async)]
 pub async fn test_all() {
     test_config();
+    test_config_reload();
 }
@@ -204,6 +204,85 @@ pub async fn test_attach_detach_multiple() {
     }
 }
 
+pub async fn test_suspend_resume() {
+    trace!("test_suspend_resume: starting");
+    let (update_callback, config_callback) = setup_veilid_core();
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+    let routing_table = api.core_context().unwrap().routing_table();
+    let attachment_state = || async { api.get_state().await.unwrap().attachment.state };
+
+    trace!("test_suspend_resume: --- suspend without attach ---");
+    assert!(api.suspend().await.is_err());
+    assert!(routing_table.take_suspend_state().await.unwrap().is_none());
+
+    trace!("test_suspend_resume: --- suspend and resume ---");
+    api.attach().await.unwrap();
+    sleep(2000).await;
+    api.suspend().await.unwrap();
+    assert_eq!(attachment_state().await, AttachmentState::Detached);
+    api.resume().await.unwrap();
+    sleep(500).await;
+    assert_eq!(attachment_state().await, AttachmentState::Resuming);
+    // The saved state is only used once
+    assert!(routing_table.take_suspend_state().await.unwrap().is_none());
+    assert!(api.resume().await.is_err());
+
+    trace!("test_suspend_resume: --- suspend then detach ---");
+    api.suspend().await.unwrap();
+    assert!(api.detach().await.is_err());
+    // A resume after a detach starts from scratch
+    api.resume().await.unwrap();
+    sleep(500).await;
+    assert_ne!(attachment_state().await, AttachmentState::Resuming);
+
+    trace!("test_suspend_resume: --- suspend then attach ---");
+    api.suspend().await.unwrap();
+    api.attach().await.unwrap();
+    assert!(routing_table.take_suspend_state().await.unwrap().is_none());
+
+    api.detach().await.unwrap();
+    drop(routing_table);
+    api.shutdown().await;
+
+    trace!("test_suspend_resume: finished");
+}
+
+pub async fn test_power_profile() {
+    trace!("test_power_profile: starting");
+    let (update_callback, config_callback) = setup_veilid_core();
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+    let attachment_manager = api.core_context().unwrap().attachment_manager();
+    let task = TickTask::<EyreReport>::new_ms("test_power_profile", 0);
+    task.set_routine(|_stop_token, _last_ts, _cur_ts| Box::pin(async { Ok(()) }));
+
+    trace!("test_power_profile: --- a task that never ran is not held back ---");
+    api.set_power_profile(PowerProfile::LowPower).unwrap();
+    assert!(attachment_manager.power_profile_allows_tick(&task, 60));
+
+    trace!("test_power_profile: --- low power holds the task back for its interval ---");
+    task.tick().await.unwrap();
+    assert!(!attachment_manager.power_profile_allows_tick(&task, 60));
+    sleep(1100).await;
+    assert!(!attachment_manager.power_profile_allows_tick(&task, 60));
+    assert!(attachment_manager.power_profile_allows_tick(&task, 1));
+
+    trace!("test_power_profile: --- normal lets the task run ---");
+    api.set_power_profile(PowerProfile::Normal).unwrap();
+    assert_eq!(api.power_profile().unwrap(), PowerProfile::Normal);
+    task.tick().await.unwrap();
+    assert!(attachment_manager.power_profile_allows_tick(&task, 60));
+
+    task.stop().await.unwrap();
+    drop(attachment_manager);
+    api.shutdown().await;
+
+    trace!("test_power_profile: finished");
+}
+
 pub async fn test_all() {
     test_startup_shutdown().await;
     test_startup_shutdown_from_config().await;
@@ -211,4 +290,6 @@ pub async fn test_all() {
     test_startup_shutdown_multiple().await;
     test_startup_shutdown_from_config_multiple().await;
     test_attach_detach_multiple().await;
+    test_suspend_resume().await;
+    test_power_profile().await;
 }
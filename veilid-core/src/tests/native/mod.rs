@@ -14,6 +14,8 @@ pub async fn run_all_tests() {
     test_veilid_core::test_all().await;
     info!("TEST: test_veilid_config");
     test_veilid_config::test_all().await;
+    info!("TEST: test_app_rpc");
+    test_app_rpc::test_all().await;
     info!("TEST: test_connection_table");
     test_connection_table::test_all().await;
     info!("TEST: test_signed_node_info");
@@ -117,6 +119,8 @@ cfg_if! {
 
         run_test!(test_veilid_config);
 
+        run_test!(test_app_rpc);
+
         run_test!(test_connection_table);
 
         run_test!(test_signed_node_info);
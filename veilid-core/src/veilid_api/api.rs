@@ -182,6 +182,102 @@ impl VeilidAPI {
         })
     }
 
+    /// Get a snapshot of internal counters for export to monitoring systems.
+    pub async fn get_metrics(&self) -> VeilidAPIResult<VeilidMetrics> {
+        let registry = self.core_context()?.registry();
+        let network_manager = registry.network_manager();
+
+        let rpc = registry.rpc_processor().get_metrics();
+        let dht = registry.storage_manager().get_metrics().await;
+        let relay = network_manager.get_relay_metrics();
+        let punishments = network_manager.address_filter().get_metrics();
+
+        Ok(VeilidMetrics {
+            rpc,
+            dht,
+            relay,
+            punishments,
+        })
+    }
+
+    /// Check the node's connectivity and report problems with suggested fixes.
+    ///
+    /// Looks at the network class, dial info validation and NAT detection results, UPnP port
+    /// mappings, relay health, how well the routing table buckets are filled, and envelopes
+    /// rejected for clock skew. Findings are sorted with the most severe first.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), skip_all, err)]
+    pub fn diagnose(&self) -> VeilidAPIResult<VeilidDiagnostics> {
+        veilid_log!(self debug
+            "VeilidAPI::diagnose()");
+
+        let registry = self.core_context()?.registry();
+        let attachment = registry.attachment_manager().get_attachment_state();
+
+        let mut findings = Vec::new();
+        if matches!(
+            attachment,
+            AttachmentState::Detached | AttachmentState::Detaching
+        ) {
+            findings.push(VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Error,
+                DiagnosticKind::NotAttached,
+                "The node is not attached to the network",
+                "Attach to the network",
+            ));
+        } else {
+            if matches!(
+                attachment,
+                AttachmentState::Attaching | AttachmentState::Resuming
+            ) {
+                findings.push(VeilidDiagnosticFinding::new(
+                    DiagnosticSeverity::Info,
+                    DiagnosticKind::Attaching,
+                    "The node is still attaching, so some checks may not have results yet",
+                    "Run the diagnosis again once the node is attached",
+                ));
+            }
+            findings.extend(registry.network_manager().diagnose());
+            findings.extend(registry.routing_table().diagnose());
+        }
+        findings.sort_by_key(|f| core::cmp::Reverse(f.severity));
+
+        Ok(VeilidDiagnostics {
+            timestamp: Timestamp::now(),
+            attachment,
+            findings,
+        })
+    }
+
+    /// Apply a new configuration to the running node.
+    ///
+    /// Keys that are read at runtime take effect immediately, and changes to the protocol
+    /// settings restart the low level network while staying attached. Keys that are only
+    /// read during startup keep their old values and are listed in the report, so the
+    /// application can decide whether a full restart is warranted.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), skip_all, err)]
+    pub async fn reload_config(
+        &self,
+        config: VeilidConfig,
+    ) -> VeilidAPIResult<VeilidConfigReloadReport> {
+        veilid_log!(self debug
+            "VeilidAPI::reload_config()");
+
+        let registry = self.core_context()?.registry();
+        let (report, reload_storage_limits) = registry.config().apply_reload(&config)?;
+
+        if reload_storage_limits {
+            registry.storage_manager().reload_limits().await;
+        }
+        if report.network_restarted {
+            let network_manager = registry.network_manager();
+            if network_manager.network_is_started() {
+                network_manager.restart_network();
+            }
+        }
+
+        Ok(report)
+    }
+
     /// Connect to the network.
     #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), skip_all, ret, err)]
     pub async fn attach(&self) -> VeilidAPIResult<()> {
@@ -208,6 +304,63 @@ impl VeilidAPI {
         Ok(())
     }
 
+    /// Disconnect from the network, keeping enough state to reconnect quickly with [VeilidAPI::resume].
+    ///
+    /// The current relay, the peers that are known to be live, the published private routes and the
+    /// outbound DHT watches are saved to the table store, so they survive the application being
+    /// backgrounded. The saved state is discarded by [VeilidAPI::attach], [VeilidAPI::detach] and
+    /// shutting down, and a resume more than an hour later starts from scratch.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), skip_all, ret, err)]
+    pub async fn suspend(&self) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "VeilidAPI::suspend()");
+
+        let attachment_manager = self.core_context()?.attachment_manager();
+        if !Box::pin(attachment_manager.suspend()).await {
+            apibail_generic!("Already detached");
+        }
+        Ok(())
+    }
+
+    /// Reconnect to the network after a [VeilidAPI::suspend].
+    ///
+    /// Instead of rebuilding from scratch, the saved relay, peers, routes and watches are revalidated
+    /// in parallel while the attachment state reports [AttachmentState::Resuming]. If nothing was
+    /// saved this behaves like [VeilidAPI::attach].
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), skip_all, ret, err)]
+    pub async fn resume(&self) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "VeilidAPI::resume()");
+
+        let attachment_manager = self.core_context()?.attachment_manager();
+        if !Box::pin(attachment_manager.resume()).await {
+            apibail_generic!("Already attached");
+        }
+        Ok(())
+    }
+
+    /// Get the power profile the node is currently using.
+    pub fn power_profile(&self) -> VeilidAPIResult<PowerProfile> {
+        let attachment_manager = self.core_context()?.attachment_manager();
+        Ok(attachment_manager.power_profile())
+    }
+
+    /// Select a power profile.
+    ///
+    /// [PowerProfile::LowPower] cuts back background network maintenance to save battery while
+    /// staying reachable through the current relay. Switching back to [PowerProfile::Normal]
+    /// restores full maintenance on the next tick. The profile can be changed whether or not
+    /// the node is attached.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), skip(self), ret, err)]
+    pub fn set_power_profile(&self, power_profile: PowerProfile) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "VeilidAPI::set_power_profile(power_profile: {:?})", power_profile);
+
+        let attachment_manager = self.core_context()?.attachment_manager();
+        attachment_manager.set_power_profile(power_profile);
+        Ok(())
+    }
+
     ////////////////////////////////////////////////////////////////
     // Routing Context
 
@@ -370,6 +523,46 @@ impl VeilidAPI {
         Ok(())
     }
 
+    ////////////////////////////////////////////////////////////////
+    // Key Derivation
+
+    /// Generate a new random seed phrase.
+    ///
+    /// Every keypair derived from the phrase with [VeilidAPI::derive_keypair] can be restored from it later,
+    /// so it should be shown to the user to write down and then forgotten.
+    #[must_use]
+    pub fn generate_seed_phrase(&self) -> String {
+        generate_seed_phrase()
+    }
+
+    /// Derive a keypair deterministically from a seed phrase.
+    ///
+    /// * `kind` - the cryptosystem to derive the keypair for.
+    /// * `seed_phrase` - a seed phrase such as one made by [VeilidAPI::generate_seed_phrase].
+    /// * `passphrase` - an optional extra passphrase, which may be empty. Different passphrases derive unrelated keys.
+    /// * `path` - which keypair to derive, such as [DerivationPath::node_id], [DerivationPath::app_identity] or [DerivationPath::record_writer].
+    ///
+    /// The same inputs always give the same keypair, so record ownership can be restored from the seed phrase alone.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), skip(self, seed_phrase, passphrase), err)]
+    pub async fn derive_keypair(
+        &self,
+        kind: CryptoKind,
+        seed_phrase: &str,
+        passphrase: &str,
+        path: &DerivationPath,
+    ) -> VeilidAPIResult<TypedKeyPair> {
+        veilid_log!(self debug
+            "VeilidAPI::derive_keypair(kind: {}, path: {})", kind, path);
+
+        let crypto = self.crypto()?;
+        let Some(vcrypto) = crypto.get_async(kind) else {
+            apibail_invalid_argument!("unsupported cryptosystem", "kind", kind);
+        };
+        let seed = seed_from_phrase(seed_phrase, passphrase)?;
+        let keypair = vcrypto.derive_keypair(&seed, path).await?;
+        Ok(TypedKeyPair::new(kind, keypair))
+    }
+
     ////////////////////////////////////////////////////////////////
     // App Calls
 
@@ -0,0 +1,134 @@
+use super::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Calls methods served by an [AppRpcServer] on a remote node.
+///
+/// Requests are sent with [RoutingContext::app_call_with_options()], so they use the routing context's safety
+/// selection and app namespace, and may target a private route, or a node id with the `footgun` feature.
+/// Clones share the request id counter.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct AppRpcClient {
+    routing_context: RoutingContext,
+    next_request_id: Arc<AtomicU64>,
+}
+
+impl AppRpcClient {
+    pub fn new(routing_context: RoutingContext) -> Self {
+        Self {
+            routing_context,
+            next_request_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    /// The routing context requests are sent with.
+    pub fn routing_context(&self) -> RoutingContext {
+        self.routing_context.clone()
+    }
+
+    /// Call a method with the default call options.
+    pub async fn call<M: AppRpcMethod>(
+        &self,
+        target: Target,
+        request: M::Request,
+    ) -> Result<M::Response, AppRpcError> {
+        self.call_with_options::<M>(target, request, CallOptions::default())
+            .await
+    }
+
+    /// Call a method with a deadline and cancellation handle.
+    pub async fn call_with_options<M: AppRpcMethod>(
+        &self,
+        target: Target,
+        request: M::Request,
+        options: CallOptions,
+    ) -> Result<M::Response, AppRpcError> {
+        let params = to_json_value(&request, AppRpcError::invalid_request)?;
+        let result = self.call_raw(target, M::NAME, params, options).await?;
+        from_json_value(result, AppRpcError::invalid_response)
+    }
+
+    /// Get the methods the server serves, with the json schemas of their requests and responses.
+    pub async fn describe(&self, target: Target) -> Result<AppRpcServiceDescription, AppRpcError> {
+        let result = self
+            .call_raw(
+                target,
+                APP_RPC_DESCRIBE_METHOD,
+                serde_json::Value::Null,
+                CallOptions::default(),
+            )
+            .await?;
+        from_json_value(result, AppRpcError::invalid_response)
+    }
+
+    /// Call a method by name with already encoded params.
+    pub async fn call_raw(
+        &self,
+        target: Target,
+        method: &str,
+        params: serde_json::Value,
+        options: CallOptions,
+    ) -> Result<serde_json::Value, AppRpcError> {
+        let id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let message = serialize_json_bytes(AppRpcRequest::new(id, method, params));
+
+        let answer = Box::pin(
+            self.routing_context
+                .app_call_with_options(target, message, options),
+        )
+        .await
+        .map_err(AppRpcError::transport)?;
+
+        decode_response(id, &answer)
+    }
+}
+
+/// Decode the answer to the request with id `id`
+fn decode_response(id: u64, answer: &[u8]) -> Result<serde_json::Value, AppRpcError> {
+    let response =
+        deserialize_json_bytes::<AppRpcResponse>(answer).map_err(AppRpcError::invalid_response)?;
+    if response.id != id {
+        return Err(AppRpcError::invalid_response(format!(
+            "response id {} does not match request id {}",
+            response.id, id
+        )));
+    }
+    response.into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_response() {
+        let answer = serialize_json_bytes(AppRpcResponse::new(7, Ok(serde_json::json!("hello"))));
+        assert_eq!(decode_response(7, &answer), Ok(serde_json::json!("hello")));
+
+        // An answer to some other request is rejected even if it succeeded
+        assert!(matches!(
+            decode_response(8, &answer),
+            Err(AppRpcError::InvalidResponse { .. })
+        ));
+
+        // Remote errors are passed through
+        let answer = serialize_json_bytes(AppRpcResponse::new(
+            9,
+            Err(AppRpcError::method_not_found("test.missing")),
+        ));
+        assert_eq!(
+            decode_response(9, &answer),
+            Err(AppRpcError::method_not_found("test.missing"))
+        );
+
+        // Garbage and empty envelopes are invalid responses
+        assert!(matches!(
+            decode_response(9, b"not json"),
+            Err(AppRpcError::InvalidResponse { .. })
+        ));
+        assert!(matches!(
+            decode_response(9, br#"{"id":9}"#),
+            Err(AppRpcError::InvalidResponse { .. })
+        ));
+    }
+}
@@ -0,0 +1,191 @@
+use super::*;
+
+/// The version of the app RPC envelope format. Requests with a different version are rejected.
+pub const APP_RPC_VERSION: u32 = 1;
+
+/// The largest encoded response that fits in an app call reply.
+pub const MAX_APP_RPC_RESPONSE_LEN: usize = 32768;
+
+/// The errors an app RPC call can fail with.
+#[derive(ThisError, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind")]
+#[must_use]
+pub enum AppRpcError {
+    /// The app call itself failed, so the request may not have reached the server.
+    #[error("Transport: {error}")]
+    Transport { error: VeilidAPIError },
+    /// The server does not serve the requested method.
+    #[error("Method not found: {method}")]
+    MethodNotFound { method: String },
+    /// The server could not decode the request.
+    #[error("Invalid request: {message}")]
+    InvalidRequest { message: String },
+    /// The client could not decode the response.
+    #[error("Invalid response: {message}")]
+    InvalidResponse { message: String },
+    /// The method handler returned an error.
+    #[error("Remote: {error}")]
+    Remote { error: VeilidAPIError },
+}
+
+impl AppRpcError {
+    pub fn transport(error: VeilidAPIError) -> Self {
+        Self::Transport { error }
+    }
+    pub fn method_not_found<T: ToString>(method: T) -> Self {
+        Self::MethodNotFound {
+            method: method.to_string(),
+        }
+    }
+    pub fn invalid_request<T: ToString>(msg: T) -> Self {
+        Self::InvalidRequest {
+            message: msg.to_string(),
+        }
+    }
+    pub fn invalid_response<T: ToString>(msg: T) -> Self {
+        Self::InvalidResponse {
+            message: msg.to_string(),
+        }
+    }
+    pub fn remote(error: VeilidAPIError) -> Self {
+        Self::Remote { error }
+    }
+}
+
+impl From<AppRpcError> for VeilidAPIError {
+    fn from(e: AppRpcError) -> Self {
+        match e {
+            AppRpcError::Transport { error } | AppRpcError::Remote { error } => error,
+            AppRpcError::MethodNotFound { method } => {
+                VeilidAPIError::unimplemented(format!("app rpc method '{}'", method))
+            }
+            AppRpcError::InvalidRequest { message } => {
+                VeilidAPIError::parse_error(message, "app rpc request")
+            }
+            AppRpcError::InvalidResponse { message } => {
+                VeilidAPIError::parse_error(message, "app rpc response")
+            }
+        }
+    }
+}
+
+/// The envelope an [AppRpcClient] sends as the app call message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[must_use]
+pub struct AppRpcRequest {
+    /// the envelope format version, [APP_RPC_VERSION]
+    pub rpc: u32,
+    /// the caller's id for this request, echoed back in the response
+    pub id: u64,
+    /// the name of the method to call
+    pub method: String,
+    /// the method's request, encoded as json
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
+impl AppRpcRequest {
+    pub fn new<M: ToString>(id: u64, method: M, params: serde_json::Value) -> Self {
+        Self {
+            rpc: APP_RPC_VERSION,
+            id,
+            method: method.to_string(),
+            params,
+        }
+    }
+}
+
+/// The envelope an [AppRpcServer] replies with. Exactly one of `result` and `error` is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[must_use]
+pub struct AppRpcResponse {
+    /// the id of the request this answers
+    pub id: u64,
+    /// the method's response, encoded as json
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    /// why the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<AppRpcError>,
+}
+
+impl AppRpcResponse {
+    pub fn new(id: u64, result: Result<serde_json::Value, AppRpcError>) -> Self {
+        match result {
+            Ok(v) => Self {
+                id,
+                result: Some(v),
+                error: None,
+            },
+            Err(e) => Self {
+                id,
+                result: None,
+                error: Some(e),
+            },
+        }
+    }
+
+    /// Get the result, or the error if the call failed.
+    pub fn into_result(self) -> Result<serde_json::Value, AppRpcError> {
+        match (self.result, self.error) {
+            (_, Some(e)) => Err(e),
+            (Some(v), None) => Ok(v),
+            (None, None) => Err(AppRpcError::invalid_response(
+                "response has neither a result nor an error",
+            )),
+        }
+    }
+}
+
+/// The name, request schema and response schema of a served method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[must_use]
+pub struct AppRpcMethodDescription {
+    /// the method name
+    pub name: String,
+    /// json schema of the request
+    pub request_schema: serde_json::Value,
+    /// json schema of the response
+    pub response_schema: serde_json::Value,
+}
+
+impl AppRpcMethodDescription {
+    pub fn new<M: AppRpcMethod>() -> Self {
+        Self {
+            name: M::NAME.to_owned(),
+            request_schema: schema_value::<M::Request>(),
+            response_schema: schema_value::<M::Response>(),
+        }
+    }
+}
+
+/// The methods an [AppRpcServer] serves, as returned by [AppRpcClient::describe()].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[must_use]
+pub struct AppRpcServiceDescription {
+    /// the app namespace the server listens on
+    pub namespace: u32,
+    /// every registered method, sorted by name
+    pub methods: Vec<AppRpcMethodDescription>,
+}
+
+fn schema_value<T: JsonSchema>() -> serde_json::Value {
+    let schema = schemars::schema_for!(T);
+    serde_json::to_value(schema).expect("json schemas should always serialize")
+}
+
+/// Encode a value as json, reporting failure as an [AppRpcError].
+pub(super) fn to_json_value<T: Serialize>(
+    value: &T,
+    on_error: fn(String) -> AppRpcError,
+) -> Result<serde_json::Value, AppRpcError> {
+    serde_json::to_value(value).map_err(|e| on_error(e.to_string()))
+}
+
+/// Decode a value from json, reporting failure as an [AppRpcError].
+pub(super) fn from_json_value<T: de::DeserializeOwned>(
+    value: serde_json::Value,
+    on_error: fn(String) -> AppRpcError,
+) -> Result<T, AppRpcError> {
+    serde_json::from_value(value).map_err(|e| on_error(e.to_string()))
+}
@@ -0,0 +1,68 @@
+/// Declare an [AppRpcMethod](crate::AppRpcMethod) as a unit struct.
+///
+/// ```ignore
+/// app_rpc_method!(pub Add = "calc.add", AddRequest => AddResponse);
+/// ```
+#[macro_export]
+macro_rules! app_rpc_method {
+    ($(#[$attr:meta])* $vis:vis $name:ident = $method:literal, $request:ty => $response:ty) => {
+        $(#[$attr])*
+        // Only used as a type parameter, so it may never be constructed
+        #[allow(dead_code)]
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        $vis struct $name;
+
+        impl $crate::AppRpcMethod for $name {
+            const NAME: &'static str = $method;
+            type Request = $request;
+            type Response = $response;
+        }
+    };
+}
+
+/// Declare a client with one async function per [AppRpcMethod](crate::AppRpcMethod).
+///
+/// ```ignore
+/// app_rpc_client!(pub CalculatorClient { add: Add, sub: Sub });
+///
+/// let calc = CalculatorClient::new(routing_context);
+/// let sum = calc.add(target, AddRequest { a: 1, b: 2 }).await?;
+/// ```
+#[macro_export]
+macro_rules! app_rpc_client {
+    ($(#[$attr:meta])* $vis:vis $name:ident { $($fn_name:ident : $method:ty),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        #[must_use]
+        $vis struct $name {
+            client: $crate::AppRpcClient,
+        }
+
+        impl $name {
+            pub fn new(routing_context: $crate::RoutingContext) -> Self {
+                Self {
+                    client: $crate::AppRpcClient::new(routing_context),
+                }
+            }
+
+            /// The untyped client, for calls with call options.
+            #[must_use]
+            pub fn client(&self) -> &$crate::AppRpcClient {
+                &self.client
+            }
+
+            $(
+                pub async fn $fn_name(
+                    &self,
+                    target: $crate::Target,
+                    request: <$method as $crate::AppRpcMethod>::Request,
+                ) -> ::core::result::Result<
+                    <$method as $crate::AppRpcMethod>::Response,
+                    $crate::AppRpcError,
+                > {
+                    self.client.call::<$method>(target, request).await
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,30 @@
+//! Typed request/response services on top of [RoutingContext::app_call()].
+//!
+//! An [AppRpcServer] dispatches incoming [VeilidAppCall]s in one app namespace to methods
+//! registered with serde types, and an [AppRpcClient] calls those methods on a remote node
+//! over a direct target or a private route. Methods are declared once with
+//! [app_rpc_method!](crate::app_rpc_method) and shared by both sides, and
+//! [app_rpc_client!](crate::app_rpc_client) generates a client with one async function per method.
+
+mod client;
+mod envelope;
+mod macros;
+mod server;
+
+pub use client::*;
+pub use envelope::*;
+pub use server::*;
+
+use super::*;
+
+/// A method that can be called through an [AppRpcClient] and served by an [AppRpcServer].
+///
+/// Usually declared with [app_rpc_method!](crate::app_rpc_method).
+pub trait AppRpcMethod: Send + Sync + 'static {
+    /// The method name, unique within the server's namespace. Names starting with `rpc.` are reserved.
+    const NAME: &'static str;
+    /// The parameters sent by the caller.
+    type Request: Serialize + de::DeserializeOwned + JsonSchema + fmt::Debug + Send + 'static;
+    /// The result returned by the handler.
+    type Response: Serialize + de::DeserializeOwned + JsonSchema + fmt::Debug + Send + 'static;
+}
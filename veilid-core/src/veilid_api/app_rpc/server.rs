@@ -0,0 +1,345 @@
+use super::*;
+
+impl_veilid_log_facility!("veilid_api");
+
+/// The method every [AppRpcServer] serves, returning its [AppRpcServiceDescription].
+pub const APP_RPC_DESCRIBE_METHOD: &str = "rpc.describe";
+
+/// How many requests an [AppRpcServer] runs at once by default. Requests beyond this are answered with [VeilidAPIError::TryAgain].
+pub const APP_RPC_DEFAULT_MAX_CONCURRENCY: usize = 16;
+
+/// How long an [AppRpcServer] lets a handler run by default before answering with [VeilidAPIError::Timeout].
+pub const APP_RPC_DEFAULT_HANDLER_TIMEOUT_MS: u32 = 5000;
+
+type AppRpcHandlerFuture = PinBoxFutureStatic<Result<serde_json::Value, AppRpcError>>;
+type AppRpcHandler =
+    Arc<dyn Fn(AppRpcContext, serde_json::Value) -> AppRpcHandlerFuture + Send + Sync>;
+
+struct AppRpcMethodEntry {
+    description: AppRpcMethodDescription,
+    handler: AppRpcHandler,
+}
+
+/// Who called a method, passed to its handler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct AppRpcContext {
+    sender: Option<TypedNodeId>,
+    route_id: Option<RouteId>,
+    request_id: u64,
+    call_id: OperationId,
+}
+
+impl AppRpcContext {
+    /// Some(sender) if the request was sent directly, None if received via a private/safety route.
+    #[must_use]
+    pub fn sender(&self) -> Option<&TypedNodeId> {
+        self.sender.as_ref()
+    }
+
+    /// Some(route_id) if the request was received over a private route.
+    #[must_use]
+    pub fn route_id(&self) -> Option<&RouteId> {
+        self.route_id.as_ref()
+    }
+
+    /// The id the client gave this request.
+    #[must_use]
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// The id of the app call carrying this request.
+    pub fn call_id(&self) -> OperationId {
+        self.call_id
+    }
+}
+
+struct AppRpcServerUnlockedInner {
+    api: VeilidAPI,
+    namespace: u32,
+    max_concurrency: usize,
+    handler_timeout_ms: u32,
+    methods: RwLock<BTreeMap<String, AppRpcMethodEntry>>,
+    in_flight: Mutex<usize>,
+}
+
+/// Holds one of the server's request slots until dropped
+struct AppRpcInFlightGuard<'a> {
+    in_flight: &'a Mutex<usize>,
+}
+
+impl Drop for AppRpcInFlightGuard<'_> {
+    fn drop(&mut self) {
+        *self.in_flight.lock() -= 1;
+    }
+}
+
+/// Serves registered methods to [AppRpcClient]s.
+///
+/// Pass every [VeilidUpdate] to [AppRpcServer::handle_update()] from the update callback. App calls in the
+/// server's namespace that carry an [AppRpcRequest] are dispatched to their method and answered with
+/// [VeilidAPI::app_call_reply()]. Anything else is left for the application to handle.
+#[derive(Clone)]
+#[must_use]
+pub struct AppRpcServer {
+    unlocked_inner: Arc<AppRpcServerUnlockedInner>,
+}
+
+impl fmt::Debug for AppRpcServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppRpcServer")
+            .field("namespace", &self.unlocked_inner.namespace)
+            .field("max_concurrency", &self.unlocked_inner.max_concurrency)
+            .field(
+                "handler_timeout_ms",
+                &self.unlocked_inner.handler_timeout_ms,
+            )
+            .field(
+                "methods",
+                &self
+                    .unlocked_inner
+                    .methods
+                    .read()
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl AppRpcServer {
+    /// Create a server for the app calls sent to `namespace`. See [RoutingContext::with_app_namespace()].
+    ///
+    /// Uses [APP_RPC_DEFAULT_MAX_CONCURRENCY] and [APP_RPC_DEFAULT_HANDLER_TIMEOUT_MS].
+    pub fn new(api: VeilidAPI, namespace: u32) -> Self {
+        Self::new_with_limits(
+            api,
+            namespace,
+            APP_RPC_DEFAULT_MAX_CONCURRENCY,
+            APP_RPC_DEFAULT_HANDLER_TIMEOUT_MS,
+        )
+    }
+
+    /// Create a server that runs at most `max_concurrency` requests at once and gives each handler `handler_timeout_ms` to finish.
+    ///
+    /// The handler timeout should be shorter than the callers' rpc timeout, or they will have given up before the answer arrives.
+    pub fn new_with_limits(
+        api: VeilidAPI,
+        namespace: u32,
+        max_concurrency: usize,
+        handler_timeout_ms: u32,
+    ) -> Self {
+        Self {
+            unlocked_inner: Arc::new(AppRpcServerUnlockedInner {
+                api,
+                namespace,
+                max_concurrency: max_concurrency.max(1),
+                handler_timeout_ms,
+                methods: RwLock::new(BTreeMap::new()),
+                in_flight: Mutex::new(0),
+            }),
+        }
+    }
+
+    #[must_use]
+    fn log_key(&self) -> &str {
+        self.unlocked_inner.api.log_key()
+    }
+
+    /// The app namespace this server listens on.
+    #[must_use]
+    pub fn namespace(&self) -> u32 {
+        self.unlocked_inner.namespace
+    }
+
+    /// Serve a method.
+    ///
+    /// The handler's errors are returned to the caller as [AppRpcError::Remote].
+    /// Returns [VeilidAPIError::InvalidArgument] if the method name is reserved or already registered.
+    pub fn register<M, F, Fut>(&self, handler: F) -> VeilidAPIResult<()>
+    where
+        M: AppRpcMethod,
+        F: Fn(AppRpcContext, M::Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VeilidAPIResult<M::Response>> + Send + 'static,
+    {
+        if M::NAME.starts_with("rpc.") {
+            apibail_invalid_argument!("method name is reserved", "name", M::NAME);
+        }
+
+        let handler = Arc::new(handler);
+        let erased: AppRpcHandler = Arc::new(
+            move |ctx: AppRpcContext, params: serde_json::Value| -> AppRpcHandlerFuture {
+                let handler = handler.clone();
+                Box::pin(async move {
+                    let request: M::Request =
+                        from_json_value(params, AppRpcError::invalid_request)?;
+                    let response = handler(ctx, request).await.map_err(AppRpcError::remote)?;
+                    to_json_value(&response, |e| {
+                        AppRpcError::remote(VeilidAPIError::internal(e))
+                    })
+                })
+            },
+        );
+
+        let mut methods = self.unlocked_inner.methods.write();
+        if methods.contains_key(M::NAME) {
+            apibail_invalid_argument!("method already registered", "name", M::NAME);
+        }
+        methods.insert(
+            M::NAME.to_owned(),
+            AppRpcMethodEntry {
+                description: AppRpcMethodDescription::new::<M>(),
+                handler: erased,
+            },
+        );
+        Ok(())
+    }
+
+    /// Stop serving a method. Returns false if it was not registered.
+    #[must_use]
+    pub fn unregister<M: AppRpcMethod>(&self) -> bool {
+        self.unlocked_inner
+            .methods
+            .write()
+            .remove(M::NAME)
+            .is_some()
+    }
+
+    /// The methods this server serves, with the json schemas of their requests and responses.
+    pub fn describe(&self) -> AppRpcServiceDescription {
+        AppRpcServiceDescription {
+            namespace: self.unlocked_inner.namespace,
+            methods: self
+                .unlocked_inner
+                .methods
+                .read()
+                .values()
+                .map(|entry| entry.description.clone())
+                .collect(),
+        }
+    }
+
+    /// Handle a [VeilidUpdate] from the update callback.
+    ///
+    /// Returns true if the update was an app RPC request for this server, which is then answered in the background.
+    #[must_use]
+    pub fn handle_update(&self, update: &VeilidUpdate) -> bool {
+        match update {
+            VeilidUpdate::AppCall(app_call) => self.handle_app_call(app_call),
+            _ => false,
+        }
+    }
+
+    /// Handle a [VeilidAppCall].
+    ///
+    /// Returns true if the call was an app RPC request for this server, which is then answered in the background.
+    /// Returns false, without replying, for calls to other namespaces and messages that are not app RPC requests.
+    #[must_use]
+    pub fn handle_app_call(&self, app_call: &VeilidAppCall) -> bool {
+        let Some((ctx, request)) = self.decode_app_call(app_call) else {
+            return false;
+        };
+
+        let this = self.clone();
+        spawn_detached("app rpc dispatch", async move {
+            let call_id = ctx.call_id;
+            let response = this.dispatch(ctx, request).await;
+            if let Err(e) = this
+                .unlocked_inner
+                .api
+                .app_call_reply(call_id, response)
+                .await
+            {
+                veilid_log!(this debug "Failed to reply to app rpc call {}: {}", call_id, e);
+            }
+        });
+
+        true
+    }
+
+    /// Get the request from an app call in this server's namespace
+    fn decode_app_call(&self, app_call: &VeilidAppCall) -> Option<(AppRpcContext, AppRpcRequest)> {
+        if app_call.namespace() != self.unlocked_inner.namespace {
+            return None;
+        }
+        let request = deserialize_json_bytes::<AppRpcRequest>(app_call.message()).ok()?;
+
+        let ctx = AppRpcContext {
+            sender: app_call.sender().cloned(),
+            route_id: app_call.route_id().cloned(),
+            request_id: request.id,
+            call_id: app_call.id(),
+        };
+        Some((ctx, request))
+    }
+
+    /// Run an app call's request and return the encoded reply, without sending it.
+    /// Returns None for calls [AppRpcServer::handle_app_call()] would leave alone.
+    pub(crate) async fn answer_app_call(&self, app_call: &VeilidAppCall) -> Option<Vec<u8>> {
+        let (ctx, request) = self.decode_app_call(app_call)?;
+        Some(self.dispatch(ctx, request).await)
+    }
+
+    /// Take a request slot, or None if the server is already running as many requests as it may
+    fn try_enter(&self) -> Option<AppRpcInFlightGuard<'_>> {
+        let in_flight = &self.unlocked_inner.in_flight;
+        let mut count = in_flight.lock();
+        if *count >= self.unlocked_inner.max_concurrency {
+            return None;
+        }
+        *count += 1;
+        Some(AppRpcInFlightGuard { in_flight })
+    }
+
+    /// Run the requested method and encode its response
+    async fn dispatch(&self, ctx: AppRpcContext, request: AppRpcRequest) -> Vec<u8> {
+        let id = request.id;
+        let opt_guard = self.try_enter();
+        let result = if opt_guard.is_none() {
+            Err(AppRpcError::remote(VeilidAPIError::try_again(
+                "app rpc server is busy",
+            )))
+        } else if request.rpc != APP_RPC_VERSION {
+            Err(AppRpcError::invalid_request(format!(
+                "unsupported app rpc version {}, expected {}",
+                request.rpc, APP_RPC_VERSION
+            )))
+        } else if request.method == APP_RPC_DESCRIBE_METHOD {
+            to_json_value(&self.describe(), |e| {
+                AppRpcError::remote(VeilidAPIError::internal(e))
+            })
+        } else {
+            let opt_handler = self
+                .unlocked_inner
+                .methods
+                .read()
+                .get(&request.method)
+                .map(|entry| entry.handler.clone());
+            match opt_handler {
+                Some(handler) => timeout(
+                    self.unlocked_inner.handler_timeout_ms,
+                    handler(ctx, request.params),
+                )
+                .await
+                .unwrap_or_else(|_| Err(AppRpcError::remote(VeilidAPIError::timeout()))),
+                None => Err(AppRpcError::method_not_found(&request.method)),
+            }
+        };
+        drop(opt_guard);
+
+        let response = serialize_json_bytes(AppRpcResponse::new(id, result));
+        if response.len() <= MAX_APP_RPC_RESPONSE_LEN {
+            return response;
+        }
+        serialize_json_bytes(AppRpcResponse::new(
+            id,
+            Err(AppRpcError::remote(VeilidAPIError::internal(format!(
+                "response of {} bytes is larger than {} bytes",
+                response.len(),
+                MAX_APP_RPC_RESPONSE_LEN
+            )))),
+        ))
+    }
+}
@@ -17,6 +17,28 @@ pub(crate) struct DebugCache {
     pub opened_record_contexts: Lazy<LinkedHashMap<TypedRecordKey, RoutingContext>>,
 }
 
+#[derive(Debug, Serialize)]
+struct DebugRouteListJson {
+    cur_ts: Timestamp,
+    routes: Vec<DebugRouteJson>,
+}
+
+#[derive(Debug, Serialize)]
+struct DebugRouteJson {
+    route_id: String,
+    remote: bool,
+    published: bool,
+    hop_count: Option<usize>,
+    stability: Option<Stability>,
+    stats: RouteStatsDebugInfo,
+}
+
+#[derive(Debug, Serialize)]
+struct DebugConnectionsJson {
+    cur_ts: Timestamp,
+    protocols: Vec<ConnectionTableDebugInfo>,
+}
+
 #[must_use]
 pub fn format_opt_ts(ts: Option<TimestampDuration>) -> String {
     let Some(ts) = ts else {
@@ -886,6 +908,17 @@ impl VeilidAPI {
             let debug_stats = registry.network_manager().debug();
 
             Ok(debug_stats)
+        } else if arg == "connections" {
+            let registry = self.core_context()?.registry();
+            let Some(connection_manager) = registry.network_manager().opt_connection_manager()
+            else {
+                apibail_internal!("Connection manager unavailable when detached");
+            };
+
+            Ok(serialize_json(DebugConnectionsJson {
+                cur_ts: Timestamp::now(),
+                protocols: connection_manager.debug_info(),
+            }))
         } else {
             apibail_invalid_argument!("debug_restart", "arg_1", arg);
         }
@@ -973,6 +1006,44 @@ impl VeilidAPI {
         Ok("Detached".to_owned())
     }
 
+    async fn debug_suspend(&self, _args: String) -> VeilidAPIResult<String> {
+        self.suspend().await?;
+
+        Ok("Suspended".to_owned())
+    }
+
+    async fn debug_resume(&self, _args: String) -> VeilidAPIResult<String> {
+        if !matches!(
+            self.get_state().await?.attachment.state,
+            AttachmentState::Detached
+        ) {
+            apibail_internal!("Not detached");
+        }
+
+        self.resume().await?;
+
+        Ok("Resuming".to_owned())
+    }
+
+    fn debug_power(&self, args: String) -> VeilidAPIResult<String> {
+        let args: Vec<String> = args.split_whitespace().map(|s| s.to_owned()).collect();
+
+        if args.is_empty() {
+            return Ok(format!("Power profile: {}\n", self.power_profile()?));
+        }
+
+        let power_profile = get_debug_argument_at(&args, 0, "debug_power", "power_profile", |s| {
+            PowerProfile::try_from(s).ok()
+        })?;
+        self.set_power_profile(power_profile)?;
+
+        Ok(format!("Power profile set to {}\n", power_profile))
+    }
+
+    fn debug_diagnose(&self, _args: String) -> VeilidAPIResult<String> {
+        Ok(format!("{}\n", self.diagnose()?))
+    }
+
     fn debug_contact(&self, args: String) -> VeilidAPIResult<String> {
         let args: Vec<String> = args.split_whitespace().map(|s| s.to_owned()).collect();
 
@@ -1095,7 +1166,7 @@ impl VeilidAPI {
         let rpc_processor = registry.rpc_processor();
 
         let out = match rpc_processor
-            .rpc_call_app_message(dest, data)
+            .rpc_call_app_message(dest, 0, data)
             .await
             .map_err(VeilidAPIError::internal)?
         {
@@ -1132,7 +1203,7 @@ impl VeilidAPI {
         let rpc_processor = registry.rpc_processor();
 
         let out = match rpc_processor
-            .rpc_call_app_call(dest, data)
+            .rpc_call_app_call(dest, 0, data, CallOptions::default())
             .await
             .map_err(VeilidAPIError::internal)?
         {
@@ -1369,12 +1440,43 @@ impl VeilidAPI {
             None => Ok("Route does not exist".to_owned()),
         }
     }
-    fn debug_route_list(&self, _args: Vec<String>) -> VeilidAPIResult<String> {
-        //
+    fn debug_route_list(&self, args: Vec<String>) -> VeilidAPIResult<String> {
+        // [json]
         let registry = self.core_context()?.registry();
         let routing_table = registry.routing_table();
         let rss = routing_table.route_spec_store();
 
+        let json = get_debug_argument_at(&args, 1, "debug_route", "format", get_string)
+            .ok()
+            .map(|x| x == "json")
+            .unwrap_or_default();
+        if json {
+            let mut routes = rss.list_allocated_routes(|k, v| {
+                Some(DebugRouteJson {
+                    route_id: k.encode(),
+                    remote: false,
+                    published: v.is_published(),
+                    hop_count: Some(v.hop_count()),
+                    stability: Some(v.get_stability()),
+                    stats: v.get_stats().debug_info(),
+                })
+            });
+            routes.extend(rss.list_remote_routes(|k, v| {
+                Some(DebugRouteJson {
+                    route_id: k.encode(),
+                    remote: true,
+                    published: false,
+                    hop_count: None,
+                    stability: None,
+                    stats: v.get_stats().debug_info(),
+                })
+            }));
+            return Ok(serialize_json(DebugRouteListJson {
+                cur_ts: Timestamp::now(),
+                routes,
+            }));
+        }
+
         let routes = rss.list_allocated_routes(|k, _| Some(*k));
         let mut out = format!("Allocated Routes: (count = {}):\n", routes.len());
         for r in routes {
@@ -2185,7 +2287,7 @@ Routing:
           publish <route> [full] - Publish a route 'blob' that can be imported on another machine
           unpublish <route> - Mark a route as 'no longer published'
           print <route> - Display details about a route
-          list - List allocated routes
+          list [json] - List allocated and imported routes, optionally with stats as json
           import <blob> - Import a remote route blob generated by another node's 'publish' command.
           test <route> - Test an allocated or imported remote route
 
@@ -2199,8 +2301,13 @@ Utilities:
 Network:
     attach - Attach the node to the network if it is detached
     detach - Detach the node from the network if it is attached
+    suspend - Detach the node, saving its connection and route state for a fast resume
+    resume - Reattach the node, revalidating the state saved by suspend
+    power [normal|low_power] - Display or change the power profile
+    diagnose - Check connectivity and suggest fixes for any problems found
     network restart - Restart the low level network
             stats - Print network manager statistics
+            connections - Print the connection table as json
 
 RPC Operations:
     ping <destination> - Send a 'Status' RPC question to a destination node and display the returned ping status
@@ -2312,6 +2419,10 @@ TableDB Operations:
                 self.debug_entry(rest)
             } else if arg == "punish" {
                 self.debug_punish(rest)
+            } else if arg == "power" {
+                self.debug_power(rest)
+            } else if arg == "diagnose" {
+                self.debug_diagnose(rest)
             } else {
                 let fut = if arg == "txtrecord" {
                     pin_dyn_future!(self.debug_txtrecord(rest))
@@ -2335,6 +2446,10 @@ TableDB Operations:
                     pin_dyn_future!(self.debug_attach(rest))
                 } else if arg == "detach" {
                     pin_dyn_future!(self.debug_detach(rest))
+                } else if arg == "suspend" {
+                    pin_dyn_future!(self.debug_suspend(rest))
+                } else if arg == "resume" {
+                    pin_dyn_future!(self.debug_resume(rest))
                 } else if arg == "config" {
                     pin_dyn_future!(self.debug_config(rest))
                 } else if arg == "network" {
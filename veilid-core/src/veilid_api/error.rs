@@ -150,6 +150,10 @@ pub enum VeilidAPIError {
     MissingArgument { context: String, argument: String },
     #[error("Generic: {message}")]
     Generic { message: String },
+    #[error("Tampered: {message}")]
+    Tampered { message: String },
+    #[error("Cancelled")]
+    Cancelled,
 }
 
 impl VeilidAPIError {
@@ -221,6 +225,14 @@ impl VeilidAPIError {
             message: msg.to_string(),
         }
     }
+    pub fn tampered<T: ToString>(msg: T) -> Self {
+        Self::Tampered {
+            message: msg.to_string(),
+        }
+    }
+    pub fn cancelled() -> Self {
+        Self::Cancelled
+    }
 
     pub(crate) fn from_network_result<T>(nr: NetworkResult<T>) -> Result<T, Self> {
         match nr {
@@ -1,4 +1,5 @@
 mod api;
+mod app_rpc;
 mod debug;
 mod error;
 mod routing_context;
@@ -9,6 +10,7 @@ mod types;
 pub mod tests;
 
 pub use api::*;
+pub use app_rpc::*;
 pub use crypto::*;
 pub use debug::*;
 pub use error::*;
@@ -17,7 +19,7 @@ pub use intf::BlockStore;
 pub use intf::ProtectedStore;
 pub use routing_context::*;
 pub use serialize_helpers::*;
-pub use table_store::{TableDB, TableDBTransaction, TableStore};
+pub use table_store::{KeyRotationProgress, TableDB, TableDBPage, TableDBTransaction, TableStore};
 pub use types::*;
 
 use crate::*;
@@ -17,6 +17,10 @@ pub enum Target {
 pub(crate) struct RoutingContextUnlockedInner {
     /// Safety routing requirements.
     safety_selection: SafetySelection,
+    /// Split app calls and messages that are too large for one operation into fragments.
+    fragmentation: bool,
+    /// Application namespace that app calls and messages are addressed to.
+    app_namespace: u32,
 }
 
 /// Routing contexts are the way you specify the communication preferences for Veilid.
@@ -38,6 +42,8 @@ impl fmt::Debug for RoutingContext {
         f.debug_struct("RoutingContext")
             .field("ptr", &format!("{:p}", Arc::as_ptr(&self.unlocked_inner)))
             .field("safety_selection", &self.unlocked_inner.safety_selection)
+            .field("fragmentation", &self.unlocked_inner.fragmentation)
+            .field("app_namespace", &self.unlocked_inner.app_namespace)
             .finish()
     }
 }
@@ -58,6 +64,8 @@ impl RoutingContext {
                     stability: Stability::Reliable,
                     sequencing: Sequencing::PreferOrdered,
                 }),
+                fragmentation: false,
+                app_namespace: 0,
             }),
         })
     }
@@ -110,7 +118,11 @@ impl RoutingContext {
 
         Ok(Self {
             api: self.api.clone(),
-            unlocked_inner: Arc::new(RoutingContextUnlockedInner { safety_selection }),
+            unlocked_inner: Arc::new(RoutingContextUnlockedInner {
+                safety_selection,
+                fragmentation: self.unlocked_inner.fragmentation,
+                app_namespace: self.unlocked_inner.app_namespace,
+            }),
         })
     }
 
@@ -132,6 +144,47 @@ impl RoutingContext {
                         sequencing,
                     }),
                 },
+                fragmentation: self.unlocked_inner.fragmentation,
+                app_namespace: self.unlocked_inner.app_namespace,
+            }),
+        }
+    }
+
+    /// Send app calls and app messages that are larger than a single operation allows by splitting them
+    /// into fragments that are reassembled by the receiver, raising the size limit of
+    /// [RoutingContext::app_call()] questions and answers and [RoutingContext::app_message()] messages to 1048576 bytes.
+    ///
+    /// The receiving node must support fragmentation as well.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret)]
+    pub fn with_fragmentation(self, fragmentation: bool) -> Self {
+        veilid_log!(self debug
+            "RoutingContext::with_fragmentation(self: {:?}, fragmentation: {:?})", self, fragmentation);
+
+        Self {
+            api: self.api.clone(),
+            unlocked_inner: Arc::new(RoutingContextUnlockedInner {
+                safety_selection: self.unlocked_inner.safety_selection,
+                fragmentation,
+                app_namespace: self.unlocked_inner.app_namespace,
+            }),
+        }
+    }
+
+    /// Address app calls and app messages to an application namespace, so that a receiving node that hosts
+    /// several applications can hand them to the application that subscribed to it.
+    ///
+    /// The default namespace is 0. Namespaces are an addressing convenience, not access control.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret)]
+    pub fn with_app_namespace(self, app_namespace: u32) -> Self {
+        veilid_log!(self debug
+            "RoutingContext::with_app_namespace(self: {:?}, app_namespace: {:?})", self, app_namespace);
+
+        Self {
+            api: self.api.clone(),
+            unlocked_inner: Arc::new(RoutingContextUnlockedInner {
+                safety_selection: self.unlocked_inner.safety_selection,
+                fragmentation: self.unlocked_inner.fragmentation,
+                app_namespace,
             }),
         }
     }
@@ -149,6 +202,18 @@ impl RoutingContext {
         }
     }
 
+    /// Get whether large app calls and app messages are fragmented by this routing context
+    #[must_use]
+    pub fn fragmentation(&self) -> bool {
+        self.unlocked_inner.fragmentation
+    }
+
+    /// Get the application namespace app calls and app messages are addressed to by this routing context
+    #[must_use]
+    pub fn app_namespace(&self) -> u32 {
+        self.unlocked_inner.app_namespace
+    }
+
     /// Get the [VeilidAPI] object that created this [RoutingContext].
     pub fn api(&self) -> VeilidAPI {
         self.api.clone()
@@ -173,9 +238,10 @@ impl RoutingContext {
         &self,
         target: Target,
         message: Vec<u8>,
+        options: CallOptions,
     ) -> VeilidAPIResult<Vec<u8>> {
         veilid_log!(self debug
-            "RoutingContext::app_call(self: {:?}, target: {:?}, message: {:?})", self, target, message);
+            "RoutingContext::app_call(self: {:?}, target: {:?}, message: {:?}, options: {:?})", self, target, message, options);
 
         let rpc_processor = self.api.core_context()?.rpc_processor();
 
@@ -183,7 +249,24 @@ impl RoutingContext {
         let dest = self.get_destination(target).await?;
 
         // Send app message
-        let answer = match Box::pin(rpc_processor.rpc_call_app_call(dest, message)).await {
+        let res = if self.unlocked_inner.fragmentation {
+            Box::pin(rpc_processor.rpc_call_app_call_fragmented(
+                dest,
+                self.unlocked_inner.app_namespace,
+                message,
+                options,
+            ))
+            .await
+        } else {
+            Box::pin(rpc_processor.rpc_call_app_call(
+                dest,
+                self.unlocked_inner.app_namespace,
+                message,
+                options,
+            ))
+            .await
+        };
+        let answer = match res {
             Ok(NetworkResult::Value(v)) => v,
             Ok(NetworkResult::Timeout) => apibail_timeout!(),
             Ok(NetworkResult::ServiceUnavailable(e)) => apibail_invalid_target!(e),
@@ -209,11 +292,26 @@ impl RoutingContext {
     /// Veilid apps may use this for arbitrary message passing.
     ///
     /// * `target` - can be either a direct node id or a private route.
-    /// * `message` - an arbitrary message blob of up to 32768 bytes.
+    /// * `message` - an arbitrary message blob of up to 32768 bytes, or 1048576 bytes with [RoutingContext::with_fragmentation()].
     ///
     /// Returns an answer blob of up to 32768 bytes.
     pub async fn app_call(&self, target: Target, message: Vec<u8>) -> VeilidAPIResult<Vec<u8>> {
-        self.internal_app_call(target, message).await
+        self.internal_app_call(target, message, CallOptions::default())
+            .await
+    }
+
+    #[cfg(feature = "footgun")]
+    /// App-level bidirectional call with a deadline and cancellation handle.
+    ///
+    /// Same as [RoutingContext::app_call()], except that `options` may end the call before `rpc.timeout_ms`.
+    /// Returns [VeilidAPIError::Timeout] if the deadline passes and [VeilidAPIError::Cancelled] if the call is cancelled.
+    pub async fn app_call_with_options(
+        &self,
+        target: Target,
+        message: Vec<u8>,
+        options: CallOptions,
+    ) -> VeilidAPIResult<Vec<u8>> {
+        self.internal_app_call(target, message, options).await
     }
 
     #[cfg(not(feature = "footgun"))]
@@ -222,12 +320,27 @@ impl RoutingContext {
     /// Veilid apps may use this for arbitrary message passing.
     ///
     /// * `target` - a private route.
-    /// * `message` - an arbitrary message blob of up to 32768 bytes.
+    /// * `message` - an arbitrary message blob of up to 32768 bytes, or 1048576 bytes with [RoutingContext::with_fragmentation()].
     ///
     /// Returns an answer blob of up to 32768 bytes.
     pub async fn app_call(&self, target: Target, message: Vec<u8>) -> VeilidAPIResult<Vec<u8>> {
+        self.app_call_with_options(target, message, CallOptions::default())
+            .await
+    }
+
+    #[cfg(not(feature = "footgun"))]
+    /// App-level bidirectional call with a deadline and cancellation handle.
+    ///
+    /// Same as [RoutingContext::app_call()], except that `options` may end the call before `rpc.timeout_ms`.
+    /// Returns [VeilidAPIError::Timeout] if the deadline passes and [VeilidAPIError::Cancelled] if the call is cancelled.
+    pub async fn app_call_with_options(
+        &self,
+        target: Target,
+        message: Vec<u8>,
+        options: CallOptions,
+    ) -> VeilidAPIResult<Vec<u8>> {
         match target {
-            Target::PrivateRoute(_) => self.internal_app_call(target, message).await,
+            Target::PrivateRoute(_) => self.internal_app_call(target, message, options).await,
             Target::NodeId(_) => Err(VeilidAPIError::invalid_target(
                 "Only PrivateRoute targets are allowed without the footgun feature",
             )),
@@ -245,7 +358,22 @@ impl RoutingContext {
         let dest = self.get_destination(target).await?;
 
         // Send app message
-        match Box::pin(rpc_processor.rpc_call_app_message(dest, message)).await {
+        let res = if self.unlocked_inner.fragmentation {
+            Box::pin(rpc_processor.rpc_call_app_message_fragmented(
+                dest,
+                self.unlocked_inner.app_namespace,
+                message,
+            ))
+            .await
+        } else {
+            Box::pin(rpc_processor.rpc_call_app_message(
+                dest,
+                self.unlocked_inner.app_namespace,
+                message,
+            ))
+            .await
+        };
+        match res {
             Ok(NetworkResult::Value(())) => {}
             Ok(NetworkResult::Timeout) => apibail_timeout!(),
             Ok(NetworkResult::ServiceUnavailable(e)) => apibail_invalid_target!(e),
@@ -267,7 +395,7 @@ impl RoutingContext {
     /// Veilid apps may use this for arbitrary message passing.
     ///
     /// * `target` - can be either a direct node id or a private route.
-    /// * `message` - an arbitrary message blob of up to 32768 bytes.
+    /// * `message` - an arbitrary message blob of up to 32768 bytes, or 1048576 bytes with [RoutingContext::with_fragmentation()].
     pub async fn app_message(&self, target: Target, message: Vec<u8>) -> VeilidAPIResult<()> {
         self.internal_app_message(target, message).await
     }
@@ -278,7 +406,7 @@ impl RoutingContext {
     /// Veilid apps may use this for arbitrary message passing.
     ///
     /// * `target` - a private route.
-    /// * `message` - an arbitrary message blob of up to 32768 bytes.
+    /// * `message` - an arbitrary message blob of up to 32768 bytes, or 1048576 bytes with [RoutingContext::with_fragmentation()].
     pub async fn app_message(&self, target: Target, message: Vec<u8>) -> VeilidAPIResult<()> {
         match target {
             Target::PrivateRoute(_) => self.internal_app_message(target, message).await,
@@ -419,7 +547,29 @@ impl RoutingContext {
         Crypto::validate_crypto_kind(key.kind)?;
 
         let storage_manager = self.api.core_context()?.storage_manager();
-        Box::pin(storage_manager.get_value(key, subkey, force_refresh)).await
+        Box::pin(storage_manager.get_value(key, subkey, force_refresh, CallOptions::default()))
+            .await
+    }
+
+    /// Gets the latest value of a subkey, with a deadline and cancellation handle for the network refresh.
+    ///
+    /// Same as [RoutingContext::get_dht_value()], except that `options` may end the refresh before `dht.get_value_timeout_ms`.
+    /// Returns [VeilidAPIError::Cancelled] if the refresh is cancelled.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn get_dht_value_with_options(
+        &self,
+        key: TypedRecordKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+        options: CallOptions,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        veilid_log!(self debug
+            "RoutingContext::get_dht_value_with_options(self: {:?}, key: {:?}, subkey: {:?}, force_refresh: {:?}, options: {:?})", self, key, subkey, force_refresh, options);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.get_value(key, subkey, force_refresh, options)).await
     }
 
     /// Pushes a changed subkey value to the network.
@@ -443,7 +593,98 @@ impl RoutingContext {
         Crypto::validate_crypto_kind(key.kind)?;
 
         let storage_manager = self.api.core_context()?.storage_manager();
-        Box::pin(storage_manager.set_value(key, subkey, data, writer)).await
+        Box::pin(storage_manager.set_value(
+            key,
+            subkey,
+            data,
+            writer,
+            vec![],
+            CallOptions::default(),
+        ))
+        .await
+    }
+
+    /// Pushes a changed subkey value to the network, with a deadline and cancellation handle.
+    ///
+    /// Same as [RoutingContext::set_dht_value()], except that `options` may end the write before `dht.set_value_timeout_ms`.
+    /// Returns [VeilidAPIError::Cancelled] if the write is cancelled, in which case the value has still been
+    /// stored locally and will be pushed to the network in the background.
+    #[instrument(target = "veilid_api", level = "debug", skip(data), fields(__VEILID_LOG_KEY = self.log_key(), data = print_data(&data, Some(64))), ret, err)]
+    pub async fn set_dht_value_with_options(
+        &self,
+        key: TypedRecordKey,
+        subkey: ValueSubkey,
+        data: Vec<u8>,
+        writer: Option<KeyPair>,
+        options: CallOptions,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        veilid_log!(self debug
+            "RoutingContext::set_dht_value_with_options(self: {:?}, key: {:?}, subkey: {:?}, data: len={}, writer: {:?}, options: {:?})", self, key, subkey, data.len(), writer, options);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.set_value(key, subkey, data, writer, vec![], options)).await
+    }
+
+    /// Pushes a changed subkey value to an owner subkey of a MULT schema record, along with the cosignatures that meet the schema threshold.
+    /// The DHT record must first by opened via open_dht_record or create_dht_record.
+    ///
+    /// The writer, if specified, will override the 'default_writer' specified when the record is opened, and must be one of the schema owners.
+    /// Each cosignature must come from a different owner, made with cosign_dht_value over the same data and the sequence number this write will use,
+    /// which is one more than that of the last value seen for the subkey.
+    ///
+    /// Returns `None` if the value was successfully put.
+    /// Returns `Some(data)` if the value put was older than the one available on the network.
+    #[instrument(target = "veilid_api", level = "debug", skip(data, cosignatures), fields(__VEILID_LOG_KEY = self.log_key(), data = print_data(&data, Some(64))), ret, err)]
+    pub async fn set_dht_value_cosigned(
+        &self,
+        key: TypedRecordKey,
+        subkey: ValueSubkey,
+        data: Vec<u8>,
+        writer: Option<KeyPair>,
+        cosignatures: Vec<DHTCosignature>,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        veilid_log!(self debug
+            "RoutingContext::set_dht_value_cosigned(self: {:?}, key: {:?}, subkey: {:?}, data: len={}, writer: {:?}, cosignatures: {})", self, key, subkey, data.len(), writer, cosignatures.len());
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.set_value(
+            key,
+            subkey,
+            data,
+            writer,
+            cosignatures,
+            CallOptions::default(),
+        ))
+        .await
+    }
+
+    /// Cosigns a value that another owner of a MULT schema record is going to write to one of its owner subkeys.
+    /// The DHT record must first by opened via open_dht_record.
+    ///
+    /// * `seq` is the sequence number the value will be written with, one more than that of the last value seen for the subkey.
+    /// * `signer` is the keypair of the owner cosigning the value.
+    ///
+    /// Nothing is written; the returned cosignature is passed to set_dht_value_cosigned by the writer.
+    #[instrument(target = "veilid_api", level = "debug", skip(data, signer), fields(__VEILID_LOG_KEY = self.log_key(), data = print_data(&data, Some(64))), ret, err)]
+    pub async fn cosign_dht_value(
+        &self,
+        key: TypedRecordKey,
+        subkey: ValueSubkey,
+        seq: ValueSeqNum,
+        data: Vec<u8>,
+        signer: KeyPair,
+    ) -> VeilidAPIResult<DHTCosignature> {
+        veilid_log!(self debug
+            "RoutingContext::cosign_dht_value(self: {:?}, key: {:?}, subkey: {:?}, seq: {}, data: len={}, signer: {:?})", self, key, subkey, seq, data.len(), signer.key);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.cosign_value(key, subkey, seq, data, signer)).await
     }
 
     /// Add or update a watch to a DHT value that informs the user via an VeilidUpdate::ValueChange callback when the record has subkeys change.
@@ -576,6 +817,258 @@ impl RoutingContext {
         Box::pin(storage_manager.inspect_record(key, subkeys, scope)).await
     }
 
+    ///////////////////////////////////
+    // Mailboxes
+
+    /// Creates a mailbox that other nodes can deposit app messages in while this node is offline.
+    ///
+    /// The mailbox is a DHT record drained in the background whenever this node is attached.
+    /// Each depositor has its own member key and range of slots, so senders can not overwrite each other's messages.
+    /// Deposited messages are delivered as `VeilidUpdate::AppMessage` with no sender and acknowledged so their slots can be reused.
+    /// To have a chosen peer keep the mailbox instead of the DHT, use [RoutingContext::create_peer_mailbox].
+    /// * 'depositor_count' - how many separate addresses to hand out to senders
+    /// * 'slots_per_depositor' - how many undrained messages each depositor can leave at once
+    /// * 'kind' - specify a cryptosystem kind to use. Normally you will leave this as None to choose the 'best' cryptosystem available.
+    ///
+    /// Returns one address per depositor if successful. Give each sender its own address.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn create_mailbox(
+        &self,
+        depositor_count: u16,
+        slots_per_depositor: u16,
+        kind: Option<CryptoKind>,
+    ) -> VeilidAPIResult<Vec<MailboxAddress>> {
+        veilid_log!(self debug
+            "RoutingContext::create_mailbox(self: {:?}, depositor_count: {:?}, slots_per_depositor: {:?}, kind: {:?})", self, depositor_count, slots_per_depositor, kind);
+
+        let kind = kind.unwrap_or(best_crypto_kind());
+        Crypto::validate_crypto_kind(kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.create_mailbox(
+            kind,
+            depositor_count,
+            slots_per_depositor,
+            self.unlocked_inner.safety_selection,
+        ))
+        .await
+    }
+
+    /// Deposits an app message in another node's mailbox.
+    ///
+    /// The message is encrypted to the mailbox owner and delivered with this routing context's app namespace.
+    /// * 'address' - the mailbox address given out by the recipient
+    /// * 'message' - an arbitrary message blob of up to the mailbox's slot size
+    /// * 'expiration' - when the message should be dropped if it has not been drained. Defaults to one week from now.
+    ///
+    /// When this node is offline, the message is written to the local copy of the mailbox and sent when
+    /// it is back online, which needs the mailbox to have been deposited in or opened before.
+    ///
+    /// Fails with `TryAgain` if the address has no free slots.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn deposit_mailbox_message(
+        &self,
+        address: MailboxAddress,
+        message: Vec<u8>,
+        expiration: Option<Timestamp>,
+    ) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "RoutingContext::deposit_mailbox_message(self: {:?}, address: {:?}, message: {:?}, expiration: {:?})", self, address, message, expiration);
+
+        Crypto::validate_crypto_kind(address.record_key().kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.deposit_mailbox_message(
+            address,
+            self.unlocked_inner.app_namespace,
+            message,
+            expiration,
+            self.unlocked_inner.safety_selection,
+        ))
+        .await
+    }
+
+    /// Stops draining a mailbox created by this node and deletes its record.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn delete_mailbox(&self, key: TypedRecordKey) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "RoutingContext::delete_mailbox(self: {:?}, key: {:?})", self, key);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.delete_mailbox(key)).await
+    }
+
+    /// Creates a mailbox kept by a chosen peer, that other nodes can deposit app messages in while this node is offline.
+    ///
+    /// The host must offer DHT storage and be reachable when the mailbox is created. It keeps deposited messages
+    /// until they are drained or expire, which this node does in the background whenever it is attached.
+    /// Deposited messages are delivered as `VeilidUpdate::AppMessage` with no sender.
+    /// * 'host' - the node to keep the mailbox. Its cryptosystem is also used for the mailbox.
+    ///
+    /// Returns the address to give to senders if successful.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn create_peer_mailbox(
+        &self,
+        host: TypedNodeId,
+    ) -> VeilidAPIResult<PeerMailboxAddress> {
+        veilid_log!(self debug
+            "RoutingContext::create_peer_mailbox(self: {:?}, host: {:?})", self, host);
+
+        Crypto::validate_crypto_kind(host.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.create_peer_mailbox(host, self.unlocked_inner.safety_selection))
+            .await
+    }
+
+    /// Deposits an app message in a mailbox kept for another node by a peer.
+    ///
+    /// The message is encrypted to the mailbox owner and delivered with this routing context's app namespace.
+    /// * 'address' - the mailbox address given out by the recipient
+    /// * 'message' - an arbitrary message blob, which is limited to 32768 bytes after encryption
+    /// * 'expiration' - when the message should be dropped if it has not been drained. Defaults to one week from now.
+    ///
+    /// Fails with `TryAgain` if this node is offline, or if the host has no room for the message.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn deposit_peer_mailbox_message(
+        &self,
+        address: PeerMailboxAddress,
+        message: Vec<u8>,
+        expiration: Option<Timestamp>,
+    ) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "RoutingContext::deposit_peer_mailbox_message(self: {:?}, address: {:?}, message: {:?}, expiration: {:?})", self, address, message, expiration);
+
+        Crypto::validate_crypto_kind(address.mailbox().kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.deposit_peer_mailbox_message(
+            address,
+            self.unlocked_inner.app_namespace,
+            message,
+            expiration,
+            self.unlocked_inner.safety_selection,
+        ))
+        .await
+    }
+
+    /// Stops draining a mailbox kept for this node by a peer. The host drops it after it has not been drained for 30 days.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn delete_peer_mailbox(&self, mailbox: TypedPublicKey) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "RoutingContext::delete_peer_mailbox(self: {:?}, mailbox: {:?})", self, mailbox);
+
+        Crypto::validate_crypto_kind(mailbox.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.delete_peer_mailbox(mailbox)).await
+    }
+
+    ///////////////////////////////////
+    // Topics
+
+    /// Creates a pub/sub topic that only the given publishers can publish to.
+    ///
+    /// The topic is a DHT record with one schema member per publisher, so each publisher signs its messages with its
+    /// own keypair and can not overwrite the messages of the others. Subscribers are told which publisher sent each message.
+    /// * 'topic' - the name of the topic, delivered with each message
+    /// * 'publishers' - the public keys of the keypairs publishers will publish with, up to 64
+    /// * 'kind' - specify a cryptosystem kind to use. Normally you will leave this as None to choose the 'best' cryptosystem available.
+    ///
+    /// Returns the DHT record key of the topic to give to publishers and subscribers if successful.
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn create_topic(
+        &self,
+        topic: String,
+        publishers: Vec<PublicKey>,
+        kind: Option<CryptoKind>,
+    ) -> VeilidAPIResult<TypedRecordKey> {
+        veilid_log!(self debug
+            "RoutingContext::create_topic(self: {:?}, topic: {:?}, publishers: {:?}, kind: {:?})", self, topic, publishers, kind);
+
+        let kind = kind.unwrap_or(best_crypto_kind());
+        Crypto::validate_crypto_kind(kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.create_topic(
+            kind,
+            topic,
+            publishers,
+            self.unlocked_inner.safety_selection,
+        ))
+        .await
+    }
+
+    /// Publishes a message to a pub/sub topic.
+    ///
+    /// Each publisher rotates through its own subkeys of the topic record, so a topic holds the most recent messages
+    /// of every publisher for subscribers that join late.
+    /// * 'key' - the DHT record key returned by create_topic()
+    /// * 'publisher' - the keypair of one of the publishers the topic was created with
+    /// * 'message' - an arbitrary message blob of up to the topic's slot size
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn publish_topic(
+        &self,
+        key: TypedRecordKey,
+        publisher: KeyPair,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "RoutingContext::publish_topic(self: {:?}, key: {:?}, publisher: {:?}, message: {:?})", self, key, publisher, message);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.publish_topic(
+            key,
+            publisher,
+            message,
+            self.unlocked_inner.safety_selection,
+        ))
+        .await
+    }
+
+    /// Subscribes to a pub/sub topic, delivering its messages as `VeilidUpdate::TopicMessage`.
+    ///
+    /// The watch on the topic record is renewed automatically, and messages missed while it was down are fetched
+    /// and delivered in publication order.
+    /// * 'key' - the DHT record key returned by create_topic()
+    /// * 'catch_up' - also deliver the messages the topic already holds
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn subscribe_topic(
+        &self,
+        key: TypedRecordKey,
+        catch_up: bool,
+    ) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "RoutingContext::subscribe_topic(self: {:?}, key: {:?}, catch_up: {:?})", self, key, catch_up);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.subscribe_topic(
+            key,
+            catch_up,
+            self.unlocked_inner.safety_selection,
+        ))
+        .await
+    }
+
+    /// Stops delivering the messages of a pub/sub topic.
+    /// * 'key' - the DHT record key passed to subscribe_topic()
+    #[instrument(target = "veilid_api", level = "debug", fields(__VEILID_LOG_KEY = self.log_key()), ret, err)]
+    pub async fn unsubscribe_topic(&self, key: TypedRecordKey) -> VeilidAPIResult<()> {
+        veilid_log!(self debug
+            "RoutingContext::unsubscribe_topic(self: {:?}, key: {:?})", self, key);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        let storage_manager = self.api.core_context()?.storage_manager();
+        Box::pin(storage_manager.unsubscribe_topic(key)).await
+    }
+
     ///////////////////////////////////
     /// Block Store
 
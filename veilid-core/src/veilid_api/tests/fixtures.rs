@@ -160,6 +160,9 @@ pub fn fix_veilidconfig() -> VeilidConfig {
             delete: true,
             device_encryption_key_password: "1234".to_string(),
             new_device_encryption_key_password: Some("5678".to_string()),
+            backend: VeilidConfigProtectedStoreBackend::FileVault,
+            vault_password: "9012".to_string(),
+            external_command: vec!["secret-tool".to_string()],
         },
         table_store: VeilidConfigTableStore {
             directory: "Yellow Pages".to_string(),
@@ -202,6 +205,14 @@ pub fn fix_veilidconfig() -> VeilidConfig {
                 timeout_ms: 3000,
                 max_route_hop_count: 7,
                 default_route_hop_count: 8,
+                queue_share_control: 9,
+                queue_share_routing: 10,
+                queue_share_dht: 11,
+                queue_share_app: 12,
+                queue_weight_control: 13,
+                queue_weight_routing: 14,
+                queue_weight_dht: 15,
+                queue_weight_app: 16,
             },
             dht: VeilidConfigDHT {
                 max_find_node_count: 1,
@@ -31,14 +31,18 @@ pub async fn test_all() {
     test_veilidloglevel();
     test_veilidlog();
     test_attachmentstate();
+    test_powerprofile();
+    test_veilid_diagnostics();
     test_veilidstateattachment();
     test_peertabledata();
     test_veilidstatenetwork();
     test_veilidroutechange();
     test_veilidstateconfig();
     test_veilidvaluechange();
+    test_veilidtopicmessage();
     test_veilidupdate();
     test_veilidstate();
+    test_app_rpc_envelope();
     // test_types_dht
     test_dhtrecorddescriptor();
     test_valuedata();
@@ -48,4 +52,5 @@ pub async fn test_all() {
     test_dhtschema();
     test_dhtschemasmplmember();
     test_dhtschemasmpl();
+    test_dhtschemamult();
 }
@@ -16,6 +16,7 @@ pub fn test_veilidappmessage() {
     let orig = VeilidAppMessage::new(
         Some(fix_typednodeid()),
         Some(fix_routeid()),
+        7,
         b"Hi there!".to_vec(),
     );
     let copy = deserialize_json(&serialize_json(&orig)).unwrap();
@@ -27,6 +28,7 @@ pub fn test_veilidappcall() {
     let orig = VeilidAppCall::new(
         Some(fix_typednodeid()),
         Some(fix_routeid()),
+        7,
         b"Well, hello!".to_vec(),
         OperationId::from(123),
     );
@@ -208,6 +210,40 @@ pub fn test_attachmentstate() {
     assert_eq!(orig, copy);
 }
 
+pub fn test_powerprofile() {
+    let orig = PowerProfile::LowPower;
+    let copy = deserialize_json(&serialize_json(orig)).unwrap();
+
+    assert_eq!(orig, copy);
+    assert_eq!(PowerProfile::try_from(orig.to_string().as_str()), Ok(orig));
+}
+
+pub fn test_veilid_diagnostics() {
+    let orig = VeilidDiagnostics {
+        timestamp: Timestamp::new(1_700_000_000_000_000),
+        attachment: AttachmentState::AttachedWeak,
+        findings: vec![
+            VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Warning,
+                DiagnosticKind::SymmetricNAT,
+                "Behind a symmetric NAT",
+                "Forward the listen ports",
+            ),
+            VeilidDiagnosticFinding::new(
+                DiagnosticSeverity::Info,
+                DiagnosticKind::UPnPGatewayNotFound,
+                "No UPnP gateway was found",
+                "Enable UPnP on the router",
+            ),
+        ],
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+    assert_eq!(copy.worst_severity(), Some(DiagnosticSeverity::Warning));
+    assert!(!copy.is_healthy());
+}
+
 pub fn test_veilidstateattachment() {
     let orig = VeilidStateAttachment {
         state: AttachmentState::OverAttached,
@@ -266,6 +302,19 @@ pub fn test_veilidvaluechange() {
     assert_eq!(orig, copy);
 }
 
+pub fn test_veilidtopicmessage() {
+    let orig = VeilidTopicMessage {
+        key: fix_typedrecordkey(),
+        topic: "weather/reports".to_string(),
+        publisher: fix_publickey(),
+        published: Timestamp::new(1_700_000_000_000_000),
+        message: b"Hi there!".to_vec(),
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+}
+
 pub fn test_veilidupdate() {
     let orig = VeilidUpdate::ValueChange(Box::new(fix_veilidvaluechange()));
     let copy = deserialize_json(&serialize_json(&orig)).unwrap();
@@ -296,3 +345,44 @@ pub fn test_veilidstate() {
 
     assert_eq!(orig, copy);
 }
+
+// app_rpc
+
+app_rpc_method!(TestEcho = "test.echo", String => String);
+
+pub fn test_app_rpc_envelope() {
+    let orig = AppRpcRequest::new(7, TestEcho::NAME, serde_json::json!("hello"));
+    let copy: AppRpcRequest = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+    assert_eq!(copy.rpc, APP_RPC_VERSION);
+
+    let orig = AppRpcResponse::new(7, Ok(serde_json::json!("hello")));
+    let copy: AppRpcResponse = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+    assert_eq!(copy.into_result(), Ok(serde_json::json!("hello")));
+
+    let orig = AppRpcResponse::new(
+        8,
+        Err(AppRpcError::remote(VeilidAPIError::invalid_argument(
+            "test", "message", "",
+        ))),
+    );
+    let copy: AppRpcResponse = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+    assert_eq!(
+        VeilidAPIError::from(copy.into_result().unwrap_err()),
+        VeilidAPIError::invalid_argument("test", "message", "")
+    );
+
+    let orig = AppRpcServiceDescription {
+        namespace: 3,
+        methods: vec![AppRpcMethodDescription::new::<TestEcho>()],
+    };
+    let copy: AppRpcServiceDescription = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+    assert_eq!(copy.methods[0].name, "test.echo");
+}
@@ -65,3 +65,38 @@ pub fn test_dhtschemasmpl() {
 
     assert_eq!(orig, copy);
 }
+
+// mult
+
+pub fn test_dhtschemamult() {
+    let owners = vec![fix_publickey(), fix_publickey(), fix_publickey()];
+    let orig = DHTSchemaMULT::new(
+        4,
+        2,
+        owners.clone(),
+        vec![DHTSchemaSMPLMember {
+            m_key: fix_publickey(),
+            m_cnt: 3,
+        }],
+    )
+    .unwrap();
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+
+    let compiled = DHTSchemaMULT::try_from(orig.compile().as_slice()).unwrap();
+    assert_eq!(orig, compiled);
+
+    // Owner subkeys need enough distinct owner signatures to meet the threshold
+    let owner = fix_publickey();
+    let value_data = ValueData::new(b"value".to_vec(), owners[0]).unwrap();
+    assert!(!orig.check_subkey_value_data(&owner, 0, &value_data, &[]));
+    assert!(!orig.check_subkey_value_data(&owner, 0, &value_data, &[owners[0]]));
+    assert!(!orig.check_subkey_value_data(&owner, 0, &value_data, &[fix_publickey()]));
+    assert!(orig.check_subkey_value_data(&owner, 0, &value_data, &[owners[2]]));
+
+    // Member subkeys are never cosigned
+    let member_data = ValueData::new(b"value".to_vec(), orig.members()[0].m_key).unwrap();
+    assert!(orig.check_subkey_value_data(&owner, 4, &member_data, &[]));
+    assert!(!orig.check_subkey_value_data(&owner, 4, &member_data, &[owners[1]]));
+}
@@ -21,6 +21,9 @@ pub struct VeilidAppMessage {
     )]
     route_id: Option<RouteId>,
 
+    #[serde(default)]
+    namespace: u32,
+
     #[cfg_attr(
         not(all(target_arch = "wasm32", target_os = "unknown")),
         serde(with = "as_human_base64")
@@ -35,10 +38,16 @@ pub struct VeilidAppMessage {
 }
 
 impl VeilidAppMessage {
-    pub fn new(sender: Option<TypedNodeId>, route_id: Option<RouteId>, message: Vec<u8>) -> Self {
+    pub fn new(
+        sender: Option<TypedNodeId>,
+        route_id: Option<RouteId>,
+        namespace: u32,
+        message: Vec<u8>,
+    ) -> Self {
         Self {
             sender,
             route_id,
+            namespace,
             message,
         }
     }
@@ -55,6 +64,12 @@ impl VeilidAppMessage {
         self.route_id.as_ref()
     }
 
+    /// The application namespace the message was addressed to, 0 if the sender did not choose one.
+    #[must_use]
+    pub fn namespace(&self) -> u32 {
+        self.namespace
+    }
+
     /// The content of the message to deliver to the application.
     #[must_use]
     pub fn message(&self) -> &[u8] {
@@ -80,6 +95,9 @@ pub struct VeilidAppCall {
     )]
     route_id: Option<RouteId>,
 
+    #[serde(default)]
+    namespace: u32,
+
     #[cfg_attr(
         not(all(target_arch = "wasm32", target_os = "unknown")),
         serde(with = "as_human_base64")
@@ -101,12 +119,14 @@ impl VeilidAppCall {
     pub fn new(
         sender: Option<TypedNodeId>,
         route_id: Option<RouteId>,
+        namespace: u32,
         message: Vec<u8>,
         call_id: OperationId,
     ) -> Self {
         Self {
             sender,
             route_id,
+            namespace,
             message,
             call_id,
         }
@@ -124,6 +144,12 @@ impl VeilidAppCall {
         self.route_id.as_ref()
     }
 
+    /// The application namespace the request was addressed to, 0 if the sender did not choose one.
+    #[must_use]
+    pub fn namespace(&self) -> u32 {
+        self.namespace
+    }
+
     /// The content of the request to deliver to the application.
     #[must_use]
     pub fn message(&self) -> &[u8] {
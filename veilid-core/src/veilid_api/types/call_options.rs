@@ -0,0 +1,107 @@
+use super::*;
+
+/// A handle that can be used to cancel an in-flight operation started with [CallOptions].
+///
+/// Clones of a handle share the same cancellation state, so one clone can be passed
+/// to the operation while another is kept by the UI to abort it.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct CancelHandle {
+    stop_source: Arc<Mutex<Option<StopSource>>>,
+    stop_token: StopToken,
+}
+
+impl CancelHandle {
+    pub fn new() -> Self {
+        let stop_source = StopSource::new();
+        let stop_token = stop_source.token();
+        Self {
+            stop_source: Arc::new(Mutex::new(Some(stop_source))),
+            stop_token,
+        }
+    }
+
+    /// Cancel every operation using this handle. Operations in progress return
+    /// [VeilidAPIError::Cancelled] and operations started later fail immediately.
+    pub fn cancel(&self) {
+        self.stop_source.lock().take();
+    }
+
+    /// Returns true if [CancelHandle::cancel] has been called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.stop_source.lock().is_none()
+    }
+
+    pub(crate) fn stop_token(&self) -> StopToken {
+        self.stop_token.clone()
+    }
+}
+
+impl Default for CancelHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Per-call options for app calls and DHT operations.
+///
+/// By default an operation uses the timeouts from the node configuration and can not be cancelled.
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CallOptions {
+    deadline: Option<Timestamp>,
+    cancel_handle: Option<CancelHandle>,
+}
+
+impl CallOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up on the operation at an absolute time. The configured timeouts still apply if they are shorter.
+    pub fn with_deadline(mut self, deadline: Timestamp) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Give up on the operation after a duration from now.
+    pub fn with_timeout(self, timeout: TimestampDuration) -> Self {
+        self.with_deadline(Timestamp::now() + timeout)
+    }
+
+    /// Allow the operation to be aborted with a cancel handle.
+    pub fn with_cancel_handle(mut self, cancel_handle: CancelHandle) -> Self {
+        self.cancel_handle = Some(cancel_handle);
+        self
+    }
+
+    #[must_use]
+    pub fn deadline(&self) -> Option<Timestamp> {
+        self.deadline
+    }
+
+    #[must_use]
+    pub fn cancel_handle(&self) -> Option<&CancelHandle> {
+        self.cancel_handle.as_ref()
+    }
+
+    /// Shorten a configured timeout so it does not extend past the deadline
+    pub(crate) fn limit_timeout(&self, timeout_us: TimestampDuration) -> TimestampDuration {
+        match self.deadline {
+            Some(deadline) => timeout_us.min(deadline.saturating_sub(Timestamp::now())),
+            None => timeout_us,
+        }
+    }
+
+    pub(crate) fn stop_token(&self) -> Option<StopToken> {
+        self.cancel_handle.as_ref().map(|ch| ch.stop_token())
+    }
+
+    #[must_use]
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancel_handle
+            .as_ref()
+            .is_some_and(|ch| ch.is_cancelled())
+    }
+}
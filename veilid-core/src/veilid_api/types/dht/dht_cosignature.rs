@@ -0,0 +1,36 @@
+use super::*;
+
+/// A signature by one of the owner keys of a MULT schema record over a value written to one of its owner subkeys.
+/// Cosignatures are made with [RoutingContext::cosign_dht_value] and passed to [RoutingContext::set_dht_value_cosigned]
+/// by the owner writing the value, so that together the writer and cosigners meet the schema threshold.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+#[must_use]
+pub struct DHTCosignature {
+    /// The owner key that made the signature
+    #[schemars(with = "String")]
+    signer: PublicKey,
+    /// The signature over the owner, subkey, sequence number and data of the value
+    #[schemars(with = "String")]
+    signature: Signature,
+}
+
+impl DHTCosignature {
+    pub fn new(signer: PublicKey, signature: Signature) -> Self {
+        Self { signer, signature }
+    }
+
+    pub fn signer(&self) -> &PublicKey {
+        &self.signer
+    }
+
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+/// Address that senders use to deposit messages in a mailbox
+///
+/// Each address has its own depositor keypair, which only grants write access to that depositor's slots.
+/// Messages are sealed to the record owner, so holding the address does not allow reading them.
+#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+#[must_use]
+pub struct MailboxAddress {
+    /// The DHT record backing the mailbox
+    #[schemars(with = "String")]
+    record_key: TypedRecordKey,
+    /// The member keypair that writes to the mailbox slots
+    #[schemars(with = "String")]
+    depositor: KeyPair,
+}
+
+impl MailboxAddress {
+    pub(crate) fn new(record_key: TypedRecordKey, depositor: KeyPair) -> Self {
+        Self {
+            record_key,
+            depositor,
+        }
+    }
+
+    pub fn record_key(&self) -> &TypedRecordKey {
+        &self.record_key
+    }
+    pub fn depositor(&self) -> &KeyPair {
+        &self.depositor
+    }
+}
@@ -1,13 +1,19 @@
+mod dht_cosignature;
 mod dht_record_descriptor;
 mod dht_record_report;
+mod mailbox_address;
+mod peer_mailbox_address;
 mod schema;
 mod value_data;
 mod value_subkey_range_set;
 
 use super::*;
 
+pub use dht_cosignature::*;
 pub use dht_record_descriptor::*;
 pub use dht_record_report::*;
+pub use mailbox_address::*;
+pub use peer_mailbox_address::*;
 pub use schema::*;
 pub use value_data::*;
 pub use value_subkey_range_set::*;
@@ -0,0 +1,34 @@
+use super::*;
+
+/// Address that senders use to deposit messages in a mailbox kept by a peer
+///
+/// Messages are sealed to the mailbox key, so holding the address does not allow reading them.
+/// Only the node that created the mailbox can drain it.
+#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+#[must_use]
+pub struct PeerMailboxAddress {
+    /// The node that keeps the mailbox
+    #[schemars(with = "String")]
+    host: TypedNodeId,
+    /// The public key of the mailbox owner
+    #[schemars(with = "String")]
+    mailbox: TypedPublicKey,
+}
+
+impl PeerMailboxAddress {
+    pub(crate) fn new(host: TypedNodeId, mailbox: TypedPublicKey) -> Self {
+        Self { host, mailbox }
+    }
+
+    pub fn host(&self) -> &TypedNodeId {
+        &self.host
+    }
+    pub fn mailbox(&self) -> &TypedPublicKey {
+        &self.mailbox
+    }
+}
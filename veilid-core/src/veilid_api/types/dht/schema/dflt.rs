@@ -62,13 +62,19 @@ impl DHTSchemaDFLT {
     }
 
     /// Check a subkey value data against the schema
+    /// Values in this schema are never cosigned
     #[must_use]
     pub fn check_subkey_value_data(
         &self,
         owner: &PublicKey,
         subkey: ValueSubkey,
         value_data: &ValueData,
+        cosigners: &[PublicKey],
     ) -> bool {
+        if !cosigners.is_empty() {
+            return false;
+        }
+
         let subkey = subkey as usize;
 
         // Check if subkey is in owner range
@@ -1,9 +1,11 @@
 mod dflt;
+mod mult;
 mod smpl;
 
 use super::*;
 
 pub use dflt::*;
+pub use mult::*;
 pub use smpl::*;
 
 /// Enum over all the supported DHT Schemas
@@ -18,6 +20,7 @@ pub use smpl::*;
 pub enum DHTSchema {
     DFLT(DHTSchemaDFLT),
     SMPL(DHTSchemaSMPL),
+    MULT(DHTSchemaMULT),
 }
 
 impl DHTSchema {
@@ -27,12 +30,23 @@ impl DHTSchema {
     pub fn smpl(o_cnt: u16, members: Vec<DHTSchemaSMPLMember>) -> VeilidAPIResult<DHTSchema> {
         Ok(DHTSchema::SMPL(DHTSchemaSMPL::new(o_cnt, members)?))
     }
+    pub fn mult(
+        o_cnt: u16,
+        threshold: u8,
+        owners: Vec<PublicKey>,
+        members: Vec<DHTSchemaSMPLMember>,
+    ) -> VeilidAPIResult<DHTSchema> {
+        Ok(DHTSchema::MULT(DHTSchemaMULT::new(
+            o_cnt, threshold, owners, members,
+        )?))
+    }
 
     /// Validate the data representation
     pub fn validate(&self) -> VeilidAPIResult<()> {
         match self {
             DHTSchema::DFLT(d) => d.validate(),
             DHTSchema::SMPL(s) => s.validate(),
+            DHTSchema::MULT(m) => m.validate(),
         }
     }
 
@@ -42,6 +56,7 @@ impl DHTSchema {
         match self {
             DHTSchema::DFLT(d) => d.compile(),
             DHTSchema::SMPL(s) => s.compile(),
+            DHTSchema::MULT(m) => m.compile(),
         }
     }
 
@@ -51,6 +66,7 @@ impl DHTSchema {
         match self {
             DHTSchema::DFLT(d) => d.max_subkey(),
             DHTSchema::SMPL(s) => s.max_subkey(),
+            DHTSchema::MULT(m) => m.max_subkey(),
         }
     }
 
@@ -60,20 +76,24 @@ impl DHTSchema {
         match self {
             DHTSchema::DFLT(d) => d.data_size(),
             DHTSchema::SMPL(s) => s.data_size(),
+            DHTSchema::MULT(m) => m.data_size(),
         }
     }
 
     /// Check a subkey value data against the schema
+    /// `cosigners` are the keys that cosigned the value in addition to its writer, whose signatures have been verified
     #[must_use]
     pub fn check_subkey_value_data(
         &self,
         owner: &PublicKey,
         subkey: ValueSubkey,
         value_data: &ValueData,
+        cosigners: &[PublicKey],
     ) -> bool {
         match self {
-            DHTSchema::DFLT(d) => d.check_subkey_value_data(owner, subkey, value_data),
-            DHTSchema::SMPL(s) => s.check_subkey_value_data(owner, subkey, value_data),
+            DHTSchema::DFLT(d) => d.check_subkey_value_data(owner, subkey, value_data, cosigners),
+            DHTSchema::SMPL(s) => s.check_subkey_value_data(owner, subkey, value_data, cosigners),
+            DHTSchema::MULT(m) => m.check_subkey_value_data(owner, subkey, value_data, cosigners),
         }
     }
 
@@ -83,6 +103,7 @@ impl DHTSchema {
         match self {
             DHTSchema::DFLT(d) => d.is_member(key),
             DHTSchema::SMPL(s) => s.is_member(key),
+            DHTSchema::MULT(m) => m.is_member(key),
         }
     }
 
@@ -129,6 +150,7 @@ impl TryFrom<&[u8]> for DHTSchema {
         match fcc {
             DHTSchemaDFLT::FCC => Ok(DHTSchema::DFLT(DHTSchemaDFLT::try_from(b)?)),
             DHTSchemaSMPL::FCC => Ok(DHTSchema::SMPL(DHTSchemaSMPL::try_from(b)?)),
+            DHTSchemaMULT::FCC => Ok(DHTSchema::MULT(DHTSchemaMULT::try_from(b)?)),
             _ => {
                 apibail_generic!("unknown fourcc");
             }
@@ -0,0 +1,285 @@
+use super::*;
+use crate::storage_manager::{MAX_RECORD_DATA_SIZE, MAX_SUBKEY_SIZE};
+
+/// Multi-owner DHT Schema (MULT)
+///
+/// Like SMPL, but the owner subkeys belong to a set of owner keys rather than to the record owner.
+/// A value written to an owner subkey must be signed by at least `threshold` of the owner keys:
+/// one signature from the writer, and the rest as cosignatures carried along with the value.
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(from_wasm_abi)
+)]
+#[must_use]
+pub struct DHTSchemaMULT {
+    /// Owner subkey count
+    o_cnt: u16,
+    /// Number of owner keys that must sign a value written to an owner subkey
+    threshold: u8,
+    /// Owner keys
+    #[schemars(with = "Vec<String>")]
+    owners: Vec<PublicKey>,
+    /// Members
+    members: Vec<DHTSchemaSMPLMember>,
+}
+
+impl DHTSchemaMULT {
+    pub const FCC: [u8; 4] = *b"MULT";
+    pub const FIXED_SIZE: usize = 8;
+    pub const MAX_OWNERS: usize = 32;
+
+    /// Make a schema
+    pub fn new(
+        o_cnt: u16,
+        threshold: u8,
+        owners: Vec<PublicKey>,
+        members: Vec<DHTSchemaSMPLMember>,
+    ) -> VeilidAPIResult<Self> {
+        let out = Self {
+            o_cnt,
+            threshold,
+            owners,
+            members,
+        };
+        out.validate()?;
+        Ok(out)
+    }
+
+    /// Validate the data representation
+    pub fn validate(&self) -> VeilidAPIResult<()> {
+        let keycount = self
+            .members
+            .iter()
+            .fold(self.o_cnt as usize, |acc, x| acc + (x.m_cnt as usize));
+
+        if keycount == 0 {
+            apibail_invalid_argument!("must have at least one subkey", "keycount", keycount);
+        }
+        if keycount > 65535 {
+            apibail_invalid_argument!("too many subkeys", "keycount", keycount);
+        }
+        if self.owners.is_empty() {
+            apibail_invalid_argument!("must have at least one owner", "owners", 0);
+        }
+        if self.owners.len() > Self::MAX_OWNERS {
+            apibail_invalid_argument!("too many owners", "owners", self.owners.len());
+        }
+        for (n, owner) in self.owners.iter().enumerate() {
+            if self.owners[0..n].contains(owner) {
+                apibail_invalid_argument!("duplicate owner", "owners", owner);
+            }
+        }
+        if self.threshold == 0 || (self.threshold as usize) > self.owners.len() {
+            apibail_invalid_argument!(
+                "threshold must be between one and the number of owners",
+                "threshold",
+                self.threshold
+            );
+        }
+        Ok(())
+    }
+
+    /// Get the owner subkey count
+    #[must_use]
+    pub fn o_cnt(&self) -> u16 {
+        self.o_cnt
+    }
+
+    /// Get the number of owner keys that must sign owner subkey values
+    #[must_use]
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Get the owner keys of the schema
+    pub fn owners(&self) -> &[PublicKey] {
+        &self.owners
+    }
+
+    /// Get the members of the schema
+    pub fn members(&self) -> &[DHTSchemaSMPLMember] {
+        &self.members
+    }
+
+    /// Build the data representation of the schema
+    #[must_use]
+    pub fn compile(&self) -> Vec<u8> {
+        let mut out = Vec::<u8>::with_capacity(
+            Self::FIXED_SIZE
+                + (self.owners.len() * PUBLIC_KEY_LENGTH)
+                + (self.members.len() * (PUBLIC_KEY_LENGTH + 2)),
+        );
+        // kind
+        out.extend_from_slice(&Self::FCC);
+        // o_cnt
+        out.extend_from_slice(&self.o_cnt.to_le_bytes());
+        // threshold
+        out.push(self.threshold);
+        // owner count
+        out.push(self.owners.len() as u8);
+        // owners
+        for o in &self.owners {
+            out.extend_from_slice(&o.bytes);
+        }
+        // members
+        for m in &self.members {
+            // m_key
+            out.extend_from_slice(&m.m_key.bytes);
+            // m_cnt
+            out.extend_from_slice(&m.m_cnt.to_le_bytes());
+        }
+        out
+    }
+
+    /// Get the maximum subkey this schema allocates
+    #[must_use]
+    pub fn max_subkey(&self) -> ValueSubkey {
+        let subkey_count = self
+            .members
+            .iter()
+            .fold(self.o_cnt as usize, |acc, x| acc + (x.m_cnt as usize));
+        (subkey_count - 1) as ValueSubkey
+    }
+
+    /// Get the data size of this schema beyond the size of the structure itself
+    #[must_use]
+    pub fn data_size(&self) -> usize {
+        self.owners.len() * mem::size_of::<PublicKey>()
+            + self.members.len() * mem::size_of::<DHTSchemaSMPLMember>()
+    }
+
+    /// Check a subkey value data against the schema
+    /// Owner subkeys must be written by an owner key and cosigned by enough other owner keys to meet the threshold
+    #[must_use]
+    pub fn check_subkey_value_data(
+        &self,
+        _owner: &PublicKey,
+        subkey: ValueSubkey,
+        value_data: &ValueData,
+        cosigners: &[PublicKey],
+    ) -> bool {
+        let mut cur_subkey = subkey as usize;
+
+        let max_value_len = usize::min(
+            MAX_SUBKEY_SIZE,
+            MAX_RECORD_DATA_SIZE / (self.max_subkey() + 1) as usize,
+        );
+
+        // Check if subkey is in owner range
+        if cur_subkey < (self.o_cnt as usize) {
+            // Check value data has valid writer
+            if !self.owners.contains(value_data.writer()) {
+                // Wrong writer
+                return false;
+            }
+
+            // Check every cosigner is a different owner
+            let mut signers = vec![value_data.writer()];
+            for cosigner in cosigners {
+                if !self.owners.contains(cosigner) || signers.contains(&cosigner) {
+                    // Wrong or repeated cosigner
+                    return false;
+                }
+                signers.push(cosigner);
+            }
+            if signers.len() < self.threshold as usize {
+                // Not enough signatures
+                return false;
+            }
+
+            // Ensure value size is within additional limit
+            if value_data.data_size() <= max_value_len {
+                return true;
+            }
+
+            // Value too big
+            return false;
+        }
+        cur_subkey -= self.o_cnt as usize;
+
+        // Member subkeys are not cosigned
+        if !cosigners.is_empty() {
+            return false;
+        }
+
+        // Check all member ranges
+        for m in &self.members {
+            // Check if subkey is in member range
+            if cur_subkey < (m.m_cnt as usize) {
+                // Check value data has valid writer
+                if value_data.writer() == &m.m_key {
+                    // Ensure value size is in allowed range
+                    if value_data.data_size() <= max_value_len {
+                        return true;
+                    }
+
+                    // Value too big
+                    return false;
+                }
+                // Wrong writer
+                return false;
+            }
+            cur_subkey -= m.m_cnt as usize;
+        }
+
+        // Subkey out of range
+        false
+    }
+
+    /// Check if a key is a schema member
+    /// Owner keys are members too
+    #[must_use]
+    pub fn is_member(&self, key: &PublicKey) -> bool {
+        self.owners.contains(key) || self.members.iter().any(|m| m.m_key == *key)
+    }
+}
+
+impl TryFrom<&[u8]> for DHTSchemaMULT {
+    type Error = VeilidAPIError;
+    fn try_from(b: &[u8]) -> Result<Self, Self::Error> {
+        if b.len() < Self::FIXED_SIZE {
+            apibail_generic!("invalid size");
+        }
+        if b[0..4] != Self::FCC {
+            apibail_generic!("wrong fourcc");
+        }
+
+        let o_cnt = u16::from_le_bytes(b[4..6].try_into().map_err(VeilidAPIError::internal)?);
+        let threshold = b[6];
+        let owners_len = b[7] as usize;
+
+        let members_start = Self::FIXED_SIZE + owners_len * PUBLIC_KEY_LENGTH;
+        if b.len() < members_start {
+            apibail_generic!("invalid owner length");
+        }
+        if (b.len() - members_start) % (PUBLIC_KEY_LENGTH + 2) != 0 {
+            apibail_generic!("invalid member length");
+        }
+
+        let mut owners: Vec<PublicKey> = Vec::with_capacity(owners_len);
+        for n in 0..owners_len {
+            let ostart = Self::FIXED_SIZE + n * PUBLIC_KEY_LENGTH;
+            let o_key = PublicKey::try_from(&b[ostart..ostart + PUBLIC_KEY_LENGTH])
+                .map_err(VeilidAPIError::internal)?;
+            owners.push(o_key);
+        }
+
+        let members_len = (b.len() - members_start) / (PUBLIC_KEY_LENGTH + 2);
+        let mut members: Vec<DHTSchemaSMPLMember> = Vec::with_capacity(members_len);
+        for n in 0..members_len {
+            let mstart = members_start + n * (PUBLIC_KEY_LENGTH + 2);
+            let m_key = PublicKey::try_from(&b[mstart..mstart + PUBLIC_KEY_LENGTH])
+                .map_err(VeilidAPIError::internal)?;
+            let m_cnt = u16::from_le_bytes(
+                b[mstart + PUBLIC_KEY_LENGTH..mstart + PUBLIC_KEY_LENGTH + 2]
+                    .try_into()
+                    .map_err(VeilidAPIError::internal)?,
+            );
+            members.push(DHTSchemaSMPLMember { m_key, m_cnt });
+        }
+
+        Self::new(o_cnt, threshold, owners, members)
+    }
+}
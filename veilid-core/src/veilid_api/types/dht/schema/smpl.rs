@@ -107,13 +107,19 @@ impl DHTSchemaSMPL {
     }
 
     /// Check a subkey value data against the schema
+    /// Values in this schema are never cosigned
     #[must_use]
     pub fn check_subkey_value_data(
         &self,
         owner: &PublicKey,
         subkey: ValueSubkey,
         value_data: &ValueData,
+        cosigners: &[PublicKey],
     ) -> bool {
+        if !cosigners.is_empty() {
+            return false;
+        }
+
         let mut cur_subkey = subkey as usize;
 
         let max_value_len = usize::min(
@@ -0,0 +1,148 @@
+use super::*;
+
+/// How serious a diagnostic finding is.
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(namespace)
+)]
+#[must_use]
+pub enum DiagnosticSeverity {
+    /// Worth knowing about, but connectivity is not impaired.
+    Info = 0,
+    /// The node works, but its connectivity is degraded or fragile.
+    Warning = 1,
+    /// Something is keeping the node from working properly.
+    Error = 2,
+}
+
+/// The condition a diagnostic finding describes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(namespace)
+)]
+#[must_use]
+pub enum DiagnosticKind {
+    /// The node is not attached to the network.
+    NotAttached,
+    /// The node is still attaching, so the report may be incomplete.
+    Attaching,
+    /// Detection of the public network class has not finished.
+    NetworkClassUnknown,
+    /// No inbound dial info was validated, so the node can only make outbound connections.
+    OutboundOnly,
+    /// Too few peers reported our external address to run NAT detection.
+    ExternalAddressUnknown,
+    /// The node is behind a symmetric NAT and can not be reached directly or by hole punching.
+    SymmetricNAT,
+    /// The node is behind a NAT or firewall that needs a relay or hole punching to reach it.
+    RestrictedNAT,
+    /// Connections to peers' dial info keep failing.
+    DialInfoFailures,
+    /// UPnP is enabled but no gateway answered.
+    UPnPGatewayNotFound,
+    /// UPnP port mappings are not being renewed.
+    UPnPMappingFailing,
+    /// A relay is required but none is selected.
+    NoRelay,
+    /// The selected relay is not responding reliably.
+    RelayUnreliable,
+    /// Too few peers are reachable for the node to work well.
+    FewPeers,
+    /// Envelopes are being rejected because their timestamps are too far from our clock.
+    ClockSkew,
+}
+
+/// A single problem found by [VeilidAPI::diagnose](crate::VeilidAPI::diagnose), with a suggested fix.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[must_use]
+pub struct VeilidDiagnosticFinding {
+    /// how serious the finding is
+    pub severity: DiagnosticSeverity,
+    /// what the finding is about
+    pub kind: DiagnosticKind,
+    /// what was observed
+    pub description: String,
+    /// what the user or application can do about it
+    pub suggestion: String,
+}
+
+impl VeilidDiagnosticFinding {
+    pub fn new<D: ToString, S: ToString>(
+        severity: DiagnosticSeverity,
+        kind: DiagnosticKind,
+        description: D,
+        suggestion: S,
+    ) -> Self {
+        Self {
+            severity,
+            kind,
+            description: description.to_string(),
+            suggestion: suggestion.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for VeilidDiagnosticFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:?}] {:?}: {}\n    fix: {}",
+            self.severity, self.kind, self.description, self.suggestion
+        )
+    }
+}
+
+/// A health check of the node's connectivity, as returned by [VeilidAPI::diagnose](crate::VeilidAPI::diagnose).
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(into_wasm_abi)
+)]
+#[must_use]
+pub struct VeilidDiagnostics {
+    /// when the report was made
+    pub timestamp: Timestamp,
+    /// the attachment state when the report was made
+    pub attachment: AttachmentState,
+    /// everything found, most severe first
+    pub findings: Vec<VeilidDiagnosticFinding>,
+}
+
+impl VeilidDiagnostics {
+    /// Returns true if nothing worse than [DiagnosticSeverity::Info] was found.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.worst_severity()
+            .is_none_or(|s| s == DiagnosticSeverity::Info)
+    }
+
+    /// The severity of the most serious finding, if there are any.
+    #[must_use]
+    pub fn worst_severity(&self) -> Option<DiagnosticSeverity> {
+        self.findings.iter().map(|x| x.severity).max()
+    }
+}
+
+impl fmt::Display for VeilidDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Attachment: {}", self.attachment)?;
+        if self.findings.is_empty() {
+            return write!(f, "No problems found");
+        }
+        for (n, finding) in self.findings.iter().enumerate() {
+            if n != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", finding)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+
+/// Counters and gauges for one RPC priority class queue.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[must_use]
+pub struct VeilidMetricsRPCQueue {
+    /// number of messages of this class waiting for an rpc worker
+    pub depth: u32,
+    /// maximum number of messages of this class that can wait for an rpc worker
+    pub capacity: u32,
+    /// share of the rpc workers this class gets relative to the other classes
+    pub weight: u32,
+    /// number of messages of this class queued
+    pub enqueued: u64,
+    /// number of messages of this class dropped because the queue was full
+    pub dropped: u64,
+    /// latency between enqueuing a message of this class and a worker picking it up
+    pub dequeue_latency: LatencyStats,
+}
+
+/// Counters and gauges for the RPC processor.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[must_use]
+pub struct VeilidMetricsRPC {
+    /// number of messages waiting for an rpc worker
+    pub queue_depth: u32,
+    /// maximum number of messages that can wait for an rpc worker
+    pub queue_capacity: u32,
+    /// queue statistics for each rpc priority class, by class name
+    pub queues: BTreeMap<String, VeilidMetricsRPCQueue>,
+    /// number of rpc workers
+    pub worker_count: u32,
+    /// number of questions waiting for an answer
+    pub questions_in_flight: u32,
+    /// latency between enqueuing a message and a worker picking it up
+    pub dequeue_latency: LatencyStats,
+    /// time taken by a worker to process a message
+    pub process_latency: LatencyStats,
+    /// number of operations received, by operation kind
+    pub operations_rcvd: BTreeMap<String, u64>,
+    /// number of operations sent, by operation kind
+    pub operations_sent: BTreeMap<String, u64>,
+}
+
+/// Sizes of the DHT record stores.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[must_use]
+pub struct VeilidMetricsDHT {
+    /// number of records in the local record store
+    pub local_record_count: u64,
+    /// bytes used by the local record store
+    pub local_storage_bytes: u64,
+    /// number of records in the remote record store
+    pub remote_record_count: u64,
+    /// bytes used by the remote record store
+    pub remote_storage_bytes: u64,
+    /// number of records currently opened by this node
+    pub opened_record_count: u64,
+    /// number of records with subkey writes waiting to be sent to the network
+    pub offline_subkey_write_count: u64,
+}
+
+/// Relay usage, both of our own relays and of us relaying for others.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[must_use]
+pub struct VeilidMetricsRelay {
+    /// whether this node uses a relay in the public internet routing domain
+    pub public_internet_relay: bool,
+    /// whether this node uses a relay in the local network routing domain
+    pub local_network_relay: bool,
+    /// number of envelopes this node has relayed for other nodes
+    pub relayed_envelopes: u64,
+    /// number of envelopes waiting for a relay worker
+    pub queue_depth: u32,
+    /// latency between enqueuing an envelope and a relay worker picking it up
+    pub dequeue_latency: LatencyStats,
+    /// time taken by a relay worker to send an envelope
+    pub process_latency: LatencyStats,
+}
+
+/// Currently active punishments.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[must_use]
+pub struct VeilidMetricsPunishments {
+    /// number of punished ipv4 addresses
+    pub ipv4_addresses: u64,
+    /// number of punished ipv6 address prefixes
+    pub ipv6_prefixes: u64,
+    /// number of punished node ids
+    pub node_ids: u64,
+}
+
+/// A point-in-time snapshot of internal counters, for export to monitoring systems.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(into_wasm_abi)
+)]
+#[must_use]
+pub struct VeilidMetrics {
+    pub rpc: VeilidMetricsRPC,
+    pub dht: VeilidMetricsDHT,
+    pub relay: VeilidMetricsRelay,
+    pub punishments: VeilidMetricsPunishments,
+}
@@ -1,10 +1,14 @@
 #[macro_use]
 mod aligned_u64;
 mod app_message_call;
+mod call_options;
 #[cfg(feature = "geolocation")]
 mod country_code;
 mod dht;
+mod diagnostics;
 mod fourcc;
+mod metrics;
+mod power_profile;
 mod safety;
 mod stats;
 mod timestamp;
@@ -18,9 +22,13 @@ use super::*;
 
 pub use aligned_u64::*;
 pub use app_message_call::*;
+pub use call_options::*;
 #[cfg(feature = "geolocation")]
 pub use country_code::*;
 pub use dht::*;
+pub use diagnostics::*;
+pub use metrics::*;
+pub use power_profile::*;
 pub use safety::*;
 pub use stats::*;
 pub use timestamp::*;
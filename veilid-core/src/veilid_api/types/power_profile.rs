@@ -0,0 +1,50 @@
+use super::*;
+
+/// How aggressively the node maintains its view of the network.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(namespace, from_wasm_abi, into_wasm_abi)
+)]
+#[must_use]
+pub enum PowerProfile {
+    /// Full background maintenance at the regular cadence.
+    #[default]
+    Normal = 0,
+    /// Battery-saving mode for mobile and constrained devices.
+    ///
+    /// The node ticks less often, validates peers and refreshes its closest peers infrequently,
+    /// keeps its current relay instead of optimizing it, and defers DHT record rehydration and
+    /// record store flushes. Relay keepalives continue so the node stays reachable.
+    LowPower = 1,
+}
+
+impl PowerProfile {
+    #[must_use]
+    pub fn is_low_power(&self) -> bool {
+        matches!(self, Self::LowPower)
+    }
+}
+
+impl fmt::Display for PowerProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let out = match self {
+            PowerProfile::Normal => "normal",
+            PowerProfile::LowPower => "low_power",
+        };
+        write!(f, "{}", out)
+    }
+}
+
+impl TryFrom<&str> for PowerProfile {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Ok(match s {
+            "normal" => PowerProfile::Normal,
+            "low_power" => PowerProfile::LowPower,
+            _ => return Err(()),
+        })
+    }
+}
@@ -17,6 +17,7 @@ pub enum AttachmentState {
     FullyAttached = 5,
     OverAttached = 6,
     Detaching = 7,
+    Resuming = 8,
 }
 impl AttachmentState {
     #[must_use]
@@ -47,6 +48,7 @@ impl fmt::Display for AttachmentState {
             AttachmentState::OverAttached => "over_attached",
             AttachmentState::Detaching => "detaching",
             AttachmentState::Detached => "detached",
+            AttachmentState::Resuming => "resuming",
         };
         write!(f, "{}", out)
     }
@@ -73,6 +75,7 @@ impl TryFrom<&str> for AttachmentState {
             "over_attached" => AttachmentState::OverAttached,
             "detaching" => AttachmentState::Detaching,
             "detached" => AttachmentState::Detached,
+            "resuming" => AttachmentState::Resuming,
             _ => return Err(()),
         })
     }
@@ -176,6 +179,35 @@ pub struct VeilidValueChange {
     pub value: Option<ValueData>,
 }
 
+/// A message published to a pub/sub topic this node is subscribed to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[must_use]
+pub struct VeilidTopicMessage {
+    /// The DHT Record key of the topic
+    #[schemars(with = "String")]
+    pub key: TypedRecordKey,
+    /// The name of the topic
+    pub topic: String,
+    /// The public key of the publisher that signed the message
+    #[schemars(with = "String")]
+    pub publisher: PublicKey,
+    /// When the publisher says it published the message
+    pub published: Timestamp,
+    /// The content of the message
+    #[cfg_attr(
+        not(all(target_arch = "wasm32", target_os = "unknown")),
+        serde(with = "as_human_base64")
+    )]
+    #[schemars(with = "String")]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        serde(with = "serde_bytes"),
+        tsify(type = "Uint8Array")
+    )]
+    pub message: Vec<u8>,
+}
+
 /// An update from the veilid-core to the host application describing a change
 /// to the internal state of the Veilid node.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -195,6 +227,7 @@ pub enum VeilidUpdate {
     Config(Box<VeilidStateConfig>),
     RouteChange(Box<VeilidRouteChange>),
     ValueChange(Box<VeilidValueChange>),
+    TopicMessage(Box<VeilidTopicMessage>),
     Shutdown,
 }
 
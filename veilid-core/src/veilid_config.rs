@@ -474,6 +474,19 @@ pub struct VeilidConfigRPC {
     pub timeout_ms: u32,
     pub max_route_hop_count: u8,
     pub default_route_hop_count: u8,
+    /// Percent of queue_size for status, receipts, signals and answers to our own questions
+    pub queue_share_control: u32,
+    /// Percent of queue_size for node lookups and private route hops
+    pub queue_share_routing: u32,
+    /// Percent of queue_size for DHT record operations
+    pub queue_share_dht: u32,
+    /// Percent of queue_size for AppCall and AppMessage traffic, and answers nothing is waiting for
+    pub queue_share_app: u32,
+    /// Relative share of the rpc workers for each class while other classes are waiting too
+    pub queue_weight_control: u32,
+    pub queue_weight_routing: u32,
+    pub queue_weight_dht: u32,
+    pub queue_weight_app: u32,
 }
 
 impl Default for VeilidConfigRPC {
@@ -486,6 +499,14 @@ impl Default for VeilidConfigRPC {
             timeout_ms: 5000,
             max_route_hop_count: 4,
             default_route_hop_count: 1,
+            queue_share_control: 10,
+            queue_share_routing: 30,
+            queue_share_dht: 40,
+            queue_share_app: 20,
+            queue_weight_control: 8,
+            queue_weight_routing: 4,
+            queue_weight_dht: 2,
+            queue_weight_app: 2,
         }
     }
 }
@@ -655,6 +676,24 @@ impl Default for VeilidConfigBlockStore {
     }
 }
 
+/// Where the protected store keeps its secrets
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    tsify(namespace, from_wasm_abi)
+)]
+#[must_use]
+pub enum VeilidConfigProtectedStoreBackend {
+    /// The operating system keyring, falling back to a plaintext file in 'directory' if insecure storage is allowed
+    #[default]
+    Keyring,
+    /// A file in 'directory' encrypted with a key derived from 'vault_password' using Argon2
+    FileVault,
+    /// An external program, such as a bridge to a PKCS#11 token or HSM, run as 'external_command'
+    ExternalCommand,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), derive(Tsify))]
 #[must_use]
@@ -666,6 +705,10 @@ pub struct VeilidConfigProtectedStore {
     pub device_encryption_key_password: String,
     #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), tsify(optional))]
     pub new_device_encryption_key_password: Option<String>,
+    pub backend: VeilidConfigProtectedStoreBackend,
+    pub vault_password: String,
+    /// Program and arguments for the 'ExternalCommand' backend
+    pub external_command: Vec<String>,
 }
 
 impl Default for VeilidConfigProtectedStore {
@@ -677,6 +720,9 @@ impl Default for VeilidConfigProtectedStore {
             delete: false,
             device_encryption_key_password: "".to_owned(),
             new_device_encryption_key_password: None,
+            backend: VeilidConfigProtectedStoreBackend::default(),
+            vault_password: "".to_owned(),
+            external_command: Vec::new(),
         }
     }
 }
@@ -894,6 +940,176 @@ impl VeilidConfig {
 
         out
     }
+
+    /// Build a 'VeilidConfig' by looking up every key with a config callback,
+    /// the same way `api_startup` does
+    pub fn from_callback(cb: ConfigCallback) -> VeilidAPIResult<Self> {
+        VeilidStartupOptions::config_from_callback(cb)
+    }
+
+    /// List the dot-separated keys whose values differ between two configurations
+    ///
+    /// Lists are compared as a whole and reported under the key of the list.
+    pub fn changed_keys(&self, other: &VeilidConfig) -> VeilidAPIResult<Vec<String>> {
+        let a = serde_json::to_value(self).map_err(VeilidAPIError::internal)?;
+        let b = serde_json::to_value(other).map_err(VeilidAPIError::internal)?;
+        let mut out = Vec::new();
+        Self::changed_keys_inner("", &a, &b, &mut out);
+        Ok(out)
+    }
+
+    fn changed_keys_inner(
+        prefix: &str,
+        a: &serde_json::Value,
+        b: &serde_json::Value,
+        out: &mut Vec<String>,
+    ) {
+        match (a, b) {
+            (serde_json::Value::Object(ao), serde_json::Value::Object(bo)) => {
+                let keys: BTreeSet<&String> = ao.keys().chain(bo.keys()).collect();
+                for k in keys {
+                    let key = if prefix.is_empty() {
+                        k.clone()
+                    } else {
+                        format!("{}.{}", prefix, k)
+                    };
+                    match (ao.get(k), bo.get(k)) {
+                        (Some(av), Some(bv)) => Self::changed_keys_inner(&key, av, bv, out),
+                        _ => out.push(key),
+                    }
+                }
+            }
+            (a, b) => {
+                if a != b {
+                    out.push(prefix.to_owned());
+                }
+            }
+        }
+    }
+}
+
+/// How a running node picks up a change to a configuration key
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ConfigReloadAction {
+    /// The value is read each time it is used
+    Live,
+    /// The value is read when the low level network starts
+    RestartNetwork,
+    /// The value is copied into the record store limits
+    ReloadStorageLimits,
+    /// The value is only read during startup
+    RestartNode,
+}
+
+/// Keys that veilid-core fills in or consumes itself during startup, and so never match
+/// what the application passed in. These are not compared when reloading.
+const CONFIG_RELOAD_IGNORED_KEYS: &[&str] = &[
+    "network.routing_table.node_id",
+    "network.routing_table.node_id_secret",
+    "protected_store.device_encryption_key_password",
+    "protected_store.new_device_encryption_key_password",
+];
+
+fn config_reload_action(key: &str) -> ConfigReloadAction {
+    const LIVE_KEYS: &[&str] = &[
+        "network.client_allowlist_timeout_ms",
+        "network.reverse_connection_receipt_time_ms",
+        "network.hole_punch_receipt_time_ms",
+        "network.routing_table.bootstrap",
+        "network.routing_table.bootstrap_keys",
+        "network.routing_table.limit_over_attached",
+        "network.routing_table.limit_fully_attached",
+        "network.routing_table.limit_attached_strong",
+        "network.routing_table.limit_attached_good",
+        "network.routing_table.limit_attached_weak",
+        "network.dht.max_find_node_count",
+        "network.dht.resolve_node_timeout_ms",
+        "network.dht.resolve_node_count",
+        "network.dht.resolve_node_fanout",
+        "network.dht.get_value_timeout_ms",
+        "network.dht.get_value_count",
+        "network.dht.get_value_fanout",
+        "network.dht.set_value_timeout_ms",
+        "network.dht.set_value_count",
+        "network.dht.set_value_fanout",
+        "network.dht.min_peer_count",
+        "network.dht.min_peer_refresh_time_ms",
+        "network.dht.validate_dial_info_receipt_time_ms",
+        "network.rpc.max_timestamp_behind_ms",
+        "network.rpc.max_timestamp_ahead_ms",
+        "network.restricted_nat_retries",
+        "network.upnp",
+    ];
+    const RESTART_NETWORK_KEYS: &[&str] = &[
+        "network.protocol.udp.enabled",
+        "network.protocol.udp.socket_pool_size",
+        "network.protocol.udp.listen_address",
+        "network.protocol.udp.public_address",
+        "network.protocol.tcp.connect",
+        "network.protocol.tcp.listen",
+        "network.protocol.tcp.listen_address",
+        "network.protocol.tcp.public_address",
+        "network.protocol.ws.connect",
+        "network.protocol.ws.listen",
+        "network.protocol.ws.listen_address",
+        "network.protocol.ws.path",
+        "network.protocol.ws.url",
+        "network.protocol.wss.connect",
+        "network.protocol.wss.listen",
+        "network.protocol.wss.listen_address",
+        "network.protocol.wss.path",
+        "network.protocol.wss.url",
+    ];
+    const RELOAD_STORAGE_LIMITS_KEYS: &[&str] = &[
+        "network.dht.public_watch_limit",
+        "network.dht.member_watch_limit",
+        "network.dht.max_watch_expiration_ms",
+    ];
+
+    if LIVE_KEYS.contains(&key) || key.starts_with("network.privacy.") {
+        ConfigReloadAction::Live
+    } else if RESTART_NETWORK_KEYS.contains(&key) {
+        ConfigReloadAction::RestartNetwork
+    } else if RELOAD_STORAGE_LIMITS_KEYS.contains(&key) {
+        ConfigReloadAction::ReloadStorageLimits
+    } else {
+        ConfigReloadAction::RestartNode
+    }
+}
+
+/// The outcome of applying a new configuration to a running node with `VeilidAPI::reload_config`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    derive(Tsify),
+    tsify(into_wasm_abi)
+)]
+#[must_use]
+pub struct VeilidConfigReloadReport {
+    /// Keys whose new values are now in effect
+    pub applied: Vec<String>,
+    /// If the low level network was restarted to pick up protocol changes
+    pub network_restarted: bool,
+    /// Keys that were left at their old values because they only take effect on restart
+    pub restart_required: Vec<String>,
+}
+
+impl fmt::Display for VeilidConfigReloadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.applied.is_empty() && self.restart_required.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for k in &self.applied {
+            writeln!(f, "applied: {}", k)?;
+        }
+        if self.network_restarted {
+            writeln!(f, "network restarted")?;
+        }
+        for k in &self.restart_required {
+            writeln!(f, "restart required: {}", k)?;
+        }
+        Ok(())
+    }
 }
 
 /// The configuration built for each Veilid node during API startup
@@ -944,6 +1160,15 @@ impl VeilidStartupOptions {
         cb: ConfigCallback,
         update_cb: UpdateCallback,
     ) -> VeilidAPIResult<Self> {
+        let inner = Self::config_from_callback(cb)?;
+
+        Ok(Self {
+            update_cb,
+            inner: Arc::new(RwLock::new(inner)),
+        })
+    }
+
+    pub(crate) fn config_from_callback(cb: ConfigCallback) -> VeilidAPIResult<VeilidConfig> {
         let mut inner = VeilidConfig::default();
 
         // Simple config transformation
@@ -966,6 +1191,9 @@ impl VeilidStartupOptions {
         get_config!(inner.protected_store.delete);
         get_config!(inner.protected_store.device_encryption_key_password);
         get_config!(inner.protected_store.new_device_encryption_key_password);
+        get_config!(inner.protected_store.backend);
+        get_config!(inner.protected_store.vault_password);
+        get_config!(inner.protected_store.external_command);
         get_config!(inner.network.connection_initial_timeout_ms);
         get_config!(inner.network.connection_inactivity_timeout_ms);
         get_config!(inner.network.max_connections_per_ip4);
@@ -1014,6 +1242,14 @@ impl VeilidStartupOptions {
         get_config!(inner.network.rpc.timeout_ms);
         get_config!(inner.network.rpc.max_route_hop_count);
         get_config!(inner.network.rpc.default_route_hop_count);
+        get_config!(inner.network.rpc.queue_share_control);
+        get_config!(inner.network.rpc.queue_share_routing);
+        get_config!(inner.network.rpc.queue_share_dht);
+        get_config!(inner.network.rpc.queue_share_app);
+        get_config!(inner.network.rpc.queue_weight_control);
+        get_config!(inner.network.rpc.queue_weight_routing);
+        get_config!(inner.network.rpc.queue_weight_dht);
+        get_config!(inner.network.rpc.queue_weight_app);
         get_config!(inner.network.upnp);
         get_config!(inner.network.detect_address_changes);
         get_config!(inner.network.restricted_nat_retries);
@@ -1057,10 +1293,7 @@ impl VeilidStartupOptions {
             get_config!(inner.network.virtual_network.server_address);
         }
 
-        Ok(Self {
-            update_cb,
-            inner: Arc::new(RwLock::new(inner)),
-        })
+        Ok(inner)
     }
 
     pub(crate) fn get_veilid_state(&self) -> Box<VeilidStateConfig> {
@@ -1086,6 +1319,7 @@ impl VeilidStartupOptions {
         safe_cfg.network.routing_table.node_id_secret = TypedSecretKeyGroup::new();
         "".clone_into(&mut safe_cfg.protected_store.device_encryption_key_password);
         safe_cfg.protected_store.new_device_encryption_key_password = None;
+        "".clone_into(&mut safe_cfg.protected_store.vault_password);
 
         safe_cfg
     }
@@ -1097,6 +1331,7 @@ impl VeilidStartupOptions {
         safe_cfg.network.routing_table.node_id_secret = TypedSecretKeyGroup::new();
         "".clone_into(&mut safe_cfg.protected_store.device_encryption_key_password);
         safe_cfg.protected_store.new_device_encryption_key_password = None;
+        "".clone_into(&mut safe_cfg.protected_store.vault_password);
 
         VeilidStartupOptions {
             update_cb: self.update_cb.clone(),
@@ -1143,6 +1378,55 @@ impl VeilidStartupOptions {
         Ok(out)
     }
 
+    /// Copy the values of the keys that can change at runtime from a new configuration
+    ///
+    /// Returns the report, and whether the record store limits need to be reloaded.
+    /// Restarting the network when the report asks for it is up to the caller.
+    pub(crate) fn apply_reload(
+        &self,
+        new_config: &VeilidConfig,
+    ) -> VeilidAPIResult<(VeilidConfigReloadReport, bool)> {
+        let mut report = VeilidConfigReloadReport::default();
+        let mut reload_storage_limits = false;
+
+        self.try_with_mut(|c| {
+            let changed_keys = c.changed_keys(new_config)?;
+
+            let mut jvc = serde_json::to_value(&*c).map_err(VeilidAPIError::internal)?;
+            let jvn = serde_json::to_value(new_config).map_err(VeilidAPIError::internal)?;
+
+            for key in changed_keys {
+                if CONFIG_RELOAD_IGNORED_KEYS.contains(&key.as_str()) {
+                    continue;
+                }
+                match config_reload_action(&key) {
+                    ConfigReloadAction::RestartNode => {
+                        report.restart_required.push(key);
+                        continue;
+                    }
+                    ConfigReloadAction::RestartNetwork => report.network_restarted = true,
+                    ConfigReloadAction::ReloadStorageLimits => reload_storage_limits = true,
+                    ConfigReloadAction::Live => {}
+                }
+
+                let pointer = format!("/{}", key.replace('.', "/"));
+                let Some(newval) = jvn.pointer(&pointer) else {
+                    apibail_internal!(format!("missing config key: {}", key));
+                };
+                let Some(oldval) = jvc.pointer_mut(&pointer) else {
+                    apibail_internal!(format!("missing config key: {}", key));
+                };
+                *oldval = newval.clone();
+                report.applied.push(key);
+            }
+
+            *c = serde_json::from_value(jvc).map_err(VeilidAPIError::generic)?;
+            Ok(())
+        })?;
+
+        Ok((report, reload_storage_limits))
+    }
+
     pub fn get_key_json(&self, key: &str, pretty: bool) -> VeilidAPIResult<String> {
         let c = self.get();
 
@@ -1248,6 +1532,20 @@ impl VeilidStartupOptions {
         Self::validate_program_name(&inner.program_name)?;
         Self::validate_namespace(&inner.namespace)?;
 
+        match inner.protected_store.backend {
+            VeilidConfigProtectedStoreBackend::Keyring => {}
+            VeilidConfigProtectedStoreBackend::FileVault => {
+                if inner.protected_store.vault_password.is_empty() {
+                    apibail_generic!("Vault password must be specified in config key 'protected_store.vault_password'");
+                }
+            }
+            VeilidConfigProtectedStoreBackend::ExternalCommand => {
+                if inner.protected_store.external_command.is_empty() {
+                    apibail_generic!("External command must be specified in config key 'protected_store.external_command'");
+                }
+            }
+        }
+
         // if inner.network.protocol.udp.enabled {
         //     // Validate UDP settings
         // }
@@ -1344,6 +1642,25 @@ impl VeilidStartupOptions {
         if inner.network.rpc.timeout_ms < 1000 {
             apibail_generic!("rpc timeout must be >= 1000 in 'network.rpc.timeout_ms'");
         }
+        let rpc = &inner.network.rpc;
+        if rpc
+            .queue_share_control
+            .saturating_add(rpc.queue_share_routing)
+            .saturating_add(rpc.queue_share_dht)
+            .saturating_add(rpc.queue_share_app)
+            > 100
+        {
+            apibail_generic!(
+                "rpc queue shares must add up to <= 100 in 'network.rpc.queue_share_*'"
+            );
+        }
+        if rpc.queue_weight_control == 0
+            || rpc.queue_weight_routing == 0
+            || rpc.queue_weight_dht == 0
+            || rpc.queue_weight_app == 0
+        {
+            apibail_generic!("rpc queue weights must be >= 1 in 'network.rpc.queue_weight_*'");
+        }
 
         Ok(())
     }
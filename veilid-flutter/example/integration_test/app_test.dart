@@ -33,6 +33,8 @@ void main() {
       test('hash and verify password', testHashAndVerifyPassword);
       test('sign and verify signature', testSignAndVerifySignature);
       test('sign and verify signatures', testSignAndVerifySignatures);
+      test('seal and open', testSealAndOpen);
+      test('signcrypt and unsigncrypt', testSigncryptAndUnsigncrypt);
     });
 
     group('Table DB Tests', () {
@@ -55,6 +57,8 @@ void main() {
             () async => testAppMessageLoopback(fixture.updateStream));
         test('app call loopback',
             () async => testAppCallLoopback(fixture.updateStream));
+        test('app call loopback cancel',
+            () async => testAppCallLoopbackCancel(fixture.updateStream));
         test('app message loopback big packets',
             () async => testAppMessageLoopbackBigPackets(fixture.updateStream));
         test('app call loopback big packets',
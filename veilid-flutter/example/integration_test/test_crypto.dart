@@ -91,3 +91,30 @@ Future<void> testGenerateSharedSecret() async {
 
   expect(ssA, isNot(equals(ssD)));
 }
+
+Future<void> testSealAndOpen() async {
+  final cs = await Veilid.instance.bestCryptoSystem();
+  final kp1 = await cs.generateKeyPair();
+  final kp2 = await cs.generateKeyPair();
+
+  // Only the recipient can open
+  final sealed = await cs.seal(kp1.key, utf8.encode('abc123'));
+  expect(await cs.openWithKeyPair(kp1, sealed), equals(utf8.encode('abc123')));
+  await expectLater(() async => cs.openWithKeyPair(kp2, sealed),
+      throwsA(isA<VeilidAPIException>()));
+}
+
+Future<void> testSigncryptAndUnsigncrypt() async {
+  final cs = await Veilid.instance.bestCryptoSystem();
+  final kp1 = await cs.generateKeyPair();
+  final kp2 = await cs.generateKeyPair();
+
+  // Sender is authenticated
+  final signcrypted =
+      await cs.signcryptWithKeyPair(kp1.key, kp2, utf8.encode('abc123'));
+  expect(await cs.unsigncryptWithKeyPair(kp1, kp2.key, signcrypted),
+      equals(utf8.encode('abc123')));
+  await expectLater(
+      () async => cs.unsigncryptWithKeyPair(kp1, kp1.key, signcrypted),
+      throwsA(isA<VeilidAPIException>()));
+}
@@ -29,6 +29,24 @@ Future<void> testRoutingContexts() async {
     rc.close();
   }
 
+  {
+    final rc = await Veilid.instance.routingContext();
+    final rcp = rc.withFragmentation(true);
+    // More debuggable this way
+    // ignore: cascade_invocations
+    rcp.close();
+    rc.close();
+  }
+
+  {
+    final rc = await Veilid.instance.routingContext();
+    final rcp = rc.withAppNamespace(5);
+    // More debuggable this way
+    // ignore: cascade_invocations
+    rcp.close();
+    rc.close();
+  }
+
   {
     final rc = await Veilid.instance.routingContext();
     final rcp = rc.withSafety(const SafetySelectionSafe(
@@ -146,6 +164,58 @@ Future<void> testAppCallLoopback(Stream<VeilidUpdate> updateStream) async {
   }
 }
 
+Future<void> testAppCallLoopbackCancel(
+    Stream<VeilidUpdate> updateStream) async {
+  final appCallQueue = StreamController<VeilidAppCall>();
+  final appMessageSubscription = updateStream.listen((update) {
+    if (update is VeilidAppCall) {
+      appCallQueue.sink.add(update);
+    }
+  });
+  try {
+    await Veilid.instance.debug('purge routes');
+
+    // make a routing context that uses a safety route
+    final rc = await Veilid.instance.routingContext();
+    try {
+      // make a new local private route
+      final prl = await Veilid.instance.newPrivateRoute();
+      try {
+        // import it as a remote route as well so we can send to it
+        final prr = await Veilid.instance.importRemotePrivateRoute(prl.blob);
+        try {
+          // send an app call to our own private route that can be cancelled
+          final cancelHandle = Veilid.instance.newCancelHandle();
+          final message = utf8.encode('abcd1234');
+          final appCallFuture =
+              rc.appCall(prr, message, cancelHandle: cancelHandle);
+
+          // wait for the request to arrive, but don't reply to it
+          final update = await appCallQueue.stream.first
+              .timeout(const Duration(seconds: 10));
+          expect(update.message, equals(message));
+
+          // cancelling the call should end it well before the rpc timeout
+          cancelHandle.cancel();
+          expect(cancelHandle.isDone(), isTrue);
+          await expectLater(
+              () async => appCallFuture.timeout(const Duration(seconds: 5)),
+              throwsA(isA<VeilidAPIExceptionCancelled>()));
+        } finally {
+          await Veilid.instance.releasePrivateRoute(prr);
+        }
+      } finally {
+        await Veilid.instance.releasePrivateRoute(prl.routeId);
+      }
+    } finally {
+      rc.close();
+    }
+  } finally {
+    await appMessageSubscription.cancel();
+    await appCallQueue.close();
+  }
+}
+
 Future<void> testAppMessageLoopbackBigPackets(
     Stream<VeilidUpdate> updateStream) async {
   final appMessageQueue = StreamController<VeilidAppMessage>();
@@ -273,6 +273,22 @@ enum DHTReportScope {
   String toJson() => name.toPascalCase();
 }
 
+//////////////////////////////////////
+/// VeilidCancelHandle
+
+/// Cancels calls that were started with it while they are in progress.
+/// Must be cancelled or closed once it is no longer needed.
+abstract class VeilidCancelHandle {
+  bool isDone();
+
+  /// Cancel every call in progress that was started with this handle, and
+  /// release it
+  void cancel();
+
+  /// Release the handle without cancelling the calls using it
+  void close();
+}
+
 //////////////////////////////////////
 /// VeilidRoutingContext
 
@@ -285,10 +301,15 @@ abstract class VeilidRoutingContext {
       {bool closeSelf = false});
   VeilidRoutingContext withSequencing(Sequencing sequencing,
       {bool closeSelf = false});
+  VeilidRoutingContext withFragmentation(bool fragmentation,
+      {bool closeSelf = false});
+  VeilidRoutingContext withAppNamespace(int appNamespace,
+      {bool closeSelf = false});
   Future<SafetySelection> safety();
 
   // App call/message
-  Future<Uint8List> appCall(String target, Uint8List request);
+  Future<Uint8List> appCall(String target, Uint8List request,
+      {Timestamp? deadline, VeilidCancelHandle? cancelHandle});
   Future<void> appMessage(String target, Uint8List message);
 
   // DHT Operations
@@ -298,9 +319,11 @@ abstract class VeilidRoutingContext {
   Future<void> closeDHTRecord(TypedKey key);
   Future<void> deleteDHTRecord(TypedKey key);
   Future<ValueData?> getDHTValue(TypedKey key, int subkey,
-      {bool forceRefresh = false});
+      {bool forceRefresh = false,
+      Timestamp? deadline,
+      VeilidCancelHandle? cancelHandle});
   Future<ValueData?> setDHTValue(TypedKey key, int subkey, Uint8List data,
-      {KeyPair? writer});
+      {KeyPair? writer, Timestamp? deadline, VeilidCancelHandle? cancelHandle});
   Future<bool> watchDHTValues(TypedKey key,
       {List<ValueSubkeyRange>? subkeys, Timestamp? expiration, int? count});
   Future<bool> cancelDHTWatch(TypedKey key, {List<ValueSubkeyRange>? subkeys});
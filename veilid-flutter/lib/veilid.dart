@@ -175,6 +175,8 @@ abstract class Veilid {
   Future<bool> isShutdown();
   Future<void> attach();
   Future<void> detach();
+  Future<PowerProfile> powerProfile();
+  Future<void> setPowerProfile(PowerProfile powerProfile);
   Future<void> shutdownVeilidCore();
 
   // Crypto
@@ -215,6 +217,9 @@ abstract class Veilid {
   // App calls
   Future<void> appCallReply(String callId, Uint8List message);
 
+  // Call cancellation
+  VeilidCancelHandle newCancelHandle();
+
   // TableStore
   Future<VeilidTableDB> openTableDB(String name, int columnCount);
   Future<bool> deleteTableDB(String name);
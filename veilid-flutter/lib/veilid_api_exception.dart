@@ -68,6 +68,14 @@ abstract class VeilidAPIException implements Exception {
         {
           return VeilidAPIExceptionGeneric(json['message']! as String);
         }
+      case 'Tampered':
+        {
+          return VeilidAPIExceptionTampered(json['message']! as String);
+        }
+      case 'Cancelled':
+        {
+          return VeilidAPIExceptionCancelled();
+        }
       default:
         {
           throw VeilidAPIExceptionInternal(
@@ -249,3 +257,25 @@ class VeilidAPIExceptionGeneric implements VeilidAPIException {
   @override
   String toDisplayError() => message;
 }
+
+@immutable
+class VeilidAPIExceptionTampered implements VeilidAPIException {
+  //
+  const VeilidAPIExceptionTampered(this.message);
+  final String message;
+
+  @override
+  String toString() => 'VeilidAPIException: Tampered (message: $message)';
+
+  @override
+  String toDisplayError() => 'Stored data was tampered with: $message';
+}
+
+@immutable
+class VeilidAPIExceptionCancelled implements VeilidAPIException {
+  @override
+  String toString() => 'VeilidAPIException: Cancelled';
+
+  @override
+  String toDisplayError() => 'Operation was cancelled';
+}
@@ -320,6 +320,14 @@ sealed class VeilidConfigRPC with _$VeilidConfigRPC {
       required int timeoutMs,
       required int maxRouteHopCount,
       required int defaultRouteHopCount,
+      required int queueShareControl,
+      required int queueShareRouting,
+      required int queueShareDht,
+      required int queueShareApp,
+      required int queueWeightControl,
+      required int queueWeightRouting,
+      required int queueWeightDht,
+      required int queueWeightApp,
       int? maxTimestampBehindMs,
       int? maxTimestampAheadMs}) = _VeilidConfigRPC;
 
@@ -405,6 +413,17 @@ sealed class VeilidConfigBlockStore with _$VeilidConfigBlockStore {
 
 ////////////
 
+enum VeilidConfigProtectedStoreBackend {
+  keyring,
+  fileVault,
+  externalCommand;
+
+  factory VeilidConfigProtectedStoreBackend.fromJson(dynamic j) =>
+      VeilidConfigProtectedStoreBackend.values
+          .byName((j as String).toCamelCase());
+  String toJson() => name.toPascalCase();
+}
+
 @freezed
 sealed class VeilidConfigProtectedStore with _$VeilidConfigProtectedStore {
   const factory VeilidConfigProtectedStore(
@@ -413,7 +432,10 @@ sealed class VeilidConfigProtectedStore with _$VeilidConfigProtectedStore {
       required String directory,
       required bool delete,
       required String deviceEncryptionKeyPassword,
-      String? newDeviceEncryptionKeyPassword}) = _VeilidConfigProtectedStore;
+      String? newDeviceEncryptionKeyPassword,
+      required VeilidConfigProtectedStoreBackend backend,
+      required String vaultPassword,
+      required List<String> externalCommand}) = _VeilidConfigProtectedStore;
 
   factory VeilidConfigProtectedStore.fromJson(dynamic json) =>
       _$VeilidConfigProtectedStoreFromJson(json as Map<String, dynamic>);
@@ -5211,6 +5211,14 @@ mixin _$VeilidConfigRPC implements DiagnosticableTreeMixin {
   int get timeoutMs;
   int get maxRouteHopCount;
   int get defaultRouteHopCount;
+  int get queueShareControl;
+  int get queueShareRouting;
+  int get queueShareDht;
+  int get queueShareApp;
+  int get queueWeightControl;
+  int get queueWeightRouting;
+  int get queueWeightDht;
+  int get queueWeightApp;
   int? get maxTimestampBehindMs;
   int? get maxTimestampAheadMs;
 
@@ -5234,6 +5242,14 @@ mixin _$VeilidConfigRPC implements DiagnosticableTreeMixin {
       ..add(DiagnosticsProperty('timeoutMs', timeoutMs))
       ..add(DiagnosticsProperty('maxRouteHopCount', maxRouteHopCount))
       ..add(DiagnosticsProperty('defaultRouteHopCount', defaultRouteHopCount))
+      ..add(DiagnosticsProperty('queueShareControl', queueShareControl))
+      ..add(DiagnosticsProperty('queueShareRouting', queueShareRouting))
+      ..add(DiagnosticsProperty('queueShareDht', queueShareDht))
+      ..add(DiagnosticsProperty('queueShareApp', queueShareApp))
+      ..add(DiagnosticsProperty('queueWeightControl', queueWeightControl))
+      ..add(DiagnosticsProperty('queueWeightRouting', queueWeightRouting))
+      ..add(DiagnosticsProperty('queueWeightDht', queueWeightDht))
+      ..add(DiagnosticsProperty('queueWeightApp', queueWeightApp))
       ..add(DiagnosticsProperty('maxTimestampBehindMs', maxTimestampBehindMs))
       ..add(DiagnosticsProperty('maxTimestampAheadMs', maxTimestampAheadMs));
   }
@@ -5253,6 +5269,22 @@ mixin _$VeilidConfigRPC implements DiagnosticableTreeMixin {
                 other.maxRouteHopCount == maxRouteHopCount) &&
             (identical(other.defaultRouteHopCount, defaultRouteHopCount) ||
                 other.defaultRouteHopCount == defaultRouteHopCount) &&
+            (identical(other.queueShareControl, queueShareControl) ||
+                other.queueShareControl == queueShareControl) &&
+            (identical(other.queueShareRouting, queueShareRouting) ||
+                other.queueShareRouting == queueShareRouting) &&
+            (identical(other.queueShareDht, queueShareDht) ||
+                other.queueShareDht == queueShareDht) &&
+            (identical(other.queueShareApp, queueShareApp) ||
+                other.queueShareApp == queueShareApp) &&
+            (identical(other.queueWeightControl, queueWeightControl) ||
+                other.queueWeightControl == queueWeightControl) &&
+            (identical(other.queueWeightRouting, queueWeightRouting) ||
+                other.queueWeightRouting == queueWeightRouting) &&
+            (identical(other.queueWeightDht, queueWeightDht) ||
+                other.queueWeightDht == queueWeightDht) &&
+            (identical(other.queueWeightApp, queueWeightApp) ||
+                other.queueWeightApp == queueWeightApp) &&
             (identical(other.maxTimestampBehindMs, maxTimestampBehindMs) ||
                 other.maxTimestampBehindMs == maxTimestampBehindMs) &&
             (identical(other.maxTimestampAheadMs, maxTimestampAheadMs) ||
@@ -5268,12 +5300,20 @@ mixin _$VeilidConfigRPC implements DiagnosticableTreeMixin {
       timeoutMs,
       maxRouteHopCount,
       defaultRouteHopCount,
+      queueShareControl,
+      queueShareRouting,
+      queueShareDht,
+      queueShareApp,
+      queueWeightControl,
+      queueWeightRouting,
+      queueWeightDht,
+      queueWeightApp,
       maxTimestampBehindMs,
       maxTimestampAheadMs);
 
   @override
   String toString({DiagnosticLevel minLevel = DiagnosticLevel.info}) {
-    return 'VeilidConfigRPC(concurrency: $concurrency, queueSize: $queueSize, timeoutMs: $timeoutMs, maxRouteHopCount: $maxRouteHopCount, defaultRouteHopCount: $defaultRouteHopCount, maxTimestampBehindMs: $maxTimestampBehindMs, maxTimestampAheadMs: $maxTimestampAheadMs)';
+    return 'VeilidConfigRPC(concurrency: $concurrency, queueSize: $queueSize, timeoutMs: $timeoutMs, maxRouteHopCount: $maxRouteHopCount, defaultRouteHopCount: $defaultRouteHopCount, queueShareControl: $queueShareControl, queueShareRouting: $queueShareRouting, queueShareDht: $queueShareDht, queueShareApp: $queueShareApp, queueWeightControl: $queueWeightControl, queueWeightRouting: $queueWeightRouting, queueWeightDht: $queueWeightDht, queueWeightApp: $queueWeightApp, maxTimestampBehindMs: $maxTimestampBehindMs, maxTimestampAheadMs: $maxTimestampAheadMs)';
   }
 }
 
@@ -5289,6 +5329,14 @@ abstract mixin class $VeilidConfigRPCCopyWith<$Res> {
       int timeoutMs,
       int maxRouteHopCount,
       int defaultRouteHopCount,
+      int queueShareControl,
+      int queueShareRouting,
+      int queueShareDht,
+      int queueShareApp,
+      int queueWeightControl,
+      int queueWeightRouting,
+      int queueWeightDht,
+      int queueWeightApp,
       int? maxTimestampBehindMs,
       int? maxTimestampAheadMs});
 }
@@ -5311,6 +5359,14 @@ class _$VeilidConfigRPCCopyWithImpl<$Res>
     Object? timeoutMs = null,
     Object? maxRouteHopCount = null,
     Object? defaultRouteHopCount = null,
+    Object? queueShareControl = null,
+    Object? queueShareRouting = null,
+    Object? queueShareDht = null,
+    Object? queueShareApp = null,
+    Object? queueWeightControl = null,
+    Object? queueWeightRouting = null,
+    Object? queueWeightDht = null,
+    Object? queueWeightApp = null,
     Object? maxTimestampBehindMs = freezed,
     Object? maxTimestampAheadMs = freezed,
   }) {
@@ -5335,6 +5391,38 @@ class _$VeilidConfigRPCCopyWithImpl<$Res>
           ? _self.defaultRouteHopCount
           : defaultRouteHopCount // ignore: cast_nullable_to_non_nullable
               as int,
+      queueShareControl: null == queueShareControl
+          ? _self.queueShareControl
+          : queueShareControl // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueShareRouting: null == queueShareRouting
+          ? _self.queueShareRouting
+          : queueShareRouting // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueShareDht: null == queueShareDht
+          ? _self.queueShareDht
+          : queueShareDht // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueShareApp: null == queueShareApp
+          ? _self.queueShareApp
+          : queueShareApp // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightControl: null == queueWeightControl
+          ? _self.queueWeightControl
+          : queueWeightControl // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightRouting: null == queueWeightRouting
+          ? _self.queueWeightRouting
+          : queueWeightRouting // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightDht: null == queueWeightDht
+          ? _self.queueWeightDht
+          : queueWeightDht // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightApp: null == queueWeightApp
+          ? _self.queueWeightApp
+          : queueWeightApp // ignore: cast_nullable_to_non_nullable
+              as int,
       maxTimestampBehindMs: freezed == maxTimestampBehindMs
           ? _self.maxTimestampBehindMs
           : maxTimestampBehindMs // ignore: cast_nullable_to_non_nullable
@@ -5356,6 +5444,14 @@ class _VeilidConfigRPC with DiagnosticableTreeMixin implements VeilidConfigRPC {
       required this.timeoutMs,
       required this.maxRouteHopCount,
       required this.defaultRouteHopCount,
+      required this.queueShareControl,
+      required this.queueShareRouting,
+      required this.queueShareDht,
+      required this.queueShareApp,
+      required this.queueWeightControl,
+      required this.queueWeightRouting,
+      required this.queueWeightDht,
+      required this.queueWeightApp,
       this.maxTimestampBehindMs,
       this.maxTimestampAheadMs});
   factory _VeilidConfigRPC.fromJson(Map<String, dynamic> json) =>
@@ -5372,6 +5468,22 @@ class _VeilidConfigRPC with DiagnosticableTreeMixin implements VeilidConfigRPC {
   @override
   final int defaultRouteHopCount;
   @override
+  final int queueShareControl;
+  @override
+  final int queueShareRouting;
+  @override
+  final int queueShareDht;
+  @override
+  final int queueShareApp;
+  @override
+  final int queueWeightControl;
+  @override
+  final int queueWeightRouting;
+  @override
+  final int queueWeightDht;
+  @override
+  final int queueWeightApp;
+  @override
   final int? maxTimestampBehindMs;
   @override
   final int? maxTimestampAheadMs;
@@ -5400,6 +5512,14 @@ class _VeilidConfigRPC with DiagnosticableTreeMixin implements VeilidConfigRPC {
       ..add(DiagnosticsProperty('timeoutMs', timeoutMs))
       ..add(DiagnosticsProperty('maxRouteHopCount', maxRouteHopCount))
       ..add(DiagnosticsProperty('defaultRouteHopCount', defaultRouteHopCount))
+      ..add(DiagnosticsProperty('queueShareControl', queueShareControl))
+      ..add(DiagnosticsProperty('queueShareRouting', queueShareRouting))
+      ..add(DiagnosticsProperty('queueShareDht', queueShareDht))
+      ..add(DiagnosticsProperty('queueShareApp', queueShareApp))
+      ..add(DiagnosticsProperty('queueWeightControl', queueWeightControl))
+      ..add(DiagnosticsProperty('queueWeightRouting', queueWeightRouting))
+      ..add(DiagnosticsProperty('queueWeightDht', queueWeightDht))
+      ..add(DiagnosticsProperty('queueWeightApp', queueWeightApp))
       ..add(DiagnosticsProperty('maxTimestampBehindMs', maxTimestampBehindMs))
       ..add(DiagnosticsProperty('maxTimestampAheadMs', maxTimestampAheadMs));
   }
@@ -5419,6 +5539,22 @@ class _VeilidConfigRPC with DiagnosticableTreeMixin implements VeilidConfigRPC {
                 other.maxRouteHopCount == maxRouteHopCount) &&
             (identical(other.defaultRouteHopCount, defaultRouteHopCount) ||
                 other.defaultRouteHopCount == defaultRouteHopCount) &&
+            (identical(other.queueShareControl, queueShareControl) ||
+                other.queueShareControl == queueShareControl) &&
+            (identical(other.queueShareRouting, queueShareRouting) ||
+                other.queueShareRouting == queueShareRouting) &&
+            (identical(other.queueShareDht, queueShareDht) ||
+                other.queueShareDht == queueShareDht) &&
+            (identical(other.queueShareApp, queueShareApp) ||
+                other.queueShareApp == queueShareApp) &&
+            (identical(other.queueWeightControl, queueWeightControl) ||
+                other.queueWeightControl == queueWeightControl) &&
+            (identical(other.queueWeightRouting, queueWeightRouting) ||
+                other.queueWeightRouting == queueWeightRouting) &&
+            (identical(other.queueWeightDht, queueWeightDht) ||
+                other.queueWeightDht == queueWeightDht) &&
+            (identical(other.queueWeightApp, queueWeightApp) ||
+                other.queueWeightApp == queueWeightApp) &&
             (identical(other.maxTimestampBehindMs, maxTimestampBehindMs) ||
                 other.maxTimestampBehindMs == maxTimestampBehindMs) &&
             (identical(other.maxTimestampAheadMs, maxTimestampAheadMs) ||
@@ -5434,12 +5570,20 @@ class _VeilidConfigRPC with DiagnosticableTreeMixin implements VeilidConfigRPC {
       timeoutMs,
       maxRouteHopCount,
       defaultRouteHopCount,
+      queueShareControl,
+      queueShareRouting,
+      queueShareDht,
+      queueShareApp,
+      queueWeightControl,
+      queueWeightRouting,
+      queueWeightDht,
+      queueWeightApp,
       maxTimestampBehindMs,
       maxTimestampAheadMs);
 
   @override
   String toString({DiagnosticLevel minLevel = DiagnosticLevel.info}) {
-    return 'VeilidConfigRPC(concurrency: $concurrency, queueSize: $queueSize, timeoutMs: $timeoutMs, maxRouteHopCount: $maxRouteHopCount, defaultRouteHopCount: $defaultRouteHopCount, maxTimestampBehindMs: $maxTimestampBehindMs, maxTimestampAheadMs: $maxTimestampAheadMs)';
+    return 'VeilidConfigRPC(concurrency: $concurrency, queueSize: $queueSize, timeoutMs: $timeoutMs, maxRouteHopCount: $maxRouteHopCount, defaultRouteHopCount: $defaultRouteHopCount, queueShareControl: $queueShareControl, queueShareRouting: $queueShareRouting, queueShareDht: $queueShareDht, queueShareApp: $queueShareApp, queueWeightControl: $queueWeightControl, queueWeightRouting: $queueWeightRouting, queueWeightDht: $queueWeightDht, queueWeightApp: $queueWeightApp, maxTimestampBehindMs: $maxTimestampBehindMs, maxTimestampAheadMs: $maxTimestampAheadMs)';
   }
 }
 
@@ -5457,6 +5601,14 @@ abstract mixin class _$VeilidConfigRPCCopyWith<$Res>
       int timeoutMs,
       int maxRouteHopCount,
       int defaultRouteHopCount,
+      int queueShareControl,
+      int queueShareRouting,
+      int queueShareDht,
+      int queueShareApp,
+      int queueWeightControl,
+      int queueWeightRouting,
+      int queueWeightDht,
+      int queueWeightApp,
       int? maxTimestampBehindMs,
       int? maxTimestampAheadMs});
 }
@@ -5479,6 +5631,14 @@ class __$VeilidConfigRPCCopyWithImpl<$Res>
     Object? timeoutMs = null,
     Object? maxRouteHopCount = null,
     Object? defaultRouteHopCount = null,
+    Object? queueShareControl = null,
+    Object? queueShareRouting = null,
+    Object? queueShareDht = null,
+    Object? queueShareApp = null,
+    Object? queueWeightControl = null,
+    Object? queueWeightRouting = null,
+    Object? queueWeightDht = null,
+    Object? queueWeightApp = null,
     Object? maxTimestampBehindMs = freezed,
     Object? maxTimestampAheadMs = freezed,
   }) {
@@ -5503,6 +5663,38 @@ class __$VeilidConfigRPCCopyWithImpl<$Res>
           ? _self.defaultRouteHopCount
           : defaultRouteHopCount // ignore: cast_nullable_to_non_nullable
               as int,
+      queueShareControl: null == queueShareControl
+          ? _self.queueShareControl
+          : queueShareControl // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueShareRouting: null == queueShareRouting
+          ? _self.queueShareRouting
+          : queueShareRouting // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueShareDht: null == queueShareDht
+          ? _self.queueShareDht
+          : queueShareDht // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueShareApp: null == queueShareApp
+          ? _self.queueShareApp
+          : queueShareApp // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightControl: null == queueWeightControl
+          ? _self.queueWeightControl
+          : queueWeightControl // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightRouting: null == queueWeightRouting
+          ? _self.queueWeightRouting
+          : queueWeightRouting // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightDht: null == queueWeightDht
+          ? _self.queueWeightDht
+          : queueWeightDht // ignore: cast_nullable_to_non_nullable
+              as int,
+      queueWeightApp: null == queueWeightApp
+          ? _self.queueWeightApp
+          : queueWeightApp // ignore: cast_nullable_to_non_nullable
+              as int,
       maxTimestampBehindMs: freezed == maxTimestampBehindMs
           ? _self.maxTimestampBehindMs
           : maxTimestampBehindMs // ignore: cast_nullable_to_non_nullable
@@ -7038,6 +7230,9 @@ mixin _$VeilidConfigProtectedStore implements DiagnosticableTreeMixin {
   bool get delete;
   String get deviceEncryptionKeyPassword;
   String? get newDeviceEncryptionKeyPassword;
+  VeilidConfigProtectedStoreBackend get backend;
+  String get vaultPassword;
+  List<String> get externalCommand;
 
   /// Create a copy of VeilidConfigProtectedStore
   /// with the given fields replaced by the non-null parameter values.
@@ -7063,7 +7258,10 @@ mixin _$VeilidConfigProtectedStore implements DiagnosticableTreeMixin {
       ..add(DiagnosticsProperty(
           'deviceEncryptionKeyPassword', deviceEncryptionKeyPassword))
       ..add(DiagnosticsProperty(
-          'newDeviceEncryptionKeyPassword', newDeviceEncryptionKeyPassword));
+          'newDeviceEncryptionKeyPassword', newDeviceEncryptionKeyPassword))
+      ..add(DiagnosticsProperty('backend', backend))
+      ..add(DiagnosticsProperty('vaultPassword', vaultPassword))
+      ..add(DiagnosticsProperty('externalCommand', externalCommand));
   }
 
   @override
@@ -7086,7 +7284,12 @@ mixin _$VeilidConfigProtectedStore implements DiagnosticableTreeMixin {
             (identical(other.newDeviceEncryptionKeyPassword,
                     newDeviceEncryptionKeyPassword) ||
                 other.newDeviceEncryptionKeyPassword ==
-                    newDeviceEncryptionKeyPassword));
+                    newDeviceEncryptionKeyPassword) &&
+            (identical(other.backend, backend) || other.backend == backend) &&
+            (identical(other.vaultPassword, vaultPassword) ||
+                other.vaultPassword == vaultPassword) &&
+            const DeepCollectionEquality()
+                .equals(other.externalCommand, externalCommand));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
@@ -7098,11 +7301,14 @@ mixin _$VeilidConfigProtectedStore implements DiagnosticableTreeMixin {
       directory,
       delete,
       deviceEncryptionKeyPassword,
-      newDeviceEncryptionKeyPassword);
+      newDeviceEncryptionKeyPassword,
+      backend,
+      vaultPassword,
+      const DeepCollectionEquality().hash(externalCommand));
 
   @override
   String toString({DiagnosticLevel minLevel = DiagnosticLevel.info}) {
-    return 'VeilidConfigProtectedStore(allowInsecureFallback: $allowInsecureFallback, alwaysUseInsecureStorage: $alwaysUseInsecureStorage, directory: $directory, delete: $delete, deviceEncryptionKeyPassword: $deviceEncryptionKeyPassword, newDeviceEncryptionKeyPassword: $newDeviceEncryptionKeyPassword)';
+    return 'VeilidConfigProtectedStore(allowInsecureFallback: $allowInsecureFallback, alwaysUseInsecureStorage: $alwaysUseInsecureStorage, directory: $directory, delete: $delete, deviceEncryptionKeyPassword: $deviceEncryptionKeyPassword, newDeviceEncryptionKeyPassword: $newDeviceEncryptionKeyPassword, backend: $backend, vaultPassword: $vaultPassword, externalCommand: $externalCommand)';
   }
 }
 
@@ -7118,7 +7324,10 @@ abstract mixin class $VeilidConfigProtectedStoreCopyWith<$Res> {
       String directory,
       bool delete,
       String deviceEncryptionKeyPassword,
-      String? newDeviceEncryptionKeyPassword});
+      String? newDeviceEncryptionKeyPassword,
+      VeilidConfigProtectedStoreBackend backend,
+      String vaultPassword,
+      List<String> externalCommand});
 }
 
 /// @nodoc
@@ -7140,6 +7349,9 @@ class _$VeilidConfigProtectedStoreCopyWithImpl<$Res>
     Object? delete = null,
     Object? deviceEncryptionKeyPassword = null,
     Object? newDeviceEncryptionKeyPassword = freezed,
+    Object? backend = null,
+    Object? vaultPassword = null,
+    Object? externalCommand = null,
   }) {
     return _then(_self.copyWith(
       allowInsecureFallback: null == allowInsecureFallback
@@ -7166,6 +7378,18 @@ class _$VeilidConfigProtectedStoreCopyWithImpl<$Res>
           ? _self.newDeviceEncryptionKeyPassword
           : newDeviceEncryptionKeyPassword // ignore: cast_nullable_to_non_nullable
               as String?,
+      backend: null == backend
+          ? _self.backend
+          : backend // ignore: cast_nullable_to_non_nullable
+              as VeilidConfigProtectedStoreBackend,
+      vaultPassword: null == vaultPassword
+          ? _self.vaultPassword
+          : vaultPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      externalCommand: null == externalCommand
+          ? _self.externalCommand
+          : externalCommand // ignore: cast_nullable_to_non_nullable
+              as List<String>,
     ));
   }
 }
@@ -7181,7 +7405,11 @@ class _VeilidConfigProtectedStore
       required this.directory,
       required this.delete,
       required this.deviceEncryptionKeyPassword,
-      this.newDeviceEncryptionKeyPassword});
+      this.newDeviceEncryptionKeyPassword,
+      required this.backend,
+      required this.vaultPassword,
+      required final List<String> externalCommand})
+      : _externalCommand = externalCommand;
   factory _VeilidConfigProtectedStore.fromJson(Map<String, dynamic> json) =>
       _$VeilidConfigProtectedStoreFromJson(json);
 
@@ -7197,6 +7425,17 @@ class _VeilidConfigProtectedStore
   final String deviceEncryptionKeyPassword;
   @override
   final String? newDeviceEncryptionKeyPassword;
+  @override
+  final VeilidConfigProtectedStoreBackend backend;
+  @override
+  final String vaultPassword;
+  final List<String> _externalCommand;
+  @override
+  List<String> get externalCommand {
+    if (_externalCommand is EqualUnmodifiableListView) return _externalCommand;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_externalCommand);
+  }
 
   /// Create a copy of VeilidConfigProtectedStore
   /// with the given fields replaced by the non-null parameter values.
@@ -7226,7 +7465,10 @@ class _VeilidConfigProtectedStore
       ..add(DiagnosticsProperty(
           'deviceEncryptionKeyPassword', deviceEncryptionKeyPassword))
       ..add(DiagnosticsProperty(
-          'newDeviceEncryptionKeyPassword', newDeviceEncryptionKeyPassword));
+          'newDeviceEncryptionKeyPassword', newDeviceEncryptionKeyPassword))
+      ..add(DiagnosticsProperty('backend', backend))
+      ..add(DiagnosticsProperty('vaultPassword', vaultPassword))
+      ..add(DiagnosticsProperty('externalCommand', externalCommand));
   }
 
   @override
@@ -7249,7 +7491,12 @@ class _VeilidConfigProtectedStore
             (identical(other.newDeviceEncryptionKeyPassword,
                     newDeviceEncryptionKeyPassword) ||
                 other.newDeviceEncryptionKeyPassword ==
-                    newDeviceEncryptionKeyPassword));
+                    newDeviceEncryptionKeyPassword) &&
+            (identical(other.backend, backend) || other.backend == backend) &&
+            (identical(other.vaultPassword, vaultPassword) ||
+                other.vaultPassword == vaultPassword) &&
+            const DeepCollectionEquality()
+                .equals(other._externalCommand, _externalCommand));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
@@ -7261,11 +7508,14 @@ class _VeilidConfigProtectedStore
       directory,
       delete,
       deviceEncryptionKeyPassword,
-      newDeviceEncryptionKeyPassword);
+      newDeviceEncryptionKeyPassword,
+      backend,
+      vaultPassword,
+      const DeepCollectionEquality().hash(_externalCommand));
 
   @override
   String toString({DiagnosticLevel minLevel = DiagnosticLevel.info}) {
-    return 'VeilidConfigProtectedStore(allowInsecureFallback: $allowInsecureFallback, alwaysUseInsecureStorage: $alwaysUseInsecureStorage, directory: $directory, delete: $delete, deviceEncryptionKeyPassword: $deviceEncryptionKeyPassword, newDeviceEncryptionKeyPassword: $newDeviceEncryptionKeyPassword)';
+    return 'VeilidConfigProtectedStore(allowInsecureFallback: $allowInsecureFallback, alwaysUseInsecureStorage: $alwaysUseInsecureStorage, directory: $directory, delete: $delete, deviceEncryptionKeyPassword: $deviceEncryptionKeyPassword, newDeviceEncryptionKeyPassword: $newDeviceEncryptionKeyPassword, backend: $backend, vaultPassword: $vaultPassword, externalCommand: $externalCommand)';
   }
 }
 
@@ -7284,7 +7534,10 @@ abstract mixin class _$VeilidConfigProtectedStoreCopyWith<$Res>
       String directory,
       bool delete,
       String deviceEncryptionKeyPassword,
-      String? newDeviceEncryptionKeyPassword});
+      String? newDeviceEncryptionKeyPassword,
+      VeilidConfigProtectedStoreBackend backend,
+      String vaultPassword,
+      List<String> externalCommand});
 }
 
 /// @nodoc
@@ -7306,6 +7559,9 @@ class __$VeilidConfigProtectedStoreCopyWithImpl<$Res>
     Object? delete = null,
     Object? deviceEncryptionKeyPassword = null,
     Object? newDeviceEncryptionKeyPassword = freezed,
+    Object? backend = null,
+    Object? vaultPassword = null,
+    Object? externalCommand = null,
   }) {
     return _then(_VeilidConfigProtectedStore(
       allowInsecureFallback: null == allowInsecureFallback
@@ -7332,6 +7588,18 @@ class __$VeilidConfigProtectedStoreCopyWithImpl<$Res>
           ? _self.newDeviceEncryptionKeyPassword
           : newDeviceEncryptionKeyPassword // ignore: cast_nullable_to_non_nullable
               as String?,
+      backend: null == backend
+          ? _self.backend
+          : backend // ignore: cast_nullable_to_non_nullable
+              as VeilidConfigProtectedStoreBackend,
+      vaultPassword: null == vaultPassword
+          ? _self.vaultPassword
+          : vaultPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      externalCommand: null == externalCommand
+          ? _self._externalCommand
+          : externalCommand // ignore: cast_nullable_to_non_nullable
+              as List<String>,
     ));
   }
 }
@@ -394,6 +394,14 @@ _VeilidConfigRPC _$VeilidConfigRPCFromJson(Map<String, dynamic> json) =>
       timeoutMs: (json['timeout_ms'] as num).toInt(),
       maxRouteHopCount: (json['max_route_hop_count'] as num).toInt(),
       defaultRouteHopCount: (json['default_route_hop_count'] as num).toInt(),
+      queueShareControl: (json['queue_share_control'] as num).toInt(),
+      queueShareRouting: (json['queue_share_routing'] as num).toInt(),
+      queueShareDht: (json['queue_share_dht'] as num).toInt(),
+      queueShareApp: (json['queue_share_app'] as num).toInt(),
+      queueWeightControl: (json['queue_weight_control'] as num).toInt(),
+      queueWeightRouting: (json['queue_weight_routing'] as num).toInt(),
+      queueWeightDht: (json['queue_weight_dht'] as num).toInt(),
+      queueWeightApp: (json['queue_weight_app'] as num).toInt(),
       maxTimestampBehindMs: (json['max_timestamp_behind_ms'] as num?)?.toInt(),
       maxTimestampAheadMs: (json['max_timestamp_ahead_ms'] as num?)?.toInt(),
     );
@@ -405,6 +413,14 @@ Map<String, dynamic> _$VeilidConfigRPCToJson(_VeilidConfigRPC instance) =>
       'timeout_ms': instance.timeoutMs,
       'max_route_hop_count': instance.maxRouteHopCount,
       'default_route_hop_count': instance.defaultRouteHopCount,
+      'queue_share_control': instance.queueShareControl,
+      'queue_share_routing': instance.queueShareRouting,
+      'queue_share_dht': instance.queueShareDht,
+      'queue_share_app': instance.queueShareApp,
+      'queue_weight_control': instance.queueWeightControl,
+      'queue_weight_routing': instance.queueWeightRouting,
+      'queue_weight_dht': instance.queueWeightDht,
+      'queue_weight_app': instance.queueWeightApp,
       'max_timestamp_behind_ms': instance.maxTimestampBehindMs,
       'max_timestamp_ahead_ms': instance.maxTimestampAheadMs,
     };
@@ -541,6 +557,11 @@ _VeilidConfigProtectedStore _$VeilidConfigProtectedStoreFromJson(
           json['device_encryption_key_password'] as String,
       newDeviceEncryptionKeyPassword:
           json['new_device_encryption_key_password'] as String?,
+      backend: VeilidConfigProtectedStoreBackend.fromJson(json['backend']),
+      vaultPassword: json['vault_password'] as String,
+      externalCommand: (json['external_command'] as List<dynamic>)
+          .map((e) => e as String)
+          .toList(),
     );
 
 Map<String, dynamic> _$VeilidConfigProtectedStoreToJson(
@@ -553,6 +574,9 @@ Map<String, dynamic> _$VeilidConfigProtectedStoreToJson(
       'device_encryption_key_password': instance.deviceEncryptionKeyPassword,
       'new_device_encryption_key_password':
           instance.newDeviceEncryptionKeyPassword,
+      'backend': instance.backend.toJson(),
+      'vault_password': instance.vaultPassword,
+      'external_command': instance.externalCommand,
     };
 
 _VeilidConfigCapabilities _$VeilidConfigCapabilitiesFromJson(
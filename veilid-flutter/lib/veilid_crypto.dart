@@ -222,6 +222,20 @@ abstract class VeilidCryptoSystem {
       SharedSecret sharedSecret, Uint8List? associatedData);
   Future<Uint8List> cryptNoAuth(
       Uint8List body, Nonce nonce, SharedSecret sharedSecret);
+  Future<Uint8List> seal(PublicKey key, Uint8List body);
+  Future<Uint8List> open(PublicKey key, SecretKey secret, Uint8List sealed);
+  Future<Uint8List> openWithKeyPair(KeyPair keyPair, Uint8List sealed) =>
+      open(keyPair.key, keyPair.secret, sealed);
+  Future<Uint8List> signcrypt(PublicKey key, PublicKey senderKey,
+      SecretKey senderSecret, Uint8List body);
+  Future<Uint8List> signcryptWithKeyPair(
+          PublicKey key, KeyPair sender, Uint8List body) =>
+      signcrypt(key, sender.key, sender.secret, body);
+  Future<Uint8List> unsigncrypt(PublicKey key, SecretKey secret,
+      PublicKey senderKey, Uint8List signcrypted);
+  Future<Uint8List> unsigncryptWithKeyPair(
+          KeyPair keyPair, PublicKey senderKey, Uint8List signcrypted) =>
+      unsigncrypt(keyPair.key, keyPair.secret, senderKey, signcrypted);
 
   Future<Uint8List> encryptAeadWithNonce(
       Uint8List body, SharedSecret secret) async {
@@ -44,6 +44,10 @@ typedef _IsShutdownDart = void Function(int);
 typedef _AttachDart = void Function(int);
 // fn detach(port: i64)
 typedef _DetachDart = void Function(int);
+// fn power_profile(port: i64)
+typedef _PowerProfileDart = void Function(int);
+// fn set_power_profile(port: i64, power_profile: FfiStr)
+typedef _SetPowerProfileDart = void Function(int, Pointer<Utf8>);
 
 // fn routing_context(port: i64)
 typedef _RoutingContextDart = void Function(int);
@@ -55,13 +59,17 @@ typedef _RoutingContextWithDefaultSafetyDart = int Function(int);
 typedef _RoutingContextWithSafetyDart = int Function(int, Pointer<Utf8>);
 // fn routing_context_with_sequencing(id: u32, sequencing: FfiStr)
 typedef _RoutingContextWithSequencingDart = int Function(int, Pointer<Utf8>);
+// fn routing_context_with_fragmentation(id: u32, fragmentation: u32)
+typedef _RoutingContextWithFragmentationDart = int Function(int, int);
+// fn routing_context_with_app_namespace(id: u32, app_namespace: u32)
+typedef _RoutingContextWithAppNamespaceDart = int Function(int, int);
 // fn routing_context_safety(port: i64,
 //    id: u32)
 typedef _RoutingContextSafetyDart = void Function(int, int);
 // fn routing_context_app_call(port: i64,
-//    id: u32, target: FfiStr, request: FfiStr)
+//    id: u32, target: FfiStr, request: FfiStr, deadline: u64, cancel_id: u32)
 typedef _RoutingContextAppCallDart = void Function(
-    int, int, Pointer<Utf8>, Pointer<Utf8>);
+    int, int, Pointer<Utf8>, Pointer<Utf8>, int, int);
 // fn routing_context_app_message(port: i64,
 //    id: u32, target: FfiStr, request: FfiStr)
 typedef _RoutingContextAppMessageDart = void Function(
@@ -81,13 +89,15 @@ typedef _RoutingContextCloseDHTRecordDart = void Function(
 typedef _RoutingContextDeleteDHTRecordDart = void Function(
     int, int, Pointer<Utf8>);
 // fn routing_context_get_dht_value(port: i64,
-//    id: u32, key: FfiStr, subkey: u32, force_refresh: bool)
+//    id: u32, key: FfiStr, subkey: u32, force_refresh: bool,
+//    deadline: u64, cancel_id: u32)
 typedef _RoutingContextGetDHTValueDart = void Function(
-    int, int, Pointer<Utf8>, int, bool);
+    int, int, Pointer<Utf8>, int, bool, int, int);
 // fn routing_context_set_dht_value(port: i64,
-//    id: u32, key: FfiStr, subkey: u32, data: FfiStr, writer: FfiStr)
+//    id: u32, key: FfiStr, subkey: u32, data: FfiStr, writer: FfiStr,
+//    deadline: u64, cancel_id: u32)
 typedef _RoutingContextSetDHTValueDart = void Function(
-    int, int, Pointer<Utf8>, int, Pointer<Utf8>, Pointer<Utf8>);
+    int, int, Pointer<Utf8>, int, Pointer<Utf8>, Pointer<Utf8>, int, int);
 // fn routing_context_watch_dht_values(port: i64,
 //     id: u32, key: FfiStr, subkeys: FfiStr, expiration: FfiStr, count: u32)
 typedef _RoutingContextWatchDHTValuesDart = void Function(
@@ -114,6 +124,13 @@ typedef _ReleasePrivateRouteDart = void Function(int, Pointer<Utf8>);
 // fn app_call_reply(port: i64, id: FfiStr, message: FfiStr)
 typedef _AppCallReplyDart = void Function(int, Pointer<Utf8>, Pointer<Utf8>);
 
+// fn new_cancel_handle() -> u32
+typedef _NewCancelHandleDart = int Function();
+// fn cancel_call(cancel_id: u32) -> i32
+typedef _CancelCallDart = int Function(int);
+// fn release_cancel_handle(cancel_id: u32) -> i32
+typedef _ReleaseCancelHandleDart = int Function(int);
+
 // fn open_table_db(port: i64, name: FfiStr, column_count: u32)
 typedef _OpenTableDbDart = void Function(int, Pointer<Utf8>, int);
 // fn release_table_db(id: u32) -> i32
@@ -221,6 +238,23 @@ typedef _CryptoDecryptAeadDart = void Function(
 //    shared_secret: FfiStr, associated_data: FfiStr)
 typedef _CryptoEncryptAeadDart = void Function(
     int, int, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>);
+// fn crypto_seal(port: i64, kind: u32, key: FfiStr, body: FfiStr)
+typedef _CryptoSealDart = void Function(
+    int, int, Pointer<Utf8>, Pointer<Utf8>);
+// fn crypto_open(port: i64,
+//    kind: u32, key: FfiStr, secret: FfiStr, sealed: FfiStr)
+typedef _CryptoOpenDart = void Function(
+    int, int, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>);
+// fn crypto_signcrypt(port: i64,
+//    kind: u32, key: FfiStr, sender_key: FfiStr,
+//    sender_secret: FfiStr, body: FfiStr)
+typedef _CryptoSigncryptDart = void Function(
+    int, int, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>);
+// fn crypto_unsigncrypt(port: i64,
+//    kind: u32, key: FfiStr, secret: FfiStr,
+//    sender_key: FfiStr, signcrypted: FfiStr)
+typedef _CryptoUnsigncryptDart = void Function(
+    int, int, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>);
 // fn crypto_crypt_no_auth(port: i64,
 //    kind: u32, body: FfiStr, nonce: FfiStr, shared_secret: FfiStr)
 typedef _CryptoCryptNoAuthDart = void Function(
@@ -588,6 +622,32 @@ class VeilidRoutingContextFFI extends VeilidRoutingContext {
     return out;
   }
 
+  @override
+  VeilidRoutingContextFFI withFragmentation(bool fragmentation,
+      {bool closeSelf = false}) {
+    _ctx.ensureValid();
+    final newId = _ctx.ffi
+        ._routingContextWithFragmentation(_ctx.id!, fragmentation ? 1 : 0);
+    final out = VeilidRoutingContextFFI._(_Ctx(newId, _ctx.ffi));
+    if (closeSelf) {
+      close();
+    }
+    return out;
+  }
+
+  @override
+  VeilidRoutingContextFFI withAppNamespace(int appNamespace,
+      {bool closeSelf = false}) {
+    _ctx.ensureValid();
+    final newId =
+        _ctx.ffi._routingContextWithAppNamespace(_ctx.id!, appNamespace);
+    final out = VeilidRoutingContextFFI._(_Ctx(newId, _ctx.ffi));
+    if (closeSelf) {
+      close();
+    }
+    return out;
+  }
+
   @override
   Future<SafetySelection> safety() async {
     _ctx.ensureValid();
@@ -600,15 +660,19 @@ class VeilidRoutingContextFFI extends VeilidRoutingContext {
   }
 
   @override
-  Future<Uint8List> appCall(String target, Uint8List request) async {
+  Future<Uint8List> appCall(String target, Uint8List request,
+      {Timestamp? deadline, VeilidCancelHandle? cancelHandle}) async {
     _ctx.ensureValid();
     final nativeEncodedTarget = target.toNativeUtf8();
     final nativeEncodedRequest = base64UrlNoPadEncode(request).toNativeUtf8();
+    final nativeDeadline = deadline?.value.toInt() ?? 0;
+    final nativeCancelId = _CH.idOf(cancelHandle);
 
     final recvPort = ReceivePort('routing_context_app_call');
     final sendPort = recvPort.sendPort;
     _ctx.ffi._routingContextAppCall(sendPort.nativePort, _ctx.id!,
-        nativeEncodedTarget, nativeEncodedRequest);
+        nativeEncodedTarget, nativeEncodedRequest, nativeDeadline,
+        nativeCancelId);
     final out = await processFuturePlain<String>(recvPort.first);
     return base64UrlNoPadDecode(out);
   }
@@ -682,13 +746,17 @@ class VeilidRoutingContextFFI extends VeilidRoutingContext {
 
   @override
   Future<ValueData?> getDHTValue(TypedKey key, int subkey,
-      {bool forceRefresh = false}) async {
+      {bool forceRefresh = false,
+      Timestamp? deadline,
+      VeilidCancelHandle? cancelHandle}) async {
     _ctx.ensureValid();
     final nativeKey = jsonEncode(key).toNativeUtf8();
+    final nativeDeadline = deadline?.value.toInt() ?? 0;
+    final nativeCancelId = _CH.idOf(cancelHandle);
     final recvPort = ReceivePort('routing_context_get_dht_value');
     final sendPort = recvPort.sendPort;
-    _ctx.ffi._routingContextGetDHTValue(
-        sendPort.nativePort, _ctx.id!, nativeKey, subkey, forceRefresh);
+    _ctx.ffi._routingContextGetDHTValue(sendPort.nativePort, _ctx.id!,
+        nativeKey, subkey, forceRefresh, nativeDeadline, nativeCancelId);
     final valueData =
         await processFutureOptJson(ValueData.fromJson, recvPort.first);
     return valueData;
@@ -696,17 +764,22 @@ class VeilidRoutingContextFFI extends VeilidRoutingContext {
 
   @override
   Future<ValueData?> setDHTValue(TypedKey key, int subkey, Uint8List data,
-      {KeyPair? writer}) async {
+      {KeyPair? writer,
+      Timestamp? deadline,
+      VeilidCancelHandle? cancelHandle}) async {
     _ctx.ensureValid();
     final nativeKey = jsonEncode(key).toNativeUtf8();
     final nativeData = base64UrlNoPadEncode(data).toNativeUtf8();
     final nativeWriter =
         writer != null ? jsonEncode(writer).toNativeUtf8() : nullptr;
+    final nativeDeadline = deadline?.value.toInt() ?? 0;
+    final nativeCancelId = _CH.idOf(cancelHandle);
 
     final recvPort = ReceivePort('routing_context_set_dht_value');
     final sendPort = recvPort.sendPort;
     _ctx.ffi._routingContextSetDHTValue(sendPort.nativePort, _ctx.id!,
-        nativeKey, subkey, nativeData, nativeWriter);
+        nativeKey, subkey, nativeData, nativeWriter, nativeDeadline,
+        nativeCancelId);
     final valueData =
         await processFutureOptJson(ValueData.fromJson, recvPort.first);
     return valueData;
@@ -772,6 +845,63 @@ class VeilidRoutingContextFFI extends VeilidRoutingContext {
   }
 }
 
+class _CH {
+  _CH(int this.id, this.ffi);
+  int? id;
+  final VeilidFFI ffi;
+
+  // The id to pass for an optional cancel handle, zero if there is none
+  static int idOf(VeilidCancelHandle? cancelHandle) {
+    if (cancelHandle == null) {
+      return 0;
+    }
+    final ch = (cancelHandle as VeilidCancelHandleFFI)._ch..ensureValid();
+    return ch.id!;
+  }
+
+  void ensureValid() {
+    if (id == null) {
+      throw VeilidAPIExceptionNotInitialized();
+    }
+  }
+
+  void cancel() {
+    if (id != null) {
+      ffi._cancelCall(id!);
+      id = null;
+    }
+  }
+
+  void close() {
+    if (id != null) {
+      ffi._releaseCancelHandle(id!);
+      id = null;
+    }
+  }
+}
+
+// FFI implementation of VeilidCancelHandle
+class VeilidCancelHandleFFI extends VeilidCancelHandle {
+  VeilidCancelHandleFFI._(this._ch) {
+    _finalizer.attach(this, _ch, detach: this);
+  }
+  final _CH _ch;
+  static final Finalizer<_CH> _finalizer = Finalizer((ch) => ch.close());
+
+  @override
+  bool isDone() => _ch.id == null;
+
+  @override
+  void cancel() {
+    _ch.cancel();
+  }
+
+  @override
+  void close() {
+    _ch.close();
+  }
+}
+
 class _TDBT {
   _TDBT(int this.id, this.tdbffi, this.ffi);
   int? id;
@@ -1225,6 +1355,66 @@ class VeilidCryptoSystemFFI extends VeilidCryptoSystem {
     return base64UrlNoPadDecode(out);
   }
 
+  @override
+  Future<Uint8List> seal(PublicKey key, Uint8List body) async {
+    final nativeKey = jsonEncode(key).toNativeUtf8();
+    final nativeEncodedBody = base64UrlNoPadEncode(body).toNativeUtf8();
+
+    final recvPort = ReceivePort('crypto_seal');
+    final sendPort = recvPort.sendPort;
+    _ffi._cryptoSeal(sendPort.nativePort, _kind, nativeKey, nativeEncodedBody);
+    final out = await processFuturePlain<String>(recvPort.first);
+    return base64UrlNoPadDecode(out);
+  }
+
+  @override
+  Future<Uint8List> open(
+      PublicKey key, SecretKey secret, Uint8List sealed) async {
+    final nativeKey = jsonEncode(key).toNativeUtf8();
+    final nativeSecret = jsonEncode(secret).toNativeUtf8();
+    final nativeEncodedSealed = base64UrlNoPadEncode(sealed).toNativeUtf8();
+
+    final recvPort = ReceivePort('crypto_open');
+    final sendPort = recvPort.sendPort;
+    _ffi._cryptoOpen(sendPort.nativePort, _kind, nativeKey, nativeSecret,
+        nativeEncodedSealed);
+    final out = await processFuturePlain<String>(recvPort.first);
+    return base64UrlNoPadDecode(out);
+  }
+
+  @override
+  Future<Uint8List> signcrypt(PublicKey key, PublicKey senderKey,
+      SecretKey senderSecret, Uint8List body) async {
+    final nativeKey = jsonEncode(key).toNativeUtf8();
+    final nativeSenderKey = jsonEncode(senderKey).toNativeUtf8();
+    final nativeSenderSecret = jsonEncode(senderSecret).toNativeUtf8();
+    final nativeEncodedBody = base64UrlNoPadEncode(body).toNativeUtf8();
+
+    final recvPort = ReceivePort('crypto_signcrypt');
+    final sendPort = recvPort.sendPort;
+    _ffi._cryptoSigncrypt(sendPort.nativePort, _kind, nativeKey,
+        nativeSenderKey, nativeSenderSecret, nativeEncodedBody);
+    final out = await processFuturePlain<String>(recvPort.first);
+    return base64UrlNoPadDecode(out);
+  }
+
+  @override
+  Future<Uint8List> unsigncrypt(PublicKey key, SecretKey secret,
+      PublicKey senderKey, Uint8List signcrypted) async {
+    final nativeKey = jsonEncode(key).toNativeUtf8();
+    final nativeSecret = jsonEncode(secret).toNativeUtf8();
+    final nativeSenderKey = jsonEncode(senderKey).toNativeUtf8();
+    final nativeEncodedSigncrypted =
+        base64UrlNoPadEncode(signcrypted).toNativeUtf8();
+
+    final recvPort = ReceivePort('crypto_unsigncrypt');
+    final sendPort = recvPort.sendPort;
+    _ffi._cryptoUnsigncrypt(sendPort.nativePort, _kind, nativeKey,
+        nativeSecret, nativeSenderKey, nativeEncodedSigncrypted);
+    final out = await processFuturePlain<String>(recvPort.first);
+    return base64UrlNoPadDecode(out);
+  }
+
   @override
   Future<Uint8List> cryptNoAuth(
       Uint8List body, Nonce nonce, SharedSecret sharedSecret) async {
@@ -1270,6 +1460,12 @@ class VeilidFFI extends Veilid {
             dylib.lookupFunction<Void Function(Int64), _AttachDart>('attach'),
         _detach =
             dylib.lookupFunction<Void Function(Int64), _DetachDart>('detach'),
+        _powerProfile =
+            dylib.lookupFunction<Void Function(Int64), _PowerProfileDart>(
+                'power_profile'),
+        _setPowerProfile = dylib.lookupFunction<
+            Void Function(Int64, Pointer<Utf8>),
+            _SetPowerProfileDart>('set_power_profile'),
         _shutdownVeilidCore =
             dylib.lookupFunction<Void Function(Int64), _ShutdownVeilidCoreDart>(
                 'shutdown_veilid_core'),
@@ -1288,11 +1484,20 @@ class VeilidFFI extends Veilid {
                 Uint32 Function(Uint32, Pointer<Utf8>),
                 _RoutingContextWithSequencingDart>(
             'routing_context_with_sequencing'),
+        _routingContextWithFragmentation = dylib.lookupFunction<
+                Uint32 Function(Uint32, Uint32),
+                _RoutingContextWithFragmentationDart>(
+            'routing_context_with_fragmentation'),
+        _routingContextWithAppNamespace = dylib.lookupFunction<
+                Uint32 Function(Uint32, Uint32),
+                _RoutingContextWithAppNamespaceDart>(
+            'routing_context_with_app_namespace'),
         _routingContextSafety = dylib.lookupFunction<
             Void Function(Int64, Uint32),
             _RoutingContextSafetyDart>('routing_context_safety'),
         _routingContextAppCall = dylib.lookupFunction<
-            Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>),
+            Void Function(
+                Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>, Uint64, Uint32),
             _RoutingContextAppCallDart>('routing_context_app_call'),
         _routingContextAppMessage = dylib.lookupFunction<
             Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>),
@@ -1316,11 +1521,12 @@ class VeilidFFI extends Veilid {
                 _RoutingContextDeleteDHTRecordDart>(
             'routing_context_delete_dht_record'),
         _routingContextGetDHTValue = dylib.lookupFunction<
-            Void Function(Int64, Uint32, Pointer<Utf8>, Uint32, Bool),
+            Void Function(
+                Int64, Uint32, Pointer<Utf8>, Uint32, Bool, Uint64, Uint32),
             _RoutingContextGetDHTValueDart>('routing_context_get_dht_value'),
         _routingContextSetDHTValue = dylib.lookupFunction<
             Void Function(Int64, Uint32, Pointer<Utf8>, Uint32, Pointer<Utf8>,
-                Pointer<Utf8>),
+                Pointer<Utf8>, Uint64, Uint32),
             _RoutingContextSetDHTValueDart>('routing_context_set_dht_value'),
         _routingContextWatchDHTValues = dylib.lookupFunction<
                 Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>,
@@ -1351,6 +1557,12 @@ class VeilidFFI extends Veilid {
         _appCallReply = dylib.lookupFunction<
             Void Function(Int64, Pointer<Utf8>, Pointer<Utf8>),
             _AppCallReplyDart>('app_call_reply'),
+        _newCancelHandle = dylib.lookupFunction<Uint32 Function(),
+            _NewCancelHandleDart>('new_cancel_handle'),
+        _cancelCall = dylib.lookupFunction<Int32 Function(Uint32),
+            _CancelCallDart>('cancel_call'),
+        _releaseCancelHandle = dylib.lookupFunction<Int32 Function(Uint32),
+            _ReleaseCancelHandleDart>('release_cancel_handle'),
         _openTableDb = dylib.lookupFunction<
             Void Function(Int64, Pointer<Utf8>, Uint32),
             _OpenTableDbDart>('open_table_db'),
@@ -1468,6 +1680,21 @@ class VeilidFFI extends Veilid {
             Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>,
                 Pointer<Utf8>, Pointer<Utf8>),
             _CryptoEncryptAeadDart>('crypto_encrypt_aead'),
+        _cryptoSeal = dylib.lookupFunction<
+            Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>),
+            _CryptoSealDart>('crypto_seal'),
+        _cryptoOpen = dylib.lookupFunction<
+            Void Function(
+                Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>),
+            _CryptoOpenDart>('crypto_open'),
+        _cryptoSigncrypt = dylib.lookupFunction<
+            Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>,
+                Pointer<Utf8>, Pointer<Utf8>),
+            _CryptoSigncryptDart>('crypto_signcrypt'),
+        _cryptoUnsigncrypt = dylib.lookupFunction<
+            Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>,
+                Pointer<Utf8>, Pointer<Utf8>),
+            _CryptoUnsigncryptDart>('crypto_unsigncrypt'),
         _cryptoCryptNoAuth = dylib.lookupFunction<
             Void Function(
                 Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>),
@@ -1509,6 +1736,8 @@ class VeilidFFI extends Veilid {
   final _IsShutdownDart _isShutdown;
   final _AttachDart _attach;
   final _DetachDart _detach;
+  final _PowerProfileDart _powerProfile;
+  final _SetPowerProfileDart _setPowerProfile;
   final _ShutdownVeilidCoreDart _shutdownVeilidCore;
 
   final _RoutingContextDart _routingContext;
@@ -1516,6 +1745,8 @@ class VeilidFFI extends Veilid {
   final _RoutingContextWithDefaultSafetyDart _routingContextWithDefaultSafety;
   final _RoutingContextWithSafetyDart _routingContextWithSafety;
   final _RoutingContextWithSequencingDart _routingContextWithSequencing;
+  final _RoutingContextWithFragmentationDart _routingContextWithFragmentation;
+  final _RoutingContextWithAppNamespaceDart _routingContextWithAppNamespace;
   final _RoutingContextSafetyDart _routingContextSafety;
   final _RoutingContextAppCallDart _routingContextAppCall;
   final _RoutingContextAppMessageDart _routingContextAppMessage;
@@ -1536,6 +1767,10 @@ class VeilidFFI extends Veilid {
 
   final _AppCallReplyDart _appCallReply;
 
+  final _NewCancelHandleDart _newCancelHandle;
+  final _CancelCallDart _cancelCall;
+  final _ReleaseCancelHandleDart _releaseCancelHandle;
+
   final _OpenTableDbDart _openTableDb;
   final _ReleaseTableDbDart _releaseTableDb;
   final _DeleteTableDbDart _deleteTableDb;
@@ -1580,6 +1815,10 @@ class VeilidFFI extends Veilid {
   final _CryptoAeadOverheadDart _cryptoAeadOverhead;
   final _CryptoDecryptAeadDart _cryptoDecryptAead;
   final _CryptoEncryptAeadDart _cryptoEncryptAead;
+  final _CryptoSealDart _cryptoSeal;
+  final _CryptoOpenDart _cryptoOpen;
+  final _CryptoSigncryptDart _cryptoSigncrypt;
+  final _CryptoUnsigncryptDart _cryptoUnsigncrypt;
   final _CryptoCryptNoAuthDart _cryptoCryptNoAuth;
 
   final _NowDart _now;
@@ -1665,6 +1904,24 @@ class VeilidFFI extends Veilid {
     return processFutureVoid(recvPort.first);
   }
 
+  @override
+  Future<PowerProfile> powerProfile() async {
+    final recvPort = ReceivePort('power_profile');
+    final sendPort = recvPort.sendPort;
+    _powerProfile(sendPort.nativePort);
+    return processFutureJson(PowerProfile.fromJson, recvPort.first);
+  }
+
+  @override
+  Future<void> setPowerProfile(PowerProfile powerProfile) async {
+    final nativePowerProfile = jsonEncode(powerProfile).toNativeUtf8();
+    final recvPort = ReceivePort('set_power_profile');
+    final sendPort = recvPort.sendPort;
+    _setPowerProfile(sendPort.nativePort, nativePowerProfile);
+    malloc.free(nativePowerProfile);
+    return processFutureVoid(recvPort.first);
+  }
+
   @override
   Future<void> shutdownVeilidCore() async {
     final recvPort = ReceivePort('shutdown_veilid_core');
@@ -1734,6 +1991,10 @@ class VeilidFFI extends Veilid {
     return processFutureVoid(recvPort.first);
   }
 
+  @override
+  VeilidCancelHandle newCancelHandle() =>
+      VeilidCancelHandleFFI._(_CH(_newCancelHandle(), this));
+
   @override
   Future<VeilidTableDB> openTableDB(String name, int columnCount) async {
     final recvPort = ReceivePort('open_table_db');
@@ -110,6 +110,32 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
     return out;
   }
 
+  @override
+  VeilidRoutingContextJS withFragmentation(bool fragmentation,
+      {bool closeSelf = false}) {
+    final id = _ctx.requireId();
+    final newId = js_util.callMethod<int>(
+        wasm, 'routing_context_with_fragmentation', [id, fragmentation]);
+    final out = VeilidRoutingContextJS._(_Ctx(newId, _ctx.js));
+    if (closeSelf) {
+      close();
+    }
+    return out;
+  }
+
+  @override
+  VeilidRoutingContextJS withAppNamespace(int appNamespace,
+      {bool closeSelf = false}) {
+    final id = _ctx.requireId();
+    final newId = js_util.callMethod<int>(
+        wasm, 'routing_context_with_app_namespace', [id, appNamespace]);
+    final out = VeilidRoutingContextJS._(_Ctx(newId, _ctx.js));
+    if (closeSelf) {
+      close();
+    }
+    return out;
+  }
+
   @override
   Future<SafetySelection> safety() async {
     final id = _ctx.requireId();
@@ -120,12 +146,19 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
   }
 
   @override
-  Future<Uint8List> appCall(String target, Uint8List request) async {
+  Future<Uint8List> appCall(String target, Uint8List request,
+      {Timestamp? deadline, VeilidCancelHandle? cancelHandle}) async {
     final id = _ctx.requireId();
     final encodedRequest = base64UrlNoPadEncode(request);
 
-    return base64UrlNoPadDecode(await _wrapApiPromise(js_util.callMethod(
-        wasm, 'routing_context_app_call', [id, target, encodedRequest])));
+    return base64UrlNoPadDecode(await _wrapApiPromise(
+        js_util.callMethod(wasm, 'routing_context_app_call', [
+      id,
+      target,
+      encodedRequest,
+      deadline?.value.toString(),
+      _CH.idOf(cancelHandle)
+    ])));
   }
 
   @override
@@ -178,12 +211,19 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
 
   @override
   Future<ValueData?> getDHTValue(TypedKey key, int subkey,
-      {bool forceRefresh = false}) async {
+      {bool forceRefresh = false,
+      Timestamp? deadline,
+      VeilidCancelHandle? cancelHandle}) async {
     final id = _ctx.requireId();
-    final opt = await _wrapApiPromise<String?>(js_util.callMethod(
-        wasm,
-        'routing_context_get_dht_value',
-        [id, jsonEncode(key), subkey, forceRefresh]));
+    final opt = await _wrapApiPromise<String?>(
+        js_util.callMethod(wasm, 'routing_context_get_dht_value', [
+      id,
+      jsonEncode(key),
+      subkey,
+      forceRefresh,
+      deadline?.value.toString(),
+      _CH.idOf(cancelHandle)
+    ]));
     if (opt == null) {
       return null;
     }
@@ -193,7 +233,9 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
 
   @override
   Future<ValueData?> setDHTValue(TypedKey key, int subkey, Uint8List data,
-      {KeyPair? writer}) async {
+      {KeyPair? writer,
+      Timestamp? deadline,
+      VeilidCancelHandle? cancelHandle}) async {
     final id = _ctx.requireId();
     final opt = await _wrapApiPromise<String?>(
         js_util.callMethod(wasm, 'routing_context_set_dht_value', [
@@ -201,7 +243,9 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
       jsonEncode(key),
       subkey,
       base64UrlNoPadEncode(data),
-      if (writer != null) jsonEncode(writer) else null
+      if (writer != null) jsonEncode(writer) else null,
+      deadline?.value.toString(),
+      _CH.idOf(cancelHandle)
     ]));
     if (opt == null) {
       return null;
@@ -409,6 +453,48 @@ class VeilidCryptoSystemJS extends VeilidCryptoSystem {
           null
       ])));
 
+  @override
+  Future<Uint8List> seal(PublicKey key, Uint8List body) async =>
+      base64UrlNoPadDecode(await _wrapApiPromise(js_util.callMethod(
+          wasm,
+          'crypto_seal',
+          [_kind, jsonEncode(key), base64UrlNoPadEncode(body)])));
+
+  @override
+  Future<Uint8List> open(
+          PublicKey key, SecretKey secret, Uint8List sealed) async =>
+      base64UrlNoPadDecode(await _wrapApiPromise(
+          js_util.callMethod(wasm, 'crypto_open', [
+        _kind,
+        jsonEncode(key),
+        jsonEncode(secret),
+        base64UrlNoPadEncode(sealed)
+      ])));
+
+  @override
+  Future<Uint8List> signcrypt(PublicKey key, PublicKey senderKey,
+          SecretKey senderSecret, Uint8List body) async =>
+      base64UrlNoPadDecode(await _wrapApiPromise(
+          js_util.callMethod(wasm, 'crypto_signcrypt', [
+        _kind,
+        jsonEncode(key),
+        jsonEncode(senderKey),
+        jsonEncode(senderSecret),
+        base64UrlNoPadEncode(body)
+      ])));
+
+  @override
+  Future<Uint8List> unsigncrypt(PublicKey key, SecretKey secret,
+          PublicKey senderKey, Uint8List signcrypted) async =>
+      base64UrlNoPadDecode(await _wrapApiPromise(
+          js_util.callMethod(wasm, 'crypto_unsigncrypt', [
+        _kind,
+        jsonEncode(key),
+        jsonEncode(secret),
+        jsonEncode(senderKey),
+        base64UrlNoPadEncode(signcrypted)
+      ])));
+
   @override
   Future<Uint8List> cryptNoAuth(
           Uint8List body, Nonce nonce, SharedSecret sharedSecret) async =>
@@ -421,6 +507,63 @@ class VeilidCryptoSystemJS extends VeilidCryptoSystem {
       ])));
 }
 
+class _CH {
+  _CH(int id, this.js) : _id = id;
+  int? _id;
+  final VeilidJS js;
+
+  // The id to pass for an optional cancel handle, null if there is none
+  static int? idOf(VeilidCancelHandle? cancelHandle) {
+    if (cancelHandle == null) {
+      return null;
+    }
+    return (cancelHandle as VeilidCancelHandleJS)._ch.requireId();
+  }
+
+  int requireId() {
+    if (_id == null) {
+      throw VeilidAPIExceptionNotInitialized();
+    }
+    return _id!;
+  }
+
+  void cancel() {
+    if (_id != null) {
+      js_util.callMethod<void>(wasm, 'cancel_call', [_id]);
+      _id = null;
+    }
+  }
+
+  void close() {
+    if (_id != null) {
+      js_util.callMethod<void>(wasm, 'release_cancel_handle', [_id]);
+      _id = null;
+    }
+  }
+}
+
+// JS implementation of VeilidCancelHandle
+class VeilidCancelHandleJS extends VeilidCancelHandle {
+  VeilidCancelHandleJS._(this._ch) {
+    _finalizer.attach(this, _ch, detach: this);
+  }
+  final _CH _ch;
+  static final Finalizer<_CH> _finalizer = Finalizer((ch) => ch.close());
+
+  @override
+  bool isDone() => _ch._id == null;
+
+  @override
+  void cancel() {
+    _ch.cancel();
+  }
+
+  @override
+  void close() {
+    _ch.close();
+  }
+}
+
 class _TDBT {
   _TDBT(this.id, this.tdbjs, this.js);
   int? id;
@@ -643,6 +786,16 @@ class VeilidJS extends Veilid {
   Future<void> detach() =>
       _wrapApiPromise(js_util.callMethod(wasm, 'detach', []));
 
+  @override
+  Future<PowerProfile> powerProfile() async =>
+      PowerProfile.fromJson(jsonDecode(await _wrapApiPromise<String>(
+          js_util.callMethod(wasm, 'power_profile', []))));
+
+  @override
+  Future<void> setPowerProfile(PowerProfile powerProfile) =>
+      _wrapApiPromise(js_util.callMethod(
+          wasm, 'set_power_profile', [jsonEncode(powerProfile)]));
+
   @override
   Future<void> shutdownVeilidCore() =>
       _wrapApiPromise(js_util.callMethod(wasm, 'shutdown_veilid_core', []));
@@ -729,6 +882,10 @@ class VeilidJS extends Veilid {
         js_util.callMethod(wasm, 'app_call_reply', [callId, encodedMessage]));
   }
 
+  @override
+  VeilidCancelHandle newCancelHandle() => VeilidCancelHandleJS._(
+      _CH(js_util.callMethod<int>(wasm, 'new_cancel_handle', []), this));
+
   @override
   Future<VeilidTableDB> openTableDB(String name, int columnCount) async {
     final dbid = await _wrapApiPromise<int>(
@@ -19,7 +19,8 @@ enum AttachmentState {
   attachedStrong,
   fullyAttached,
   overAttached,
-  detaching;
+  detaching,
+  resuming;
 
   factory AttachmentState.fromJson(dynamic j) =>
       AttachmentState.values.byName((j as String).toCamelCase());
@@ -27,6 +28,19 @@ enum AttachmentState {
   String toJson() => name.toPascalCase();
 }
 
+//////////////////////////////////////
+/// PowerProfile
+
+enum PowerProfile {
+  normal,
+  lowPower;
+
+  factory PowerProfile.fromJson(dynamic j) =>
+      PowerProfile.values.byName((j as String).toCamelCase());
+
+  String toJson() => name.toPascalCase();
+}
+
 //////////////////////////////////////
 /// VeilidLogLevel
 
@@ -211,12 +225,14 @@ sealed class VeilidUpdate with _$VeilidUpdate {
     @Uint8ListJsonConverter.jsIsArray() required Uint8List message,
     TypedKey? sender,
     String? routeId,
+    @Default(0) int namespace,
   }) = VeilidAppMessage;
   const factory VeilidUpdate.appCall({
     @Uint8ListJsonConverter.jsIsArray() required Uint8List message,
     required String callId,
     TypedKey? sender,
     String? routeId,
+    @Default(0) int namespace,
   }) = VeilidAppCall;
   const factory VeilidUpdate.attachment(
       {required AttachmentState state,
@@ -242,6 +258,13 @@ sealed class VeilidUpdate with _$VeilidUpdate {
     required int count,
     required ValueData? value,
   }) = VeilidUpdateValueChange;
+  const factory VeilidUpdate.topicMessage({
+    required TypedKey key,
+    required String topic,
+    required PublicKey publisher,
+    required Timestamp published,
+    @Uint8ListJsonConverter.jsIsArray() required Uint8List message,
+  }) = VeilidUpdateTopicMessage;
 
   factory VeilidUpdate.fromJson(dynamic json) =>
       _$VeilidUpdateFromJson(json as Map<String, dynamic>);
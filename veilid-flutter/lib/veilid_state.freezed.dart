@@ -2566,6 +2566,8 @@ VeilidUpdate _$VeilidUpdateFromJson(Map<String, dynamic> json) {
       return VeilidUpdateRouteChange.fromJson(json);
     case 'ValueChange':
       return VeilidUpdateValueChange.fromJson(json);
+    case 'TopicMessage':
+      return VeilidUpdateTopicMessage.fromJson(json);
 
     default:
       throw CheckedFromJsonException(json, 'kind', 'VeilidUpdate',
@@ -2702,6 +2704,7 @@ class VeilidAppMessage implements VeilidUpdate {
       {@Uint8ListJsonConverter.jsIsArray() required this.message,
       this.sender,
       this.routeId,
+      this.namespace = 0,
       final String? $type})
       : $type = $type ?? 'AppMessage';
   factory VeilidAppMessage.fromJson(Map<String, dynamic> json) =>
@@ -2711,6 +2714,8 @@ class VeilidAppMessage implements VeilidUpdate {
   final Uint8List message;
   final TypedKey? sender;
   final String? routeId;
+  @JsonKey()
+  final int namespace;
 
   @JsonKey(name: 'kind')
   final String $type;
@@ -2736,17 +2741,19 @@ class VeilidAppMessage implements VeilidUpdate {
             other is VeilidAppMessage &&
             const DeepCollectionEquality().equals(other.message, message) &&
             (identical(other.sender, sender) || other.sender == sender) &&
-            (identical(other.routeId, routeId) || other.routeId == routeId));
+            (identical(other.routeId, routeId) || other.routeId == routeId) &&
+            (identical(other.namespace, namespace) ||
+                other.namespace == namespace));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   int get hashCode => Object.hash(runtimeType,
-      const DeepCollectionEquality().hash(message), sender, routeId);
+      const DeepCollectionEquality().hash(message), sender, routeId, namespace);
 
   @override
   String toString() {
-    return 'VeilidUpdate.appMessage(message: $message, sender: $sender, routeId: $routeId)';
+    return 'VeilidUpdate.appMessage(message: $message, sender: $sender, routeId: $routeId, namespace: $namespace)';
   }
 }
 
@@ -2760,7 +2767,8 @@ abstract mixin class $VeilidAppMessageCopyWith<$Res>
   $Res call(
       {@Uint8ListJsonConverter.jsIsArray() Uint8List message,
       TypedKey? sender,
-      String? routeId});
+      String? routeId,
+      int namespace});
 }
 
 /// @nodoc
@@ -2778,6 +2786,7 @@ class _$VeilidAppMessageCopyWithImpl<$Res>
     Object? message = null,
     Object? sender = freezed,
     Object? routeId = freezed,
+    Object? namespace = null,
   }) {
     return _then(VeilidAppMessage(
       message: null == message
@@ -2792,6 +2801,10 @@ class _$VeilidAppMessageCopyWithImpl<$Res>
           ? _self.routeId
           : routeId // ignore: cast_nullable_to_non_nullable
               as String?,
+      namespace: null == namespace
+          ? _self.namespace
+          : namespace // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
@@ -2804,6 +2817,7 @@ class VeilidAppCall implements VeilidUpdate {
       required this.callId,
       this.sender,
       this.routeId,
+      this.namespace = 0,
       final String? $type})
       : $type = $type ?? 'AppCall';
   factory VeilidAppCall.fromJson(Map<String, dynamic> json) =>
@@ -2814,6 +2828,8 @@ class VeilidAppCall implements VeilidUpdate {
   final String callId;
   final TypedKey? sender;
   final String? routeId;
+  @JsonKey()
+  final int namespace;
 
   @JsonKey(name: 'kind')
   final String $type;
@@ -2840,17 +2856,23 @@ class VeilidAppCall implements VeilidUpdate {
             const DeepCollectionEquality().equals(other.message, message) &&
             (identical(other.callId, callId) || other.callId == callId) &&
             (identical(other.sender, sender) || other.sender == sender) &&
-            (identical(other.routeId, routeId) || other.routeId == routeId));
+            (identical(other.routeId, routeId) || other.routeId == routeId) &&
+            (identical(other.namespace, namespace) ||
+                other.namespace == namespace));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   int get hashCode => Object.hash(runtimeType,
-      const DeepCollectionEquality().hash(message), callId, sender, routeId);
+      const DeepCollectionEquality().hash(message),
+      callId,
+      sender,
+      routeId,
+      namespace);
 
   @override
   String toString() {
-    return 'VeilidUpdate.appCall(message: $message, callId: $callId, sender: $sender, routeId: $routeId)';
+    return 'VeilidUpdate.appCall(message: $message, callId: $callId, sender: $sender, routeId: $routeId, namespace: $namespace)';
   }
 }
 
@@ -2865,7 +2887,8 @@ abstract mixin class $VeilidAppCallCopyWith<$Res>
       {@Uint8ListJsonConverter.jsIsArray() Uint8List message,
       String callId,
       TypedKey? sender,
-      String? routeId});
+      String? routeId,
+      int namespace});
 }
 
 /// @nodoc
@@ -2884,6 +2907,7 @@ class _$VeilidAppCallCopyWithImpl<$Res>
     Object? callId = null,
     Object? sender = freezed,
     Object? routeId = freezed,
+    Object? namespace = null,
   }) {
     return _then(VeilidAppCall(
       message: null == message
@@ -2902,6 +2926,10 @@ class _$VeilidAppCallCopyWithImpl<$Res>
           ? _self.routeId
           : routeId // ignore: cast_nullable_to_non_nullable
               as String?,
+      namespace: null == namespace
+          ? _self.namespace
+          : namespace // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
@@ -3471,6 +3499,128 @@ class _$VeilidUpdateValueChangeCopyWithImpl<$Res>
   }
 }
 
+/// @nodoc
+@JsonSerializable()
+class VeilidUpdateTopicMessage implements VeilidUpdate {
+  const VeilidUpdateTopicMessage(
+      {required this.key,
+      required this.topic,
+      required this.publisher,
+      required this.published,
+      @Uint8ListJsonConverter.jsIsArray() required this.message,
+      final String? $type})
+      : $type = $type ?? 'TopicMessage';
+  factory VeilidUpdateTopicMessage.fromJson(Map<String, dynamic> json) =>
+      _$VeilidUpdateTopicMessageFromJson(json);
+
+  final TypedKey key;
+  final String topic;
+  final PublicKey publisher;
+  final Timestamp published;
+  @Uint8ListJsonConverter.jsIsArray()
+  final Uint8List message;
+
+  @JsonKey(name: 'kind')
+  final String $type;
+
+  /// Create a copy of VeilidUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $VeilidUpdateTopicMessageCopyWith<VeilidUpdateTopicMessage> get copyWith =>
+      _$VeilidUpdateTopicMessageCopyWithImpl<VeilidUpdateTopicMessage>(
+          this, _$identity);
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$VeilidUpdateTopicMessageToJson(
+      this,
+    );
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is VeilidUpdateTopicMessage &&
+            (identical(other.key, key) || other.key == key) &&
+            (identical(other.topic, topic) || other.topic == topic) &&
+            (identical(other.publisher, publisher) ||
+                other.publisher == publisher) &&
+            (identical(other.published, published) ||
+                other.published == published) &&
+            const DeepCollectionEquality().equals(other.message, message));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(runtimeType, key, topic, publisher,
+      published, const DeepCollectionEquality().hash(message));
+
+  @override
+  String toString() {
+    return 'VeilidUpdate.topicMessage(key: $key, topic: $topic, publisher: $publisher, published: $published, message: $message)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $VeilidUpdateTopicMessageCopyWith<$Res>
+    implements $VeilidUpdateCopyWith<$Res> {
+  factory $VeilidUpdateTopicMessageCopyWith(VeilidUpdateTopicMessage value,
+          $Res Function(VeilidUpdateTopicMessage) _then) =
+      _$VeilidUpdateTopicMessageCopyWithImpl;
+  @useResult
+  $Res call(
+      {TypedKey key,
+      String topic,
+      PublicKey publisher,
+      Timestamp published,
+      @Uint8ListJsonConverter.jsIsArray() Uint8List message});
+}
+
+/// @nodoc
+class _$VeilidUpdateTopicMessageCopyWithImpl<$Res>
+    implements $VeilidUpdateTopicMessageCopyWith<$Res> {
+  _$VeilidUpdateTopicMessageCopyWithImpl(this._self, this._then);
+
+  final VeilidUpdateTopicMessage _self;
+  final $Res Function(VeilidUpdateTopicMessage) _then;
+
+  /// Create a copy of VeilidUpdate
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? key = null,
+    Object? topic = null,
+    Object? publisher = null,
+    Object? published = null,
+    Object? message = null,
+  }) {
+    return _then(VeilidUpdateTopicMessage(
+      key: null == key
+          ? _self.key
+          : key // ignore: cast_nullable_to_non_nullable
+              as TypedKey,
+      topic: null == topic
+          ? _self.topic
+          : topic // ignore: cast_nullable_to_non_nullable
+              as String,
+      publisher: null == publisher
+          ? _self.publisher
+          : publisher // ignore: cast_nullable_to_non_nullable
+              as PublicKey,
+      published: null == published
+          ? _self.published
+          : published // ignore: cast_nullable_to_non_nullable
+              as Timestamp,
+      message: null == message
+          ? _self.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
 /// @nodoc
 mixin _$VeilidStateAttachment {
   AttachmentState get state;
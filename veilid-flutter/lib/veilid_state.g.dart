@@ -230,6 +230,7 @@ VeilidAppMessage _$VeilidAppMessageFromJson(Map<String, dynamic> json) =>
           ? null
           : Typed<FixedEncodedString43>.fromJson(json['sender']),
       routeId: json['route_id'] as String?,
+      namespace: (json['namespace'] as num?)?.toInt() ?? 0,
       $type: json['kind'] as String?,
     );
 
@@ -239,6 +240,7 @@ Map<String, dynamic> _$VeilidAppMessageToJson(VeilidAppMessage instance) =>
           const Uint8ListJsonConverter.jsIsArray().toJson(instance.message),
       'sender': instance.sender?.toJson(),
       'route_id': instance.routeId,
+      'namespace': instance.namespace,
       'kind': instance.$type,
     };
 
@@ -251,6 +253,7 @@ VeilidAppCall _$VeilidAppCallFromJson(Map<String, dynamic> json) =>
           ? null
           : Typed<FixedEncodedString43>.fromJson(json['sender']),
       routeId: json['route_id'] as String?,
+      namespace: (json['namespace'] as num?)?.toInt() ?? 0,
       $type: json['kind'] as String?,
     );
 
@@ -261,6 +264,7 @@ Map<String, dynamic> _$VeilidAppCallToJson(VeilidAppCall instance) =>
       'call_id': instance.callId,
       'sender': instance.sender?.toJson(),
       'route_id': instance.routeId,
+      'namespace': instance.namespace,
       'kind': instance.$type,
     };
 
@@ -362,6 +366,30 @@ Map<String, dynamic> _$VeilidUpdateValueChangeToJson(
       'kind': instance.$type,
     };
 
+VeilidUpdateTopicMessage _$VeilidUpdateTopicMessageFromJson(
+        Map<String, dynamic> json) =>
+    VeilidUpdateTopicMessage(
+      key: Typed<FixedEncodedString43>.fromJson(json['key']),
+      topic: json['topic'] as String,
+      publisher: FixedEncodedString43.fromJson(json['publisher']),
+      published: Timestamp.fromJson(json['published']),
+      message:
+          const Uint8ListJsonConverter.jsIsArray().fromJson(json['message']),
+      $type: json['kind'] as String?,
+    );
+
+Map<String, dynamic> _$VeilidUpdateTopicMessageToJson(
+        VeilidUpdateTopicMessage instance) =>
+    <String, dynamic>{
+      'key': instance.key.toJson(),
+      'topic': instance.topic,
+      'publisher': instance.publisher.toJson(),
+      'published': instance.published.toJson(),
+      'message':
+          const Uint8ListJsonConverter.jsIsArray().toJson(instance.message),
+      'kind': instance.$type,
+    };
+
 _VeilidStateAttachment _$VeilidStateAttachmentFromJson(
         Map<String, dynamic> json) =>
     _VeilidStateAttachment(
@@ -16,7 +16,8 @@ class ProcessorConnectionState {
 
   bool get isAttached => !(attachment.state == AttachmentState.detached ||
       attachment.state == AttachmentState.detaching ||
-      attachment.state == AttachmentState.attaching);
+      attachment.state == AttachmentState.attaching ||
+      attachment.state == AttachmentState.resuming);
 
   bool get isPublicInternetReady => attachment.publicInternetReady;
 }
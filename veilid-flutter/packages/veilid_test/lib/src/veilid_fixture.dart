@@ -118,6 +118,7 @@ class DefaultVeilidFixture implements VeilidFixture {
       } else if (update is VeilidAppMessage) {
       } else if (update is VeilidAppCall) {
       } else if (update is VeilidUpdateValueChange) {
+      } else if (update is VeilidUpdateTopicMessage) {
       } else if (update is VeilidUpdateRouteChange) {
       } else {
         throw Exception('unexpected update: $update');
@@ -145,6 +146,8 @@ class DefaultVeilidFixture implements VeilidFixture {
             break;
           case AttachmentState.detaching:
             break;
+          case AttachmentState.resuming:
+            break;
           case AttachmentState.attachedGood:
           case AttachmentState.attachedStrong:
           case AttachmentState.attachedWeak:
@@ -66,6 +66,8 @@ lazy_static! {
     static ref TABLE_DBS: Mutex<BTreeMap<u32, veilid_core::TableDB>> = Mutex::new(BTreeMap::new());
     static ref TABLE_DB_TRANSACTIONS: Mutex<BTreeMap<u32, veilid_core::TableDBTransaction>> =
         Mutex::new(BTreeMap::new());
+    static ref CANCEL_HANDLES: Mutex<BTreeMap<u32, veilid_core::CancelHandle>> =
+        Mutex::new(BTreeMap::new());
     static ref FLAME_GUARD: Mutex<Option<tracing_flame::FlushGuard<std::io::BufWriter<std::fs::File>>>> =
         Mutex::new(None);
 }
@@ -518,6 +520,34 @@ pub extern "C" fn detach(port: i64) {
     );
 }
 
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn power_profile(port: i64) {
+    DartIsolateWrapper::new(port).spawn_result_json(
+        async move {
+            let veilid_api = get_veilid_api().await?;
+            let power_profile = veilid_api.power_profile()?;
+            APIResult::Ok(power_profile)
+        }
+        .in_current_span(),
+    );
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn set_power_profile(port: i64, power_profile: FfiStr) {
+    let power_profile: veilid_core::PowerProfile =
+        veilid_core::deserialize_opt_json(power_profile.into_opt_string()).unwrap();
+    DartIsolateWrapper::new(port).spawn_result(
+        async move {
+            let veilid_api = get_veilid_api().await?;
+            veilid_api.set_power_profile(power_profile)?;
+            APIRESULT_VOID
+        }
+        .in_current_span(),
+    );
+}
+
 #[no_mangle]
 #[instrument(level = "trace", target = "ffi", skip_all)]
 pub extern "C" fn shutdown_veilid_core(port: i64) {
@@ -617,6 +647,88 @@ pub extern "C" fn routing_context_with_sequencing(id: u32, sequencing: FfiStr) -
     add_routing_context(&mut rc, routing_context)
 }
 
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn routing_context_with_fragmentation(id: u32, fragmentation: u32) -> u32 {
+    let mut rc = ROUTING_CONTEXTS.lock();
+    let Some(routing_context) = rc.get(&id) else {
+        return 0;
+    };
+    let routing_context = routing_context
+        .clone()
+        .with_fragmentation(fragmentation != 0);
+
+    add_routing_context(&mut rc, routing_context)
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn routing_context_with_app_namespace(id: u32, app_namespace: u32) -> u32 {
+    let mut rc = ROUTING_CONTEXTS.lock();
+    let Some(routing_context) = rc.get(&id) else {
+        return 0;
+    };
+    let routing_context = routing_context.clone().with_app_namespace(app_namespace);
+
+    add_routing_context(&mut rc, routing_context)
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn new_cancel_handle() -> u32 {
+    let mut ch = CANCEL_HANDLES.lock();
+    let mut next_id: u32 = 1;
+    while ch.contains_key(&next_id) {
+        next_id += 1;
+    }
+    ch.insert(next_id, veilid_core::CancelHandle::new());
+    next_id
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn cancel_call(cancel_id: u32) -> i32 {
+    let Some(cancel_handle) = CANCEL_HANDLES.lock().remove(&cancel_id) else {
+        return 0;
+    };
+    cancel_handle.cancel();
+    1
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn release_cancel_handle(cancel_id: u32) -> i32 {
+    let mut ch = CANCEL_HANDLES.lock();
+    if ch.remove(&cancel_id).is_none() {
+        return 0;
+    }
+    1
+}
+
+/// Call options from ffi arguments, where a zero deadline or cancel id leaves that option unset
+fn get_call_options(
+    deadline: u64,
+    cancel_id: u32,
+    func_name: &str,
+) -> APIResult<veilid_core::CallOptions> {
+    let mut options = veilid_core::CallOptions::new();
+    if deadline != 0 {
+        options = options.with_deadline(veilid_core::Timestamp::new(deadline));
+    }
+    if cancel_id != 0 {
+        let ch = CANCEL_HANDLES.lock();
+        let Some(cancel_handle) = ch.get(&cancel_id) else {
+            return APIResult::Err(veilid_core::VeilidAPIError::invalid_argument(
+                func_name,
+                "cancel_id",
+                cancel_id,
+            ));
+        };
+        options = options.with_cancel_handle(cancel_handle.clone());
+    }
+    Ok(options)
+}
+
 fn get_routing_context(id: u32, func_name: &str) -> APIResult<veilid_core::RoutingContext> {
     let rc = ROUTING_CONTEXTS.lock();
     let Some(routing_context) = rc.get(&id) else {
@@ -638,18 +750,30 @@ pub extern "C" fn routing_context_safety(port: i64, id: u32) {
 
 #[no_mangle]
 #[instrument(level = "trace", target = "ffi", skip_all)]
-pub extern "C" fn routing_context_app_call(port: i64, id: u32, target: FfiStr, request: FfiStr) {
+pub extern "C" fn routing_context_app_call(
+    port: i64,
+    id: u32,
+    target: FfiStr,
+    request: FfiStr,
+    deadline: u64,
+    cancel_id: u32,
+) {
     let target_string: String = target.into_opt_string().unwrap();
     let request: Vec<u8> = data_encoding::BASE64URL_NOPAD
         .decode(request.into_opt_string().unwrap().as_bytes())
         .unwrap();
+    // Taken before spawning so a cancel issued right after the call is not missed
+    let options = get_call_options(deadline, cancel_id, "routing_context_app_call");
     DartIsolateWrapper::new(port).spawn_result(
         async move {
+            let options = options?;
             let routing_context = get_routing_context(id, "routing_context_app_call")?;
 
             let veilid_api = get_veilid_api().await?;
             let target = veilid_api.parse_as_target(target_string)?;
-            let answer = routing_context.app_call(target, request).await?;
+            let answer = routing_context
+                .app_call_with_options(target, request, options)
+                .await?;
             let answer = data_encoding::BASE64URL_NOPAD.encode(&answer);
             APIResult::Ok(answer)
         }
@@ -769,15 +893,19 @@ pub extern "C" fn routing_context_get_dht_value(
     key: FfiStr,
     subkey: u32,
     force_refresh: bool,
+    deadline: u64,
+    cancel_id: u32,
 ) {
     let key: veilid_core::TypedRecordKey =
         veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
+    let options = get_call_options(deadline, cancel_id, "routing_context_get_dht_value");
     DartIsolateWrapper::new(port).spawn_result_json(
         async move {
+            let options = options?;
             let routing_context = get_routing_context(id, "routing_context_get_dht_value")?;
 
             let res = routing_context
-                .get_dht_value(key, subkey, force_refresh)
+                .get_dht_value_with_options(key, subkey, force_refresh, options)
                 .await?;
             APIResult::Ok(res)
         }
@@ -794,6 +922,8 @@ pub extern "C" fn routing_context_set_dht_value(
     subkey: u32,
     data: FfiStr,
     writer: FfiStr,
+    deadline: u64,
+    cancel_id: u32,
 ) {
     let key: veilid_core::TypedRecordKey =
         veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
@@ -803,13 +933,15 @@ pub extern "C" fn routing_context_set_dht_value(
     let writer: Option<veilid_core::KeyPair> = writer
         .into_opt_string()
         .map(|s| veilid_core::deserialize_json(&s).unwrap());
+    let options = get_call_options(deadline, cancel_id, "routing_context_set_dht_value");
 
     DartIsolateWrapper::new(port).spawn_result_json(
         async move {
+            let options = options?;
             let routing_context = get_routing_context(id, "routing_context_set_dht_value")?;
 
             let res = routing_context
-                .set_dht_value(key, subkey, data, writer)
+                .set_dht_value_with_options(key, subkey, data, writer, options)
                 .await?;
             APIResult::Ok(res)
         }
@@ -1960,6 +2092,154 @@ pub extern "C" fn crypto_encrypt_aead(
     );
 }
 
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn crypto_seal(port: i64, kind: u32, key: FfiStr, body: FfiStr) {
+    let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+    let key: veilid_core::PublicKey =
+        veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
+
+    let body: Vec<u8> = data_encoding::BASE64URL_NOPAD
+        .decode(body.into_opt_string().unwrap().as_bytes())
+        .unwrap();
+
+    DartIsolateWrapper::new(port).spawn_result(
+        async move {
+            let veilid_api = get_veilid_api().await?;
+            let crypto = veilid_api.crypto()?;
+            let csv = crypto.get(kind).ok_or_else(|| {
+                veilid_core::VeilidAPIError::invalid_argument(
+                    "crypto_seal",
+                    "kind",
+                    kind.to_string(),
+                )
+            })?;
+            let out = csv.seal(&key, &body)?;
+            let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+            APIResult::Ok(out)
+        }
+        .in_current_span(),
+    );
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn crypto_open(port: i64, kind: u32, key: FfiStr, secret: FfiStr, sealed: FfiStr) {
+    let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+    let key: veilid_core::PublicKey =
+        veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
+    let secret: veilid_core::SecretKey =
+        veilid_core::deserialize_opt_json(secret.into_opt_string()).unwrap();
+
+    let sealed: Vec<u8> = data_encoding::BASE64URL_NOPAD
+        .decode(sealed.into_opt_string().unwrap().as_bytes())
+        .unwrap();
+
+    DartIsolateWrapper::new(port).spawn_result(
+        async move {
+            let veilid_api = get_veilid_api().await?;
+            let crypto = veilid_api.crypto()?;
+            let csv = crypto.get(kind).ok_or_else(|| {
+                veilid_core::VeilidAPIError::invalid_argument(
+                    "crypto_open",
+                    "kind",
+                    kind.to_string(),
+                )
+            })?;
+            let out = csv.open(&key, &secret, &sealed)?;
+            let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+            APIResult::Ok(out)
+        }
+        .in_current_span(),
+    );
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn crypto_signcrypt(
+    port: i64,
+    kind: u32,
+    key: FfiStr,
+    sender_key: FfiStr,
+    sender_secret: FfiStr,
+    body: FfiStr,
+) {
+    let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+    let key: veilid_core::PublicKey =
+        veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
+    let sender_key: veilid_core::PublicKey =
+        veilid_core::deserialize_opt_json(sender_key.into_opt_string()).unwrap();
+    let sender_secret: veilid_core::SecretKey =
+        veilid_core::deserialize_opt_json(sender_secret.into_opt_string()).unwrap();
+
+    let body: Vec<u8> = data_encoding::BASE64URL_NOPAD
+        .decode(body.into_opt_string().unwrap().as_bytes())
+        .unwrap();
+
+    DartIsolateWrapper::new(port).spawn_result(
+        async move {
+            let veilid_api = get_veilid_api().await?;
+            let crypto = veilid_api.crypto()?;
+            let csv = crypto.get(kind).ok_or_else(|| {
+                veilid_core::VeilidAPIError::invalid_argument(
+                    "crypto_signcrypt",
+                    "kind",
+                    kind.to_string(),
+                )
+            })?;
+            let out = csv.signcrypt(&key, &sender_key, &sender_secret, &body)?;
+            let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+            APIResult::Ok(out)
+        }
+        .in_current_span(),
+    );
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn crypto_unsigncrypt(
+    port: i64,
+    kind: u32,
+    key: FfiStr,
+    secret: FfiStr,
+    sender_key: FfiStr,
+    signcrypted: FfiStr,
+) {
+    let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+    let key: veilid_core::PublicKey =
+        veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
+    let secret: veilid_core::SecretKey =
+        veilid_core::deserialize_opt_json(secret.into_opt_string()).unwrap();
+    let sender_key: veilid_core::PublicKey =
+        veilid_core::deserialize_opt_json(sender_key.into_opt_string()).unwrap();
+
+    let signcrypted: Vec<u8> = data_encoding::BASE64URL_NOPAD
+        .decode(signcrypted.into_opt_string().unwrap().as_bytes())
+        .unwrap();
+
+    DartIsolateWrapper::new(port).spawn_result(
+        async move {
+            let veilid_api = get_veilid_api().await?;
+            let crypto = veilid_api.crypto()?;
+            let csv = crypto.get(kind).ok_or_else(|| {
+                veilid_core::VeilidAPIError::invalid_argument(
+                    "crypto_unsigncrypt",
+                    "kind",
+                    kind.to_string(),
+                )
+            })?;
+            let out = csv.unsigncrypt(&key, &secret, &sender_key, &signcrypted)?;
+            let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+            APIResult::Ok(out)
+        }
+        .in_current_span(),
+    );
+}
+
 #[no_mangle]
 #[instrument(level = "trace", target = "ffi", skip_all)]
 pub extern "C" fn crypto_crypt_no_auth(
@@ -26,6 +26,30 @@ async def test_get_node_id(api_connection: veilid.VeilidAPI):
         assert node_id[4] == ":"
 
 
+@pytest.mark.asyncio
+async def test_power_profile(api_connection: veilid.VeilidAPI):
+    try:
+        await api_connection.set_power_profile(veilid.PowerProfile.LOW_POWER)
+        assert await api_connection.power_profile() == veilid.PowerProfile.LOW_POWER
+    finally:
+        await api_connection.set_power_profile(veilid.PowerProfile.NORMAL)
+
+    assert await api_connection.power_profile() == veilid.PowerProfile.NORMAL
+
+
+@pytest.mark.asyncio
+async def test_diagnose(api_connection: veilid.VeilidAPI):
+    report = await api_connection.diagnose()
+    assert isinstance(report.attachment, veilid.AttachmentState)
+    for finding in report.findings:
+        assert isinstance(finding.severity, veilid.DiagnosticSeverity)
+        assert isinstance(finding.kind, veilid.DiagnosticKind)
+        assert finding.description != ""
+        assert finding.suggestion != ""
+    severities = [list(veilid.DiagnosticSeverity).index(f.severity) for f in report.findings]
+    assert severities == sorted(severities, reverse=True)
+
+
 @pytest.mark.asyncio
 async def test_fail_connect():
     with pytest.raises(socket.gaierror) as exc:
@@ -63,6 +63,34 @@ async def test_sign_and_verify_signature(api_connection: veilid.VeilidAPI):
         assert not await cs.verify(kp2.key(), b"abc1234", sig2)
 
 
+@pytest.mark.asyncio
+async def test_seal_and_open(api_connection: veilid.VeilidAPI):
+    cs = await api_connection.best_crypto_system()
+    async with cs:
+        kp1 = await cs.generate_key_pair()
+        kp2 = await cs.generate_key_pair()
+
+        # Only the recipient can open
+        sealed = await cs.seal(kp1.key(), b"abc123")
+        assert await cs.open(kp1.key(), kp1.secret(), sealed) == b"abc123"
+        with pytest.raises(veilid.VeilidAPIError):
+            await cs.open(kp2.key(), kp2.secret(), sealed)
+
+
+@pytest.mark.asyncio
+async def test_signcrypt_and_unsigncrypt(api_connection: veilid.VeilidAPI):
+    cs = await api_connection.best_crypto_system()
+    async with cs:
+        kp1 = await cs.generate_key_pair()
+        kp2 = await cs.generate_key_pair()
+
+        # Sender is authenticated
+        signcrypted = await cs.signcrypt(kp1.key(), kp2.key(), kp2.secret(), b"abc123")
+        assert await cs.unsigncrypt(kp1.key(), kp1.secret(), kp2.key(), signcrypted) == b"abc123"
+        with pytest.raises(veilid.VeilidAPIError):
+            await cs.unsigncrypt(kp1.key(), kp1.secret(), kp1.key(), signcrypted)
+
+
 @pytest.mark.asyncio
 async def test_sign_and_verify_signatures(api_connection: veilid.VeilidAPI):
     cs = await api_connection.best_crypto_system()
@@ -100,3 +128,31 @@ async def test_generate_shared_secret(api_connection: veilid.VeilidAPI):
 
         assert ssA != ssD
 
+
+
+@pytest.mark.asyncio
+async def test_derive_key_pair(api_connection: veilid.VeilidAPI):
+    kind = veilid.CryptoKind.CRYPTO_KIND_VLD0
+    phrase = await api_connection.generate_seed_phrase()
+    assert len(phrase.split()) == 24
+
+    # Same phrase and path derive the same keypair
+    path = veilid.DerivationPath.record_writer(0, 1)
+    kp1 = await api_connection.derive_key_pair(kind, phrase, "", path)
+    kp2 = await api_connection.derive_key_pair(kind, phrase.upper(), "", path)
+    assert kp1 == kp2
+
+    # Different paths and passphrases derive different keypairs
+    kp3 = await api_connection.derive_key_pair(kind, phrase, "", veilid.DerivationPath.record_writer(0, 2))
+    kp4 = await api_connection.derive_key_pair(kind, phrase, "extra", path)
+    assert kp1 != kp3
+    assert kp1 != kp4
+
+    # Invalid phrases are rejected
+    with pytest.raises(veilid.VeilidAPIErrorInvalidArgument):
+        await api_connection.derive_key_pair(kind, "not a seed phrase", "", path)
+
+    cs = await api_connection.get_crypto_system(kind)
+    async with cs:
+        kp5 = await cs.derive_key_pair(b"\x01" * 64, path)
+        assert await cs.validate_key_pair(kp5.key(), kp5.secret())
@@ -127,6 +127,38 @@ async def test_set_get_dht_value_with_owner(api_connection: veilid.VeilidAPI):
         await rc.delete_dht_record(rec.key)
 
 
+@pytest.mark.asyncio
+async def test_set_get_dht_value_cosigned(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+
+        cs = await api_connection.best_crypto_system()
+        async with cs:
+            owner1 = await cs.generate_key_pair()
+            owner2 = await cs.generate_key_pair()
+
+        schema = veilid.DHTSchema.mult(1, 2, [owner1.key, owner2.key], [])
+        rec = await rc.create_dht_record(schema)
+
+        # A single owner signature does not meet the threshold
+        with pytest.raises(veilid.VeilidAPIError):
+            await rc.set_dht_value_cosigned(rec.key, ValueSubkey(0), b"BLAH", owner1, [])
+
+        cosig = await rc.cosign_dht_value(rec.key, ValueSubkey(0), ValueSeqNum(0), b"BLAH", owner2)
+        assert cosig.signer == owner2.key
+
+        vd = await rc.set_dht_value_cosigned(rec.key, ValueSubkey(0), b"BLAH", owner1, [cosig])
+        assert vd is None
+
+        vd2 = await rc.get_dht_value(rec.key, ValueSubkey(0), False)
+        assert vd2 is not None
+        assert vd2.data == b"BLAH"
+        assert vd2.writer == owner1.key
+
+        await rc.close_dht_record(rec.key)
+        await rc.delete_dht_record(rec.key)
+
+
 @pytest.mark.asyncio
 async def test_open_writer_dht_value(api_connection: veilid.VeilidAPI):
     rc = await api_connection.new_routing_context()
@@ -569,6 +601,108 @@ async def _run_test_schema_limit(api_connection: veilid.VeilidAPI, open_record:
             print(f'  {n}')
 
 
+@pytest.mark.asyncio
+async def test_delete_mailbox_nonexistent(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        with pytest.raises(veilid.VeilidAPIError):
+            await rc.delete_mailbox(BOGUS_KEY)
+
+
+@pytest.mark.asyncio
+async def test_delete_peer_mailbox_nonexistent(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        with pytest.raises(veilid.VeilidAPIError):
+            await rc.delete_peer_mailbox(BOGUS_KEY)
+
+
+@pytest.mark.asyncio
+async def test_create_deposit_delete_mailbox(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        addresses = await rc.create_mailbox(
+            2, 2, kind=veilid.CryptoKind.CRYPTO_KIND_VLD0
+        )
+        assert len(addresses) == 2
+        assert addresses[0].record_key == addresses[1].record_key
+        assert addresses[0].depositor != addresses[1].depositor
+        address = addresses[0]
+        assert address.record_key.kind() == veilid.CryptoKind.CRYPTO_KIND_VLD0
+
+        await rc.deposit_mailbox_message(address, b"hello mailbox")
+        await rc.deposit_mailbox_message(
+            address, b"short lived", Timestamp(int((time.time() + 60) * 1_000_000))
+        )
+
+        # Acknowledgement subkey plus one slot per deposited message
+        rec = await rc.open_dht_record(address.record_key)
+        report = await rc.inspect_dht_record(rec.key, [], veilid.DHTReportScope.LOCAL)
+        assert sum(1 for s in report.local_seqs if s is not None) == 3
+        await rc.close_dht_record(rec.key)
+
+        # Each depositor writes to its own slots
+        await rc.deposit_mailbox_message(addresses[1], b"other depositor")
+
+        await rc.delete_mailbox(address.record_key)
+
+
+@pytest.mark.asyncio
+async def test_unsubscribe_topic_nonexistent(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        with pytest.raises(veilid.VeilidAPIError):
+            await rc.unsubscribe_topic(BOGUS_KEY)
+
+
+@pytest.mark.asyncio
+async def test_publish_subscribe_topic_catch_up():
+    topic_message_queue: asyncio.Queue = asyncio.Queue()
+
+    async def topic_message_queue_update_callback(update: veilid.VeilidUpdate):
+        if update.kind == veilid.VeilidUpdateKind.TOPIC_MESSAGE:
+            await topic_message_queue.put(update)
+
+    try:
+        api = await veilid.api_connector(topic_message_queue_update_callback)
+    except veilid.VeilidConnectionError:
+        pytest.skip("Unable to connect to veilid-server.")
+
+    async with api:
+        cs = await api.best_crypto_system()
+        async with cs:
+            publisher = await cs.generate_key_pair()
+            outsider = await cs.generate_key_pair()
+
+        rc = await api.new_routing_context()
+        async with rc:
+            topic = f"test-topic-{time.time()}"
+            key = await rc.create_topic(topic, [publisher.key()])
+            await rc.publish_topic(key, publisher, b"first")
+            await rc.publish_topic(key, publisher, b"second")
+
+            # only the publishers the topic was created with can publish
+            with pytest.raises(veilid.VeilidAPIError):
+                await rc.publish_topic(key, outsider, b"intruder")
+
+            # late joiners get what the topic already holds, in publication order
+            await rc.subscribe_topic(key, catch_up=True)
+            try:
+                messages = []
+                for _ in range(2):
+                    update: veilid.VeilidUpdate = await asyncio.wait_for(
+                        topic_message_queue.get(), timeout=10
+                    )
+                    assert isinstance(update.detail, veilid.VeilidTopicMessage)
+                    assert update.detail.key == key
+                    assert update.detail.topic == topic
+                    assert update.detail.publisher == publisher.key()
+                    messages.append(update.detail.message)
+                assert messages == [b"first", b"second"]
+            finally:
+                await rc.unsubscribe_topic(key)
+
+
 @pytest.mark.asyncio
 async def test_schema_limit_dflt(api_connection: veilid.VeilidAPI):
 
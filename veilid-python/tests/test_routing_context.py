@@ -148,6 +148,275 @@ async def test_routing_context_app_call_loopback():
                 # release local private route
                 await api.release_private_route(prl)
 
+
+@pytest.mark.asyncio
+async def test_routing_context_app_message_loopback_fragmented():
+    app_message_queue: asyncio.Queue = asyncio.Queue()
+
+    async def app_message_queue_update_callback(update: veilid.VeilidUpdate):
+        if update.kind == veilid.VeilidUpdateKind.APP_MESSAGE:
+            await app_message_queue.put(update)
+
+    try:
+        api = await veilid.api_connector(app_message_queue_update_callback)
+    except veilid.VeilidConnectionError:
+        pytest.skip("Unable to connect to veilid-server.")
+
+    async with api:
+        # purge routes to ensure we start fresh
+        await api.debug("purge routes")
+
+        # make a routing context that fragments large messages
+        rc = await (await api.new_routing_context()).with_fragmentation(True)
+        async with rc:
+            # make a new local private route
+            prl, blob = await api.new_private_route()
+            try:
+                # import it as a remote route as well so we can send to it
+                prr = await api.import_remote_private_route(blob)
+                try:
+                    # send a message too large for a single operation to our own private route
+                    message = random.randbytes(200000)
+                    await rc.app_message(prr, message)
+
+                    # we should get the whole message back in one piece
+                    update: veilid.VeilidUpdate = await asyncio.wait_for(
+                        app_message_queue.get(), timeout=30
+                    )
+
+                    assert isinstance(update.detail, veilid.VeilidAppMessage)
+                    assert update.detail.message == message
+                    assert update.detail.route_id is not None
+
+                finally:
+                    # release imported private route
+                    await api.release_private_route(prr)
+            finally:
+                # release local private route
+                await api.release_private_route(prl)
+
+
+@pytest.mark.asyncio
+async def test_routing_context_app_call_loopback_fragmented():
+    app_call_queue: asyncio.Queue = asyncio.Queue()
+
+    async def app_call_queue_update_callback(update: veilid.VeilidUpdate):
+        if update.kind == veilid.VeilidUpdateKind.APP_CALL:
+            await app_call_queue.put(update)
+
+    try:
+        api = await veilid.api_connector(app_call_queue_update_callback)
+    except veilid.VeilidConnectionError:
+        pytest.skip("Unable to connect to veilid-server.")
+
+    async with api:
+        # purge routes to ensure we start fresh
+        await api.debug("purge routes")
+
+        # make a routing context that fragments large requests
+        rc = await (await api.new_routing_context()).with_fragmentation(True)
+        async with rc:
+            # make a new local private route
+            prl, blob = await api.new_private_route()
+            try:
+                # import it as a remote route as well so we can send to it
+                prr = await api.import_remote_private_route(blob)
+                try:
+                    # send a request too large for a single operation to our own private route
+                    request = random.randbytes(100000)
+                    app_call_task = asyncio.create_task(rc.app_call(prr, request), name="app call task")
+
+                    # we should get the whole request back in one piece
+                    update: veilid.VeilidUpdate = await asyncio.wait_for(app_call_queue.get(), timeout=30)
+                    appcall = update.detail
+
+                    assert isinstance(appcall, veilid.VeilidAppCall)
+                    assert appcall.message == request
+                    assert appcall.route_id is not None
+
+                    # now we reply to the request
+                    reply = b"qwer5678"
+                    await api.app_call_reply(appcall.call_id, reply)
+
+                    # now we should get the reply from the call
+                    result = await app_call_task
+                    assert result == reply
+
+                finally:
+                    # release imported private route
+                    await api.release_private_route(prr)
+            finally:
+                # release local private route
+                await api.release_private_route(prl)
+
+
+@pytest.mark.asyncio
+async def test_routing_context_app_call_loopback_fragmented_reply():
+    app_call_queue: asyncio.Queue = asyncio.Queue()
+
+    async def app_call_queue_update_callback(update: veilid.VeilidUpdate):
+        if update.kind == veilid.VeilidUpdateKind.APP_CALL:
+            await app_call_queue.put(update)
+
+    try:
+        api = await veilid.api_connector(app_call_queue_update_callback)
+    except veilid.VeilidConnectionError:
+        pytest.skip("Unable to connect to veilid-server.")
+
+    async with api:
+        # purge routes to ensure we start fresh
+        await api.debug("purge routes")
+
+        # make a routing context that fragments large requests and answers
+        rc = await (await api.new_routing_context()).with_fragmentation(True)
+        async with rc:
+            # make a new local private route
+            prl, blob = await api.new_private_route()
+            try:
+                # import it as a remote route as well so we can send to it
+                prr = await api.import_remote_private_route(blob)
+                try:
+                    # send a small request to our own private route
+                    request = b"abcd1234"
+                    app_call_task = asyncio.create_task(rc.app_call(prr, request), name="app call task")
+
+                    update: veilid.VeilidUpdate = await asyncio.wait_for(app_call_queue.get(), timeout=30)
+                    appcall = update.detail
+
+                    assert isinstance(appcall, veilid.VeilidAppCall)
+                    assert appcall.message == request
+
+                    # reply with an answer too large for a single operation
+                    reply = random.randbytes(200000)
+                    await api.app_call_reply(appcall.call_id, reply)
+
+                    # we should get the whole reply back in one piece
+                    result = await asyncio.wait_for(app_call_task, timeout=60)
+                    assert result == reply
+
+                finally:
+                    # release imported private route
+                    await api.release_private_route(prr)
+            finally:
+                # release local private route
+                await api.release_private_route(prl)
+
+@pytest.mark.asyncio
+async def test_routing_context_app_call_loopback_namespace():
+    app_call_queue: asyncio.Queue = asyncio.Queue()
+
+    async def app_call_queue_update_callback(update: veilid.VeilidUpdate):
+        if update.kind == veilid.VeilidUpdateKind.APP_CALL:
+            await app_call_queue.put(update)
+
+    async def other_update_callback(update: veilid.VeilidUpdate):
+        pass
+
+    try:
+        api = await veilid.api_connector(app_call_queue_update_callback)
+        other_api = await veilid.api_connector(other_update_callback)
+    except veilid.VeilidConnectionError:
+        pytest.skip("Unable to connect to veilid-server.")
+
+    async with api, other_api:
+        # purge routes to ensure we start fresh
+        await api.debug("purge routes")
+
+        # only this client handles the namespace
+        await api.subscribe_app_namespace(5)
+
+        # make a routing context that addresses its calls to the namespace
+        rc = await (await api.new_routing_context()).with_app_namespace(5)
+        async with rc:
+            # make a new local private route
+            prl, blob = await api.new_private_route()
+            try:
+                # import it as a remote route as well so we can send to it
+                prr = await api.import_remote_private_route(blob)
+                try:
+                    # send an app call to our own private route
+                    request = b"abcd1234"
+                    app_call_task = asyncio.create_task(rc.app_call(prr, request), name="app call task")
+
+                    # the subscribed client should get the request with its namespace
+                    update: veilid.VeilidUpdate = await asyncio.wait_for(app_call_queue.get(), timeout=10)
+                    appcall = update.detail
+
+                    assert isinstance(appcall, veilid.VeilidAppCall)
+                    assert appcall.message == request
+                    assert appcall.namespace == 5
+
+                    # a client that did not get the request can not reply to it
+                    with pytest.raises(veilid.VeilidAPIError):
+                        await other_api.app_call_reply(appcall.call_id, b"wrong")
+
+                    # now we reply to the request
+                    reply = b"qwer5678"
+                    await api.app_call_reply(appcall.call_id, reply)
+
+                    # now we should get the reply from the call
+                    result = await app_call_task
+                    assert result == reply
+
+                finally:
+                    # release imported private route
+                    await api.release_private_route(prr)
+            finally:
+                # release local private route
+                await api.release_private_route(prl)
+                await api.unsubscribe_app_namespace(5)
+
+
+@pytest.mark.asyncio
+async def test_routing_context_app_call_loopback_cancel():
+    app_call_queue: asyncio.Queue = asyncio.Queue()
+
+    async def app_call_queue_update_callback(update: veilid.VeilidUpdate):
+        if update.kind == veilid.VeilidUpdateKind.APP_CALL:
+            await app_call_queue.put(update)
+
+    try:
+        api = await veilid.api_connector(app_call_queue_update_callback)
+    except veilid.VeilidConnectionError:
+        pytest.skip("Unable to connect to veilid-server.")
+
+    async with api:
+        # purge routes to ensure we start fresh
+        await api.debug("purge routes")
+
+        # make a routing context
+        rc = await api.new_routing_context()
+        async with rc:
+            # make a new local private route
+            prl, blob = await api.new_private_route()
+            try:
+                # import it as a remote route as well so we can send to it
+                prr = await api.import_remote_private_route(blob)
+                try:
+                    # send an app call to our own private route that can be cancelled
+                    cancel_handle = await api.new_cancel_handle()
+                    request = b"abcd1234"
+                    app_call_task = asyncio.create_task(
+                        rc.app_call(prr, request, cancel_handle=cancel_handle), name="app call task"
+                    )
+
+                    # wait for the request to arrive, but don't reply to it
+                    update: veilid.VeilidUpdate = await asyncio.wait_for(app_call_queue.get(), timeout=10)
+                    assert update.detail.message == request
+
+                    # cancelling the call should end it well before the rpc timeout
+                    await cancel_handle.cancel()
+                    with pytest.raises(veilid.VeilidAPIErrorCancelled):
+                        await asyncio.wait_for(app_call_task, timeout=5)
+
+                finally:
+                    # release imported private route
+                    await api.release_private_route(prr)
+            finally:
+                # release local private route
+                await api.release_private_route(prl)
+
+
 @pytest.mark.asyncio
 async def test_routing_context_app_message_loopback_big_packets():
     app_message_queue: asyncio.Queue = asyncio.Queue()
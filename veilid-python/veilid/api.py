@@ -2,7 +2,37 @@ from abc import ABC, abstractmethod
 from typing import Optional, Self
 
 from . import types
-from .state import VeilidState
+from .state import VeilidDiagnostics, VeilidState
+
+
+class CancelHandle(ABC):
+    ref_count: int
+
+    def __init__(
+        self,
+    ):
+        self.ref_count = 0
+
+    async def __aenter__(self) -> Self:
+        self.ref_count += 1
+        return self
+
+    async def __aexit__(self, *excinfo):
+        self.ref_count -= 1
+        if self.ref_count == 0 and not self.is_done():
+            await self.release()
+
+    @abstractmethod
+    def is_done(self) -> bool:
+        pass
+
+    @abstractmethod
+    async def release(self):
+        pass
+
+    @abstractmethod
+    async def cancel(self):
+        pass
 
 
 class RoutingContext(ABC):
@@ -44,12 +74,26 @@ class RoutingContext(ABC):
     async def with_sequencing(self, sequencing: types.Sequencing, release=True) -> Self:
         pass
 
+    @abstractmethod
+    async def with_fragmentation(self, fragmentation: bool, release=True) -> Self:
+        pass
+
+    @abstractmethod
+    async def with_app_namespace(self, app_namespace: int, release=True) -> Self:
+        pass
+
     @abstractmethod
     async def safety(self) -> types.SafetySelection:
         pass
 
     @abstractmethod
-    async def app_call(self, target: types.TypedKey | types.RouteId, request: bytes) -> bytes:
+    async def app_call(
+        self,
+        target: types.TypedKey | types.RouteId,
+        request: bytes,
+        deadline: Optional[types.Timestamp] = None,
+        cancel_handle: Optional[CancelHandle] = None,
+    ) -> bytes:
         pass
 
     @abstractmethod
@@ -78,16 +122,49 @@ class RoutingContext(ABC):
 
     @abstractmethod
     async def get_dht_value(
-        self, key: types.TypedKey, subkey: types.ValueSubkey, force_refresh: bool = False
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        force_refresh: bool = False,
+        deadline: Optional[types.Timestamp] = None,
+        cancel_handle: Optional[CancelHandle] = None,
     ) -> Optional[types.ValueData]:
         pass
 
     @abstractmethod
     async def set_dht_value(
-        self, key: types.TypedKey, subkey: types.ValueSubkey, data: bytes, writer: Optional[types.KeyPair] = None
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        data: bytes,
+        writer: Optional[types.KeyPair] = None,
+        deadline: Optional[types.Timestamp] = None,
+        cancel_handle: Optional[CancelHandle] = None,
+    ) -> Optional[types.ValueData]:
+        pass
+
+    @abstractmethod
+    async def set_dht_value_cosigned(
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        data: bytes,
+        writer: Optional[types.KeyPair],
+        cosignatures: list[types.DHTCosignature],
     ) -> Optional[types.ValueData]:
         pass
 
+    @abstractmethod
+    async def cosign_dht_value(
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        seq: types.ValueSeqNum,
+        data: bytes,
+        signer: types.KeyPair,
+    ) -> types.DHTCosignature:
+        pass
+
     @abstractmethod
     async def watch_dht_values(
         self,
@@ -115,6 +192,70 @@ class RoutingContext(ABC):
     ) -> types.DHTRecordReport:
         pass
 
+    @abstractmethod
+    async def create_mailbox(
+        self,
+        depositor_count: int,
+        slots_per_depositor: int,
+        kind: Optional[types.CryptoKind] = None,
+    ) -> list[types.MailboxAddress]:
+        pass
+
+    @abstractmethod
+    async def deposit_mailbox_message(
+        self,
+        address: types.MailboxAddress,
+        message: bytes,
+        expiration: Optional[types.Timestamp] = None,
+    ):
+        pass
+
+    @abstractmethod
+    async def delete_mailbox(self, key: types.TypedKey):
+        pass
+
+    @abstractmethod
+    async def create_peer_mailbox(
+        self, host: types.TypedKey
+    ) -> types.PeerMailboxAddress:
+        pass
+
+    @abstractmethod
+    async def deposit_peer_mailbox_message(
+        self,
+        address: types.PeerMailboxAddress,
+        message: bytes,
+        expiration: Optional[types.Timestamp] = None,
+    ):
+        pass
+
+    @abstractmethod
+    async def delete_peer_mailbox(self, mailbox: types.TypedKey):
+        pass
+
+    @abstractmethod
+    async def create_topic(
+        self,
+        topic: str,
+        publishers: list[types.PublicKey],
+        kind: Optional[types.CryptoKind] = None,
+    ) -> types.TypedKey:
+        pass
+
+    @abstractmethod
+    async def publish_topic(
+        self, key: types.TypedKey, publisher: types.KeyPair, message: bytes
+    ):
+        pass
+
+    @abstractmethod
+    async def subscribe_topic(self, key: types.TypedKey, catch_up: bool = False):
+        pass
+
+    @abstractmethod
+    async def unsubscribe_topic(self, key: types.TypedKey):
+        pass
+
 
 
 class TableDbTransaction(ABC):
@@ -282,6 +423,10 @@ class CryptoSystem(ABC):
     async def generate_key_pair(self) -> types.KeyPair:
         pass
 
+    @abstractmethod
+    async def derive_key_pair(self, seed: bytes, path: types.DerivationPath) -> types.KeyPair:
+        pass
+
     @abstractmethod
     async def generate_hash(self, data: bytes) -> types.HashDigest:
         pass
@@ -334,6 +479,34 @@ class CryptoSystem(ABC):
     ) -> bytes:
         pass
 
+    @abstractmethod
+    async def seal(self, key: types.PublicKey, body: bytes) -> bytes:
+        pass
+
+    @abstractmethod
+    async def open(self, key: types.PublicKey, secret: types.SecretKey, sealed: bytes) -> bytes:
+        pass
+
+    @abstractmethod
+    async def signcrypt(
+        self,
+        key: types.PublicKey,
+        sender_key: types.PublicKey,
+        sender_secret: types.SecretKey,
+        body: bytes,
+    ) -> bytes:
+        pass
+
+    @abstractmethod
+    async def unsigncrypt(
+        self,
+        key: types.PublicKey,
+        secret: types.SecretKey,
+        sender_key: types.PublicKey,
+        signcrypted: bytes,
+    ) -> bytes:
+        pass
+
     @abstractmethod
     async def crypt_no_auth(
         self, body: bytes, nonce: types.Nonce, shared_secret: types.SharedSecret
@@ -386,6 +559,26 @@ class VeilidAPI(ABC):
     async def detach(self):
         pass
 
+    @abstractmethod
+    async def suspend(self):
+        pass
+
+    @abstractmethod
+    async def resume(self):
+        pass
+
+    @abstractmethod
+    async def power_profile(self) -> types.PowerProfile:
+        pass
+
+    @abstractmethod
+    async def set_power_profile(self, power_profile: types.PowerProfile):
+        pass
+
+    @abstractmethod
+    async def diagnose(self) -> VeilidDiagnostics:
+        pass
+
     @abstractmethod
     async def new_private_route(self) -> tuple[types.RouteId, bytes]:
         pass
@@ -411,6 +604,18 @@ class VeilidAPI(ABC):
     async def app_call_reply(self, call_id: types.OperationId, message: bytes):
         pass
 
+    @abstractmethod
+    async def subscribe_app_namespace(self, namespace: int):
+        pass
+
+    @abstractmethod
+    async def unsubscribe_app_namespace(self, namespace: int):
+        pass
+
+    @abstractmethod
+    async def new_cancel_handle(self) -> CancelHandle:
+        pass
+
     @abstractmethod
     async def new_routing_context(self) -> RoutingContext:
         pass
@@ -450,6 +655,20 @@ class VeilidAPI(ABC):
     async def generate_key_pair(self, kind: types.CryptoKind) -> list[types.TypedKeyPair]:
         pass
 
+    @abstractmethod
+    async def generate_seed_phrase(self) -> str:
+        pass
+
+    @abstractmethod
+    async def derive_key_pair(
+        self,
+        kind: types.CryptoKind,
+        seed_phrase: str,
+        passphrase: str,
+        path: types.DerivationPath,
+    ) -> types.TypedKeyPair:
+        pass
+
     @abstractmethod
     async def now(self) -> types.Timestamp:
         pass
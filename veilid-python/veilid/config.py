@@ -14,6 +14,12 @@ class VeilidConfigLogLevel(StrEnum):
     TRACE = "Trace"
 
 
+class VeilidConfigProtectedStoreBackend(StrEnum):
+    KEYRING = "Keyring"
+    FILE_VAULT = "FileVault"
+    EXTERNAL_COMMAND = "ExternalCommand"
+
+
 @dataclass
 class ConfigBase:
     @classmethod
@@ -52,6 +58,9 @@ class VeilidConfigProtectedStore(ConfigBase):
     delete: bool
     device_encryption_key_password: str
     new_device_encryption_key_password: Optional[str]
+    backend: VeilidConfigProtectedStoreBackend
+    vault_password: str
+    external_command: list[str]
 
 
 @dataclass
@@ -88,6 +97,14 @@ class VeilidConfigRPC(ConfigBase):
     timeout_ms: int
     max_route_hop_count: int
     default_route_hop_count: int
+    queue_share_control: int
+    queue_share_routing: int
+    queue_share_dht: int
+    queue_share_app: int
+    queue_weight_control: int
+    queue_weight_routing: int
+    queue_weight_dht: int
+    queue_weight_app: int
 
 
 @dataclass
@@ -141,6 +141,21 @@ class VeilidAPIErrorGeneric(VeilidAPIError):
     message: str
 
 
+@dataclass
+class VeilidAPIErrorTampered(VeilidAPIError):
+    """Stored data failed its integrity check"""
+
+    label = "Tampered"
+    message: str
+
+
+@dataclass
+class VeilidAPIErrorCancelled(VeilidAPIError):
+    """Operation was cancelled before it completed"""
+
+    label = "Cancelled"
+
+
 # Build a mapping of canonicalized labels to their exception classes. Do this in-place to update
 # the object inside the closure so VeilidAPIError.from_json can access the values.
 _ERROR_REGISTRY.clear()
@@ -9,7 +9,7 @@ from typing import Awaitable, Callable, Optional, Self
 from jsonschema import exceptions, validators
 
 from . import schema
-from .api import CryptoSystem, RoutingContext, TableDb, TableDbTransaction, VeilidAPI
+from .api import CancelHandle, CryptoSystem, RoutingContext, TableDb, TableDbTransaction, VeilidAPI
 from .error import raise_api_result
 from .operations import (
     CryptoSystemOperation,
@@ -18,19 +18,24 @@ from .operations import (
     TableDbOperation,
     TableDbTransactionOperation,
 )
-from .state import VeilidState, VeilidUpdate
+from .state import VeilidDiagnostics, VeilidState, VeilidUpdate
 from .types import (
     HashDistance,
     CryptoKind,
+    DHTCosignature,
     DHTRecordDescriptor,
     DHTRecordReport,
     DHTReportScope,
     DHTSchema,
+    DerivationPath,
     HashDigest,
     KeyPair,
+    MailboxAddress,
+    PeerMailboxAddress,
     NewPrivateRouteResult,
     Nonce,
     OperationId,
+    PowerProfile,
     PublicKey,
     RouteId,
     SafetySelection,
@@ -44,6 +49,7 @@ from .types import (
     TypedKeyPair,
     TypedSignature,
     ValueData,
+    ValueSeqNum,
     ValueSubkey,
     VeilidJSONEncoder,
     VeilidVersion,
@@ -332,6 +338,31 @@ class _JsonVeilidAPI(VeilidAPI):
     async def detach(self):
         raise_api_result(await self.send_ndjson_request(Operation.DETACH))
 
+    async def suspend(self):
+        raise_api_result(await self.send_ndjson_request(Operation.SUSPEND))
+
+    async def resume(self):
+        raise_api_result(await self.send_ndjson_request(Operation.RESUME))
+
+    async def power_profile(self) -> PowerProfile:
+        return PowerProfile(
+            raise_api_result(await self.send_ndjson_request(Operation.GET_POWER_PROFILE))
+        )
+
+    async def set_power_profile(self, power_profile: PowerProfile):
+        assert isinstance(power_profile, PowerProfile)
+
+        raise_api_result(
+            await self.send_ndjson_request(
+                Operation.SET_POWER_PROFILE, power_profile=power_profile
+            )
+        )
+
+    async def diagnose(self) -> VeilidDiagnostics:
+        return VeilidDiagnostics.from_json(
+            raise_api_result(await self.send_ndjson_request(Operation.DIAGNOSE))
+        )
+
     async def new_private_route(self) -> tuple[RouteId, bytes]:
         return NewPrivateRouteResult.from_json(
             raise_api_result(await self.send_ndjson_request(Operation.NEW_PRIVATE_ROUTE))
@@ -383,6 +414,28 @@ class _JsonVeilidAPI(VeilidAPI):
             )
         )
 
+    async def subscribe_app_namespace(self, namespace: int):
+        assert isinstance(namespace, int)
+
+        raise_api_result(
+            await self.send_ndjson_request(
+                Operation.SUBSCRIBE_APP_NAMESPACE, namespace=namespace
+            )
+        )
+
+    async def unsubscribe_app_namespace(self, namespace: int):
+        assert isinstance(namespace, int)
+
+        raise_api_result(
+            await self.send_ndjson_request(
+                Operation.UNSUBSCRIBE_APP_NAMESPACE, namespace=namespace
+            )
+        )
+
+    async def new_cancel_handle(self) -> CancelHandle:
+        cancel_id = raise_api_result(await self.send_ndjson_request(Operation.NEW_CANCEL_HANDLE))
+        return _JsonCancelHandle(self, cancel_id)
+
     async def new_routing_context(self) -> RoutingContext:
         rc_id = raise_api_result(await self.send_ndjson_request(Operation.NEW_ROUTING_CONTEXT))
         return _JsonRoutingContext(self, rc_id)
@@ -475,6 +528,33 @@ class _JsonVeilidAPI(VeilidAPI):
             )
         )
 
+    async def generate_seed_phrase(self) -> str:
+        return raise_api_result(await self.send_ndjson_request(Operation.GENERATE_SEED_PHRASE))
+
+    async def derive_key_pair(
+        self,
+        kind: CryptoKind,
+        seed_phrase: str,
+        passphrase: str,
+        path: DerivationPath,
+    ) -> TypedKeyPair:
+        assert isinstance(kind, CryptoKind)
+        assert isinstance(seed_phrase, str)
+        assert isinstance(passphrase, str)
+        assert isinstance(path, DerivationPath)
+
+        return TypedKeyPair(
+            raise_api_result(
+                await self.send_ndjson_request(
+                    Operation.DERIVE_KEY_PAIR,
+                    kind=kind,
+                    seed_phrase=seed_phrase,
+                    passphrase=passphrase,
+                    path=path,
+                )
+            )
+        )
+
     async def now(self) -> Timestamp:
         return Timestamp(raise_api_result(await self.send_ndjson_request(Operation.NOW)))
 
@@ -504,6 +584,51 @@ def validate_rc_op(request: dict, response: dict):
         raise ValueError(f"Response rc_op does not match request rc_op: {response["rc_op"]} != {request["rc_op"]}")
 
 
+class _JsonCancelHandle(CancelHandle):
+    api: _JsonVeilidAPI
+    cancel_id: int
+    done: bool
+
+    def __init__(self, api: _JsonVeilidAPI, cancel_id: int):
+        super().__init__()
+
+        self.api = api
+        self.cancel_id = cancel_id
+        self.done = False
+
+    def __del__(self):
+        if not self.done:
+            # attempt to clean up server-side anyway
+            self.api.send_one_way_ndjson_request(
+                Operation.RELEASE_CANCEL_HANDLE, cancel_id=self.cancel_id
+            )
+
+            # complain
+            raise AssertionError("Should have released cancel handle before dropping object")
+
+    def is_done(self) -> bool:
+        return self.done
+
+    async def release(self):
+        if self.done:
+            return
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.RELEASE_CANCEL_HANDLE, cancel_id=self.cancel_id
+            )
+        )
+        self.done = True
+
+    async def cancel(self):
+        # Cancelling also releases the handle on the server
+        if self.done:
+            return
+        raise_api_result(
+            await self.api.send_ndjson_request(Operation.CANCEL_CALL, cancel_id=self.cancel_id)
+        )
+        self.done = True
+
+
 class _JsonRoutingContext(RoutingContext):
     api: _JsonVeilidAPI
     rc_id: int
@@ -589,6 +714,40 @@ class _JsonRoutingContext(RoutingContext):
             await self.release()
         return self.__class__(self.api, new_rc_id)
 
+    async def with_fragmentation(self, fragmentation: bool, release=True) -> Self:
+        assert isinstance(fragmentation, bool)
+        assert isinstance(release, bool)
+
+        new_rc_id = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.WITH_FRAGMENTATION,
+                fragmentation=fragmentation,
+            )
+        )
+        if release:
+            await self.release()
+        return self.__class__(self.api, new_rc_id)
+
+    async def with_app_namespace(self, app_namespace: int, release=True) -> Self:
+        assert isinstance(app_namespace, int)
+        assert isinstance(release, bool)
+
+        new_rc_id = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.WITH_APP_NAMESPACE,
+                app_namespace=app_namespace,
+            )
+        )
+        if release:
+            await self.release()
+        return self.__class__(self.api, new_rc_id)
+
     async def safety(
         self
     ) -> SafetySelection:
@@ -602,9 +761,17 @@ class _JsonRoutingContext(RoutingContext):
                 )
             )
         )
-    async def app_call(self, target: TypedKey | RouteId, message: bytes) -> bytes:
+    async def app_call(
+        self,
+        target: TypedKey | RouteId,
+        message: bytes,
+        deadline: Optional[Timestamp] = None,
+        cancel_handle: Optional[CancelHandle] = None,
+    ) -> bytes:
         assert isinstance(target, TypedKey) or isinstance(target, RouteId)
         assert isinstance(message, bytes)
+        assert deadline is None or isinstance(deadline, Timestamp)
+        assert cancel_handle is None or isinstance(cancel_handle, _JsonCancelHandle)
 
         return urlsafe_b64decode_no_pad(
             raise_api_result(
@@ -615,6 +782,8 @@ class _JsonRoutingContext(RoutingContext):
                     rc_op=RoutingContextOperation.APP_CALL,
                     target=target,
                     message=message,
+                    deadline=None if deadline is None else str(deadline),
+                    cancel_id=None if cancel_handle is None else cancel_handle.cancel_id,
                 )
             )
         )
@@ -701,11 +870,18 @@ class _JsonRoutingContext(RoutingContext):
         )
 
     async def get_dht_value(
-        self, key: TypedKey, subkey: ValueSubkey, force_refresh: bool = False
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool = False,
+        deadline: Optional[Timestamp] = None,
+        cancel_handle: Optional[CancelHandle] = None,
     ) -> Optional[ValueData]:
         assert isinstance(key, TypedKey)
         assert isinstance(subkey, ValueSubkey)
         assert isinstance(force_refresh, bool)
+        assert deadline is None or isinstance(deadline, Timestamp)
+        assert cancel_handle is None or isinstance(cancel_handle, _JsonCancelHandle)
 
         ret = raise_api_result(
             await self.api.send_ndjson_request(
@@ -716,17 +892,27 @@ class _JsonRoutingContext(RoutingContext):
                 key=key,
                 subkey=subkey,
                 force_refresh=force_refresh,
+                deadline=None if deadline is None else str(deadline),
+                cancel_id=None if cancel_handle is None else cancel_handle.cancel_id,
             )
         )
         return None if ret is None else ValueData.from_json(ret)
 
     async def set_dht_value(
-        self, key: TypedKey, subkey: ValueSubkey, data: bytes, writer: Optional[KeyPair] = None
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: bytes,
+        writer: Optional[KeyPair] = None,
+        deadline: Optional[Timestamp] = None,
+        cancel_handle: Optional[CancelHandle] = None,
     ) -> Optional[ValueData]:
         assert isinstance(key, TypedKey)
         assert isinstance(subkey, ValueSubkey)
         assert isinstance(data, bytes)
         assert writer is None or isinstance(writer, KeyPair)
+        assert deadline is None or isinstance(deadline, Timestamp)
+        assert cancel_handle is None or isinstance(cancel_handle, _JsonCancelHandle)
 
         ret = raise_api_result(
             await self.api.send_ndjson_request(
@@ -738,10 +924,73 @@ class _JsonRoutingContext(RoutingContext):
                 subkey=subkey,
                 data=data,
                 writer=writer,
+                deadline=None if deadline is None else str(deadline),
+                cancel_id=None if cancel_handle is None else cancel_handle.cancel_id,
+            )
+        )
+        return None if ret is None else ValueData.from_json(ret)
+
+    async def set_dht_value_cosigned(
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: bytes,
+        writer: Optional[KeyPair],
+        cosignatures: list[DHTCosignature],
+    ) -> Optional[ValueData]:
+        assert isinstance(key, TypedKey)
+        assert isinstance(subkey, ValueSubkey)
+        assert isinstance(data, bytes)
+        assert writer is None or isinstance(writer, KeyPair)
+        assert isinstance(cosignatures, list)
+        for cosignature in cosignatures:
+            assert isinstance(cosignature, DHTCosignature)
+
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.SET_DHT_VALUE_COSIGNED,
+                key=key,
+                subkey=subkey,
+                data=data,
+                writer=writer,
+                cosignatures=cosignatures,
             )
         )
         return None if ret is None else ValueData.from_json(ret)
 
+    async def cosign_dht_value(
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        seq: ValueSeqNum,
+        data: bytes,
+        signer: KeyPair,
+    ) -> DHTCosignature:
+        assert isinstance(key, TypedKey)
+        assert isinstance(subkey, ValueSubkey)
+        assert isinstance(seq, ValueSeqNum)
+        assert isinstance(data, bytes)
+        assert isinstance(signer, KeyPair)
+
+        return DHTCosignature.from_json(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.COSIGN_DHT_VALUE,
+                    key=key,
+                    subkey=subkey,
+                    seq=seq,
+                    data=data,
+                    signer=signer,
+                )
+            )
+        )
+
     async def watch_dht_values(
         self,
         key: TypedKey,
@@ -820,6 +1069,187 @@ class _JsonRoutingContext(RoutingContext):
                 )
             )
         )
+
+    async def create_mailbox(
+        self,
+        depositor_count: int,
+        slots_per_depositor: int,
+        kind: Optional[CryptoKind] = None,
+    ) -> list[MailboxAddress]:
+        assert isinstance(depositor_count, int)
+        assert isinstance(slots_per_depositor, int)
+        assert kind is None or isinstance(kind, CryptoKind)
+
+        return [
+            MailboxAddress.from_json(a)
+            for a in raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.CREATE_MAILBOX,
+                    depositor_count=depositor_count,
+                    slots_per_depositor=slots_per_depositor,
+                    kind=kind,
+                )
+            )
+        ]
+
+    async def deposit_mailbox_message(
+        self,
+        address: MailboxAddress,
+        message: bytes,
+        expiration: Optional[Timestamp] = None,
+    ):
+        assert isinstance(address, MailboxAddress)
+        assert isinstance(message, bytes)
+        assert expiration is None or isinstance(expiration, Timestamp)
+
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.DEPOSIT_MAILBOX_MESSAGE,
+                address=address,
+                message=message,
+                expiration=None if expiration is None else str(expiration),
+            )
+        )
+
+    async def delete_mailbox(self, key: TypedKey):
+        assert isinstance(key, TypedKey)
+
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.DELETE_MAILBOX,
+                key=key,
+            )
+        )
+
+    async def create_peer_mailbox(self, host: TypedKey) -> PeerMailboxAddress:
+        assert isinstance(host, TypedKey)
+
+        return PeerMailboxAddress.from_json(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.CREATE_PEER_MAILBOX,
+                    host=host,
+                )
+            )
+        )
+
+    async def deposit_peer_mailbox_message(
+        self,
+        address: PeerMailboxAddress,
+        message: bytes,
+        expiration: Optional[Timestamp] = None,
+    ):
+        assert isinstance(address, PeerMailboxAddress)
+        assert isinstance(message, bytes)
+        assert expiration is None or isinstance(expiration, Timestamp)
+
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.DEPOSIT_PEER_MAILBOX_MESSAGE,
+                address=address,
+                message=message,
+                expiration=None if expiration is None else str(expiration),
+            )
+        )
+
+    async def delete_peer_mailbox(self, mailbox: TypedKey):
+        assert isinstance(mailbox, TypedKey)
+
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.DELETE_PEER_MAILBOX,
+                mailbox=mailbox,
+            )
+        )
+
+    async def create_topic(
+        self,
+        topic: str,
+        publishers: list[PublicKey],
+        kind: Optional[CryptoKind] = None,
+    ) -> TypedKey:
+        assert isinstance(topic, str)
+        assert isinstance(publishers, list)
+        for publisher in publishers:
+            assert isinstance(publisher, PublicKey)
+        assert kind is None or isinstance(kind, CryptoKind)
+
+        return TypedKey(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.CREATE_TOPIC,
+                    topic=topic,
+                    publishers=publishers,
+                    kind=kind,
+                )
+            )
+        )
+
+    async def publish_topic(self, key: TypedKey, publisher: KeyPair, message: bytes):
+        assert isinstance(key, TypedKey)
+        assert isinstance(publisher, KeyPair)
+        assert isinstance(message, bytes)
+
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.PUBLISH_TOPIC,
+                key=key,
+                publisher=publisher,
+                message=message,
+            )
+        )
+
+    async def subscribe_topic(self, key: TypedKey, catch_up: bool = False):
+        assert isinstance(key, TypedKey)
+        assert isinstance(catch_up, bool)
+
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.SUBSCRIBE_TOPIC,
+                key=key,
+                catch_up=catch_up,
+            )
+        )
+
+    async def unsubscribe_topic(self, key: TypedKey):
+        assert isinstance(key, TypedKey)
+
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.UNSUBSCRIBE_TOPIC,
+                key=key,
+            )
+        )
         
 
 
@@ -1265,6 +1695,23 @@ class _JsonCryptoSystem(CryptoSystem):
             )
         )
 
+    async def derive_key_pair(self, seed: bytes, path: DerivationPath) -> KeyPair:
+        assert isinstance(seed, bytes)
+        assert isinstance(path, DerivationPath)
+
+        return KeyPair(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.CRYPTO_SYSTEM,
+                    validate=validate_cs_op,
+                    cs_id=self.cs_id,
+                    cs_op=CryptoSystemOperation.DERIVE_KEY_PAIR,
+                    seed=seed,
+                    path=path,
+                )
+            )
+        )
+
     async def generate_hash(self, data: bytes) -> HashDigest:
         assert isinstance(data, bytes)
 
@@ -1427,6 +1874,96 @@ class _JsonCryptoSystem(CryptoSystem):
             )
         )
 
+    async def seal(self, key: PublicKey, body: bytes) -> bytes:
+        assert isinstance(key, PublicKey)
+        assert isinstance(body, bytes)
+
+        return urlsafe_b64decode_no_pad(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.CRYPTO_SYSTEM,
+                    validate=validate_cs_op,
+                    cs_id=self.cs_id,
+                    cs_op=CryptoSystemOperation.SEAL,
+                    key=key,
+                    body=body,
+                )
+            )
+        )
+
+    async def open(self, key: PublicKey, secret: SecretKey, sealed: bytes) -> bytes:
+        assert isinstance(key, PublicKey)
+        assert isinstance(secret, SecretKey)
+        assert isinstance(sealed, bytes)
+
+        return urlsafe_b64decode_no_pad(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.CRYPTO_SYSTEM,
+                    validate=validate_cs_op,
+                    cs_id=self.cs_id,
+                    cs_op=CryptoSystemOperation.OPEN,
+                    key=key,
+                    secret=secret,
+                    sealed=sealed,
+                )
+            )
+        )
+
+    async def signcrypt(
+        self,
+        key: PublicKey,
+        sender_key: PublicKey,
+        sender_secret: SecretKey,
+        body: bytes,
+    ) -> bytes:
+        assert isinstance(key, PublicKey)
+        assert isinstance(sender_key, PublicKey)
+        assert isinstance(sender_secret, SecretKey)
+        assert isinstance(body, bytes)
+
+        return urlsafe_b64decode_no_pad(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.CRYPTO_SYSTEM,
+                    validate=validate_cs_op,
+                    cs_id=self.cs_id,
+                    cs_op=CryptoSystemOperation.SIGNCRYPT,
+                    key=key,
+                    sender_key=sender_key,
+                    sender_secret=sender_secret,
+                    body=body,
+                )
+            )
+        )
+
+    async def unsigncrypt(
+        self,
+        key: PublicKey,
+        secret: SecretKey,
+        sender_key: PublicKey,
+        signcrypted: bytes,
+    ) -> bytes:
+        assert isinstance(key, PublicKey)
+        assert isinstance(secret, SecretKey)
+        assert isinstance(sender_key, PublicKey)
+        assert isinstance(signcrypted, bytes)
+
+        return urlsafe_b64decode_no_pad(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.CRYPTO_SYSTEM,
+                    validate=validate_cs_op,
+                    cs_id=self.cs_id,
+                    cs_op=CryptoSystemOperation.UNSIGNCRYPT,
+                    key=key,
+                    secret=secret,
+                    sender_key=sender_key,
+                    signcrypted=signcrypted,
+                )
+            )
+        )
+
     async def crypt_no_auth(self, body: bytes, nonce: Nonce, shared_secret: SharedSecret) -> bytes:
         assert isinstance(body, bytes)
         assert isinstance(nonce, Nonce)
@@ -8,11 +8,21 @@ class Operation(StrEnum):
     IS_SHUTDOWN = "IsShutdown"
     ATTACH = "Attach"
     DETACH = "Detach"
+    SUSPEND = "Suspend"
+    RESUME = "Resume"
+    GET_POWER_PROFILE = "GetPowerProfile"
+    SET_POWER_PROFILE = "SetPowerProfile"
+    DIAGNOSE = "Diagnose"
     NEW_PRIVATE_ROUTE = "NewPrivateRoute"
     NEW_CUSTOM_PRIVATE_ROUTE = "NewCustomPrivateRoute"
     IMPORT_REMOTE_PRIVATE_ROUTE = "ImportRemotePrivateRoute"
     RELEASE_PRIVATE_ROUTE = "ReleasePrivateRoute"
     APP_CALL_REPLY = "AppCallReply"
+    SUBSCRIBE_APP_NAMESPACE = "SubscribeAppNamespace"
+    UNSUBSCRIBE_APP_NAMESPACE = "UnsubscribeAppNamespace"
+    NEW_CANCEL_HANDLE = "NewCancelHandle"
+    CANCEL_CALL = "CancelCall"
+    RELEASE_CANCEL_HANDLE = "ReleaseCancelHandle"
     NEW_ROUTING_CONTEXT = "NewRoutingContext"
     ROUTING_CONTEXT = "RoutingContext"
     OPEN_TABLE_DB = "OpenTableDb"
@@ -25,6 +35,8 @@ class Operation(StrEnum):
     VERIFY_SIGNATURES = "VerifySignatures"
     GENERATE_SIGNATURES = "GenerateSignatures"
     GENERATE_KEY_PAIR = "GenerateKeyPair"
+    GENERATE_SEED_PHRASE = "GenerateSeedPhrase"
+    DERIVE_KEY_PAIR = "DeriveKeyPair"
     NOW = "Now"
     DEBUG = "Debug"
     VEILID_VERSION_STRING = "VeilidVersionString"
@@ -39,6 +51,8 @@ class RoutingContextOperation(StrEnum):
     WITH_DEFAULT_SAFETY = "WithDefaultSafety"
     WITH_SAFETY = "WithSafety"
     WITH_SEQUENCING = "WithSequencing"
+    WITH_FRAGMENTATION = "WithFragmentation"
+    WITH_APP_NAMESPACE = "WithAppNamespace"
     SAFETY = "Safety"
     APP_CALL = "AppCall"
     APP_MESSAGE = "AppMessage"
@@ -48,9 +62,21 @@ class RoutingContextOperation(StrEnum):
     DELETE_DHT_RECORD = "DeleteDhtRecord"
     GET_DHT_VALUE = "GetDhtValue"
     SET_DHT_VALUE = "SetDhtValue"
+    SET_DHT_VALUE_COSIGNED = "SetDhtValueCosigned"
+    COSIGN_DHT_VALUE = "CosignDhtValue"
     WATCH_DHT_VALUES = "WatchDhtValues"
     CANCEL_DHT_WATCH = "CancelDhtWatch"
     INSPECT_DHT_RECORD = "InspectDhtRecord"
+    CREATE_MAILBOX = "CreateMailbox"
+    DEPOSIT_MAILBOX_MESSAGE = "DepositMailboxMessage"
+    DELETE_MAILBOX = "DeleteMailbox"
+    CREATE_PEER_MAILBOX = "CreatePeerMailbox"
+    DEPOSIT_PEER_MAILBOX_MESSAGE = "DepositPeerMailboxMessage"
+    DELETE_PEER_MAILBOX = "DeletePeerMailbox"
+    CREATE_TOPIC = "CreateTopic"
+    PUBLISH_TOPIC = "PublishTopic"
+    SUBSCRIBE_TOPIC = "SubscribeTopic"
+    UNSUBSCRIBE_TOPIC = "UnsubscribeTopic"
 
 
 class TableDbOperation(StrEnum):
@@ -87,6 +113,7 @@ class CryptoSystemOperation(StrEnum):
     RANDOM_NONCE = "RandomNonce"
     RANDOM_SHARED_SECRET = "RandomSharedSecret"
     GENERATE_KEY_PAIR = "GenerateKeyPair"
+    DERIVE_KEY_PAIR = "DeriveKeyPair"
     GENERATE_HASH = "GenerateHash"
     VALIDATE_KEY_PAIR = "ValidateKeyPair"
     VALIDATE_HASH = "ValidateHash"
@@ -96,6 +123,10 @@ class CryptoSystemOperation(StrEnum):
     AEAD_OVERHEAD = "AeadOverhead"
     DECRYPT_AEAD = "DecryptAead"
     ENCRYPT_AEAD = "EncryptAead"
+    SEAL = "Seal"
+    OPEN = "Open"
+    SIGNCRYPT = "Signcrypt"
+    UNSIGNCRYPT = "Unsigncrypt"
     CRYPT_NO_AUTH = "CryptNoAuth"
 
 
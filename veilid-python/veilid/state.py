@@ -4,6 +4,7 @@ from typing import Optional, Self
 from .config import VeilidConfig
 from .types import (
     ByteCount,
+    PublicKey,
     RouteId,
     Timestamp,
     TimestampDuration,
@@ -25,6 +26,7 @@ class AttachmentState(StrEnum):
     FULLY_ATTACHED = "FullyAttached"
     OVER_ATTACHED = "OverAttached"
     DETACHING = "Detaching"
+    RESUMING = "Resuming"
 
 
 class VeilidStateAttachment:
@@ -474,6 +476,92 @@ class VeilidState:
         return self.__dict__
 
 
+class DiagnosticSeverity(StrEnum):
+    INFO = "Info"
+    WARNING = "Warning"
+    ERROR = "Error"
+
+
+class DiagnosticKind(StrEnum):
+    NOT_ATTACHED = "NotAttached"
+    ATTACHING = "Attaching"
+    NETWORK_CLASS_UNKNOWN = "NetworkClassUnknown"
+    OUTBOUND_ONLY = "OutboundOnly"
+    EXTERNAL_ADDRESS_UNKNOWN = "ExternalAddressUnknown"
+    SYMMETRIC_NAT = "SymmetricNAT"
+    RESTRICTED_NAT = "RestrictedNAT"
+    DIAL_INFO_FAILURES = "DialInfoFailures"
+    UPNP_GATEWAY_NOT_FOUND = "UPnPGatewayNotFound"
+    UPNP_MAPPING_FAILING = "UPnPMappingFailing"
+    NO_RELAY = "NoRelay"
+    RELAY_UNRELIABLE = "RelayUnreliable"
+    FEW_PEERS = "FewPeers"
+    CLOCK_SKEW = "ClockSkew"
+
+
+class VeilidDiagnosticFinding:
+    severity: DiagnosticSeverity
+    kind: DiagnosticKind
+    description: str
+    suggestion: str
+
+    def __init__(
+        self,
+        severity: DiagnosticSeverity,
+        kind: DiagnosticKind,
+        description: str,
+        suggestion: str,
+    ):
+        self.severity = severity
+        self.kind = kind
+        self.description = description
+        self.suggestion = suggestion
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            DiagnosticSeverity(j["severity"]),
+            DiagnosticKind(j["kind"]),
+            j["description"],
+            j["suggestion"],
+        )
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
+class VeilidDiagnostics:
+    timestamp: Timestamp
+    attachment: AttachmentState
+    findings: list[VeilidDiagnosticFinding]
+
+    def __init__(
+        self,
+        timestamp: Timestamp,
+        attachment: AttachmentState,
+        findings: list[VeilidDiagnosticFinding],
+    ):
+        self.timestamp = timestamp
+        self.attachment = attachment
+        self.findings = findings
+
+    def is_healthy(self) -> bool:
+        return all(f.severity == DiagnosticSeverity.INFO for f in self.findings)
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            Timestamp(j["timestamp"]),
+            AttachmentState(j["attachment"]),
+            [VeilidDiagnosticFinding.from_json(f) for f in j["findings"]],
+        )
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
 class VeilidLog:
     log_level: VeilidLogLevel
     message: str
@@ -496,11 +584,13 @@ class VeilidLog:
 class VeilidAppMessage:
     sender: Optional[TypedKey]
     route_id: Optional[RouteId]
+    namespace: int
     message: bytes
 
-    def __init__(self, sender: Optional[TypedKey], route_id: Optional[RouteId], message: bytes):
+    def __init__(self, sender: Optional[TypedKey], route_id: Optional[RouteId], namespace: int, message: bytes):
         self.sender = sender
         self.route_id = route_id
+        self.namespace = namespace
         self.message = message
 
     @classmethod
@@ -509,6 +599,7 @@ class VeilidAppMessage:
         return cls(
             None if j["sender"] is None else TypedKey(j["sender"]),
             None if j["route_id"] is None else RouteId(j["route_id"]),
+            j.get("namespace", 0),
             urlsafe_b64decode_no_pad(j["message"]),
         )
 
@@ -519,12 +610,14 @@ class VeilidAppMessage:
 class VeilidAppCall:
     sender: Optional[TypedKey]
     route_id: Optional[RouteId]
+    namespace: int
     message: bytes
     call_id: OperationId
 
-    def __init__(self, sender: Optional[TypedKey], route_id: Optional[TypedKey], message: bytes, call_id: OperationId):
+    def __init__(self, sender: Optional[TypedKey], route_id: Optional[TypedKey], namespace: int, message: bytes, call_id: OperationId):
         self.sender = sender
         self.route_id = route_id
+        self.namespace = namespace
         self.message = message
         self.call_id = call_id
 
@@ -534,6 +627,7 @@ class VeilidAppCall:
         return cls(
             None if j["sender"] is None else TypedKey(j["sender"]),
             None if j["route_id"] is None else RouteId(j["route_id"]),
+            j.get("namespace", 0),
             urlsafe_b64decode_no_pad(j["message"]),
             OperationId(j["call_id"]),
         )
@@ -588,6 +682,42 @@ class VeilidValueChange:
         return self.__dict__
 
 
+class VeilidTopicMessage:
+    key: TypedKey
+    topic: str
+    publisher: PublicKey
+    published: Timestamp
+    message: bytes
+
+    def __init__(
+        self,
+        key: TypedKey,
+        topic: str,
+        publisher: PublicKey,
+        published: Timestamp,
+        message: bytes,
+    ):
+        self.key = key
+        self.topic = topic
+        self.publisher = publisher
+        self.published = published
+        self.message = message
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            TypedKey(j["key"]),
+            j["topic"],
+            PublicKey(j["publisher"]),
+            Timestamp(j["published"]),
+            urlsafe_b64decode_no_pad(j["message"]),
+        )
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
 class VeilidUpdateKind(StrEnum):
     LOG = "Log"
     APP_MESSAGE = "AppMessage"
@@ -597,6 +727,7 @@ class VeilidUpdateKind(StrEnum):
     CONFIG = "Config"
     ROUTE_CHANGE = "RouteChange"
     VALUE_CHANGE = "ValueChange"
+    TOPIC_MESSAGE = "TopicMessage"
     SHUTDOWN = "Shutdown"
 
 
@@ -609,6 +740,7 @@ VeilidUpdateDetailType = Optional[
     | VeilidStateConfig
     | VeilidRouteChange
     | VeilidValueChange
+    | VeilidTopicMessage
 ]
 
 
@@ -646,6 +778,8 @@ class VeilidUpdate:
                 detail = VeilidRouteChange.from_json(j)
             case VeilidUpdateKind.VALUE_CHANGE:
                 detail = VeilidValueChange.from_json(j)
+            case VeilidUpdateKind.TOPIC_MESSAGE:
+                detail = VeilidTopicMessage.from_json(j)
             case VeilidUpdateKind.SHUTDOWN:
                 detail = None
             case _:
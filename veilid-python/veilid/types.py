@@ -69,6 +69,11 @@ class Stability(StrEnum):
     RELIABLE = "Reliable"
 
 
+class PowerProfile(StrEnum):
+    NORMAL = "Normal"
+    LOW_POWER = "LowPower"
+
+
 class Sequencing(StrEnum):
     NO_PREFERENCE = "NoPreference"
     PREFER_ORDERED = "PreferOrdered"
@@ -78,6 +83,7 @@ class Sequencing(StrEnum):
 class DHTSchemaKind(StrEnum):
     DFLT = "DFLT"
     SMPL = "SMPL"
+    MULT = "MULT"
 
 
 class SafetySelectionKind(StrEnum):
@@ -154,6 +160,26 @@ class Nonce(EncodedString):
     pass
 
 
+class DerivationPath(str):
+    """Path from the root of a seed phrase to a derived keypair, such as m/1'/0'"""
+
+    @classmethod
+    def from_indices(cls, indices: list[int]) -> Self:
+        return cls("m" + "".join(f"/{i}'" for i in indices))
+
+    @classmethod
+    def node_id(cls, index: int) -> Self:
+        return cls.from_indices([0, index])
+
+    @classmethod
+    def app_identity(cls, app: int) -> Self:
+        return cls.from_indices([1, app])
+
+    @classmethod
+    def record_writer(cls, app: int, record: int) -> Self:
+        return cls.from_indices([2, app, record])
+
+
 class KeyPair(str):
     @classmethod
     def from_parts(cls, key: PublicKey, secret: SecretKey) -> Self:
@@ -344,6 +370,30 @@ class DHTSchema:
             assert isinstance(m, DHTSchemaSMPLMember)
         return cls(DHTSchemaKind.SMPL, o_cnt=o_cnt, members=members)
 
+    @classmethod
+    def mult(
+        cls,
+        o_cnt: int,
+        threshold: int,
+        owners: list[PublicKey],
+        members: list[DHTSchemaSMPLMember],
+    ) -> Self:
+        assert isinstance(o_cnt, int)
+        assert isinstance(threshold, int)
+        assert isinstance(owners, list)
+        for o in owners:
+            assert isinstance(o, PublicKey)
+        assert isinstance(members, list)
+        for m in members:
+            assert isinstance(m, DHTSchemaSMPLMember)
+        return cls(
+            DHTSchemaKind.MULT,
+            o_cnt=o_cnt,
+            threshold=threshold,
+            owners=owners,
+            members=members,
+        )
+
     @classmethod
     def from_json(cls, j: dict) -> Self:
         if DHTSchemaKind(j["kind"]) == DHTSchemaKind.DFLT:
@@ -353,6 +403,13 @@ class DHTSchema:
                 j["o_cnt"],
                 [DHTSchemaSMPLMember.from_json(member) for member in j["members"]],
             )
+        if DHTSchemaKind(j["kind"]) == DHTSchemaKind.MULT:
+            return cls.mult(
+                j["o_cnt"],
+                j["threshold"],
+                [PublicKey(owner) for owner in j["owners"]],
+                [DHTSchemaSMPLMember.from_json(member) for member in j["members"]],
+            )
         raise Exception("Unknown DHTSchema kind", j["kind"])
 
     def to_json(self) -> dict:
@@ -397,6 +454,70 @@ class DHTRecordDescriptor:
 
 
 
+class MailboxAddress:
+    record_key: TypedKey
+    depositor: KeyPair
+
+    def __init__(self, record_key: TypedKey, depositor: KeyPair):
+        self.record_key = record_key
+        self.depositor = depositor
+
+    def __repr__(self) -> str:
+        return f"<{self.__class__.__name__}(record_key={self.record_key!r}, depositor={self.depositor!r})>"
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        return cls(TypedKey(j["record_key"]), KeyPair(j["depositor"]))
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
+class PeerMailboxAddress:
+    host: TypedKey
+    mailbox: TypedKey
+
+    def __init__(self, host: TypedKey, mailbox: TypedKey):
+        self.host = host
+        self.mailbox = mailbox
+
+    def __repr__(self) -> str:
+        return f"<{self.__class__.__name__}(host={self.host!r}, mailbox={self.mailbox!r})>"
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        return cls(TypedKey(j["host"]), TypedKey(j["mailbox"]))
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
+class DHTCosignature:
+    signer: PublicKey
+    signature: Signature
+
+    def __init__(self, signer: PublicKey, signature: Signature):
+        self.signer = signer
+        self.signature = signature
+
+    def __repr__(self) -> str:
+        return f"<{self.__class__.__name__}(signer={self.signer!r}, signature={self.signature!r})>"
+
+    def __eq__(self, other):
+        return (
+            isinstance(other, DHTCosignature)
+            and self.signer == other.signer
+            and self.signature == other.signature
+        )
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        return cls(PublicKey(j["signer"]), Signature(j["signature"]))
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
 class DHTRecordReport:
     subkeys: list[tuple[ValueSubkey, ValueSubkey]]
     offline_subkeys: list[tuple[ValueSubkey, ValueSubkey]]
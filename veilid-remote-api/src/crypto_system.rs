@@ -69,6 +69,13 @@ pub enum CryptoSystemRequestOp {
     RandomNonce,
     RandomSharedSecret,
     GenerateKeyPair,
+    DeriveKeyPair {
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        seed: Vec<u8>,
+        #[schemars(with = "String")]
+        path: DerivationPath,
+    },
     GenerateHash {
         #[serde(with = "as_human_base64")]
         #[schemars(with = "String")]
@@ -136,6 +143,44 @@ pub enum CryptoSystemRequestOp {
         #[schemars(with = "Option<String>")]
         associated_data: Option<Vec<u8>>,
     },
+    Seal {
+        #[schemars(with = "String")]
+        key: PublicKey,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        body: Vec<u8>,
+    },
+    Open {
+        #[schemars(with = "String")]
+        key: PublicKey,
+        #[schemars(with = "String")]
+        secret: SecretKey,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        sealed: Vec<u8>,
+    },
+    Signcrypt {
+        #[schemars(with = "String")]
+        key: PublicKey,
+        #[schemars(with = "String")]
+        sender_key: PublicKey,
+        #[schemars(with = "String")]
+        sender_secret: SecretKey,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        body: Vec<u8>,
+    },
+    Unsigncrypt {
+        #[schemars(with = "String")]
+        key: PublicKey,
+        #[schemars(with = "String")]
+        secret: SecretKey,
+        #[schemars(with = "String")]
+        sender_key: PublicKey,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        signcrypted: Vec<u8>,
+    },
     CryptNoAuth {
         #[serde(with = "as_human_base64")]
         #[schemars(with = "String")]
@@ -203,6 +248,11 @@ pub enum CryptoSystemResponseOp {
         #[schemars(with = "String")]
         value: KeyPair,
     },
+    DeriveKeyPair {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithString<KeyPair>,
+    },
     GenerateHash {
         #[schemars(with = "String")]
         value: HashDigest,
@@ -239,6 +289,26 @@ pub enum CryptoSystemResponseOp {
         #[schemars(with = "ApiResult<String>")]
         result: ApiResultWithVecU8,
     },
+    Seal {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithVecU8,
+    },
+    Open {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithVecU8,
+    },
+    Signcrypt {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithVecU8,
+    },
+    Unsigncrypt {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithVecU8,
+    },
     CryptNoAuth {
         #[serde(with = "as_human_base64")]
         #[schemars(with = "String")]
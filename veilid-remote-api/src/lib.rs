@@ -63,6 +63,13 @@ pub enum RequestOp {
     IsShutdown,
     Attach,
     Detach,
+    Suspend,
+    Resume,
+    GetPowerProfile,
+    SetPowerProfile {
+        power_profile: PowerProfile,
+    },
+    Diagnose,
     NewPrivateRoute,
     NewCustomPrivateRoute {
         #[schemars(with = "Vec<String>")]
@@ -88,6 +95,20 @@ pub enum RequestOp {
         #[schemars(with = "String")]
         message: Vec<u8>,
     },
+    SubscribeAppNamespace {
+        namespace: u32,
+    },
+    UnsubscribeAppNamespace {
+        namespace: u32,
+    },
+    // Cancellation
+    NewCancelHandle,
+    CancelCall {
+        cancel_id: u32,
+    },
+    ReleaseCancelHandle {
+        cancel_id: u32,
+    },
     // Routing Context
     NewRoutingContext,
     RoutingContext(RoutingContextRequest),
@@ -128,6 +149,15 @@ pub enum RequestOp {
         #[schemars(with = "String")]
         kind: CryptoKind,
     },
+    GenerateSeedPhrase,
+    DeriveKeyPair {
+        #[schemars(with = "String")]
+        kind: CryptoKind,
+        seed_phrase: String,
+        passphrase: String,
+        #[schemars(with = "String")]
+        path: DerivationPath,
+    },
     // Misc
     Now,
     Debug {
@@ -170,6 +200,26 @@ pub enum ResponseOp {
         #[serde(flatten)]
         result: ApiResult<()>,
     },
+    Suspend {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    Resume {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    GetPowerProfile {
+        #[serde(flatten)]
+        result: ApiResult<PowerProfile>,
+    },
+    SetPowerProfile {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    Diagnose {
+        #[serde(flatten)]
+        result: ApiResult<Box<VeilidDiagnostics>>,
+    },
     NewPrivateRoute {
         #[serde(flatten)]
         result: ApiResult<NewPrivateRouteResult>,
@@ -191,6 +241,26 @@ pub enum ResponseOp {
         #[serde(flatten)]
         result: ApiResult<()>,
     },
+    SubscribeAppNamespace {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    UnsubscribeAppNamespace {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    // Cancellation
+    NewCancelHandle {
+        value: u32,
+    },
+    CancelCall {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    ReleaseCancelHandle {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
     // Routing Context
     NewRoutingContext {
         #[serde(flatten)]
@@ -233,6 +303,14 @@ pub enum ResponseOp {
         #[schemars(with = "ApiResult<String>")]
         result: ApiResultWithString<TypedKeyPair>,
     },
+    GenerateSeedPhrase {
+        value: String,
+    },
+    DeriveKeyPair {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithString<TypedKeyPair>,
+    },
     // Misc
     Now {
         #[schemars(with = "String")]
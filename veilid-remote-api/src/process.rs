@@ -61,6 +61,7 @@ struct JsonRequestProcessorInner {
     table_dbs: BTreeMap<u32, TableDB>,
     table_db_transactions: BTreeMap<u32, TableDBTransaction>,
     crypto_kinds: BTreeMap<u32, CryptoKind>,
+    cancel_handles: BTreeMap<u32, CancelHandle>,
 }
 
 #[derive(Clone)]
@@ -79,6 +80,7 @@ impl JsonRequestProcessor {
                 table_dbs: Default::default(),
                 table_db_transactions: Default::default(),
                 crypto_kinds: Default::default(),
+                cancel_handles: Default::default(),
             })),
         }
     }
@@ -211,6 +213,45 @@ impl JsonRequestProcessor {
         1
     }
 
+    // CancelHandle
+    fn add_cancel_handle(&self, cancel_handle: CancelHandle) -> u32 {
+        let mut inner = self.inner.lock();
+        let mut next_id: u32 = 1;
+        while inner.cancel_handles.contains_key(&next_id) {
+            next_id += 1;
+        }
+        inner.cancel_handles.insert(next_id, cancel_handle);
+        next_id
+    }
+    fn lookup_cancel_handle(&self, cancel_id: u32) -> VeilidAPIResult<CancelHandle> {
+        let inner = self.inner.lock();
+        let Some(cancel_handle) = inner.cancel_handles.get(&cancel_id).cloned() else {
+            apibail_invalid_argument!("lookup_cancel_handle", "cancel_id", cancel_id);
+        };
+        Ok(cancel_handle)
+    }
+    fn release_cancel_handle(&self, cancel_id: u32) -> VeilidAPIResult<CancelHandle> {
+        let mut inner = self.inner.lock();
+        let Some(cancel_handle) = inner.cancel_handles.remove(&cancel_id) else {
+            apibail_invalid_argument!("release_cancel_handle", "cancel_id", cancel_id);
+        };
+        Ok(cancel_handle)
+    }
+    fn call_options(
+        &self,
+        deadline: Option<Timestamp>,
+        cancel_id: Option<u32>,
+    ) -> VeilidAPIResult<CallOptions> {
+        let mut options = CallOptions::new();
+        if let Some(deadline) = deadline {
+            options = options.with_deadline(deadline);
+        }
+        if let Some(cancel_id) = cancel_id {
+            options = options.with_cancel_handle(self.lookup_cancel_handle(cancel_id)?);
+        }
+        Ok(options)
+    }
+
     //////////////////////////////////////////////////////////////////////////////////////
 
     #[instrument(level = "trace", target = "json_api", skip_all)]
@@ -250,21 +291,42 @@ impl JsonRequestProcessor {
                         .add_routing_context(routing_context.clone().with_sequencing(sequencing)),
                 }
             }
-            RoutingContextRequestOp::Safety => RoutingContextResponseOp::Safety {
-                value: routing_context.safety(),
-            },
-            RoutingContextRequestOp::AppCall { target, message } => {
-                RoutingContextResponseOp::AppCall {
-                    result: to_json_api_result_with_vec_u8(
-                        async {
-                            routing_context
-                                .app_call(self.api.parse_as_target(target)?, message)
-                                .await
-                        }
-                        .await,
+            RoutingContextRequestOp::WithFragmentation { fragmentation } => {
+                RoutingContextResponseOp::WithFragmentation {
+                    value: self.add_routing_context(
+                        routing_context.clone().with_fragmentation(fragmentation),
                     ),
                 }
             }
+            RoutingContextRequestOp::WithAppNamespace { app_namespace } => {
+                RoutingContextResponseOp::WithAppNamespace {
+                    value: self.add_routing_context(
+                        routing_context.clone().with_app_namespace(app_namespace),
+                    ),
+                }
+            }
+            RoutingContextRequestOp::Safety => RoutingContextResponseOp::Safety {
+                value: routing_context.safety(),
+            },
+            RoutingContextRequestOp::AppCall {
+                target,
+                message,
+                deadline,
+                cancel_id,
+            } => RoutingContextResponseOp::AppCall {
+                result: to_json_api_result_with_vec_u8(
+                    async {
+                        routing_context
+                            .app_call_with_options(
+                                self.api.parse_as_target(target)?,
+                                message,
+                                self.call_options(deadline, cancel_id)?,
+                            )
+                            .await
+                    }
+                    .await,
+                ),
+            },
             RoutingContextRequestOp::AppMessage { target, message } => {
                 RoutingContextResponseOp::AppMessage {
                     result: to_json_api_result(
@@ -313,11 +375,21 @@ impl JsonRequestProcessor {
                 key,
                 subkey,
                 force_refresh,
+                deadline,
+                cancel_id,
             } => RoutingContextResponseOp::GetDhtValue {
                 result: to_json_api_result(
-                    routing_context
-                        .get_dht_value(key, subkey, force_refresh)
-                        .await,
+                    async {
+                        routing_context
+                            .get_dht_value_with_options(
+                                key,
+                                subkey,
+                                force_refresh,
+                                self.call_options(deadline, cancel_id)?,
+                            )
+                            .await
+                    }
+                    .await,
                 ),
             },
             RoutingContextRequestOp::SetDhtValue {
@@ -325,10 +397,47 @@ impl JsonRequestProcessor {
                 subkey,
                 data,
                 writer,
+                deadline,
+                cancel_id,
             } => RoutingContextResponseOp::SetDhtValue {
+                result: to_json_api_result(
+                    async {
+                        routing_context
+                            .set_dht_value_with_options(
+                                key,
+                                subkey,
+                                data,
+                                writer,
+                                self.call_options(deadline, cancel_id)?,
+                            )
+                            .await
+                    }
+                    .await,
+                ),
+            },
+            RoutingContextRequestOp::SetDhtValueCosigned {
+                key,
+                subkey,
+                data,
+                writer,
+                cosignatures,
+            } => RoutingContextResponseOp::SetDhtValueCosigned {
                 result: to_json_api_result(
                     routing_context
-                        .set_dht_value(key, subkey, data, writer)
+                        .set_dht_value_cosigned(key, subkey, data, writer, cosignatures)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::CosignDhtValue {
+                key,
+                subkey,
+                seq,
+                data,
+                signer,
+            } => RoutingContextResponseOp::CosignDhtValue {
+                result: to_json_api_result(
+                    routing_context
+                        .cosign_dht_value(key, subkey, seq, data, signer)
                         .await,
                 ),
             },
@@ -363,6 +472,89 @@ impl JsonRequestProcessor {
                         .map(Box::new),
                 ),
             },
+            RoutingContextRequestOp::CreateMailbox {
+                depositor_count,
+                slots_per_depositor,
+                kind,
+            } => RoutingContextResponseOp::CreateMailbox {
+                result: to_json_api_result(
+                    routing_context
+                        .create_mailbox(depositor_count, slots_per_depositor, kind)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::DepositMailboxMessage {
+                address,
+                message,
+                expiration,
+            } => RoutingContextResponseOp::DepositMailboxMessage {
+                result: to_json_api_result(
+                    routing_context
+                        .deposit_mailbox_message(address, message, expiration)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::DeleteMailbox { key } => {
+                RoutingContextResponseOp::DeleteMailbox {
+                    result: to_json_api_result(routing_context.delete_mailbox(key).await),
+                }
+            }
+            RoutingContextRequestOp::CreatePeerMailbox { host } => {
+                RoutingContextResponseOp::CreatePeerMailbox {
+                    result: to_json_api_result(
+                        routing_context
+                            .create_peer_mailbox(host)
+                            .await
+                            .map(Box::new),
+                    ),
+                }
+            }
+            RoutingContextRequestOp::DepositPeerMailboxMessage {
+                address,
+                message,
+                expiration,
+            } => RoutingContextResponseOp::DepositPeerMailboxMessage {
+                result: to_json_api_result(
+                    routing_context
+                        .deposit_peer_mailbox_message(address, message, expiration)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::DeletePeerMailbox { mailbox } => {
+                RoutingContextResponseOp::DeletePeerMailbox {
+                    result: to_json_api_result(routing_context.delete_peer_mailbox(mailbox).await),
+                }
+            }
+            RoutingContextRequestOp::CreateTopic {
+                topic,
+                publishers,
+                kind,
+            } => RoutingContextResponseOp::CreateTopic {
+                result: to_json_api_result_with_string(
+                    routing_context.create_topic(topic, publishers, kind).await,
+                ),
+            },
+            RoutingContextRequestOp::PublishTopic {
+                key,
+                publisher,
+                message,
+            } => RoutingContextResponseOp::PublishTopic {
+                result: to_json_api_result(
+                    routing_context.publish_topic(key, publisher, message).await,
+                ),
+            },
+            RoutingContextRequestOp::SubscribeTopic { key, catch_up } => {
+                RoutingContextResponseOp::SubscribeTopic {
+                    result: to_json_api_result(
+                        routing_context.subscribe_topic(key, catch_up).await,
+                    ),
+                }
+            }
+            RoutingContextRequestOp::UnsubscribeTopic { key } => {
+                RoutingContextResponseOp::UnsubscribeTopic {
+                    result: to_json_api_result(routing_context.unsubscribe_topic(key).await),
+                }
+            }
         };
         RoutingContextResponse {
             rc_id: rcr.rc_id,
@@ -514,6 +706,11 @@ impl JsonRequestProcessor {
             CryptoSystemRequestOp::GenerateKeyPair => CryptoSystemResponseOp::GenerateKeyPair {
                 value: csv.generate_keypair(),
             },
+            CryptoSystemRequestOp::DeriveKeyPair { seed, path } => {
+                CryptoSystemResponseOp::DeriveKeyPair {
+                    result: to_json_api_result_with_string(csv.derive_keypair(&seed, &path)),
+                }
+            }
             CryptoSystemRequestOp::GenerateHash { data } => CryptoSystemResponseOp::GenerateHash {
                 value: csv.generate_hash(&data),
             },
@@ -569,6 +766,42 @@ impl JsonRequestProcessor {
                     associated_data.as_deref(),
                 )),
             },
+            CryptoSystemRequestOp::Seal { key, body } => CryptoSystemResponseOp::Seal {
+                result: to_json_api_result_with_vec_u8(csv.seal(&key, &body)),
+            },
+            CryptoSystemRequestOp::Open {
+                key,
+                secret,
+                sealed,
+            } => CryptoSystemResponseOp::Open {
+                result: to_json_api_result_with_vec_u8(csv.open(&key, &secret, &sealed)),
+            },
+            CryptoSystemRequestOp::Signcrypt {
+                key,
+                sender_key,
+                sender_secret,
+                body,
+            } => CryptoSystemResponseOp::Signcrypt {
+                result: to_json_api_result_with_vec_u8(csv.signcrypt(
+                    &key,
+                    &sender_key,
+                    &sender_secret,
+                    &body,
+                )),
+            },
+            CryptoSystemRequestOp::Unsigncrypt {
+                key,
+                secret,
+                sender_key,
+                signcrypted,
+            } => CryptoSystemResponseOp::Unsigncrypt {
+                result: to_json_api_result_with_vec_u8(csv.unsigncrypt(
+                    &key,
+                    &secret,
+                    &sender_key,
+                    &signcrypted,
+                )),
+            },
             CryptoSystemRequestOp::CryptNoAuth {
                 body,
                 nonce,
@@ -605,6 +838,21 @@ impl JsonRequestProcessor {
             RequestOp::Detach => ResponseOp::Detach {
                 result: to_json_api_result(self.api.detach().await),
             },
+            RequestOp::Suspend => ResponseOp::Suspend {
+                result: to_json_api_result(self.api.suspend().await),
+            },
+            RequestOp::Resume => ResponseOp::Resume {
+                result: to_json_api_result(self.api.resume().await),
+            },
+            RequestOp::GetPowerProfile => ResponseOp::GetPowerProfile {
+                result: to_json_api_result(self.api.power_profile()),
+            },
+            RequestOp::SetPowerProfile { power_profile } => ResponseOp::SetPowerProfile {
+                result: to_json_api_result(self.api.set_power_profile(power_profile)),
+            },
+            RequestOp::Diagnose => ResponseOp::Diagnose {
+                result: to_json_api_result(self.api.diagnose().map(Box::new)),
+            },
             RequestOp::NewPrivateRoute => ResponseOp::NewPrivateRoute {
                 result: to_json_api_result(self.api.new_private_route().await.map(|r| {
                     NewPrivateRouteResult {
@@ -637,6 +885,32 @@ impl JsonRequestProcessor {
             RequestOp::AppCallReply { call_id, message } => ResponseOp::AppCallReply {
                 result: to_json_api_result(self.api.app_call_reply(call_id, message).await),
             },
+            RequestOp::SubscribeAppNamespace {
+                namespace: _namespace,
+            } => ResponseOp::SubscribeAppNamespace {
+                result: to_json_api_result(VeilidAPIResult::Err(VeilidAPIError::unimplemented(
+                    "app namespaces should be handled by veilid-core host application",
+                ))),
+            },
+            RequestOp::UnsubscribeAppNamespace {
+                namespace: _namespace,
+            } => ResponseOp::UnsubscribeAppNamespace {
+                result: to_json_api_result(VeilidAPIResult::Err(VeilidAPIError::unimplemented(
+                    "app namespaces should be handled by veilid-core host application",
+                ))),
+            },
+            RequestOp::NewCancelHandle => ResponseOp::NewCancelHandle {
+                value: self.add_cancel_handle(CancelHandle::new()),
+            },
+            RequestOp::CancelCall { cancel_id } => ResponseOp::CancelCall {
+                result: to_json_api_result(
+                    self.release_cancel_handle(cancel_id)
+                        .map(|cancel_handle| cancel_handle.cancel()),
+                ),
+            },
+            RequestOp::ReleaseCancelHandle { cancel_id } => ResponseOp::ReleaseCancelHandle {
+                result: to_json_api_result(self.release_cancel_handle(cancel_id).map(drop)),
+            },
             RequestOp::NewRoutingContext => ResponseOp::NewRoutingContext {
                 result: to_json_api_result(
                     self.api
@@ -818,6 +1092,21 @@ impl JsonRequestProcessor {
             RequestOp::GenerateKeyPair { kind } => ResponseOp::GenerateKeyPair {
                 result: to_json_api_result_with_string(Crypto::generate_keypair(kind)),
             },
+            RequestOp::GenerateSeedPhrase => ResponseOp::GenerateSeedPhrase {
+                value: self.api.generate_seed_phrase(),
+            },
+            RequestOp::DeriveKeyPair {
+                kind,
+                seed_phrase,
+                passphrase,
+                path,
+            } => ResponseOp::DeriveKeyPair {
+                result: to_json_api_result_with_string(
+                    self.api
+                        .derive_keypair(kind, &seed_phrase, &passphrase, &path)
+                        .await,
+                ),
+            },
             RequestOp::Now => ResponseOp::Now {
                 value: Timestamp::now(),
             },
@@ -25,12 +25,22 @@ pub enum RoutingContextRequestOp {
     WithSequencing {
         sequencing: Sequencing,
     },
+    WithFragmentation {
+        fragmentation: bool,
+    },
+    WithAppNamespace {
+        app_namespace: u32,
+    },
     Safety,
     AppCall {
         target: String,
         #[serde(with = "as_human_base64")]
         #[schemars(with = "String")]
         message: Vec<u8>,
+        #[serde(default)]
+        deadline: Option<Timestamp>,
+        #[serde(default)]
+        cancel_id: Option<u32>,
     },
     AppMessage {
         target: String,
@@ -64,6 +74,10 @@ pub enum RoutingContextRequestOp {
         key: TypedRecordKey,
         subkey: ValueSubkey,
         force_refresh: bool,
+        #[serde(default)]
+        deadline: Option<Timestamp>,
+        #[serde(default)]
+        cancel_id: Option<u32>,
     },
     SetDhtValue {
         #[schemars(with = "String")]
@@ -74,6 +88,32 @@ pub enum RoutingContextRequestOp {
         data: Vec<u8>,
         #[schemars(with = "Option<String>")]
         writer: Option<KeyPair>,
+        #[serde(default)]
+        deadline: Option<Timestamp>,
+        #[serde(default)]
+        cancel_id: Option<u32>,
+    },
+    SetDhtValueCosigned {
+        #[schemars(with = "String")]
+        key: TypedRecordKey,
+        subkey: ValueSubkey,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        data: Vec<u8>,
+        #[schemars(with = "Option<String>")]
+        writer: Option<KeyPair>,
+        cosignatures: Vec<DHTCosignature>,
+    },
+    CosignDhtValue {
+        #[schemars(with = "String")]
+        key: TypedRecordKey,
+        subkey: ValueSubkey,
+        seq: ValueSeqNum,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        data: Vec<u8>,
+        #[schemars(with = "String")]
+        signer: KeyPair,
     },
     WatchDhtValues {
         #[schemars(with = "String")]
@@ -94,6 +134,63 @@ pub enum RoutingContextRequestOp {
         #[schemars(default)]
         scope: DHTReportScope,
     },
+    CreateMailbox {
+        depositor_count: u16,
+        slots_per_depositor: u16,
+        #[schemars(with = "Option<String>")]
+        kind: Option<CryptoKind>,
+    },
+    DepositMailboxMessage {
+        address: MailboxAddress,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        message: Vec<u8>,
+        expiration: Option<Timestamp>,
+    },
+    DeleteMailbox {
+        #[schemars(with = "String")]
+        key: TypedRecordKey,
+    },
+    CreatePeerMailbox {
+        #[schemars(with = "String")]
+        host: TypedNodeId,
+    },
+    DepositPeerMailboxMessage {
+        address: PeerMailboxAddress,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        message: Vec<u8>,
+        expiration: Option<Timestamp>,
+    },
+    DeletePeerMailbox {
+        #[schemars(with = "String")]
+        mailbox: TypedPublicKey,
+    },
+    CreateTopic {
+        topic: String,
+        #[schemars(with = "Vec<String>")]
+        publishers: Vec<PublicKey>,
+        #[schemars(with = "Option<String>")]
+        kind: Option<CryptoKind>,
+    },
+    PublishTopic {
+        #[schemars(with = "String")]
+        key: TypedRecordKey,
+        #[schemars(with = "String")]
+        publisher: KeyPair,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        message: Vec<u8>,
+    },
+    SubscribeTopic {
+        #[schemars(with = "String")]
+        key: TypedRecordKey,
+        catch_up: bool,
+    },
+    UnsubscribeTopic {
+        #[schemars(with = "String")]
+        key: TypedRecordKey,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -112,6 +209,12 @@ pub enum RoutingContextResponseOp {
     WithSequencing {
         value: u32,
     },
+    WithFragmentation {
+        value: u32,
+    },
+    WithAppNamespace {
+        value: u32,
+    },
     Safety {
         value: SafetySelection,
     },
@@ -148,6 +251,14 @@ pub enum RoutingContextResponseOp {
         #[serde(flatten)]
         result: ApiResult<Option<ValueData>>,
     },
+    SetDhtValueCosigned {
+        #[serde(flatten)]
+        result: ApiResult<Option<ValueData>>,
+    },
+    CosignDhtValue {
+        #[serde(flatten)]
+        result: ApiResult<DHTCosignature>,
+    },
     WatchDhtValues {
         #[serde(flatten)]
         result: ApiResult<bool>,
@@ -160,4 +271,45 @@ pub enum RoutingContextResponseOp {
         #[serde(flatten)]
         result: ApiResult<Box<DHTRecordReport>>,
     },
+    CreateMailbox {
+        #[serde(flatten)]
+        result: ApiResult<Vec<MailboxAddress>>,
+    },
+    DepositMailboxMessage {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    DeleteMailbox {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    CreatePeerMailbox {
+        #[serde(flatten)]
+        result: ApiResult<Box<PeerMailboxAddress>>,
+    },
+    DepositPeerMailboxMessage {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    DeletePeerMailbox {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    CreateTopic {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithString<TypedRecordKey>,
+    },
+    PublishTopic {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    SubscribeTopic {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    UnsubscribeTopic {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
 }
@@ -4,7 +4,7 @@ use crate::veilid_logs::VeilidLogs;
 use cfg_if::*;
 use futures_util::{future::join_all, stream::FuturesUnordered, StreamExt};
 use parking_lot::Mutex;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -42,13 +42,58 @@ struct RequestLine {
     responses_tx: flume::Sender<Arc<String>>,
 }
 
+struct ClientApiConnection {
+    // Where to send updates
+    updates_tx: flume::Sender<Arc<String>>,
+    // App namespaces this client subscribed to, or empty to get the app traffic nobody subscribed to
+    app_namespaces: BTreeSet<u32>,
+}
+
+struct AppCallRecipients {
+    // Connections the app call was delivered to, any of which may reply to it
+    connection_ids: Vec<u64>,
+    // When the app call was delivered
+    timestamp: u64,
+}
+
 struct ClientApiInner {
     veilid_api: veilid_core::VeilidAPI,
     veilid_logs: VeilidLogs,
     settings: Settings,
     stop: Option<StopSource>,
     join_handle: Option<ClientApiAllFuturesJoinHandle>,
-    update_channels: HashMap<u64, flume::Sender<Arc<String>>>,
+    // Connections are numbered in the order they are accepted, so ids are never reused
+    next_connection_id: u64,
+    connections: HashMap<u64, ClientApiConnection>,
+    app_call_recipients: HashMap<OperationId, AppCallRecipients>,
+}
+
+impl ClientApiInner {
+    // Clients that subscribed to an app namespace get its traffic to themselves,
+    // otherwise it goes to every client that has not subscribed to any namespace
+    fn app_namespace_recipients(&self, namespace: u32) -> Vec<u64> {
+        let subscribers: Vec<u64> = self
+            .connections
+            .iter()
+            .filter(|(_, c)| c.app_namespaces.contains(&namespace))
+            .map(|(id, _)| *id)
+            .collect();
+        if !subscribers.is_empty() {
+            return subscribers;
+        }
+        self.connections
+            .iter()
+            .filter(|(_, c)| c.app_namespaces.is_empty())
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    // Forget app calls that can no longer be replied to
+    fn expire_app_call_recipients(&mut self, cur_ts: u64) {
+        let max_age = self.settings.read().core.network.rpc.timeout_ms as u64 * 1000;
+        self.app_call_recipients
+            .retain(|_, r| cur_ts.saturating_sub(r.timestamp) <= max_age);
+    }
 }
 
 #[derive(Clone)]
@@ -70,7 +115,9 @@ impl ClientApi {
                 settings,
                 stop: Some(StopSource::new()),
                 join_handle: None,
-                update_channels: HashMap::new(),
+                next_connection_id: 0,
+                connections: HashMap::new(),
+                app_call_recipients: HashMap::new(),
             })),
         }
     }
@@ -208,7 +255,7 @@ impl ClientApi {
     }
 
     // Process control messages for the server
-    fn process_control(self, args: Vec<String>) -> VeilidAPIResult<String> {
+    async fn process_control(self, args: Vec<String>) -> VeilidAPIResult<String> {
         if args.is_empty() {
             apibail_generic!("no control request specified");
         }
@@ -236,15 +283,20 @@ impl ClientApi {
                 apibail_generic!("wrong number of arguments");
             }
             let settings = self.inner.lock().settings.clone();
-            let settings = &*settings.read();
-            let settings_json_string = serialize_json(settings);
-            let mut settings_json =
-                json::parse(&settings_json_string).map_err(VeilidAPIError::internal)?;
-            settings_json["core"]["network"].remove("node_id_secret");
-            settings_json["core"]["protected_store"].remove("device_encryption_key_password");
-            settings_json["core"]["protected_store"].remove("new_device_encryption_key_password");
-            let safe_settings_json = settings_json.to_string();
-            Ok(safe_settings_json)
+            let safe_settings_json = settings.redacted_json().map_err(VeilidAPIError::internal)?;
+            Ok(safe_settings_json.to_string())
+        } else if args[0] == "ReloadConfig" {
+            if args.len() != 1 {
+                apibail_generic!("wrong number of arguments");
+            }
+            let (settings, veilid_logs) = {
+                let inner = self.inner.lock();
+                (inner.settings.clone(), inner.veilid_logs.clone())
+            };
+            let report = crate::server::reload_config(&settings, &veilid_logs)
+                .await
+                .map_err(|e| VeilidAPIError::generic(format!("{:?}", e)))?;
+            Ok(serialize_json(report))
         } else if args[0] == "EmitSchema" {
             if args.len() != 2 {
                 apibail_generic!("wrong number of arguments");
@@ -263,9 +315,55 @@ impl ClientApi {
         }
     }
 
+    fn subscribe_app_namespace(&self, connection_id: u64, namespace: u32) -> VeilidAPIResult<()> {
+        let mut inner = self.inner.lock();
+        let Some(connection) = inner.connections.get_mut(&connection_id) else {
+            apibail_internal!("client connection not found");
+        };
+        connection.app_namespaces.insert(namespace);
+        Ok(())
+    }
+
+    fn unsubscribe_app_namespace(&self, connection_id: u64, namespace: u32) -> VeilidAPIResult<()> {
+        let mut inner = self.inner.lock();
+        let Some(connection) = inner.connections.get_mut(&connection_id) else {
+            apibail_internal!("client connection not found");
+        };
+        connection.app_namespaces.remove(&namespace);
+        Ok(())
+    }
+
+    // Only a client that an app call was delivered to may reply to it
+    async fn process_app_call_reply(
+        self,
+        connection_id: u64,
+        call_id: OperationId,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<()> {
+        let veilid_api = {
+            let mut inner = self.inner.lock();
+            let delivered = inner
+                .app_call_recipients
+                .get(&call_id)
+                .map(|r| r.connection_ids.contains(&connection_id))
+                .unwrap_or(false);
+            if !delivered {
+                apibail_invalid_argument!(
+                    "app call was not delivered to this client",
+                    "call_id",
+                    call_id
+                );
+            }
+            inner.app_call_recipients.remove(&call_id);
+            inner.veilid_api.clone()
+        };
+        veilid_api.app_call_reply(call_id, message).await
+    }
+
     async fn process_request_line(
         self,
         jrp: JsonRequestProcessor,
+        connection_id: u64,
         request_line: RequestLine,
     ) -> VeilidAPIResult<Option<RequestLine>> {
         let line = request_line.line.trim_start();
@@ -287,18 +385,57 @@ impl ClientApi {
         #[cfg(feature = "debug-json-api")]
         debug!("JSONAPI: Request: {:?}", request);
 
-        // See if this is a control message or a veilid-core message
-        let response = if let veilid_remote_api::RequestOp::Control { args } = request.op {
-            // Process control messages
-            veilid_remote_api::Response {
-                id: request.id,
-                op: veilid_remote_api::ResponseOp::Control {
-                    result: veilid_remote_api::to_json_api_result(self.process_control(args)),
-                },
+        // See if this is a message for the server or a veilid-core message
+        let veilid_remote_api::Request { id, op } = request;
+        let response = match op {
+            veilid_remote_api::RequestOp::Control { args } => {
+                // Process control messages
+                veilid_remote_api::Response {
+                    id,
+                    op: veilid_remote_api::ResponseOp::Control {
+                        result: veilid_remote_api::to_json_api_result(
+                            self.process_control(args).await,
+                        ),
+                    },
+                }
+            }
+            veilid_remote_api::RequestOp::SubscribeAppNamespace { namespace } => {
+                veilid_remote_api::Response {
+                    id,
+                    op: veilid_remote_api::ResponseOp::SubscribeAppNamespace {
+                        result: veilid_remote_api::to_json_api_result(
+                            self.subscribe_app_namespace(connection_id, namespace),
+                        ),
+                    },
+                }
+            }
+            veilid_remote_api::RequestOp::UnsubscribeAppNamespace { namespace } => {
+                veilid_remote_api::Response {
+                    id,
+                    op: veilid_remote_api::ResponseOp::UnsubscribeAppNamespace {
+                        result: veilid_remote_api::to_json_api_result(
+                            self.unsubscribe_app_namespace(connection_id, namespace),
+                        ),
+                    },
+                }
+            }
+            veilid_remote_api::RequestOp::AppCallReply { call_id, message } => {
+                veilid_remote_api::Response {
+                    id,
+                    op: veilid_remote_api::ResponseOp::AppCallReply {
+                        result: veilid_remote_api::to_json_api_result(
+                            self.process_app_call_reply(connection_id, call_id, message)
+                                .await,
+                        ),
+                    },
+                }
+            }
+            op => {
+                // Process with ndjson api
+                jrp.clone()
+                    .process_request(veilid_remote_api::Request { id, op })
+                    .await
             }
-        } else {
-            // Process with ndjson api
-            jrp.clone().process_request(request).await
         };
 
         #[cfg(feature = "debug-json-api")]
@@ -386,11 +523,19 @@ impl ClientApi {
         let (responses_tx, responses_rx) = flume::unbounded();
 
         // Start sending updates
-        let id = get_timestamp();
-        self.inner
-            .lock()
-            .update_channels
-            .insert(id, responses_tx.clone());
+        let connection_id = {
+            let mut inner = self.inner.lock();
+            let connection_id = inner.next_connection_id;
+            inner.next_connection_id += 1;
+            inner.connections.insert(
+                connection_id,
+                ClientApiConnection {
+                    updates_tx: responses_tx.clone(),
+                    app_namespaces: BTreeSet::new(),
+                },
+            );
+            connection_id
+        };
 
         // Request receive processor future
         // Receives from socket and enqueues RequestLines
@@ -438,13 +583,15 @@ impl ClientApi {
             };
 
             // Enqueue unordered future to process request line in parallel
-            unord.push(pin_dyn_future!(self
-                .clone()
-                .process_request_line(jrp.clone(), request_line)));
+            unord.push(pin_dyn_future!(self.clone().process_request_line(
+                jrp.clone(),
+                connection_id,
+                request_line
+            )));
         }
 
         // Stop sending updates
-        self.inner.lock().update_channels.remove(&id);
+        self.inner.lock().connections.remove(&connection_id);
     }
 
     pub async fn handle_tcp_connection(self, stream: TcpStream, awg: AsyncWaitGroup) {
@@ -526,18 +673,44 @@ impl ClientApi {
     }
 
     pub fn handle_update(&self, veilid_update: veilid_core::VeilidUpdate) {
-        let inner = self.inner.lock();
-        if inner.update_channels.is_empty() {
+        let mut inner = self.inner.lock();
+        if inner.connections.is_empty() {
             return;
         }
 
+        // App traffic only goes to the clients handling its namespace
+        let recipients: Vec<u64> = match &veilid_update {
+            VeilidUpdate::AppMessage(app_message) => {
+                inner.app_namespace_recipients(app_message.namespace())
+            }
+            VeilidUpdate::AppCall(app_call) => {
+                let recipients = inner.app_namespace_recipients(app_call.namespace());
+                let cur_ts = get_timestamp();
+                inner.expire_app_call_recipients(cur_ts);
+                if !recipients.is_empty() {
+                    inner.app_call_recipients.insert(
+                        app_call.id(),
+                        AppCallRecipients {
+                            connection_ids: recipients.clone(),
+                            timestamp: cur_ts,
+                        },
+                    );
+                }
+                recipients
+            }
+            _ => inner.connections.keys().copied().collect(),
+        };
+
         // serialize update to NDJSON
         let veilid_update =
             Arc::new(serialize_json(veilid_remote_api::RecvMessage::Update(veilid_update)) + "\n");
 
         // Pass updates to clients
-        for ch in inner.update_channels.values() {
-            if ch.send(veilid_update.clone()).is_err() {
+        for connection_id in recipients {
+            let Some(connection) = inner.connections.get(&connection_id) else {
+                continue;
+            };
+            if connection.updates_tx.send(veilid_update.clone()).is_err() {
                 // eprintln!("failed to send update: {}", e);
             }
         }
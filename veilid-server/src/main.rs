@@ -5,6 +5,7 @@
 compile_error!("async-std compilation for windows is currently unsupported");
 
 mod client_api;
+mod metrics;
 mod server;
 mod settings;
 mod tools;
@@ -125,6 +126,22 @@ pub struct CmdlineArgs {
     #[arg(long)]
     delete_block_store: bool,
 
+    /// Export tables from the table store to a password-encrypted archive file and then quit
+    ///
+    /// All tables are exported unless '--tables' is given. A prompt appears to enter the archive password.
+    #[arg(long, value_name = "FILE")]
+    export_tables: Option<PathBuf>,
+
+    /// Import tables from a password-encrypted archive file and then quit
+    ///
+    /// Imported tables replace the existing tables of the same name the next time the server starts. A prompt appears to enter the archive password.
+    #[arg(long, value_name = "FILE", conflicts_with = "export_tables")]
+    import_tables: Option<PathBuf>,
+
+    /// Comma-separated list of the tables to export
+    #[arg(long, value_name = "TABLES", requires = "export_tables")]
+    tables: Option<String>,
+
     /// Instead of running the server, print the configuration it would use to the console
     #[arg(long)]
     dump_config: bool,
@@ -304,6 +321,26 @@ fn main() -> EyreResult<()> {
         // Turn off terminal logging so we can be interactive
         settingsrw.logging.terminal.enabled = false;
     }
+    let mut table_archive_password = None;
+    if args.export_tables.is_some() || args.import_tables.is_some() {
+        if settingsrw.testing.subnode_count != 1 {
+            bail!("subnode count must be 1 if exporting or importing tables");
+        }
+        // Turn off terminal logging so we can be interactive
+        settingsrw.logging.terminal.enabled = false;
+
+        let password = rpassword::prompt_password("Enter archive password (will not echo): ")
+            .wrap_err("invalid archive password")?;
+        if args.export_tables.is_some() {
+            let confirm = rpassword::prompt_password("Confirm archive password: ")
+                .wrap_err("invalid archive password")?;
+            if confirm != password {
+                bail!("archive passwords do not match");
+            }
+        }
+        table_archive_password = Some(password);
+    }
+
     let mut node_id_set = false;
     if let Some(key_set) = args.set_node_id {
         if settingsrw.testing.subnode_count != 1 {
@@ -467,6 +504,32 @@ fn main() -> EyreResult<()> {
             "Node Id and Secret set successfully",
             "Failed to set Node Id and Secret",
         )
+    } else if let Some(path) = args.export_tables.clone() {
+        (
+            ServerMode::ExportTables {
+                path,
+                tables: args
+                    .tables
+                    .as_deref()
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(|x| x.trim().to_owned())
+                    .filter(|x| !x.is_empty())
+                    .collect(),
+                password: table_archive_password.clone().unwrap_or_default(),
+            },
+            "Tables exported successfully",
+            "Failed to export tables",
+        )
+    } else if let Some(path) = args.import_tables.clone() {
+        (
+            ServerMode::ImportTables {
+                path,
+                password: table_archive_password.clone().unwrap_or_default(),
+            },
+            "Tables imported successfully, they will be used the next time the server starts",
+            "Failed to import tables",
+        )
     } else if let Some(skpstr) = args.dump_txt_record.as_ref() {
         (
             ServerMode::DumpTXTRecord(
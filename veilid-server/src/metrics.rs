@@ -0,0 +1,651 @@
+use crate::tools::*;
+use cfg_if::*;
+use futures_util::{future::join_all, StreamExt};
+use parking_lot::Mutex;
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use stop_token::future::FutureExt as _;
+use stop_token::*;
+use tracing::*;
+use veilid_core::tools::*;
+use veilid_core::*;
+use wg::AsyncWaitGroup;
+
+cfg_if! {
+    if #[cfg(feature="rt-async-std")] {
+        use futures_util::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+    } else
+    if #[cfg(feature="rt-tokio")] {
+        use tokio::io::AsyncBufReadExt;
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+    } else {
+        compile_error!("needs executor implementation");
+    }
+}
+
+// Limits on what we are willing to read from a scraper before giving up on it
+const MAX_REQUEST_HEADER_LINES: usize = 100;
+const MAX_REQUEST_LINE_LENGTH: usize = 8192;
+const REQUEST_TIMEOUT_MS: u32 = 10000;
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+// --- Metrics Server ---------------------------------
+
+type MetricsServerAllFuturesJoinHandle = MustJoinHandle<Vec<()>>;
+
+struct MetricsServerInner {
+    veilid_api: veilid_core::VeilidAPI,
+    path: String,
+    peer_metrics: bool,
+    stop: Option<StopSource>,
+    join_handle: Option<MetricsServerAllFuturesJoinHandle>,
+}
+
+/// Serves node metrics in the Prometheus text exposition format over plain HTTP
+#[derive(Clone)]
+pub struct MetricsServer {
+    inner: Arc<Mutex<MetricsServerInner>>,
+}
+
+impl MetricsServer {
+    #[instrument(level = "trace", skip_all)]
+    pub fn new(veilid_api: veilid_core::VeilidAPI, path: String, peer_metrics: bool) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(MetricsServerInner {
+                veilid_api,
+                path,
+                peer_metrics,
+                stop: Some(StopSource::new()),
+                join_handle: None,
+            })),
+        }
+    }
+
+    #[instrument(level = "trace", skip_all)]
+    pub async fn stop(&self) {
+        trace!(target: "metrics", "MetricsServer::stop requested");
+        let jh = {
+            let mut inner = self.inner.lock();
+            if inner.join_handle.is_none() {
+                trace!(target: "metrics", "MetricsServer stop ignored");
+                return;
+            }
+            drop(inner.stop.take());
+            inner.join_handle.take().unwrap()
+        };
+        trace!(target: "metrics", "MetricsServer::stop: waiting for stop");
+        jh.await;
+        trace!(target: "metrics", "MetricsServer::stop: stopped");
+    }
+
+    async fn handle_tcp_incoming(self, bind_addr: SocketAddr) -> std::io::Result<()> {
+        let listener = bind_async_tcp_listener(bind_addr)?
+            .ok_or(std::io::Error::from(std::io::ErrorKind::AddrInUse))?;
+        debug!(target: "metrics", "Metrics listening on: {:?}", bind_addr);
+
+        // Process the incoming accept stream
+        let mut incoming_stream = async_tcp_listener_incoming(listener);
+
+        // Make wait group for all incoming connections
+        let awg = AsyncWaitGroup::new();
+
+        let stop_token = match self.inner.lock().stop.as_ref() {
+            Some(stop) => stop.token(),
+            None => {
+                debug!(target: "metrics", "Already stopped");
+                return Ok(());
+            }
+        };
+        while let Ok(Some(stream_result)) =
+            incoming_stream.next().timeout_at(stop_token.clone()).await
+        {
+            // Get the stream to process
+            let stream = stream_result?;
+            stream.set_nodelay(true)?;
+
+            // Increment wait group
+            awg.add(1);
+            let t_awg = awg.clone();
+
+            // Process the connection
+            spawn(
+                "metrics handle_tcp_connection",
+                self.clone().handle_tcp_connection(stream, t_awg),
+            )
+            .detach();
+        }
+
+        // Wait for all connections to terminate
+        awg.wait().await;
+
+        Ok(())
+    }
+
+    async fn handle_tcp_connection(self, stream: TcpStream, awg: AsyncWaitGroup) {
+        let peer_addr = stream.peer_addr().ok();
+        trace!(target: "metrics", "Accepted metrics connection from {:?}", peer_addr);
+
+        let mut reader = BufReader::new(stream);
+        match timeout(REQUEST_TIMEOUT_MS, self.clone().handle_request(&mut reader)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                debug!(target: "metrics", "Metrics request from {:?} failed: {}", peer_addr, e);
+            }
+            Err(_) => {
+                debug!(target: "metrics", "Metrics request from {:?} timed out", peer_addr);
+            }
+        }
+
+        awg.done();
+    }
+
+    async fn handle_request(self, reader: &mut BufReader<TcpStream>) -> std::io::Result<()> {
+        // Read the request line and skip the headers, we have no use for them
+        let mut request_line = String::new();
+        let mut header_lines = 0usize;
+        loop {
+            // Never buffer more than one byte past the limit, so an endless line is cut off
+            let mut line = String::new();
+            if (&mut *reader)
+                .take(MAX_REQUEST_LINE_LENGTH as u64 + 1)
+                .read_line(&mut line)
+                .await?
+                == 0
+            {
+                return Ok(());
+            }
+            if line.len() > MAX_REQUEST_LINE_LENGTH || header_lines > MAX_REQUEST_HEADER_LINES {
+                return Err(std::io::Error::from(std::io::ErrorKind::InvalidData));
+            }
+            let line = line.trim_end();
+            if request_line.is_empty() {
+                request_line = line.to_owned();
+            } else if line.is_empty() {
+                break;
+            } else {
+                header_lines += 1;
+            }
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let target = parts.next().unwrap_or_default();
+        let path = target.split('?').next().unwrap_or_default();
+
+        let (configured_path, peer_metrics, veilid_api) = {
+            let inner = self.inner.lock();
+            (
+                inner.path.clone(),
+                inner.peer_metrics,
+                inner.veilid_api.clone(),
+            )
+        };
+
+        let (status, content_type, body) = if method != "GET" && method != "HEAD" {
+            (
+                "405 Method Not Allowed",
+                "text/plain",
+                "method not allowed\n".to_owned(),
+            )
+        } else if path != configured_path {
+            ("404 Not Found", "text/plain", "not found\n".to_owned())
+        } else {
+            match render_metrics(&veilid_api, peer_metrics).await {
+                Ok(body) => ("200 OK", PROMETHEUS_CONTENT_TYPE, body),
+                Err(e) => (
+                    "503 Service Unavailable",
+                    "text/plain",
+                    format!("metrics unavailable: {}\n", e),
+                ),
+            }
+        };
+
+        let mut response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            status,
+            content_type,
+            body.len()
+        );
+        if method != "HEAD" {
+            response.push_str(&body);
+        }
+
+        let stream = reader.get_mut();
+        stream.write_all(response.as_bytes()).await?;
+        stream.flush().await?;
+        Ok(())
+    }
+
+    #[instrument(level = "trace", skip(self))]
+    pub fn run(&self, tcp_bind_addrs: Vec<SocketAddr>) {
+        let mut bind_futures: Vec<PinBoxFutureStatic<()>> = Vec::new();
+
+        for addr in tcp_bind_addrs.iter().copied() {
+            let this = self.clone();
+            bind_futures.push(Box::pin(async move {
+                if let Err(e) = this.handle_tcp_incoming(addr).await {
+                    warn!("Not binding metrics endpoint to {}: {}", addr, e);
+                }
+            }));
+        }
+
+        let bind_futures_join = join_all(bind_futures);
+        self.inner.lock().join_handle = Some(spawn("metrics bind_futures", bind_futures_join));
+    }
+}
+
+// --- Prometheus Rendering ---------------------------------
+
+struct MetricsWriter {
+    out: String,
+}
+
+impl MetricsWriter {
+    fn new() -> Self {
+        Self { out: String::new() }
+    }
+
+    fn header(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.out, "# HELP veilid_{} {}", name, help);
+        let _ = writeln!(self.out, "# TYPE veilid_{} {}", name, kind);
+    }
+
+    fn sample<V: std::fmt::Display>(&mut self, name: &str, labels: &[(&str, &str)], value: V) {
+        let _ = write!(self.out, "veilid_{}", name);
+        if !labels.is_empty() {
+            let labels = labels
+                .iter()
+                .map(|(k, v)| format!("{}=\"{}\"", k, escape_label_value(v)))
+                .collect::<Vec<_>>()
+                .join(",");
+            let _ = write!(self.out, "{{{}}}", labels);
+        }
+        let _ = writeln!(self.out, " {}", value);
+    }
+
+    fn single<V: std::fmt::Display>(&mut self, name: &str, kind: &str, help: &str, value: V) {
+        self.header(name, kind, help);
+        self.sample(name, &[], value);
+    }
+
+    fn latency(&mut self, name: &str, labels: &[(&str, &str)], ls: &LatencyStats) {
+        for (stat, value) in [
+            ("fastest", ls.fastest),
+            ("average", ls.average),
+            ("slowest", ls.slowest),
+            ("p90", ls.p90),
+            ("p75", ls.p75),
+        ] {
+            let mut labels = labels.to_vec();
+            labels.push(("stat", stat));
+            self.sample(name, &labels, micros_to_seconds(value.as_u64()));
+        }
+    }
+
+    fn finish(self) -> String {
+        self.out
+    }
+}
+
+fn escape_label_value(v: &str) -> String {
+    v.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn micros_to_seconds(us: u64) -> f64 {
+    (us as f64) / 1_000_000.0f64
+}
+
+fn bool_gauge(b: bool) -> u8 {
+    u8::from(b)
+}
+
+async fn render_metrics(veilid_api: &VeilidAPI, peer_metrics: bool) -> VeilidAPIResult<String> {
+    let state = veilid_api.get_state().await?;
+    let metrics = veilid_api.get_metrics().await?;
+
+    let mut w = MetricsWriter::new();
+
+    // Attachment
+    w.header(
+        "attachment_state",
+        "gauge",
+        "Current attachment state, 1 for the active state",
+    );
+    for s in [
+        AttachmentState::Detached,
+        AttachmentState::Attaching,
+        AttachmentState::AttachedWeak,
+        AttachmentState::AttachedGood,
+        AttachmentState::AttachedStrong,
+        AttachmentState::FullyAttached,
+        AttachmentState::OverAttached,
+        AttachmentState::Detaching,
+        AttachmentState::Resuming,
+    ] {
+        let name = s.to_string();
+        w.sample(
+            "attachment_state",
+            &[("state", &name)],
+            bool_gauge(state.attachment.state == s),
+        );
+    }
+    w.single(
+        "public_internet_ready",
+        "gauge",
+        "Whether the public internet routing domain is ready",
+        bool_gauge(state.attachment.public_internet_ready),
+    );
+    w.single(
+        "local_network_ready",
+        "gauge",
+        "Whether the local network routing domain is ready",
+        bool_gauge(state.attachment.local_network_ready),
+    );
+    w.single(
+        "uptime_seconds",
+        "gauge",
+        "Time since the node was started",
+        micros_to_seconds(state.attachment.uptime.as_u64()),
+    );
+    w.single(
+        "attached_uptime_seconds",
+        "gauge",
+        "Time since the node last attached, zero if detached",
+        micros_to_seconds(
+            state
+                .attachment
+                .attached_uptime
+                .map(|x| x.as_u64())
+                .unwrap_or_default(),
+        ),
+    );
+
+    // Network
+    w.single(
+        "network_started",
+        "gauge",
+        "Whether the network is started",
+        bool_gauge(state.network.started),
+    );
+    w.header(
+        "network_bytes_per_second",
+        "gauge",
+        "Current total bandwidth use",
+    );
+    w.sample(
+        "network_bytes_per_second",
+        &[("direction", "down")],
+        state.network.bps_down.as_u64(),
+    );
+    w.sample(
+        "network_bytes_per_second",
+        &[("direction", "up")],
+        state.network.bps_up.as_u64(),
+    );
+    w.single(
+        "peer_count",
+        "gauge",
+        "Number of peers in the routing table that are recently active",
+        state.network.peers.len(),
+    );
+
+    if peer_metrics {
+        w.header(
+            "peer_transfer_bytes_total",
+            "counter",
+            "Bytes transferred with a peer",
+        );
+        for peer in &state.network.peers {
+            let Some(node_id) = peer.node_ids.first().map(|x| x.to_string()) else {
+                continue;
+            };
+            let transfer = &peer.peer_stats.transfer;
+            w.sample(
+                "peer_transfer_bytes_total",
+                &[("node_id", &node_id), ("direction", "down")],
+                transfer.down.total.as_u64(),
+            );
+            w.sample(
+                "peer_transfer_bytes_total",
+                &[("node_id", &node_id), ("direction", "up")],
+                transfer.up.total.as_u64(),
+            );
+        }
+        w.header(
+            "peer_latency_seconds",
+            "gauge",
+            "Latency to a peer over recent answers",
+        );
+        for peer in &state.network.peers {
+            let Some(node_id) = peer.node_ids.first().map(|x| x.to_string()) else {
+                continue;
+            };
+            if let Some(ls) = &peer.peer_stats.latency {
+                w.latency("peer_latency_seconds", &[("node_id", &node_id)], ls);
+            }
+        }
+        w.header(
+            "peer_questions_in_flight",
+            "gauge",
+            "Questions sent to a peer that have not been answered yet",
+        );
+        for peer in &state.network.peers {
+            let Some(node_id) = peer.node_ids.first().map(|x| x.to_string()) else {
+                continue;
+            };
+            w.sample(
+                "peer_questions_in_flight",
+                &[("node_id", &node_id)],
+                peer.peer_stats.rpc_stats.questions_in_flight,
+            );
+        }
+    }
+
+    // RPC
+    let rpc = &metrics.rpc;
+    w.single(
+        "rpc_queue_depth",
+        "gauge",
+        "Messages waiting for an rpc worker",
+        rpc.queue_depth,
+    );
+    w.single(
+        "rpc_queue_capacity",
+        "gauge",
+        "Maximum number of messages that can wait for an rpc worker",
+        rpc.queue_capacity,
+    );
+    w.header(
+        "rpc_class_queue_depth",
+        "gauge",
+        "Messages waiting for an rpc worker, by priority class",
+    );
+    for (class, queue) in &rpc.queues {
+        w.sample("rpc_class_queue_depth", &[("class", class)], queue.depth);
+    }
+    w.header(
+        "rpc_class_queue_capacity",
+        "gauge",
+        "Maximum number of messages that can wait for an rpc worker, by priority class",
+    );
+    for (class, queue) in &rpc.queues {
+        w.sample(
+            "rpc_class_queue_capacity",
+            &[("class", class)],
+            queue.capacity,
+        );
+    }
+    w.header(
+        "rpc_class_enqueued_total",
+        "counter",
+        "Messages queued for an rpc worker, by priority class",
+    );
+    for (class, queue) in &rpc.queues {
+        w.sample(
+            "rpc_class_enqueued_total",
+            &[("class", class)],
+            queue.enqueued,
+        );
+    }
+    w.header(
+        "rpc_class_dropped_total",
+        "counter",
+        "Messages dropped because their rpc queue was full, by priority class",
+    );
+    for (class, queue) in &rpc.queues {
+        w.sample(
+            "rpc_class_dropped_total",
+            &[("class", class)],
+            queue.dropped,
+        );
+    }
+    w.header(
+        "rpc_class_dequeue_latency_seconds",
+        "gauge",
+        "Time between enqueuing a message and a worker picking it up, by priority class",
+    );
+    for (class, queue) in &rpc.queues {
+        w.latency(
+            "rpc_class_dequeue_latency_seconds",
+            &[("class", class)],
+            &queue.dequeue_latency,
+        );
+    }
+    w.single(
+        "rpc_workers",
+        "gauge",
+        "Number of rpc workers",
+        rpc.worker_count,
+    );
+    w.single(
+        "rpc_questions_in_flight",
+        "gauge",
+        "Questions waiting for an answer",
+        rpc.questions_in_flight,
+    );
+    w.header(
+        "rpc_dequeue_latency_seconds",
+        "gauge",
+        "Time between enqueuing a message and a worker picking it up",
+    );
+    w.latency("rpc_dequeue_latency_seconds", &[], &rpc.dequeue_latency);
+    w.header(
+        "rpc_process_latency_seconds",
+        "gauge",
+        "Time taken by a worker to process a message",
+    );
+    w.latency("rpc_process_latency_seconds", &[], &rpc.process_latency);
+    w.header(
+        "rpc_operations_received_total",
+        "counter",
+        "Operations received, by kind",
+    );
+    for (op, count) in &rpc.operations_rcvd {
+        w.sample("rpc_operations_received_total", &[("op", op)], count);
+    }
+    w.header(
+        "rpc_operations_sent_total",
+        "counter",
+        "Operations sent, by kind",
+    );
+    for (op, count) in &rpc.operations_sent {
+        w.sample("rpc_operations_sent_total", &[("op", op)], count);
+    }
+
+    // DHT
+    let dht = &metrics.dht;
+    w.header("dht_records", "gauge", "Records held in the record stores");
+    w.sample("dht_records", &[("store", "local")], dht.local_record_count);
+    w.sample(
+        "dht_records",
+        &[("store", "remote")],
+        dht.remote_record_count,
+    );
+    w.header(
+        "dht_storage_bytes",
+        "gauge",
+        "Bytes used by the record stores",
+    );
+    w.sample(
+        "dht_storage_bytes",
+        &[("store", "local")],
+        dht.local_storage_bytes,
+    );
+    w.sample(
+        "dht_storage_bytes",
+        &[("store", "remote")],
+        dht.remote_storage_bytes,
+    );
+    w.single(
+        "dht_opened_records",
+        "gauge",
+        "Records currently opened by this node",
+        dht.opened_record_count,
+    );
+    w.single(
+        "dht_offline_subkey_writes",
+        "gauge",
+        "Records with subkey writes waiting to be sent to the network",
+        dht.offline_subkey_write_count,
+    );
+
+    // Relay
+    let relay = &metrics.relay;
+    w.header("relay_in_use", "gauge", "Whether this node uses a relay");
+    w.sample(
+        "relay_in_use",
+        &[("routing_domain", "PublicInternet")],
+        bool_gauge(relay.public_internet_relay),
+    );
+    w.sample(
+        "relay_in_use",
+        &[("routing_domain", "LocalNetwork")],
+        bool_gauge(relay.local_network_relay),
+    );
+    w.single(
+        "relayed_envelopes_total",
+        "counter",
+        "Envelopes this node has relayed for other nodes",
+        relay.relayed_envelopes,
+    );
+    w.single(
+        "relay_queue_depth",
+        "gauge",
+        "Envelopes waiting for a relay worker",
+        relay.queue_depth,
+    );
+    w.header(
+        "relay_dequeue_latency_seconds",
+        "gauge",
+        "Time between enqueuing an envelope and a relay worker picking it up",
+    );
+    w.latency("relay_dequeue_latency_seconds", &[], &relay.dequeue_latency);
+    w.header(
+        "relay_process_latency_seconds",
+        "gauge",
+        "Time taken by a relay worker to send an envelope",
+    );
+    w.latency("relay_process_latency_seconds", &[], &relay.process_latency);
+
+    // Punishments
+    let punishments = &metrics.punishments;
+    w.header("punishments", "gauge", "Currently active punishments");
+    w.sample(
+        "punishments",
+        &[("kind", "ipv4_address")],
+        punishments.ipv4_addresses,
+    );
+    w.sample(
+        "punishments",
+        &[("kind", "ipv6_prefix")],
+        punishments.ipv6_prefixes,
+    );
+    w.sample("punishments", &[("kind", "node_id")], punishments.node_ids);
+
+    Ok(w.finish())
+}
@@ -1,4 +1,5 @@
 use crate::client_api;
+use crate::metrics;
 use crate::settings::*;
 use crate::tools::*;
 use crate::veilid_logs::*;
@@ -7,21 +8,181 @@ use futures_util::select;
 use futures_util::FutureExt;
 use lazy_static::*;
 use parking_lot::Mutex;
+use serde_derive::*;
+use std::collections::BTreeMap;
+use std::fmt;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tracing::*;
 use veilid_core::tools::*;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ServerMode {
     Normal,
     ShutdownImmediate,
     DumpTXTRecord(veilid_core::TypedKeyPair),
+    ExportTables {
+        path: PathBuf,
+        tables: Vec<String>,
+        password: String,
+    },
+    ImportTables {
+        path: PathBuf,
+        password: String,
+    },
 }
 
 lazy_static! {
     static ref SHUTDOWN_SWITCH: Mutex<Option<SingleShotEventual<()>>> =
         Mutex::new(Some(SingleShotEventual::new(Some(()))));
+    static ref RUNNING_SUBNODES: Mutex<BTreeMap<u16, RunningSubnode>> = Mutex::new(BTreeMap::new());
+}
+
+/// What is needed to push a configuration reload into a running subnode
+#[derive(Clone)]
+struct RunningSubnode {
+    veilid_api: veilid_core::VeilidAPI,
+    config_callback: veilid_core::ConfigCallback,
+}
+
+/// The outcome of reloading the configuration file
+#[derive(Debug, Default, Serialize)]
+pub struct ReloadReport {
+    /// Settings keys that were edited in the config file
+    pub changed: Vec<String>,
+    /// Server settings that are now in effect
+    pub applied: Vec<String>,
+    /// Server settings that only take effect after a restart
+    pub restart_required: Vec<String>,
+    /// What each running subnode did with the core configuration changes
+    pub subnodes: BTreeMap<u16, veilid_core::VeilidConfigReloadReport>,
+}
+
+impl fmt::Display for ReloadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changed.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for k in &self.applied {
+            writeln!(f, "applied: {}", k)?;
+        }
+        for k in &self.restart_required {
+            writeln!(f, "restart required: {}", k)?;
+        }
+        for (subnode, report) in &self.subnodes {
+            writeln!(f, "subnode {}:", subnode)?;
+            write!(f, "{}", veilid_core::indent_all_string(report))?;
+        }
+        Ok(())
+    }
+}
+
+/// Re-read the config file and apply what can be changed without restarting
+///
+/// Core settings are handed to every running subnode first, which applies what it can in place.
+/// If any subnode rejects them, the previous settings are restored everywhere and nothing else
+/// is applied. Log levels are then changed on the running log layers.
+#[instrument(level = "debug", skip_all, err)]
+pub async fn reload_config(
+    settings: &Settings,
+    veilid_logs: &VeilidLogs,
+) -> EyreResult<ReloadReport> {
+    let snapshot = settings.snapshot()?;
+    let changed = settings.reload()?;
+
+    let mut report = ReloadReport::default();
+
+    if changed.iter().any(|k| k.starts_with("core.")) {
+        let mut reloaded = Vec::new();
+        match reload_core_config(&mut reloaded).await {
+            Ok(subnodes) => {
+                report.subnodes = subnodes;
+            }
+            Err(e) => {
+                settings.restore(snapshot)?;
+                rollback_core_config(reloaded).await;
+                return Err(e);
+            }
+        }
+    }
+
+    for key in &changed {
+        if key.starts_with("core.") {
+            continue;
+        }
+        let log_level_layer = key
+            .strip_prefix("logging.")
+            .and_then(|k| k.strip_suffix(".level"));
+        if let Some(layer) = log_level_layer {
+            let level = match layer {
+                "system" => settings.read().logging.system.level,
+                "terminal" => settings.read().logging.terminal.level,
+                "file" => settings.read().logging.file.level,
+                "api" => settings.read().logging.api.level,
+                #[cfg(feature = "opentelemetry-otlp")]
+                "otlp" => settings.read().logging.otlp.level,
+                _ => {
+                    report.restart_required.push(key.clone());
+                    continue;
+                }
+            };
+            // Layers that are not enabled can only be turned on with a restart
+            if veilid_logs
+                .change_log_level(layer.to_owned(), convert_loglevel(level))
+                .is_ok()
+            {
+                report.applied.push(key.clone());
+            } else {
+                report.restart_required.push(key.clone());
+            }
+        } else {
+            report.restart_required.push(key.clone());
+        }
+    }
+
+    report.changed = changed;
+    Ok(report)
+}
+
+/// Hand the new core settings to every running subnode
+///
+/// Every subnode's configuration is validated before any of them is changed. The subnodes that
+/// took the new configuration are added to `reloaded`, so they can be rolled back on failure.
+async fn reload_core_config(
+    reloaded: &mut Vec<RunningSubnode>,
+) -> EyreResult<BTreeMap<u16, veilid_core::VeilidConfigReloadReport>> {
+    let subnodes = RUNNING_SUBNODES.lock().clone();
+
+    let mut configs = Vec::with_capacity(subnodes.len());
+    for (subnode, rs) in subnodes {
+        let config = veilid_core::VeilidConfig::from_callback(rs.config_callback.clone())
+            .wrap_err(format!("configuration is invalid for subnode {}", subnode))?;
+        configs.push((subnode, rs, config));
+    }
+
+    let mut reports = BTreeMap::new();
+    for (subnode, rs, config) in configs {
+        let subnode_report = rs.veilid_api.reload_config(config).await.wrap_err(format!(
+            "failed to reload core configuration for subnode {}",
+            subnode
+        ))?;
+        reports.insert(subnode, subnode_report);
+        reloaded.push(rs);
+    }
+    Ok(reports)
+}
+
+/// Put subnodes that took a new configuration back on the restored settings
+async fn rollback_core_config(reloaded: Vec<RunningSubnode>) {
+    for rs in reloaded {
+        let res = match veilid_core::VeilidConfig::from_callback(rs.config_callback.clone()) {
+            Ok(config) => rs.veilid_api.reload_config(config).await.map(drop),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = res {
+            error!("failed to roll back core configuration: {}", e);
+        }
+    }
 }
 
 #[instrument]
@@ -44,6 +205,10 @@ pub async fn run_veilid_server_subnode(
         settings_client_api_network_enabled,
         settings_client_api_ipc_directory,
         settings_client_api_listen_address_addrs,
+        settings_metrics_enabled,
+        settings_metrics_listen_address_addrs,
+        settings_metrics_path,
+        settings_metrics_peer_metrics,
         subnode_offset,
     ) = {
         let settingsr = settings.read();
@@ -71,6 +236,14 @@ pub async fn run_veilid_server_subnode(
                 .listen_address
                 .with_offset_port(subnode_offset)?
                 .addrs,
+            settingsr.metrics.enabled,
+            settingsr
+                .metrics
+                .listen_address
+                .with_offset_port(subnode_offset)?
+                .addrs,
+            settingsr.metrics.path.clone(),
+            settingsr.metrics.peer_metrics,
             subnode_offset,
         )
     };
@@ -92,10 +265,19 @@ pub async fn run_veilid_server_subnode(
     let config_callback = settings.get_core_config_callback(subnode, subnode_offset);
 
     // Start Veilid Core and get API
-    let veilid_api = veilid_core::api_startup(update_callback, config_callback)
+    let veilid_api = veilid_core::api_startup(update_callback, config_callback.clone())
         .await
         .wrap_err("VeilidCore startup failed")?;
 
+    // Make this subnode reachable for configuration reloads
+    RUNNING_SUBNODES.lock().insert(
+        subnode,
+        RunningSubnode {
+            veilid_api: veilid_api.clone(),
+            config_callback,
+        },
+    );
+
     // Start client api if one is requested
     let capi_enabled = settings_client_api_ipc_enabled || settings_client_api_network_enabled;
     let mut capi = if capi_enabled && matches!(server_mode, ServerMode::Normal) {
@@ -118,8 +300,25 @@ pub async fn run_veilid_server_subnode(
         None
     };
 
+    // Start metrics endpoint if one is requested
+    let metrics_server = if settings_metrics_enabled && matches!(server_mode, ServerMode::Normal) {
+        let some_metrics_server = metrics::MetricsServer::new(
+            veilid_api.clone(),
+            settings_metrics_path,
+            settings_metrics_peer_metrics,
+        );
+        some_metrics_server.run(settings_metrics_listen_address_addrs);
+        Some(some_metrics_server)
+    } else {
+        None
+    };
+
     // Drop rwlock on settings
-    let auto_attach = settings_auto_attach || !matches!(server_mode, ServerMode::Normal);
+    let auto_attach = match server_mode {
+        ServerMode::Normal => settings_auto_attach,
+        ServerMode::ExportTables { .. } | ServerMode::ImportTables { .. } => false,
+        ServerMode::ShutdownImmediate | ServerMode::DumpTXTRecord(_) => true,
+    };
 
     // Process all updates
     let capi2 = capi.clone();
@@ -163,7 +362,7 @@ pub async fn run_veilid_server_subnode(
     }
 
     // Process dump-txt-record
-    if let ServerMode::DumpTXTRecord(keypair) = server_mode {
+    if let ServerMode::DumpTXTRecord(keypair) = &server_mode {
         let start_time = Instant::now();
         while Instant::now().duration_since(start_time) < Duration::from_secs(10) {
             match veilid_api.get_state().await {
@@ -190,6 +389,56 @@ pub async fn run_veilid_server_subnode(
         shutdown();
     }
 
+    // Process export-tables
+    if let ServerMode::ExportTables {
+        path,
+        tables,
+        password,
+    } = &server_mode
+    {
+        let res = match veilid_api.table_store() {
+            Ok(ts) => ts.export(tables, password).await,
+            Err(e) => Err(e),
+        };
+        match res {
+            Ok(archive) => {
+                if let Err(e) = std::fs::write(path, archive) {
+                    out = Err(eyre!("Writing table archive failed: {}", e));
+                }
+            }
+            Err(e) => {
+                out = Err(eyre!("Exporting tables failed: {}", e));
+            }
+        }
+        shutdown();
+    }
+
+    // Process import-tables
+    if let ServerMode::ImportTables { path, password } = &server_mode {
+        match std::fs::read(path) {
+            Ok(archive) => {
+                let res = match veilid_api.table_store() {
+                    Ok(ts) => ts.import(&archive, password).await,
+                    Err(e) => Err(e),
+                };
+                match res {
+                    Ok(imported) => {
+                        for name in imported {
+                            println!("imported table: {}", name);
+                        }
+                    }
+                    Err(e) => {
+                        out = Err(eyre!("Importing tables failed: {}", e));
+                    }
+                }
+            }
+            Err(e) => {
+                out = Err(eyre!("Reading table archive failed: {}", e));
+            }
+        }
+        shutdown();
+    }
+
     // Process shutdown-immediate
     if matches!(server_mode, ServerMode::ShutdownImmediate) {
         shutdown();
@@ -209,6 +458,14 @@ pub async fn run_veilid_server_subnode(
         c.stop().await;
     }
 
+    // Stop the metrics endpoint if we have one
+    if let Some(m) = metrics_server {
+        m.stop().await;
+    }
+
+    // No more configuration reloads for this subnode
+    RUNNING_SUBNODES.lock().remove(&subnode);
+
     // Shut down Veilid API to release state change sender
     veilid_api.shutdown().await;
 
@@ -246,7 +503,12 @@ pub async fn run_veilid_server(
         debug!("Spawning subnode {}", subnode);
         let jh = spawn(
             &format!("subnode{}", subnode),
-            run_veilid_server_subnode(subnode, settings.clone(), server_mode, veilid_logs.clone()),
+            run_veilid_server_subnode(
+                subnode,
+                settings.clone(),
+                server_mode.clone(),
+                veilid_logs.clone(),
+            ),
         );
         all_subnodes_jh.push(jh);
     }
@@ -74,6 +74,11 @@ client_api:
     ipc_directory: '%IPC_DIRECTORY%'
     network_enabled: false
     listen_address: 'localhost:5959'
+metrics:
+    enabled: false
+    listen_address: 'localhost:5960'
+    path: '/metrics'
+    peer_metrics: true
 auto_attach: true
 logging:
     system:
@@ -121,6 +126,9 @@ core:
         delete: false
         device_encryption_key_password: '%DEVICE_ENCRYPTION_KEY_PASSWORD%'
         new_device_encryption_key_password: %NEW_DEVICE_ENCRYPTION_KEY_PASSWORD%
+        backend: 'Keyring'
+        vault_password: '%VAULT_PASSWORD%'
+        external_command: []
     table_store:
         directory: '%TABLE_STORE_DIRECTORY%'
         delete: false
@@ -157,6 +165,14 @@ core:
             timeout_ms: 5000
             max_route_hop_count: 4
             default_route_hop_count: 1
+            queue_share_control: 10
+            queue_share_routing: 30
+            queue_share_dht: 40
+            queue_share_app: 20
+            queue_weight_control: 8
+            queue_weight_routing: 4
+            queue_weight_dht: 2
+            queue_weight_app: 2
         dht:
             max_find_node_count: 20
             resolve_node_timeout_ms: 10000
@@ -286,6 +302,16 @@ core:
     default_config =
         default_config.replace("%NEW_DEVICE_ENCRYPTION_KEY_PASSWORD%", &new_dek_password);
 
+    let vault_password = if let Some(vault_password) = std::env::var_os("VAULT_PASSWORD") {
+        vault_password
+            .to_str()
+            .ok_or_else(|| eyre!("VAULT_PASSWORD is not valid unicode"))?
+            .to_owned()
+    } else {
+        "".to_owned()
+    };
+    default_config = default_config.replace("%VAULT_PASSWORD%", &vault_password);
+
     config::Config::builder()
         .add_source(config::File::from_str(
             &default_config,
@@ -295,6 +321,31 @@ core:
         .wrap_err("failed to parse default config")
 }
 
+fn changed_settings_keys(
+    prefix: &str,
+    a: &json::JsonValue,
+    b: &json::JsonValue,
+    out: &mut Vec<String>,
+) {
+    if a.is_object() && b.is_object() {
+        let keys: std::collections::BTreeSet<&str> = a
+            .entries()
+            .map(|(k, _)| k)
+            .chain(b.entries().map(|(k, _)| k))
+            .collect();
+        for k in keys {
+            let key = if prefix.is_empty() {
+                k.to_owned()
+            } else {
+                format!("{}.{}", prefix, k)
+            };
+            changed_settings_keys(&key, &a[k], &b[k], out);
+        }
+    } else if a != b {
+        out.push(prefix.to_owned());
+    }
+}
+
 pub fn load_config(cfg: config::Config, config_file: &Path) -> EyreResult<config::Config> {
     if let Some(config_file_str) = config_file.to_str() {
         config::Config::builder()
@@ -563,6 +614,14 @@ pub struct ClientApi {
     pub listen_address: NamedSocketAddrs,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Metrics {
+    pub enabled: bool,
+    pub listen_address: NamedSocketAddrs,
+    pub path: String,
+    pub peer_metrics: bool,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Logging {
     pub system: System,
@@ -668,6 +727,14 @@ pub struct Rpc {
     pub timeout_ms: u32,
     pub max_route_hop_count: u8,
     pub default_route_hop_count: u8,
+    pub queue_share_control: u32,
+    pub queue_share_routing: u32,
+    pub queue_share_dht: u32,
+    pub queue_share_app: u32,
+    pub queue_weight_control: u32,
+    pub queue_weight_routing: u32,
+    pub queue_weight_dht: u32,
+    pub queue_weight_app: u32,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -791,6 +858,9 @@ pub struct ProtectedStore {
     pub delete: bool,
     pub device_encryption_key_password: String,
     pub new_device_encryption_key_password: Option<String>,
+    pub backend: veilid_core::VeilidConfigProtectedStoreBackend,
+    pub vault_password: String,
+    pub external_command: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -819,10 +889,18 @@ pub struct Daemon {
     pub stderr_file: Option<String>,
 }
 
+/// The settings at one point in time, from [Settings::snapshot]
+#[derive(Debug, Clone)]
+pub struct SettingsSnapshot {
+    inner: json::JsonValue,
+    file_settings: json::JsonValue,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SettingsInner {
     pub daemon: Daemon,
     pub client_api: ClientApi,
+    pub metrics: Metrics,
     pub auto_attach: bool,
     pub logging: Logging,
     pub testing: Testing,
@@ -832,16 +910,31 @@ pub struct SettingsInner {
 #[derive(Clone, Debug)]
 pub struct Settings {
     inner: Arc<RwLock<SettingsInner>>,
+    config_file: Option<PathBuf>,
+    /// The settings as loaded from the config file, before any command line overrides
+    file_settings: Arc<Mutex<json::JsonValue>>,
 }
 
 impl Settings {
     pub fn new(config_file: Option<&OsStr>) -> EyreResult<Self> {
+        let config_file = config_file.map(PathBuf::from);
+        let inner = Self::load(config_file.as_deref())?;
+        let file_settings = json::parse(&serialize_json(&inner))?;
+
+        //
+        Ok(Self {
+            inner: Arc::new(RwLock::new(inner)),
+            config_file,
+            file_settings: Arc::new(Mutex::new(file_settings)),
+        })
+    }
+
+    fn load(config_file: Option<&Path>) -> EyreResult<SettingsInner> {
         // Load the default config
         let mut cfg = load_default_config()?;
 
         // Merge in the config file if we have one
-        if let Some(config_file) = config_file {
-            let config_file_path = Path::new(config_file);
+        if let Some(config_file_path) = config_file {
             // If the user specifies a config file on the command line then it must exist
             cfg = load_config(cfg, config_file_path)?;
         }
@@ -855,12 +948,72 @@ impl Settings {
                 Self::get_default_remote_max_storage_space_mb(&inner);
         }
 
-        //
-        Ok(Self {
-            inner: Arc::new(RwLock::new(inner)),
+        Ok(inner)
+    }
+
+    /// Re-read the config file and apply the keys that were edited in it since it was last loaded
+    ///
+    /// Keys that were not edited in the file keep their current values, so command line
+    /// overrides survive a reload. Returns the dot-separated names of the changed keys.
+    pub fn reload(&self) -> EyreResult<Vec<String>> {
+        let Some(config_file) = &self.config_file else {
+            bail!("no config file to reload");
+        };
+        let new_inner = Self::load(Some(config_file))?;
+        let new_file_settings = json::parse(&serialize_json(&new_inner))?;
+
+        let mut file_settings = self.file_settings.lock();
+        let mut changed_keys = Vec::new();
+        changed_settings_keys("", &file_settings, &new_file_settings, &mut changed_keys);
+        if changed_keys.is_empty() {
+            return Ok(changed_keys);
+        }
+
+        // Apply just the edited keys to the live settings
+        let mut inner = self.inner.write();
+        let mut settings = json::parse(&serialize_json(&*inner))?;
+        for key in &changed_keys {
+            let mut src = &new_file_settings;
+            let mut dest = &mut settings;
+            for k in key.split('.') {
+                src = &src[k];
+                dest = &mut dest[k];
+            }
+            *dest = src.clone();
+        }
+        *inner = deserialize_json(&settings.dump())?;
+        *file_settings = new_file_settings;
+
+        Ok(changed_keys)
+    }
+
+    /// Save the current settings so a failed [Settings::reload] can be undone with [Settings::restore]
+    pub fn snapshot(&self) -> EyreResult<SettingsSnapshot> {
+        Ok(SettingsSnapshot {
+            inner: json::parse(&serialize_json(&*self.inner.read()))?,
+            file_settings: self.file_settings.lock().clone(),
         })
     }
 
+    /// Put back the settings saved by [Settings::snapshot]
+    pub fn restore(&self, snapshot: SettingsSnapshot) -> EyreResult<()> {
+        let inner = deserialize_json(&snapshot.inner.dump())?;
+        *self.inner.write() = inner;
+        *self.file_settings.lock() = snapshot.file_settings;
+        Ok(())
+    }
+
+    /// The settings as json, without the secrets that must not leave the server
+    pub fn redacted_json(&self) -> EyreResult<json::JsonValue> {
+        let mut settings_json = json::parse(&serialize_json(&*self.inner.read()))?;
+        settings_json["core"]["network"]["routing_table"].remove("node_id_secret");
+        settings_json["core"]["protected_store"].remove("device_encryption_key_password");
+        settings_json["core"]["protected_store"].remove("new_device_encryption_key_password");
+        settings_json["core"]["protected_store"].remove("vault_password");
+        settings_json["core"]["protected_store"].remove("external_command");
+        Ok(settings_json)
+    }
+
     pub fn verify(&self) -> EyreResult<()> {
         cfg_if! {
             if #[cfg(windows)] {
@@ -1081,6 +1234,11 @@ impl Settings {
         set_config_value!(inner.client_api.network_enabled, value);
         set_config_value!(inner.client_api.listen_address, value);
 
+        set_config_value!(inner.metrics.enabled, value);
+        set_config_value!(inner.metrics.listen_address, value);
+        set_config_value!(inner.metrics.path, value);
+        set_config_value!(inner.metrics.peer_metrics, value);
+
         set_config_value!(inner.auto_attach, value);
 
         set_config_value!(inner.logging.system.enabled, value);
@@ -1150,6 +1308,9 @@ impl Settings {
                 .new_device_encryption_key_password,
             value
         );
+        set_config_value!(inner.core.protected_store.backend, value);
+        set_config_value!(inner.core.protected_store.vault_password, value);
+        set_config_value!(inner.core.protected_store.external_command, value);
         set_config_value!(inner.core.table_store.directory, value);
         set_config_value!(inner.core.table_store.delete, value);
         set_config_value!(inner.core.block_store.directory, value);
@@ -1186,6 +1347,14 @@ impl Settings {
         set_config_value!(inner.core.network.rpc.timeout_ms, value);
         set_config_value!(inner.core.network.rpc.max_route_hop_count, value);
         set_config_value!(inner.core.network.rpc.default_route_hop_count, value);
+        set_config_value!(inner.core.network.rpc.queue_share_control, value);
+        set_config_value!(inner.core.network.rpc.queue_share_routing, value);
+        set_config_value!(inner.core.network.rpc.queue_share_dht, value);
+        set_config_value!(inner.core.network.rpc.queue_share_app, value);
+        set_config_value!(inner.core.network.rpc.queue_weight_control, value);
+        set_config_value!(inner.core.network.rpc.queue_weight_routing, value);
+        set_config_value!(inner.core.network.rpc.queue_weight_dht, value);
+        set_config_value!(inner.core.network.rpc.queue_weight_app, value);
         set_config_value!(inner.core.network.dht.max_find_node_count, value);
         set_config_value!(inner.core.network.dht.resolve_node_timeout_ms, value);
         set_config_value!(inner.core.network.dht.resolve_node_count, value);
@@ -1309,6 +1478,13 @@ impl Settings {
                         .new_device_encryption_key_password
                         .clone(),
                 )),
+                "protected_store.backend" => Ok(Box::new(inner.core.protected_store.backend)),
+                "protected_store.vault_password" => {
+                    Ok(Box::new(inner.core.protected_store.vault_password.clone()))
+                }
+                "protected_store.external_command" => Ok(Box::new(
+                    inner.core.protected_store.external_command.clone(),
+                )),
 
                 "table_store.directory" => Ok(Box::new(inner.core.table_store.directory.clone())),
                 "table_store.delete" => Ok(Box::new(inner.core.table_store.delete)),
@@ -1400,6 +1576,30 @@ impl Settings {
                 "network.rpc.default_route_hop_count" => {
                     Ok(Box::new(inner.core.network.rpc.default_route_hop_count))
                 }
+                "network.rpc.queue_share_control" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_share_control))
+                }
+                "network.rpc.queue_share_routing" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_share_routing))
+                }
+                "network.rpc.queue_share_dht" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_share_dht))
+                }
+                "network.rpc.queue_share_app" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_share_app))
+                }
+                "network.rpc.queue_weight_control" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_weight_control))
+                }
+                "network.rpc.queue_weight_routing" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_weight_routing))
+                }
+                "network.rpc.queue_weight_dht" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_weight_dht))
+                }
+                "network.rpc.queue_weight_app" => {
+                    Ok(Box::new(inner.core.network.rpc.queue_weight_app))
+                }
                 "network.dht.max_find_node_count" => {
                     Ok(Box::new(inner.core.network.dht.max_find_node_count))
                 }
@@ -1733,6 +1933,61 @@ mod tests {
         println!("default settings: {:?}", inner);
     }
 
+    #[test]
+    #[serial]
+    fn test_redacted_json() {
+        let settings = Settings::new(None).unwrap();
+        let secrets = [
+            (
+                "core.protected_store.device_encryption_key_password",
+                "hunter2-dek",
+            ),
+            (
+                "core.protected_store.new_device_encryption_key_password",
+                "hunter2-new-dek",
+            ),
+            ("core.protected_store.vault_password", "hunter2-vault"),
+        ];
+        for (key, secret) in secrets {
+            settings.set(key, &json::stringify(secret)).unwrap();
+        }
+        settings
+            .set(
+                "core.protected_store.external_command",
+                r#"["keytool", "--token", "hunter2-command"]"#,
+            )
+            .unwrap();
+        settings
+            .set(
+                "core.network.routing_table.node_id_secret",
+                r#"["VLD0:ZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXp7fH1-f4CBgoM"]"#,
+            )
+            .unwrap();
+
+        let redacted = settings.redacted_json().unwrap();
+        let protected_store = &redacted["core"]["protected_store"];
+        assert!(!protected_store.has_key("device_encryption_key_password"));
+        assert!(!protected_store.has_key("new_device_encryption_key_password"));
+        assert!(!protected_store.has_key("vault_password"));
+        assert!(!protected_store.has_key("external_command"));
+        assert!(!redacted["core"]["network"]["routing_table"].has_key("node_id_secret"));
+        let dump = redacted.dump();
+        assert!(!dump.contains("hunter2"), "secret shown in {}", dump);
+        assert!(!dump.contains("ZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXp7fH1-f4CBgoM"));
+
+        // Everything else is shown
+        assert_eq!(
+            redacted["client_api"]["listen_address"].as_str(),
+            Some("localhost:5959")
+        );
+        assert!(protected_store["directory"].is_string());
+        assert_eq!(protected_store["backend"].as_str(), Some("Keyring"));
+        assert_eq!(
+            redacted["core"]["network"]["rpc"]["queue_size"].as_u32(),
+            Some(1024)
+        );
+    }
+
     #[test]
     #[serial]
     fn test_default_config_settings() {
@@ -1754,6 +2009,14 @@ mod tests {
             s.client_api.listen_address.addrs,
             listen_address_to_socket_addrs("localhost:5959").unwrap()
         );
+        assert!(!s.metrics.enabled);
+        assert_eq!(s.metrics.listen_address.name, "localhost:5960");
+        assert_eq!(
+            s.metrics.listen_address.addrs,
+            listen_address_to_socket_addrs("localhost:5960").unwrap()
+        );
+        assert_eq!(s.metrics.path, "/metrics");
+        assert!(s.metrics.peer_metrics);
         assert!(s.auto_attach);
         assert!(!s.logging.system.enabled);
         assert_eq!(s.logging.system.level, LogLevel::Info);
@@ -1828,6 +2091,12 @@ mod tests {
             s.core.protected_store.new_device_encryption_key_password,
             None
         );
+        assert_eq!(
+            s.core.protected_store.backend,
+            veilid_core::VeilidConfigProtectedStoreBackend::Keyring
+        );
+        assert_eq!(s.core.protected_store.vault_password, "");
+        assert!(s.core.protected_store.external_command.is_empty());
 
         assert_eq!(s.core.network.connection_initial_timeout_ms, 2_000u32);
         assert_eq!(s.core.network.connection_inactivity_timeout_ms, 60_000u32);
@@ -1865,6 +2134,14 @@ mod tests {
         assert_eq!(s.core.network.rpc.timeout_ms, 5_000u32);
         assert_eq!(s.core.network.rpc.max_route_hop_count, 4);
         assert_eq!(s.core.network.rpc.default_route_hop_count, 1);
+        assert_eq!(s.core.network.rpc.queue_share_control, 10);
+        assert_eq!(s.core.network.rpc.queue_share_routing, 30);
+        assert_eq!(s.core.network.rpc.queue_share_dht, 40);
+        assert_eq!(s.core.network.rpc.queue_share_app, 20);
+        assert_eq!(s.core.network.rpc.queue_weight_control, 8);
+        assert_eq!(s.core.network.rpc.queue_weight_routing, 4);
+        assert_eq!(s.core.network.rpc.queue_weight_dht, 2);
+        assert_eq!(s.core.network.rpc.queue_weight_app, 2);
         //
         assert_eq!(s.core.network.dht.max_find_node_count, 20u32);
         assert_eq!(s.core.network.dht.resolve_node_timeout_ms, 10_000u32);
@@ -1989,4 +2266,27 @@ mod tests {
             assert_eq!(s.core.network.virtual_network.server_address, "");
         }
     }
+
+    #[test]
+    fn test_changed_settings_keys() {
+        let a = json::object! {
+            auto_attach: true,
+            logging: { terminal: { enabled: true, level: "info" } },
+            core: { network: { routing_table: { bootstrap: ["a", "b"] } } },
+        };
+        let b = json::object! {
+            auto_attach: true,
+            logging: { terminal: { enabled: true, level: "debug" } },
+            core: { network: { routing_table: { bootstrap: ["a"] } } },
+        };
+        let mut changed = Vec::new();
+        changed_settings_keys("", &a, &b, &mut changed);
+        assert_eq!(
+            changed,
+            vec![
+                "core.network.routing_table.bootstrap".to_owned(),
+                "logging.terminal.level".to_owned(),
+            ]
+        );
+    }
 }
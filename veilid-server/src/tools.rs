@@ -4,7 +4,7 @@ pub use core::future::Future;
 pub use parking_lot::*;
 pub use std::collections::{HashMap, HashSet};
 pub use std::ffi::OsString;
-pub use std::path::Path;
+pub use std::path::{Path, PathBuf};
 pub use std::str::FromStr;
 pub use tracing::*;
 
@@ -9,11 +9,19 @@ use signal_hook_async_std::Signals;
 use veilid_core::tools::*;
 
 #[instrument(level = "trace", skip_all)]
-async fn handle_signals(mut signals: Signals) {
+async fn handle_signals(mut signals: Signals, settings: Settings, veilid_logs: VeilidLogs) {
     while let Some(signal) = signals.next().await {
         match signal {
             SIGHUP => {
-                // XXX: reload configuration?
+                // Reload configuration
+                match reload_config(&settings, &veilid_logs).await {
+                    Ok(report) => {
+                        info!("Configuration reloaded:\n{}", report);
+                    }
+                    Err(e) => {
+                        error!("Configuration reload failed: {:?}", e);
+                    }
+                }
             }
             SIGTERM | SIGINT | SIGQUIT => {
                 // Shutdown the system;
@@ -34,7 +42,10 @@ pub async fn run_veilid_server_with_signals(
         Signals::new([SIGHUP, SIGTERM, SIGINT, SIGQUIT]).wrap_err("failed to init signals")?;
     let handle = signals.handle();
 
-    let signals_task = spawn("signals", handle_signals(signals));
+    let signals_task = spawn(
+        "signals",
+        handle_signals(signals, settings.clone(), veilid_logs.clone()),
+    );
 
     // Run veilid server
     let res = run_veilid_server(settings, server_mode, veilid_logs).await;
@@ -79,6 +79,10 @@ veilidEventEmitter.on('ValueChange', (valueChange) => {
     console.log('VALUE CHANGE', valueChange);
 });
 
+veilidEventEmitter.on('TopicMessage', (topicMessage) => {
+    console.log('TOPIC MESSAGE', topicMessage);
+});
+
 export async function stopVeilid() {
     if (ROUTING_CONTEXT_SINGLETON) {
         ROUTING_CONTEXT_SINGLETON.free();
@@ -46,6 +46,8 @@ lazy_static! {
         SendWrapper::new(RefCell::new(BTreeMap::new()));
     static ref TABLE_DB_TRANSACTIONS: SendWrapper<RefCell<BTreeMap<u32, veilid_core::TableDBTransaction>>> =
         SendWrapper::new(RefCell::new(BTreeMap::new()));
+    static ref CANCEL_HANDLES: SendWrapper<RefCell<BTreeMap<u32, veilid_core::CancelHandle>>> =
+        SendWrapper::new(RefCell::new(BTreeMap::new()));
 }
 
 fn get_veilid_api() -> Result<veilid_core::VeilidAPI, veilid_core::VeilidAPIError> {
@@ -353,6 +355,26 @@ pub fn detach() -> Promise {
     })
 }
 
+#[wasm_bindgen()]
+pub fn power_profile() -> Promise {
+    wrap_api_future_json(async move {
+        let veilid_api = get_veilid_api()?;
+        let power_profile = veilid_api.power_profile()?;
+        APIResult::Ok(power_profile)
+    })
+}
+
+#[wasm_bindgen()]
+pub fn set_power_profile(power_profile: String) -> Promise {
+    let power_profile: veilid_core::PowerProfile =
+        veilid_core::deserialize_json(&power_profile).unwrap();
+    wrap_api_future_void(async move {
+        let veilid_api = get_veilid_api()?;
+        veilid_api.set_power_profile(power_profile)?;
+        APIRESULT_UNDEFINED
+    })
+}
+
 #[wasm_bindgen()]
 pub fn shutdown_veilid_core() -> Promise {
     wrap_api_future_void(async move {
@@ -443,6 +465,107 @@ pub fn routing_context_with_sequencing(id: u32, sequencing: String) -> u32 {
     add_routing_context(routing_context)
 }
 
+#[wasm_bindgen()]
+#[must_use]
+pub fn routing_context_with_fragmentation(id: u32, fragmentation: bool) -> u32 {
+    let routing_context = {
+        let rc = (*ROUTING_CONTEXTS).borrow();
+        let Some(routing_context) = rc.get(&id) else {
+            return 0;
+        };
+        routing_context.clone()
+    };
+    let routing_context = routing_context.with_fragmentation(fragmentation);
+    add_routing_context(routing_context)
+}
+
+#[wasm_bindgen()]
+#[must_use]
+pub fn routing_context_with_app_namespace(id: u32, app_namespace: u32) -> u32 {
+    let routing_context = {
+        let rc = (*ROUTING_CONTEXTS).borrow();
+        let Some(routing_context) = rc.get(&id) else {
+            return 0;
+        };
+        routing_context.clone()
+    };
+    let routing_context = routing_context.with_app_namespace(app_namespace);
+    add_routing_context(routing_context)
+}
+
+fn add_cancel_handle(cancel_handle: veilid_core::CancelHandle) -> u32 {
+    let mut next_id: u32 = 1;
+    let mut ch = (*CANCEL_HANDLES).borrow_mut();
+    while ch.contains_key(&next_id) {
+        next_id += 1;
+    }
+    ch.insert(next_id, cancel_handle);
+    next_id
+}
+
+fn take_cancel_handle(id: u32, func_name: &str) -> APIResult<veilid_core::CancelHandle> {
+    let mut ch = (*CANCEL_HANDLES).borrow_mut();
+    let Some(cancel_handle) = ch.remove(&id) else {
+        return APIResult::Err(veilid_core::VeilidAPIError::invalid_argument(
+            func_name,
+            "cancel_id",
+            id,
+        ));
+    };
+    Ok(cancel_handle)
+}
+
+fn get_call_options(
+    deadline: Option<String>,
+    cancel_id: Option<u32>,
+    func_name: &str,
+) -> APIResult<veilid_core::CallOptions> {
+    let mut options = veilid_core::CallOptions::new();
+    if let Some(deadline) = deadline {
+        let deadline = veilid_core::Timestamp::from_str(&deadline)
+            .map_err(|e| veilid_core::VeilidAPIError::invalid_argument(func_name, "deadline", e))?;
+        options = options.with_deadline(deadline);
+    }
+    if let Some(cancel_id) = cancel_id {
+        let ch = (*CANCEL_HANDLES).borrow();
+        let Some(cancel_handle) = ch.get(&cancel_id) else {
+            return APIResult::Err(veilid_core::VeilidAPIError::invalid_argument(
+                func_name,
+                "cancel_id",
+                cancel_id,
+            ));
+        };
+        options = options.with_cancel_handle(cancel_handle.clone());
+    }
+    Ok(options)
+}
+
+#[wasm_bindgen()]
+#[must_use]
+pub fn new_cancel_handle() -> u32 {
+    add_cancel_handle(veilid_core::CancelHandle::new())
+}
+
+#[wasm_bindgen()]
+#[must_use]
+pub fn cancel_call(cancel_id: u32) -> i32 {
+    let Some(cancel_handle) = (*CANCEL_HANDLES).borrow_mut().remove(&cancel_id) else {
+        return 0;
+    };
+    cancel_handle.cancel();
+    1
+}
+
+#[wasm_bindgen()]
+#[must_use]
+pub fn release_cancel_handle(cancel_id: u32) -> i32 {
+    let mut ch = (*CANCEL_HANDLES).borrow_mut();
+    if ch.remove(&cancel_id).is_none() {
+        return 0;
+    }
+    1
+}
+
 fn get_routing_context(id: u32, func_name: &str) -> APIResult<veilid_core::RoutingContext> {
     let rc = (*ROUTING_CONTEXTS).borrow();
     let Some(routing_context) = rc.get(&id) else {
@@ -464,8 +587,16 @@ pub fn routing_context_safety(id: u32) -> Promise {
 }
 
 #[wasm_bindgen()]
-pub fn routing_context_app_call(id: u32, target_string: String, request: String) -> Promise {
+pub fn routing_context_app_call(
+    id: u32,
+    target_string: String,
+    request: String,
+    deadline: Option<String>,
+    cancel_id: Option<u32>,
+) -> Promise {
+    let options = get_call_options(deadline, cancel_id, "routing_context_app_call");
     wrap_api_future_plain(async move {
+        let options = options?;
         let request: Vec<u8> = data_encoding::BASE64URL_NOPAD
             .decode(request.as_bytes())
             .map_err(VeilidAPIError::generic)?;
@@ -474,7 +605,9 @@ pub fn routing_context_app_call(id: u32, target_string: String, request: String)
 
         let veilid_api = get_veilid_api()?;
         let target = veilid_api.parse_as_target(target_string)?;
-        let answer = routing_context.app_call(target, request).await?;
+        let answer = routing_context
+            .app_call_with_options(target, request, options)
+            .await?;
         let answer = data_encoding::BASE64URL_NOPAD.encode(&answer);
         APIResult::Ok(answer)
     })
@@ -573,15 +706,19 @@ pub fn routing_context_get_dht_value(
     key: String,
     subkey: u32,
     force_refresh: bool,
+    deadline: Option<String>,
+    cancel_id: Option<u32>,
 ) -> Promise {
+    let options = get_call_options(deadline, cancel_id, "routing_context_get_dht_value");
     wrap_api_future_json(async move {
+        let options = options?;
         let key: veilid_core::TypedRecordKey =
             veilid_core::deserialize_json(&key).map_err(VeilidAPIError::generic)?;
 
         let routing_context = get_routing_context(id, "routing_context_get_dht_value")?;
 
         let res = routing_context
-            .get_dht_value(key, subkey, force_refresh)
+            .get_dht_value_with_options(key, subkey, force_refresh, options)
             .await?;
         APIResult::Ok(res)
     })
@@ -594,8 +731,12 @@ pub fn routing_context_set_dht_value(
     subkey: u32,
     data: String,
     writer: Option<String>,
+    deadline: Option<String>,
+    cancel_id: Option<u32>,
 ) -> Promise {
+    let options = get_call_options(deadline, cancel_id, "routing_context_set_dht_value");
     wrap_api_future_json(async move {
+        let options = options?;
         let key: veilid_core::TypedRecordKey =
             veilid_core::deserialize_json(&key).map_err(VeilidAPIError::generic)?;
         let data: Vec<u8> = data_encoding::BASE64URL_NOPAD
@@ -609,7 +750,7 @@ pub fn routing_context_set_dht_value(
         let routing_context = get_routing_context(id, "routing_context_set_dht_value")?;
 
         let res = routing_context
-            .set_dht_value(key, subkey, data, writer)
+            .set_dht_value_with_options(key, subkey, data, writer, options)
             .await?;
         APIResult::Ok(res)
     })
@@ -1562,6 +1703,124 @@ pub fn crypto_encrypt_aead(
     })
 }
 
+#[wasm_bindgen()]
+pub fn crypto_seal(kind: u32, key: String, body: String) -> Promise {
+    wrap_api_future_plain(async move {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+        let key: veilid_core::PublicKey =
+            veilid_core::deserialize_json(&key).map_err(VeilidAPIError::generic)?;
+        let body: Vec<u8> = data_encoding::BASE64URL_NOPAD
+            .decode(body.as_bytes())
+            .map_err(VeilidAPIError::generic)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let csv = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument("crypto_seal", "kind", kind.to_string())
+        })?;
+        let out = csv.seal(&key, &body)?;
+        let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+        APIResult::Ok(out)
+    })
+}
+
+#[wasm_bindgen()]
+pub fn crypto_open(kind: u32, key: String, secret: String, sealed: String) -> Promise {
+    wrap_api_future_plain(async move {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+        let key: veilid_core::PublicKey =
+            veilid_core::deserialize_json(&key).map_err(VeilidAPIError::generic)?;
+        let secret: veilid_core::SecretKey =
+            veilid_core::deserialize_json(&secret).map_err(VeilidAPIError::generic)?;
+        let sealed: Vec<u8> = data_encoding::BASE64URL_NOPAD
+            .decode(sealed.as_bytes())
+            .map_err(VeilidAPIError::generic)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let csv = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument("crypto_open", "kind", kind.to_string())
+        })?;
+        let out = csv.open(&key, &secret, &sealed)?;
+        let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+        APIResult::Ok(out)
+    })
+}
+
+#[wasm_bindgen()]
+pub fn crypto_signcrypt(
+    kind: u32,
+    key: String,
+    sender_key: String,
+    sender_secret: String,
+    body: String,
+) -> Promise {
+    wrap_api_future_plain(async move {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+        let key: veilid_core::PublicKey =
+            veilid_core::deserialize_json(&key).map_err(VeilidAPIError::generic)?;
+        let sender_key: veilid_core::PublicKey =
+            veilid_core::deserialize_json(&sender_key).map_err(VeilidAPIError::generic)?;
+        let sender_secret: veilid_core::SecretKey =
+            veilid_core::deserialize_json(&sender_secret).map_err(VeilidAPIError::generic)?;
+        let body: Vec<u8> = data_encoding::BASE64URL_NOPAD
+            .decode(body.as_bytes())
+            .map_err(VeilidAPIError::generic)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let csv = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument(
+                "crypto_signcrypt",
+                "kind",
+                kind.to_string(),
+            )
+        })?;
+        let out = csv.signcrypt(&key, &sender_key, &sender_secret, &body)?;
+        let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+        APIResult::Ok(out)
+    })
+}
+
+#[wasm_bindgen()]
+pub fn crypto_unsigncrypt(
+    kind: u32,
+    key: String,
+    secret: String,
+    sender_key: String,
+    signcrypted: String,
+) -> Promise {
+    wrap_api_future_plain(async move {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from(kind);
+
+        let key: veilid_core::PublicKey =
+            veilid_core::deserialize_json(&key).map_err(VeilidAPIError::generic)?;
+        let secret: veilid_core::SecretKey =
+            veilid_core::deserialize_json(&secret).map_err(VeilidAPIError::generic)?;
+        let sender_key: veilid_core::PublicKey =
+            veilid_core::deserialize_json(&sender_key).map_err(VeilidAPIError::generic)?;
+        let signcrypted: Vec<u8> = data_encoding::BASE64URL_NOPAD
+            .decode(signcrypted.as_bytes())
+            .map_err(VeilidAPIError::generic)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let csv = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument(
+                "crypto_unsigncrypt",
+                "kind",
+                kind.to_string(),
+            )
+        })?;
+        let out = csv.unsigncrypt(&key, &secret, &sender_key, &signcrypted)?;
+        let out = data_encoding::BASE64URL_NOPAD.encode(&out);
+        APIResult::Ok(out)
+    })
+}
+
 #[wasm_bindgen()]
 pub fn crypto_crypt_no_auth(
     kind: u32,
@@ -183,6 +183,60 @@ impl VeilidClient {
         APIRESULT_UNDEFINED
     }
 
+    /// Disconnect from the network, saving connection and route state so `resume()` can reconnect quickly.
+    pub async fn suspend() -> APIResult<()> {
+        let veilid_api = get_veilid_api()?;
+        veilid_api.suspend().await?;
+        APIRESULT_UNDEFINED
+    }
+
+    /// Reconnect to the network, revalidating the state saved by `suspend()`.
+    /// The attachment state is `Resuming` until revalidation is done.
+    pub async fn resume() -> APIResult<()> {
+        let veilid_api = get_veilid_api()?;
+        veilid_api.resume().await?;
+        APIRESULT_UNDEFINED
+    }
+
+    /// Get the power profile the node is currently using.
+    pub fn powerProfile() -> APIResult<PowerProfile> {
+        let veilid_api = get_veilid_api()?;
+        APIResult::Ok(veilid_api.power_profile()?)
+    }
+
+    /// Select a power profile. `LowPower` cuts back background network maintenance to save battery
+    /// while staying reachable, and `Normal` restores full maintenance.
+    pub fn setPowerProfile(powerProfile: PowerProfile) -> APIResult<()> {
+        let veilid_api = get_veilid_api()?;
+        veilid_api.set_power_profile(powerProfile)?;
+        APIRESULT_UNDEFINED
+    }
+
+    /// Check the node's connectivity and get a list of problems found, each with a suggested fix.
+    pub fn diagnose() -> APIResult<VeilidDiagnostics> {
+        let veilid_api = get_veilid_api()?;
+        APIResult::Ok(veilid_api.diagnose()?)
+    }
+
+    /// Create a handle that can be passed to calls to cancel them while they are in progress.
+    /// Returns an id for the handle, which must be passed to `cancelCall()` or `releaseCancelHandle()` when done.
+    #[must_use]
+    pub fn newCancelHandle() -> u32 {
+        add_cancel_handle(veilid_core::CancelHandle::new())
+    }
+
+    /// Cancel all calls in progress that were started with a cancel handle, and release the handle.
+    pub fn cancelCall(cancelId: u32) -> APIResult<()> {
+        take_cancel_handle(cancelId, "cancelCall")?.cancel();
+        APIRESULT_UNDEFINED
+    }
+
+    /// Release a cancel handle without cancelling the calls using it.
+    pub fn releaseCancelHandle(cancelId: u32) -> APIResult<()> {
+        take_cancel_handle(cancelId, "releaseCancelHandle")?;
+        APIRESULT_UNDEFINED
+    }
+
     /// Get the current timestamp, in string format
     #[must_use]
     pub fn now() -> String {
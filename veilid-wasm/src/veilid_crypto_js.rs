@@ -466,6 +466,79 @@ impl VeilidCrypto {
         APIResult::Ok(out.into_boxed_slice())
     }
 
+    pub fn seal(kind: String, key: PublicKey, body: Box<[u8]>) -> APIResult<Box<[u8]>> {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from_str(&kind)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let crypto_system = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument("crypto_seal", "kind", kind.to_string())
+        })?;
+        let out = crypto_system.seal(&key, &body)?;
+        APIResult::Ok(out.into_boxed_slice())
+    }
+
+    pub fn open(
+        kind: String,
+        key: PublicKey,
+        secret: SecretKey,
+        sealed: Box<[u8]>,
+    ) -> APIResult<Box<[u8]>> {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from_str(&kind)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let crypto_system = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument("crypto_open", "kind", kind.to_string())
+        })?;
+        let out = crypto_system.open(&key, &secret, &sealed)?;
+        APIResult::Ok(out.into_boxed_slice())
+    }
+
+    pub fn signcrypt(
+        kind: String,
+        key: PublicKey,
+        sender_key: PublicKey,
+        sender_secret: SecretKey,
+        body: Box<[u8]>,
+    ) -> APIResult<Box<[u8]>> {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from_str(&kind)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let crypto_system = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument(
+                "crypto_signcrypt",
+                "kind",
+                kind.to_string(),
+            )
+        })?;
+        let out = crypto_system.signcrypt(&key, &sender_key, &sender_secret, &body)?;
+        APIResult::Ok(out.into_boxed_slice())
+    }
+
+    pub fn unsigncrypt(
+        kind: String,
+        key: PublicKey,
+        secret: SecretKey,
+        sender_key: PublicKey,
+        signcrypted: Box<[u8]>,
+    ) -> APIResult<Box<[u8]>> {
+        let kind: veilid_core::CryptoKind = veilid_core::CryptoKind::from_str(&kind)?;
+
+        let veilid_api = get_veilid_api()?;
+        let crypto = veilid_api.crypto()?;
+        let crypto_system = crypto.get(kind).ok_or_else(|| {
+            veilid_core::VeilidAPIError::invalid_argument(
+                "crypto_unsigncrypt",
+                "kind",
+                kind.to_string(),
+            )
+        })?;
+        let out = crypto_system.unsigncrypt(&key, &secret, &sender_key, &signcrypted)?;
+        APIResult::Ok(out.into_boxed_slice())
+    }
+
     pub fn cryptNoAuth(
         kind: String,
         mut body: Box<[u8]>,
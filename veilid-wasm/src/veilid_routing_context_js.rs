@@ -142,6 +142,26 @@ impl VeilidRoutingContext {
         })
     }
 
+    /// Split app calls and app messages that are too large for a single operation into fragments
+    /// that are reassembled by the receiver, allowing messages of up to 1048576 bytes.
+    /// Returns a new instance of VeilidRoutingContext - does not mutate.
+    pub fn withFragmentation(&self, fragmentation: bool) -> APIResult<VeilidRoutingContext> {
+        let routing_context = self.getRoutingContext()?;
+        APIResult::Ok(VeilidRoutingContext {
+            inner_routing_context: routing_context.with_fragmentation(fragmentation),
+        })
+    }
+
+    /// Address app calls and app messages to an application namespace on the receiving node.
+    /// The default namespace is 0.
+    /// Returns a new instance of VeilidRoutingContext - does not mutate.
+    pub fn withAppNamespace(&self, app_namespace: u32) -> APIResult<VeilidRoutingContext> {
+        let routing_context = self.getRoutingContext()?;
+        APIResult::Ok(VeilidRoutingContext {
+            inner_routing_context: routing_context.with_app_namespace(app_namespace),
+        })
+    }
+
     /// Get the safety selection in use on this routing context
     /// @returns the SafetySelection currently in use if successful.
     pub fn safety(&self) -> APIResult<SafetySelection> {
@@ -172,19 +192,26 @@ impl VeilidRoutingContext {
     ///
     /// @param {string} target_string - can be either a direct node id or a private route.
     /// @param {Uint8Array} message - an arbitrary message blob of up to `32768` bytes.
+    /// @param {string} deadline - optional timestamp after which the call is abandoned with a `Timeout` error.
+    /// @param {number} cancelId - optional id from `veilidClient.newCancelHandle()` that can abort the call with a `Cancelled` error.
     /// @returns {Uint8Array} an answer blob of up to `32768` bytes.
     #[wasm_bindgen(skip_jsdoc)]
     pub async fn appCall(
         &self,
         target_string: String,
         request: Box<[u8]>,
+        deadline: Option<String>,
+        cancelId: Option<u32>,
     ) -> APIResult<Uint8Array> {
         let request: Vec<u8> = request.into_vec();
+        let options = get_call_options(deadline, cancelId, "appCall")?;
         let routing_context = self.getRoutingContext()?;
 
         let veilid_api = get_veilid_api()?;
         let target = veilid_api.parse_as_target(target_string)?;
-        let answer = routing_context.app_call(target, request).await?;
+        let answer = routing_context
+            .app_call_with_options(target, request, options)
+            .await?;
         let answer = Uint8Array::from(answer.as_slice());
         APIResult::Ok(answer)
     }
@@ -294,6 +321,8 @@ impl VeilidRoutingContext {
     ///
     /// May pull the latest value from the network, but by settings 'force_refresh' you can force a network data refresh.
     ///
+    /// An optional `deadline` timestamp and `cancelId` from `veilidClient.newCancelHandle()` can end a network refresh early.
+    ///
     /// Returns `undefined` if the value subkey has not yet been set.
     /// Returns a Uint8Array of `data` if the value subkey has valid data.
     pub async fn getDhtValue(
@@ -301,11 +330,14 @@ impl VeilidRoutingContext {
         key: String,
         subkey: u32,
         forceRefresh: bool,
+        deadline: Option<String>,
+        cancelId: Option<u32>,
     ) -> APIResult<Option<ValueData>> {
         let key = TypedRecordKey::from_str(&key)?;
+        let options = get_call_options(deadline, cancelId, "getDhtValue")?;
         let routing_context = self.getRoutingContext()?;
         let res = routing_context
-            .get_dht_value(key, subkey, forceRefresh)
+            .get_dht_value_with_options(key, subkey, forceRefresh, options)
             .await?;
         APIResult::Ok(res)
     }
@@ -314,6 +346,8 @@ impl VeilidRoutingContext {
     /// The DHT record must first by opened via open_dht_record or create_dht_record.
     ///
     /// The writer, if specified, will override the 'default_writer' specified when the record is opened.
+    /// An optional `deadline` timestamp and `cancelId` from `veilidClient.newCancelHandle()` can end the network write early,
+    /// in which case the value is still stored locally and pushed to the network in the background.
     ///
     /// Returns `undefined` if the value was successfully put.
     /// Returns a Uint8Array of `data` if the value put was older than the one available on the network.
@@ -323,16 +357,19 @@ impl VeilidRoutingContext {
         subkey: u32,
         data: Box<[u8]>,
         writer: Option<String>,
+        deadline: Option<String>,
+        cancelId: Option<u32>,
     ) -> APIResult<Option<ValueData>> {
         let key = TypedRecordKey::from_str(&key)?;
         let data = data.into_vec();
         let writer = writer
             .map(|writer| KeyPair::from_str(&writer))
             .map_or(APIResult::Ok(None), |r| r.map(Some))?;
+        let options = get_call_options(deadline, cancelId, "setDhtValue")?;
 
         let routing_context = self.getRoutingContext()?;
         let res = routing_context
-            .set_dht_value(key, subkey, data, writer)
+            .set_dht_value_with_options(key, subkey, data, writer, options)
             .await?;
         APIResult::Ok(res)
     }
@@ -459,4 +496,70 @@ impl VeilidRoutingContext {
             .await?;
         APIResult::Ok(res)
     }
+
+    ///////////////////////////////////
+    // Topics
+
+    /// Creates a pub/sub topic that only the given publishers can publish to.
+    ///
+    /// * 'topic' - the name of the topic, delivered with each message
+    /// * 'publishers' - the public keys of the keypairs publishers will publish with
+    /// * 'kind' - specify a cryptosystem kind to use. Normally you will leave this as None to choose the 'best' cryptosystem available.
+    /// Returns the DHT record key of the topic if successful.
+    pub async fn createTopic(
+        &self,
+        topic: String,
+        publishers: StringArray,
+        kind: Option<String>,
+    ) -> APIResult<String> {
+        let publishers = into_unchecked_string_vec(publishers)
+            .iter()
+            .map(|k| PublicKey::from_str(k))
+            .collect::<APIResult<Vec<PublicKey>>>()?;
+        let crypto_kind = kind
+            .map(|kind| veilid_core::CryptoKind::from_str(&kind))
+            .map_or(APIResult::Ok(None), |r| r.map(Some))?;
+
+        let routing_context = self.getRoutingContext()?;
+        let key = routing_context
+            .create_topic(topic, publishers, crypto_kind)
+            .await?;
+        APIResult::Ok(key.to_string())
+    }
+
+    /// Publishes a message to a pub/sub topic with the keypair of one of its publishers.
+    pub async fn publishTopic(
+        &self,
+        key: String,
+        publisher: String,
+        message: Box<[u8]>,
+    ) -> APIResult<()> {
+        let key = TypedRecordKey::from_str(&key)?;
+        let publisher = KeyPair::from_str(&publisher)?;
+
+        let routing_context = self.getRoutingContext()?;
+        routing_context
+            .publish_topic(key, publisher, message.into_vec())
+            .await?;
+        APIResult::Ok(())
+    }
+
+    /// Subscribes to a pub/sub topic, delivering its messages via VeilidUpdate::TopicMessage callbacks.
+    /// With `catchUp` the messages the topic already holds are delivered first.
+    pub async fn subscribeTopic(&self, key: String, catchUp: bool) -> APIResult<()> {
+        let key = TypedRecordKey::from_str(&key)?;
+
+        let routing_context = self.getRoutingContext()?;
+        routing_context.subscribe_topic(key, catchUp).await?;
+        APIResult::Ok(())
+    }
+
+    /// Stops delivering the messages of a pub/sub topic.
+    pub async fn unsubscribeTopic(&self, key: String) -> APIResult<()> {
+        let key = TypedRecordKey::from_str(&key)?;
+
+        let routing_context = self.getRoutingContext()?;
+        routing_context.unsubscribe_topic(key).await?;
+        APIResult::Ok(())
+    }
 }
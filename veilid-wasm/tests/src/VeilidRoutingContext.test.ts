@@ -66,6 +66,18 @@ describe('VeilidRoutingContext', () => {
       expect(routingContext instanceof VeilidRoutingContext).toBe(true);
     });
 
+    it('should create with fragmentation', async () => {
+      const routingContext =
+        VeilidRoutingContext.create().withFragmentation(true);
+      expect(routingContext instanceof VeilidRoutingContext).toBe(true);
+    });
+
+    it('should create with app namespace', async () => {
+      const routingContext =
+        VeilidRoutingContext.create().withAppNamespace(5);
+      expect(routingContext instanceof VeilidRoutingContext).toBe(true);
+    });
+
     it('should error if unsafe is used', async () => {
       expect(() => {
         VeilidRoutingContext.create().withSafety({
@@ -29,6 +29,7 @@ export const waitForPublicAttachment = async () => {
         case "Detached":
         case "Detaching":
         case "Attaching":
+        case "Resuming":
           break;
         default:
           attached = true;
@@ -142,6 +142,59 @@ describe('veilidCrypto', () => {
     }).not.toThrow();
   });
 
+  it('should seal and open', () => {
+    const bestKind = veilidCrypto.bestCryptoKind();
+    const body = textEncoder.encode('This is a sealed body for my eyes only📦');
+    const [publicKey, secretKey] = veilidCrypto
+      .generateKeyPair(bestKind)
+      .split(':');
+
+    const sealed = veilidCrypto.seal(bestKind, publicKey, body);
+    expect(sealed instanceof Uint8Array).toBe(true);
+
+    const opened = veilidCrypto.open(bestKind, publicKey, secretKey, sealed);
+    expect(body).toEqual(opened);
+  });
+
+  it('should signcrypt and unsigncrypt', () => {
+    const bestKind = veilidCrypto.bestCryptoKind();
+    const body = textEncoder.encode('This is a body only I could have sent✍️');
+    const [publicKey, secretKey] = veilidCrypto
+      .generateKeyPair(bestKind)
+      .split(':');
+    const [senderKey, senderSecret] = veilidCrypto
+      .generateKeyPair(bestKind)
+      .split(':');
+
+    const signcrypted = veilidCrypto.signcrypt(
+      bestKind,
+      publicKey,
+      senderKey,
+      senderSecret,
+      body
+    );
+    expect(signcrypted instanceof Uint8Array).toBe(true);
+
+    const unsigncrypted = veilidCrypto.unsigncrypt(
+      bestKind,
+      publicKey,
+      secretKey,
+      senderKey,
+      signcrypted
+    );
+    expect(body).toEqual(unsigncrypted);
+
+    expect(() => {
+      veilidCrypto.unsigncrypt(
+        bestKind,
+        publicKey,
+        secretKey,
+        publicKey,
+        signcrypted
+      );
+    }).toThrow();
+  });
+
   describe('constants', () => {
     it('CRYPTO_KEY_LENGTH', () => {
       expect(typeof veilidCrypto.CRYPTO_KEY_LENGTH).toBe('number');